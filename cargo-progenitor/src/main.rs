@@ -54,6 +54,9 @@ struct Args {
     /// Include client code rather than depending on progenitor-client
     #[clap(default_value = match is_non_release() { true => "true", false => "false" }, long, action = clap::ArgAction::Set)]
     include_client: bool,
+    /// Implement proptest's Arbitrary for the generated types
+    #[clap(long)]
+    proptest: bool,
 }
 
 #[derive(Copy, Clone, ValueEnum)]
@@ -119,7 +122,8 @@ fn main() -> Result<()> {
     let mut builder = Generator::new(
         GenerationSettings::default()
             .with_interface(args.interface.into())
-            .with_tag(args.tags.into()),
+            .with_tag(args.tags.into())
+            .with_proptest(args.proptest),
     );
 
     match builder.generate_tokens(&api) {
@@ -136,6 +140,9 @@ fn main() -> Result<()> {
             println!("-----------------------------------------------------");
             println!();
 
+            let features = args
+                .include_client
+                .then(|| included_client_features(&builder));
             let name = &args.name;
             let version = &args.version;
 
@@ -168,6 +175,9 @@ fn main() -> Result<()> {
                 )
                 .chars(),
             );
+            if let Some(features) = features {
+                tomlout.push_str(&features);
+            }
 
             save(&toml, tomlout.as_str())?;
 
@@ -210,35 +220,84 @@ fn main() -> Result<()> {
 // manually encode the versions. We need to take care to update this
 // particularly when generated code depends on particular dependency versions.
 struct Dependencies {
+    async_trait: &'static str,
     base64: &'static str,
     bytes: &'static str,
     chrono: &'static str,
     futures: &'static str,
+    http: &'static str,
+    metrics: &'static str,
     percent_encoding: &'static str,
+    proptest: &'static str,
     rand: &'static str,
     regress: &'static str,
     reqwest: &'static str,
     serde: &'static str,
     serde_json: &'static str,
     serde_urlencoded: &'static str,
+    tokio: &'static str,
+    tracing: &'static str,
     uuid: &'static str,
 }
 
 static DEPENDENCIES: Dependencies = Dependencies {
+    async_trait: "0.1",
     base64: "0.22",
     bytes: "1.9",
     chrono: "0.4",
     futures: "0.3",
+    http: "1.0",
+    metrics: "0.24",
     percent_encoding: "2.3",
-    rand: "0.8",
+    proptest: "1.7",
+    rand: "0.9",
     regress: "0.10",
     reqwest: "0.12",
     serde: "1.0",
     serde_json: "1.0",
     serde_urlencoded: "0.7",
+    tokio: "1.0",
+    tracing: "0.1",
     uuid: "1.0",
 };
 
+/// Quoted names of the features of progenitor-client that the generated code
+/// needs.
+fn client_features(builder: &Generator) -> Vec<String> {
+    [
+        ("async-trait", builder.uses_async_trait()),
+        ("proptest", builder.uses_proptest()),
+        ("tokio", builder.uses_tokio()),
+    ]
+    .into_iter()
+    .filter(|(_, used)| *used)
+    .map(|(feature, _)| format!("\"{feature}\""))
+    .collect()
+}
+
+/// The `[features]` section for a crate that includes the client code. Its
+/// optional dependencies double as features, enabled by default where the
+/// generated code needs them; `rand` is only needed by the ranged downloads
+/// and trace context of the `tokio` and `tracing` features, unless
+/// websockets need it regardless.
+fn included_client_features(builder: &Generator) -> String {
+    let mut features = String::from("[features]\n");
+    let default_features = client_features(builder);
+    if !default_features.is_empty() {
+        // e.g. file uploads and downloads need the optional `tokio`
+        // dependency of the included client code
+        features.push_str(&format!("default = [{}]\n", default_features.join(", ")));
+    }
+    if builder.uses_websockets() {
+        features.push_str("tokio = [\"dep:tokio\"]\ntracing = [\"dep:tracing\"]\n");
+    } else {
+        features.push_str(
+            "tokio = [\"dep:rand\", \"dep:tokio\"]\ntracing = [\"dep:rand\", \"dep:tracing\"]\n",
+        );
+    }
+    features
+}
+
 pub fn dependencies(builder: Generator, include_client: bool) -> Vec<String> {
     let mut deps = vec![
         format!("bytes = \"{}\"", DEPENDENCIES.bytes),
@@ -253,10 +312,40 @@ pub fn dependencies(builder: Generator, include_client: bool) -> Vec<String> {
 
     if include_client {
         // code included from progenitor-client needs extra dependencies
+        deps.push(format!(
+            "async-trait = {{ version = \"{}\", optional = true }}",
+            DEPENDENCIES.async_trait
+        ));
+        deps.push(format!("http = \"{}\"", DEPENDENCIES.http));
         deps.push(format!(
             "percent-encoding = \"{}\"",
             DEPENDENCIES.percent_encoding
         ));
+        deps.push(format!(
+            "tokio = {{ version = \"{}\", features = [\"fs\", \"io-util\", \"time\"], optional = true }}",
+            DEPENDENCIES.tokio
+        ));
+        // optional dependencies double as the `async-trait`, `metrics`, and
+        // `proptest` features; see `included_client_features` for `tokio` and
+        // `tracing`
+        deps.push(format!(
+            "tracing = {{ version = \"{}\", optional = true }}",
+            DEPENDENCIES.tracing
+        ));
+        deps.push(format!(
+            "metrics = {{ version = \"{}\", optional = true }}",
+            DEPENDENCIES.metrics
+        ));
+        deps.push(format!(
+            "proptest = {{ version = \"{}\", optional = true }}",
+            DEPENDENCIES.proptest
+        ));
+        if !builder.uses_websockets() {
+            deps.push(format!(
+                "rand = {{ version = \"{}\", optional = true }}",
+                DEPENDENCIES.rand
+            ));
+        }
         needs_serde_json = true;
     } else {
         let crate_version =
@@ -265,11 +354,23 @@ pub fn dependencies(builder: Generator, include_client: bool) -> Vec<String> {
             } else {
                 "*"
             };
-        let client_version_dep = format!("progenitor-client = \"{}\"", crate_version);
+        let features = client_features(&builder);
+        let client_version_dep = if !features.is_empty() {
+            format!(
+                "progenitor-client = {{ version = \"{}\", features = [{}] }}",
+                crate_version,
+                features.join(", "),
+            )
+        } else {
+            format!("progenitor-client = \"{}\"", crate_version)
+        };
         deps.push(client_version_dep);
+        if builder.uses_proptest() {
+            deps.push(format!("proptest = \"{}\"", DEPENDENCIES.proptest));
+        }
     }
 
-    if type_space.uses_regress() {
+    if type_space.uses_regress() || builder.uses_regress() {
         deps.push(format!("regress = \"{}\"", DEPENDENCIES.regress));
     }
     if type_space.uses_uuid() {
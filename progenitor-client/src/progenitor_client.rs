@@ -4,7 +4,10 @@
 
 //! Support code for generated clients.
 
-use std::ops::{Deref, DerefMut};
+use std::{
+    ops::{Deref, DerefMut},
+    sync::Arc,
+};
 
 use bytes::Bytes;
 use futures_core::Stream;
@@ -18,20 +21,550 @@ type InnerByteStream = std::pin::Pin<Box<dyn Stream<Item = reqwest::Result<Bytes
 type InnerByteStream = std::pin::Pin<Box<dyn Stream<Item = reqwest::Result<Bytes>>>>;
 
 /// Untyped byte stream used for both success and error responses.
-pub struct ByteStream(InnerByteStream);
+pub struct ByteStream(InnerByteStream, Option<u64>);
 
 impl ByteStream {
     /// Creates a new ByteStream
     ///
     /// Useful for generating test fixtures.
     pub fn new(inner: InnerByteStream) -> Self {
-        Self(inner)
+        Self(inner, None)
     }
 
     /// Consumes the [`ByteStream`] and return its inner [`Stream`].
     pub fn into_inner(self) -> InnerByteStream {
         self.0
     }
+
+    /// Invoke `progress` as data is received with the number of bytes
+    /// received so far and the total length of the body (if known).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_progress<F>(self, progress: F) -> Self
+    where
+        F: FnMut(u64, Option<u64>) + Send + Sync + 'static,
+    {
+        let Self(inner, length) = self;
+        Self(
+            Box::pin(ProgressStream::new(inner, length, Box::new(progress))),
+            length,
+        )
+    }
+
+    /// Invoke `progress` as data is received with the number of bytes
+    /// received so far and the total length of the body (if known).
+    #[cfg(target_arch = "wasm32")]
+    pub fn with_progress<F>(self, progress: F) -> Self
+    where
+        F: FnMut(u64, Option<u64>) + 'static,
+    {
+        let Self(inner, length) = self;
+        Self(
+            Box::pin(ProgressStream::new(inner, length, Box::new(progress))),
+            length,
+        )
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+type ProgressFn = Box<dyn FnMut(u64, Option<u64>) + Send + Sync>;
+
+#[cfg(target_arch = "wasm32")]
+type ProgressFn = Box<dyn FnMut(u64, Option<u64>)>;
+
+/// Reports the number of bytes that have passed through a stream.
+struct ProgressStream<S> {
+    inner: S,
+    transferred: u64,
+    total: Option<u64>,
+    progress: ProgressFn,
+}
+
+impl<S> ProgressStream<S> {
+    fn new(inner: S, total: Option<u64>, progress: ProgressFn) -> Self {
+        Self {
+            inner,
+            transferred: 0,
+            total,
+            progress,
+        }
+    }
+}
+
+impl<S, E> Stream for ProgressStream<S>
+where
+    S: Stream<Item = Result<Bytes, E>> + Unpin,
+{
+    type Item = Result<Bytes, E>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let poll = std::pin::Pin::new(&mut this.inner).poll_next(cx);
+        if let std::task::Poll::Ready(Some(Ok(bytes))) = &poll {
+            this.transferred += bytes.len() as u64;
+            (this.progress)(this.transferred, this.total);
+        }
+        poll
+    }
+}
+
+/// Body for operations that accept arbitrary bytes (such as
+/// `application/octet-stream`).
+///
+/// Any type that can be converted into a [`reqwest::Body`] may be used; large
+/// bodies may be streamed from a file or other reader, in which case their
+/// length is sent with the request.
+pub struct UploadBody {
+    inner: UploadBodyInner,
+    length: Option<u64>,
+    progress: Option<ProgressFn>,
+}
+
+enum UploadBodyInner {
+    Body(reqwest::Body),
+    #[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+    Reader(std::pin::Pin<Box<dyn tokio::io::AsyncRead + Send + Sync>>),
+}
+
+impl<B: Into<reqwest::Body>> From<B> for UploadBody {
+    fn from(body: B) -> Self {
+        let body = body.into();
+        let length = body.as_bytes().map(|bytes| bytes.len() as u64);
+        Self {
+            inner: UploadBodyInner::Body(body),
+            length,
+            progress: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for UploadBody {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UploadBody")
+            .field("length", &self.length)
+            .finish_non_exhaustive()
+    }
+}
+
+impl UploadBody {
+    /// Stream the body from the file at `path`.
+    ///
+    /// The file is opened (and its length determined) immediately with
+    /// blocking calls, which are brief for local files; where blocking the
+    /// executor matters, open the file with `tokio::fs` and use
+    /// [`UploadBody::from_reader`]. Requires the `tokio` feature.
+    #[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+    pub fn from_file<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Self> {
+        let file = std::fs::File::open(path)?;
+        let length = file.metadata()?.len();
+        Ok(Self::from_reader(tokio::fs::File::from_std(file), length))
+    }
+
+    /// Stream the body from `reader`, which must produce exactly `length`
+    /// bytes. Requires the `tokio` feature.
+    #[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+    pub fn from_reader<R>(reader: R, length: u64) -> Self
+    where
+        R: tokio::io::AsyncRead + Send + Sync + 'static,
+    {
+        Self {
+            inner: UploadBodyInner::Reader(Box::pin(reader)),
+            length: Some(length),
+            progress: None,
+        }
+    }
+
+    /// Invoke `progress` as the body is sent with the number of bytes sent so
+    /// far and the total length of the body (if known).
+    ///
+    /// Progress is reported as each chunk of the body is handed to the
+    /// connection; in-memory bodies are sent as a single chunk.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_progress<F>(mut self, progress: F) -> Self
+    where
+        F: FnMut(u64, Option<u64>) + Send + Sync + 'static,
+    {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+
+    /// The length of the body, if known.
+    pub fn content_length(&self) -> Option<u64> {
+        self.length
+    }
+
+    #[doc(hidden)]
+    pub fn into_body(self) -> reqwest::Body {
+        let Self {
+            inner,
+            length,
+            progress,
+        } = self;
+
+        match (inner, progress) {
+            (UploadBodyInner::Body(body), None) => body,
+            #[cfg(not(target_arch = "wasm32"))]
+            (UploadBodyInner::Body(body), Some(progress)) => {
+                reqwest::Body::wrap_stream(ProgressStream::new(BodyStream(body), length, progress))
+            }
+            #[cfg(target_arch = "wasm32")]
+            (UploadBodyInner::Body(body), Some(_)) => body,
+            #[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+            (UploadBodyInner::Reader(reader), progress) => {
+                let stream = ReaderStream::new(reader);
+                match progress {
+                    Some(progress) => {
+                        reqwest::Body::wrap_stream(ProgressStream::new(stream, length, progress))
+                    }
+                    None => reqwest::Body::wrap_stream(stream),
+                }
+            }
+        }
+    }
+}
+
+/// Re-exported for bounds on generated download methods, which require the
+/// `tokio` feature.
+#[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+pub use tokio::io::AsyncWrite;
+
+/// Re-exported for the generated `ClientApi` trait and server traits, which
+/// require the `async-trait` feature.
+#[cfg(feature = "async-trait")]
+#[doc(hidden)]
+pub use async_trait::async_trait;
+
+/// Writes a binary response body to a file or other [`AsyncWrite`], resuming
+/// with `Range` requests if the transfer is interrupted.
+///
+/// Generated clients provide download methods for operations that support
+/// range requests; this may also be driven directly by sending each request
+/// with the headers from [`RangedDownload::range_headers`] and passing the
+/// result to [`RangedDownload::receive`] until it produces a value:
+///
+/// ```ignore
+/// let mut download = RangedDownload::new(&mut file);
+/// let written = loop {
+///     let mut headers = HeaderMap::new();
+///     download.range_headers(&mut headers);
+///     let result = send_with_headers(headers).await;
+///     if let Some(result) = download.receive(result).await {
+///         break result?;
+///     }
+/// };
+/// ```
+///
+/// A resumed request includes an `If-Range` header with the original
+/// response's (strong) `ETag`; if the server instead responds with the full
+/// body and the `ETag` differs, the download fails rather than mixing
+/// contents.
+///
+/// Each retry waits with exponential backoff (and random jitter) after the
+/// previous attempt; the number of retries without progress, and in total, is
+/// bounded.
+///
+/// Requires the `tokio` feature.
+#[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+pub struct RangedDownload<'a, W: ?Sized> {
+    writer: &'a mut W,
+    written: u64,
+    total: Option<u64>,
+    etag: Option<reqwest::header::HeaderValue>,
+    response: Option<(reqwest::StatusCode, reqwest::header::HeaderMap)>,
+    attempts: u32,
+    max_attempts: u32,
+    retries: u32,
+    max_retries: u32,
+    initial_backoff: std::time::Duration,
+    max_backoff: std::time::Duration,
+}
+
+#[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+impl<'a, W> RangedDownload<'a, W>
+where
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    /// Download into `writer`, retrying up to three times in a row without
+    /// progress and up to ten times in total, with backoff starting at 100
+    /// milliseconds and growing to at most ten seconds.
+    pub fn new(writer: &'a mut W) -> Self {
+        Self {
+            writer,
+            written: 0,
+            total: None,
+            etag: None,
+            response: None,
+            attempts: 0,
+            max_attempts: 3,
+            retries: 0,
+            max_retries: 10,
+            initial_backoff: std::time::Duration::from_millis(100),
+            max_backoff: std::time::Duration::from_secs(10),
+        }
+    }
+
+    /// Set the number of consecutive failed attempts (i.e. those that made no
+    /// progress) after which the download fails.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Set the total number of retries after which the download fails,
+    /// whether or not they made progress.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Set the delay before the first retry and the limit to which it doubles
+    /// with each consecutive retry that made no progress. Each delay is
+    /// chosen at random between half and all of that value.
+    pub fn with_backoff(mut self, initial: std::time::Duration, max: std::time::Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// The number of bytes written so far.
+    pub fn written(&self) -> u64 {
+        self.written
+    }
+
+    /// Add the headers for the next request to `headers`.
+    pub fn range_headers(&self, headers: &mut reqwest::header::HeaderMap) {
+        if self.written == 0 {
+            return;
+        }
+        if let Ok(range) = format!("bytes={}-", self.written).try_into() {
+            headers.insert(reqwest::header::RANGE, range);
+        }
+        if let Some(etag) = &self.etag {
+            headers.insert(reqwest::header::IF_RANGE, etag.clone());
+        }
+    }
+
+    /// Write the body of a response, returning `None` if another request
+    /// should be sent to resume the download, or the total number of bytes
+    /// written along with the status and headers of the first response.
+    pub async fn receive<E>(
+        &mut self,
+        result: Result<ResponseValue<ByteStream>, Error<E>>,
+    ) -> Option<Result<ResponseValue<u64>, Error<E>>> {
+        use tokio::io::AsyncWriteExt;
+
+        let response = match result {
+            Ok(response) => response,
+            Err(Error::CommunicationError(e)) => {
+                return self.retry(Error::CommunicationError(e)).await;
+            }
+            Err(e) => return Some(Err(e)),
+        };
+
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        // A partial response must pick up where we left off; a full response
+        // is only usable if the content hasn't changed, in which case we skip
+        // what we've already written.
+        let mut skip = 0;
+        if status == reqwest::StatusCode::PARTIAL_CONTENT {
+            match content_range(&headers) {
+                Some((start, total)) if start == self.written => {
+                    self.total = total.or(self.total);
+                }
+                _ => {
+                    return Some(Err(Error::Custom(format!(
+                        "unexpected Content-Range resuming download at byte {}",
+                        self.written
+                    ))))
+                }
+            }
+        } else {
+            if self.written > 0
+                && (self.etag.is_none() || headers.get(reqwest::header::ETAG) != self.etag.as_ref())
+            {
+                return Some(Err(Error::Custom(
+                    "resource changed while resuming download".to_string(),
+                )));
+            }
+            skip = self.written;
+            self.total = response.content_length();
+        }
+
+        if self.response.is_none() {
+            // Only strong validators may be used with If-Range.
+            self.etag = headers
+                .get(reqwest::header::ETAG)
+                .filter(|etag| !etag.as_bytes().starts_with(b"W/"))
+                .cloned();
+            self.response = Some((status, headers));
+        }
+
+        let mut stream = response.into_inner().into_inner();
+        while let Some(chunk) = std::future::poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+            let mut chunk = match chunk {
+                Ok(chunk) => chunk,
+                Err(e) => return self.retry(Error::ResponseBodyError(e)).await,
+            };
+            if skip > 0 {
+                let n = skip.min(chunk.len() as u64);
+                let _ = chunk.split_to(n as usize);
+                skip -= n;
+            }
+            if let Err(e) = self.writer.write_all(&chunk).await {
+                return Some(Err(Error::Custom(format!(
+                    "failed to write download: {}",
+                    e
+                ))));
+            }
+            if !chunk.is_empty() {
+                self.written += chunk.len() as u64;
+                self.attempts = 0;
+            }
+        }
+
+        // The connection may also close early without an error.
+        if let Some(total) = self.total {
+            if self.written < total {
+                return self
+                    .retry(Error::Custom(format!(
+                        "download ended after {} of {} bytes",
+                        self.written, total
+                    )))
+                    .await;
+            }
+        }
+
+        if let Err(e) = self.writer.flush().await {
+            return Some(Err(Error::Custom(format!(
+                "failed to write download: {}",
+                e
+            ))));
+        }
+
+        let (status, headers) = self.response.take()?;
+        Some(Ok(ResponseValue::new(self.written, status, headers)))
+    }
+
+    /// Wait before the next attempt, or fail with `error` if there have been
+    /// too many.
+    async fn retry<E>(&mut self, error: Error<E>) -> Option<Result<ResponseValue<u64>, Error<E>>> {
+        self.attempts += 1;
+        self.retries += 1;
+        if self.attempts > self.max_attempts || self.retries > self.max_retries {
+            return Some(Err(error));
+        }
+
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(1 << (self.attempts - 1).min(16))
+            .min(self.max_backoff);
+        if !backoff.is_zero() {
+            let half = backoff / 2;
+            let jitter = rand::random::<u64>() % ((backoff - half).as_millis() as u64 + 1);
+            tokio::time::sleep(half + std::time::Duration::from_millis(jitter)).await;
+        }
+        None
+    }
+}
+
+/// Parse the start offset and (if known) complete length from a
+/// `Content-Range` header of the form `bytes <start>-<end>/<length>`.
+#[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+fn content_range(headers: &reqwest::header::HeaderMap) -> Option<(u64, Option<u64>)> {
+    let value = headers.get(reqwest::header::CONTENT_RANGE)?.to_str().ok()?;
+    let (range, total) = value.strip_prefix("bytes ")?.split_once('/')?;
+    let (start, _) = range.split_once('-')?;
+    let total = match total {
+        "*" => None,
+        total => Some(total.parse().ok()?),
+    };
+    Some((start.parse().ok()?, total))
+}
+
+/// Produces the data of a [`reqwest::Body`] as a stream of chunks; the bytes
+/// of an in-memory body are passed on without being copied.
+#[cfg(not(target_arch = "wasm32"))]
+struct BodyStream(reqwest::Body);
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Stream for BodyStream {
+    type Item = reqwest::Result<Bytes>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        use http_body::Body;
+        use std::task::Poll;
+
+        let body = &mut self.get_mut().0;
+        loop {
+            match std::pin::Pin::new(&mut *body).poll_frame(cx) {
+                Poll::Ready(Some(Ok(frame))) => {
+                    // Trailers aren't part of the data.
+                    if let Ok(data) = frame.into_data() {
+                        return Poll::Ready(Some(Ok(data)));
+                    }
+                }
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Produces the contents of a reader as a stream of chunks, read into a
+/// buffer whose allocation is reused once the chunks sent from it are
+/// dropped.
+#[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+struct ReaderStream {
+    reader: std::pin::Pin<Box<dyn tokio::io::AsyncRead + Send + Sync>>,
+    buf: bytes::BytesMut,
+}
+
+#[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+impl ReaderStream {
+    const CHUNK_SIZE: usize = 64 * 1024;
+
+    fn new(reader: std::pin::Pin<Box<dyn tokio::io::AsyncRead + Send + Sync>>) -> Self {
+        Self {
+            reader,
+            buf: bytes::BytesMut::new(),
+        }
+    }
+}
+
+#[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+impl Stream for ReaderStream {
+    type Item = std::io::Result<Bytes>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        use std::task::Poll;
+
+        let Self { reader, buf } = self.get_mut();
+        // Growing the empty buffer reclaims its allocation if every chunk
+        // split from it has been dropped.
+        buf.resize(Self::CHUNK_SIZE, 0);
+        let mut read_buf = tokio::io::ReadBuf::new(buf);
+        match reader.as_mut().poll_read(cx, &mut read_buf) {
+            Poll::Ready(Ok(())) if read_buf.filled().is_empty() => Poll::Ready(None),
+            Poll::Ready(Ok(())) => {
+                let len = read_buf.filled().len();
+                buf.truncate(len);
+                Poll::Ready(Some(Ok(buf.split().freeze())))
+            }
+            Poll::Ready(Err(e)) => Poll::Ready(Some(Err(e))),
+            Poll::Pending => Poll::Pending,
+        }
+    }
 }
 
 impl Deref for ByteStream {
@@ -48,58 +581,1070 @@ impl DerefMut for ByteStream {
     }
 }
 
-/// Interface for which an implementation is generated for all clients.
-pub trait ClientInfo<Inner> {
-    /// Get the version of this API.
-    ///
-    /// This string is pulled directly from the source OpenAPI document and may
-    /// be in any format the API selects.
-    fn api_version() -> &'static str;
+#[cfg(not(target_arch = "wasm32"))]
+type BoxFuture<T> = std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send>>;
 
-    /// Get the base URL to which requests are made.
-    fn baseurl(&self) -> &str;
+#[cfg(target_arch = "wasm32")]
+type BoxFuture<T> = std::pin::Pin<Box<dyn std::future::Future<Output = T>>>;
 
-    /// Get the internal `reqwest::Client` used to make requests.
-    fn client(&self) -> &reqwest::Client;
+#[cfg(not(target_arch = "wasm32"))]
+type SleepFn = Box<dyn Fn(std::time::Duration) -> BoxFuture<()> + Send + Sync>;
 
-    /// Get the inner value of type `T` if one is specified.
-    fn inner(&self) -> &Inner;
+#[cfg(target_arch = "wasm32")]
+type SleepFn = Box<dyn Fn(std::time::Duration) -> BoxFuture<()>>;
+
+/// A single event received from a `text/event-stream` response.
+///
+/// The `data` payload is deserialized into the type described by the
+/// operation's response schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServerSentEvent<T> {
+    /// The event type, if the server specified one. Per the specification,
+    /// events without a type should be treated as `message` events.
+    pub event: Option<String>,
+    /// The last event ID set by the server at the time this event was
+    /// dispatched.
+    pub id: Option<String>,
+    /// The reconnection time most recently requested by the server.
+    pub retry: Option<std::time::Duration>,
+    /// The deserialized payload of the event.
+    pub data: T,
 }
 
-impl<T, Inner> ClientInfo<Inner> for &T
-where
-    T: ClientInfo<Inner>,
-{
-    fn api_version() -> &'static str {
-        T::api_version()
+/// Typed stream of events from a `text/event-stream` (Server-Sent Events)
+/// response.
+///
+/// Each item is either an event whose `data` has been deserialized into `T`
+/// or an error that occurred while reading or decoding the stream.
+pub struct EventStream<T> {
+    inner: Option<InnerByteStream>,
+    buffer: Vec<u8>,
+    frame: EventFrame,
+    last_event_id: Option<String>,
+    retry: Option<std::time::Duration>,
+    pending: std::collections::VecDeque<Result<ServerSentEvent<T>, Error>>,
+    reconnect: Option<EventStreamReconnect>,
+    connecting: Option<BoxFuture<reqwest::Result<reqwest::Response>>>,
+    max_event_size: usize,
+}
+
+/// The fields of the event currently being assembled.
+#[derive(Default)]
+struct EventFrame {
+    event: Option<String>,
+    data: String,
+}
+
+/// State required to re-issue the original request.
+struct EventStreamReconnect {
+    client: reqwest::Client,
+    request: Option<reqwest::Request>,
+    sleep: Option<SleepFn>,
+}
+
+/// The reconnection time suggested by the specification when the server
+/// hasn't provided one.
+const EVENT_STREAM_DEFAULT_RETRY: std::time::Duration = std::time::Duration::from_secs(3);
+
+const EVENT_STREAM_DEFAULT_MAX_EVENT_SIZE: usize = 16 * 1024 * 1024;
+
+impl<T> EventStream<T> {
+    /// Creates a new EventStream from a stream of bytes.
+    ///
+    /// Useful for generating test fixtures.
+    pub fn new(inner: InnerByteStream) -> Self {
+        Self {
+            inner: Some(inner),
+            buffer: Vec::new(),
+            frame: EventFrame::default(),
+            last_event_id: None,
+            retry: None,
+            pending: Default::default(),
+            reconnect: None,
+            connecting: None,
+            max_event_size: EVENT_STREAM_DEFAULT_MAX_EVENT_SIZE,
+        }
     }
 
-    fn baseurl(&self) -> &str {
-        (*self).baseurl()
+    /// Limit the size of each event, in bytes, which bounds the memory used
+    /// to buffer an incomplete event.
+    pub fn with_max_event_size(mut self, limit: usize) -> Self {
+        self.max_event_size = limit;
+        self
     }
 
-    fn client(&self) -> &reqwest::Client {
-        (*self).client()
+    /// Reconnect automatically when the connection is closed or interrupted.
+    ///
+    /// The original request is re-sent with the `Last-Event-ID` header set to
+    /// the ID of the most recently received event. Before reconnecting, the
+    /// stream awaits the future returned by `sleep` for the reconnection time
+    /// requested by the server (or a default of three seconds); for example,
+    /// `|d| tokio::time::sleep(d)`. The stream ends when the server responds
+    /// to a reconnection with `204 No Content`; any other unsuccessful
+    /// response is returned as an error and ends the stream.
+    ///
+    /// This has no effect if the request could not be retained for
+    /// reconnection, for example if its body was a stream.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_reconnect<F, Fut>(mut self, sleep: F) -> Self
+    where
+        F: Fn(std::time::Duration) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = ()> + Send + 'static,
+    {
+        if let Some(reconnect) = &mut self.reconnect {
+            reconnect.sleep = Some(Box::new(move |d| Box::pin(sleep(d))));
+        }
+        self
     }
 
-    fn inner(&self) -> &Inner {
-        (*self).inner()
+    /// Reconnect automatically when the connection is closed or interrupted.
+    ///
+    /// See the non-wasm variant of this method for details.
+    #[cfg(target_arch = "wasm32")]
+    pub fn with_reconnect<F, Fut>(mut self, sleep: F) -> Self
+    where
+        F: Fn(std::time::Duration) -> Fut + 'static,
+        Fut: std::future::Future<Output = ()> + 'static,
+    {
+        if let Some(reconnect) = &mut self.reconnect {
+            reconnect.sleep = Some(Box::new(move |d| Box::pin(sleep(d))));
+        }
+        self
+    }
+
+    /// The ID of the most recently received event, if any.
+    pub fn last_event_id(&self) -> Option<&str> {
+        self.last_event_id.as_deref()
+    }
+
+    /// Begin a reconnection attempt if one is configured. Returns `false` if
+    /// the stream should instead end.
+    fn start_reconnect(&mut self) -> bool {
+        let Some(EventStreamReconnect {
+            client,
+            request: Some(request),
+            sleep: Some(sleep),
+        }) = &self.reconnect
+        else {
+            return false;
+        };
+        let Some(mut request) = request.try_clone() else {
+            return false;
+        };
+
+        if let Some(id) = &self.last_event_id {
+            match reqwest::header::HeaderValue::from_str(id) {
+                Ok(value) => {
+                    request.headers_mut().insert("last-event-id", value);
+                }
+                Err(e) => {
+                    self.pending
+                        .push_back(Err(Error::InvalidRequest(e.to_string())));
+                    return false;
+                }
+            }
+        }
+
+        let client = client.clone();
+        let delay = sleep(self.retry.unwrap_or(EVENT_STREAM_DEFAULT_RETRY));
+        self.connecting = Some(Box::pin(async move {
+            delay.await;
+            client.execute(request).await
+        }));
+        true
     }
 }
 
-/// Information about an operation, consumed by hook implementations.
-pub struct OperationInfo {
-    /// The corresponding operationId from the source OpenAPI document.
-    pub operation_id: &'static str,
+impl<T: DeserializeOwned> EventStream<T> {
+    /// Process all complete lines in the buffer, queueing any events that
+    /// they complete.
+    fn process_buffer(&mut self) {
+        let mut start = 0;
+        while let Some(offset) = self.buffer[start..]
+            .iter()
+            .position(|b| *b == b'\n' || *b == b'\r')
+        {
+            let end = start + offset;
+            // A trailing CR may be the first half of a CRLF; wait for more
+            // data to find out.
+            let next = match self.buffer[end] {
+                b'\r' => match self.buffer.get(end + 1) {
+                    Some(b'\n') => end + 2,
+                    Some(_) => end + 1,
+                    None => break,
+                },
+                _ => end + 1,
+            };
+
+            let line = String::from_utf8_lossy(&self.buffer[start..end]).into_owned();
+            self.process_line(&line);
+            start = next;
+        }
+        self.buffer.drain(..start);
+    }
+
+    fn process_line(&mut self, line: &str) {
+        if line.is_empty() {
+            self.dispatch();
+            return;
+        }
+
+        let (field, value) = match line.find(':') {
+            // Comment lines are ignored.
+            Some(0) => return,
+            Some(ii) => {
+                let value = &line[ii + 1..];
+                (&line[..ii], value.strip_prefix(' ').unwrap_or(value))
+            }
+            None => (line, ""),
+        };
+
+        match field {
+            "event" => self.frame.event = Some(value.to_string()),
+            "data" => {
+                self.frame.data.push_str(value);
+                self.frame.data.push('\n');
+            }
+            "id" if !value.contains('\0') => {
+                self.last_event_id = (!value.is_empty()).then(|| value.to_string());
+            }
+            "retry" if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) => {
+                if let Ok(ms) = value.parse() {
+                    self.retry = Some(std::time::Duration::from_millis(ms));
+                }
+            }
+            // Unknown fields are ignored.
+            _ => {}
+        }
+    }
+
+    fn dispatch(&mut self) {
+        let EventFrame { event, mut data } = std::mem::take(&mut self.frame);
+
+        // An event with no data is not dispatched.
+        if data.is_empty() {
+            return;
+        }
+        data.pop();
+
+        // The data is typically JSON; if it isn't, it may still be a string
+        // (or a type that can be deserialized from one).
+        let result = serde_json::from_str(&data).or_else(|e| {
+            serde_json::from_value(serde_json::Value::String(data.clone()))
+                .map_err(|_| Error::InvalidResponsePayload(data.into(), e))
+        });
+
+        self.pending.push_back(result.map(|data| ServerSentEvent {
+            event,
+            id: self.last_event_id.clone(),
+            retry: self.retry,
+            data,
+        }));
+    }
 }
 
-/// Interface for changing the behavior of generated clients. All clients
-/// implement this for `&Client`; to override the default behavior, implement
-/// some or all of the interfaces for the `Client` type (without the
-/// reference). This mechanism relies on so-called "auto-ref specialization".
-#[allow(async_fn_in_trait, unused)]
-pub trait ClientHooks<Inner = ()>
-where
+// No fields are structurally pinned; pending events are only moved out.
+impl<T> Unpin for EventStream<T> {}
+
+impl<T: DeserializeOwned> Stream for EventStream<T> {
+    type Item = Result<ServerSentEvent<T>, Error>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        use std::task::Poll;
+
+        let this = self.get_mut();
+
+        loop {
+            if let Some(item) = this.pending.pop_front() {
+                return Poll::Ready(Some(item));
+            }
+
+            if let Some(connecting) = &mut this.connecting {
+                let result = match connecting.as_mut().poll(cx) {
+                    Poll::Ready(result) => result,
+                    Poll::Pending => return Poll::Pending,
+                };
+                this.connecting = None;
+                match result {
+                    Ok(response) if response.status() == reqwest::StatusCode::NO_CONTENT => {
+                        return Poll::Ready(None);
+                    }
+                    Ok(response) if response.status().is_success() => {
+                        this.inner = Some(Box::pin(response.bytes_stream()));
+                        this.buffer.clear();
+                        this.frame = EventFrame::default();
+                    }
+                    Ok(response) => {
+                        this.reconnect = None;
+                        return Poll::Ready(Some(Err(Error::UnexpectedResponse(response))));
+                    }
+                    Err(e) => {
+                        this.reconnect = None;
+                        return Poll::Ready(Some(Err(Error::CommunicationError(e))));
+                    }
+                }
+            }
+
+            let Some(inner) = &mut this.inner else {
+                return Poll::Ready(None);
+            };
+
+            match inner.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(bytes))) => {
+                    this.buffer.extend_from_slice(&bytes);
+                    this.process_buffer();
+
+                    // The incomplete event must not grow without bound.
+                    if this.buffer.len() + this.frame.data.len() > this.max_event_size {
+                        this.inner = None;
+                        this.reconnect = None;
+                        this.buffer = Vec::new();
+                        this.frame = EventFrame::default();
+                        this.pending.push_back(Err(Error::ResponseBodyTooLarge(
+                            this.max_event_size as u64,
+                        )));
+                    }
+                }
+                Poll::Ready(Some(Err(e))) => {
+                    this.inner = None;
+                    if !this.start_reconnect() {
+                        this.pending.push_back(Err(Error::ResponseBodyError(e)));
+                    }
+                }
+                Poll::Ready(None) => {
+                    // Any partially received event is discarded.
+                    this.inner = None;
+                    this.start_reconnect();
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Typed stream of values from a JSON Lines (`application/x-ndjson` or
+/// `application/jsonl`) response.
+///
+/// Each line of the response body is deserialized into `T` as it arrives;
+/// only the current, incomplete line is buffered. Blank lines are skipped. A
+/// line longer than the maximum line length (16 MiB by default) produces
+/// [`Error::ResponseBodyTooLarge`] and ends the stream.
+pub struct JsonLinesStream<T> {
+    inner: Option<InnerByteStream>,
+    buffer: Vec<u8>,
+    // Offset into `buffer` of the start of the next line.
+    start: usize,
+    // Offset into `buffer` up to which we know there is no newline.
+    searched: usize,
+    max_line_length: usize,
+    _phantom: std::marker::PhantomData<fn() -> T>,
+}
+
+const JSON_LINES_DEFAULT_MAX_LINE_LENGTH: usize = 16 * 1024 * 1024;
+
+impl<T> JsonLinesStream<T> {
+    /// Creates a new JsonLinesStream from a stream of bytes.
+    ///
+    /// Useful for generating test fixtures.
+    pub fn new(inner: InnerByteStream) -> Self {
+        Self {
+            inner: Some(inner),
+            buffer: Vec::new(),
+            start: 0,
+            searched: 0,
+            max_line_length: JSON_LINES_DEFAULT_MAX_LINE_LENGTH,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Limit the length of each line, in bytes, which bounds the memory used
+    /// to buffer an incomplete line.
+    pub fn with_max_line_length(mut self, limit: usize) -> Self {
+        self.max_line_length = limit;
+        self
+    }
+}
+
+impl<T: DeserializeOwned> JsonLinesStream<T> {
+    /// Take the next complete line from the buffer, if there is one.
+    fn next_line(&mut self) -> Option<(usize, usize)> {
+        let Some(offset) = self.buffer[self.searched..]
+            .iter()
+            .position(|b| *b == b'\n')
+        else {
+            self.searched = self.buffer.len();
+            return None;
+        };
+        let end = self.searched + offset;
+        let line = (self.start, end);
+        self.start = end + 1;
+        self.searched = self.start;
+        Some(line)
+    }
+
+    fn decode(&self, start: usize, end: usize) -> Option<Result<T, Error>> {
+        let line = self.buffer[start..end].trim_ascii();
+        if line.is_empty() {
+            return None;
+        }
+        Some(
+            serde_json::from_slice(line)
+                .map_err(|e| Error::InvalidResponsePayload(Bytes::copy_from_slice(line), e)),
+        )
+    }
+}
+
+impl<T: DeserializeOwned> Stream for JsonLinesStream<T> {
+    type Item = Result<T, Error>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        use std::task::Poll;
+
+        let this = self.get_mut();
+
+        loop {
+            while let Some((start, end)) = this.next_line() {
+                if let Some(item) = this.decode(start, end) {
+                    return Poll::Ready(Some(item));
+                }
+            }
+
+            let Some(inner) = &mut this.inner else {
+                return Poll::Ready(None);
+            };
+
+            // The incomplete line must not grow without bound.
+            if this.buffer.len() - this.start > this.max_line_length {
+                this.inner = None;
+                this.buffer = Vec::new();
+                this.start = 0;
+                this.searched = 0;
+                return Poll::Ready(Some(Err(Error::ResponseBodyTooLarge(
+                    this.max_line_length as u64,
+                ))));
+            }
+
+            match inner.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(bytes))) => {
+                    // Discard consumed lines before reading more.
+                    this.buffer.drain(..this.start);
+                    this.searched -= this.start;
+                    this.start = 0;
+                    this.buffer.extend_from_slice(&bytes);
+                }
+                Poll::Ready(Some(Err(e))) => {
+                    this.inner = None;
+                    return Poll::Ready(Some(Err(Error::ResponseBodyError(e))));
+                }
+                Poll::Ready(None) => {
+                    // The final line need not be terminated by a newline.
+                    this.inner = None;
+                    let (start, end) = (this.start, this.buffer.len());
+                    this.start = end;
+                    this.searched = end;
+                    return Poll::Ready(this.decode(start, end));
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Typed stream of the elements of a JSON array in a response body.
+///
+/// The array may be the entire body or the value of a single property of a
+/// top-level object. Elements are deserialized into `T` as they arrive so that
+/// only the element currently being received is buffered; the other
+/// properties of an enclosing object are available from
+/// [`other_fields`](Self::other_fields) once the stream has ended.
+pub struct JsonArrayStream<T> {
+    inner: Option<InnerByteStream>,
+    buffer: Vec<u8>,
+    // Offset into `buffer` of the next unconsumed byte.
+    pos: usize,
+    state: JsonArrayState,
+    scanner: JsonValueScanner,
+    field: Option<&'static str>,
+    other_fields: serde_json::Map<String, serde_json::Value>,
+    received: u64,
+    max_body_size: Option<u64>,
+    _phantom: std::marker::PhantomData<fn() -> T>,
+}
+
+/// Position within the body of a [`JsonArrayStream`].
+enum JsonArrayState {
+    Start,
+    ObjectKey { first: bool },
+    ObjectColon(String),
+    ObjectValue(String),
+    ObjectNext,
+    FieldValue,
+    ArrayElement { first: bool },
+    ArrayNext,
+    Trailing,
+    Done,
+}
+
+/// Locates the end of a JSON value, possibly across several calls as more of
+/// the value arrives.
+#[derive(Default)]
+struct JsonValueScanner {
+    offset: usize,
+    depth: usize,
+    in_string: bool,
+    escape: bool,
+}
+
+impl JsonValueScanner {
+    /// Returns the length of the value at the start of `buf` if it's
+    /// complete.
+    fn scan(&mut self, buf: &[u8], eof: bool) -> Option<usize> {
+        while let Some(&b) = buf.get(self.offset) {
+            self.offset += 1;
+            if self.in_string {
+                if self.escape {
+                    self.escape = false;
+                } else if b == b'\\' {
+                    self.escape = true;
+                } else if b == b'"' {
+                    self.in_string = false;
+                    if self.depth == 0 {
+                        return Some(self.offset);
+                    }
+                }
+                continue;
+            }
+            match b {
+                b'"' => self.in_string = true,
+                b'{' | b'[' => self.depth += 1,
+                b'}' | b']' if self.depth > 0 => {
+                    self.depth -= 1;
+                    if self.depth == 0 {
+                        return Some(self.offset);
+                    }
+                }
+                // Scalars end at the next delimiter.
+                b',' | b'}' | b']' if self.depth == 0 => return Some(self.offset - 1),
+                b if b.is_ascii_whitespace() && self.depth == 0 => return Some(self.offset - 1),
+                _ => {}
+            }
+        }
+        // A scalar may also end at the end of the body.
+        (eof && self.depth == 0 && !self.in_string).then_some(self.offset)
+    }
+}
+
+/// The result of processing buffered data in a [`JsonArrayStream`].
+enum JsonArrayStep<T> {
+    Item(Result<T, Error>),
+    NeedMore,
+    End,
+}
+
+impl<T> JsonArrayStream<T> {
+    /// Creates a new JsonArrayStream from a stream of bytes. If `field` is
+    /// specified, the body is expected to be an object and the elements of the
+    /// array-valued property with that name are produced; otherwise the body
+    /// is expected to be an array.
+    ///
+    /// Useful for generating test fixtures.
+    pub fn new(inner: InnerByteStream, field: Option<&'static str>) -> Self {
+        Self {
+            inner: Some(inner),
+            buffer: Vec::new(),
+            pos: 0,
+            state: JsonArrayState::Start,
+            scanner: JsonValueScanner::default(),
+            field,
+            other_fields: Default::default(),
+            received: 0,
+            max_body_size: None,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Limit the total size of the response body. If the body exceeds `limit`
+    /// bytes, the stream produces [`Error::ResponseBodyTooLarge`] and ends.
+    pub fn with_max_body_size(mut self, limit: u64) -> Self {
+        self.max_body_size = Some(limit);
+        self
+    }
+
+    /// The properties of the enclosing object other than the array. These are
+    /// complete once the stream has ended.
+    pub fn other_fields(&self) -> &serde_json::Map<String, serde_json::Value> {
+        &self.other_fields
+    }
+
+    fn fail<U>(&mut self, msg: &str) -> JsonArrayStep<U> {
+        self.state = JsonArrayState::Done;
+        self.inner = None;
+        let rest = Bytes::copy_from_slice(&self.buffer[self.pos..]);
+        JsonArrayStep::Item(Err(Error::InvalidResponsePayload(
+            rest,
+            serde::de::Error::custom(msg),
+        )))
+    }
+
+    fn need_more<U>(&mut self, state: JsonArrayState, eof: bool) -> JsonArrayStep<U> {
+        if eof {
+            self.fail("unexpected end of JSON input")
+        } else {
+            self.state = state;
+            JsonArrayStep::NeedMore
+        }
+    }
+
+    /// Consume the next complete value in the buffer, if there is one.
+    fn next_value(&mut self, eof: bool) -> Option<&[u8]> {
+        let len = self.scanner.scan(&self.buffer[self.pos..], eof)?;
+        let start = self.pos;
+        self.pos += len;
+        self.scanner = JsonValueScanner::default();
+        Some(&self.buffer[start..self.pos])
+    }
+}
+
+impl<T: DeserializeOwned> JsonArrayStream<T> {
+    fn step(&mut self, eof: bool) -> JsonArrayStep<T> {
+        loop {
+            // Skip whitespace between tokens.
+            if self.scanner.offset == 0 {
+                while self
+                    .buffer
+                    .get(self.pos)
+                    .is_some_and(u8::is_ascii_whitespace)
+                {
+                    self.pos += 1;
+                }
+            }
+            let next = self.buffer.get(self.pos).copied();
+
+            let state = std::mem::replace(&mut self.state, JsonArrayState::Done);
+            self.state = match (state, next) {
+                (JsonArrayState::Done, _) => return JsonArrayStep::End,
+                (JsonArrayState::Trailing, None) if eof => return JsonArrayStep::End,
+                (JsonArrayState::Trailing, Some(_)) => {
+                    return self.fail("trailing characters after JSON value")
+                }
+                (state, None) => return self.need_more(state, eof),
+
+                (JsonArrayState::Start, Some(b'[')) if self.field.is_none() => {
+                    self.pos += 1;
+                    JsonArrayState::ArrayElement { first: true }
+                }
+                (JsonArrayState::Start, Some(b'{')) if self.field.is_some() => {
+                    self.pos += 1;
+                    JsonArrayState::ObjectKey { first: true }
+                }
+                (JsonArrayState::Start, _) => return self.fail("unexpected JSON value"),
+
+                (JsonArrayState::ObjectKey { first: true }, Some(b'}')) => {
+                    self.pos += 1;
+                    JsonArrayState::Trailing
+                }
+                (JsonArrayState::ObjectKey { first }, Some(b'"')) => {
+                    let Some(key) = self.next_value(eof) else {
+                        return self.need_more(JsonArrayState::ObjectKey { first }, eof);
+                    };
+                    match serde_json::from_slice::<String>(key) {
+                        Ok(key) => JsonArrayState::ObjectColon(key),
+                        Err(_) => return self.fail("invalid object key"),
+                    }
+                }
+                (JsonArrayState::ObjectKey { .. }, _) => return self.fail("expected object key"),
+
+                (JsonArrayState::ObjectColon(key), Some(b':')) => {
+                    self.pos += 1;
+                    if Some(key.as_str()) == self.field {
+                        JsonArrayState::FieldValue
+                    } else {
+                        JsonArrayState::ObjectValue(key)
+                    }
+                }
+                (JsonArrayState::ObjectColon(_), _) => return self.fail("expected `:`"),
+
+                (JsonArrayState::ObjectValue(key), Some(_)) => {
+                    let Some(value) = self.next_value(eof) else {
+                        return self.need_more(JsonArrayState::ObjectValue(key), eof);
+                    };
+                    match serde_json::from_slice(value) {
+                        Ok(value) => {
+                            self.other_fields.insert(key, value);
+                            JsonArrayState::ObjectNext
+                        }
+                        Err(_) => return self.fail("invalid object value"),
+                    }
+                }
+
+                (JsonArrayState::ObjectNext, Some(b',')) => {
+                    self.pos += 1;
+                    JsonArrayState::ObjectKey { first: false }
+                }
+                (JsonArrayState::ObjectNext, Some(b'}')) => {
+                    self.pos += 1;
+                    JsonArrayState::Trailing
+                }
+                (JsonArrayState::ObjectNext, _) => return self.fail("expected `,` or `}`"),
+
+                (JsonArrayState::FieldValue, Some(b'[')) => {
+                    self.pos += 1;
+                    JsonArrayState::ArrayElement { first: true }
+                }
+                (JsonArrayState::FieldValue, _) => return self.fail("expected array"),
+
+                (JsonArrayState::ArrayElement { first: true }, Some(b']')) => {
+                    self.pos += 1;
+                    self.after_array()
+                }
+                (JsonArrayState::ArrayElement { first }, Some(_)) => {
+                    let Some(value) = self.next_value(eof) else {
+                        return self.need_more(JsonArrayState::ArrayElement { first }, eof);
+                    };
+                    // Elements that don't match the type produce an error
+                    // without ending the stream.
+                    let item = serde_json::from_slice(value).map_err(|e| {
+                        Error::InvalidResponsePayload(Bytes::copy_from_slice(value), e)
+                    });
+                    self.state = JsonArrayState::ArrayNext;
+                    return JsonArrayStep::Item(item);
+                }
+
+                (JsonArrayState::ArrayNext, Some(b',')) => {
+                    self.pos += 1;
+                    JsonArrayState::ArrayElement { first: false }
+                }
+                (JsonArrayState::ArrayNext, Some(b']')) => {
+                    self.pos += 1;
+                    self.after_array()
+                }
+                (JsonArrayState::ArrayNext, _) => return self.fail("expected `,` or `]`"),
+            };
+        }
+    }
+
+    fn after_array(&self) -> JsonArrayState {
+        if self.field.is_some() {
+            JsonArrayState::ObjectNext
+        } else {
+            JsonArrayState::Trailing
+        }
+    }
+}
+
+impl<T: DeserializeOwned> Stream for JsonArrayStream<T> {
+    type Item = Result<T, Error>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        use std::task::Poll;
+
+        let this = self.get_mut();
+
+        loop {
+            match this.step(this.inner.is_none()) {
+                JsonArrayStep::Item(item) => return Poll::Ready(Some(item)),
+                JsonArrayStep::End => return Poll::Ready(None),
+                JsonArrayStep::NeedMore => {}
+            }
+
+            let Some(inner) = &mut this.inner else {
+                unreachable!("more data needed at the end of the body");
+            };
+
+            match inner.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(bytes))) => {
+                    this.received += bytes.len() as u64;
+                    if let Some(limit) = this.max_body_size.filter(|limit| this.received > *limit) {
+                        this.inner = None;
+                        this.state = JsonArrayState::Done;
+                        return Poll::Ready(Some(Err(Error::ResponseBodyTooLarge(limit))));
+                    }
+
+                    // Discard consumed data before reading more.
+                    this.buffer.drain(..this.pos);
+                    this.pos = 0;
+                    this.buffer.extend_from_slice(&bytes);
+                }
+                Poll::Ready(Some(Err(e))) => {
+                    this.inner = None;
+                    this.state = JsonArrayState::Done;
+                    return Poll::Ready(Some(Err(Error::ResponseBodyError(e))));
+                }
+                Poll::Ready(None) => this.inner = None,
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Request body that serializes each item of a stream as a line of JSON.
+#[cfg(not(target_arch = "wasm32"))]
+struct JsonLinesBody<S> {
+    // The mutex is never locked; it's only present so that the body is `Sync`
+    // (as reqwest requires) without requiring the same of the stream.
+    items: std::sync::Mutex<std::pin::Pin<Box<S>>>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<S> Stream for JsonLinesBody<S>
+where
+    S: Stream,
+    S::Item: Serialize,
+{
+    type Item = Result<Bytes, serde_json::Error>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        let items = self
+            .get_mut()
+            .items
+            .get_mut()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        items.as_mut().poll_next(cx).map(|item| {
+            item.map(|item| {
+                let mut line = serde_json::to_vec(&item)?;
+                line.push(b'\n');
+                Ok(line.into())
+            })
+        })
+    }
+}
+
+/// Construct a JSON Lines request body from a stream of items.
+///
+/// Items are serialized as the body is sent so that the full body need never
+/// be held in memory.
+#[doc(hidden)]
+#[cfg(not(target_arch = "wasm32"))]
+pub fn json_lines_body<S>(items: S) -> reqwest::Body
+where
+    S: Stream + Send + 'static,
+    S::Item: Serialize,
+{
+    reqwest::Body::wrap_stream(JsonLinesBody {
+        items: std::sync::Mutex::new(Box::pin(items)),
+    })
+}
+
+/// Interface for which an implementation is generated for all clients.
+pub trait ClientInfo<Inner> {
+    /// Get the version of this API.
+    ///
+    /// This string is pulled directly from the source OpenAPI document and may
+    /// be in any format the API selects.
+    fn api_version() -> &'static str;
+
+    /// Get the base URL to which requests are made.
+    fn baseurl(&self) -> &str;
+
+    /// Get the internal `reqwest::Client` used to make requests.
+    fn client(&self) -> &reqwest::Client;
+
+    /// Get the inner value of type `T` if one is specified.
+    fn inner(&self) -> &Inner;
+}
+
+impl<T, Inner> ClientInfo<Inner> for &T
+where
+    T: ClientInfo<Inner>,
+{
+    fn api_version() -> &'static str {
+        T::api_version()
+    }
+
+    fn baseurl(&self) -> &str {
+        (*self).baseurl()
+    }
+
+    fn client(&self) -> &reqwest::Client {
+        (*self).client()
+    }
+
+    fn inner(&self) -> &Inner {
+        (*self).inner()
+    }
+}
+
+/// Description of an operation from the source OpenAPI document. Generated
+/// clients list all of their operations in `Client::OPERATIONS`.
+#[derive(Debug)]
+pub struct OperationMetadata {
+    /// The corresponding operationId, as used for the generated method.
+    pub operation_id: &'static str,
+    /// The HTTP method.
+    pub method: reqwest::Method,
+    /// The path template, with parameters in braces such as `/users/{id}`.
+    pub path: &'static str,
+    /// The tags of the operation.
+    pub tags: &'static [&'static str],
+    /// Whether sending the request more than once has the same effect as
+    /// sending it once, according to the semantics of its HTTP method.
+    pub idempotent: bool,
+    /// Whether the operation is marked as deprecated.
+    pub deprecated: bool,
+    /// The status codes of the declared successful (`1XX` and `2XX`)
+    /// responses; `default` responses aren't included.
+    pub success_statuses: &'static [std::ops::RangeInclusive<u16>],
+}
+
+impl OperationMetadata {
+    /// Whether `status` is among the declared successful responses.
+    pub fn is_success(&self, status: reqwest::StatusCode) -> bool {
+        self.success_statuses
+            .iter()
+            .any(|statuses| statuses.contains(&status.as_u16()))
+    }
+}
+
+/// Information about an operation, consumed by hook implementations.
+#[non_exhaustive]
+pub struct OperationInfo {
+    /// The corresponding operationId from the source OpenAPI document.
+    pub operation_id: &'static str,
+    /// The description of the operation.
+    pub metadata: &'static OperationMetadata,
+    #[doc(hidden)]
+    pub context: Option<Arc<dyn std::any::Any + Send + Sync>>,
+}
+
+impl OperationInfo {
+    /// Information about an invocation of the operation described by
+    /// `metadata`.
+    pub fn new(metadata: &'static OperationMetadata) -> Self {
+        Self {
+            operation_id: metadata.operation_id,
+            metadata,
+            context: None,
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn with_context(mut self, context: Option<Arc<dyn std::any::Any + Send + Sync>>) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// The context value provided with [`RequestOptions::context`], if it's
+    /// of type `T`.
+    pub fn context<T: std::any::Any>(&self) -> Option<&T> {
+        self.context.as_deref()?.downcast_ref()
+    }
+}
+
+/// Additional headers, query parameters, and context for a single request.
+///
+/// Builders accept these with `with_header`, `with_query`, `with_context`,
+/// and `with_options`; positional clients have an `<operation>_with_options`
+/// method for each operation.
+#[derive(Clone, Default)]
+pub struct RequestOptions {
+    headers: reqwest::header::HeaderMap,
+    query: Vec<(String, String)>,
+    context: Option<Arc<dyn std::any::Any + Send + Sync>>,
+    error: Option<String>,
+}
+
+impl std::fmt::Debug for RequestOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequestOptions")
+            .field("headers", &self.headers)
+            .field("query", &self.query)
+            .field("context", &self.context.is_some())
+            .finish()
+    }
+}
+
+impl RequestOptions {
+    /// Create empty request options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a header, replacing any header of the same name that the request
+    /// would otherwise have. An invalid name or value is reported as
+    /// [`Error::InvalidRequest`] when the request is sent.
+    pub fn header<K, V>(mut self, name: K, value: V) -> Self
+    where
+        reqwest::header::HeaderName: TryFrom<K>,
+        <reqwest::header::HeaderName as TryFrom<K>>::Error: std::fmt::Display,
+        reqwest::header::HeaderValue: TryFrom<V>,
+        <reqwest::header::HeaderValue as TryFrom<V>>::Error: std::fmt::Display,
+    {
+        let header = reqwest::header::HeaderName::try_from(name)
+            .map_err(|e| format!("invalid header name: {}", e))
+            .and_then(|name| match reqwest::header::HeaderValue::try_from(value) {
+                Ok(value) => Ok((name, value)),
+                Err(e) => Err(format!("invalid value for header {}: {}", name, e)),
+            });
+        match header {
+            Ok((name, value)) => {
+                self.headers.append(name, value);
+            }
+            Err(e) => {
+                self.error.get_or_insert(e);
+            }
+        }
+        self
+    }
+
+    /// Add a query parameter, after those of the operation.
+    pub fn query<K: Into<String>, V: ToString>(mut self, name: K, value: V) -> Self {
+        self.query.push((name.into(), value.to_string()));
+        self
+    }
+
+    /// Add an `If-Match` header so that the server applies an update only if
+    /// the resource still has the entity tag `etag`, such as one from
+    /// [`ResponseValue::etag`].
+    pub fn if_match<V: AsRef<str>>(self, etag: V) -> Self {
+        self.header(reqwest::header::IF_MATCH, etag.as_ref())
+    }
+
+    /// Set a value that hooks may retrieve with [`OperationInfo::context`].
+    pub fn context<T: std::any::Any + Send + Sync>(mut self, value: T) -> Self {
+        self.context = Some(Arc::new(value));
+        self
+    }
+
+    /// Apply the headers and query parameters to `request`, returning the
+    /// context for [`OperationInfo`].
+    #[doc(hidden)]
+    pub fn apply<E>(
+        &self,
+        request: &mut reqwest::Request,
+    ) -> Result<Option<Arc<dyn std::any::Any + Send + Sync>>, Error<E>> {
+        if let Some(e) = &self.error {
+            return Err(Error::InvalidRequest(e.clone()));
+        }
+        if !self.query.is_empty() {
+            request
+                .url_mut()
+                .query_pairs_mut()
+                .extend_pairs(&self.query);
+        }
+        request.headers_mut().extend(self.headers.clone());
+        Ok(self.context.clone())
+    }
+}
+
+/// Interface for changing the behavior of generated clients. All clients
+/// implement this for `&Client`; to override the default behavior, implement
+/// some or all of the interfaces for the `Client` type (without the
+/// reference). This mechanism relies on so-called "auto-ref specialization".
+#[allow(async_fn_in_trait, unused)]
+pub trait ClientHooks<Inner = ()>
+where
     Self: ClientInfo<Inner>,
 {
     /// Runs prior to the execution of the request. This may be used to modify
@@ -112,45 +1657,1462 @@ where
         Ok(())
     }
 
-    /// Runs after completion of the request.
-    async fn post<E>(
-        &self,
-        result: &reqwest::Result<reqwest::Response>,
-        info: &OperationInfo,
-    ) -> std::result::Result<(), Error<E>> {
-        Ok(())
+    /// Runs after completion of the request.
+    async fn post<E>(
+        &self,
+        result: &reqwest::Result<reqwest::Response>,
+        info: &OperationInfo,
+    ) -> std::result::Result<(), Error<E>> {
+        Ok(())
+    }
+
+    /// Execute the request. Note that for almost any reasonable implementation
+    /// this will include code equivalent to this:
+    /// ```
+    /// # use progenitor_client::{ClientHooks, ClientInfo, OperationInfo};
+    /// # struct X;
+    /// # impl ClientInfo<()> for X {
+    /// #   fn api_version() -> &'static str { panic!() }
+    /// #   fn baseurl(&self) -> &str { panic!() }
+    /// #   fn client(&self) -> &reqwest::Client { panic!() }
+    /// #   fn inner(&self) -> &() { panic!() }
+    /// # }
+    /// # impl ClientHooks for X {
+    /// #   async fn exec(
+    /// #       &self,
+    /// #       request: reqwest::Request,
+    /// #       info: &OperationInfo,
+    /// #   ) -> reqwest::Result<reqwest::Response> {
+    ///         self.client().execute(request).await
+    /// #   }
+    /// # }
+    /// ```
+    async fn exec(
+        &self,
+        request: reqwest::Request,
+        info: &OperationInfo,
+    ) -> reqwest::Result<reqwest::Response> {
+        self.client().execute(request).await
+    }
+
+    /// Invoked for each difference between a response and the API
+    /// description, by clients generated with response checking enabled. The
+    /// default implementation ignores violations; tests may override it to
+    /// record them or to panic.
+    fn contract_violation(
+        &self,
+        status: reqwest::StatusCode,
+        violation: &ContractViolation,
+        info: &OperationInfo,
+    ) {
+    }
+}
+
+/// Result produced by [`Middleware`] and by [`Next::run`].
+///
+/// Middleware can't produce a documented error response, so the error type is
+/// [`Error`] with an uninhabited response body; generated methods convert it
+/// with [`Error::into_typed`].
+pub type MiddlewareResult = Result<reqwest::Response, Error<std::convert::Infallible>>;
+
+/// Future returned by [`Middleware::handle`] and by [`Next::run`].
+#[cfg(not(target_arch = "wasm32"))]
+pub type MiddlewareFuture<'a> =
+    std::pin::Pin<Box<dyn std::future::Future<Output = MiddlewareResult> + Send + 'a>>;
+
+/// Future returned by [`Middleware::handle`] and by [`Next::run`].
+#[cfg(target_arch = "wasm32")]
+pub type MiddlewareFuture<'a> =
+    std::pin::Pin<Box<dyn std::future::Future<Output = MiddlewareResult> + 'a>>;
+
+/// A step in the chain of middleware that surrounds the execution of each
+/// request.
+///
+/// Middleware is attached to a generated client with its `with_middleware`
+/// method and runs in the order in which it was attached. Each implementation
+/// may inspect or modify the request, pass it on with [`Next::run`] (zero, one,
+/// or several times), and inspect or replace the result.
+///
+/// ```
+/// # use progenitor_client::{Middleware, MiddlewareFuture, Next, OperationInfo};
+/// struct Logging;
+///
+/// impl Middleware for Logging {
+///     fn handle<'a>(
+///         &'a self,
+///         request: reqwest::Request,
+///         info: &'a OperationInfo,
+///         next: Next<'a>,
+///     ) -> MiddlewareFuture<'a> {
+///         Box::pin(async move {
+///             println!("{} {}", info.operation_id, request.url());
+///             let result = next.run(request).await;
+///             println!("{} done", info.operation_id);
+///             result
+///         })
+///     }
+/// }
+/// ```
+pub trait Middleware: Send + Sync + 'static {
+    /// Handles a request, typically by calling `next.run(request)`.
+    fn handle<'a>(
+        &'a self,
+        request: reqwest::Request,
+        info: &'a OperationInfo,
+        next: Next<'a>,
+    ) -> MiddlewareFuture<'a>;
+}
+
+type Exec<'a> = dyn Fn(reqwest::Request) -> MiddlewareFuture<'a> + Send + Sync + 'a;
+
+/// The remainder of a middleware chain, passed to [`Middleware::handle`].
+#[derive(Clone, Copy)]
+pub struct Next<'a> {
+    middleware: &'a [Arc<dyn Middleware>],
+    exec: &'a Exec<'a>,
+    info: &'a OperationInfo,
+}
+
+impl<'a> Next<'a> {
+    /// Passes the request to the next middleware or, at the end of the chain,
+    /// executes it (with [`ClientHooks::exec`] and [`ClientHooks::post`]).
+    pub fn run(self, request: reqwest::Request) -> MiddlewareFuture<'a> {
+        match self.middleware.split_first() {
+            Some((first, middleware)) => {
+                first.handle(request, self.info, Next { middleware, ..self })
+            }
+            None => (self.exec)(request),
+        }
+    }
+}
+
+/// The ordered middleware attached to a generated client.
+#[derive(Clone, Default)]
+pub struct MiddlewareStack(Vec<Arc<dyn Middleware>>);
+
+impl MiddlewareStack {
+    /// Appends `middleware` to the end of the chain.
+    pub fn push<M: Middleware>(&mut self, middleware: M) {
+        self.0.push(Arc::new(middleware));
+    }
+
+    /// The number of middleware in the chain.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether the chain is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[doc(hidden)]
+    pub async fn run<'a>(
+        &'a self,
+        mut request: reqwest::Request,
+        info: &'a OperationInfo,
+        exec: &'a Exec<'a>,
+    ) -> MiddlewareResult {
+        let telemetry = Telemetry::start(info, &mut request);
+        let attempts = std::sync::atomic::AtomicU32::new(0);
+        let exec = |request| {
+            attempts.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            exec(request)
+        };
+        let next = Next {
+            middleware: &self.0,
+            exec: &exec,
+            info,
+        };
+        let result = telemetry.instrument(next.run(request)).await;
+        telemetry.finish(&result, attempts.into_inner());
+        result
+    }
+}
+
+/// Per-operation instrumentation, enabled by the `tracing` and `metrics`
+/// features.
+struct Telemetry<'a> {
+    info: &'a OperationInfo,
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+    #[cfg(all(feature = "metrics", not(target_arch = "wasm32")))]
+    start: std::time::Instant,
+}
+
+impl<'a> Telemetry<'a> {
+    fn start(info: &'a OperationInfo, request: &mut reqwest::Request) -> Self {
+        #[cfg(feature = "tracing")]
+        let span = {
+            let span = tracing::info_span!(
+                "progenitor_client.operation",
+                operation_id = info.operation_id,
+                http.request.method = %info.metadata.method,
+                http.route = info.metadata.path,
+                http.response.status_code = tracing::field::Empty,
+                retry_count = tracing::field::Empty,
+                error = tracing::field::Empty,
+                otel.kind = "client",
+                trace_id = tracing::field::Empty,
+                span_id = tracing::field::Empty,
+            );
+            #[cfg(not(target_arch = "wasm32"))]
+            propagate_traceparent(request, &span);
+            span
+        };
+        #[cfg(any(not(feature = "tracing"), target_arch = "wasm32"))]
+        let _ = request;
+
+        Self {
+            info,
+            #[cfg(feature = "tracing")]
+            span,
+            #[cfg(all(feature = "metrics", not(target_arch = "wasm32")))]
+            start: std::time::Instant::now(),
+        }
+    }
+
+    async fn instrument<F: std::future::Future>(&self, future: F) -> F::Output {
+        #[cfg(feature = "tracing")]
+        let future = tracing::Instrument::instrument(future, self.span.clone());
+        future.await
+    }
+
+    #[cfg_attr(
+        not(all(feature = "tracing", feature = "metrics")),
+        allow(unused_variables)
+    )]
+    fn finish(&self, result: &MiddlewareResult, attempts: u32) {
+        let retries = attempts.saturating_sub(1);
+        let status = match result {
+            Ok(response) => Some(response.status()),
+            Err(e) => e.status(),
+        };
+        // Responses with undeclared or error statuses count as failures.
+        let error_kind = match (result, status) {
+            (Err(Error::CommunicationError(_)), _) => Some("communication"),
+            (Err(Error::InvalidRequest(_)), _) => Some("invalid_request"),
+            (Err(_), _) => Some("other"),
+            (Ok(_), Some(status)) if !self.info.metadata.is_success(status) => Some("status"),
+            (Ok(_), _) => None,
+        };
+
+        #[cfg(feature = "tracing")]
+        {
+            if let Some(status) = status {
+                self.span
+                    .record("http.response.status_code", status.as_u16());
+            }
+            self.span.record("retry_count", retries);
+            if let Err(e) = result {
+                self.span.record("error", tracing::field::display(e));
+            }
+        }
+
+        #[cfg(all(feature = "metrics", not(target_arch = "wasm32")))]
+        {
+            let operation_id = self.info.operation_id;
+            let method = self.info.metadata.method.to_string();
+            let status = status.map_or_else(String::new, |status| status.as_u16().to_string());
+            metrics::counter!(
+                "progenitor_client_requests_total",
+                "operation_id" => operation_id,
+                "method" => method.clone(),
+                "status" => status,
+            )
+            .increment(1);
+            metrics::histogram!(
+                "progenitor_client_request_duration_seconds",
+                "operation_id" => operation_id,
+                "method" => method,
+            )
+            .record(self.start.elapsed().as_secs_f64());
+            if let Some(kind) = error_kind {
+                metrics::counter!(
+                    "progenitor_client_errors_total",
+                    "operation_id" => operation_id,
+                    "kind" => kind,
+                )
+                .increment(1);
+            }
+            if retries > 0 {
+                metrics::counter!(
+                    "progenitor_client_retries_total",
+                    "operation_id" => operation_id,
+                )
+                .increment(u64::from(retries));
+            }
+        }
+    }
+}
+
+/// Sets the W3C `traceparent` header with `span` as the parent of the
+/// server's work, continuing the trace of an existing `traceparent` header or
+/// else starting a new one. The span is given a random ID, since those of
+/// `tracing` are only unique among the spans open at once in this process;
+/// it and the trace ID are recorded as the span's `span_id` and `trace_id`.
+#[cfg(all(feature = "tracing", not(target_arch = "wasm32")))]
+fn propagate_traceparent(request: &mut reqwest::Request, span: &tracing::Span) {
+    let inherited = request
+        .headers()
+        .get("traceparent")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| match value.split('-').collect::<Vec<_>>()[..] {
+            ["00", trace_id, _, flags]
+                if trace_id.len() == 32
+                    && flags.len() == 2
+                    && trace_id.bytes().all(|b| b.is_ascii_hexdigit()) =>
+            {
+                Some((trace_id.to_string(), flags.to_string()))
+            }
+            _ => None,
+        });
+    let (trace_id, flags) = inherited.unwrap_or_else(|| {
+        let flags = if span.is_disabled() { "00" } else { "01" };
+        // All-zero IDs are invalid.
+        (
+            format!("{:032x}", rand::random::<u128>().max(1)),
+            flags.to_string(),
+        )
+    });
+    let parent_id = format!("{:016x}", rand::random::<u64>().max(1));
+    span.record("trace_id", trace_id.as_str());
+    span.record("span_id", parent_id.as_str());
+    let value = format!("00-{trace_id}-{parent_id}-{flags}");
+    if let Ok(value) = reqwest::header::HeaderValue::try_from(value) {
+        request.headers_mut().insert("traceparent", value);
+    }
+}
+
+impl std::fmt::Debug for MiddlewareStack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MiddlewareStack")
+            .field("len", &self.0.len())
+            .finish()
+    }
+}
+
+/// Middleware that records requests and responses to a file, or replays them
+/// from one, for deterministic tests of generated clients without a live
+/// server.
+///
+/// A recording cassette passes each request on and keeps the request and its
+/// response, writing them to the file with [`Cassette::flush`] or when the
+/// cassette (and every clone of it) is dropped; headers named with
+/// [`Cassette::redact`] are saved as `[REDACTED]`. A replaying cassette
+/// answers each request with the response of the first unused recorded
+/// interaction that matches according to [`CassetteMatch`] and fails with
+/// [`Error::Custom`] if there's none. Attach a cassette after other
+/// middleware so that it sees requests as they would be sent.
+///
+/// ```no_run
+/// # use progenitor_client::Cassette;
+/// let cassette = if std::env::var_os("RECORD").is_some() {
+///     Cassette::record("tests/cassettes/widgets.json")
+/// } else {
+///     Cassette::replay("tests/cassettes/widgets.json").unwrap()
+/// };
+/// // let client = Client::new("http://localhost:8080").with_middleware(cassette.clone());
+/// ```
+///
+/// Response bodies are buffered in both modes. (Not available when targeting
+/// WebAssembly.)
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone)]
+pub struct Cassette {
+    replay: bool,
+    matching: CassetteMatch,
+    redact: Vec<reqwest::header::HeaderName>,
+    tape: Arc<Tape>,
+}
+
+/// The interactions of a [`Cassette`], shared by its clones.
+#[cfg(not(target_arch = "wasm32"))]
+struct Tape {
+    path: std::path::PathBuf,
+    interactions: std::sync::Mutex<Vec<(Interaction, bool)>>,
+    /// Whether interactions have been recorded since the file was written.
+    unsaved: std::sync::atomic::AtomicBool,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Tape {
+    fn interactions(&self) -> std::sync::MutexGuard<'_, Vec<(Interaction, bool)>> {
+        // The interactions remain consistent even if a thread panicked while
+        // holding the lock.
+        self.interactions
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// The contents of the file, if there are unsaved interactions.
+    fn contents(&self) -> std::io::Result<Option<Vec<u8>>> {
+        if !self
+            .unsaved
+            .swap(false, std::sync::atomic::Ordering::SeqCst)
+        {
+            return Ok(None);
+        }
+        let cassettes = Cassettes {
+            interactions: self
+                .interactions()
+                .iter()
+                .map(|(interaction, _)| interaction.clone())
+                .collect(),
+        };
+        serde_json::to_vec_pretty(&cassettes)
+            .map(Some)
+            .map_err(|e| self.failed(e.into()))
+    }
+
+    /// Note that the interactions remain unsaved after `error`.
+    fn failed(&self, error: std::io::Error) -> std::io::Error {
+        self.unsaved
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+        std::io::Error::new(
+            error.kind(),
+            format!("failed to write {}: {error}", self.path.display()),
+        )
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Drop for Tape {
+    fn drop(&mut self) {
+        let result = self.contents().and_then(|contents| match contents {
+            Some(contents) => std::fs::write(&self.path, contents).map_err(|e| self.failed(e)),
+            None => Ok(()),
+        });
+        // Call `Cassette::flush` to handle errors; here they can only be
+        // logged.
+        #[cfg(feature = "tracing")]
+        if let Err(e) = result {
+            tracing::error!(path = %self.path.display(), error = %e, "failed to write cassette");
+        }
+        #[cfg(not(feature = "tracing"))]
+        let _ = result;
+    }
+}
+
+/// The parts of a request that a replaying [`Cassette`] compares with those
+/// of recorded requests. All are compared by default.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CassetteMatch {
+    /// Compare the `operationId`.
+    pub operation_id: bool,
+    /// Compare the HTTP method.
+    pub method: bool,
+    /// Compare the URL path (but not the scheme, host, or port).
+    pub path: bool,
+    /// Compare the query string.
+    pub query: bool,
+    /// Compare the body, as JSON values if both are JSON.
+    pub body: bool,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Default for CassetteMatch {
+    fn default() -> Self {
+        Self {
+            operation_id: true,
+            method: true,
+            path: true,
+            query: true,
+            body: true,
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Cassettes {
+    interactions: Vec<Interaction>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+struct Interaction {
+    operation_id: String,
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+struct RecordedRequest {
+    method: String,
+    path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    query: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    headers: Vec<(String, String)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<RecordedBody>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    headers: Vec<(String, String)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<RecordedBody>,
+}
+
+/// A body exactly as sent or received: as text if it's UTF-8.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum RecordedBody {
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl RecordedBody {
+    fn new(bytes: &[u8]) -> Option<Self> {
+        if bytes.is_empty() {
+            None
+        } else if let Ok(text) = std::str::from_utf8(bytes) {
+            Some(Self::Text(text.to_string()))
+        } else {
+            Some(Self::Bytes(bytes.to_vec()))
+        }
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        match self {
+            Self::Text(text) => text.into_bytes(),
+            Self::Bytes(bytes) => bytes,
+        }
+    }
+
+    /// Whether two bodies are the same, as JSON values if both are JSON.
+    fn same(a: &Option<Self>, b: &Option<Self>) -> bool {
+        match (a, b) {
+            (Some(Self::Text(a)), Some(Self::Text(b))) if a != b => matches!(
+                (
+                    serde_json::from_str::<serde_json::Value>(a),
+                    serde_json::from_str::<serde_json::Value>(b),
+                ),
+                (Ok(a), Ok(b)) if a == b
+            ),
+            _ => a == b,
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Cassette {
+    /// A cassette that records interactions to the file at `path`, replacing
+    /// its contents.
+    pub fn record(path: impl Into<std::path::PathBuf>) -> Self {
+        Self {
+            replay: false,
+            matching: CassetteMatch::default(),
+            redact: [
+                "authorization",
+                "proxy-authorization",
+                "cookie",
+                "set-cookie",
+            ]
+            .into_iter()
+            .map(reqwest::header::HeaderName::from_static)
+            .collect(),
+            tape: Arc::new(Tape {
+                path: path.into(),
+                interactions: Default::default(),
+                unsaved: Default::default(),
+            }),
+        }
+    }
+
+    /// A cassette that replays the interactions recorded in the file at
+    /// `path`.
+    pub fn replay(path: impl Into<std::path::PathBuf>) -> std::io::Result<Self> {
+        let path = path.into();
+        let Cassettes { interactions } = serde_json::from_slice(&std::fs::read(&path)?)?;
+        Ok(Self {
+            replay: true,
+            tape: Arc::new(Tape {
+                path,
+                interactions: std::sync::Mutex::new(
+                    interactions
+                        .into_iter()
+                        .map(|interaction| (interaction, false))
+                        .collect(),
+                ),
+                unsaved: Default::default(),
+            }),
+            matching: CassetteMatch::default(),
+            // Headers are only redacted when recording.
+            redact: Vec::new(),
+        })
+    }
+
+    /// Write the interactions recorded so far to the file, if there are any
+    /// that haven't been written. This also happens when the cassette (and
+    /// every clone of it) is dropped, but errors are then only logged (with
+    /// the `tracing` feature). Requires the `tokio` feature.
+    #[cfg(feature = "tokio")]
+    pub async fn flush(&self) -> std::io::Result<()> {
+        let Some(contents) = self.tape.contents()? else {
+            return Ok(());
+        };
+        tokio::fs::write(&self.tape.path, contents)
+            .await
+            .map_err(|e| self.tape.failed(e))
+    }
+
+    /// Sets the parts of requests compared when replaying.
+    pub fn match_on(mut self, matching: CassetteMatch) -> Self {
+        self.matching = matching;
+        self
+    }
+
+    /// Adds a header whose values aren't recorded. `Authorization`,
+    /// `Proxy-Authorization`, `Cookie`, and `Set-Cookie` are always redacted.
+    pub fn redact(mut self, name: reqwest::header::HeaderName) -> Self {
+        self.redact.push(name);
+        self
+    }
+
+    fn headers(&self, headers: &reqwest::header::HeaderMap) -> Vec<(String, String)> {
+        headers
+            .iter()
+            .map(|(name, value)| {
+                let value = if self.redact.contains(name) {
+                    "[REDACTED]".to_string()
+                } else {
+                    String::from_utf8_lossy(value.as_bytes()).into_owned()
+                };
+                (name.to_string(), value)
+            })
+            .collect()
+    }
+
+    fn matches(
+        &self,
+        recorded: &Interaction,
+        operation_id: &str,
+        request: &RecordedRequest,
+    ) -> bool {
+        let matching = &self.matching;
+        let other = &recorded.request;
+        (!matching.operation_id || recorded.operation_id == operation_id)
+            && (!matching.method || other.method == request.method)
+            && (!matching.path || other.path == request.path)
+            && (!matching.query || other.query == request.query)
+            && (!matching.body || RecordedBody::same(&other.body, &request.body))
+    }
+
+    fn response(
+        &self,
+        request: &reqwest::Request,
+        info: &OperationInfo,
+    ) -> Result<reqwest::Response, Error<std::convert::Infallible>> {
+        let request = self.request(request);
+        let mut interactions = self.tape.interactions();
+        let Some((interaction, used)) = interactions
+            .iter_mut()
+            .find(|(recorded, used)| !*used && self.matches(recorded, info.operation_id, &request))
+        else {
+            return Err(Error::Custom(format!(
+                "no recorded interaction matches {} {} ({})",
+                request.method, request.path, info.operation_id,
+            )));
+        };
+        *used = true;
+
+        let recorded = interaction.response.clone();
+        let mut response = http::Response::builder().status(recorded.status);
+        for (name, value) in &recorded.headers {
+            response = response.header(name, value);
+        }
+        let response = response
+            .body(
+                recorded
+                    .body
+                    .map(RecordedBody::into_bytes)
+                    .unwrap_or_default(),
+            )
+            .map_err(|e| Error::Custom(format!("invalid recorded response: {e}")))?;
+        Ok(response.into())
+    }
+
+    fn request(&self, request: &reqwest::Request) -> RecordedRequest {
+        RecordedRequest {
+            method: request.method().to_string(),
+            path: request.url().path().to_string(),
+            query: request.url().query().map(str::to_string),
+            headers: self.headers(request.headers()),
+            body: request
+                .body()
+                .and_then(reqwest::Body::as_bytes)
+                .and_then(RecordedBody::new),
+        }
+    }
+
+    async fn record_interaction(
+        &self,
+        request: reqwest::Request,
+        info: &OperationInfo,
+        next: Next<'_>,
+    ) -> MiddlewareResult {
+        let recorded = self.request(&request);
+        let response = next.run(request).await?;
+
+        let status = response.status();
+        let version = response.version();
+        let headers = response.headers().clone();
+        let body = response.bytes().await.map_err(Error::ResponseBodyError)?;
+
+        let interaction = Interaction {
+            operation_id: info.operation_id.to_string(),
+            request: recorded,
+            response: RecordedResponse {
+                status: status.as_u16(),
+                headers: self.headers(&headers),
+                body: RecordedBody::new(&body),
+            },
+        };
+        self.tape.interactions().push((interaction, true));
+        self.tape
+            .unsaved
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+
+        let mut response = http::Response::new(body);
+        *response.status_mut() = status;
+        *response.version_mut() = version;
+        *response.headers_mut() = headers;
+        Ok(response.into())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Middleware for Cassette {
+    fn handle<'a>(
+        &'a self,
+        request: reqwest::Request,
+        info: &'a OperationInfo,
+        next: Next<'a>,
+    ) -> MiddlewareFuture<'a> {
+        if self.replay {
+            let response = self.response(&request, info);
+            Box::pin(async move { response })
+        } else {
+            Box::pin(self.record_interaction(request, info, next))
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl std::fmt::Debug for Cassette {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cassette")
+            .field("path", &self.tape.path)
+            .field("replay", &self.replay)
+            .field("matching", &self.matching)
+            .finish()
+    }
+}
+
+/// A response stored by [`HttpCache`].
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CachedResponse {
+    /// The status code.
+    pub status: u16,
+    /// The response headers.
+    pub headers: Vec<(String, String)>,
+    /// The request headers named by the response's `Vary` header, with the
+    /// values they had in the request.
+    pub vary: Vec<(String, Option<String>)>,
+    /// When the response was received or last revalidated.
+    pub stored_at: std::time::SystemTime,
+    /// The response body.
+    #[serde(skip)]
+    pub body: Bytes,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl CachedResponse {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn is_fresh(&self) -> bool {
+        let directives = CacheControl::new(self.header("cache-control"));
+        match directives.max_age {
+            Some(max_age) if !directives.no_cache => self
+                .stored_at
+                .elapsed()
+                .is_ok_and(|age| age < std::time::Duration::from_secs(max_age)),
+            _ => false,
+        }
+    }
+
+    fn varies_from(&self, request: &reqwest::header::HeaderMap) -> bool {
+        self.vary.iter().any(|(name, value)| {
+            request.get(name.as_str()).and_then(|v| v.to_str().ok()) != value.as_deref()
+        })
+    }
+
+    fn to_response(&self) -> Result<reqwest::Response, Error<std::convert::Infallible>> {
+        let mut response = http::Response::builder().status(self.status);
+        for (name, value) in &self.headers {
+            response = response.header(name, value);
+        }
+        let response = response
+            .body(self.body.clone())
+            .map_err(|e| Error::Custom(format!("invalid cached response: {e}")))?;
+        Ok(response.into())
+    }
+}
+
+/// The `Cache-Control` directives that [`HttpCache`] honors.
+#[cfg(not(target_arch = "wasm32"))]
+struct CacheControl {
+    no_store: bool,
+    no_cache: bool,
+    max_age: Option<u64>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl CacheControl {
+    fn new(value: Option<&str>) -> Self {
+        let mut directives = Self {
+            no_store: false,
+            no_cache: false,
+            max_age: None,
+        };
+        for directive in value.unwrap_or_default().split(',') {
+            let directive = directive.trim().to_ascii_lowercase();
+            match directive.split_once('=') {
+                Some(("max-age", seconds)) => directives.max_age = seconds.trim().parse().ok(),
+                None if directive == "no-store" => directives.no_store = true,
+                None if directive == "no-cache" => directives.no_cache = true,
+                _ => {}
+            }
+        }
+        directives
+    }
+}
+
+/// Storage for the responses cached by [`HttpCache`], keyed by URL.
+///
+/// [`MemoryCache`] and [`DiskCache`] are provided; other implementations may
+/// share responses between processes. Storage is a best-effort optimization,
+/// so implementations ignore their own failures. Implementations that do I/O
+/// should do it asynchronously (or with `tokio::task::spawn_blocking`) so as
+/// not to block the requests being cached.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait CacheStore: Send + Sync + 'static {
+    /// Gets the response stored for `key`.
+    fn get<'a>(&'a self, key: &'a str) -> CacheFuture<'a, Option<CachedResponse>>;
+    /// Stores `response` for `key`, replacing any previous response.
+    fn put<'a>(&'a self, key: &'a str, response: CachedResponse) -> CacheFuture<'a, ()>;
+    /// Removes the response stored for `key`.
+    fn remove<'a>(&'a self, key: &'a str) -> CacheFuture<'a, ()>;
+}
+
+/// Future returned by the methods of [`CacheStore`].
+#[cfg(not(target_arch = "wasm32"))]
+pub type CacheFuture<'a, T> = std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send + 'a>>;
+
+/// A [`CacheStore`] that keeps responses in memory.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Default)]
+pub struct MemoryCache {
+    entries: std::sync::Mutex<std::collections::HashMap<String, CachedResponse>>,
+    capacity: Option<usize>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl MemoryCache {
+    /// An unbounded cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A cache of at most `capacity` responses, evicting the least recently
+    /// stored.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: Default::default(),
+            capacity: Some(capacity),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl MemoryCache {
+    fn entries(
+        &self,
+    ) -> std::sync::MutexGuard<'_, std::collections::HashMap<String, CachedResponse>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn insert(&self, key: &str, response: CachedResponse) {
+        let mut entries = self.entries();
+        if let Some(capacity) = self.capacity {
+            while entries.len() >= capacity && !entries.contains_key(key) {
+                let Some(oldest) = entries
+                    .iter()
+                    .min_by_key(|(_, response)| response.stored_at)
+                    .map(|(key, _)| key.clone())
+                else {
+                    return;
+                };
+                entries.remove(&oldest);
+            }
+        }
+        entries.insert(key.to_string(), response);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl CacheStore for MemoryCache {
+    fn get<'a>(&'a self, key: &'a str) -> CacheFuture<'a, Option<CachedResponse>> {
+        Box::pin(std::future::ready(self.entries().get(key).cloned()))
+    }
+
+    fn put<'a>(&'a self, key: &'a str, response: CachedResponse) -> CacheFuture<'a, ()> {
+        self.insert(key, response);
+        Box::pin(std::future::ready(()))
+    }
+
+    fn remove<'a>(&'a self, key: &'a str) -> CacheFuture<'a, ()> {
+        self.entries().remove(key);
+        Box::pin(std::future::ready(()))
+    }
+}
+
+/// A [`CacheStore`] that keeps each response in a file in a directory.
+///
+/// Requires the `tokio` feature.
+#[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+#[derive(Debug)]
+pub struct DiskCache {
+    dir: std::path::PathBuf,
+}
+
+#[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct DiskCacheEntry {
+    key: String,
+    response: CachedResponse,
+}
+
+#[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+impl DiskCache {
+    /// A cache in the directory `dir`, which is created if necessary.
+    pub fn new(dir: impl Into<std::path::PathBuf>) -> std::io::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    fn path(&self, key: &str) -> std::path::PathBuf {
+        // FNV-1a, for file names that are stable across builds.
+        let hash = key.bytes().fold(0xcbf29ce484222325_u64, |hash, b| {
+            (hash ^ u64::from(b)).wrapping_mul(0x100000001b3)
+        });
+        self.dir.join(format!("{hash:016x}"))
+    }
+}
+
+#[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+impl CacheStore for DiskCache {
+    fn get<'a>(&'a self, key: &'a str) -> CacheFuture<'a, Option<CachedResponse>> {
+        Box::pin(async move {
+            // Each file holds a line of JSON followed by the body.
+            let contents = tokio::fs::read(self.path(key)).await.ok()?;
+            let split = contents.iter().position(|b| *b == b'\n')?;
+            let entry = serde_json::from_slice::<DiskCacheEntry>(&contents[..split]).ok()?;
+            (entry.key == key).then(|| CachedResponse {
+                body: Bytes::copy_from_slice(&contents[split + 1..]),
+                ..entry.response
+            })
+        })
+    }
+
+    fn put<'a>(&'a self, key: &'a str, response: CachedResponse) -> CacheFuture<'a, ()> {
+        Box::pin(async move {
+            let body = response.body.clone();
+            let entry = DiskCacheEntry {
+                key: key.to_string(),
+                response,
+            };
+            let Ok(mut contents) = serde_json::to_vec(&entry) else {
+                return;
+            };
+            contents.push(b'\n');
+            contents.extend_from_slice(&body);
+            let _ = tokio::fs::write(self.path(key), contents).await;
+        })
+    }
+
+    fn remove<'a>(&'a self, key: &'a str) -> CacheFuture<'a, ()> {
+        Box::pin(async move {
+            let _ = tokio::fs::remove_file(self.path(key)).await;
+        })
+    }
+}
+
+/// Middleware that caches responses to `GET` requests, honoring the
+/// `Cache-Control` (`no-store`, `no-cache`, and `max-age`), `ETag`,
+/// `Last-Modified`, and `Vary` headers of responses.
+///
+/// A fresh cached response is returned without a request. Otherwise, a
+/// request for a cached response is sent with `If-None-Match` and
+/// `If-Modified-Since` headers, and a `304 Not Modified` response is replaced
+/// with the cached response, so generated methods return it as usual.
+/// Successful requests with other methods remove the response cached for the
+/// same URL.
+///
+/// Responses are keyed by URL, so requests with an `Authorization` header
+/// bypass the cache rather than share responses between credentials; as do
+/// requests with conditional or `Range` headers. Responses without a
+/// `Content-Length`, or larger than the maximum entry size (see
+/// [`HttpCache::with_max_entry_size`]), are not cached.
+///
+/// ```
+/// # use progenitor_client::{HttpCache, MemoryCache};
+/// let cache = HttpCache::new(MemoryCache::with_capacity(1000));
+/// // let client = Client::new("https://example.com").with_middleware(cache);
+/// ```
+///
+/// (Not available when targeting WebAssembly.)
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug)]
+pub struct HttpCache<S> {
+    store: S,
+    max_entry_size: u64,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+const HTTP_CACHE_DEFAULT_MAX_ENTRY_SIZE: u64 = 1024 * 1024;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<S: CacheStore> HttpCache<S> {
+    /// A cache that keeps responses in `store`.
+    pub fn new(store: S) -> Self {
+        Self {
+            store,
+            max_entry_size: HTTP_CACHE_DEFAULT_MAX_ENTRY_SIZE,
+        }
+    }
+
+    /// Limit the size, in bytes, of the response bodies that are cached. The
+    /// default is 1 MiB.
+    pub fn with_max_entry_size(mut self, limit: u64) -> Self {
+        self.max_entry_size = limit;
+        self
+    }
+
+    /// The underlying storage.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    async fn handle_get(&self, mut request: reqwest::Request, next: Next<'_>) -> MiddlewareResult {
+        use reqwest::header::{IF_MODIFIED_SINCE, IF_NONE_MATCH};
+
+        let key = request.url().to_string();
+        let bypass = [
+            IF_NONE_MATCH,
+            IF_MODIFIED_SINCE,
+            reqwest::header::IF_MATCH,
+            reqwest::header::IF_UNMODIFIED_SINCE,
+            reqwest::header::IF_RANGE,
+            reqwest::header::RANGE,
+            reqwest::header::AUTHORIZATION,
+        ]
+        .iter()
+        .any(|name| request.headers().contains_key(name));
+        let request_directives = CacheControl::new(
+            request
+                .headers()
+                .get(reqwest::header::CACHE_CONTROL)
+                .and_then(|value| value.to_str().ok()),
+        );
+        if bypass || request_directives.no_store {
+            return next.run(request).await;
+        }
+
+        let cached = self
+            .store
+            .get(&key)
+            .await
+            .filter(|cached| !cached.varies_from(request.headers()));
+        if let Some(cached) = &cached {
+            if cached.is_fresh() && !request_directives.no_cache {
+                return cached.to_response();
+            }
+            let validators = [
+                (IF_NONE_MATCH, cached.header("etag")),
+                (IF_MODIFIED_SINCE, cached.header("last-modified")),
+            ];
+            for (name, value) in validators {
+                if let Some(value) = value.and_then(|v| v.try_into().ok()) {
+                    request.headers_mut().insert(name, value);
+                }
+            }
+        }
+
+        let request_headers = request.headers().clone();
+        let response = next.run(request).await?;
+
+        match cached {
+            Some(mut cached) if response.status() == reqwest::StatusCode::NOT_MODIFIED => {
+                for (name, value) in response.headers() {
+                    let value = String::from_utf8_lossy(value.as_bytes()).into_owned();
+                    match cached
+                        .headers
+                        .iter_mut()
+                        .find(|(header, _)| header.eq_ignore_ascii_case(name.as_str()))
+                    {
+                        Some((_, existing)) => *existing = value,
+                        None => cached.headers.push((name.to_string(), value)),
+                    }
+                }
+                cached.stored_at = std::time::SystemTime::now();
+                self.store.put(&key, cached.clone()).await;
+                cached.to_response()
+            }
+            _ => self.store_response(&key, &request_headers, response).await,
+        }
+    }
+
+    async fn store_response(
+        &self,
+        key: &str,
+        request_headers: &reqwest::header::HeaderMap,
+        response: reqwest::Response,
+    ) -> MiddlewareResult {
+        let headers = response.headers();
+        let directives = CacheControl::new(
+            headers
+                .get(reqwest::header::CACHE_CONTROL)
+                .and_then(|value| value.to_str().ok()),
+        );
+        let vary = headers
+            .get_all(reqwest::header::VARY)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(|name| name.trim().to_ascii_lowercase())
+            .filter(|name| !name.is_empty())
+            .collect::<Vec<_>>();
+        let cacheable = response.status() == reqwest::StatusCode::OK
+            && response
+                .content_length()
+                .is_some_and(|length| length <= self.max_entry_size)
+            && !directives.no_store
+            && !vary.iter().any(|name| name == "*")
+            && (directives.max_age.is_some()
+                || headers.contains_key(reqwest::header::ETAG)
+                || headers.contains_key(reqwest::header::LAST_MODIFIED));
+        if !cacheable {
+            return Ok(response);
+        }
+
+        let status = response.status();
+        let version = response.version();
+        let headers = response.headers().clone();
+        let body = response.bytes().await.map_err(Error::ResponseBodyError)?;
+        self.store
+            .put(
+                key,
+                CachedResponse {
+                    status: status.as_u16(),
+                    headers: headers
+                        .iter()
+                        .map(|(name, value)| {
+                            (
+                                name.to_string(),
+                                String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                            )
+                        })
+                        .collect(),
+                    vary: vary
+                        .into_iter()
+                        .map(|name| {
+                            let value = request_headers
+                                .get(name.as_str())
+                                .and_then(|value| value.to_str().ok())
+                                .map(str::to_string);
+                            (name, value)
+                        })
+                        .collect(),
+                    stored_at: std::time::SystemTime::now(),
+                    body: body.clone(),
+                },
+            )
+            .await;
+
+        let mut response = http::Response::new(body);
+        *response.status_mut() = status;
+        *response.version_mut() = version;
+        *response.headers_mut() = headers;
+        Ok(response.into())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<S: CacheStore> Middleware for HttpCache<S> {
+    fn handle<'a>(
+        &'a self,
+        request: reqwest::Request,
+        _info: &'a OperationInfo,
+        next: Next<'a>,
+    ) -> MiddlewareFuture<'a> {
+        if request.method() == reqwest::Method::GET {
+            return Box::pin(self.handle_get(request, next));
+        }
+        Box::pin(async move {
+            let key = request.url().to_string();
+            let response = next.run(request).await?;
+            if response.status().is_success() {
+                self.store.remove(&key).await;
+            }
+            Ok(response)
+        })
+    }
+}
+
+/// Decodes the parts of a request for a fake backend generated with
+/// `Generator::fake`, which answers requests in-process rather than sending
+/// them.
+#[doc(hidden)]
+pub struct FakeRequest<'a> {
+    request: &'a reqwest::Request,
+    info: &'a OperationInfo,
+}
+
+impl<'a> FakeRequest<'a> {
+    /// Wraps `request` for the operation described by `info`.
+    pub fn new(request: &'a reqwest::Request, info: &'a OperationInfo) -> Self {
+        Self { request, info }
+    }
+
+    /// Decodes the path parameters by matching the end of the URL path with
+    /// the operation's path template.
+    pub fn path<T: DeserializeOwned>(&self) -> Result<T, Error<std::convert::Infallible>> {
+        let template = self.info.metadata.path;
+        let mismatch = || {
+            Error::InvalidRequest(format!(
+                "path {} doesn't match {}",
+                self.request.url().path(),
+                template,
+            ))
+        };
+
+        // The base URL may have a path of its own, so align the segments of
+        // the template with the last segments of the URL path.
+        let template_segments = template.split('/').skip(1).collect::<Vec<_>>();
+        let segments = self.request.url().path().split('/').collect::<Vec<_>>();
+        let skip = segments
+            .len()
+            .checked_sub(template_segments.len())
+            .ok_or_else(mismatch)?;
+
+        let mut params = Vec::new();
+        for (template, segment) in template_segments.iter().zip(&segments[skip..]) {
+            fake_match_segment(template, segment, &mut params).ok_or_else(mismatch)?;
+        }
+        let params = params
+            .into_iter()
+            .map(|(name, value)| {
+                percent_encoding::percent_decode_str(value)
+                    .decode_utf8()
+                    .map(|value| (name, value))
+                    .map_err(|e| Error::InvalidRequest(format!("path parameter {name}: {e}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let encoded = serde_urlencoded::to_string(params).unwrap();
+        serde_urlencoded::from_str(&encoded)
+            .map_err(|e| Error::InvalidRequest(format!("path parameters: {e}")))
+    }
+
+    /// Decodes the query parameters.
+    pub fn query<T: DeserializeOwned>(&self) -> Result<T, Error<std::convert::Infallible>> {
+        serde_urlencoded::from_str(self.request.url().query().unwrap_or_default())
+            .map_err(|e| Error::InvalidRequest(format!("query parameters: {e}")))
+    }
+
+    /// The request headers.
+    pub fn headers(&self) -> reqwest::header::HeaderMap {
+        self.request.headers().clone()
+    }
+
+    /// The body, which must not be streaming.
+    pub fn bytes(&self) -> Result<Vec<u8>, Error<std::convert::Infallible>> {
+        match self.request.body() {
+            None => Ok(Vec::new()),
+            Some(body) => body.as_bytes().map(<[u8]>::to_vec).ok_or_else(|| {
+                Error::InvalidRequest("a streaming body can't be faked".to_string())
+            }),
+        }
+    }
+
+    /// Decodes a JSON body.
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, Error<std::convert::Infallible>> {
+        serde_json::from_slice(&self.bytes()?)
+            .map_err(|e| Error::InvalidRequest(format!("body: {e}")))
+    }
+
+    /// Decodes a form-urlencoded body.
+    pub fn form<T: DeserializeOwned>(&self) -> Result<T, Error<std::convert::Infallible>> {
+        serde_urlencoded::from_bytes(&self.bytes()?)
+            .map_err(|e| Error::InvalidRequest(format!("body: {e}")))
+    }
+}
+
+/// Matches one segment of a URL path with that of a path template, adding
+/// the (still encoded) values of its parameters to `params`.
+fn fake_match_segment<'t, 's>(
+    template: &'t str,
+    mut segment: &'s str,
+    params: &mut Vec<(&'t str, &'s str)>,
+) -> Option<()> {
+    let mut rest = template;
+    while !rest.is_empty() {
+        match rest.strip_prefix('{') {
+            Some(param) => {
+                let (name, after) = param.split_once('}')?;
+                // A parameter extends to the constant that follows it.
+                let constant = after.split('{').next().unwrap();
+                let end = if constant.is_empty() {
+                    segment.len()
+                } else if constant.len() == after.len() {
+                    segment.len().checked_sub(constant.len())?
+                } else {
+                    segment.find(constant)?
+                };
+                params.push((name, &segment[..end]));
+                segment = &segment[end..];
+                rest = after;
+            }
+            None => {
+                let constant = rest.split('{').next().unwrap();
+                segment = segment.strip_prefix(constant)?;
+                rest = &rest[constant.len()..];
+            }
+        }
+    }
+    segment.is_empty().then_some(())
+}
+
+/// A response with a JSON body, from a fake backend.
+#[doc(hidden)]
+pub fn fake_json_response<T: Serialize>(
+    status: reqwest::StatusCode,
+    value: &T,
+) -> MiddlewareResult {
+    let body = serde_json::to_vec(value).map_err(|e| Error::Custom(e.to_string()))?;
+    let mut response = http::Response::new(body);
+    *response.status_mut() = status;
+    response.headers_mut().insert(
+        reqwest::header::CONTENT_TYPE,
+        reqwest::header::HeaderValue::from_static("application/json"),
+    );
+    Ok(response.into())
+}
+
+/// A response without a body, from a fake backend.
+#[doc(hidden)]
+pub fn fake_empty_response(status: reqwest::StatusCode) -> MiddlewareResult {
+    let mut response = http::Response::new(Vec::new());
+    *response.status_mut() = status;
+    Ok(response.into())
+}
+
+/// Renders `request` as text: the method and URL, the headers, and then the
+/// body after a blank line. Bodies that aren't valid UTF-8 are rendered
+/// lossily, and streaming bodies (which can't be read without consuming
+/// them) are elided.
+///
+/// Generated clients prepare requests without sending them with
+/// `into_request()` on builders (or `<operation>_request()` on positional
+/// clients).
+pub fn format_request(request: &reqwest::Request) -> String {
+    let mut text = format!("{} {}\n", request.method(), request.url());
+    for (name, value) in request.headers() {
+        text.push_str(&format!(
+            "{}: {}\n",
+            name,
+            String::from_utf8_lossy(value.as_bytes())
+        ));
+    }
+    match request.body().map(reqwest::Body::as_bytes) {
+        Some(Some(bytes)) if !bytes.is_empty() => {
+            text.push('\n');
+            text.push_str(&String::from_utf8_lossy(bytes));
+            text.push('\n');
+        }
+        Some(None) => text.push_str("\n<streaming body>\n"),
+        _ => {}
+    }
+    text
+}
+
+/// Renders `request` as an equivalent `curl` command line, with arguments
+/// quoted for POSIX shells. As with [`format_request`], bodies that aren't
+/// valid UTF-8 are rendered lossily; a streaming body is read from standard
+/// input.
+pub fn curl_command(request: &reqwest::Request) -> String {
+    fn quote(arg: &str) -> String {
+        format!("'{}'", arg.replace('\'', r"'\''"))
     }
 
-    /// Execute the request. Note that for almost any reasonable implementation
-    /// this will include code equivalent to this:
-    /// ```
-    /// # use progenitor_client::{ClientHooks, ClientInfo, OperationInfo};
-    /// # struct X;
-    /// # impl ClientInfo<()> for X {
-    /// #   fn api_version() -> &'static str { panic!() }
-    /// #   fn baseurl(&self) -> &str { panic!() }
-    /// #   fn client(&self) -> &reqwest::Client { panic!() }
-    /// #   fn inner(&self) -> &() { panic!() }
-    /// # }
-    /// # impl ClientHooks for X {
-    /// #   async fn exec(
-    /// #       &self,
-    /// #       request: reqwest::Request,
-    /// #       info: &OperationInfo,
-    /// #   ) -> reqwest::Result<reqwest::Response> {
-    ///         self.client().execute(request).await
-    /// #   }
-    /// # }
-    /// ```
-    async fn exec(
-        &self,
-        request: reqwest::Request,
-        info: &OperationInfo,
-    ) -> reqwest::Result<reqwest::Response> {
-        self.client().execute(request).await
+    let mut args = vec!["curl".to_string()];
+    if request.method() != reqwest::Method::GET {
+        args.push("-X".to_string());
+        args.push(request.method().to_string());
+    }
+    args.push(quote(request.url().as_str()));
+    for (name, value) in request.headers() {
+        args.push("-H".to_string());
+        args.push(quote(&format!(
+            "{}: {}",
+            name,
+            String::from_utf8_lossy(value.as_bytes())
+        )));
     }
+    match request.body().map(reqwest::Body::as_bytes) {
+        Some(Some(bytes)) if !bytes.is_empty() => {
+            args.push("--data-binary".to_string());
+            args.push(quote(&String::from_utf8_lossy(bytes)));
+        }
+        Some(None) => {
+            args.push("--data-binary".to_string());
+            args.push("@-".to_string());
+        }
+        _ => {}
+    }
+    args.join(" ")
 }
 
+/// Type parameter of a typestate builder for a required parameter that has
+/// not yet been provided.
+#[derive(Debug, Clone, Copy)]
+pub struct Missing;
+
+/// Type parameter of a typestate builder for a required parameter that has
+/// been provided.
+#[derive(Debug, Clone, Copy)]
+pub struct Provided;
+
 /// Typed value returned by generated client methods.
 ///
 /// This is used for successful responses and may appear in error responses
@@ -177,6 +3139,31 @@ impl<T: DeserializeOwned> ResponseValue<T> {
             headers,
         })
     }
+
+    /// Like `from_response`, but first checks the body against `contract`,
+    /// passing any violations to `report`. Bodies that aren't valid JSON are
+    /// reported only through the decoding error.
+    #[doc(hidden)]
+    pub async fn from_response_checked<E>(
+        response: reqwest::Response,
+        contract: &ContractSchema,
+        mut report: impl FnMut(reqwest::StatusCode, &ContractViolation),
+    ) -> Result<Self, Error<E>> {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let full = response.bytes().await.map_err(Error::ResponseBodyError)?;
+        if let Ok(value) = serde_json::from_slice::<serde_json::Value>(&full) {
+            contract.check("body", &value, &mut |violation| report(status, &violation));
+        }
+        let inner =
+            serde_json::from_slice(&full).map_err(|e| Error::InvalidResponsePayload(full, e))?;
+
+        Ok(Self {
+            inner,
+            status,
+            headers,
+        })
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -204,10 +3191,60 @@ impl ResponseValue<reqwest::Upgraded> {
 impl ResponseValue<ByteStream> {
     #[doc(hidden)]
     pub fn stream(response: reqwest::Response) -> Self {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let length = response.content_length();
+        Self {
+            inner: ByteStream(Box::pin(response.bytes_stream()), length),
+            status,
+            headers,
+        }
+    }
+}
+
+impl<T> ResponseValue<EventStream<T>> {
+    #[doc(hidden)]
+    pub fn event_stream(
+        response: reqwest::Response,
+        client: &reqwest::Client,
+        request: Option<reqwest::Request>,
+    ) -> Self {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let mut inner = EventStream::new(Box::pin(response.bytes_stream()));
+        inner.reconnect = Some(EventStreamReconnect {
+            client: client.clone(),
+            request,
+            sleep: None,
+        });
+        Self {
+            inner,
+            status,
+            headers,
+        }
+    }
+}
+
+impl<T> ResponseValue<JsonLinesStream<T>> {
+    #[doc(hidden)]
+    pub fn json_lines(response: reqwest::Response) -> Self {
+        let status = response.status();
+        let headers = response.headers().clone();
+        Self {
+            inner: JsonLinesStream::new(Box::pin(response.bytes_stream())),
+            status,
+            headers,
+        }
+    }
+}
+
+impl<T> ResponseValue<JsonArrayStream<T>> {
+    #[doc(hidden)]
+    pub fn json_array(response: reqwest::Response, field: Option<&'static str>) -> Self {
         let status = response.status();
         let headers = response.headers().clone();
         Self {
-            inner: ByteStream(Box::pin(response.bytes_stream())),
+            inner: JsonArrayStream::new(Box::pin(response.bytes_stream()), field),
             status,
             headers,
         }
@@ -256,6 +3293,12 @@ impl<T> ResponseValue<T> {
         &self.headers
     }
 
+    /// Gets the value of the ETag header, if present, for use with
+    /// [`RequestOptions::if_match`].
+    pub fn etag(&self) -> Option<&str> {
+        self.headers.get(reqwest::header::ETAG)?.to_str().ok()
+    }
+
     /// Gets the parsed value of the Content-Length header, if present and
     /// valid.
     pub fn content_length(&self) -> Option<u64> {
@@ -343,11 +3386,16 @@ pub enum Error<E = ()> {
     /// An expected response code whose deserialization failed.
     InvalidResponsePayload(Bytes, serde_json::Error),
 
+    /// A response body, or a line of a JSON Lines response body, exceeded
+    /// the configured maximum size (in bytes).
+    ResponseBodyTooLarge(u64),
+
     /// A response not listed in the API description. This may represent a
     /// success or failure response; check `status().is_success()`.
     UnexpectedResponse(reqwest::Response),
 
-    /// A custom error from a consumer-defined hook.
+    /// A custom error from a consumer-defined hook or a failed
+    /// [`RangedDownload`].
     Custom(String),
 }
 
@@ -362,6 +3410,7 @@ impl<E> Error<E> {
             Error::InvalidUpgrade(e) => e.status(),
             Error::ResponseBodyError(e) => e.status(),
             Error::InvalidResponsePayload(_, _) => None,
+            Error::ResponseBodyTooLarge(_) => None,
             Error::UnexpectedResponse(r) => Some(r.status()),
         }
     }
@@ -387,6 +3436,52 @@ impl<E> Error<E> {
             Error::InvalidUpgrade(e) => Error::InvalidUpgrade(e),
             Error::ResponseBodyError(e) => Error::ResponseBodyError(e),
             Error::InvalidResponsePayload(b, e) => Error::InvalidResponsePayload(b, e),
+            Error::ResponseBodyTooLarge(limit) => Error::ResponseBodyTooLarge(limit),
+            Error::UnexpectedResponse(r) => Error::UnexpectedResponse(r),
+        }
+    }
+
+    /// Converts the body of an error response with `f`, leaving other
+    /// errors unchanged.
+    pub fn map<T, F>(self, f: F) -> Error<T>
+    where
+        F: FnOnce(E) -> T,
+    {
+        match self {
+            Error::InvalidRequest(s) => Error::InvalidRequest(s),
+            Error::Custom(s) => Error::Custom(s),
+            Error::CommunicationError(e) => Error::CommunicationError(e),
+            Error::ErrorResponse(ResponseValue {
+                inner,
+                status,
+                headers,
+            }) => Error::ErrorResponse(ResponseValue {
+                inner: f(inner),
+                status,
+                headers,
+            }),
+            Error::InvalidUpgrade(e) => Error::InvalidUpgrade(e),
+            Error::ResponseBodyError(e) => Error::ResponseBodyError(e),
+            Error::InvalidResponsePayload(b, e) => Error::InvalidResponsePayload(b, e),
+            Error::ResponseBodyTooLarge(limit) => Error::ResponseBodyTooLarge(limit),
+            Error::UnexpectedResponse(r) => Error::UnexpectedResponse(r),
+        }
+    }
+}
+
+impl Error<std::convert::Infallible> {
+    /// Converts an error that can't hold a response body, such as one produced
+    /// by [`Middleware`], into an error of any response type.
+    pub fn into_typed<E>(self) -> Error<E> {
+        match self {
+            Error::InvalidRequest(s) => Error::InvalidRequest(s),
+            Error::Custom(s) => Error::Custom(s),
+            Error::CommunicationError(e) => Error::CommunicationError(e),
+            Error::ErrorResponse(rv) => match rv.inner {},
+            Error::InvalidUpgrade(e) => Error::InvalidUpgrade(e),
+            Error::ResponseBodyError(e) => Error::ResponseBodyError(e),
+            Error::InvalidResponsePayload(b, e) => Error::InvalidResponsePayload(b, e),
+            Error::ResponseBodyTooLarge(limit) => Error::ResponseBodyTooLarge(limit),
             Error::UnexpectedResponse(r) => Error::UnexpectedResponse(r),
         }
     }
@@ -435,6 +3530,9 @@ where
             Error::InvalidResponsePayload(b, e) => {
                 write!(f, "Invalid Response Payload ({:?}): {}", b, e)?;
             }
+            Error::ResponseBodyTooLarge(limit) => {
+                write!(f, "Response Body Too Large: exceeded {} bytes", limit)?;
+            }
             Error::UnexpectedResponse(r) => {
                 write!(f, "Unexpected Response: {:?}", r)?;
             }
@@ -473,6 +3571,36 @@ where
     }
 }
 
+impl<T> ErrorFormat for ResponseValue<EventStream<T>> {
+    fn fmt_info(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "status: {}; headers: {:?}; value: <event stream>",
+            self.status, self.headers,
+        )
+    }
+}
+
+impl<T> ErrorFormat for ResponseValue<JsonLinesStream<T>> {
+    fn fmt_info(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "status: {}; headers: {:?}; value: <json lines stream>",
+            self.status, self.headers,
+        )
+    }
+}
+
+impl<T> ErrorFormat for ResponseValue<JsonArrayStream<T>> {
+    fn fmt_info(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "status: {}; headers: {:?}; value: <json array stream>",
+            self.status, self.headers,
+        )
+    }
+}
+
 impl ErrorFormat for ResponseValue<ByteStream> {
     fn fmt_info(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -526,6 +3654,489 @@ pub fn encode_path(pc: &str) -> String {
     percent_encoding::utf8_percent_encode(pc, PATH_SET).to_string()
 }
 
+/// Checks whether a value matches a pattern.
+#[doc(hidden)]
+pub type PatternFn = fn(&str) -> bool;
+
+/// Schema constraints on a request value, checked by generated clients (when
+/// request validation is enabled) before a request is sent.
+#[doc(hidden)]
+pub struct Constraints {
+    /// Minimum value and whether it's exclusive.
+    pub minimum: Option<(f64, bool)>,
+    /// Maximum value and whether it's exclusive.
+    pub maximum: Option<(f64, bool)>,
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+    /// The pattern and a function, compiled from it by the generated code,
+    /// that checks whether a value matches it.
+    pub pattern: Option<(&'static str, PatternFn)>,
+    pub min_items: Option<usize>,
+    pub max_items: Option<usize>,
+    pub items: Option<&'static Constraints>,
+    pub properties: &'static [(&'static str, Constraints)],
+}
+
+impl Constraints {
+    pub const NONE: Self = Self {
+        minimum: None,
+        maximum: None,
+        min_length: None,
+        max_length: None,
+        pattern: None,
+        min_items: None,
+        max_items: None,
+        items: None,
+        properties: &[],
+    };
+}
+
+/// A schema constraint on a request value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Constraint {
+    /// `minimum`, or `exclusiveMinimum` if `exclusive`.
+    Minimum {
+        /// The bound.
+        value: f64,
+        /// Whether the bound is excluded.
+        exclusive: bool,
+    },
+    /// `maximum`, or `exclusiveMaximum` if `exclusive`.
+    Maximum {
+        /// The bound.
+        value: f64,
+        /// Whether the bound is excluded.
+        exclusive: bool,
+    },
+    /// `minLength`, in characters.
+    MinLength(usize),
+    /// `maxLength`, in characters.
+    MaxLength(usize),
+    /// `pattern`.
+    Pattern(&'static str),
+    /// `minItems`.
+    MinItems(usize),
+    /// `maxItems`.
+    MaxItems(usize),
+}
+
+impl std::fmt::Display for Constraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Constraint::Minimum {
+                value,
+                exclusive: false,
+            } => write!(f, "minimum = {}", value),
+            Constraint::Minimum {
+                value,
+                exclusive: true,
+            } => write!(f, "exclusiveMinimum = {}", value),
+            Constraint::Maximum {
+                value,
+                exclusive: false,
+            } => write!(f, "maximum = {}", value),
+            Constraint::Maximum {
+                value,
+                exclusive: true,
+            } => write!(f, "exclusiveMaximum = {}", value),
+            Constraint::MinLength(min) => write!(f, "minLength = {}", min),
+            Constraint::MaxLength(max) => write!(f, "maxLength = {}", max),
+            Constraint::Pattern(pattern) => write!(f, "pattern = {:?}", pattern),
+            Constraint::MinItems(min) => write!(f, "minItems = {}", min),
+            Constraint::MaxItems(max) => write!(f, "maxItems = {}", max),
+        }
+    }
+}
+
+/// A request value that violates a constraint in its schema, found by
+/// generated clients (when request validation is enabled) before a request
+/// is sent. It's reported as [`Error::InvalidRequest`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestValidationError {
+    /// The path to the value, starting with the name of the parameter, such
+    /// as `body.names[1]`.
+    pub field: String,
+    /// The constraint violated.
+    pub constraint: Constraint,
+}
+
+impl std::fmt::Display for RequestValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}` violates `{}`", self.field, self.constraint)
+    }
+}
+
+impl std::error::Error for RequestValidationError {}
+
+impl<E> From<RequestValidationError> for Error<E> {
+    fn from(e: RequestValidationError) -> Self {
+        Self::InvalidRequest(e.to_string())
+    }
+}
+
+/// Check `value` (as it will be serialized) against `constraints`, returning
+/// the field and constraint violated.
+#[doc(hidden)]
+pub fn validate_request<T: Serialize + ?Sized>(
+    field: &str,
+    value: &T,
+    constraints: &Constraints,
+) -> Result<(), RequestValidationError> {
+    match serde_json::to_value(value) {
+        Ok(value) => validate_value(field, &value, constraints),
+        // Serialization errors are reported when the request is built.
+        Err(_) => Ok(()),
+    }
+}
+
+fn validate_value(
+    field: &str,
+    value: &serde_json::Value,
+    constraints: &Constraints,
+) -> Result<(), RequestValidationError> {
+    let violation = |constraint| {
+        Err(RequestValidationError {
+            field: field.to_string(),
+            constraint,
+        })
+    };
+
+    match value {
+        serde_json::Value::Number(number) => {
+            let n = number.as_f64().unwrap_or_default();
+            if let Some((value, exclusive)) = constraints.minimum {
+                if n < value || (exclusive && n <= value) {
+                    return violation(Constraint::Minimum { value, exclusive });
+                }
+            }
+            if let Some((value, exclusive)) = constraints.maximum {
+                if n > value || (exclusive && n >= value) {
+                    return violation(Constraint::Maximum { value, exclusive });
+                }
+            }
+        }
+        serde_json::Value::String(s) => {
+            // Lengths are measured in characters rather than bytes.
+            let len = s.chars().count();
+            match (constraints.min_length, constraints.max_length) {
+                (Some(min), _) if len < min => return violation(Constraint::MinLength(min)),
+                (_, Some(max)) if len > max => return violation(Constraint::MaxLength(max)),
+                _ => {}
+            }
+            if let Some((pattern, matches)) = constraints.pattern {
+                if !matches(s) {
+                    return violation(Constraint::Pattern(pattern));
+                }
+            }
+        }
+        serde_json::Value::Array(items) => {
+            match (constraints.min_items, constraints.max_items) {
+                (Some(min), _) if items.len() < min => return violation(Constraint::MinItems(min)),
+                (_, Some(max)) if items.len() > max => return violation(Constraint::MaxItems(max)),
+                _ => {}
+            }
+            if let Some(item_constraints) = constraints.items {
+                for (i, item) in items.iter().enumerate() {
+                    validate_value(&format!("{}[{}]", field, i), item, item_constraints)?;
+                }
+            }
+        }
+        serde_json::Value::Object(object) => {
+            for (name, property_constraints) in constraints.properties {
+                if let Some(property) = object.get(*name) {
+                    validate_value(
+                        &format!("{}.{}", field, name),
+                        property,
+                        property_constraints,
+                    )?;
+                }
+            }
+        }
+        serde_json::Value::Null | serde_json::Value::Bool(_) => {}
+    }
+
+    Ok(())
+}
+
+/// Strategies used by the `proptest::arbitrary::Arbitrary` implementations of
+/// generated types, and the check made by their generated round-trip tests.
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+pub mod arbitrary {
+    use proptest::{
+        arbitrary::any,
+        collection::vec,
+        strategy::{BoxedStrategy, Strategy},
+        test_runner::TestCaseError,
+    };
+    use serde::{de::DeserializeOwned, Serialize};
+
+    /// How deeply recursive types nest before the generation of further
+    /// nested values stops.
+    pub const MAX_DEPTH: u32 = 3;
+
+    /// Strings that match `pattern`, generated from it, of `min_length` to
+    /// `max_length` characters. With a pattern, the lengths are approached by
+    /// limiting its unbounded repetitions to `max_length`; as the pattern may
+    /// still allow strings of other lengths, callers reject those that don't
+    /// satisfy their schema.
+    pub fn string(
+        pattern: Option<&'static str>,
+        min_length: Option<usize>,
+        max_length: Option<usize>,
+    ) -> BoxedStrategy<String> {
+        let min = min_length.unwrap_or(0);
+        let max = max_length.unwrap_or(min + 16).max(min);
+
+        pattern
+            .and_then(|pattern| {
+                proptest::string::string_regex(&generation_pattern(pattern, max_length)).ok()
+            })
+            .map(Strategy::boxed)
+            .unwrap_or_else(|| {
+                vec(any::<char>(), min..=max)
+                    .prop_map(String::from_iter)
+                    .boxed()
+            })
+    }
+
+    /// Translate a pattern into one from which proptest can generate strings
+    /// that match it: anchors, which proptest doesn't support, are removed,
+    /// classes of characters are limited to those of ECMA 262 rather than
+    /// their Unicode counterparts, and unbounded repetitions are limited to
+    /// `max_repeat` if it's given.
+    fn generation_pattern(pattern: &str, max_repeat: Option<usize>) -> String {
+        const DIGIT: &str = "0-9";
+        const WORD: &str = "0-9A-Za-z_";
+
+        let mut out = String::new();
+        let mut chars = pattern.chars();
+        let mut class = false;
+
+        while let Some(c) = chars.next() {
+            let piece = match c {
+                '\\' => match (chars.next(), class) {
+                    (Some('d'), false) => format!("[{DIGIT}]"),
+                    (Some('D'), false) => format!("[^{DIGIT}]"),
+                    (Some('w'), false) => format!("[{WORD}]"),
+                    (Some('W'), false) => format!("[^{WORD}]"),
+                    (Some('d'), true) => DIGIT.to_string(),
+                    (Some('w'), true) => WORD.to_string(),
+                    (escaped, _) => String::from_iter([Some(c), escaped].into_iter().flatten()),
+                },
+                '[' if !class => {
+                    class = true;
+                    c.to_string()
+                }
+                ']' if class => {
+                    class = false;
+                    c.to_string()
+                }
+                _ if class => c.to_string(),
+                '^' | '$' => continue,
+                '.' => "[^\\n\\r\\u{2028}\\u{2029}]".to_string(),
+                '*' | '+' => match max_repeat {
+                    Some(max) => {
+                        let min = (c == '+') as usize;
+                        format!("{{{},{}}}", min, max.max(min))
+                    }
+                    None => c.to_string(),
+                },
+                '{' => {
+                    // A repetition with no upper bound, as in `{2,}`.
+                    let rest = chars.as_str();
+                    let unbounded = rest.find('}').and_then(|end| {
+                        let min = rest[..end].strip_suffix(',')?.parse::<usize>().ok()?;
+                        Some((end, min, max_repeat?))
+                    });
+                    match unbounded {
+                        Some((end, min, max)) => {
+                            chars = rest[end + 1..].chars();
+                            format!("{{{},{}}}", min, max.max(min))
+                        }
+                        None => c.to_string(),
+                    }
+                }
+                _ => c.to_string(),
+            };
+            out.push_str(&piece);
+        }
+        out
+    }
+
+    /// Finite `f64` values, which JSON can represent.
+    pub fn finite_f64() -> BoxedStrategy<f64> {
+        use proptest::num::f64::{NEGATIVE, NORMAL, POSITIVE, SUBNORMAL, ZERO};
+        (POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO).boxed()
+    }
+
+    /// Finite `f32` values, which JSON can represent.
+    pub fn finite_f32() -> BoxedStrategy<f32> {
+        use proptest::num::f32::{NEGATIVE, NORMAL, POSITIVE, SUBNORMAL, ZERO};
+        (POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO).boxed()
+    }
+
+    /// Arbitrary JSON values, other than `null`; a `null` held by an optional
+    /// value is indistinguishable from an absent one.
+    pub fn json_value() -> BoxedStrategy<serde_json::Value> {
+        let leaf = proptest::prop_oneof![
+            any::<bool>().prop_map(serde_json::Value::from),
+            any::<i64>().prop_map(serde_json::Value::from),
+            finite_f64().prop_map(serde_json::Value::from),
+            any::<String>().prop_map(serde_json::Value::from),
+        ];
+        leaf.prop_recursive(MAX_DEPTH, 16, 4, |inner| {
+            proptest::prop_oneof![
+                vec(inner.clone(), 0..4).prop_map(serde_json::Value::Array),
+                vec((any::<String>(), inner), 0..4)
+                    .prop_map(|fields| serde_json::Value::Object(fields.into_iter().collect())),
+            ]
+        })
+        .boxed()
+    }
+
+    /// Check that `value` is unchanged by serializing it and deserializing
+    /// the result, comparing the two as JSON.
+    pub fn round_trip<T>(value: &T) -> Result<(), TestCaseError>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let json = serde_json::to_value(value)
+            .map_err(|e| TestCaseError::fail(format!("failed to serialize: {}", e)))?;
+        let decoded = serde_json::from_value::<T>(json.clone())
+            .map_err(|e| TestCaseError::fail(format!("failed to deserialize {}: {}", json, e)))?;
+        let redecoded = serde_json::to_value(&decoded)
+            .map_err(|e| TestCaseError::fail(format!("failed to reserialize: {}", e)))?;
+        proptest::prop_assert_eq!(redecoded, json);
+        Ok(())
+    }
+}
+
+/// A difference between a response and the API description from which the
+/// client was generated, reported to [`ClientHooks::contract_violation`] by
+/// clients generated with response checking enabled.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContractViolation {
+    /// The response status code isn't among those declared for the
+    /// operation.
+    UndeclaredStatus,
+    /// A required property is absent from the object at `path`.
+    MissingProperty {
+        /// Location of the object within the response body.
+        path: String,
+        /// Name of the missing property.
+        property: String,
+    },
+    /// The value at `path` isn't one of the enumerated values.
+    UnknownEnumValue {
+        /// Location of the value within the response body.
+        path: String,
+        /// The unexpected value.
+        value: serde_json::Value,
+    },
+    /// The object at `path` has a property that isn't declared, and its
+    /// schema doesn't permit additional properties.
+    ExtraProperty {
+        /// Location of the object within the response body.
+        path: String,
+        /// Name of the unexpected property.
+        property: String,
+    },
+}
+
+impl std::fmt::Display for ContractViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContractViolation::UndeclaredStatus => write!(f, "undeclared response status"),
+            ContractViolation::MissingProperty { path, property } => {
+                write!(f, "`{}` is missing required property `{}`", path, property)
+            }
+            ContractViolation::UnknownEnumValue { path, value } => {
+                write!(f, "`{}` has unknown enum value {}", path, value)
+            }
+            ContractViolation::ExtraProperty { path, property } => {
+                write!(f, "`{}` has undeclared property `{}`", path, property)
+            }
+        }
+    }
+}
+
+/// The parts of a response schema checked by generated clients (when
+/// response checking is enabled) after a response is received.
+#[doc(hidden)]
+pub struct ContractSchema {
+    /// Permitted values, each serialized as JSON.
+    pub enum_values: &'static [&'static str],
+    pub properties: &'static [(&'static str, ContractSchema)],
+    pub required: &'static [&'static str],
+    pub additional_properties: bool,
+    pub items: Option<&'static ContractSchema>,
+}
+
+impl ContractSchema {
+    pub const ANY: Self = Self {
+        enum_values: &[],
+        properties: &[],
+        required: &[],
+        additional_properties: true,
+        items: None,
+    };
+
+    fn check(
+        &self,
+        path: &str,
+        value: &serde_json::Value,
+        report: &mut dyn FnMut(ContractViolation),
+    ) {
+        if !self.enum_values.is_empty()
+            && !self
+                .enum_values
+                .iter()
+                .any(|v| serde_json::from_str::<serde_json::Value>(v).ok().as_ref() == Some(value))
+        {
+            report(ContractViolation::UnknownEnumValue {
+                path: path.to_string(),
+                value: value.clone(),
+            });
+        }
+
+        match value {
+            serde_json::Value::Array(items) => {
+                if let Some(item_schema) = self.items {
+                    for (i, item) in items.iter().enumerate() {
+                        item_schema.check(&format!("{}[{}]", path, i), item, report);
+                    }
+                }
+            }
+            serde_json::Value::Object(object) => {
+                for required in self.required {
+                    if !object.contains_key(*required) {
+                        report(ContractViolation::MissingProperty {
+                            path: path.to_string(),
+                            property: required.to_string(),
+                        });
+                    }
+                }
+                for (name, property) in object {
+                    match self.properties.iter().find(|(n, _)| n == name) {
+                        Some((_, schema)) => {
+                            schema.check(&format!("{}.{}", path, name), property, report)
+                        }
+                        None if !self.additional_properties => {
+                            report(ContractViolation::ExtraProperty {
+                                path: path.to_string(),
+                                property: name.clone(),
+                            })
+                        }
+                        None => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
 #[doc(hidden)]
 pub trait RequestBuilderExt<E> {
     fn form_urlencoded<T: Serialize + ?Sized>(self, body: &T) -> Result<RequestBuilder, Error<E>>;
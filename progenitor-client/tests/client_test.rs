@@ -5,8 +5,14 @@ use std::{
     error::Error,
 };
 
-use progenitor_client::{encode_path, QueryParam};
-use serde::Serialize;
+use bytes::Bytes;
+use futures::{executor::block_on, StreamExt};
+use progenitor_client::{
+    encode_path, validate_request, ByteStream, Constraint, Constraints, EventStream, FakeRequest,
+    JsonArrayStream, JsonLinesStream, OperationInfo, OperationMetadata, QueryParam,
+    RequestValidationError, ServerSentEvent, UploadBody,
+};
+use serde::{Deserialize, Serialize};
 
 #[test]
 fn test_path_segment_encoding() {
@@ -219,3 +225,660 @@ fn test_query_option() {
     let result = encode_query_param("paramName", &value).unwrap();
     assert_eq!(result, "paramName=42");
 }
+
+fn event_stream<T: serde::de::DeserializeOwned>(
+    chunks: &[&'static str],
+) -> Vec<Result<ServerSentEvent<T>, progenitor_client::Error>> {
+    let inner = futures::stream::iter(
+        chunks
+            .iter()
+            .map(|chunk| Ok(Bytes::from_static(chunk.as_bytes())))
+            .collect::<Vec<_>>(),
+    );
+    block_on(EventStream::<T>::new(Box::pin(inner)).collect())
+}
+
+#[test]
+fn test_event_stream_framing() {
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Progress {
+        done: u32,
+    }
+
+    // Events split across chunks with a mix of line endings, comments, and
+    // multi-line data.
+    let events = event_stream::<Progress>(&[
+        ": keep-alive\n\n",
+        "event: progress\r\nid: 1\r\ndata: {\"done\"",
+        ": 1}\r",
+        "\n\r\nretry: 2500\n",
+        "data: {\n",
+        "data: \"done\": 2\n",
+        "data: }\n\n",
+        "id\ndata:{\"done\":3}\n\n",
+        "data: {\"done\": 4}\n",
+    ]);
+
+    let events = events.into_iter().map(Result::unwrap).collect::<Vec<_>>();
+    assert_eq!(
+        events,
+        vec![
+            ServerSentEvent {
+                event: Some("progress".to_string()),
+                id: Some("1".to_string()),
+                retry: None,
+                data: Progress { done: 1 },
+            },
+            ServerSentEvent {
+                event: None,
+                id: Some("1".to_string()),
+                retry: Some(std::time::Duration::from_millis(2500)),
+                data: Progress { done: 2 },
+            },
+            // An empty id field resets the last event ID; the final,
+            // unterminated event is never dispatched.
+            ServerSentEvent {
+                event: None,
+                id: None,
+                retry: Some(std::time::Duration::from_millis(2500)),
+                data: Progress { done: 3 },
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_event_stream_data() {
+    // Non-JSON data may still be deserialized as a string.
+    let events = event_stream::<String>(&["data: hello\ndata: world\n\n"]);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].as_ref().unwrap().data, "hello\nworld");
+
+    // Data that doesn't match the type produces an error without ending the
+    // stream.
+    let events = event_stream::<u32>(&["data: nope\n\ndata: 7\n\n"]);
+    assert_eq!(events.len(), 2);
+    assert!(matches!(
+        &events[0],
+        Err(progenitor_client::Error::InvalidResponsePayload(data, _)) if data == "nope"
+    ));
+    assert_eq!(events[1].as_ref().unwrap().data, 7);
+
+    // An event that exceeds the maximum size, even over several lines, ends
+    // the stream.
+    let inner = futures::stream::iter(
+        [
+            "data: 1\n\n",
+            "data: 2222\n",
+            "data: 2222\n",
+            "data: 2222\n\n",
+        ]
+        .into_iter()
+        .map(|chunk| Ok(Bytes::from_static(chunk.as_bytes())))
+        .collect::<Vec<_>>(),
+    );
+    let events = block_on(
+        EventStream::<u32>::new(Box::pin(inner))
+            .with_max_event_size(8)
+            .collect::<Vec<_>>(),
+    );
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].as_ref().unwrap().data, 1);
+    assert!(matches!(
+        events[1],
+        Err(progenitor_client::Error::ResponseBodyTooLarge(8))
+    ));
+}
+
+#[test]
+fn test_json_lines_stream() {
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Record {
+        id: u32,
+    }
+
+    // Records split across chunks, blank lines, CRLF line endings, and a
+    // final record without a trailing newline.
+    let inner = futures::stream::iter(
+        [
+            "{\"id\":1}\n{\"i",
+            "d\":2}\r\n\n",
+            "not json\n",
+            "{\"id\":3}",
+        ]
+        .into_iter()
+        .map(|chunk| Ok(Bytes::from_static(chunk.as_bytes())))
+        .collect::<Vec<_>>(),
+    );
+    let records = block_on(JsonLinesStream::<Record>::new(Box::pin(inner)).collect::<Vec<_>>());
+
+    assert_eq!(records.len(), 4);
+    assert_eq!(records[0].as_ref().unwrap(), &Record { id: 1 });
+    assert_eq!(records[1].as_ref().unwrap(), &Record { id: 2 });
+    assert!(matches!(
+        &records[2],
+        Err(progenitor_client::Error::InvalidResponsePayload(data, _)) if data == "not json"
+    ));
+    assert_eq!(records[3].as_ref().unwrap(), &Record { id: 3 });
+
+    // A line that exceeds the maximum length ends the stream.
+    let inner = futures::stream::iter(
+        [
+            "{\"id\":1}\n{\"id\":",
+            "2222",
+            "2222",
+            "2222}\n{\"id\":3}\n",
+        ]
+        .into_iter()
+        .map(|chunk| Ok(Bytes::from_static(chunk.as_bytes())))
+        .collect::<Vec<_>>(),
+    );
+    let records = block_on(
+        JsonLinesStream::<Record>::new(Box::pin(inner))
+            .with_max_line_length(12)
+            .collect::<Vec<_>>(),
+    );
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].as_ref().unwrap(), &Record { id: 1 });
+    assert!(matches!(
+        records[1],
+        Err(progenitor_client::Error::ResponseBodyTooLarge(12))
+    ));
+}
+
+fn json_array<T: serde::de::DeserializeOwned>(
+    body: &'static str,
+    field: Option<&'static str>,
+    max_body_size: Option<u64>,
+) -> (
+    Vec<Result<T, progenitor_client::Error>>,
+    serde_json::Map<String, serde_json::Value>,
+) {
+    // Deliver the body one byte at a time to exercise every split point.
+    let inner = futures::stream::iter(
+        body.as_bytes()
+            .chunks(1)
+            .map(|chunk| Ok(Bytes::copy_from_slice(chunk)))
+            .collect::<Vec<_>>(),
+    );
+    let mut stream = JsonArrayStream::<T>::new(Box::pin(inner), field);
+    if let Some(limit) = max_body_size {
+        stream = stream.with_max_body_size(limit);
+    }
+    let items = block_on((&mut stream).collect());
+    (items, stream.other_fields().clone())
+}
+
+#[test]
+fn test_json_array_stream() {
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Item {
+        name: String,
+    }
+
+    let (items, _) = json_array::<Item>(
+        r#" [ {"name": "a, [b]"}, {"name": "\"}\\"} ,{"name":"c"}] "#,
+        None,
+        None,
+    );
+    assert_eq!(
+        items
+            .into_iter()
+            .map(|item| item.unwrap().name)
+            .collect::<Vec<_>>(),
+        vec!["a, [b]", "\"}\\", "c"],
+    );
+
+    let (items, _) = json_array::<u32>("[]", None, None);
+    assert!(items.is_empty());
+
+    // Scalars, including one that ends the body.
+    let (items, _) = json_array::<i64>("[1,-20 , 300]", None, None);
+    assert_eq!(
+        items.into_iter().map(Result::unwrap).collect::<Vec<_>>(),
+        vec![1, -20, 300],
+    );
+
+    // An array within an object.
+    let (items, other) = json_array::<u32>(
+        r#"{"before": {"x": [1]}, "items": [1, 2], "next_page": "abc"}"#,
+        Some("items"),
+        None,
+    );
+    assert_eq!(
+        items.into_iter().map(Result::unwrap).collect::<Vec<_>>(),
+        vec![1, 2],
+    );
+    assert_eq!(
+        serde_json::Value::Object(other),
+        serde_json::json!({ "before": { "x": [1] }, "next_page": "abc" }),
+    );
+}
+
+#[test]
+fn test_json_array_stream_errors() {
+    // Elements that don't match the type produce an error without ending the
+    // stream.
+    let (items, _) = json_array::<u32>(r#"[1, "two", 3]"#, None, None);
+    assert_eq!(items.len(), 3);
+    assert!(matches!(
+        &items[1],
+        Err(progenitor_client::Error::InvalidResponsePayload(data, _)) if data == "\"two\""
+    ));
+
+    // Malformed or truncated bodies end the stream with an error.
+    for body in ["{}", "[1, 2", "[1 2]", "[1] x", ""] {
+        let (items, _) = json_array::<u32>(body, None, None);
+        assert!(
+            matches!(
+                items.last(),
+                Some(Err(progenitor_client::Error::InvalidResponsePayload(..)))
+            ),
+            "{body}: {items:?}",
+        );
+    }
+
+    // Exceeding the maximum body size ends the stream.
+    let (items, _) = json_array::<u32>("[1, 2, 3, 4]", None, Some(5));
+    assert_eq!(items.len(), 2);
+    assert_eq!(*items[0].as_ref().unwrap(), 1);
+    assert!(matches!(
+        items[1],
+        Err(progenitor_client::Error::ResponseBodyTooLarge(5))
+    ));
+}
+
+#[test]
+fn test_byte_stream_progress() {
+    let inner = futures::stream::iter(
+        ["abc", "de", "", "fghij"]
+            .into_iter()
+            .map(|chunk| Ok(Bytes::from_static(chunk.as_bytes())))
+            .collect::<Vec<_>>(),
+    );
+    let reports = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let progress = reports.clone();
+    let stream = ByteStream::new(Box::pin(inner)).with_progress(move |received, total| {
+        progress.lock().unwrap().push((received, total));
+    });
+    let chunks = block_on(stream.into_inner().collect::<Vec<_>>());
+
+    assert_eq!(chunks.len(), 4);
+    assert_eq!(
+        *reports.lock().unwrap(),
+        vec![(3, None), (5, None), (5, None), (10, None)],
+    );
+}
+
+#[test]
+fn test_upload_body_length() {
+    assert_eq!(UploadBody::from("hello").content_length(), Some(5));
+    assert_eq!(
+        UploadBody::from(vec![0u8; 1024]).content_length(),
+        Some(1024)
+    );
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn test_upload_body_from_reader() {
+    assert_eq!(
+        UploadBody::from_reader(&b"streamed"[..], 8).content_length(),
+        Some(8)
+    );
+
+    let path = std::env::temp_dir().join(format!("upload-{}", uuid::Uuid::new_v4()));
+    std::fs::write(&path, [7u8; 100]).unwrap();
+    let body = UploadBody::from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(body.content_length(), Some(100));
+
+    assert!(UploadBody::from_file(&path).is_err());
+}
+
+#[cfg(feature = "tokio")]
+fn ranged_response(
+    status: u16,
+    headers: &[(&'static str, &'static str)],
+    chunks: Vec<Result<&'static str, ()>>,
+) -> Result<progenitor_client::ResponseValue<ByteStream>, progenitor_client::Error> {
+    // A failed chunk stands in for a dropped connection.
+    let inner = futures::stream::iter(chunks.into_iter().map(|chunk| match chunk {
+        Ok(chunk) => Ok(Bytes::from_static(chunk.as_bytes())),
+        Err(()) => Err(reqwest::Client::new().get("bad url").build().unwrap_err()),
+    }));
+    let mut header_map = reqwest::header::HeaderMap::new();
+    for (name, value) in headers {
+        header_map.insert(*name, value.parse().unwrap());
+    }
+    Ok(progenitor_client::ResponseValue::new(
+        ByteStream::new(Box::pin(inner)),
+        reqwest::StatusCode::from_u16(status).unwrap(),
+        header_map,
+    ))
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn test_ranged_download() {
+    use progenitor_client::RangedDownload;
+
+    let mut data = Vec::new();
+    let mut download = RangedDownload::new(&mut data)
+        .with_backoff(std::time::Duration::ZERO, std::time::Duration::ZERO);
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    download.range_headers(&mut headers);
+    assert!(headers.is_empty());
+
+    // The first response is interrupted ...
+    let response = ranged_response(
+        200,
+        &[("etag", "\"v1\""), ("content-length", "10")],
+        vec![Ok("abcd"), Err(())],
+    );
+    assert!(block_on(download.receive(response)).is_none());
+    assert_eq!(download.written(), 4);
+
+    download.range_headers(&mut headers);
+    assert_eq!(headers["range"], "bytes=4-");
+    assert_eq!(headers["if-range"], "\"v1\"");
+
+    // ... and resumed with a partial response.
+    let response = ranged_response(
+        206,
+        &[("etag", "\"v1\""), ("content-range", "bytes 4-9/10")],
+        vec![Ok("efghij")],
+    );
+    let response = block_on(download.receive(response)).unwrap().unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    assert_eq!(response.into_inner(), 10);
+    assert_eq!(data, b"abcdefghij");
+
+    // A full response with the same ETag skips what was already written; one
+    // with a different ETag is an error.
+    let mut data = Vec::new();
+    let mut download = RangedDownload::new(&mut data)
+        .with_backoff(std::time::Duration::ZERO, std::time::Duration::ZERO);
+    let response = ranged_response(
+        200,
+        &[("etag", "\"v1\""), ("content-length", "6")],
+        vec![Ok("abc"), Err(())],
+    );
+    assert!(block_on(download.receive(response)).is_none());
+    let response = ranged_response(
+        200,
+        &[("etag", "\"v1\""), ("content-length", "6")],
+        vec![Ok("ab"), Ok("cdef")],
+    );
+    let response = block_on(download.receive(response)).unwrap().unwrap();
+    assert_eq!(response.into_inner(), 6);
+    assert_eq!(data, b"abcdef");
+
+    let mut data = Vec::new();
+    let mut download = RangedDownload::new(&mut data)
+        .with_backoff(std::time::Duration::ZERO, std::time::Duration::ZERO);
+    let response = ranged_response(
+        200,
+        &[("etag", "\"v1\""), ("content-length", "6")],
+        vec![Ok("abc"), Err(())],
+    );
+    assert!(block_on(download.receive(response)).is_none());
+    let response = ranged_response(
+        200,
+        &[("etag", "\"v2\""), ("content-length", "6")],
+        vec![Ok("uvwxyz")],
+    );
+    assert!(matches!(
+        block_on(download.receive(response)),
+        Some(Err(progenitor_client::Error::Custom(_)))
+    ));
+
+    // Downloads give up after repeated failures without progress; those that
+    // made progress don't count.
+    let mut data = Vec::new();
+    let mut download = RangedDownload::new(&mut data)
+        .with_backoff(std::time::Duration::ZERO, std::time::Duration::ZERO)
+        .with_max_attempts(1);
+    let response = ranged_response(200, &[("content-length", "6")], vec![Ok("abc"), Err(())]);
+    assert!(block_on(download.receive(response)).is_none());
+    let response = ranged_response(206, &[("content-range", "bytes 3-5/6")], vec![Err(())]);
+    assert!(matches!(
+        block_on(download.receive(response)),
+        Some(Err(progenitor_client::Error::ResponseBodyError(_)))
+    ));
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn test_ranged_download_backoff() {
+    use progenitor_client::RangedDownload;
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap();
+
+    // Retries wait between attempts, and stop after the total limit even when
+    // each made progress.
+    let mut data = Vec::new();
+    let mut download = RangedDownload::new(&mut data)
+        .with_backoff(
+            std::time::Duration::from_millis(20),
+            std::time::Duration::from_millis(40),
+        )
+        .with_max_retries(2);
+    let start = std::time::Instant::now();
+    let response = ranged_response(200, &[("content-length", "6")], vec![Ok("a"), Err(())]);
+    assert!(runtime.block_on(download.receive(response)).is_none());
+    let response = ranged_response(
+        206,
+        &[("content-range", "bytes 1-5/6")],
+        vec![Ok("b"), Err(())],
+    );
+    assert!(runtime.block_on(download.receive(response)).is_none());
+    assert!(start.elapsed() >= std::time::Duration::from_millis(20));
+    let response = ranged_response(
+        206,
+        &[("content-range", "bytes 2-5/6")],
+        vec![Ok("c"), Err(())],
+    );
+    assert!(matches!(
+        runtime.block_on(download.receive(response)),
+        Some(Err(progenitor_client::Error::ResponseBodyError(_)))
+    ));
+    assert_eq!(data, b"abc");
+}
+
+#[test]
+fn test_validate_request() {
+    const NAME: Constraints = Constraints {
+        min_length: Some(2),
+        max_length: Some(4),
+        pattern: Some(("^[a-z]+$", |value| {
+            !value.is_empty() && value.chars().all(|c| c.is_ascii_lowercase())
+        })),
+        ..Constraints::NONE
+    };
+    assert!(validate_request("name", "abc", &NAME).is_ok());
+    assert_eq!(
+        validate_request("name", "a", &NAME)
+            .unwrap_err()
+            .to_string(),
+        "`name` violates `minLength = 2`"
+    );
+    // Lengths are in characters.
+    assert!(validate_request("name", "ééé", &NAME).is_err());
+    assert_eq!(
+        validate_request("name", "abcde", &NAME)
+            .unwrap_err()
+            .to_string(),
+        "`name` violates `maxLength = 4`"
+    );
+    assert_eq!(
+        validate_request("name", "AB", &NAME)
+            .unwrap_err()
+            .to_string(),
+        "`name` violates `pattern = \"^[a-z]+$\"`"
+    );
+
+    const LIMIT: Constraints = Constraints {
+        minimum: Some((0.0, true)),
+        maximum: Some((100.0, false)),
+        ..Constraints::NONE
+    };
+    assert!(validate_request("limit", &100, &LIMIT).is_ok());
+    assert!(validate_request("limit", &Some(1.5), &LIMIT).is_ok());
+    assert!(validate_request("limit", &None::<u32>, &LIMIT).is_ok());
+    assert_eq!(
+        validate_request("limit", &0, &LIMIT)
+            .unwrap_err()
+            .to_string(),
+        "`limit` violates `exclusiveMinimum = 0`"
+    );
+    assert_eq!(
+        validate_request("limit", &101, &LIMIT)
+            .unwrap_err()
+            .to_string(),
+        "`limit` violates `maximum = 100`"
+    );
+
+    #[derive(Serialize)]
+    struct Body {
+        names: Vec<&'static str>,
+        limit: u32,
+    }
+    const BODY: Constraints = Constraints {
+        properties: &[
+            (
+                "names",
+                Constraints {
+                    max_items: Some(2),
+                    items: Some(&NAME),
+                    ..Constraints::NONE
+                },
+            ),
+            ("limit", LIMIT),
+        ],
+        ..Constraints::NONE
+    };
+    let body = Body {
+        names: vec!["ab", "cd"],
+        limit: 1,
+    };
+    assert!(validate_request("body", &body, &BODY).is_ok());
+    let body = Body {
+        names: vec!["ab", "cd", "ef"],
+        limit: 1,
+    };
+    assert_eq!(
+        validate_request("body", &body, &BODY)
+            .unwrap_err()
+            .to_string(),
+        "`body.names` violates `maxItems = 2`"
+    );
+    assert_eq!(
+        validate_request("body", &body, &BODY).unwrap_err(),
+        RequestValidationError {
+            field: "body.names".to_string(),
+            constraint: Constraint::MaxItems(2),
+        }
+    );
+    let body = Body {
+        names: vec!["ab", "c"],
+        limit: 1,
+    };
+    assert_eq!(
+        validate_request("body", &body, &BODY)
+            .unwrap_err()
+            .to_string(),
+        "`body.names[1]` violates `minLength = 2`"
+    );
+    let body = Body {
+        names: vec![],
+        limit: 1000,
+    };
+    assert_eq!(
+        validate_request("body", &body, &BODY)
+            .unwrap_err()
+            .to_string(),
+        "`body.limit` violates `maximum = 100`"
+    );
+}
+
+#[test]
+fn test_fake_request_path() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct FileParams {
+        #[serde(rename = "fileId")]
+        file_id: u32,
+        name: String,
+    }
+
+    static METADATA: OperationMetadata = OperationMetadata {
+        operation_id: "complete",
+        method: reqwest::Method::POST,
+        path: "/v1/{name}/files/{fileId}:complete",
+        tags: &[],
+        idempotent: false,
+        deprecated: false,
+        success_statuses: &[],
+    };
+    let info = OperationInfo::new(&METADATA);
+
+    let request = |url: &str| reqwest::Request::new(reqwest::Method::POST, url.parse().unwrap());
+
+    // The base URL may have a path of its own.
+    let r = request("http://localhost/base/v1/a%20b/files/42:complete");
+    assert_eq!(
+        FakeRequest::new(&r, &info).path::<FileParams>().unwrap(),
+        FileParams {
+            file_id: 42,
+            name: "a b".to_string(),
+        }
+    );
+
+    for url in [
+        "http://localhost/v1/a/files/42",
+        "http://localhost/v1/a/files/42:completed",
+        "http://localhost/v1/a/other/42:complete",
+        "http://localhost/files/42:complete",
+    ] {
+        assert!(FakeRequest::new(&request(url), &info)
+            .path::<FileParams>()
+            .is_err());
+    }
+}
+
+#[cfg(feature = "proptest")]
+#[test]
+fn test_arbitrary_string() {
+    use proptest::{
+        strategy::{Strategy, ValueTree},
+        test_runner::TestRunner,
+    };
+
+    // Anchors aren't supported by proptest's generation, but constrain the
+    // generated strings nonetheless; `\d` and `.` are limited
+    // to ASCII digits and exclude line terminators as in ECMA 262. Unbounded
+    // repetitions are limited so that nearly all strings are short enough;
+    // generated types reject the rest.
+    let mut runner = TestRunner::deterministic();
+    for (pattern, max_length) in [
+        ("^[a-z][a-z0-9-]*[a-z0-9]$", Some(63)),
+        ("^\\d+\\.\\d+\\.\\d+([\\-\\+].+)?$", None),
+    ] {
+        let re = regress::Regex::new(pattern).unwrap();
+        let strategy = progenitor_client::arbitrary::string(Some(pattern), None, max_length);
+        let mut satisfied = 0;
+        for _ in 0..256 {
+            let value = strategy.new_tree(&mut runner).unwrap().current();
+            if re.find(&value).is_some()
+                && value.chars().count() <= max_length.unwrap_or(usize::MAX)
+            {
+                satisfied += 1;
+            }
+        }
+        assert!(satisfied >= 240, "{pattern}: {satisfied}");
+    }
+}
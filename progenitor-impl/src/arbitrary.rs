@@ -0,0 +1,990 @@
+// Copyright 2025 Oxide Computer Company
+
+//! Generation of `proptest` strategies for the generated types, and of tests
+//! that values of each type survive a serde round trip.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use openapiv3::{AdditionalProperties, Components, OpenAPI, ReferenceOr, Schema, SchemaKind, Type};
+use proc_macro2::{Literal, Spacing, TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use typify::{TypeDetails, TypeEnumVariant, TypeId};
+
+use crate::{
+    method::enum_json,
+    util::{pattern_example, sanitize, Case, ReferenceOrExt},
+    Generator, Result,
+};
+
+/// A strategy for a value within a named type.
+struct ValueStrategy {
+    tokens: TokenStream,
+    /// Whether the strategy recurses into the type being generated without
+    /// an optional or empty alternative that bounds its depth.
+    recursive: bool,
+}
+
+/// What's known while generating the strategy for a named type.
+struct StrategyContext<'a> {
+    components: &'a Option<Components>,
+    /// The named types reachable from each named type.
+    reachable: &'a BTreeMap<String, BTreeSet<String>>,
+    /// The name of the type being generated.
+    current: &'a str,
+}
+
+impl StrategyContext<'_> {
+    /// Whether a reference to the named type from the current type is part
+    /// of a cycle.
+    fn is_recursive(&self, name: &str) -> bool {
+        self.reachable
+            .get(name)
+            .is_some_and(|reachable| reachable.contains(self.current))
+    }
+}
+
+impl Generator {
+    /// Implement `proptest::arbitrary::Arbitrary` for each named type, and
+    /// generate a test that values of each survive a serde round trip.
+    pub(crate) fn arbitrary(&mut self, spec: &OpenAPI) -> Result<TokenStream> {
+        let components = &spec.components;
+
+        // Schemas of the types defined in components take precedence over
+        // those of types defined inline within operations.
+        let mut roots = Vec::new();
+        if let Some(components) = components {
+            for (name, schema) in &components.schemas {
+                let type_id = self
+                    .type_space
+                    .add_type(&schemars::schema::Schema::new_ref(format!(
+                        "#/components/schemas/{}",
+                        name
+                    )))?;
+                roots.push((type_id, schema.clone()));
+            }
+        }
+
+        let mut schemas = BTreeMap::new();
+        for (type_id, schema) in &roots {
+            if let Some(name) = self.named_type(type_id) {
+                schemas.entry(name).or_insert_with(|| schema.clone());
+            }
+        }
+        for (type_id, schema) in &roots {
+            self.assign_child_schemas(type_id, schema, components, &mut schemas);
+        }
+        for (type_id, schema) in &self.type_schemas {
+            self.assign_schema(type_id, schema, components, &mut schemas);
+        }
+
+        let mut types = self
+            .type_space
+            .iter_types()
+            .filter(|typ| {
+                matches!(
+                    typ.details(),
+                    TypeDetails::Struct(_) | TypeDetails::Enum(_) | TypeDetails::Newtype(_)
+                )
+            })
+            .collect::<Vec<_>>();
+        types.sort_by_key(|typ| typ.name());
+
+        // Each type's references to other named types, and from those the
+        // types reachable from each, to find cycles.
+        let references = types
+            .iter()
+            .map(|typ| {
+                let mut names = BTreeSet::new();
+                match typ.details() {
+                    TypeDetails::Struct(tstruct) => tstruct
+                        .properties_info()
+                        .for_each(|prop| self.referenced_types(&prop.type_id, &mut names)),
+                    TypeDetails::Enum(tenum) => {
+                        tenum
+                            .variants_info()
+                            .for_each(|variant| match &variant.details {
+                                TypeEnumVariant::Simple => {}
+                                TypeEnumVariant::Tuple(type_ids) => type_ids
+                                    .iter()
+                                    .for_each(|type_id| self.referenced_types(type_id, &mut names)),
+                                TypeEnumVariant::Struct(props) => {
+                                    props.iter().for_each(|(_, type_id)| {
+                                        self.referenced_types(type_id, &mut names)
+                                    })
+                                }
+                            })
+                    }
+                    TypeDetails::Newtype(newtype) => {
+                        self.referenced_types(&newtype.inner(), &mut names)
+                    }
+                    _ => unreachable!(),
+                }
+                (typ.name(), names)
+            })
+            .collect::<BTreeMap<_, _>>();
+        let reachable = references
+            .keys()
+            .map(|name| {
+                let mut reachable = BTreeSet::new();
+                let mut pending = vec![name.clone()];
+                while let Some(next) = pending.pop() {
+                    for referenced in references.get(&next).into_iter().flatten() {
+                        if reachable.insert(referenced.clone()) {
+                            pending.push(referenced.clone());
+                        }
+                    }
+                }
+                (name.clone(), reachable)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let mut impls = Vec::new();
+        let mut tests = Vec::new();
+        for typ in &types {
+            let name = typ.name();
+            let ident = typ.ident();
+            let ctx = StrategyContext {
+                components,
+                reachable: &reachable,
+                current: &name,
+            };
+            let schema = schemas.get(&name);
+
+            let strategy = match typ.details() {
+                TypeDetails::Struct(tstruct) => {
+                    let resolved = schema.and_then(|schema| resolve(schema, components));
+                    let fields = tstruct
+                        .properties_info()
+                        .map(|prop| {
+                            let schema = resolved.and_then(|resolved| {
+                                property_schema(resolved, prop.name, components)
+                            });
+                            let strategy =
+                                self.value_strategy(&prop.type_id, schema.as_ref(), &ctx);
+                            (format_ident!("{}", prop.name), strategy.tokens)
+                        })
+                        .collect::<Vec<_>>();
+                    let names = fields.iter().map(|(name, _)| name);
+                    let construct = quote! { Self { #(#names),* } };
+                    let strategy = group(fields, construct);
+                    quote! { #strategy.boxed() }
+                }
+                TypeDetails::Enum(tenum) => {
+                    let (recursive, always): (Vec<_>, Vec<_>) = tenum
+                        .variants_info()
+                        .map(|variant| {
+                            let variant_name = format_ident!("{}", variant.name);
+                            match &variant.details {
+                                TypeEnumVariant::Simple => ValueStrategy {
+                                    tokens: quote! {
+                                        ::proptest::strategy::Just(Self::#variant_name)
+                                    },
+                                    recursive: false,
+                                },
+                                TypeEnumVariant::Tuple(type_ids) => {
+                                    let values = type_ids
+                                        .iter()
+                                        .map(|type_id| self.value_strategy(type_id, None, &ctx))
+                                        .collect::<Vec<_>>();
+                                    let recursive = values.iter().any(|value| value.recursive);
+                                    if let [value] = values.as_slice() {
+                                        let tokens = receiver(&value.tokens);
+                                        return ValueStrategy {
+                                            tokens: quote! {
+                                                #tokens.prop_map(Self::#variant_name)
+                                            },
+                                            recursive,
+                                        };
+                                    }
+                                    let fields = values
+                                        .into_iter()
+                                        .enumerate()
+                                        .map(|(ii, value)| {
+                                            (format_ident!("value{}", ii), value.tokens)
+                                        })
+                                        .collect::<Vec<_>>();
+                                    let names = fields.iter().map(|(name, _)| name);
+                                    let construct = quote! { Self::#variant_name(#(#names),*) };
+                                    ValueStrategy {
+                                        tokens: group(fields, construct),
+                                        recursive,
+                                    }
+                                }
+                                TypeEnumVariant::Struct(props) => {
+                                    let values = props
+                                        .iter()
+                                        .map(|(prop_name, type_id)| {
+                                            (
+                                                format_ident!("{}", prop_name),
+                                                self.value_strategy(type_id, None, &ctx),
+                                            )
+                                        })
+                                        .collect::<Vec<_>>();
+                                    let recursive = values.iter().any(|(_, value)| value.recursive);
+                                    let fields = values
+                                        .into_iter()
+                                        .map(|(name, value)| (name, value.tokens))
+                                        .collect::<Vec<_>>();
+                                    let names = fields.iter().map(|(name, _)| name);
+                                    let construct = quote! { Self::#variant_name { #(#names),* } };
+                                    ValueStrategy {
+                                        tokens: group(fields, construct),
+                                        recursive,
+                                    }
+                                }
+                            }
+                        })
+                        .partition(|variant| variant.recursive);
+                    let always = always.into_iter().map(|variant| variant.tokens);
+                    let recursive = recursive
+                        .into_iter()
+                        .map(|variant| variant.tokens)
+                        .collect::<Vec<_>>();
+
+                    // Variants that recurse are left out beyond the maximum
+                    // depth.
+                    if recursive.is_empty() {
+                        quote! {
+                            ::proptest::strategy::Union::new(vec![#(#always.boxed()),*]).boxed()
+                        }
+                    } else {
+                        quote! {
+                            {
+                                let mut variants = vec![#(#always.boxed()),*];
+                                #(
+                                    if depth < progenitor_client::arbitrary::MAX_DEPTH {
+                                        variants.push(#recursive.boxed());
+                                    }
+                                )*
+                                ::proptest::strategy::Union::new(variants).boxed()
+                            }
+                        }
+                    }
+                }
+                TypeDetails::Newtype(newtype) => {
+                    let inner = self.value_strategy(&newtype.inner(), schema, &ctx);
+                    let inner = receiver(&contain(inner).tokens);
+                    let resolved = schema.and_then(|schema| resolve(schema, components));
+                    if is_constrained(resolved) {
+                        quote! {
+                            #inner
+                                .prop_filter_map("value must satisfy the schema", |value| {
+                                    Self::try_from(value).ok()
+                                })
+                                .boxed()
+                        }
+                    } else {
+                        quote! { #inner.prop_map(Self::from).boxed() }
+                    }
+                }
+                _ => unreachable!(),
+            };
+
+            let depth = if ctx.is_recursive(&name) {
+                quote! {
+                    type Parameters = u32;
+                    type Strategy = ::proptest::strategy::BoxedStrategy<Self>;
+
+                    fn arbitrary_with(depth: Self::Parameters) -> Self::Strategy
+                }
+            } else {
+                quote! {
+                    type Parameters = ();
+                    type Strategy = ::proptest::strategy::BoxedStrategy<Self>;
+
+                    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy
+                }
+            };
+
+            impls.push(quote! {
+                impl ::proptest::arbitrary::Arbitrary for #ident {
+                    #depth {
+                        use ::proptest::strategy::Strategy as _;
+                        #strategy
+                    }
+                }
+            });
+
+            let test_name = format_ident!("{}", sanitize(&name, Case::Snake));
+            tests.push(quote! {
+                #[test]
+                fn #test_name() {
+                    ::proptest::test_runner::TestRunner::default()
+                        .run(
+                            &::proptest::arbitrary::any::<#ident>(),
+                            |value| progenitor_client::arbitrary::round_trip(&value),
+                        )
+                        .unwrap();
+                }
+            });
+        }
+
+        Ok(quote! {
+            #(#impls)*
+
+            /// Tests that values of each type are unchanged by a serde round
+            /// trip.
+            #[cfg(test)]
+            mod types_round_trip {
+                // This brings in `types`, as well as the `progenitor_client`
+                // module of a crate that includes the client code.
+                #[allow(unused_imports)]
+                use super::*;
+
+                #(#tests)*
+            }
+        })
+    }
+
+    /// The name of a type if it's a named type that may be generated.
+    fn named_type(&self, type_id: &TypeId) -> Option<String> {
+        let typ = self.type_space.get_type(type_id).ok()?;
+        let named = matches!(
+            typ.details(),
+            TypeDetails::Struct(_) | TypeDetails::Enum(_) | TypeDetails::Newtype(_)
+        );
+        named.then(|| typ.name())
+    }
+
+    /// Record the schema of a named type (unless one is already known), and
+    /// those of the named types within it.
+    fn assign_schema(
+        &self,
+        type_id: &TypeId,
+        schema: &ReferenceOr<Schema>,
+        components: &Option<Components>,
+        schemas: &mut BTreeMap<String, ReferenceOr<Schema>>,
+    ) {
+        if let Some(name) = self.named_type(type_id) {
+            if schemas.contains_key(&name) {
+                return;
+            }
+            schemas.insert(name, schema.clone());
+        }
+        self.assign_child_schemas(type_id, schema, components, schemas);
+    }
+
+    /// Record the schemas of the named types within a type with the given
+    /// schema.
+    fn assign_child_schemas(
+        &self,
+        type_id: &TypeId,
+        schema: &ReferenceOr<Schema>,
+        components: &Option<Components>,
+        schemas: &mut BTreeMap<String, ReferenceOr<Schema>>,
+    ) {
+        let Ok(typ) = self.type_space.get_type(type_id) else {
+            return;
+        };
+        let resolved = resolve(schema, components);
+        match typ.details() {
+            TypeDetails::Struct(tstruct) => {
+                let Some(resolved) = resolved else {
+                    return;
+                };
+                for prop in tstruct.properties_info() {
+                    if let Some(prop_schema) = property_schema(resolved, prop.name, components) {
+                        self.assign_schema(&prop.type_id, &prop_schema, components, schemas);
+                    }
+                }
+            }
+            TypeDetails::Newtype(newtype) => {
+                self.assign_schema(&newtype.inner(), schema, components, schemas)
+            }
+            TypeDetails::Option(inner) | TypeDetails::Box(inner) => {
+                self.assign_schema(&inner, schema, components, schemas)
+            }
+            TypeDetails::Vec(item) | TypeDetails::Set(item) | TypeDetails::Array(item, _) => {
+                if let Some(item_schema) = resolved.and_then(items_schema) {
+                    self.assign_schema(&item, &item_schema, components, schemas);
+                }
+            }
+            TypeDetails::Map(_, value) => {
+                if let Some(value_schema) = resolved.and_then(additional_properties_schema) {
+                    self.assign_schema(&value, &value_schema, components, schemas);
+                }
+            }
+            _ => {}
+        };
+    }
+
+    /// Collect the names of the named types directly referenced by a type.
+    fn referenced_types(&self, type_id: &TypeId, names: &mut BTreeSet<String>) {
+        if let Some(name) = self.named_type(type_id) {
+            names.insert(name);
+            return;
+        }
+        let Ok(typ) = self.type_space.get_type(type_id) else {
+            return;
+        };
+        match typ.details() {
+            TypeDetails::Option(inner)
+            | TypeDetails::Box(inner)
+            | TypeDetails::Vec(inner)
+            | TypeDetails::Set(inner)
+            | TypeDetails::Array(inner, _) => self.referenced_types(&inner, names),
+            TypeDetails::Map(key, value) => {
+                self.referenced_types(&key, names);
+                self.referenced_types(&value, names);
+            }
+            TypeDetails::Tuple(type_ids) => {
+                type_ids.for_each(|type_id| self.referenced_types(&type_id, names))
+            }
+            _ => {}
+        };
+    }
+
+    /// The strategy for a value of the given type, constrained by its schema
+    /// if that's known.
+    fn value_strategy(
+        &self,
+        type_id: &TypeId,
+        schema: Option<&ReferenceOr<Schema>>,
+        ctx: &StrategyContext,
+    ) -> ValueStrategy {
+        let typ = self.type_space.get_type(type_id).unwrap();
+        let ident = typ.ident();
+        let resolved = schema.and_then(|schema| resolve(schema, ctx.components));
+
+        let leaf = |tokens| ValueStrategy {
+            tokens,
+            recursive: false,
+        };
+
+        let strategy = match typ.details() {
+            TypeDetails::Struct(_) | TypeDetails::Enum(_) | TypeDetails::Newtype(_) => {
+                if ctx.is_recursive(&typ.name()) {
+                    ValueStrategy {
+                        tokens: quote! {
+                            ::proptest::arbitrary::any_with::<#ident>(depth + 1)
+                        },
+                        recursive: true,
+                    }
+                } else {
+                    leaf(quote! { ::proptest::arbitrary::any::<#ident>() })
+                }
+            }
+
+            TypeDetails::Option(inner) => {
+                let inner = self.value_strategy(&inner, schema, ctx);
+                let tokens = inner.tokens;
+                contain(ValueStrategy {
+                    tokens: quote! { ::proptest::option::of(#tokens) },
+                    recursive: inner.recursive,
+                })
+            }
+            TypeDetails::Box(inner) => {
+                let inner = self.value_strategy(&inner, schema, ctx);
+                let tokens = receiver(&inner.tokens);
+                ValueStrategy {
+                    tokens: quote! { #tokens.prop_map(::std::boxed::Box::new) },
+                    recursive: inner.recursive,
+                }
+            }
+            TypeDetails::Vec(item) | TypeDetails::Set(item) => {
+                let item_schema = resolved.and_then(items_schema);
+                let item = self.value_strategy(&item, item_schema.as_ref(), ctx);
+                let tokens = item.tokens;
+                let (min, max) = match resolved.map(|schema| &schema.schema_kind) {
+                    Some(SchemaKind::Type(Type::Array(array))) => {
+                        (array.min_items, array.max_items)
+                    }
+                    _ => (None, None),
+                };
+                let (lo, hi) = size_range(min, max);
+                let tokens = if matches!(typ.details(), TypeDetails::Set(_)) {
+                    // Duplicates collapse, so check the size of the set.
+                    quote! {
+                        ::proptest::collection::vec(#tokens, #lo..=#hi)
+                            .prop_map(|items| items.into_iter().collect::<#ident>())
+                            .prop_filter("set must have enough items", |items| {
+                                items.len() >= #lo
+                            })
+                    }
+                } else {
+                    quote! { ::proptest::collection::vec(#tokens, #lo..=#hi) }
+                };
+                contain(ValueStrategy {
+                    tokens,
+                    recursive: item.recursive && lo == 0,
+                })
+            }
+            TypeDetails::Array(item, length) => {
+                let item_schema = resolved.and_then(items_schema);
+                let item = self.value_strategy(&item, item_schema.as_ref(), ctx);
+                let tokens = item.tokens;
+                ValueStrategy {
+                    tokens: quote! {
+                        ::proptest::collection::vec(#tokens, #length)
+                            .prop_map(|items| <#ident>::try_from(items).unwrap())
+                    },
+                    recursive: item.recursive,
+                }
+            }
+            TypeDetails::Map(key, value) => {
+                let key = self.value_strategy(&key, None, ctx);
+                let value_schema = resolved.and_then(additional_properties_schema);
+                let value = self.value_strategy(&value, value_schema.as_ref(), ctx);
+                let key_tokens = key.tokens;
+                let value_tokens = value.tokens;
+                contain(ValueStrategy {
+                    tokens: quote! {
+                        ::proptest::collection::vec((#key_tokens, #value_tokens), 0usize..=4usize)
+                            .prop_map(|entries| entries.into_iter().collect())
+                    },
+                    recursive: key.recursive || value.recursive,
+                })
+            }
+            TypeDetails::Tuple(type_ids) => {
+                let values = type_ids
+                    .map(|type_id| self.value_strategy(&type_id, None, ctx))
+                    .collect::<Vec<_>>();
+                let recursive = values.iter().any(|value| value.recursive);
+                let tokens = values.into_iter().map(|value| value.tokens);
+                ValueStrategy {
+                    tokens: quote! { (#(#tokens,)*) },
+                    recursive,
+                }
+            }
+            TypeDetails::Unit => leaf(quote! { ::proptest::strategy::Just(()) }),
+
+            TypeDetails::String => leaf(
+                enumerated_strategy(resolved, &ident).unwrap_or_else(|| string_strategy(resolved)),
+            ),
+            TypeDetails::Builtin(name) => leaf(
+                enumerated_strategy(resolved, &ident)
+                    .unwrap_or_else(|| builtin_strategy(name, &ident, resolved)),
+            ),
+        };
+        strategy
+    }
+}
+
+/// Resolve references, and subschemas that merely decorate a single schema,
+/// to the schema that describes a value.
+fn resolve<'a>(
+    schema: &'a ReferenceOr<Schema>,
+    components: &'a Option<Components>,
+) -> Option<&'a Schema> {
+    if let ReferenceOr::Reference { reference } = schema {
+        let name = reference.strip_prefix("#/components/schemas/")?;
+        components.as_ref()?.schemas.get(name)?;
+    }
+    let schema = schema.item(components).ok()?;
+    match &schema.schema_kind {
+        SchemaKind::AllOf { all_of: subschemas }
+        | SchemaKind::OneOf { one_of: subschemas }
+        | SchemaKind::AnyOf { any_of: subschemas }
+            if subschemas.len() == 1 =>
+        {
+            resolve(&subschemas[0], components)
+        }
+        _ => Some(schema),
+    }
+}
+
+/// The schema of the property of an object schema (or of the schemas it
+/// merges with `allOf`) that has the given Rust name.
+fn property_schema(
+    schema: &Schema,
+    rust_name: &str,
+    components: &Option<Components>,
+) -> Option<ReferenceOr<Schema>> {
+    match &schema.schema_kind {
+        SchemaKind::Type(Type::Object(object)) => object
+            .properties
+            .iter()
+            .find(|(name, _)| sanitize(name, Case::Snake) == rust_name)
+            .map(|(_, schema)| schema.clone().unbox()),
+        SchemaKind::AllOf { all_of } => all_of.iter().find_map(|subschema| {
+            property_schema(resolve(subschema, components)?, rust_name, components)
+        }),
+        _ => None,
+    }
+}
+
+/// Whether a newtype generated for the schema constrains the values of its
+/// inner type, and so is converted from it fallibly. Without the schema,
+/// that's assumed.
+fn is_constrained(schema: Option<&Schema>) -> bool {
+    let Some(schema) = schema else {
+        return true;
+    };
+    match &schema.schema_kind {
+        SchemaKind::Type(Type::String(string)) => {
+            string.pattern.is_some()
+                || string.min_length.is_some()
+                || string.max_length.is_some()
+                || !string.enumeration.is_empty()
+        }
+        SchemaKind::Type(Type::Integer(integer)) => !integer.enumeration.is_empty(),
+        SchemaKind::Type(Type::Number(number)) => !number.enumeration.is_empty(),
+        SchemaKind::Type(Type::Boolean(boolean)) => !boolean.enumeration.is_empty(),
+        SchemaKind::Not { .. } => true,
+        _ => false,
+    }
+}
+
+fn items_schema(schema: &Schema) -> Option<ReferenceOr<Schema>> {
+    match &schema.schema_kind {
+        SchemaKind::Type(Type::Array(array)) => {
+            array.items.as_ref().map(|items| items.clone().unbox())
+        }
+        _ => None,
+    }
+}
+
+fn additional_properties_schema(schema: &Schema) -> Option<ReferenceOr<Schema>> {
+    match &schema.schema_kind {
+        SchemaKind::Type(Type::Object(object)) => match &object.additional_properties {
+            Some(AdditionalProperties::Schema(schema)) => Some(schema.as_ref().clone()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The range of sizes of generated collections: at least the minimum and a
+/// few more, within the maximum.
+fn size_range(min: Option<usize>, max: Option<usize>) -> (usize, usize) {
+    let lo = min.unwrap_or(0);
+    let hi = max.unwrap_or(usize::MAX).min(lo + 4).max(lo);
+    (lo, hi)
+}
+
+/// Where recursion is unbounded, generate the default (empty) value beyond
+/// the maximum depth.
+fn contain(value: ValueStrategy) -> ValueStrategy {
+    if !value.recursive {
+        return value;
+    }
+    let tokens = receiver(&value.tokens);
+    ValueStrategy {
+        tokens: quote! {
+            if depth < progenitor_client::arbitrary::MAX_DEPTH {
+                #tokens.boxed()
+            } else {
+                ::proptest::strategy::Just(::std::default::Default::default()).boxed()
+            }
+        },
+        recursive: false,
+    }
+}
+
+/// Combine the strategies for the named fields of a value into a strategy
+/// that constructs it. A tuple strategy has at most 12 elements, so large
+/// numbers of fields are nested.
+fn group(fields: Vec<(proc_macro2::Ident, TokenStream)>, construct: TokenStream) -> TokenStream {
+    if fields.is_empty() {
+        return quote! { ::proptest::strategy::Just(#construct) };
+    }
+
+    fn nest(mut items: Vec<(TokenStream, TokenStream)>) -> (TokenStream, TokenStream) {
+        while items.len() > 10 {
+            let mut chunks = Vec::new();
+            while !items.is_empty() {
+                let rest = items.split_off(items.len().min(10));
+                let chunk = std::mem::replace(&mut items, rest);
+                let patterns = chunk.iter().map(|(pattern, _)| pattern);
+                let strategies = chunk.iter().map(|(_, strategy)| strategy);
+                chunks.push((quote! { (#(#patterns,)*) }, quote! { (#(#strategies,)*) }));
+            }
+            items = chunks;
+        }
+        let patterns = items.iter().map(|(pattern, _)| pattern);
+        let strategies = items.iter().map(|(_, strategy)| strategy);
+        (quote! { (#(#patterns,)*) }, quote! { (#(#strategies,)*) })
+    }
+
+    if let [(name, strategy)] = fields.as_slice() {
+        let strategy = receiver(strategy);
+        return quote! {
+            #strategy.prop_map(|#name| #construct)
+        };
+    }
+
+    let (pattern, strategy) = nest(
+        fields
+            .into_iter()
+            .map(|(name, strategy)| (quote! { #name }, strategy))
+            .collect(),
+    );
+    quote! {
+        #strategy.prop_map(|#pattern| #construct)
+    }
+}
+
+/// A strategy that selects among the values of a schema's `enum`.
+fn enumerated_strategy(schema: Option<&Schema>, ident: &TokenStream) -> Option<TokenStream> {
+    let values = match &schema?.schema_kind {
+        SchemaKind::Type(Type::String(t)) => enum_json(&t.enumeration),
+        SchemaKind::Type(Type::Integer(t)) => enum_json(&t.enumeration),
+        SchemaKind::Type(Type::Number(t)) => enum_json(&t.enumeration),
+        SchemaKind::Type(Type::Boolean(t)) => enum_json(&t.enumeration),
+        _ => return None,
+    };
+    if values.is_empty() {
+        return None;
+    }
+    Some(quote! {
+        ::proptest::sample::select(vec![#(#values),*])
+            .prop_filter_map("value must be of the type", |value| {
+                ::serde_json::from_str::<#ident>(value).ok()
+            })
+    })
+}
+
+/// The strategy for a string. Strings are generated from a pattern that the
+/// `regex` crate can represent; for one it can't, such as one with lookaround
+/// assertions, the strategy is a single string known to match it, or, if
+/// none is found, any string. Values of a type with such a pattern may then
+/// be rejected.
+fn string_strategy(schema: Option<&Schema>) -> TokenStream {
+    match schema.map(|schema| &schema.schema_kind) {
+        Some(SchemaKind::Type(Type::String(string)))
+            if string
+                .pattern
+                .as_ref()
+                .is_some_and(|pattern| regex_syntax::Parser::new().parse(pattern).is_err()) =>
+        {
+            let pattern = string.pattern.as_ref().unwrap();
+            match pattern_example(pattern, None, string.min_length, string.max_length) {
+                Some(example) => quote! {
+                    ::proptest::strategy::Just(#example.to_string())
+                },
+                None => quote! { ::proptest::arbitrary::any::<::std::string::String>() },
+            }
+        }
+        Some(SchemaKind::Type(Type::String(string)))
+            if string.pattern.is_some()
+                || string.min_length.is_some()
+                || string.max_length.is_some() =>
+        {
+            let pattern = option_tokens(string.pattern.as_ref());
+            let min_length = option_tokens(string.min_length);
+            let max_length = option_tokens(string.max_length);
+            quote! {
+                progenitor_client::arbitrary::string(#pattern, #min_length, #max_length)
+            }
+        }
+        _ => quote! { ::proptest::arbitrary::any::<::std::string::String>() },
+    }
+}
+
+fn option_tokens<T: quote::ToTokens>(value: Option<T>) -> TokenStream {
+    match value {
+        Some(value) => quote! { Some(#value) },
+        None => quote! { None },
+    }
+}
+
+fn builtin_strategy(name: &str, ident: &TokenStream, schema: Option<&Schema>) -> TokenStream {
+    let integer = match name {
+        "i8" => Some(("i8", i8::MIN as i128, i8::MAX as i128)),
+        "i16" => Some(("i16", i16::MIN as i128, i16::MAX as i128)),
+        "i32" => Some(("i32", i32::MIN as i128, i32::MAX as i128)),
+        "i64" => Some(("i64", i64::MIN as i128, i64::MAX as i128)),
+        "u8" | "::std::num::NonZeroU8" => Some(("u8", 0, u8::MAX as i128)),
+        "u16" | "::std::num::NonZeroU16" => Some(("u16", 0, u16::MAX as i128)),
+        "u32" | "::std::num::NonZeroU32" => Some(("u32", 0, u32::MAX as i128)),
+        "u64" | "::std::num::NonZeroU64" => Some(("u64", 0, u64::MAX as i128)),
+        _ => None,
+    };
+    if let Some((primitive, type_min, type_max)) = integer {
+        let nonzero = name.starts_with("::std::num::NonZero");
+        return integer_strategy(primitive, type_min, type_max, nonzero, ident, schema);
+    }
+
+    match name {
+        "f64" | "f32" => float_strategy(name, schema),
+        "::serde_json::Value" => quote! { progenitor_client::arbitrary::json_value() },
+        "::uuid::Uuid" => quote! {
+            ::proptest::arbitrary::any::<u128>().prop_map(::uuid::Uuid::from_u128)
+        },
+        // Times from the epoch to the end of the year 9999, which have
+        // RFC 3339 representations.
+        "::chrono::DateTime<::chrono::offset::Utc>" => quote! {
+            (0i64..=253_402_300_799i64, 0u32..1_000_000_000u32).prop_map(|(secs, nanos)| {
+                ::chrono::DateTime::from_timestamp(secs, nanos).unwrap()
+            })
+        },
+        "::chrono::naive::NaiveDate" => quote! {
+            (1i32..=3_652_059i32).prop_map(|days| {
+                ::chrono::naive::NaiveDate::from_num_days_from_ce_opt(days).unwrap()
+            })
+        },
+        _ => quote! { ::proptest::arbitrary::any::<#ident>() },
+    }
+}
+
+fn integer_strategy(
+    primitive: &str,
+    type_min: i128,
+    type_max: i128,
+    nonzero: bool,
+    ident: &TokenStream,
+    schema: Option<&Schema>,
+) -> TokenStream {
+    let (min, max) = match schema.map(|schema| &schema.schema_kind) {
+        Some(SchemaKind::Type(Type::Integer(integer))) => (
+            integer
+                .minimum
+                .map(|min| min as i128 + integer.exclusive_minimum as i128),
+            integer
+                .maximum
+                .map(|max| max as i128 - integer.exclusive_maximum as i128),
+        ),
+        Some(SchemaKind::Type(Type::Number(number))) => (
+            number.minimum.map(|min| {
+                let min = min.ceil() as i128;
+                min + (number.exclusive_minimum && min as f64 == number.minimum.unwrap()) as i128
+            }),
+            number.maximum.map(|max| {
+                let max = max.floor() as i128;
+                max - (number.exclusive_maximum && max as f64 == number.maximum.unwrap()) as i128
+            }),
+        ),
+        _ => (None, None),
+    };
+
+    let type_min = if nonzero { 1 } else { type_min };
+    let lo = min.unwrap_or(type_min).clamp(type_min, type_max);
+    let hi = max.unwrap_or(type_max).clamp(type_min, type_max);
+
+    if lo > hi || (lo == type_min && hi == type_max) {
+        // Contradictory bounds can't be satisfied, and across the full range
+        // of the type any value will do.
+        let ident = if nonzero {
+            ident.clone()
+        } else {
+            let primitive = format_ident!("{}", primitive);
+            quote! { #primitive }
+        };
+        return quote! { ::proptest::arbitrary::any::<#ident>() };
+    }
+
+    let lo: TokenStream = format!("{}{}", lo, primitive).parse().unwrap();
+    let hi: TokenStream = format!("{}{}", hi, primitive).parse().unwrap();
+    if nonzero {
+        quote! {
+            (#lo..=#hi).prop_filter_map("value must not be zero", #ident::new)
+        }
+    } else {
+        quote! { #lo..=#hi }
+    }
+}
+
+fn float_strategy(primitive: &str, schema: Option<&Schema>) -> TokenStream {
+    let (min, max, exclusive_min, exclusive_max) = match schema.map(|schema| &schema.schema_kind) {
+        Some(SchemaKind::Type(Type::Number(number))) => (
+            number.minimum,
+            number.maximum,
+            number.exclusive_minimum,
+            number.exclusive_maximum,
+        ),
+        Some(SchemaKind::Type(Type::Integer(integer))) => (
+            integer.minimum.map(|min| min as f64),
+            integer.maximum.map(|max| max as f64),
+            integer.exclusive_minimum,
+            integer.exclusive_maximum,
+        ),
+        _ => (None, None, false, false),
+    };
+
+    // Contradictory bounds can't be satisfied, and without bounds any value
+    // will do.
+    let contradictory = match (min, max) {
+        (Some(min), Some(max)) => min > max || (min == max && (exclusive_min || exclusive_max)),
+        _ => false,
+    };
+    if contradictory || (min.is_none() && max.is_none()) {
+        return if primitive == "f32" {
+            quote! { progenitor_client::arbitrary::finite_f32() }
+        } else {
+            quote! { progenitor_client::arbitrary::finite_f64() }
+        };
+    }
+
+    // A range with a missing bound spans a million either side of zero, or
+    // of the other bound.
+    const SPAN: f64 = 1_000_000.0;
+    let lo = min.unwrap_or_else(|| max.unwrap().min(0.0) - SPAN);
+    let hi = max.unwrap_or_else(|| lo.max(0.0) + SPAN);
+    let literal = |value: f64| {
+        if primitive == "f32" {
+            Literal::f32_suffixed(value as f32)
+        } else {
+            Literal::f64_suffixed(value)
+        }
+    };
+    let lo_lit = literal(lo);
+    let hi_lit = literal(hi);
+
+    let mut tokens = quote! { #lo_lit..=#hi_lit };
+    if exclusive_min && min.is_some() {
+        let receiver = receiver(&tokens);
+        tokens = quote! {
+            #receiver.prop_filter("value must exceed the minimum", |value| *value > #lo_lit)
+        };
+    }
+    if exclusive_max && max.is_some() {
+        let receiver = receiver(&tokens);
+        tokens = quote! {
+            #receiver.prop_filter("value must be below the maximum", |value| *value < #hi_lit)
+        };
+    }
+    tokens
+}
+
+/// A strategy on which methods may be called: a range is parenthesized, as
+/// the method would otherwise apply to its upper bound.
+fn receiver(tokens: &TokenStream) -> TokenStream {
+    let is_range = tokens.clone().into_iter().any(|token| {
+        matches!(token, TokenTree::Punct(punct) if punct.as_char() == '.' && punct.spacing() == Spacing::Joint)
+    });
+    if is_range {
+        quote! { (#tokens) }
+    } else {
+        tokens.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use openapiv3::Schema;
+    use quote::quote;
+
+    use super::{float_strategy, integer_strategy};
+
+    #[test]
+    fn contradictory_bounds() {
+        let trials = [
+            serde_json::json!({ "type": "number", "minimum": 2.0, "maximum": 1.0 }),
+            serde_json::json!({
+                "type": "number",
+                "minimum": 1.0,
+                "maximum": 1.0,
+                "exclusiveMaximum": true
+            }),
+        ];
+        for schema in trials {
+            let schema = serde_json::from_value::<Schema>(schema).unwrap();
+            assert_eq!(
+                float_strategy("f64", Some(&schema)).to_string(),
+                quote! { progenitor_client::arbitrary::finite_f64() }.to_string(),
+            );
+            assert_eq!(
+                integer_strategy(
+                    "i32",
+                    i32::MIN as i128,
+                    i32::MAX as i128,
+                    false,
+                    &quote! { i32 },
+                    Some(&schema)
+                )
+                .to_string(),
+                quote! { ::proptest::arbitrary::any::<i32>() }.to_string(),
+            );
+        }
+    }
+}
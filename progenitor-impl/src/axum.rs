@@ -0,0 +1,207 @@
+// Copyright 2025 Oxide Computer Company
+
+//! Generation of an axum handler trait and router
+
+use openapiv3::OpenAPI;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+    method::OperationMethod,
+    server::{server_doc, ServerBody, ServerParams, ServerResponse, ServerResponses},
+    Generator, Result,
+};
+
+struct AxumOp {
+    /// Path and query parameter structs.
+    params: TokenStream,
+    handler: TokenStream,
+    route: TokenStream,
+}
+
+impl Generator {
+    /// Generate an axum handler trait with a method for each operation, and
+    /// a function that builds an `axum::Router` dispatching to it.
+    ///
+    /// The `crate_path` parameter should be a valid Rust path corresponding to
+    /// the SDK whose `types` module the handlers use. This can include `::`
+    /// and instances of `-` in the crate name should be converted to `_`.
+    ///
+    /// Successful responses must have a standard status code (e.g. not 299).
+    pub fn axum(&mut self, spec: &OpenAPI, crate_path: &str) -> Result<TokenStream> {
+        self.uses_async_trait = true;
+        let raw_methods = self.operation_methods(spec)?;
+
+        let ops = raw_methods
+            .iter()
+            .map(|method| self.axum_method(method))
+            .collect::<Result<Vec<_>>>()?;
+
+        let params = ops.iter().map(|op| &op.params);
+        let handlers = ops.iter().map(|op| &op.handler);
+        let routes = ops.iter().map(|op| &op.route);
+
+        let crate_path = syn::TypePath {
+            qself: None,
+            path: syn::parse_str(crate_path)
+                .unwrap_or_else(|_| panic!("{} is not a valid identifier", crate_path)),
+        };
+
+        let code = quote! {
+            #[allow(unused_imports)]
+            use #crate_path::types;
+
+            pub mod parameters {
+
+                //! Path and query parameters of each operation, as extracted
+                //! by the handlers of [`Api`](super::Api).
+
+                #[allow(unused_imports)]
+                use #crate_path::types;
+
+                #(#params)*
+            }
+
+            /// An axum service with a handler for each operation.
+            ///
+            /// Successful responses are sent with the status code from the
+            /// API description. A typed error is sent as JSON with the
+            /// status code the handler returns with it; other responses are
+            /// sent as the handler builds them.
+            #[progenitor_client::async_trait]
+            pub trait Api: Send + Sync + 'static {
+                #(#handlers)*
+            }
+
+            /// Build an `axum::Router` that routes each operation to its
+            /// handler in `api`.
+            pub fn router<A: Api>(api: std::sync::Arc<A>) -> axum::Router {
+                use axum::response::IntoResponse;
+
+                axum::Router::new()
+                    #(#routes)*
+                    .with_state(api)
+            }
+        };
+        Ok(code)
+    }
+
+    fn axum_method(&mut self, method: &OperationMethod) -> Result<AxumOp> {
+        let op = format_ident!("{}", method.operation_id);
+        let ServerParams {
+            params,
+            path: path_struct,
+            query: query_struct,
+            body,
+            headers,
+        } = self.server_params(method, false, false)?;
+
+        // Extractors, in the order axum requires: the body must be last.
+        let mut args = Vec::new();
+        if let Some(ident) = path_struct {
+            args.push((
+                format_ident!("path"),
+                quote! { axum::extract::Path<parameters::#ident> },
+            ));
+        }
+        if let Some(ident) = query_struct {
+            args.push((
+                format_ident!("query"),
+                quote! { axum::extract::Query<parameters::#ident> },
+            ));
+        }
+        if headers {
+            args.push((format_ident!("headers"), quote! { axum::http::HeaderMap }));
+        }
+        match body {
+            Some(ServerBody::Json(typ)) => {
+                args.push((format_ident!("body"), quote! { axum::Json<#typ> }));
+            }
+            Some(ServerBody::Form(typ)) => {
+                args.push((format_ident!("body"), quote! { axum::Form<#typ> }));
+            }
+            Some(ServerBody::Untyped) => {
+                args.push((format_ident!("body"), quote! { axum::body::Bytes }));
+            }
+            None => {}
+        }
+        let arg_names = args.iter().map(|(name, _)| name).collect::<Vec<_>>();
+        let arg_types = args.iter().map(|(_, typ)| typ).collect::<Vec<_>>();
+
+        let ServerResponses {
+            status,
+            success,
+            error,
+        } = self.server_responses(method)?;
+        let (success_type, success_pat, success_response) = match success {
+            ServerResponse::Json(typ) => (
+                typ,
+                quote! { value },
+                quote! {
+                    (
+                        axum::http::StatusCode::#status,
+                        axum::Json(value),
+                    )
+                        .into_response()
+                },
+            ),
+            ServerResponse::Empty => (
+                quote! { () },
+                quote! { () },
+                quote! {
+                    axum::http::StatusCode::#status.into_response()
+                },
+            ),
+            ServerResponse::Raw => (
+                quote! { axum::response::Response },
+                quote! { response },
+                quote! { response },
+            ),
+        };
+        let (error_type, error_pat, error_response) = match error {
+            ServerResponse::Json(typ) => (
+                quote! { (axum::http::StatusCode, #typ) },
+                quote! { (status, error) },
+                quote! { (status, axum::Json(error)).into_response() },
+            ),
+            ServerResponse::Empty | ServerResponse::Raw => (
+                quote! { axum::response::Response },
+                quote! { response },
+                quote! { response },
+            ),
+        };
+
+        let doc = server_doc(method);
+
+        let handler = quote! {
+            #[doc = #doc]
+            async fn #op(
+                &self,
+                #(#arg_names: #arg_types,)*
+            ) -> Result<#success_type, #error_type>;
+        };
+
+        let route_path = method.path.as_axum_route()?;
+        let routing = format_ident!("{}", method.method.as_str());
+        let route = quote! {
+            .route(
+                #route_path,
+                axum::routing::#routing(
+                    |axum::extract::State(api): axum::extract::State<std::sync::Arc<A>>,
+                     #(#arg_names: #arg_types,)*| async move {
+                        match api.#op(#(#arg_names,)*).await {
+                            Ok(#success_pat) => #success_response,
+                            Err(#error_pat) => #error_response,
+                        }
+                    },
+                ),
+            )
+        };
+
+        Ok(AxumOp {
+            params,
+            handler,
+            route,
+        })
+    }
+}
@@ -9,12 +9,17 @@ use quote::{format_ident, quote};
 use typify::{Type, TypeEnumVariant, TypeSpaceImpl, TypeStructPropInfo};
 
 use crate::{
-    method::{OperationParameterKind, OperationParameterType, OperationResponseStatus},
+    method::{
+        BodyContentType, OperationParameterKind, OperationParameterType, OperationResponseStatus,
+    },
     to_schema::ToSchema,
     util::{sanitize, Case},
-    validate_openapi, Generator, Result,
+    validate_openapi, Error, Generator, Result,
 };
 
+/// Flags that every generated subcommand accepts.
+const CLI_FLAGS: [&str; 2] = ["progenitor-dry-run", "progenitor-print-curl"];
+
 struct CliOperation {
     cli_fn: TokenStream,
     execute_fn: TokenStream,
@@ -54,7 +59,7 @@ impl Generator {
         let methods = raw_methods
             .iter()
             .map(|method| self.cli_method(method))
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>>>()?;
 
         let cli_ops = methods.iter().map(|op| &op.cli_fn);
         let execute_ops = methods.iter().map(|op| &op.execute_fn);
@@ -144,6 +149,15 @@ impl Generator {
                 where
                     T: schemars::JsonSchema + serde::Serialize + std::fmt::Debug;
 
+                /// Output for `--progenitor-dry-run`.
+                fn dry_run(&self, request: &reqwest::Request) {
+                    print!("{}", progenitor_client::format_request(request));
+                }
+                /// Output for `--progenitor-print-curl`.
+                fn print_curl(&self, request: &reqwest::Request) {
+                    println!("{}", progenitor_client::curl_command(request));
+                }
+
                 #(#trait_ops)*
             }
 
@@ -167,11 +181,11 @@ impl Generator {
         Ok(code)
     }
 
-    fn cli_method(&mut self, method: &crate::method::OperationMethod) -> CliOperation {
+    fn cli_method(&mut self, method: &crate::method::OperationMethod) -> Result<CliOperation> {
         let CliArg {
             parser: parser_args,
             consumer: consumer_args,
-        } = self.cli_method_args(method);
+        } = self.cli_method_args(method)?;
 
         let about = method.summary.as_ref().map(|summary| {
             quote! {
@@ -192,6 +206,18 @@ impl Generator {
             {
                 ::clap::Command::new("")
                 #parser_args
+                .arg(
+                    ::clap::Arg::new("progenitor-dry-run")
+                        .long("progenitor-dry-run")
+                        .action(::clap::ArgAction::SetTrue)
+                        .help("Print the request rather than sending it")
+                )
+                .arg(
+                    ::clap::Arg::new("progenitor-print-curl")
+                        .long("progenitor-print-curl")
+                        .action(::clap::ArgAction::SetTrue)
+                        .help("Print an equivalent curl command rather than sending the request")
+                )
                 #about
                 #long_about
             }
@@ -225,6 +251,42 @@ impl Generator {
                             }
                         }
                     }
+                    // Each event (or line) is output as an item in a list.
+                    crate::method::OperationResponseKind::EventStream(ref type_id)
+                    | crate::method::OperationResponseKind::JsonLines(ref type_id)
+                    | crate::method::OperationResponseKind::JsonArray {
+                        item: ref type_id, ..
+                    } => {
+                        let event_type = self.type_space.get_type(type_id).unwrap().ident();
+                        let item = match success_kind {
+                            crate::method::OperationResponseKind::EventStream(_) => {
+                                quote! { &event.data }
+                            }
+                            _ => quote! { &event },
+                        };
+                        quote! {
+                            {
+                                self.config.list_start::<#event_type>();
+
+                                let mut stream = r.into_inner();
+                                loop {
+                                    match futures::TryStreamExt::try_next(&mut stream).await {
+                                        Err(r) => {
+                                            self.config.list_end_error(&r);
+                                            return Err(anyhow::Error::new(r))
+                                        }
+                                        Ok(None) => {
+                                            self.config.list_end_success::<#event_type>();
+                                            return Ok(());
+                                        }
+                                        Ok(Some(event)) => {
+                                            self.config.list_item(#item);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
                     crate::method::OperationResponseKind::Raw
                     | crate::method::OperationResponseKind::Upgrade => {
                         quote! {
@@ -253,6 +315,11 @@ impl Generator {
                             }
                         }
                     }
+                    crate::method::OperationResponseKind::EventStream(_)
+                    | crate::method::OperationResponseKind::JsonLines(_)
+                    | crate::method::OperationResponseKind::JsonArray { .. } => {
+                        return Err(streaming_unsupported(method, "error responses"));
+                    }
                 };
 
                 quote! {
@@ -274,6 +341,11 @@ impl Generator {
                     crate::method::OperationResponseKind::None => quote! { () },
                     crate::method::OperationResponseKind::Raw => todo!(),
                     crate::method::OperationResponseKind::Upgrade => todo!(),
+                    crate::method::OperationResponseKind::EventStream(_)
+                    | crate::method::OperationResponseKind::JsonLines(_)
+                    | crate::method::OperationResponseKind::JsonArray { .. } => {
+                        return Err(streaming_unsupported(method, "paginated responses"));
+                    }
                 };
                 let error_output = match error_kind {
                     crate::method::OperationResponseKind::Type(_)
@@ -293,6 +365,11 @@ impl Generator {
                             }
                         }
                     }
+                    crate::method::OperationResponseKind::EventStream(_)
+                    | crate::method::OperationResponseKind::JsonLines(_)
+                    | crate::method::OperationResponseKind::JsonArray { .. } => {
+                        return Err(streaming_unsupported(method, "error responses"));
+                    }
                 };
                 quote! {
                     self.config.list_start::<#success_type>();
@@ -333,6 +410,20 @@ impl Generator {
                 // Call the override function.
                 self.config.#fn_name(matches, &mut request)?;
 
+                // Print the prepared request instead of sending it.
+                let dry_run = matches.get_flag("progenitor-dry-run");
+                let print_curl = matches.get_flag("progenitor-print-curl");
+                if dry_run || print_curl {
+                    let request = request.into_request()?;
+                    if dry_run {
+                        self.config.dry_run(&request);
+                    }
+                    if print_curl {
+                        self.config.print_curl(&request);
+                    }
+                    return Ok(());
+                }
+
                 #execute_and_output
             }
         };
@@ -351,14 +442,14 @@ impl Generator {
             }
         };
 
-        CliOperation {
+        Ok(CliOperation {
             cli_fn,
             execute_fn,
             execute_trait,
-        }
+        })
     }
 
-    fn cli_method_args(&self, method: &crate::method::OperationMethod) -> CliArg {
+    fn cli_method_args(&self, method: &crate::method::OperationMethod) -> Result<CliArg> {
         let mut args = CliOperationArgs::default();
 
         let first_page_required_set = method
@@ -397,6 +488,14 @@ impl Generator {
 
             let arg_name = param.name.to_kebab_case();
 
+            if CLI_FLAGS.contains(&arg_name.as_str()) {
+                return Err(Error::UnexpectedFormat(format!(
+                    "parameter `{}` of operation `{}` collides with the CLI's \
+                    `--{}` flag",
+                    param.api_name, method.operation_id, arg_name,
+                )));
+            }
+
             // There should be no conflicting path or query parameters.
             assert!(!args.has_arg(&arg_name));
 
@@ -432,6 +531,15 @@ impl Generator {
                 // need **some** input so we shouldn't just ignore it... as we
                 // are currently...
                 OperationParameterType::RawBody => None,
+                // TODO likewise for JSON Lines bodies, which are streams.
+                OperationParameterType::Type(_)
+                    if matches!(
+                        param.kind,
+                        OperationParameterKind::Body(BodyContentType::JsonLines(_))
+                    ) =>
+                {
+                    None
+                }
 
                 OperationParameterType::Type(body_type_id) => Some(body_type_id),
             });
@@ -521,7 +629,7 @@ impl Generator {
             #body_json_consumer
         };
 
-        CliArg { parser, consumer }
+        Ok(CliArg { parser, consumer })
     }
 
     fn cli_method_body_arg(&self, args: &mut CliOperationArgs, prop_info: TypeStructPropInfo<'_>) {
@@ -559,7 +667,9 @@ impl Generator {
         let scalar = prop_type.has_impl(TypeSpaceImpl::FromStr);
 
         let prop_name = name.to_kebab_case();
-        if scalar && !args.has_arg(&prop_name) {
+        // Properties that collide with other arguments can only be set with
+        // `--json-body`.
+        if scalar && !args.has_arg(&prop_name) && !CLI_FLAGS.contains(&prop_name.as_str()) {
             let volitionality = if required {
                 Volitionality::RequiredIfNoBody
             } else {
@@ -605,6 +715,14 @@ impl Generator {
     }
 }
 
+/// The CLI can't output streaming `what` of `method`.
+fn streaming_unsupported(method: &crate::method::OperationMethod, what: &str) -> Error {
+    Error::UnexpectedFormat(format!(
+        "streaming {} of operation `{}` aren't supported by the CLI",
+        what, method.operation_id,
+    ))
+}
+
 enum Volitionality {
     Optional,
     Required,
@@ -0,0 +1,178 @@
+// Copyright 2025 Oxide Computer Company
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+    method::{
+        make_doc_comment, BodyContentType, OperationMethod, OperationParameterKind,
+        OperationParameterType, OperationResponseStatus,
+    },
+    util::{sanitize, unique_ident_from, Case},
+    Error, Generator, InterfaceStyle, Result,
+};
+
+impl Generator {
+    /// Generate the `ClientApi` trait with a method for each operation, and
+    /// its implementation for `Client`.
+    pub(crate) fn client_api(&mut self, methods: &[OperationMethod]) -> Result<TokenStream> {
+        self.uses_async_trait = true;
+
+        let mut trait_methods = Vec::new();
+        let mut impl_methods = Vec::new();
+
+        for method in methods {
+            let op = format_ident!("{}", method.operation_id);
+            let names = method
+                .params
+                .iter()
+                .map(|param| format_ident!("{}", param.name))
+                .collect::<Vec<_>>();
+
+            // Parameters are as for positional methods, except that generic
+            // bodies are concrete so that the trait is object safe.
+            let mut params = Vec::new();
+            let mut setters = Vec::new();
+            let mut by_ref_params = false;
+            for (param, name) in method.params.iter().zip(&names) {
+                let (typ, by_ref) = match (&param.typ, &param.kind) {
+                    (
+                        OperationParameterType::Type(type_id),
+                        OperationParameterKind::Body(BodyContentType::JsonLines(_)),
+                    ) => {
+                        self.uses_futures = true;
+                        let item = self.type_space.get_type(type_id)?.ident();
+                        (
+                            quote! {
+                                ::std::pin::Pin<Box<
+                                    dyn futures::Stream<Item = #item> + Send + 'static
+                                >>
+                            },
+                            false,
+                        )
+                    }
+                    (OperationParameterType::Type(type_id), _) => {
+                        // Lifetimes are named so that the trait may be mocked.
+                        let typ = self
+                            .type_space
+                            .get_type(type_id)?
+                            .parameter_ident_with_lifetime("a");
+                        let by_ref = typ.to_string().starts_with('&');
+                        if param.kind.is_optional() {
+                            (quote! { Option<#typ> }, by_ref)
+                        } else {
+                            (typ, by_ref)
+                        }
+                    }
+                    (
+                        OperationParameterType::RawBody,
+                        OperationParameterKind::Body(BodyContentType::OctetStream),
+                    ) => (quote! { progenitor_client::UploadBody }, false),
+                    (
+                        OperationParameterType::RawBody,
+                        OperationParameterKind::Body(BodyContentType::Text(_)),
+                    ) => (quote! { String }, false),
+                    (OperationParameterType::RawBody, _) => {
+                        return Err(Error::InternalError(format!(
+                            "unexpected raw body for {}",
+                            method.operation_id,
+                        )))
+                    }
+                };
+                params.push(quote! { #name: #typ });
+
+                // Builders take owned values.
+                by_ref_params |= by_ref;
+                let value = if by_ref {
+                    quote! { #name.to_owned() }
+                } else {
+                    quote! { #name }
+                };
+                setters.push((param.kind.is_optional(), name, value));
+            }
+
+            let (_, success_kind) =
+                self.extract_responses(method, OperationResponseStatus::is_success_or_default);
+            let (_, error_kind) =
+                self.extract_responses(method, OperationResponseStatus::is_error_or_default);
+            let success = success_kind.into_tokens(&self.type_space);
+            let error = error_kind.into_tokens(&self.type_space);
+            let doc_comment = make_doc_comment(method);
+
+            let lifetime = by_ref_params.then(|| quote! { <'a> });
+            let sig = quote! {
+                async fn #op #lifetime(
+                    &self,
+                    #(#params),*
+                ) -> Result<ResponseValue<#success>, Error<#error>>
+            };
+            trait_methods.push(quote! {
+                #[doc = #doc_comment]
+                #sig;
+            });
+
+            let body = match self.settings.interface {
+                InterfaceStyle::Positional => quote! {
+                    Client::#op(self, #(#names),*).await
+                },
+                InterfaceStyle::Builder => {
+                    let request = unique_ident_from("request", &names);
+                    let struct_ident =
+                        format_ident!("{}", sanitize(&method.operation_id, Case::Pascal));
+                    let required = setters
+                        .iter()
+                        .filter(|(optional, _, _)| !optional)
+                        .map(|(_, name, value)| quote! { .#name(#value) });
+                    let optional = setters.iter().filter(|(optional, _, _)| *optional).map(
+                        |(_, name, value)| {
+                            quote! {
+                                if let Some(#name) = #name {
+                                    #request = #request.#name(#value);
+                                }
+                            }
+                        },
+                    );
+                    quote! {
+                        #[allow(unused_mut)]
+                        let mut #request = builder::#struct_ident::new(self) #(#required)*;
+                        #(#optional)*
+                        #request.send().await
+                    }
+                }
+            };
+            impl_methods.push(quote! {
+                #sig {
+                    #body
+                }
+            });
+        }
+
+        let attrs = self
+            .settings
+            .client_api_attrs
+            .iter()
+            .map(|attr| {
+                attr.parse::<TokenStream>()
+                    .map_err(|e| Error::InternalError(format!("invalid attribute {attr}: {e}")))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(quote! {
+            /// The operations of the API, implemented by [`Client`].
+            ///
+            /// Code that depends on this trait rather than on `Client` may be
+            /// tested with a fake or mock implementation.
+            #( #[#attrs] )*
+            #[progenitor_client::async_trait]
+            pub trait ClientApi {
+                #(#trait_methods)*
+            }
+
+            #[allow(clippy::all)]
+            #[progenitor_client::async_trait]
+            impl ClientApi for Client {
+                #(#impl_methods)*
+            }
+        })
+    }
+}
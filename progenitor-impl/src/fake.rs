@@ -0,0 +1,235 @@
+// Copyright 2025 Oxide Computer Company
+
+//! Generation of an in-process fake backend
+
+use openapiv3::OpenAPI;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+    method::OperationMethod,
+    server::{server_doc, ServerBody, ServerParams, ServerResponse, ServerResponses},
+    Generator, Result,
+};
+
+struct FakeOp {
+    /// Path and query parameter structs.
+    params: TokenStream,
+    handler: TokenStream,
+    dispatch: TokenStream,
+}
+
+impl Generator {
+    /// Generate a trait with a method for each operation, and middleware
+    /// that answers the requests of a client by calling an implementation of
+    /// it rather than sending them.
+    ///
+    /// The `crate_path` parameter should be a valid Rust path corresponding to
+    /// the SDK. This can include `::` and instances of `-` in the crate name
+    /// should be converted to `_`.
+    ///
+    /// Successful responses must have a standard status code (e.g. not 299).
+    pub fn fake(&mut self, spec: &OpenAPI, crate_path: &str) -> Result<TokenStream> {
+        self.uses_async_trait = true;
+        let raw_methods = self.operation_methods(spec)?;
+
+        let ops = raw_methods
+            .iter()
+            .map(|method| self.fake_method(method))
+            .collect::<Result<Vec<_>>>()?;
+
+        let params = ops.iter().map(|op| &op.params);
+        let handlers = ops.iter().map(|op| &op.handler);
+        let dispatches = ops.iter().map(|op| &op.dispatch);
+
+        let crate_path = syn::TypePath {
+            qself: None,
+            path: syn::parse_str(crate_path)
+                .unwrap_or_else(|_| panic!("{} is not a valid identifier", crate_path)),
+        };
+
+        let code = quote! {
+            #[allow(unused_imports)]
+            use #crate_path::types;
+
+            pub mod parameters {
+
+                //! Path and query parameters of each operation, as passed to
+                //! the methods of [`Api`](super::Api).
+
+                #[allow(unused_imports)]
+                use #crate_path::types;
+
+                #(#params)*
+            }
+
+            /// The operations of the API, as implemented by a fake backend.
+            ///
+            /// Successful responses are sent with the status code from the
+            /// API description. A typed error is sent as JSON with the
+            /// status code the method returns with it; other responses are
+            /// sent as the method builds them.
+            #[progenitor_client::async_trait]
+            pub trait Api: Send + Sync + 'static {
+                #(#handlers)*
+            }
+
+            /// Middleware that answers each request by calling the method of
+            /// `A` for its operation, without sending it.
+            ///
+            /// Attach it to a client with `with_middleware`, after any other
+            /// middleware.
+            pub struct Fake<A>(pub std::sync::Arc<A>);
+
+            impl<A: Api> Fake<A> {
+                /// Create middleware that calls `api`.
+                pub fn new(api: A) -> Self {
+                    Self(std::sync::Arc::new(api))
+                }
+            }
+
+            impl<A: Api> progenitor_client::Middleware for Fake<A> {
+                fn handle<'a>(
+                    &'a self,
+                    request: reqwest::Request,
+                    info: &'a progenitor_client::OperationInfo,
+                    _next: progenitor_client::Next<'a>,
+                ) -> progenitor_client::MiddlewareFuture<'a> {
+                    Box::pin(async move {
+                        let request = progenitor_client::FakeRequest::new(&request, info);
+                        match info.operation_id {
+                            #(#dispatches)*
+                            _ => Err(progenitor_client::Error::Custom(format!(
+                                "unknown operation {}",
+                                info.operation_id,
+                            ))),
+                        }
+                    })
+                }
+            }
+        };
+        Ok(code)
+    }
+
+    fn fake_method(&mut self, method: &OperationMethod) -> Result<FakeOp> {
+        let op = format_ident!("{}", method.operation_id);
+        let operation_id = &method.operation_id;
+        let ServerParams {
+            params,
+            path: path_struct,
+            query: query_struct,
+            body,
+            headers,
+        } = self.server_params(method, false, false)?;
+
+        let mut args = Vec::new();
+        if let Some(ident) = path_struct {
+            args.push((
+                format_ident!("path"),
+                quote! { parameters::#ident },
+                quote! { request.path()? },
+            ));
+        }
+        if let Some(ident) = query_struct {
+            args.push((
+                format_ident!("query"),
+                quote! { parameters::#ident },
+                quote! { request.query()? },
+            ));
+        }
+        if headers {
+            args.push((
+                format_ident!("headers"),
+                quote! { reqwest::header::HeaderMap },
+                quote! { request.headers() },
+            ));
+        }
+        match body {
+            Some(ServerBody::Json(typ)) => {
+                args.push((format_ident!("body"), typ, quote! { request.json()? }));
+            }
+            Some(ServerBody::Form(typ)) => {
+                args.push((format_ident!("body"), typ, quote! { request.form()? }));
+            }
+            Some(ServerBody::Untyped) => {
+                args.push((
+                    format_ident!("body"),
+                    quote! { Vec<u8> },
+                    quote! { request.bytes()? },
+                ));
+            }
+            None => {}
+        }
+        let arg_names = args.iter().map(|(name, _, _)| name).collect::<Vec<_>>();
+        let arg_types = args.iter().map(|(_, typ, _)| typ);
+        let arg_values = args.iter().map(|(_, _, value)| value);
+
+        let ServerResponses {
+            status,
+            success,
+            error,
+        } = self.server_responses(method)?;
+        let (success_type, success_pat, success_response) = match success {
+            ServerResponse::Json(typ) => (
+                typ,
+                quote! { value },
+                quote! {
+                    progenitor_client::fake_json_response(
+                        reqwest::StatusCode::#status,
+                        &value,
+                    )
+                },
+            ),
+            ServerResponse::Empty => (
+                quote! { () },
+                quote! { () },
+                quote! {
+                    progenitor_client::fake_empty_response(reqwest::StatusCode::#status)
+                },
+            ),
+            ServerResponse::Raw => (
+                quote! { reqwest::Response },
+                quote! { response },
+                quote! { Ok(response) },
+            ),
+        };
+        let (error_type, error_pat, error_response) = match error {
+            ServerResponse::Json(typ) => (
+                quote! { (reqwest::StatusCode, #typ) },
+                quote! { (status, error) },
+                quote! { progenitor_client::fake_json_response(status, &error) },
+            ),
+            ServerResponse::Empty | ServerResponse::Raw => (
+                quote! { reqwest::Response },
+                quote! { response },
+                quote! { Ok(response) },
+            ),
+        };
+
+        let doc = server_doc(method);
+
+        let handler = quote! {
+            #[doc = #doc]
+            async fn #op(
+                &self,
+                #(#arg_names: #arg_types,)*
+            ) -> Result<#success_type, #error_type>;
+        };
+
+        let dispatch = quote! {
+            #operation_id => {
+                #(let #arg_names = #arg_values;)*
+                match self.0.#op(#(#arg_names,)*).await {
+                    Ok(#success_pat) => #success_response,
+                    Err(#error_pat) => #error_response,
+                }
+            }
+        };
+
+        Ok(FakeOp {
+            params,
+            handler,
+            dispatch,
+        })
+    }
+}
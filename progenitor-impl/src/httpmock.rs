@@ -2,25 +2,39 @@
 
 //! Generation of mocking extensions for `httpmock`
 
-use openapiv3::OpenAPI;
+use openapiv3::{Components, OpenAPI, Operation, ReferenceOr, Schema, SchemaKind, Type};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
+use serde_json::Value;
 
 use crate::{
     method::{
-        BodyContentType, HttpMethod, OperationParameter, OperationParameterKind,
-        OperationParameterType, OperationResponse, OperationResponseStatus,
+        OperationMethod, OperationParameterKind, OperationResponseKind, OperationResponseStatus,
+    },
+    mock::{
+        MockOperation, MockParameterKind, MockResponse, MockResponseBody, MockResponseHeader,
+        MockResponseStatus, QueryShape,
     },
     to_schema::ToSchema,
-    util::{sanitize, Case},
+    util::{pattern_example, ReferenceOrExt},
     validate_openapi, Generator, Result,
 };
 
+/// How deeply to follow schemas when synthesizing an example, so that
+/// recursive types terminate.
+const EXAMPLE_DEPTH: usize = 16;
+
+/// The number of distinct predicates that may be given to the generated
+/// `*_matches` methods for JSON bodies.
+const BODY_PREDICATES: usize = 64;
+
 struct MockOp {
     when: TokenStream,
     when_impl: TokenStream,
     then: TokenStream,
     then_impl: TokenStream,
+    /// Whether the `When` wrapper matches bodies with a predicate.
+    body_predicates: bool,
 }
 
 impl Generator {
@@ -54,9 +68,22 @@ impl Generator {
             })
             .map(|(path, method, operation, path_parameters)| {
                 self.process_operation(operation, &spec.components, path, method, path_parameters)
+                    .map(|method| (operation, method))
             })
             .collect::<Result<Vec<_>>>()?;
 
+        let mock_all = if self.settings.httpmock_examples {
+            Some(self.httpmock_examples(&raw_methods, &spec.components)?)
+        } else {
+            None
+        };
+        let (mock_all_decl, mock_all_impl) = mock_all.unzip();
+
+        let raw_methods = raw_methods
+            .into_iter()
+            .map(|(_, method)| method)
+            .collect::<Vec<_>>();
+
         let methods = raw_methods
             .iter()
             .map(|method| self.httpmock_method(method))
@@ -70,6 +97,10 @@ impl Generator {
         let when_impl = methods.iter().map(|op| &op.when_impl).collect::<Vec<_>>();
         let then = methods.iter().map(|op| &op.then).collect::<Vec<_>>();
         let then_impl = methods.iter().map(|op| &op.then_impl).collect::<Vec<_>>();
+        let body_predicates = methods
+            .iter()
+            .any(|op| op.body_predicates)
+            .then(body_predicates);
 
         let crate_path = syn::TypePath {
             qself: None,
@@ -87,6 +118,8 @@ impl Generator {
 
                 use #crate_path::*;
 
+                #body_predicates
+
                 #(
                     pub struct #when(::httpmock::When);
                     #when_impl
@@ -105,6 +138,8 @@ impl Generator {
                     where
                         F: FnOnce(operations::#when, operations::#then);
                 )*
+
+                #mock_all_decl
             }
 
             impl MockServerExt for ::httpmock::MockServer {
@@ -121,198 +156,310 @@ impl Generator {
                         })
                     }
                 )*
+
+                #mock_all_impl
             }
         };
         Ok(code)
     }
 
-    fn httpmock_method(&mut self, method: &crate::method::OperationMethod) -> MockOp {
-        let when_name = sanitize(&format!("{}-when", method.operation_id), Case::Pascal);
-        let when = format_ident!("{}", when_name).to_token_stream();
-        let then_name = sanitize(&format!("{}-then", method.operation_id), Case::Pascal);
-        let then = format_ident!("{}", then_name).to_token_stream();
-
-        let http_method = match &method.method {
-            HttpMethod::Get => quote! { ::httpmock::Method::GET },
-            HttpMethod::Put => quote! { ::httpmock::Method::PUT },
-            HttpMethod::Post => quote! { ::httpmock::Method::POST },
-            HttpMethod::Delete => quote! { ::httpmock::Method::DELETE },
-            HttpMethod::Options => quote! { ::httpmock::Method::OPTIONS },
-            HttpMethod::Head => quote! { ::httpmock::Method::HEAD },
-            HttpMethod::Patch => quote! { ::httpmock::Method::PATCH },
-            HttpMethod::Trace => quote! { ::httpmock::Method::TRACE },
+    /// Generate the declaration and implementation of
+    /// `mock_all_from_examples`.
+    fn httpmock_examples(
+        &self,
+        methods: &[(&Operation, OperationMethod)],
+        components: &Option<Components>,
+    ) -> Result<(TokenStream, TokenStream)> {
+        // httpmock answers with the first matching mock, so operations whose
+        // paths have fewer parameters are mocked first; a constant path
+        // segment then takes precedence over a parameter in the same place.
+        let mut methods = methods.iter().collect::<Vec<_>>();
+        methods.sort_by_key(|(_, method)| {
+            method
+                .params
+                .iter()
+                .filter(|param| param.kind == OperationParameterKind::Path)
+                .count()
+        });
+
+        let mut mocks = Vec::new();
+        for (operation, method) in methods {
+            let (responses, _) =
+                self.extract_responses(method, OperationResponseStatus::is_success_or_default);
+            let Some(response) = responses.first() else {
+                continue;
+            };
+            let status = match response.status_code {
+                OperationResponseStatus::Code(code) => code,
+                OperationResponseStatus::Range(range) => range * 100,
+                OperationResponseStatus::Default => 200,
+            };
+
+            let body = match &response.typ {
+                OperationResponseKind::Type(_) | OperationResponseKind::JsonArray { .. } => {
+                    let Some(value) =
+                        response_example(operation, &response.status_code, components)?
+                    else {
+                        continue;
+                    };
+                    let body = raw_string_literal(&value.to_string());
+                    quote! {
+                        .header("content-type", "application/json")
+                        .body(#body)
+                    }
+                }
+                OperationResponseKind::EventStream(_) => {
+                    let Some(value) =
+                        response_example(operation, &response.status_code, components)?
+                    else {
+                        continue;
+                    };
+                    let value = raw_string_literal(&value.to_string());
+                    quote! {
+                        .header("content-type", "text/event-stream")
+                        .body(concat!("data: ", #value, "\n\n"))
+                    }
+                }
+                OperationResponseKind::JsonLines(_) => {
+                    let Some(value) =
+                        response_example(operation, &response.status_code, components)?
+                    else {
+                        continue;
+                    };
+                    let value = raw_string_literal(&value.to_string());
+                    quote! {
+                        .header("content-type", "application/x-ndjson")
+                        .body(concat!(#value, "\n"))
+                    }
+                }
+                OperationResponseKind::None | OperationResponseKind::Raw => quote! {},
+                // A mock can't accept a connection upgrade.
+                OperationResponseKind::Upgrade => continue,
+            };
+
+            let operation_id = &method.operation_id;
+            let op = format_ident!("{}", operation_id);
+            mocks.push(quote! {
+                mocks.insert(
+                    #operation_id,
+                    self.#op(|_, then| {
+                        then.into_inner()
+                            .status(#status)
+                            #body;
+                    }),
+                );
+            });
+        }
+
+        let decl = quote! {
+            /// Mock every operation, answering with the example of its
+            /// first successful response from the API description, or a
+            /// value built from the response's schema if it has none. The
+            /// mocks are returned by operation ID; operations for which no
+            /// value satisfying the schema was found aren't mocked.
+            ///
+            /// httpmock answers with the mock created first, so mock an
+            /// operation before calling this to override it, or `delete()`
+            /// its mock afterwards and mock it again.
+            fn mock_all_from_examples(
+                &self,
+            ) -> std::collections::BTreeMap<&'static str, ::httpmock::Mock>;
         };
+        let imp = quote! {
+            fn mock_all_from_examples(
+                &self,
+            ) -> std::collections::BTreeMap<&'static str, ::httpmock::Mock> {
+                let mut mocks = std::collections::BTreeMap::new();
+                #(#mocks)*
+                mocks
+            }
+        };
+        Ok((decl, imp))
+    }
 
-        let path_re = method.path.as_wildcard();
+    fn httpmock_method(&mut self, method: &OperationMethod) -> MockOp {
+        let MockOperation {
+            when,
+            then,
+            method: http_method,
+            path_re,
+            params,
+            responses,
+            response_headers,
+        } = self.mock_operation(method);
+        let http_method = format_ident!("{}", http_method);
+
+        let body_predicates = params
+            .iter()
+            .any(|param| matches!(param.kind, MockParameterKind::JsonBody(_)));
 
         // Generate methods corresponding to each parameter so that callers
         // can specify a prescribed value for that parameter.
-        let when_methods = method.params.iter().map(
-            |OperationParameter {
-                 name,
-                 typ,
-                 kind,
-                 api_name,
-                 description: _,
-             }| {
-                let arg_type_name = match typ {
-                    OperationParameterType::Type(arg_type_id) => self
-                        .type_space
-                        .get_type(arg_type_id)
-                        .unwrap()
-                        .parameter_ident(),
-                    OperationParameterType::RawBody => match kind {
-                        OperationParameterKind::Body(BodyContentType::OctetStream) => quote! {
-                            ::serde_json::Value
+        let when_methods = params.iter().map(|param| {
+            let api_name = &param.api_name;
+            let absent = quote! {
+                Self(self.0.matches(|req| {
+                    req.query_params
+                        .as_ref()
+                        .and_then(|qs| {
+                            qs.iter().find(
+                                |(key, _)| key == #api_name)
+                        })
+                        .is_none()
+                }))
+            };
+            match &param.kind {
+                MockParameterKind::Path(re_fmt) => param.method(
+                    quote! {
+                        let re = regex::Regex::new(
+                            &format!(#re_fmt, value.to_string())
+                        ).unwrap();
+                        Self(self.0.path_matches(re))
+                    },
+                    quote! {},
+                ),
+                MockParameterKind::Query(shape) => {
+                    let present = match shape {
+                        QueryShape::Scalar => quote! {
+                            Self(self.0.query_param(#api_name, value.to_string()))
                         },
-                        OperationParameterKind::Body(BodyContentType::Text(_)) => quote! {
-                            String
+                        QueryShape::Array(_) => quote! {
+                            Self(value.iter().fold(self.0, |when, item| {
+                                when.query_param(#api_name, item.to_string())
+                            }))
                         },
-                        _ => unreachable!(),
-                    },
-                };
+                        QueryShape::Object => quote! {
+                            Self(match ::serde_json::to_value(value).unwrap() {
+                                ::serde_json::Value::Object(fields) => fields
+                                    .into_iter()
+                                    .fold(self.0, |when, (key, field)| match field {
+                                        ::serde_json::Value::Null => when,
+                                        ::serde_json::Value::String(field) => {
+                                            when.query_param(key, field)
+                                        }
+                                        field => when.query_param(key, field.to_string()),
+                                    }),
+                                _ => unreachable!(),
+                            })
+                        },
+                    };
+                    let method = param.method(present, absent);
 
-                let name_ident = format_ident!("{}", name);
-                let (required, handler) = match kind {
-                    OperationParameterKind::Path => {
-                        let re_fmt = method.path.as_wildcard_param(api_name);
-                        (
-                            true,
+                    // Match a single element of an array.
+                    let contains = match shape {
+                        QueryShape::Array(item_type) => {
+                            let fn_name = format_ident!("{}_contains", param.name);
                             quote! {
-                                let re = regex::Regex::new(
-                                    &format!(#re_fmt, value.to_string())
-                                ).unwrap();
-                                Self(self.0.path_matches(re))
-                            },
-                        )
+                                pub fn #fn_name(self, value: #item_type) -> Self {
+                                    Self(self.0.query_param(#api_name, value.to_string()))
+                                }
+                            }
+                        }
+                        _ => quote! {},
+                    };
+
+                    quote! {
+                        #method
+                        #contains
                     }
-                    OperationParameterKind::Query(true) => (
-                        true,
+                }
+                MockParameterKind::Header => {
+                    let method = param.method(
                         quote! {
-                            Self(self.0.query_param(#api_name, value.to_string()))
+                            Self(self.0.header(#api_name, value.to_string()))
                         },
-                    ),
-                    OperationParameterKind::Header(true) => (
-                        true,
                         quote! {
-                            Self(self.0.header(#api_name, value.to_string()))
+                            Self(self.0.matches(|req| {
+                                req.headers
+                                    .as_ref()
+                                    .and_then(|hs| {
+                                        hs.iter().find(
+                                            |(key, _)| key == #api_name
+                                        )
+                                    })
+                                    .is_none()
+                            }))
                         },
-                    ),
+                    );
+                    let fn_name = format_ident!("{}_exists", param.name);
+                    quote! {
+                        #method
 
-                    OperationParameterKind::Query(false) => (
-                        false,
+                        pub fn #fn_name(self) -> Self {
+                            Self(self.0.header_exists(#api_name))
+                        }
+                    }
+                }
+                MockParameterKind::JsonBody(body_type) => {
+                    let method = param.method(
                         quote! {
-                            if let Some(value) = value.into() {
-                                Self(self.0.query_param(
-                                    #api_name,
-                                    value.to_string(),
-                                ))
-                            } else {
-                                Self(self.0.matches(|req| {
-                                    req.query_params
-                                        .as_ref()
-                                        .and_then(|qs| {
-                                            qs.iter().find(
-                                                |(key, _)| key == #api_name)
-                                        })
-                                        .is_none()
-                                }))
-                            }
+                            Self(self.0.json_body_obj(value))
                         },
-                    ),
-                    OperationParameterKind::Header(false) => (
-                        false,
-                        quote! {
-                            if let Some(value) = value.into() {
-                                Self(self.0.header(
-                                    #api_name,
-                                    value.to_string()
-                                ))
-                            } else {
-                                Self(self.0.matches(|req| {
-                                    req.headers
-                                        .as_ref()
-                                        .and_then(|hs| {
-                                            hs.iter().find(
-                                                |(key, _)| key == #api_name
-                                            )
-                                        })
-                                        .is_none()
-                                }))
-                            }
-                        },
-                    ),
-                    OperationParameterKind::Body(body_content_type) => match typ {
-                        OperationParameterType::Type(_) => (
-                            true,
-                            quote! {
-                                Self(self.0.json_body_obj(value))
-
-                            },
-                        ),
-                        OperationParameterType::RawBody => match body_content_type {
-                            BodyContentType::OctetStream => (
-                                true,
-                                quote! {
-                                    Self(self.0.json_body(value))
-                                },
-                            ),
-                            BodyContentType::Text(_) => (
-                                true,
-                                quote! {
-                                    Self(self.0.body(value))
-                                },
-                            ),
-                            _ => unreachable!(),
-                        },
-                    },
-                };
-
-                if required {
-                    // The value is required so we just check for a simple
-                    // match.
+                        quote! {},
+                    );
+                    let partial_name = format_ident!("{}_partial", param.name);
+                    let matches_name = format_ident!("{}_matches", param.name);
                     quote! {
-                        pub fn #name_ident(self, value: #arg_type_name) -> Self {
-                            #handler
+                        #method
+
+                        pub fn #partial_name(self, value: ::serde_json::Value) -> Self {
+                            Self(self.0.json_body_partial(value.to_string()))
                         }
-                    }
-                } else {
-                    // For optional values we permit an input that's an
-                    // `Into<Option<T>`. This allows callers to specify a value
-                    // or specify that the parameter must be absent with None.
-
-                    // If the type is a ref, augment it with a lifetime that
-                    // we'll also use in the function
-                    let (lifetime, arg_type_name) = if let syn::Type::Reference(mut rr) =
-                        syn::parse2::<syn::Type>(arg_type_name.clone()).unwrap()
-                    {
-                        rr.lifetime =
-                            Some(syn::Lifetime::new("'a", proc_macro2::Span::call_site()));
-                        (Some(quote! { 'a, }), rr.to_token_stream())
-                    } else {
-                        (None, arg_type_name)
-                    };
 
-                    quote! {
-                        pub fn #name_ident<#lifetime T>(
+                        /// Match requests whose body decodes to a value for
+                        /// which `predicate` returns `true`. As httpmock
+                        /// matchers are plain functions, the predicate can't
+                        /// capture any variables.
+                        pub fn #matches_name(
                             self,
-                            value: T,
-                        ) -> Self
-                        where
-                            T: Into<Option<#arg_type_name>>,
-                        {
-                            #handler
+                            predicate: fn(&#body_type) -> bool,
+                        ) -> Self {
+                            Self(self.0.matches(body_predicates::matcher(predicate)))
                         }
                     }
                 }
-            },
-        );
+                MockParameterKind::FormBody => param.method(
+                    quote! {
+                        Self(self.0.json_body_obj(value))
+                    },
+                    quote! {},
+                ),
+                MockParameterKind::JsonLinesBody => param.method(
+                    quote! {
+                        Self(self.0.body(
+                            value
+                                .iter()
+                                .map(|item| {
+                                    format!(
+                                        "{}\n",
+                                        ::serde_json::to_string(item).unwrap(),
+                                    )
+                                })
+                                .collect::<String>()
+                        ))
+                    },
+                    quote! {},
+                ),
+                MockParameterKind::OctetStreamBody => param.method(
+                    quote! {
+                        Self(self.0.json_body(value))
+                    },
+                    quote! {},
+                ),
+                MockParameterKind::TextBody => param.method(
+                    quote! {
+                        Self(self.0.body(value))
+                    },
+                    quote! {},
+                ),
+            }
+        });
 
         let when_impl = quote! {
             impl #when {
                 pub fn new(inner: ::httpmock::When) -> Self {
                     Self(inner
-                        .method(#http_method)
+                        .method(::httpmock::Method::#http_method)
                         .path_matches(regex::Regex::new(#path_re).unwrap()))
                 }
 
@@ -324,85 +471,107 @@ impl Generator {
             }
         };
 
-        // Methods for each discrete response. For specific status codes we use
-        // the name of that code; for classes of codes we use the class name
-        // and require a status code that must be within the prescribed range.
-        let then_methods = method.responses.iter().map(
-            |OperationResponse {
-                 status_code, typ, ..
-             }| {
-                let (value_param, value_use) = match typ {
-                    crate::method::OperationResponseKind::Type(arg_type_id) => {
-                        let arg_type = self.type_space.get_type(arg_type_id).unwrap();
-                        let arg_type_ident = arg_type.parameter_ident();
-                        (
-                            quote! {
-                                value: #arg_type_ident,
-                            },
-                            quote! {
-                                .header("content-type", "application/json")
-                                .json_body_obj(value)
-                            },
+        let then_methods = responses.iter().map(|MockResponse { name, status, body }| {
+            let (value_param, value_use) = match body {
+                MockResponseBody::Json(arg_type) => (
+                    quote! {
+                        value: #arg_type,
+                    },
+                    quote! {
+                        .header("content-type", "application/json")
+                        .json_body_obj(value)
+                    },
+                ),
+                MockResponseBody::None => Default::default(),
+                MockResponseBody::Raw => (
+                    quote! {
+                        value: ::serde_json::Value,
+                    },
+                    quote! {
+                        .header("content-type", "application/json")
+                        .json_body(value)
+                    },
+                ),
+                MockResponseBody::EventStream(arg_type) => (
+                    quote! {
+                        value: Vec<#arg_type>,
+                    },
+                    quote! {
+                        .header("content-type", "text/event-stream")
+                        .body(
+                            value
+                                .iter()
+                                .map(|event| {
+                                    format!(
+                                        "data: {}\n\n",
+                                        ::serde_json::to_string(event).unwrap(),
+                                    )
+                                })
+                                .collect::<String>()
+                        )
+                    },
+                ),
+                MockResponseBody::JsonLines(arg_type) => (
+                    quote! {
+                        value: Vec<#arg_type>,
+                    },
+                    quote! {
+                        .header("content-type", "application/x-ndjson")
+                        .body(
+                            value
+                                .iter()
+                                .map(|item| {
+                                    format!(
+                                        "{}\n",
+                                        ::serde_json::to_string(item).unwrap(),
+                                    )
+                                })
+                                .collect::<String>()
+                        )
+                    },
+                ),
+            };
+
+            match status {
+                MockResponseStatus::Code(status_code) => quote! {
+                    pub fn #name(self, #value_param) -> Self {
+                        Self(self.0
+                            .status(#status_code)
+                            #value_use
                         )
                     }
-                    crate::method::OperationResponseKind::None => Default::default(),
-                    crate::method::OperationResponseKind::Raw => (
-                        quote! {
-                            value: ::serde_json::Value,
-                        },
-                        quote! {
-                            .header("content-type", "application/json")
-                            .json_body(value)
-                        },
-                    ),
-                    crate::method::OperationResponseKind::Upgrade => Default::default(),
-                };
-
-                match status_code {
-                    OperationResponseStatus::Code(status_code) => {
-                        let canonical_reason = http::StatusCode::from_u16(*status_code)
-                            .unwrap()
-                            .canonical_reason()
-                            .unwrap();
-                        let fn_name = format_ident!("{}", &sanitize(canonical_reason, Case::Snake));
-
-                        quote! {
-                            pub fn #fn_name(self, #value_param) -> Self {
-                                Self(self.0
-                                    .status(#status_code)
-                                    #value_use
-                                )
-                            }
-                        }
+                },
+                MockResponseStatus::Range(status_type) => quote! {
+                    pub fn #name(self, status: u16, #value_param) -> Self {
+                        assert_eq!(status / 100u16, #status_type);
+                        Self(self.0
+                            .status(status)
+                            #value_use
+                        )
                     }
-                    OperationResponseStatus::Range(status_type) => {
-                        let status_string = match status_type {
-                            1 => "informational",
-                            2 => "success",
-                            3 => "redirect",
-                            4 => "client_error",
-                            5 => "server_error",
-                            _ => unreachable!(),
-                        };
-                        let fn_name = format_ident!("{}", status_string);
-                        quote! {
-                            pub fn #fn_name(self, status: u16, #value_param) -> Self {
-                                assert_eq!(status / 100u16, #status_type);
-                                Self(self.0
-                                    .status(status)
-                                    #value_use
-                                )
-                            }
-                        }
+                },
+                MockResponseStatus::Default => quote! {
+                    pub fn #name(self, status: u16, #value_param) -> Self {
+                        Self(self.0
+                            .status(status)
+                            #value_use
+                        )
+                    }
+                },
+            }
+        });
+
+        // Setters for the headers described for any of the responses.
+        let header_methods = response_headers.iter().map(
+            |MockResponseHeader {
+                 name,
+                 api_name,
+                 typ,
+             }| {
+                quote! {
+                    pub fn #name(self, value: #typ) -> Self {
+                        Self(self.0.header(#api_name, value.to_string()))
                     }
-                    OperationResponseStatus::Default => quote! {
-                        pub fn default_response(self, status: u16, #value_param) -> Self {
-                            Self(self.0
-                                .status(status)
-                                #value_use
-                            )
-                        }
-                    },
                 }
             },
         );
@@ -417,15 +586,349 @@ impl Generator {
                     self.0
                 }
 
+                pub fn delay(self, duration: ::std::time::Duration) -> Self {
+                    Self(self.0.delay(duration))
+                }
+
                 #(#then_methods)*
+
+                #(#header_methods)*
             }
         };
 
         MockOp {
-            when,
+            when: when.to_token_stream(),
             when_impl,
-            then,
+            then: then.to_token_stream(),
             then_impl,
+            body_predicates,
+        }
+    }
+}
+
+/// Generate the `body_predicates` module, which adapts the typed predicates
+/// given to the `*_matches` methods for JSON bodies to httpmock matchers.
+///
+/// httpmock matchers are plain functions, so they can't capture a predicate.
+/// Instead, each distinct predicate is kept in a table, and matched by a
+/// matcher function that's specialized for its index in the table.
+fn body_predicates() -> TokenStream {
+    let matchers = (0..BODY_PREDICATES).map(|index| {
+        let index = proc_macro2::Literal::usize_unsuffixed(index);
+        quote! { matcher_at::<#index> }
+    });
+    let len = proc_macro2::Literal::usize_unsuffixed(BODY_PREDICATES);
+
+    quote! {
+        mod body_predicates {
+            type Predicate = Box<dyn Fn(&[u8]) -> bool + Send + Sync>;
+
+            static PREDICATES: ::std::sync::Mutex<
+                Vec<((::std::any::TypeId, usize), Predicate)>,
+            > = ::std::sync::Mutex::new(Vec::new());
+
+            fn matcher_at<const N: usize>(
+                req: &::httpmock::prelude::HttpMockRequest,
+            ) -> bool {
+                let predicates = PREDICATES
+                    .lock()
+                    .unwrap_or_else(::std::sync::PoisonError::into_inner);
+                req.body
+                    .as_deref()
+                    .is_some_and(|body| (predicates[N].1)(body))
+            }
+
+            const MATCHERS: [fn(&::httpmock::prelude::HttpMockRequest) -> bool; #len] =
+                [#(#matchers,)*];
+
+            /// The matcher for bodies that decode to a value for which
+            /// `predicate` returns `true`.
+            pub(super) fn matcher<T>(
+                predicate: fn(&T) -> bool,
+            ) -> fn(&::httpmock::prelude::HttpMockRequest) -> bool
+            where
+                T: ::serde::de::DeserializeOwned + 'static,
+            {
+                let key = (::std::any::TypeId::of::<T>(), predicate as usize);
+                let mut predicates = PREDICATES
+                    .lock()
+                    .unwrap_or_else(::std::sync::PoisonError::into_inner);
+                let index = match predicates.iter().position(|(k, _)| *k == key) {
+                    Some(index) => index,
+                    None => {
+                        assert!(
+                            predicates.len() < MATCHERS.len(),
+                            "at most {} distinct body predicates may be used",
+                            MATCHERS.len(),
+                        );
+                        predicates.push((
+                            key,
+                            Box::new(move |body| {
+                                ::serde_json::from_slice(body)
+                                    .is_ok_and(|value| predicate(&value))
+                            }),
+                        ));
+                        predicates.len() - 1
+                    }
+                };
+                MATCHERS[index]
+            }
         }
     }
 }
+
+/// A raw string literal containing `value`; unlike an escaped literal, it's
+/// left intact if a long example is wrapped by rustfmt.
+fn raw_string_literal(value: &str) -> TokenStream {
+    let mut hashes = String::from("#");
+    while value.contains(&format!("\"{}", hashes)) {
+        hashes.push('#');
+    }
+    format!("r{hashes}\"{value}\"{hashes}").parse().unwrap()
+}
+
+/// Find the example for the response to `operation` with `status`: the
+/// media type's example, then the first of its examples, then one
+/// synthesized from its schema. `None` if no value could be found that
+/// satisfies the schema.
+fn response_example(
+    operation: &Operation,
+    status: &OperationResponseStatus,
+    components: &Option<Components>,
+) -> Result<Option<Value>> {
+    let response = match status {
+        OperationResponseStatus::Code(code) => operation
+            .responses
+            .responses
+            .get(&openapiv3::StatusCode::Code(*code)),
+        OperationResponseStatus::Range(range) => operation
+            .responses
+            .responses
+            .get(&openapiv3::StatusCode::Range(*range)),
+        OperationResponseStatus::Default => operation.responses.default.as_ref(),
+    };
+    let Some(response) = response else {
+        return Ok(Some(Value::Null));
+    };
+    let Some((_, media)) = response.item(components)?.content.first() else {
+        return Ok(Some(Value::Null));
+    };
+
+    if let Some(example) = &media.example {
+        return Ok(Some(example.clone()));
+    }
+    if let Some((_, example)) = media.examples.first() {
+        if let Some(value) = &example.item(components)?.value {
+            return Ok(Some(value.clone()));
+        }
+    }
+    match &media.schema {
+        Some(schema) => schema_example(schema.item(components)?, components, EXAMPLE_DEPTH),
+        None => Ok(Some(Value::Null)),
+    }
+}
+
+/// Find the example for a property or array item, which may be a boxed
+/// schema or a reference.
+fn boxed_schema_example(
+    schema: &ReferenceOr<Box<Schema>>,
+    components: &Option<Components>,
+    depth: usize,
+) -> Result<Option<Value>> {
+    match schema {
+        ReferenceOr::Item(schema) => schema_example(schema, components, depth),
+        ReferenceOr::Reference { reference } => {
+            let schema = ReferenceOr::<Schema>::Reference {
+                reference: reference.clone(),
+            };
+            schema_example(schema.item(components)?, components, depth)
+        }
+    }
+}
+
+/// Find a value that satisfies `schema`: its example or default if it has
+/// one, otherwise the smallest value permitted by its constraints. `None` if
+/// no such value could be found, such as for a string whose pattern can't be
+/// satisfied.
+fn schema_example(
+    schema: &Schema,
+    components: &Option<Components>,
+    depth: usize,
+) -> Result<Option<Value>> {
+    let data = &schema.schema_data;
+    if let Some(example) = data.example.as_ref().or(data.default.as_ref()) {
+        return Ok(Some(example.clone()));
+    }
+    let Some(depth) = depth.checked_sub(1) else {
+        return Ok(Some(Value::Null));
+    };
+
+    let value = match &schema.schema_kind {
+        SchemaKind::Type(Type::String(string)) => {
+            if let Some(value) = string.enumeration.iter().flatten().next() {
+                Value::String(value.clone())
+            } else {
+                let value = match &string.format {
+                    openapiv3::VariantOrUnknownOrEmpty::Item(openapiv3::StringFormat::Date) => {
+                        "1970-01-01"
+                    }
+                    openapiv3::VariantOrUnknownOrEmpty::Item(openapiv3::StringFormat::DateTime) => {
+                        "1970-01-01T00:00:00Z"
+                    }
+                    openapiv3::VariantOrUnknownOrEmpty::Unknown(format) => match format.as_str() {
+                        "uuid" => "00000000-0000-0000-0000-000000000000",
+                        "ip" | "ipv4" => "127.0.0.1",
+                        "ipv6" => "::1",
+                        _ => "string",
+                    },
+                    _ => "string",
+                };
+                let value = match &string.pattern {
+                    // The value for the format is used if it happens to
+                    // match; otherwise one is built from the pattern.
+                    Some(pattern) => match pattern_example(
+                        pattern,
+                        Some(value),
+                        string.min_length,
+                        string.max_length,
+                    ) {
+                        Some(value) => value,
+                        None => return Ok(None),
+                    },
+                    None => {
+                        let mut value = value.to_string();
+                        if let Some(min) = string.min_length {
+                            while value.len() < min {
+                                value.push('x');
+                            }
+                        }
+                        if let Some(max) = string.max_length {
+                            value.truncate(max);
+                        }
+                        value
+                    }
+                };
+                Value::String(value)
+            }
+        }
+        SchemaKind::Type(Type::Number(number)) => {
+            let value = if let Some(value) = number.enumeration.iter().flatten().next() {
+                *value
+            } else {
+                match (number.minimum, number.maximum) {
+                    (Some(min), _) if number.exclusive_minimum => min + 1.0,
+                    (Some(min), _) => min,
+                    (None, Some(max)) if max < 0.0 && number.exclusive_maximum => max - 1.0,
+                    (None, Some(max)) if max < 0.0 => max,
+                    _ => 0.0,
+                }
+            };
+            serde_json::json!(value)
+        }
+        SchemaKind::Type(Type::Integer(integer)) => {
+            let value = if let Some(value) = integer.enumeration.iter().flatten().next() {
+                *value
+            } else {
+                match (integer.minimum, integer.maximum) {
+                    (Some(min), _) if integer.exclusive_minimum => min + 1,
+                    (Some(min), _) => min,
+                    (None, Some(max)) if max < 0 && integer.exclusive_maximum => max - 1,
+                    (None, Some(max)) if max < 0 => max,
+                    _ => 0,
+                }
+            };
+            serde_json::json!(value)
+        }
+        SchemaKind::Type(Type::Boolean(boolean)) => Value::Bool(
+            boolean
+                .enumeration
+                .iter()
+                .flatten()
+                .next()
+                .copied()
+                .unwrap_or(false),
+        ),
+        SchemaKind::Type(Type::Object(object)) => {
+            return object_example(&object.properties, &object.required, components, depth);
+        }
+        SchemaKind::Type(Type::Array(array)) => {
+            return array_example(array.items.as_ref(), array.min_items, components, depth);
+        }
+        SchemaKind::OneOf { one_of: schemas } | SchemaKind::AnyOf { any_of: schemas } => {
+            // Use the first subschema for which a value can be found.
+            for schema in schemas {
+                if let Some(value) = schema_example(schema.item(components)?, components, depth)? {
+                    return Ok(Some(value));
+                }
+            }
+            if !schemas.is_empty() {
+                return Ok(None);
+            }
+            Value::Null
+        }
+        SchemaKind::AllOf { all_of } => {
+            // Merge the properties of each subschema.
+            let mut merged = serde_json::Map::new();
+            for schema in all_of {
+                match schema_example(schema.item(components)?, components, depth)? {
+                    Some(Value::Object(object)) => merged.extend(object),
+                    Some(value) if all_of.len() == 1 => return Ok(Some(value)),
+                    Some(_) => {}
+                    None => return Ok(None),
+                }
+            }
+            Value::Object(merged)
+        }
+        SchemaKind::Not { .. } => Value::Null,
+        SchemaKind::Any(any) => {
+            if let Some(value) = any.enumeration.first() {
+                value.clone()
+            } else if !any.properties.is_empty() {
+                return object_example(&any.properties, &any.required, components, depth);
+            } else if any.items.is_some() {
+                return array_example(any.items.as_ref(), any.min_items, components, depth);
+            } else {
+                Value::Null
+            }
+        }
+    };
+    Ok(Some(value))
+}
+
+fn object_example(
+    properties: &indexmap::IndexMap<String, ReferenceOr<Box<Schema>>>,
+    required: &[String],
+    components: &Option<Components>,
+    depth: usize,
+) -> Result<Option<Value>> {
+    let mut object = serde_json::Map::new();
+    for (name, schema) in properties
+        .iter()
+        .filter(|(name, _)| required.contains(name))
+    {
+        let Some(value) = boxed_schema_example(schema, components, depth)? else {
+            return Ok(None);
+        };
+        object.insert(name.clone(), value);
+    }
+    Ok(Some(Value::Object(object)))
+}
+
+fn array_example(
+    items: Option<&ReferenceOr<Box<Schema>>>,
+    min_items: Option<usize>,
+    components: &Option<Components>,
+    depth: usize,
+) -> Result<Option<Value>> {
+    let count = min_items.unwrap_or(0);
+    let items = match items {
+        Some(schema) if count > 0 => {
+            let Some(item) = boxed_schema_example(schema, components, depth)? else {
+                return Ok(None);
+            };
+            vec![item; count]
+        }
+        _ => Vec::new(),
+    };
+    Ok(Some(Value::Array(items)))
+}
@@ -11,7 +11,7 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use serde::Deserialize;
 use thiserror::Error;
-use typify::{TypeSpace, TypeSpaceSettings};
+use typify::{TypeId, TypeSpace, TypeSpaceSettings};
 
 use crate::to_schema::ToSchema;
 
@@ -20,12 +20,20 @@ pub use typify::TypeSpaceImpl as TypeImpl;
 pub use typify::TypeSpacePatch as TypePatch;
 pub use typify::UnknownPolicy;
 
+mod arbitrary;
+mod axum;
 mod cli;
+mod client_api;
+mod fake;
 mod httpmock;
 mod method;
+mod mock;
+mod operation;
+mod server;
 mod template;
 mod to_schema;
 mod util;
+mod wiremock;
 
 #[allow(missing_docs)]
 #[derive(Error, Debug)]
@@ -53,6 +61,16 @@ pub struct Generator {
     settings: GenerationSettings,
     uses_futures: bool,
     uses_websockets: bool,
+    uses_event_streams: bool,
+    uses_json_lines: bool,
+    uses_json_arrays: bool,
+    uses_upload_bodies: bool,
+    uses_tokio: bool,
+    uses_regress: bool,
+    uses_async_trait: bool,
+    /// Schemas of the operations' types, for the generation of `proptest`
+    /// strategies.
+    type_schemas: Vec<(TypeId, openapiv3::ReferenceOr<openapiv3::Schema>)>,
 }
 
 /// Settings for [Generator].
@@ -66,6 +84,15 @@ pub struct GenerationSettings {
     post_hook: Option<TokenStream>,
     post_hook_async: Option<TokenStream>,
     extra_derives: Vec<String>,
+    array_streaming: bool,
+    request_validation: bool,
+    response_checking: bool,
+    typestate_builders: bool,
+    operation_enum: bool,
+    client_api: bool,
+    client_api_attrs: Vec<String>,
+    httpmock_examples: bool,
+    proptest: bool,
 
     map_type: Option<String>,
     unknown_crates: UnknownPolicy,
@@ -166,6 +193,96 @@ impl GenerationSettings {
         self
     }
 
+    /// Decode large array responses incrementally. Operations whose
+    /// successful response is a JSON array, or an object with a single
+    /// array-valued property, return a `JsonArrayStream` of the array's items
+    /// rather than the full response type.
+    pub fn with_array_streaming(&mut self, array_streaming: bool) -> &mut Self {
+        self.array_streaming = array_streaming;
+        self
+    }
+
+    /// Check operation parameters and request bodies against the constraints
+    /// in their schemas (`minimum`, `maxLength`, `pattern`, `maxItems`,
+    /// etc.) before sending a request, returning `Error::InvalidRequest` for
+    /// values that violate them. Patterns are compiled once with the `regress`
+    /// crate (see [Generator::uses_regress]); invalid patterns are reported
+    /// when generating the client.
+    pub fn with_request_validation(&mut self, request_validation: bool) -> &mut Self {
+        self.request_validation = request_validation;
+        self
+    }
+
+    /// Check decoded JSON responses against the schemas in the API
+    /// description, reporting undeclared status codes, missing required
+    /// properties, unknown enum values, and undeclared properties (where
+    /// `additionalProperties` is `false`) to
+    /// `ClientHooks::contract_violation`. This is intended for testing that
+    /// a server conforms to the API description.
+    pub fn with_response_checking(&mut self, response_checking: bool) -> &mut Self {
+        self.response_checking = response_checking;
+        self
+    }
+
+    /// For the builder interface style, track which required parameters
+    /// have been provided in the type of each operation's builder so that
+    /// `send()` is only available once all of them have been set. Omitting a
+    /// required parameter is then a compile-time error rather than an
+    /// `Error::InvalidRequest`.
+    pub fn with_typestate_builders(&mut self, typestate_builders: bool) -> &mut Self {
+        self.typestate_builders = typestate_builders;
+        self
+    }
+
+    /// Generate an `Operation` enum with a variant for each operation that
+    /// holds its parameters in a struct (in the `operations` module), and
+    /// `Client::execute_operation` to execute one. Operations may then be
+    /// serialized with `serde`, for example to persist them to be executed
+    /// later.
+    pub fn with_operation_enum(&mut self, operation_enum: bool) -> &mut Self {
+        self.operation_enum = operation_enum;
+        self
+    }
+
+    /// Generate a `ClientApi` trait with an `async` method for each operation
+    /// and its implementation for `Client`, so that code may depend on the
+    /// trait (including as `dyn ClientApi`) and be tested with fakes. The
+    /// generated code needs the `async-trait` feature of progenitor-client
+    /// (see [Generator::uses_async_trait]).
+    pub fn with_client_api(&mut self, client_api: bool) -> &mut Self {
+        self.client_api = client_api;
+        self
+    }
+
+    /// Add an attribute to the `ClientApi` trait, such as
+    /// `cfg_attr(test, mockall::automock)`.
+    pub fn with_client_api_attr(&mut self, attr: impl ToString) -> &mut Self {
+        self.client_api_attrs.push(attr.to_string());
+        self
+    }
+
+    /// Add `MockServerExt::mock_all_from_examples` to the `httpmock`
+    /// extension generated by [`Generator::httpmock`]. It mocks every
+    /// operation with the example of its first successful response from the
+    /// API description, or a value synthesized from the response's schema;
+    /// operations for which no value satisfying the schema is found, such as
+    /// one with an unsupported string pattern, aren't mocked.
+    pub fn with_httpmock_examples(&mut self, httpmock_examples: bool) -> &mut Self {
+        self.httpmock_examples = httpmock_examples;
+        self
+    }
+
+    /// Implement `proptest::arbitrary::Arbitrary` for each type in the
+    /// `types` module, generating values that satisfy the constraints of the
+    /// type's schema (such as `enum`, `pattern`, `maxLength`, and `minimum`),
+    /// and generate a test for each type that its values are unchanged by a
+    /// serde round trip. The generated code requires the `proptest` crate and
+    /// the `proptest` feature of `progenitor-client`.
+    pub fn with_proptest(&mut self, proptest: bool) -> &mut Self {
+        self.proptest = proptest;
+        self
+    }
+
     /// Modify a type with the given name.
     /// See [typify::TypeSpaceSettings::with_patch].
     pub fn with_patch<S: AsRef<str>>(&mut self, type_name: S, patch: &TypePatch) -> &mut Self {
@@ -250,6 +367,14 @@ impl Default for Generator {
             settings: Default::default(),
             uses_futures: Default::default(),
             uses_websockets: Default::default(),
+            uses_event_streams: Default::default(),
+            uses_json_lines: Default::default(),
+            uses_json_arrays: Default::default(),
+            uses_upload_bodies: Default::default(),
+            uses_tokio: Default::default(),
+            uses_regress: Default::default(),
+            uses_async_trait: Default::default(),
+            type_schemas: Default::default(),
         }
     }
 }
@@ -301,37 +426,24 @@ impl Generator {
             settings: settings.clone(),
             uses_futures: false,
             uses_websockets: false,
+            uses_event_streams: false,
+            uses_json_lines: false,
+            uses_json_arrays: false,
+            uses_upload_bodies: false,
+            uses_tokio: false,
+            uses_regress: false,
+            uses_async_trait: false,
+            type_schemas: Vec::new(),
         }
     }
 
     /// Emit a [TokenStream] containing the generated client code.
     pub fn generate_tokens(&mut self, spec: &OpenAPI) -> Result<TokenStream> {
-        validate_openapi(spec)?;
-
-        // Convert our components dictionary to schemars
-        let schemas = spec.components.iter().flat_map(|components| {
-            components
-                .schemas
-                .iter()
-                .map(|(name, ref_or_schema)| (name.clone(), ref_or_schema.to_schema()))
-        });
-
-        self.type_space.add_ref_types(schemas)?;
-
-        let raw_methods = spec
-            .paths
+        let raw_methods = self.operation_methods(spec)?;
+        let operations = raw_methods
             .iter()
-            .flat_map(|(path, ref_or_item)| {
-                // Exclude externally defined path items.
-                let item = ref_or_item.as_item().unwrap();
-                item.iter().map(move |(method, operation)| {
-                    (path.as_str(), method, operation, &item.parameters)
-                })
-            })
-            .map(|(path, method, operation, path_parameters)| {
-                self.process_operation(operation, &spec.components, path, method, path_parameters)
-            })
-            .collect::<Result<Vec<_>>>()?;
+            .map(|method| self.operation_metadata(method))
+            .collect::<Vec<_>>();
 
         let operation_code = match (&self.settings.interface, &self.settings.tag) {
             (InterfaceStyle::Positional, TagStyle::Merged) => self
@@ -358,6 +470,24 @@ impl Generator {
             }
         }?;
 
+        let operation_enum = if self.settings.operation_enum {
+            Some(self.operation_enum(&raw_methods)?)
+        } else {
+            None
+        };
+
+        let client_api = if self.settings.client_api {
+            Some(self.client_api(&raw_methods)?)
+        } else {
+            None
+        };
+
+        let arbitrary = if self.settings.proptest {
+            Some(self.arbitrary(spec)?)
+        } else {
+            None
+        };
+
         let types = self.type_space.to_stream();
 
         let (inner_type, inner_fn_value) = match self.settings.inner_type.as_ref() {
@@ -400,6 +530,10 @@ impl Generator {
 
         let version_str = &spec.info.version;
 
+        // Only mention typed streams if they're used to avoid cluttering the
+        // generated client.
+        let stream_types = self.stream_type_uses();
+
         // The allow(unused_imports) on the `pub use` is necessary with Rust
         // 1.76+, in case the generated file is not at the top level of the
         // crate.
@@ -411,6 +545,8 @@ impl Generator {
                 ByteStream,
                 ClientInfo,
                 Error,
+                #stream_types
+                RequestOptions,
                 ResponseValue,
             };
             #[allow(unused_imports)]
@@ -427,11 +563,14 @@ impl Generator {
                 #types
             }
 
+            #arbitrary
+
             #[derive(Clone, Debug)]
             #[doc = #client_docstring]
             pub struct Client {
                 pub(crate) baseurl: String,
                 pub(crate) client: reqwest::Client,
+                pub(crate) middleware: progenitor_client::MiddlewareStack,
                 #inner_property
             }
 
@@ -473,9 +612,26 @@ impl Generator {
                     Self {
                         baseurl: baseurl.to_string(),
                         client,
+                        middleware: Default::default(),
                         #inner_value
                     }
                 }
+
+                /// Append `middleware` to the chain that surrounds the
+                /// execution of each request. Middleware runs in the order in
+                /// which it is attached.
+                pub fn with_middleware<M: progenitor_client::Middleware>(
+                    mut self,
+                    middleware: M,
+                ) -> Self {
+                    self.middleware.push(middleware);
+                    self
+                }
+
+                /// Descriptions of the API's operations.
+                pub const OPERATIONS: &'static [progenitor_client::OperationMetadata] = &[
+                    #(#operations),*
+                ];
             }
 
             impl ClientInfo<#inner_type> for Client {
@@ -499,19 +655,66 @@ impl Generator {
             impl ClientHooks<#inner_type> for &Client {}
 
             #operation_code
+
+            #operation_enum
+
+            #client_api
         };
 
         Ok(file)
     }
 
+    /// Validate the API description, add its component schemas to the type
+    /// space, and process each of its operations, in the order of
+    /// `Client::OPERATIONS`.
+    pub(crate) fn operation_methods(
+        &mut self,
+        spec: &OpenAPI,
+    ) -> Result<Vec<method::OperationMethod>> {
+        validate_openapi(spec)?;
+
+        // Convert our components dictionary to schemars
+        let schemas = spec.components.iter().flat_map(|components| {
+            components
+                .schemas
+                .iter()
+                .map(|(name, ref_or_schema)| (name.clone(), ref_or_schema.to_schema()))
+        });
+
+        self.type_space.add_ref_types(schemas)?;
+
+        let mut raw_methods = spec
+            .paths
+            .iter()
+            .flat_map(|(path, ref_or_item)| {
+                // Exclude externally defined path items.
+                let item = ref_or_item.as_item().unwrap();
+                item.iter().map(move |(method, operation)| {
+                    (path.as_str(), method, operation, &item.parameters)
+                })
+            })
+            .map(|(path, method, operation, path_parameters)| {
+                self.process_operation(operation, &spec.components, path, method, path_parameters)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        raw_methods
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, method)| method.index = index);
+        Ok(raw_methods)
+    }
+
     fn generate_tokens_positional_merged(
         &mut self,
         input_methods: &[method::OperationMethod],
         has_inner: bool,
     ) -> Result<TokenStream> {
+        let names = method::positional_names(input_methods);
+
         let methods = input_methods
             .iter()
-            .map(|method| self.positional_method(method, has_inner))
+            .zip(&names)
+            .map(|(method, names)| self.positional_method(method, names, has_inner))
             .collect::<Result<Vec<_>>>()?;
 
         // The allow(unused_imports) on the `pub use` is necessary with Rust
@@ -548,6 +751,7 @@ impl Generator {
             .iter()
             .map(|method| self.builder_impl(method))
             .collect::<Vec<_>>();
+        let stream_types = self.stream_type_uses();
 
         let out = quote! {
             impl Client {
@@ -565,6 +769,7 @@ impl Generator {
                     ClientInfo,
                     ClientHooks,
                     Error,
+                    #stream_types
                     OperationInfo,
                     RequestBuilderExt,
                     ResponseValue,
@@ -594,6 +799,7 @@ impl Generator {
             .collect::<Result<Vec<_>>>()?;
 
         let (traits_and_impls, trait_preludes) = self.builder_tags(input_methods, &tag_info);
+        let stream_types = self.stream_type_uses();
 
         // The allow(unused_imports) on the `pub use` is necessary with Rust
         // 1.76+, in case the generated file is not at the top level of the
@@ -613,6 +819,7 @@ impl Generator {
                     ClientInfo,
                     ClientHooks,
                     Error,
+                    #stream_types
                     OperationInfo,
                     RequestBuilderExt,
                     ResponseValue,
@@ -633,6 +840,14 @@ impl Generator {
         Ok(out)
     }
 
+    fn stream_type_uses(&self) -> TokenStream {
+        let event_stream = self.uses_event_streams.then(|| quote! { EventStream, });
+        let json_lines = self.uses_json_lines.then(|| quote! { JsonLinesStream, });
+        let json_arrays = self.uses_json_arrays.then(|| quote! { JsonArrayStream, });
+        let upload_body = self.uses_upload_bodies.then(|| quote! { UploadBody, });
+        quote! { #event_stream #json_arrays #json_lines #upload_body }
+    }
+
     /// Get the [TypeSpace] for schemas present in the OpenAPI specification.
     pub fn get_type_space(&self) -> &TypeSpace {
         &self.type_space
@@ -649,6 +864,33 @@ impl Generator {
     pub fn uses_websockets(&self) -> bool {
         self.uses_websockets
     }
+
+    /// Whether the generated client needs the `tokio` feature of
+    /// progenitor-client to upload files or download responses.
+    pub fn uses_tokio(&self) -> bool {
+        self.uses_tokio
+    }
+
+    /// Whether the generated client checks requests against patterns, which
+    /// needs the `regress` crate (in addition to any patterns in the types of
+    /// the [TypeSpace]).
+    pub fn uses_regress(&self) -> bool {
+        self.uses_regress
+    }
+
+    /// Whether the generated code defines traits with `async_trait` (such as
+    /// the `ClientApi` trait), which needs the `async-trait` feature of
+    /// progenitor-client.
+    pub fn uses_async_trait(&self) -> bool {
+        self.uses_async_trait
+    }
+
+    /// Whether the generated code implements `proptest::arbitrary::Arbitrary`
+    /// for its types, which needs the `proptest` crate and the `proptest`
+    /// feature of progenitor-client.
+    pub fn uses_proptest(&self) -> bool {
+        self.settings.proptest
+    }
 }
 
 /// Add newlines after end-braces at <= two levels of indentation.
@@ -21,6 +21,8 @@ use crate::{to_schema::ToSchema, util::ReferenceOrExt};
 /// The intermediate representation of an operation that will become a method.
 pub(crate) struct OperationMethod {
     pub operation_id: String,
+    /// The operation ID as in the API description.
+    pub api_operation_id: String,
     pub tags: Vec<String>,
     pub method: HttpMethod,
     pub path: PathTemplate,
@@ -30,6 +32,10 @@ pub(crate) struct OperationMethod {
     pub responses: Vec<OperationResponse>,
     pub dropshot_paginated: Option<DropshotPagination>,
     dropshot_websocket: bool,
+    ranged_download: bool,
+    pub deprecated: bool,
+    /// Position among the API's operations, i.e. in `Client::OPERATIONS`.
+    pub index: usize,
 }
 
 pub enum HttpMethod {
@@ -61,7 +67,7 @@ impl std::str::FromStr for HttpMethod {
     }
 }
 impl HttpMethod {
-    fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &'static str {
         match self {
             HttpMethod::Get => "get",
             HttpMethod::Put => "put",
@@ -79,6 +85,12 @@ struct MethodSigBody {
     success: TokenStream,
     error: TokenStream,
     body: TokenStream,
+    /// Code that prepares the request without sending it, binding it to a
+    /// variable named by `unique_ident_from("request", ..)`.
+    prepare: TokenStream,
+    /// For operations that support range requests, the name of the writer
+    /// parameter and the body of a method that downloads the response to it.
+    download: Option<(proc_macro2::Ident, TokenStream)>,
 }
 
 struct BuilderImpl {
@@ -100,6 +112,9 @@ pub struct OperationParameter {
     pub description: Option<String>,
     pub typ: OperationParameterType,
     pub kind: OperationParameterKind,
+    /// Schema constraints checked before sending a request (if request
+    /// validation is enabled).
+    pub constraints: Option<TokenStream>,
 }
 
 #[derive(Eq, PartialEq)]
@@ -127,7 +142,7 @@ impl OperationParameterKind {
             OperationParameterKind::Body(_) => true,
         }
     }
-    fn is_optional(&self) -> bool {
+    pub(crate) fn is_optional(&self) -> bool {
         !self.is_required()
     }
 }
@@ -138,6 +153,7 @@ pub enum BodyContentType {
     Json,
     FormUrlencoded,
     Text(String),
+    JsonLines(String),
 }
 
 impl FromStr for BodyContentType {
@@ -150,6 +166,9 @@ impl FromStr for BodyContentType {
             "application/json" => Ok(Self::Json),
             "application/x-www-form-urlencoded" => Ok(Self::FormUrlencoded),
             "text/plain" | "text/x-markdown" => Ok(Self::Text(String::from(&s[..offset]))),
+            "application/x-ndjson" | "application/jsonl" => {
+                Ok(Self::JsonLines(String::from(&s[..offset])))
+            }
             _ => Err(Error::UnexpectedFormat(format!(
                 "unexpected content type: {}",
                 s
@@ -164,7 +183,7 @@ impl std::fmt::Display for BodyContentType {
             Self::OctetStream => "application/octet-stream",
             Self::Json => "application/json",
             Self::FormUrlencoded => "application/x-www-form-urlencoded",
-            Self::Text(typ) => typ,
+            Self::Text(typ) | Self::JsonLines(typ) => typ,
         })
     }
 }
@@ -173,12 +192,27 @@ impl std::fmt::Display for BodyContentType {
 pub(crate) struct OperationResponse {
     pub status_code: OperationResponseStatus,
     pub typ: OperationResponseKind,
+    /// A `progenitor_client::ContractSchema` for checking the response body
+    /// (when response checking is enabled).
+    pub contract: Option<TokenStream>,
+    /// Headers described for the response.
+    pub headers: Vec<OperationResponseHeader>,
     // TODO this isn't currently used because dropshot doesn't give us a
     // particularly useful message here.
     #[allow(dead_code)]
     description: Option<String>,
 }
 
+/// A header described for a response.
+#[derive(Debug)]
+pub(crate) struct OperationResponseHeader {
+    /// Sanitized name.
+    pub name: String,
+    /// Original name in the API.
+    pub api_name: String,
+    pub typ: TypeId,
+}
+
 impl Eq for OperationResponse {}
 impl PartialEq for OperationResponse {
     fn eq(&self, other: &Self) -> bool {
@@ -260,6 +294,15 @@ pub(crate) enum OperationResponseKind {
     None,
     Raw,
     Upgrade,
+    EventStream(TypeId),
+    JsonLines(TypeId),
+    /// A JSON array whose items are decoded incrementally; the array is
+    /// either the whole body or the value of the `field` property.
+    JsonArray {
+        body: TypeId,
+        item: TypeId,
+        field: Option<String>,
+    },
 }
 
 impl OperationResponseKind {
@@ -278,6 +321,18 @@ impl OperationResponseKind {
             OperationResponseKind::Upgrade => {
                 quote! { reqwest::Upgraded }
             }
+            OperationResponseKind::EventStream(ref type_id) => {
+                let type_name = type_space.get_type(type_id).unwrap().ident();
+                quote! { EventStream<#type_name> }
+            }
+            OperationResponseKind::JsonLines(ref type_id) => {
+                let type_name = type_space.get_type(type_id).unwrap().ident();
+                quote! { JsonLinesStream<#type_name> }
+            }
+            OperationResponseKind::JsonArray { ref item, .. } => {
+                let type_name = type_space.get_type(item).unwrap().ident();
+                quote! { JsonArrayStream<#type_name> }
+            }
         }
     }
 }
@@ -312,13 +367,13 @@ impl Generator {
                         // Path parameters MUST be required.
                         assert!(parameter_data.required);
 
-                        let schema = parameter_data.schema()?.to_schema();
-
                         let name = sanitize(
                             &format!("{}-{}", operation_id, &parameter_data.name),
                             Case::Pascal,
                         );
-                        let typ = self.type_space.add_type_with_name(&schema, Some(name))?;
+                        let typ = self.add_schema_type(parameter_data.schema()?, name)?;
+                        let constraints =
+                            self.param_constraints(parameter_data.schema()?, components)?;
 
                         Ok(OperationParameter {
                             name: sanitize(&parameter_data.name, Case::Snake),
@@ -326,6 +381,7 @@ impl Generator {
                             description: parameter_data.description.clone(),
                             typ: OperationParameterType::Type(typ),
                             kind: OperationParameterKind::Path,
+                            constraints,
                         })
                     }
                     openapiv3::Parameter::Query {
@@ -334,7 +390,6 @@ impl Generator {
                         style: openapiv3::QueryStyle::Form,
                         allow_empty_value: _, // Irrelevant for this client
                     } => {
-                        let schema = parameter_data.schema()?.to_schema();
                         let name = sanitize(
                             &format!(
                                 "{}-{}",
@@ -344,7 +399,9 @@ impl Generator {
                             Case::Pascal,
                         );
 
-                        let type_id = self.type_space.add_type_with_name(&schema, Some(name))?;
+                        let type_id = self.add_schema_type(parameter_data.schema()?, name)?;
+                        let constraints =
+                            self.param_constraints(parameter_data.schema()?, components)?;
 
                         let ty = self.type_space.get_type(&type_id).unwrap();
 
@@ -365,13 +422,13 @@ impl Generator {
                             description: parameter_data.description.clone(),
                             typ: OperationParameterType::Type(type_id),
                             kind: OperationParameterKind::Query(required),
+                            constraints,
                         })
                     }
                     openapiv3::Parameter::Header {
                         parameter_data,
                         style: openapiv3::HeaderStyle::Simple,
                     } => {
-                        let schema = parameter_data.schema()?.to_schema();
                         let name = sanitize(
                             &format!(
                                 "{}-{}",
@@ -381,7 +438,9 @@ impl Generator {
                             Case::Pascal,
                         );
 
-                        let typ = self.type_space.add_type_with_name(&schema, Some(name))?;
+                        let typ = self.add_schema_type(parameter_data.schema()?, name)?;
+                        let constraints =
+                            self.param_constraints(parameter_data.schema()?, components)?;
 
                         Ok(OperationParameter {
                             name: sanitize(&parameter_data.name, Case::Snake),
@@ -389,6 +448,7 @@ impl Generator {
                             description: parameter_data.description.clone(),
                             typ: OperationParameterType::Type(typ),
                             kind: OperationParameterKind::Header(parameter_data.required),
+                            constraints,
                         })
                     }
                     openapiv3::Parameter::Path { style, .. } => Err(Error::UnexpectedFormat(
@@ -409,6 +469,10 @@ impl Generator {
             self.uses_websockets = true;
         }
 
+        // Paginated operations already retrieve their items incrementally.
+        let stream_arrays = self.settings.array_streaming
+            && operation.extensions.get("x-dropshot-pagination").is_none();
+
         if let Some(body_param) = self.get_body_param(operation, components)? {
             params.push(body_param);
         }
@@ -446,33 +510,100 @@ impl Generator {
                     let (status_code, response) = v?;
 
                     // We categorize responses as "typed" based on the
-                    // "application/json" content type, "upgrade" if it's a
-                    // websocket channel without a meaningful content-type,
-                    // "raw" if there's any other response content type (we don't
-                    // investigate further), or "none" if there is no content.
+                    // "application/json" content type, "event stream" for
+                    // successful (or default) "text/event-stream" responses
+                    // with a schema for the event data, "json lines" for
+                    // successful (or default) "application/x-ndjson" or
+                    // "application/jsonl" responses with a schema for each
+                    // line, "upgrade" if it's a
+                    // successful (or default) response for a websocket channel
+                    // without a meaningful content-type, "raw" if there's any
+                    // other response content type (we don't investigate
+                    // further), or "none" if there is no content.
                     // TODO if there are multiple response content types we could
                     // treat those like different response types and create an
                     // enum; the generated client method would check for the
                     // content type of the response just as it currently examines
                     // the status code.
+                    let mut contract = None;
                     let typ = if let Some(mt) = response.content.iter().find_map(|(x, v)| {
                         (x == "application/json" || x.starts_with("application/json;")).then_some(v)
                     }) {
                         assert!(mt.encoding.is_empty());
 
-                        let typ = if let Some(schema) = &mt.schema {
-                            let schema = schema.to_schema();
-                            let name = sanitize(
-                                &format!("{}-response", operation.operation_id.as_ref().unwrap(),),
-                                Case::Pascal,
-                            );
-                            self.type_space.add_type_with_name(&schema, Some(name))?
-                        } else {
+                        let Some(schema) = &mt.schema else {
                             todo!("media type encoding, no schema: {:#?}", mt);
                         };
+                        let name = sanitize(
+                            &format!("{}-response", operation.operation_id.as_ref().unwrap(),),
+                            Case::Pascal,
+                        );
+                        let typ = self.add_schema_type(schema, name)?;
+                        contract = self.response_contract(schema, components)?;
+
+                        match stream_arrays
+                            .then(|| self.json_array_response(&typ, schema, components))
+                            .transpose()?
+                            .flatten()
+                            // Default responses may also be errors, which
+                            // are always decoded in full.
+                            .filter(|_| {
+                                matches!(
+                                    status_code,
+                                    OperationResponseStatus::Code(200..=299)
+                                        | OperationResponseStatus::Range(2)
+                                )
+                            }) {
+                            Some((item, field)) => {
+                                self.uses_json_arrays = true;
+                                OperationResponseKind::JsonArray {
+                                    body: typ,
+                                    item,
+                                    field,
+                                }
+                            }
+                            None => OperationResponseKind::Type(typ),
+                        }
+                    } else if let Some(schema) = response
+                        .content
+                        .iter()
+                        .find_map(|(x, v)| {
+                            (x == "text/event-stream" || x.starts_with("text/event-stream;"))
+                                .then_some(v.schema.as_ref())
+                        })
+                        .flatten()
+                        .filter(|_| status_code.is_success_or_default())
+                    {
+                        let name = sanitize(
+                            &format!("{}-response", operation.operation_id.as_ref().unwrap(),),
+                            Case::Pascal,
+                        );
+                        let typ = self.add_schema_type(schema, name)?;
+                        self.uses_event_streams = true;
 
-                        OperationResponseKind::Type(typ)
-                    } else if dropshot_websocket {
+                        OperationResponseKind::EventStream(typ)
+                    } else if let Some(schema) = response
+                        .content
+                        .iter()
+                        .find_map(|(x, v)| {
+                            matches!(
+                                BodyContentType::from_str(x),
+                                Ok(BodyContentType::JsonLines(_))
+                            )
+                            .then_some(v.schema.as_ref())
+                        })
+                        .flatten()
+                        .filter(|_| status_code.is_success_or_default())
+                    {
+                        let name = sanitize(
+                            &format!("{}-response", operation.operation_id.as_ref().unwrap(),),
+                            Case::Pascal,
+                        );
+                        let typ = self.add_schema_type(schema, name)?;
+                        self.uses_json_lines = true;
+
+                        OperationResponseKind::JsonLines(typ)
+                    } else if dropshot_websocket && status_code.is_success_or_default() {
                         OperationResponseKind::Upgrade
                     } else if response.content.first().is_some() {
                         OperationResponseKind::Raw
@@ -490,6 +621,36 @@ impl Generator {
                         success = true;
                     }
 
+                    // Headers described with a content type rather than a
+                    // schema are ignored.
+                    let headers = response
+                        .headers
+                        .iter()
+                        .map(|(header_name, header_or_ref)| {
+                            let header = header_or_ref.item(components)?;
+                            let openapiv3::ParameterSchemaOrContent::Schema(schema) =
+                                &header.format
+                            else {
+                                return Ok(None);
+                            };
+                            let name = sanitize(
+                                &format!(
+                                    "{}-{}-header",
+                                    operation.operation_id.as_ref().unwrap(),
+                                    header_name,
+                                ),
+                                Case::Pascal,
+                            );
+                            let typ = self.add_schema_type(schema, name)?;
+                            Ok(Some(OperationResponseHeader {
+                                name: sanitize(header_name, Case::Snake),
+                                api_name: header_name.clone(),
+                                typ,
+                            }))
+                        })
+                        .filter_map(Result::transpose)
+                        .collect::<Result<Vec<_>>>()?;
+
                     let description = if response.description.is_empty() {
                         None
                     } else {
@@ -499,6 +660,8 @@ impl Generator {
                     Ok(OperationResponse {
                         status_code,
                         typ,
+                        contract,
+                        headers,
                         description,
                     })
                 })
@@ -512,6 +675,8 @@ impl Generator {
             responses.push(OperationResponse {
                 status_code: OperationResponseStatus::Range(2),
                 typ: OperationResponseKind::Raw,
+                contract: None,
+                headers: Vec::new(),
                 description: None,
             });
         }
@@ -521,6 +686,8 @@ impl Generator {
             responses.push(OperationResponse {
                 status_code: OperationResponseStatus::Code(101),
                 typ: OperationResponseKind::Upgrade,
+                contract: None,
+                headers: Vec::new(),
                 description: None,
             })
         }
@@ -534,8 +701,31 @@ impl Generator {
             )));
         }
 
+        // Operations that support range requests (i.e. that describe a 206
+        // Partial Content response or an Accept-Ranges header) may have their
+        // responses downloaded in pieces. We only do so for operations without
+        // a body so that the request can be sent repeatedly.
+        let ranged_download = !dropshot_websocket
+            && !params
+                .iter()
+                .any(|param| matches!(param.kind, OperationParameterKind::Body(_)))
+            && operation
+                .responses
+                .responses
+                .iter()
+                .any(|(status_code, response_or_ref)| {
+                    *status_code == StatusCode::Code(206)
+                        || response_or_ref.item(components).is_ok_and(|response| {
+                            response
+                                .headers
+                                .keys()
+                                .any(|name| name.eq_ignore_ascii_case("accept-ranges"))
+                        })
+                });
+
         Ok(OperationMethod {
             operation_id: sanitize(operation_id, Case::Snake),
+            api_operation_id: operation_id.clone(),
             tags: operation.tags.clone(),
             method: HttpMethod::from_str(method)?,
             path: tmp,
@@ -545,12 +735,55 @@ impl Generator {
             responses,
             dropshot_paginated,
             dropshot_websocket,
+            ranged_download,
+            deprecated: operation.deprecated,
+            index: 0,
         })
     }
 
+    /// Generate the `progenitor_client::OperationMetadata` for an operation.
+    pub(crate) fn operation_metadata(&self, method: &OperationMethod) -> TokenStream {
+        let operation_id = &method.operation_id;
+        let http_method = format_ident!("{}", method.method.as_str().to_ascii_uppercase());
+        let path = method.path.to_string();
+        let tags = &method.tags;
+        let idempotent = !matches!(method.method, HttpMethod::Post | HttpMethod::Patch);
+        let deprecated = method.deprecated;
+
+        // Only explicitly successful responses count; a default response may
+        // just as well describe errors.
+        let mut success_statuses = method
+            .responses
+            .iter()
+            .filter_map(|response| match response.status_code {
+                OperationResponseStatus::Code(code @ (101 | 200..=299)) => Some((code, code)),
+                OperationResponseStatus::Range(2) => Some((200, 299)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        success_statuses.sort_unstable();
+        success_statuses.dedup();
+        let success_statuses = success_statuses
+            .into_iter()
+            .map(|(min, max)| quote! { #min ..= #max });
+
+        quote! {
+            progenitor_client::OperationMetadata {
+                operation_id: #operation_id,
+                method: reqwest::Method::#http_method,
+                path: #path,
+                tags: &[ #(#tags),* ],
+                idempotent: #idempotent,
+                deprecated: #deprecated,
+                success_statuses: &[ #(#success_statuses),* ],
+            }
+        }
+    }
+
     pub(crate) fn positional_method(
         &mut self,
         method: &OperationMethod,
+        names: &PositionalNames,
         has_inner: bool,
     ) -> Result<TokenStream> {
         let operation_id = format_ident!("{}", method.operation_id);
@@ -562,6 +795,14 @@ impl Generator {
             .map(|param| {
                 let name = format_ident!("{}", param.name);
                 let typ = match (&param.typ, param.kind.is_optional()) {
+                    (OperationParameterType::Type(_), false)
+                        if matches!(
+                            param.kind,
+                            OperationParameterKind::Body(BodyContentType::JsonLines(_))
+                        ) =>
+                    {
+                        quote! { S }
+                    }
                     (OperationParameterType::Type(type_id), false) => self
                         .type_space
                         .get_type(type_id)
@@ -597,19 +838,71 @@ impl Generator {
                 && param.kind == OperationParameterKind::Body(BodyContentType::OctetStream)
         });
 
+        let json_lines_param =
+            method
+                .params
+                .iter()
+                .find_map(|param| match (&param.kind, &param.typ) {
+                    (
+                        OperationParameterKind::Body(BodyContentType::JsonLines(_)),
+                        OperationParameterType::Type(type_id),
+                    ) => Some(type_id),
+                    _ => None,
+                });
+
         let bounds = if raw_body_param {
-            quote! { <'a, B: Into<reqwest::Body> > }
+            quote! { <'a, B: Into<progenitor_client::UploadBody> > }
+        } else if let Some(type_id) = json_lines_param {
+            let item = self.type_space.get_type(type_id)?.ident();
+            quote! { <'a, S: futures::Stream<Item = #item> + Send + 'static> }
         } else {
             quote! { <'a> }
         };
 
+        // Builders convert a JSON Lines stream into a body as it's provided;
+        // here we do so before proceeding with the common code.
+        let json_lines_body = json_lines_param.map(|_| {
+            quote! {
+                let body = progenitor_client::json_lines_body(body);
+            }
+        });
+
         let doc_comment = make_doc_comment(method);
 
+        let param_names = method
+            .params
+            .iter()
+            .map(|param| format_ident!("{}", param.name))
+            .collect::<Vec<_>>();
+        let options_ident = unique_ident_from("options", &param_names);
+        let request_ident = unique_ident_from("request", &param_names);
+        let with_options_id = &names.with_options;
+        let request_id = &names.request;
+
         let MethodSigBody {
             success: success_type,
             error: error_type,
             body,
-        } = self.method_sig_body(method, quote! { Self }, quote! { self }, has_inner)?;
+            prepare,
+            download,
+        } = self.method_sig_body(
+            method,
+            quote! { Self },
+            quote! { self },
+            quote! { self.#request_id(#(#param_names,)*) },
+            has_inner,
+        )?;
+
+        let with_options_doc = format!(
+            "Sends the request of [`Client::{}`] with additional headers, query \
+            parameters, or context from `{}`.",
+            method.operation_id, options_ident,
+        );
+
+        let request_doc = format!(
+            "Prepares the request of [`Client::{}`] without sending it.",
+            method.operation_id,
+        );
 
         let method_impl = quote! {
             #[doc = #doc_comment]
@@ -619,9 +912,36 @@ impl Generator {
             ) -> Result<
                 ResponseValue<#success_type>,
                 Error<#error_type>,
+            > {
+                self.#with_options_id(
+                    #(#param_names,)*
+                    progenitor_client::RequestOptions::default(),
+                )
+                .await
+            }
+
+            #[doc = #with_options_doc]
+            pub async fn #with_options_id #bounds (
+                &'a self,
+                #(#params,)*
+                #options_ident: progenitor_client::RequestOptions,
+            ) -> Result<
+                ResponseValue<#success_type>,
+                Error<#error_type>,
             > {
                 #body
             }
+
+            #[doc = #request_doc]
+            pub fn #request_id #bounds (
+                &'a self,
+                #(#params),*
+            ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+                #json_lines_body
+                #prepare
+
+                Ok(#request_ident)
+            }
         };
 
         let stream_impl = method.dropshot_paginated.as_ref().map(|page_data| {
@@ -635,7 +955,7 @@ impl Generator {
             let stream_params = method
                 .params
                 .iter()
-                .zip(params)
+                .zip(&params)
                 .filter_map(|(param, stream)| {
                     if param.name.as_str() == "page_token" {
                         None
@@ -752,22 +1072,58 @@ impl Generator {
             }
         });
 
+        if download.is_some() {
+            self.uses_tokio = true;
+        }
+        let download_impl = download.map(|(writer, download_body)| {
+            let download_id = &names.download;
+            let doc_comment = format!(
+                "Downloads the response of [`Client::{}`] to `{}`, resuming with \
+                `Range` requests if the transfer is interrupted.\n\n\
+                Returns the number of bytes written.",
+                method.operation_id, writer,
+            );
+
+            quote! {
+                #[doc = #doc_comment]
+                #[cfg(not(target_arch = "wasm32"))]
+                pub async fn #download_id<'a, W>(
+                    &'a self,
+                    #(#params,)*
+                    #writer: &'a mut W,
+                ) -> Result<ResponseValue<u64>, Error<#error_type>>
+                where
+                    W: progenitor_client::AsyncWrite + Unpin + ?Sized,
+                {
+                    let #options_ident = progenitor_client::RequestOptions::default();
+                    #download_body
+                }
+            }
+        });
+
         let all = quote! {
             #method_impl
             #stream_impl
+            #download_impl
         };
 
         Ok(all)
     }
 
     /// Common code generation between positional and builder interface-styles.
-    /// Returns a struct with the success and error types and the core body
-    /// implementation that marshals arguments and executes the request.
+    /// Returns a struct with the success and error types, the code that
+    /// marshals arguments into a request, and the core body implementation
+    /// that executes the request produced by `prepared`, an expression of type
+    /// `Result<reqwest::Request, Error<Infallible>>`. The body expects a
+    /// `progenitor_client::RequestOptions` (named by
+    /// `unique_ident_from("options", ..)`) to be in scope; the code to prepare
+    /// the request expects the parameters.
     fn method_sig_body(
         &self,
         method: &OperationMethod,
         client_type: TokenStream,
         client_value: TokenStream,
+        prepared: TokenStream,
         has_inner: bool,
     ) -> Result<MethodSigBody> {
         let param_names = method
@@ -781,6 +1137,9 @@ impl Generator {
         let request_ident = unique_ident_from("request", &param_names);
         let response_ident = unique_ident_from("response", &param_names);
         let result_ident = unique_ident_from("result", &param_names);
+        let reconnect_ident = unique_ident_from("reconnect", &param_names);
+        let download_ident = unique_ident_from("download", &param_names);
+        let options_ident = unique_ident_from("options", &param_names);
 
         // Generate code for query parameters.
         let query_params = method
@@ -828,7 +1187,22 @@ impl Generator {
             })
             .collect::<Vec<_>>();
 
-        let headers_size = headers.len() + 1;
+        // Streamed upload bodies don't carry their length so we send it
+        // explicitly when it's known.
+        let upload_body = method.params.iter().any(|param| {
+            param.typ == OperationParameterType::RawBody
+                && param.kind == OperationParameterKind::Body(BodyContentType::OctetStream)
+        });
+        let upload_length = upload_body.then(|| {
+            quote! {
+                let body: progenitor_client::UploadBody = body.into();
+                if let Some(length) = body.content_length() {
+                    header_map.append(::reqwest::header::CONTENT_LENGTH, length.into());
+                }
+            }
+        });
+
+        let headers_size = headers.len() + 1 + usize::from(upload_body);
         let headers_build = quote! {
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(#headers_size);
             header_map.append(
@@ -837,6 +1211,7 @@ impl Generator {
             );
 
             #(#headers)*
+            #upload_length
         };
 
         let headers_use = quote! {
@@ -871,6 +1246,23 @@ impl Generator {
             })
             .collect();
 
+        // Check parameters against the constraints in their schemas.
+        let validation = method
+            .params
+            .iter()
+            .filter_map(|param| {
+                let constraints = param.constraints.as_ref()?;
+                let field = &param.api_name;
+                let ident = format_ident!("{}", param.name);
+                Some(quote! {
+                    progenitor_client::validate_request(#field, &#ident, {
+                        static CONSTRAINTS: progenitor_client::Constraints = #constraints;
+                        &CONSTRAINTS
+                    })?;
+                })
+            })
+            .collect::<Vec<_>>();
+
         let url_path = method.path.compile(url_renames, client_value.clone());
         let url_path = quote! {
             let #url_ident = #url_path;
@@ -889,7 +1281,7 @@ impl Generator {
                         ::reqwest::header::CONTENT_TYPE,
                         ::reqwest::header::HeaderValue::from_static("application/octet-stream"),
                     )
-                    .body(body)
+                    .body(body.into_body())
                 }),
                 (
                     OperationParameterKind::Body(BodyContentType::Text(mime_type)),
@@ -910,6 +1302,16 @@ impl Generator {
                     // Serialization errors are deferred.
                     .json(&body)
                 }),
+                (
+                    OperationParameterKind::Body(BodyContentType::JsonLines(mime_type)),
+                    OperationParameterType::Type(_),
+                ) => Some(quote! {
+                    .header(
+                        ::reqwest::header::CONTENT_TYPE,
+                        ::reqwest::header::HeaderValue::from_static(#mime_type),
+                    )
+                    .body(body)
+                }),
                 (
                     OperationParameterKind::Body(BodyContentType::FormUrlencoded),
                     OperationParameterType::Type(_),
@@ -925,7 +1327,8 @@ impl Generator {
             }
         });
         // ... and there can be at most one body.
-        assert!(body_func.clone().count() <= 1);
+        let body_func = body_func.collect::<Vec<_>>();
+        assert!(body_func.len() <= 1);
 
         let (success_response_items, response_type) =
             self.extract_responses(method, OperationResponseStatus::is_success_or_default);
@@ -938,7 +1341,20 @@ impl Generator {
                 }
             };
 
+            let contract = &response.contract;
             let decode = match &response.typ {
+                OperationResponseKind::Type(_) if contract.is_some() => {
+                    quote! {
+                        ResponseValue::from_response_checked(
+                            #response_ident,
+                            &#contract,
+                            |status, violation| {
+                                #client_value.contract_violation(status, violation, &info)
+                            },
+                        )
+                        .await
+                    }
+                }
                 OperationResponseKind::Type(_) => {
                     quote! {
                         ResponseValue::from_response(#response_ident).await
@@ -959,11 +1375,36 @@ impl Generator {
                         ResponseValue::upgrade(#response_ident).await
                     }
                 }
+                OperationResponseKind::EventStream(_) => {
+                    quote! {
+                        Ok(ResponseValue::event_stream(
+                            #response_ident,
+                            &#client_value.client,
+                            #reconnect_ident,
+                        ))
+                    }
+                }
+                OperationResponseKind::JsonLines(_) => {
+                    quote! {
+                        Ok(ResponseValue::json_lines(#response_ident))
+                    }
+                }
+                OperationResponseKind::JsonArray { field, .. } => {
+                    let field = match field {
+                        Some(field) => quote! { Some(#field) },
+                        None => quote! { None },
+                    };
+                    quote! {
+                        Ok(ResponseValue::json_array(#response_ident, #field))
+                    }
+                }
             };
 
             quote! { #pat => { #decode } }
         });
 
+        let success_response_matches = success_response_matches.collect::<Vec<_>>();
+
         // Errors...
         let (error_response_items, error_type) =
             self.extract_responses(method, OperationResponseStatus::is_error_or_default);
@@ -984,7 +1425,22 @@ impl Generator {
                 }
             };
 
+            let contract = &response.contract;
             let decode = match &response.typ {
+                OperationResponseKind::Type(_) if contract.is_some() => {
+                    quote! {
+                        Err(Error::ErrorResponse(
+                            ResponseValue::from_response_checked(
+                                #response_ident,
+                                &#contract,
+                                |status, violation| {
+                                    #client_value.contract_violation(status, violation, &info)
+                                },
+                            )
+                            .await?
+                        ))
+                    }
+                }
                 OperationResponseKind::Type(_) => {
                     quote! {
                         Err(Error::ErrorResponse(
@@ -1007,37 +1463,72 @@ impl Generator {
                         ))
                     }
                 }
-                OperationResponseKind::Upgrade => {
-                    if response.status_code == OperationResponseStatus::Default {
-                        return quote! {}; // catch-all handled below
-                    } else {
-                        todo!(
-                            "non-default error response handling for \
-                                upgrade requests is not yet implemented"
-                        );
+                OperationResponseKind::EventStream(_) => {
+                    quote! {
+                        Err(Error::ErrorResponse(
+                            ResponseValue::event_stream(
+                                #response_ident,
+                                &#client_value.client,
+                                None,
+                            )
+                        ))
+                    }
+                }
+                OperationResponseKind::JsonLines(_) => {
+                    quote! {
+                        Err(Error::ErrorResponse(
+                            ResponseValue::json_lines(#response_ident)
+                        ))
                     }
                 }
+                OperationResponseKind::JsonArray { .. } => {
+                    unreachable!("only success responses are streamed")
+                }
+                OperationResponseKind::Upgrade => {
+                    // Only default responses for websocket channels are
+                    // categorized as upgrades; specific error responses are
+                    // decoded according to their content (see
+                    // `process_operation`).
+                    assert!(response.status_code.is_default());
+                    return quote! {}; // catch-all handled below
+                }
             };
 
             quote! { #pat => { #decode } }
         });
+        let error_response_matches = error_response_matches.collect::<Vec<_>>();
 
-        let accept_header = matches!(
-            (&response_type, &error_type),
-            (OperationResponseKind::Type(_), _)
-                | (OperationResponseKind::None, OperationResponseKind::Type(_))
-        )
-        .then(|| {
+        let accept_mime = match (&response_type, &error_type) {
+            (OperationResponseKind::Type(_) | OperationResponseKind::JsonArray { .. }, _)
+            | (OperationResponseKind::None, OperationResponseKind::Type(_)) => {
+                Some("application/json")
+            }
+            (OperationResponseKind::EventStream(_), _) => Some("text/event-stream"),
+            (OperationResponseKind::JsonLines(_), _) => {
+                Some("application/x-ndjson, application/jsonl")
+            }
+            _ => None,
+        };
+        let accept_header = accept_mime.map(|mime| {
             quote! {
                     .header(
                         ::reqwest::header::ACCEPT,
                         ::reqwest::header::HeaderValue::from_static(
-                            "application/json",
+                            #mime,
                         ),
                     )
             }
         });
 
+        // Event streams retain a copy of the request (after any hooks have
+        // modified it) so that they may reconnect if asked to.
+        let reconnect_request = matches!(response_type, OperationResponseKind::EventStream(_))
+            .then(|| {
+                quote! {
+                    let #reconnect_ident = #request_ident.try_clone();
+                }
+            });
+
         // Generate the catch-all case for other statuses. If the operation
         // specifies a default response, we've already generated a default
         // match as part of error response code handling. (And we've handled
@@ -1046,6 +1537,18 @@ impl Generator {
         // API description.
         let default_response = match method.responses.iter().last() {
             Some(response) if response.status_code.is_default() => quote! {},
+            _ if self.settings.response_checking => {
+                quote! {
+                    _ => {
+                        #client_value.contract_violation(
+                            #response_ident.status(),
+                            &progenitor_client::ContractViolation::UndeclaredStatus,
+                            &info,
+                        );
+                        Err(Error::UnexpectedResponse(#response_ident))
+                    }
+                }
+            }
             _ => {
                 quote! { _ => Err(Error::UnexpectedResponse(#response_ident)), }
             }
@@ -1082,27 +1585,37 @@ impl Generator {
             }
         });
 
-        let operation_id = &method.operation_id;
+        let index = proc_macro2::Literal::usize_unsuffixed(method.index);
         let method_func = format_ident!("{}", method.method.as_str());
 
-        let body_impl = quote! {
-            #url_path
+        let prepare_request = |headers_build: &TokenStream| {
+            quote! {
+                #(#validation)*
 
-            #headers_build
+                #url_path
 
-            #[allow(unused_mut)]
-            let mut #request_ident = #client_value.client
-                . #method_func (#url_ident)
-                #accept_header
-                #(#body_func)*
-                #( .query(#query_params) )*
-                #headers_use
-                #websock_hdrs
-                .build()?;
+                #headers_build
 
-            let info = OperationInfo {
-                operation_id: #operation_id,
-            };
+                #[allow(unused_mut)]
+                let mut #request_ident = #client_value.client
+                    . #method_func (#url_ident)
+                    #accept_header
+                    #(#body_func)*
+                    #( .query(#query_params) )*
+                    #headers_use
+                    #websock_hdrs
+                    .build()?;
+            }
+        };
+
+        // Prepares the request as it would be sent, but without the
+        // additional options, hooks, or middleware.
+        let prepare_impl = prepare_request(&headers_build);
+
+        // Sends the prepared request.
+        let send_request = quote! {
+            let info = OperationInfo::new(&#client_type::OPERATIONS[#index])
+                .with_context(#options_ident.apply(&mut #request_ident)?);
 
             #pre_hook
             #pre_hook_async
@@ -1110,17 +1623,30 @@ impl Generator {
                 .pre(&mut #request_ident, &info)
                 .await?;
 
-            let #result_ident = #client_value
-                .exec(#request_ident, &info)
-                .await;
-
-            #client_value
-                .post(&#result_ident, &info)
-                .await?;
-            #post_hook_async
-            #post_hook
-
-            let #response_ident = #result_ident?;
+            #reconnect_request
+
+            let #response_ident = {
+                let info = &info;
+                #client_value
+                    .middleware
+                    .run(#request_ident, info, &|#request_ident| {
+                        Box::pin(async move {
+                            let #result_ident = #client_value
+                                .exec(#request_ident, info)
+                                .await;
+
+                            #client_value
+                                .post(&#result_ident, info)
+                                .await?;
+                            #post_hook_async
+                            #post_hook
+
+                            Ok(#result_ident?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
 
             match #response_ident.status().as_u16() {
                 // These will be of the form...
@@ -1157,11 +1683,55 @@ impl Generator {
                 #default_response
             }
         };
+        let body_impl = quote! {
+            let mut #request_ident = #prepared.map_err(Error::into_typed)?;
+
+            #send_request
+        };
+
+        // Binary responses from operations that support range requests may
+        // also be downloaded to a writer; each attempt sends the request
+        // with headers to resume where the previous one left off.
+        let download = (method.ranged_download && response_type == OperationResponseKind::Raw)
+            .then(|| {
+                let writer_ident = unique_ident_from("writer", &param_names);
+                let headers_size = headers_size + 2;
+                let headers_build = quote! {
+                    let mut header_map = ::reqwest::header::HeaderMap::with_capacity(#headers_size);
+                    header_map.append(
+                        ::reqwest::header::HeaderName::from_static("api-version"),
+                        ::reqwest::header::HeaderValue::from_static(#client_type::api_version()),
+                    );
+
+                    #(#headers)*
+                    #download_ident.range_headers(&mut header_map);
+                };
+                // Each attempt prepares the request anew, as the parameters
+                // are used again.
+                let prepare = prepare_request(&headers_build);
+                let download_body = quote! {
+                    let mut #download_ident = progenitor_client::RangedDownload::new(#writer_ident);
+                    loop {
+                        let #result_ident = async {
+                            #prepare
+
+                            #send_request
+                        }
+                        .await;
+                        if let Some(#result_ident) = #download_ident.receive(#result_ident).await {
+                            break #result_ident;
+                        }
+                    }
+                };
+                (writer_ident, download_body)
+            });
 
         Ok(MethodSigBody {
             success: response_type.into_tokens(&self.type_space),
             error: error_type.into_tokens(&self.type_space),
             body: body_impl,
+            prepare: prepare_impl,
+            download,
         })
     }
 
@@ -1201,11 +1771,23 @@ impl Generator {
             }
         }
 
-        let response_types = response_items
+        let mut response_types = response_items
             .iter()
             .map(|response| response.typ.clone())
             .collect::<BTreeSet<_>>();
 
+        // A websocket channel's default response is an upgrade, which also
+        // falls into the set of error cases. If the operation additionally
+        // specifies error responses, those determine the error type; the
+        // default will never produce an `Error::ErrorResponse`.
+        if response_types.len() > 1
+            && response_items.iter().all(|response| {
+                response.typ != OperationResponseKind::Upgrade || response.status_code.is_default()
+            })
+        {
+            response_types.remove(&OperationResponseKind::Upgrade);
+        }
+
         // TODO to deal with multiple response types, we'll need to create an
         // enum type with variants for each of the response types.
         assert!(response_types.len() <= 1);
@@ -1217,6 +1799,64 @@ impl Generator {
         (response_items, response_type)
     }
 
+    // Determines whether a response may be decoded incrementally: its type
+    // must be an array, or an object with a single array-valued property.
+    // Returns the item type and, for an object, the name of that property.
+    fn json_array_response(
+        &self,
+        type_id: &TypeId,
+        schema: &ReferenceOr<openapiv3::Schema>,
+        components: &Option<Components>,
+    ) -> Result<Option<(TypeId, Option<String>)>> {
+        let vec_item = |type_id: &TypeId| match self.type_space.get_type(type_id).ok()?.details() {
+            typify::TypeDetails::Vec(item) => Some(item),
+            typify::TypeDetails::Option(inner) => {
+                match self.type_space.get_type(&inner).ok()?.details() {
+                    typify::TypeDetails::Vec(item) => Some(item),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        let typ = self.type_space.get_type(type_id)?;
+        let details = match typ.details() {
+            typify::TypeDetails::Vec(item) => return Ok(Some((item, None))),
+            typify::TypeDetails::Struct(details) => details,
+            _ => return Ok(None),
+        };
+
+        let mut items = details
+            .properties()
+            .filter_map(|(_, prop_id)| vec_item(&prop_id));
+        let item = match (items.next(), items.next()) {
+            (Some(item), None) => item,
+            _ => return Ok(None),
+        };
+
+        // The property names in the generated type may have been changed to
+        // be valid identifiers, so we find the original name in the schema.
+        let openapiv3::SchemaKind::Type(openapiv3::Type::Object(object)) =
+            &schema.item(components)?.schema_kind
+        else {
+            return Ok(None);
+        };
+        let mut fields = Vec::new();
+        for (name, prop) in &object.properties {
+            let prop = prop.clone().unbox();
+            if let openapiv3::SchemaKind::Type(openapiv3::Type::Array(_)) =
+                &prop.item(components)?.schema_kind
+            {
+                fields.push(name.clone());
+            }
+        }
+
+        match fields.as_slice() {
+            [field] => Ok(Some((item, Some(field.clone())))),
+            _ => Ok(None),
+        }
+    }
+
     // Validates all the necessary conditions for Dropshot pagination. Returns
     // the paginated item type data if all conditions are met.
     fn dropshot_pagination_data(
@@ -1428,6 +2068,32 @@ impl Generator {
             .collect::<Vec<_>>();
 
         let client_ident = unique_ident_from("client", &param_names);
+        let options_ident = unique_ident_from("options", &param_names);
+        let request_ident = unique_ident_from("request", &param_names);
+        let state_ident = unique_ident_from("state", &param_names);
+
+        // Binary bodies may be read from a file.
+        if method.params.iter().any(|param| {
+            matches!(
+                param.kind,
+                OperationParameterKind::Body(BodyContentType::OctetStream)
+            )
+        }) {
+            self.uses_tokio = true;
+        }
+
+        // Typestate builders have a type parameter for each required
+        // parameter that records whether it has been provided.
+        let param_states = method
+            .params
+            .iter()
+            .map(|param| {
+                (self.settings.typestate_builders && param.kind.is_required())
+                    .then(|| format_ident!("{}State", sanitize(&param.name, Case::Pascal)))
+            })
+            .collect::<Vec<_>>();
+        let state_params = param_states.iter().flatten().collect::<Vec<_>>();
+        let state_pattern = (!state_params.is_empty()).then(|| quote! { #state_ident: _, });
 
         let mut cloneable = true;
 
@@ -1439,6 +2105,14 @@ impl Generator {
                 OperationParameterType::Type(type_id) => {
                     let ty = self.type_space.get_type(type_id)?;
 
+                    // JSON Lines bodies are streams of items that are
+                    // converted into a body when they're provided.
+                    if let OperationParameterKind::Body(BodyContentType::JsonLines(_)) = &param.kind
+                    {
+                        cloneable = false;
+                        return Ok(quote! { Result<reqwest::Body, String> });
+                    }
+
                     // For body parameters only, if there's a builder we'll
                     // nest that within this builder.
                     if let (OperationParameterKind::Body(_), Some(builder_name)) =
@@ -1456,7 +2130,12 @@ impl Generator {
 
                 OperationParameterType::RawBody => {
                     cloneable = false;
-                    Ok(quote! { Result<reqwest::Body, String> })
+                    match &param.kind {
+                        OperationParameterKind::Body(BodyContentType::OctetStream) => {
+                            Ok(quote! { Result<progenitor_client::UploadBody, String> })
+                        }
+                        _ => Ok(quote! { Result<reqwest::Body, String> }),
+                    }
                 }
             })
             .collect::<Result<Vec<_>>>()?;
@@ -1474,7 +2153,12 @@ impl Generator {
 
                     // Fill in the appropriate initial value for the
                     // param_types generated above.
-                    if let (OperationParameterKind::Body(_), Some(_)) = (&param.kind, ty.builder())
+                    if let OperationParameterKind::Body(BodyContentType::JsonLines(_)) = &param.kind
+                    {
+                        let err_msg = format!("{} was not initialized", param.name);
+                        Ok(quote! { Err(#err_msg.to_string()) })
+                    } else if let (OperationParameterKind::Body(_), Some(_)) =
+                        (&param.kind, ty.builder())
                     {
                         Ok(quote! { Ok(::std::default::Default::default()) })
                     } else if param.kind.is_required() {
@@ -1501,7 +2185,12 @@ impl Generator {
             .map(|param| match &param.typ {
                 OperationParameterType::Type(type_id) => {
                     let ty = self.type_space.get_type(type_id)?;
-                    if ty.builder().is_some() {
+                    if ty.builder().is_some()
+                        && !matches!(
+                            param.kind,
+                            OperationParameterKind::Body(BodyContentType::JsonLines(_))
+                        )
+                    {
                         let type_name = ty.ident();
                         Ok(quote! {
                             .and_then(|v| #type_name::try_from(v)
@@ -1520,9 +2209,53 @@ impl Generator {
         let param_impls = method
             .params
             .iter()
-            .map(|param| {
+            .zip(&param_states)
+            .map(|(param, state)| {
                 let param_name = format_ident!("{}", param.name);
+
+                // Setting a required parameter of a typestate builder marks
+                // it as provided.
+                let (ret_ty, ret) = match state {
+                    Some(state) => {
+                        let states = param_states.iter().flatten().map(|s| {
+                            if s == state {
+                                quote! { progenitor_client::Provided }
+                            } else {
+                                quote! { #s }
+                            }
+                        });
+                        (
+                            quote! { #struct_ident<'a, #(#states),*> },
+                            quote! {
+                                #struct_ident {
+                                    #client_ident: self.#client_ident,
+                                    #( #param_names: self.#param_names, )*
+                                    #options_ident: self.#options_ident,
+                                    #state_ident: ::std::marker::PhantomData,
+                                }
+                            },
+                        )
+                    }
+                    None => (quote! { Self }, quote! { self }),
+                };
                 match &param.typ {
+                    OperationParameterType::Type(type_id)
+                        if matches!(
+                            param.kind,
+                            OperationParameterKind::Body(BodyContentType::JsonLines(_))
+                        ) =>
+                    {
+                        let typ = self.type_space.get_type(type_id)?.ident();
+                        Ok(quote! {
+                            pub fn #param_name<S>(mut self, value: S) -> #ret_ty
+                                where S: futures::Stream<Item = #typ> + Send + 'static
+                            {
+                                self.#param_name =
+                                    Ok(progenitor_client::json_lines_body(value));
+                                #ret
+                            }
+                        })
+                    }
                     OperationParameterType::Type(type_id) => {
                         let ty = self.type_space.get_type(type_id)?;
                         match (ty.builder(), param.kind.is_optional()) {
@@ -1542,13 +2275,13 @@ impl Generator {
                                     pub fn #param_name<V>(
                                         mut self,
                                         value: V,
-                                    ) -> Self
+                                    ) -> #ret_ty
                                         where V: std::convert::TryInto<#typ>,
                                     {
                                         self.#param_name = value.try_into()
                                             .map(Some)
                                             .map_err(|_| #err_msg.to_string());
-                                        self
+                                        #ret
                                     }
                                 })
                             }
@@ -1563,12 +2296,12 @@ impl Generator {
                                     pub fn #param_name<V>(
                                         mut self,
                                         value: V,
-                                    ) -> Self
+                                    ) -> #ret_ty
                                         where V: std::convert::TryInto<#typ>,
                                     {
                                         self.#param_name = value.try_into()
                                             .map_err(|_| #err_msg.to_string());
-                                        self
+                                        #ret
                                     }
                                 })
                             }
@@ -1587,7 +2320,7 @@ impl Generator {
                                     param.name,
                                 );
                                 Ok(quote! {
-                                    pub fn body<V>(mut self, value: V) -> Self
+                                    pub fn body<V>(mut self, value: V) -> #ret_ty
                                     where
                                         V: std::convert::TryInto<#typ>,
                                         <V as std::convert::TryInto<#typ>>::Error:
@@ -1596,16 +2329,16 @@ impl Generator {
                                         self.body = value.try_into()
                                             .map(From::from)
                                             .map_err(|s| format!(#err_msg, s));
-                                        self
+                                        #ret
                                     }
 
-                                    pub fn body_map<F>(mut self, f: F) -> Self
+                                    pub fn body_map<F>(mut self, f: F) -> #ret_ty
                                     where
                                         F: std::ops::FnOnce(#builder_name)
                                             -> #builder_name,
                                     {
                                         self.body = self.body.map(f);
-                                        self
+                                        #ret
                                     }
                                 })
                             }
@@ -1616,14 +2349,27 @@ impl Generator {
                         OperationParameterKind::Body(BodyContentType::OctetStream) => {
                             let err_msg =
                                 format!("conversion to `reqwest::Body` for {} failed", param.name,);
+                            let file_name = format_ident!("{}_file", param.name);
+                            let file_err_msg =
+                                format!("opening file for {} failed: {{}}", param.name);
 
                             Ok(quote! {
-                                pub fn #param_name<B>(mut self, value: B) -> Self
-                                    where B: std::convert::TryInto<reqwest::Body>
+                                pub fn #param_name<B>(mut self, value: B) -> #ret_ty
+                                    where B: std::convert::TryInto<progenitor_client::UploadBody>
                                 {
                                     self.#param_name = value.try_into()
                                         .map_err(|_| #err_msg.to_string());
-                                    self
+                                    #ret
+                                }
+
+                                #[cfg(not(target_arch = "wasm32"))]
+                                pub fn #file_name<P>(mut self, path: P) -> #ret_ty
+                                    where P: AsRef<std::path::Path>
+                                {
+                                    self.#param_name =
+                                        progenitor_client::UploadBody::from_file(path)
+                                            .map_err(|e| format!(#file_err_msg, e));
+                                    #ret
                                 }
                             })
                         }
@@ -1632,14 +2378,14 @@ impl Generator {
                                 format!("conversion to `String` for {} failed", param.name,);
 
                             Ok(quote! {
-                                pub fn #param_name<V>(mut self, value: V) -> Self
+                                pub fn #param_name<V>(mut self, value: V) -> #ret_ty
                                     where V: std::convert::TryInto<String>
                                 {
                                     self.#param_name = value
                                         .try_into()
                                         .map_err(|_| #err_msg.to_string())
                                         .map(|v| v.into());
-                                    self
+                                    #ret
                                 }
                             })
                         }
@@ -1649,14 +2395,82 @@ impl Generator {
             })
             .collect::<Result<Vec<_>>>()?;
 
+        // Update operations may be made conditional on an entity tag for
+        // optimistic concurrency.
+        let if_match = matches!(
+            method.method,
+            HttpMethod::Put | HttpMethod::Patch | HttpMethod::Delete
+        )
+        .then(|| {
+            quote! {
+                /// Add an `If-Match` header so that the server applies the
+                /// update only if the resource still has the entity tag `etag`,
+                /// such as one from `ResponseValue::etag`.
+                pub fn with_if_match<V: AsRef<str>>(mut self, etag: V) -> Self {
+                    self.#options_ident = self.#options_ident.if_match(etag);
+                    self
+                }
+            }
+        });
+
+        // Every builder accepts additional headers, query parameters, and
+        // context for the request.
+        let options_impl = quote! {
+            /// Add a header to the request, replacing any header of the same
+            /// name.
+            pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+            where
+                reqwest::header::HeaderName: std::convert::TryFrom<K>,
+                <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error:
+                    std::fmt::Display,
+                reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+                <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error:
+                    std::fmt::Display,
+            {
+                self.#options_ident = self.#options_ident.header(name, value);
+                self
+            }
+
+            /// Add a query parameter to the request.
+            pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+            where
+                K: Into<String>,
+                V: ToString,
+            {
+                self.#options_ident = self.#options_ident.query(name, value);
+                self
+            }
+
+            /// Set a value available to hooks through `OperationInfo::context`.
+            pub fn with_context<T>(mut self, value: T) -> Self
+            where
+                T: std::any::Any + Send + Sync,
+            {
+                self.#options_ident = self.#options_ident.context(value);
+                self
+            }
+
+            /// Replace the additional headers, query parameters, and context
+            /// for the request.
+            pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+                self.#options_ident = options;
+                self
+            }
+
+            #if_match
+        };
+
         let MethodSigBody {
             success,
             error,
             body,
+            prepare,
+            download,
         } = self.method_sig_body(
             method,
             quote! { super::Client },
             quote! { #client_ident },
+            quote! { self.into_request() },
             has_inner,
         )?;
 
@@ -1667,14 +2481,28 @@ impl Generator {
         );
         let send_impl = quote! {
             #[doc = #send_doc]
-            pub async fn send(self) -> Result<
+            pub async fn send(mut self) -> Result<
                 ResponseValue<#success>,
                 Error<#error>,
             > {
+                // The options are applied as the request is sent, so that
+                // their context is available to hooks.
+                let #client_ident = self.#client_ident;
+                let #options_ident = std::mem::take(&mut self.#options_ident);
+
+                // Do the work.
+                #body
+            }
+
+            /// Prepares the request as [`Self::send`] would, but returns it
+            /// rather than sending it. Hooks and middleware don't run.
+            pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
                 // Destructure the builder for convenience.
                 let Self {
                     #client_ident,
                     #( #param_names, )*
+                    #options_ident,
+                    #state_pattern
                 } = self;
 
                 // Extract parameters into variables, returning an error if
@@ -1690,11 +2518,55 @@ impl Generator {
                         .map_err(Error::InvalidRequest)?;
                 )*
 
-                // Do the work.
-                #body
+                #prepare
+
+                #options_ident.apply(&mut #request_ident)?;
+                Ok(#request_ident)
             }
         };
 
+        if download.is_some() {
+            self.uses_tokio = true;
+        }
+        let download_impl = download.map(|(writer, download_body)| {
+            let download_doc = format!(
+                "Sends `{}` requests to `{}`, writing the response to `{}` and \
+                resuming with `Range` requests if the transfer is interrupted.\n\n\
+                Returns the number of bytes written.",
+                method.method.as_str().to_ascii_uppercase(),
+                method.path.to_string(),
+                writer,
+            );
+
+            quote! {
+                #[doc = #download_doc]
+                #[cfg(not(target_arch = "wasm32"))]
+                pub async fn download<W>(
+                    self,
+                    #writer: &mut W,
+                ) -> Result<ResponseValue<u64>, Error<#error>>
+                where
+                    W: progenitor_client::AsyncWrite + Unpin + ?Sized,
+                {
+                    let Self {
+                        #client_ident,
+                        #( #param_names, )*
+                        #options_ident,
+                        #state_pattern
+                    } = self;
+
+                    #(
+                    let #param_names =
+                        #param_names
+                            #param_finalize
+                            .map_err(Error::InvalidRequest)?;
+                    )*
+
+                    #download_body
+                }
+            }
+        });
+
         let stream_impl = method.dropshot_paginated.as_ref().map(|page_data| {
             // We're now using futures.
             self.uses_futures = true;
@@ -1856,12 +2728,55 @@ impl Generator {
             }
         };
 
+        if !state_params.is_empty() {
+            let provided = state_params
+                .iter()
+                .map(|_| quote! { progenitor_client::Provided });
+
+            return Ok(quote! {
+                #[doc = #struct_doc]
+                #derive
+                pub struct #struct_ident<
+                    'a,
+                    #( #state_params = progenitor_client::Missing, )*
+                > {
+                    #client_ident: &'a super::Client,
+                    #( #param_names: #param_types, )*
+                    #options_ident: progenitor_client::RequestOptions,
+                    #state_ident: ::std::marker::PhantomData<( #( #state_params, )* )>,
+                }
+
+                impl<'a> #struct_ident<'a> {
+                    pub fn new(client: &'a super::Client) -> Self {
+                        Self {
+                            #client_ident: client,
+                            #( #param_names: #param_values, )*
+                            #options_ident: ::std::default::Default::default(),
+                            #state_ident: ::std::marker::PhantomData,
+                        }
+                    }
+                }
+
+                impl<'a, #( #state_params, )*> #struct_ident<'a, #( #state_params, )*> {
+                    #( #param_impls )*
+                    #options_impl
+                }
+
+                impl<'a> #struct_ident<'a, #( #provided, )*> {
+                    #send_impl
+                    #stream_impl
+                    #download_impl
+                }
+            });
+        }
+
         Ok(quote! {
             #[doc = #struct_doc]
             #derive
             pub struct #struct_ident<'a> {
                 #client_ident: &'a super::Client,
                 #( #param_names: #param_types, )*
+                #options_ident: progenitor_client::RequestOptions,
             }
 
             impl<'a> #struct_ident<'a> {
@@ -1869,12 +2784,15 @@ impl Generator {
                     Self {
                         #client_ident: client,
                         #( #param_names: #param_values, )*
+                        #options_ident: ::std::default::Default::default(),
                     }
                 }
 
                 #( #param_impls )*
+                #options_impl
                 #send_impl
                 #stream_impl
+                #download_impl
             }
         })
     }
@@ -2095,6 +3013,7 @@ impl Generator {
                         schema
                     ))),
                 }?;
+                self.uses_upload_bodies = true;
                 OperationParameterType::RawBody
             }
             BodyContentType::Text(_) => {
@@ -2131,6 +3050,17 @@ impl Generator {
                 }?;
                 OperationParameterType::RawBody
             }
+            BodyContentType::JsonLines(_) => {
+                // The schema describes each line of the body; callers provide
+                // a stream of values of that type.
+                let name = sanitize(
+                    &format!("{}-body", operation.operation_id.as_ref().unwrap(),),
+                    Case::Pascal,
+                );
+                let typ = self.add_schema_type(schema, name)?;
+                self.uses_futures = true;
+                OperationParameterType::Type(typ)
+            }
             BodyContentType::Json | BodyContentType::FormUrlencoded => {
                 // TODO it would be legal to have the encoding field set for
                 // application/x-www-form-urlencoded content, but I'm not sure
@@ -2142,24 +3072,367 @@ impl Generator {
                     &format!("{}-body", operation.operation_id.as_ref().unwrap(),),
                     Case::Pascal,
                 );
-                let typ = self
-                    .type_space
-                    .add_type_with_name(&schema.to_schema(), Some(name))?;
+                let typ = self.add_schema_type(schema, name)?;
                 OperationParameterType::Type(typ)
             }
         };
 
+        // Only structured bodies are validated.
+        let constraints = match content_type {
+            BodyContentType::Json | BodyContentType::FormUrlencoded => {
+                self.param_constraints(schema, components)?
+            }
+            _ => None,
+        };
+
         Ok(Some(OperationParameter {
             name: "body".to_string(),
             api_name: "body".to_string(),
             description: body.description.clone(),
             typ,
             kind: OperationParameterKind::Body(content_type),
+            constraints,
         }))
     }
+
+    /// Add the type of a parameter, body, response, or header schema,
+    /// recording the schema for the generation of `proptest` strategies if
+    /// that's enabled.
+    fn add_schema_type(
+        &mut self,
+        schema: &ReferenceOr<openapiv3::Schema>,
+        name: String,
+    ) -> Result<TypeId> {
+        let typ = self
+            .type_space
+            .add_type_with_name(&schema.to_schema(), Some(name))?;
+        if self.settings.proptest {
+            self.type_schemas.push((typ.clone(), schema.clone()));
+        }
+        Ok(typ)
+    }
+
+    /// Generate the constraints from a parameter or body schema if request
+    /// validation is enabled and there are any.
+    fn param_constraints(
+        &mut self,
+        schema: &ReferenceOr<openapiv3::Schema>,
+        components: &Option<Components>,
+    ) -> Result<Option<TokenStream>> {
+        if !self.settings.request_validation {
+            return Ok(None);
+        }
+        schema_constraints(schema, components, &mut Vec::new(), &mut self.uses_regress)
+    }
+
+    fn response_contract(
+        &self,
+        schema: &ReferenceOr<openapiv3::Schema>,
+        components: &Option<Components>,
+    ) -> Result<Option<TokenStream>> {
+        if !self.settings.response_checking {
+            return Ok(None);
+        }
+        schema_contract(schema, components, &mut Vec::new()).map(Some)
+    }
+}
+
+/// Generate a `progenitor_client::Constraints` value for the validation
+/// keywords in `schema` (and the properties and items within it), or `None`
+/// if there aren't any. References already being expanded in `seen` are
+/// skipped so that recursive schemas terminate. Patterns are compiled once, on
+/// first use, with the `regress` crate; `uses_regress` is set if there are
+/// any.
+///
+/// An `allOf`, `oneOf`, or `anyOf` with a single subschema, as used to wrap a
+/// reference, is resolved to that subschema. Those with several subschemas
+/// aren't checked.
+fn schema_constraints(
+    schema: &ReferenceOr<openapiv3::Schema>,
+    components: &Option<Components>,
+    seen: &mut Vec<String>,
+    uses_regress: &mut bool,
+) -> Result<Option<TokenStream>> {
+    if let ReferenceOr::Reference { reference } = schema {
+        if seen.contains(reference) {
+            return Ok(None);
+        }
+        seen.push(reference.clone());
+    }
+
+    let mut fields = Vec::new();
+    match &schema.item(components)?.schema_kind {
+        openapiv3::SchemaKind::AllOf { all_of: subschemas }
+        | openapiv3::SchemaKind::OneOf { one_of: subschemas }
+        | openapiv3::SchemaKind::AnyOf { any_of: subschemas }
+            if subschemas.len() == 1 =>
+        {
+            let constraints = schema_constraints(&subschemas[0], components, seen, uses_regress)?;
+            if let ReferenceOr::Reference { .. } = schema {
+                seen.pop();
+            }
+            return Ok(constraints);
+        }
+        openapiv3::SchemaKind::Type(openapiv3::Type::String(string)) => {
+            if let Some(min) = string.min_length {
+                fields.push(quote! { min_length: Some(#min) });
+            }
+            if let Some(max) = string.max_length {
+                fields.push(quote! { max_length: Some(#max) });
+            }
+            if let Some(pattern) = &string.pattern {
+                if let Err(e) = regress::Regex::new(pattern) {
+                    return Err(Error::UnexpectedFormat(format!(
+                        "invalid pattern {:?}: {}",
+                        pattern, e
+                    )));
+                }
+                *uses_regress = true;
+                fields.push(quote! {
+                    pattern: Some((#pattern, {
+                        fn matches(value: &str) -> bool {
+                            static PATTERN: ::std::sync::LazyLock<::regress::Regex> =
+                                ::std::sync::LazyLock::new(|| {
+                                    ::regress::Regex::new(#pattern).unwrap()
+                                });
+                            PATTERN.find(value).is_some()
+                        }
+                        matches
+                    }))
+                });
+            }
+        }
+        openapiv3::SchemaKind::Type(openapiv3::Type::Number(number)) => {
+            if let Some(min) = number.minimum {
+                let exclusive = number.exclusive_minimum;
+                fields.push(quote! { minimum: Some((#min, #exclusive)) });
+            }
+            if let Some(max) = number.maximum {
+                let exclusive = number.exclusive_maximum;
+                fields.push(quote! { maximum: Some((#max, #exclusive)) });
+            }
+        }
+        openapiv3::SchemaKind::Type(openapiv3::Type::Integer(integer)) => {
+            if let Some(min) = integer.minimum {
+                let min = min as f64;
+                let exclusive = integer.exclusive_minimum;
+                fields.push(quote! { minimum: Some((#min, #exclusive)) });
+            }
+            if let Some(max) = integer.maximum {
+                let max = max as f64;
+                let exclusive = integer.exclusive_maximum;
+                fields.push(quote! { maximum: Some((#max, #exclusive)) });
+            }
+        }
+        openapiv3::SchemaKind::Type(openapiv3::Type::Array(array)) => {
+            if let Some(min) = array.min_items {
+                fields.push(quote! { min_items: Some(#min) });
+            }
+            if let Some(max) = array.max_items {
+                fields.push(quote! { max_items: Some(#max) });
+            }
+            if let Some(items) = &array.items {
+                if let Some(items) =
+                    schema_constraints(&items.clone().unbox(), components, seen, uses_regress)?
+                {
+                    fields.push(quote! { items: Some(&#items) });
+                }
+            }
+        }
+        openapiv3::SchemaKind::Type(openapiv3::Type::Object(object)) => {
+            let properties = object
+                .properties
+                .iter()
+                .filter_map(|(name, property)| {
+                    schema_constraints(&property.clone().unbox(), components, seen, uses_regress)
+                        .map(|constraints| constraints.map(|c| quote! { (#name, #c) }))
+                        .transpose()
+                })
+                .collect::<Result<Vec<_>>>()?;
+            if !properties.is_empty() {
+                fields.push(quote! { properties: &[ #(#properties),* ] });
+            }
+        }
+        _ => {}
+    }
+
+    if let ReferenceOr::Reference { .. } = schema {
+        seen.pop();
+    }
+
+    Ok((!fields.is_empty()).then(|| {
+        quote! {
+            progenitor_client::Constraints {
+                #(#fields,)*
+                ..progenitor_client::Constraints::NONE
+            }
+        }
+    }))
+}
+
+/// Generate a `progenitor_client::ContractSchema` value describing the
+/// enumerated values, required and permitted properties, and array items of
+/// `schema`. As with [`schema_constraints`], references already being
+/// expanded in `seen` are not checked further.
+fn schema_contract(
+    schema: &ReferenceOr<openapiv3::Schema>,
+    components: &Option<Components>,
+    seen: &mut Vec<String>,
+) -> Result<TokenStream> {
+    if let ReferenceOr::Reference { reference } = schema {
+        if seen.contains(reference) {
+            return Ok(quote! { progenitor_client::ContractSchema::ANY });
+        }
+        seen.push(reference.clone());
+    }
+
+    let item = schema.item(components)?;
+    let enum_values = |values: Vec<String>| {
+        // Nullable schemas permit null whether or not it's enumerated.
+        let null = (item.schema_data.nullable && !values.iter().any(|v| v == "null"))
+            .then(|| "null".to_string());
+        let values = values.into_iter().chain(null);
+        quote! { enum_values: &[ #(#values),* ] }
+    };
+
+    let mut fields = Vec::new();
+    match &item.schema_kind {
+        openapiv3::SchemaKind::Type(openapiv3::Type::String(string))
+            if !string.enumeration.is_empty() =>
+        {
+            fields.push(enum_values(enum_json(&string.enumeration)));
+        }
+        openapiv3::SchemaKind::Type(openapiv3::Type::Number(number))
+            if !number.enumeration.is_empty() =>
+        {
+            fields.push(enum_values(enum_json(&number.enumeration)));
+        }
+        openapiv3::SchemaKind::Type(openapiv3::Type::Integer(integer))
+            if !integer.enumeration.is_empty() =>
+        {
+            fields.push(enum_values(enum_json(&integer.enumeration)));
+        }
+        openapiv3::SchemaKind::Type(openapiv3::Type::Boolean(boolean))
+            if !boolean.enumeration.is_empty() =>
+        {
+            fields.push(enum_values(enum_json(&boolean.enumeration)));
+        }
+        openapiv3::SchemaKind::Type(openapiv3::Type::Array(array)) => {
+            if let Some(items) = &array.items {
+                let items = schema_contract(&items.clone().unbox(), components, seen)?;
+                fields.push(quote! { items: Some(&#items) });
+            }
+        }
+        openapiv3::SchemaKind::Type(openapiv3::Type::Object(object)) => {
+            let properties = object
+                .properties
+                .iter()
+                .map(|(name, property)| {
+                    let contract = schema_contract(&property.clone().unbox(), components, seen)?;
+                    Ok(quote! { (#name, #contract) })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let required = &object.required;
+            fields.push(quote! { properties: &[ #(#properties),* ] });
+            fields.push(quote! { required: &[ #(#required),* ] });
+            if matches!(
+                object.additional_properties,
+                Some(openapiv3::AdditionalProperties::Any(false))
+            ) {
+                fields.push(quote! { additional_properties: false });
+            }
+        }
+        // A lone subschema is typically a reference decorated with a
+        // description.
+        openapiv3::SchemaKind::AllOf { all_of: subschemas }
+        | openapiv3::SchemaKind::OneOf { one_of: subschemas }
+        | openapiv3::SchemaKind::AnyOf { any_of: subschemas }
+            if subschemas.len() == 1 =>
+        {
+            let contract = schema_contract(&subschemas[0], components, seen)?;
+            if let ReferenceOr::Reference { .. } = schema {
+                seen.pop();
+            }
+            return Ok(contract);
+        }
+        _ => {}
+    }
+
+    if let ReferenceOr::Reference { .. } = schema {
+        seen.pop();
+    }
+
+    if fields.is_empty() {
+        return Ok(quote! { progenitor_client::ContractSchema::ANY });
+    }
+    Ok(quote! {
+        progenitor_client::ContractSchema {
+            #(#fields,)*
+            ..progenitor_client::ContractSchema::ANY
+        }
+    })
+}
+
+/// The names of the methods generated for an operation in the positional
+/// interface in addition to the one named for the operation.
+pub(crate) struct PositionalNames {
+    pub with_options: proc_macro2::Ident,
+    pub request: proc_macro2::Ident,
+    pub download: proc_macro2::Ident,
+}
+
+/// Name the additional methods of each operation in the positional interface
+/// (e.g. `<op>_request` alongside `<op>`). Where one would have the same name
+/// as the method of an operation, the stream method of a paginated one, or
+/// another such method, a numeric suffix is added to it.
+pub(crate) fn positional_names(methods: &[OperationMethod]) -> Vec<PositionalNames> {
+    let mut taken = methods
+        .iter()
+        .flat_map(|method| {
+            let op = &method.operation_id;
+            [
+                Some(op.clone()),
+                method
+                    .dropshot_paginated
+                    .as_ref()
+                    .map(|_| format!("{}_stream", op)),
+            ]
+        })
+        .flatten()
+        .collect::<BTreeSet<_>>();
+
+    let mut unique = |name: String| {
+        let mut candidate = name.clone();
+        let mut suffix = 2;
+        while taken.contains(&candidate) {
+            candidate = format!("{}_{}", name, suffix);
+            suffix += 1;
+        }
+        taken.insert(candidate.clone());
+        format_ident!("{}", candidate)
+    };
+
+    methods
+        .iter()
+        .map(|method| {
+            let op = &method.operation_id;
+            PositionalNames {
+                with_options: unique(format!("{}_with_options", op)),
+                request: unique(format!("{}_request", op)),
+                download: unique(format!("{}_download", op)),
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn enum_json<T: serde::Serialize>(values: &[Option<T>]) -> Vec<String> {
+    values
+        .iter()
+        .map(|value| serde_json::to_string(value).unwrap())
+        .collect()
 }
 
-fn make_doc_comment(method: &OperationMethod) -> String {
+pub(crate) fn make_doc_comment(method: &OperationMethod) -> String {
     let mut buf = String::new();
 
     if let Some(summary) = &method.summary {
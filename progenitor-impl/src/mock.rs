@@ -0,0 +1,375 @@
+// Copyright 2025 Oxide Computer Company
+
+//! Analysis of operations shared by the generators of mocking extensions
+//! (`httpmock` and `wiremock`), so that each matches requests and forms
+//! responses just as the client sends and expects them.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use typify::TypeDetails;
+
+use crate::{
+    method::{
+        BodyContentType, OperationMethod, OperationParameter, OperationParameterKind,
+        OperationParameterType, OperationResponse, OperationResponseKind, OperationResponseStatus,
+    },
+    util::{sanitize, Case},
+    Generator,
+};
+
+/// An operation as seen by a mock server.
+pub(crate) struct MockOperation {
+    /// The name of the wrapper for matching requests.
+    pub when: Ident,
+    /// The name of the wrapper for forming responses.
+    pub then: Ident,
+    /// The HTTP method, in upper case.
+    pub method: String,
+    /// A regular expression that matches the path of any request.
+    pub path_re: String,
+    pub params: Vec<MockParameter>,
+    pub responses: Vec<MockResponse>,
+    /// Headers described for any of the responses.
+    pub response_headers: Vec<MockResponseHeader>,
+}
+
+pub(crate) struct MockParameter {
+    pub name: Ident,
+    /// Original name in the API.
+    pub api_name: String,
+    /// The type of the value to match.
+    pub arg_type: TokenStream,
+    pub required: bool,
+    pub kind: MockParameterKind,
+}
+
+pub(crate) enum MockParameterKind {
+    /// A path parameter; the format string takes the value and produces a
+    /// regular expression matching paths with that value.
+    Path(String),
+    Query(QueryShape),
+    Header,
+    /// A JSON body of the given type.
+    JsonBody(TokenStream),
+    /// A form body, matched as JSON.
+    FormBody,
+    /// A JSON Lines body, matched against its full sequence of items.
+    JsonLinesBody,
+    /// An octet-stream body, matched as JSON.
+    OctetStreamBody,
+    TextBody,
+}
+
+/// How the client encodes a query parameter.
+pub(crate) enum QueryShape {
+    /// A single pair.
+    Scalar,
+    /// A pair for each element, which has the given type.
+    Array(TokenStream),
+    /// A pair for each field.
+    Object,
+}
+
+pub(crate) struct MockResponse {
+    /// The name of the method that forms the response.
+    pub name: Ident,
+    pub status: MockResponseStatus,
+    pub body: MockResponseBody,
+}
+
+pub(crate) enum MockResponseStatus {
+    Code(u16),
+    /// A class of status codes (e.g. 4 for 4xx); the caller chooses one.
+    Range(u16),
+    /// Any status code; the caller chooses one.
+    Default,
+}
+
+pub(crate) enum MockResponseBody {
+    None,
+    /// A JSON body of the given type.
+    Json(TokenStream),
+    /// Any JSON value.
+    Raw,
+    /// A sequence of Server-Sent Events with data of the given type.
+    EventStream(TokenStream),
+    /// A sequence of JSON Lines items of the given type.
+    JsonLines(TokenStream),
+}
+
+pub(crate) struct MockResponseHeader {
+    /// The name of the method that sets the header.
+    pub name: Ident,
+    /// Original name in the API.
+    pub api_name: String,
+    pub typ: TokenStream,
+}
+
+impl MockParameter {
+    /// Generate a method that matches the value of the parameter with
+    /// `present` (which uses `value`). For optional parameters the value may
+    /// be `None`, in which case `absent` must match requests without the
+    /// parameter.
+    pub fn method(&self, present: TokenStream, absent: TokenStream) -> TokenStream {
+        let name = &self.name;
+        let arg_type = &self.arg_type;
+
+        if self.required {
+            // The value is required so we just check for a simple match.
+            quote! {
+                pub fn #name(self, value: #arg_type) -> Self {
+                    #present
+                }
+            }
+        } else {
+            // For optional values we permit an input that's an
+            // `Into<Option<T>`. This allows callers to specify a value or
+            // specify that the parameter must be absent with None.
+
+            // If the type is a ref, augment it with a lifetime that we'll also
+            // use in the function
+            let (lifetime, arg_type) = if let syn::Type::Reference(mut rr) =
+                syn::parse2::<syn::Type>(arg_type.clone()).unwrap()
+            {
+                rr.lifetime = Some(syn::Lifetime::new("'a", proc_macro2::Span::call_site()));
+                (Some(quote! { 'a, }), rr.to_token_stream())
+            } else {
+                (None, arg_type.clone())
+            };
+
+            quote! {
+                pub fn #name<#lifetime T>(
+                    self,
+                    value: T,
+                ) -> Self
+                where
+                    T: Into<Option<#arg_type>>,
+                {
+                    if let Some(value) = value.into() {
+                        #present
+                    } else {
+                        #absent
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Generator {
+    /// Describe an operation for the generation of a mock server.
+    pub(crate) fn mock_operation(&self, method: &OperationMethod) -> MockOperation {
+        let when = format_ident!(
+            "{}",
+            sanitize(&format!("{}-when", method.operation_id), Case::Pascal)
+        );
+        let then = format_ident!(
+            "{}",
+            sanitize(&format!("{}-then", method.operation_id), Case::Pascal)
+        );
+
+        let params = method
+            .params
+            .iter()
+            .map(|param| self.mock_parameter(method, param))
+            .collect();
+
+        // Methods for each discrete response. For specific status codes we use
+        // the name of that code; for classes of codes we use the class name
+        // and require a status code that must be within the prescribed range.
+        let responses = method
+            .responses
+            .iter()
+            .map(|response| self.mock_response(response))
+            .collect();
+
+        let mut header_names = Vec::new();
+        let response_headers = method
+            .responses
+            .iter()
+            .flat_map(|response| &response.headers)
+            .filter(|header| {
+                let api_name = header.api_name.to_ascii_lowercase();
+                if header_names.contains(&api_name) {
+                    false
+                } else {
+                    header_names.push(api_name);
+                    true
+                }
+            })
+            .map(|header| MockResponseHeader {
+                name: format_ident!("{}_header", header.name),
+                api_name: header.api_name.clone(),
+                typ: self
+                    .type_space
+                    .get_type(&header.typ)
+                    .unwrap()
+                    .parameter_ident(),
+            })
+            .collect();
+
+        MockOperation {
+            when,
+            then,
+            method: method.method.as_str().to_ascii_uppercase(),
+            path_re: method.path.as_wildcard(),
+            params,
+            responses,
+            response_headers,
+        }
+    }
+
+    fn mock_parameter(
+        &self,
+        method: &OperationMethod,
+        param: &OperationParameter,
+    ) -> MockParameter {
+        let OperationParameter {
+            name,
+            typ,
+            kind,
+            api_name,
+            description: _,
+            constraints: _,
+        } = param;
+
+        let arg_type = match typ {
+            // JSON Lines bodies are matched against the full sequence of
+            // items.
+            OperationParameterType::Type(arg_type_id)
+                if matches!(
+                    kind,
+                    OperationParameterKind::Body(BodyContentType::JsonLines(_))
+                ) =>
+            {
+                let arg_type = self.type_space.get_type(arg_type_id).unwrap().ident();
+                quote! { &[#arg_type] }
+            }
+            OperationParameterType::Type(arg_type_id) => self
+                .type_space
+                .get_type(arg_type_id)
+                .unwrap()
+                .parameter_ident(),
+            OperationParameterType::RawBody => match kind {
+                OperationParameterKind::Body(BodyContentType::OctetStream) => quote! {
+                    ::serde_json::Value
+                },
+                OperationParameterKind::Body(BodyContentType::Text(_)) => quote! {
+                    String
+                },
+                _ => unreachable!(),
+            },
+        };
+
+        let (required, kind) = match kind {
+            OperationParameterKind::Path => (
+                true,
+                MockParameterKind::Path(method.path.as_wildcard_param(api_name)),
+            ),
+            OperationParameterKind::Query(required) => {
+                // The client sends each element of an array query parameter
+                // as a separate pair, and each field of an object as a pair
+                // named for the field.
+                let shape = match typ {
+                    OperationParameterType::Type(type_id) => {
+                        match self.type_space.get_type(type_id).unwrap().details() {
+                            TypeDetails::Vec(item) | TypeDetails::Set(item) => QueryShape::Array(
+                                self.type_space.get_type(&item).unwrap().parameter_ident(),
+                            ),
+                            TypeDetails::Struct(_) | TypeDetails::Map(..) => QueryShape::Object,
+                            _ => QueryShape::Scalar,
+                        }
+                    }
+                    OperationParameterType::RawBody => QueryShape::Scalar,
+                };
+                (*required, MockParameterKind::Query(shape))
+            }
+            OperationParameterKind::Header(required) => (*required, MockParameterKind::Header),
+            OperationParameterKind::Body(body_content_type) => {
+                let kind = match (body_content_type, typ) {
+                    (BodyContentType::JsonLines(_), OperationParameterType::Type(_)) => {
+                        MockParameterKind::JsonLinesBody
+                    }
+                    (BodyContentType::FormUrlencoded, OperationParameterType::Type(_)) => {
+                        MockParameterKind::FormBody
+                    }
+                    (_, OperationParameterType::Type(type_id)) => MockParameterKind::JsonBody(
+                        self.type_space.get_type(type_id).unwrap().ident(),
+                    ),
+                    (BodyContentType::OctetStream, OperationParameterType::RawBody) => {
+                        MockParameterKind::OctetStreamBody
+                    }
+                    (BodyContentType::Text(_), OperationParameterType::RawBody) => {
+                        MockParameterKind::TextBody
+                    }
+                    _ => unreachable!(),
+                };
+                (true, kind)
+            }
+        };
+
+        MockParameter {
+            name: format_ident!("{}", name),
+            api_name: api_name.clone(),
+            arg_type,
+            required,
+            kind,
+        }
+    }
+
+    fn mock_response(&self, response: &OperationResponse) -> MockResponse {
+        let body = match &response.typ {
+            // Streamed arrays are provided in full.
+            OperationResponseKind::Type(arg_type_id)
+            | OperationResponseKind::JsonArray {
+                body: arg_type_id, ..
+            } => MockResponseBody::Json(
+                self.type_space
+                    .get_type(arg_type_id)
+                    .unwrap()
+                    .parameter_ident(),
+            ),
+            OperationResponseKind::None | OperationResponseKind::Upgrade => MockResponseBody::None,
+            OperationResponseKind::Raw => MockResponseBody::Raw,
+            OperationResponseKind::EventStream(arg_type_id) => MockResponseBody::EventStream(
+                self.type_space.get_type(arg_type_id).unwrap().ident(),
+            ),
+            OperationResponseKind::JsonLines(arg_type_id) => {
+                MockResponseBody::JsonLines(self.type_space.get_type(arg_type_id).unwrap().ident())
+            }
+        };
+
+        let (name, status) = match response.status_code {
+            OperationResponseStatus::Code(status_code) => {
+                let canonical_reason = http::StatusCode::from_u16(status_code)
+                    .unwrap()
+                    .canonical_reason()
+                    .unwrap();
+                (
+                    format_ident!("{}", &sanitize(canonical_reason, Case::Snake)),
+                    MockResponseStatus::Code(status_code),
+                )
+            }
+            OperationResponseStatus::Range(status_type) => {
+                let status_string = match status_type {
+                    1 => "informational",
+                    2 => "success",
+                    3 => "redirect",
+                    4 => "client_error",
+                    5 => "server_error",
+                    _ => unreachable!(),
+                };
+                (
+                    format_ident!("{}", status_string),
+                    MockResponseStatus::Range(status_type),
+                )
+            }
+            OperationResponseStatus::Default => (
+                format_ident!("default_response"),
+                MockResponseStatus::Default,
+            ),
+        };
+
+        MockResponse { name, status, body }
+    }
+}
@@ -0,0 +1,266 @@
+// Copyright 2025 Oxide Computer Company
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+    method::{
+        BodyContentType, OperationMethod, OperationParameterKind, OperationParameterType,
+        OperationResponseKind, OperationResponseStatus,
+    },
+    util::{sanitize, unique_ident_from, Case},
+    Generator, InterfaceStyle, Result,
+};
+
+/// A parameter of an operation as a field of its `operations` struct.
+struct OperationField {
+    name: proc_macro2::Ident,
+    /// The owned type of the field.
+    typ: TokenStream,
+    /// The value passed to the positional method, or to the builder method
+    /// of the same name.
+    arg: TokenStream,
+    optional: bool,
+}
+
+impl Generator {
+    /// Generate the `Operation` enum with the parameters of each operation,
+    /// the `OperationResponse` and `OperationError` enums with its results,
+    /// and `Client::execute_operation` to dispatch it.
+    pub(crate) fn operation_enum(&self, methods: &[OperationMethod]) -> Result<TokenStream> {
+        let mut structs = Vec::new();
+        let mut variants = Vec::new();
+        let mut responses = Vec::new();
+        let mut errors = Vec::new();
+        let mut arms = Vec::new();
+        let mut debug_responses = true;
+        let mut debug_errors = true;
+
+        for method in methods {
+            let operation_id = &method.operation_id;
+            let variant = format_ident!("{}", sanitize(operation_id, Case::Pascal));
+            let fields = method
+                .params
+                .iter()
+                .map(|param| self.operation_field(param))
+                .collect::<Result<Vec<_>>>()?;
+
+            let struct_doc = format!("Parameters of the `{}` operation.", operation_id);
+            let field_names = fields.iter().map(|field| &field.name).collect::<Vec<_>>();
+            let field_types = fields.iter().map(|field| &field.typ);
+            structs.push(quote! {
+                #[doc = #struct_doc]
+                #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+                pub struct #variant {
+                    #( pub #field_names: #field_types, )*
+                }
+
+                impl From<#variant> for super::Operation {
+                    fn from(value: #variant) -> Self {
+                        Self::#variant(value)
+                    }
+                }
+            });
+            variants.push(quote! {
+                #[serde(rename = #operation_id)]
+                #variant(operations::#variant),
+            });
+
+            let (_, success_kind) =
+                self.extract_responses(method, OperationResponseStatus::is_success_or_default);
+            let (_, error_kind) =
+                self.extract_responses(method, OperationResponseStatus::is_error_or_default);
+            debug_responses &= has_debug(&success_kind);
+            debug_errors &= has_debug(&error_kind);
+            let success = success_kind.into_tokens(&self.type_space);
+            let error = error_kind.into_tokens(&self.type_space);
+            responses.push(quote! { #variant(#success), });
+            errors.push(quote! { #variant(#error), });
+
+            let op = format_ident!("{}", operation_id);
+            let call = match self.settings.interface {
+                InterfaceStyle::Positional => {
+                    let args = fields.iter().map(|field| &field.arg);
+                    quote! { self.#op(#(#args),*) }
+                }
+                InterfaceStyle::Builder => {
+                    let names = fields
+                        .iter()
+                        .map(|field| field.name.clone())
+                        .collect::<Vec<_>>();
+                    let request = unique_ident_from("request", &names);
+                    let (required, optional): (Vec<_>, Vec<_>) =
+                        fields.iter().partition(|field| !field.optional);
+                    let required = required.iter().map(|field| {
+                        let OperationField { name, arg, .. } = field;
+                        quote! { .#name(#arg) }
+                    });
+                    let optional = optional.iter().map(|field| {
+                        let OperationField { name, arg, .. } = field;
+                        quote! {
+                            if let Some(#name) = #name {
+                                #request = #request.#name(#arg);
+                            }
+                        }
+                    });
+                    quote! {
+                        {
+                            #[allow(unused_mut)]
+                            let mut #request = builder::#variant::new(self) #(#required)*;
+                            #(#optional)*
+                            #request.send()
+                        }
+                    }
+                }
+            };
+            arms.push(quote! {
+                Operation::#variant(operations::#variant { #(#field_names),* }) => #call
+                    .await
+                    .map(|response| {
+                        let status = response.status();
+                        let headers = response.headers().clone();
+                        ResponseValue::new(
+                            OperationResponse::#variant(response.into_inner()),
+                            status,
+                            headers,
+                        )
+                    })
+                    .map_err(|e| e.map(OperationError::#variant)),
+            });
+        }
+
+        let operation_ids = methods.iter().map(|method| &method.operation_id);
+        let variant_idents = methods
+            .iter()
+            .map(|method| format_ident!("{}", sanitize(&method.operation_id, Case::Pascal)))
+            .collect::<Vec<_>>();
+        let response_derive = debug_responses.then(|| quote! { #[derive(Debug)] });
+        let error_derive = debug_errors.then(|| quote! { #[derive(Debug)] });
+
+        Ok(quote! {
+            /// Parameters of each operation, for use in [`Operation`].
+            pub mod operations {
+                #[allow(unused_imports)]
+                use super::types;
+
+                #(#structs)*
+            }
+
+            /// An operation with its parameters, which may be serialized (for
+            /// example, to queue it) and later executed with
+            /// [`Client::execute_operation`].
+            #[allow(clippy::large_enum_variant)]
+            #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+            #[serde(tag = "operation_id", content = "parameters")]
+            pub enum Operation {
+                #(#variants)*
+            }
+
+            impl Operation {
+                /// The `operationId` of the operation.
+                pub fn operation_id(&self) -> &'static str {
+                    match *self {
+                        #( Self::#variant_idents(_) => #operation_ids, )*
+                    }
+                }
+            }
+
+            /// The successful response of an [`Operation`].
+            #[allow(clippy::large_enum_variant)]
+            #response_derive
+            pub enum OperationResponse {
+                #(#responses)*
+            }
+
+            /// The error response body of an [`Operation`].
+            #[allow(clippy::large_enum_variant)]
+            #error_derive
+            pub enum OperationError {
+                #(#errors)*
+            }
+
+            #[allow(clippy::all)]
+            impl Client {
+                /// Execute `operation` as the corresponding method would,
+                /// with the response or error body in the variant of that
+                /// operation.
+                pub async fn execute_operation(
+                    &self,
+                    operation: Operation,
+                ) -> Result<ResponseValue<OperationResponse>, Error<OperationError>> {
+                    match operation {
+                        #(#arms)*
+                    }
+                }
+            }
+        })
+    }
+
+    fn operation_field(&self, param: &crate::method::OperationParameter) -> Result<OperationField> {
+        let name = format_ident!("{}", param.name);
+        let optional = param.kind.is_optional();
+        let positional = self.settings.interface == InterfaceStyle::Positional;
+
+        let (typ, arg) = match (&param.typ, &param.kind) {
+            (
+                OperationParameterType::Type(type_id),
+                OperationParameterKind::Body(BodyContentType::JsonLines(_)),
+            ) => {
+                let item = self.type_space.get_type(type_id)?.ident();
+                (
+                    quote! { ::std::vec::Vec<#item> },
+                    quote! { futures::stream::iter(#name) },
+                )
+            }
+            (OperationParameterType::Type(type_id), _) => {
+                let ty = self.type_space.get_type(type_id)?;
+                let owned = ty.ident();
+                // Positional methods take most parameters by reference.
+                let param_type = ty.parameter_ident_with_lifetime("a").to_string();
+                let arg = match (positional, param_type.starts_with('&'), optional) {
+                    (false, _, _) | (true, false, _) => quote! { #name },
+                    (true, true, false) => quote! { &#name },
+                    (true, true, true) if param_type == "& 'a str" => {
+                        quote! { #name.as_deref() }
+                    }
+                    (true, true, true) => quote! { #name.as_ref() },
+                };
+                let typ = if optional {
+                    quote! { Option<#owned> }
+                } else {
+                    owned
+                };
+                (typ, arg)
+            }
+            (
+                OperationParameterType::RawBody,
+                OperationParameterKind::Body(BodyContentType::Text(_)),
+            ) => (quote! { ::std::string::String }, quote! { #name }),
+            (OperationParameterType::RawBody, _) => {
+                (quote! { ::std::vec::Vec<u8> }, quote! { #name })
+            }
+        };
+
+        // Builders take optional values without the `Option`.
+        let arg = if optional && !positional {
+            quote! { #name }
+        } else {
+            arg
+        };
+
+        Ok(OperationField {
+            name,
+            typ,
+            arg,
+            optional,
+        })
+    }
+}
+
+/// Whether the type of a response implements `Debug`.
+fn has_debug(kind: &OperationResponseKind) -> bool {
+    matches!(
+        kind,
+        OperationResponseKind::Type(_) | OperationResponseKind::None
+    )
+}
@@ -0,0 +1,575 @@
+// Copyright 2025 Oxide Computer Company
+
+//! Generation of a Dropshot API trait
+
+use openapiv3::OpenAPI;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::{
+    method::{
+        BodyContentType, HttpMethod, OperationMethod, OperationParameterKind,
+        OperationParameterType, OperationResponseKind, OperationResponseStatus,
+    },
+    util::{sanitize, Case},
+    Error, Generator, Result,
+};
+
+struct ServerOp {
+    /// Path, query, and header parameter structs.
+    params: TokenStream,
+    handler: TokenStream,
+    /// Whether the operation has header parameters.
+    headers: bool,
+}
+
+/// The parameters of an operation as extracted by a server handler.
+pub(crate) struct ServerParams {
+    /// Path and query parameter structs, for the `parameters` module.
+    pub params: TokenStream,
+    pub path: Option<Ident>,
+    pub query: Option<Ident>,
+    pub body: Option<ServerBody>,
+    /// Whether the operation has header parameters.
+    pub headers: bool,
+}
+
+pub(crate) enum ServerBody {
+    Json(TokenStream),
+    Form(TokenStream),
+    /// A body of any other content type, as received.
+    Untyped,
+}
+
+/// The responses of an operation as returned by a server handler.
+pub(crate) struct ServerResponses {
+    /// The name of the `StatusCode` constant for successful responses.
+    pub status: Ident,
+    pub success: ServerResponse,
+    pub error: ServerResponse,
+}
+
+pub(crate) enum ServerResponse {
+    Json(TokenStream),
+    Empty,
+    /// Any other response, as built by the handler.
+    Raw,
+}
+
+impl Generator {
+    /// Generate a Dropshot API trait with a handler for each operation.
+    ///
+    /// The `crate_path` parameter should be a valid Rust path corresponding to
+    /// the SDK whose `types` module the handlers use. This can include `::`
+    /// and instances of `-` in the crate name should be converted to `_`. As
+    /// Dropshot describes its parameters and bodies with `schemars`, the SDK
+    /// must be generated with `schemars::JsonSchema` among its derives.
+    pub fn server(&mut self, spec: &OpenAPI, crate_path: &str) -> Result<TokenStream> {
+        let raw_methods = self.operation_methods(spec)?;
+
+        let ops = raw_methods
+            .iter()
+            .map(|method| self.server_method(method))
+            .collect::<Result<Vec<_>>>()?;
+
+        let params = ops.iter().map(|op| &op.params);
+        let handlers = ops.iter().map(|op| &op.handler);
+        let header_value = ops.iter().any(|op| op.headers).then(|| {
+            quote! {
+                /// Decode the value of an optional header, rejecting values
+                /// that aren't valid for its type.
+                fn header_value<T>(
+                    headers: &http::HeaderMap,
+                    name: &str,
+                ) -> Result<Option<T>, dropshot::HttpError>
+                where
+                    T: std::str::FromStr,
+                    T::Err: std::fmt::Display,
+                {
+                    let Some(value) = headers.get(name) else {
+                        return Ok(None);
+                    };
+                    let value = value.to_str().map_err(|e| {
+                        dropshot::HttpError::for_bad_request(
+                            None,
+                            format!("invalid value for header {}: {}", name, e),
+                        )
+                    })?;
+                    value.parse().map(Some).map_err(|e| {
+                        dropshot::HttpError::for_bad_request(
+                            None,
+                            format!("invalid value for header {}: {}", name, e),
+                        )
+                    })
+                }
+            }
+        });
+
+        let crate_path = syn::TypePath {
+            qself: None,
+            path: syn::parse_str(crate_path)
+                .unwrap_or_else(|_| panic!("{} is not a valid identifier", crate_path)),
+        };
+
+        let code = quote! {
+            #[allow(unused_imports)]
+            use #crate_path::types;
+
+            pub mod parameters {
+
+                //! Path, query, and header parameters of each operation, as
+                //! used by the handlers of [`Api`](super::Api).
+
+                #[allow(unused_imports)]
+                use #crate_path::types;
+
+                #(#params)*
+
+                #header_value
+            }
+
+            /// A Dropshot API with a handler for each operation.
+            ///
+            /// Dropshot has no extractor for headers, so handlers decode the
+            /// header parameters of an operation from the request in `rqctx`
+            /// with the `from_headers` function of its `parameters` struct.
+            /// Paginated operations receive their scan parameters with
+            /// `PaginationParams`; the page selector of their `ResultsPage`
+            /// is any JSON value. An implementation may be served with the
+            /// `ApiDescription` from `api_mod::api_description`.
+            #[dropshot::api_description]
+            pub trait Api {
+                /// The context shared by all handlers.
+                type Context;
+
+                #(#handlers)*
+            }
+        };
+        Ok(code)
+    }
+
+    /// Generate the path and query parameter structs of an operation, and
+    /// determine the type of its body.
+    ///
+    /// Dropshot also requires the structs to implement `JsonSchema`. When
+    /// `paginated` is set, the `page_token` and `limit` parameters of a
+    /// paginated operation are left to `PaginationParams`, so that the query
+    /// struct holds only its scan parameters.
+    pub(crate) fn server_params(
+        &mut self,
+        method: &OperationMethod,
+        json_schema: bool,
+        paginated: bool,
+    ) -> Result<ServerParams> {
+        let pascal = sanitize(&method.operation_id, Case::Pascal);
+
+        let mut path_fields = Vec::new();
+        let mut query_fields = Vec::new();
+        let mut body = None;
+        let mut headers = false;
+
+        for param in &method.params {
+            let name = format_ident!("{}", param.name);
+            let api_name = &param.api_name;
+            let rename = (&param.name != api_name).then(|| quote! { #[serde(rename = #api_name)] });
+            let doc = param.description.as_ref().map(|d| quote! { #[doc = #d] });
+
+            match (&param.kind, &param.typ) {
+                (OperationParameterKind::Query(_), _)
+                    if paginated
+                        && method.dropshot_paginated.is_some()
+                        && matches!(api_name.as_str(), "page_token" | "limit") => {}
+                (OperationParameterKind::Path, OperationParameterType::Type(type_id)) => {
+                    let typ = self.type_space.get_type(type_id)?.ident();
+                    path_fields.push(quote! {
+                        #doc
+                        #rename
+                        pub #name: #typ,
+                    });
+                }
+                (
+                    OperationParameterKind::Query(required),
+                    OperationParameterType::Type(type_id),
+                ) => {
+                    let typ = self.type_space.get_type(type_id)?.ident();
+                    let typ = if *required {
+                        typ
+                    } else {
+                        quote! { Option<#typ> }
+                    };
+                    query_fields.push(quote! {
+                        #doc
+                        #rename
+                        pub #name: #typ,
+                    });
+                }
+                (OperationParameterKind::Header(_), _) => headers = true,
+                (
+                    OperationParameterKind::Body(BodyContentType::Json),
+                    OperationParameterType::Type(type_id),
+                ) => {
+                    let typ = self.type_space.get_type(type_id)?.ident();
+                    body = Some(ServerBody::Json(typ));
+                }
+                (
+                    OperationParameterKind::Body(BodyContentType::FormUrlencoded),
+                    OperationParameterType::Type(type_id),
+                ) => {
+                    let typ = self.type_space.get_type(type_id)?.ident();
+                    body = Some(ServerBody::Form(typ));
+                }
+                // Other bodies are provided as received.
+                (OperationParameterKind::Body(_), _) => {
+                    body = Some(ServerBody::Untyped);
+                }
+                (_, OperationParameterType::RawBody) => {
+                    return Err(Error::InternalError(format!(
+                        "unexpected raw parameter for {}",
+                        method.operation_id,
+                    )))
+                }
+            }
+        }
+
+        let derive_json_schema = json_schema.then(|| quote! { , schemars::JsonSchema });
+        let mut params = TokenStream::new();
+        let mut path = None;
+        let mut query = None;
+        if !path_fields.is_empty() {
+            let ident = format_ident!("{}Path", pascal);
+            let doc = format!(
+                "Path parameters of the `{}` operation.",
+                method.operation_id
+            );
+            params.extend(quote! {
+                #[doc = #doc]
+                #[derive(Clone, Debug, serde::Deserialize #derive_json_schema)]
+                pub struct #ident {
+                    #(#path_fields)*
+                }
+            });
+            path = Some(ident);
+        }
+        if !query_fields.is_empty() {
+            let ident = format_ident!("{}Query", pascal);
+            let doc = format!(
+                "Query parameters of the `{}` operation.",
+                method.operation_id
+            );
+            params.extend(quote! {
+                #[doc = #doc]
+                #[derive(Clone, Debug, serde::Deserialize #derive_json_schema)]
+                pub struct #ident {
+                    #(#query_fields)*
+                }
+            });
+            query = Some(ident);
+        }
+
+        Ok(ServerParams {
+            params,
+            path,
+            query,
+            body,
+            headers,
+        })
+    }
+
+    fn server_method(&mut self, method: &OperationMethod) -> Result<ServerOp> {
+        let op = format_ident!("{}", method.operation_id);
+        let path = method.path.to_string();
+        let ServerParams {
+            mut params,
+            path: path_struct,
+            query: query_struct,
+            body,
+            headers,
+        } = self.server_params(method, true, true)?;
+        if headers {
+            params.extend(self.server_headers(method)?);
+        }
+
+        let mut args = Vec::new();
+        if let Some(ident) = path_struct {
+            args.push(quote! { path: dropshot::Path<parameters::#ident> });
+        }
+        match (&method.dropshot_paginated, query_struct) {
+            (Some(_), scan_params) => {
+                let scan_params = match scan_params {
+                    Some(ident) => quote! { parameters::#ident },
+                    None => quote! { dropshot::EmptyScanParams },
+                };
+                args.push(quote! {
+                    query: dropshot::Query<
+                        dropshot::PaginationParams<#scan_params, serde_json::Value>
+                    >
+                });
+            }
+            (None, Some(ident)) => {
+                args.push(quote! { query: dropshot::Query<parameters::#ident> });
+            }
+            (None, None) => {}
+        }
+        let mut content_type = None;
+        match body {
+            Some(ServerBody::Json(typ)) => {
+                args.push(quote! { body: dropshot::TypedBody<#typ> });
+            }
+            Some(ServerBody::Form(typ)) => {
+                args.push(quote! { body: dropshot::TypedBody<#typ> });
+                content_type = Some("application/x-www-form-urlencoded");
+            }
+            Some(ServerBody::Untyped) => {
+                args.push(quote! { body: dropshot::UntypedBody });
+            }
+            None => {}
+        }
+
+        // The first success response determines the response type; those
+        // that Dropshot can't describe are returned as they are built.
+        let success = method
+            .responses
+            .iter()
+            .find(|response| response.status_code.is_success_or_default());
+        let websocket = success
+            .map(|response| matches!(response.typ, OperationResponseKind::Upgrade))
+            .unwrap_or(false);
+        let response = match success.map(|response| (&response.status_code, &response.typ)) {
+            // Paginated operations return their items with a page selector.
+            _ if method.dropshot_paginated.is_some() => {
+                let item = &method.dropshot_paginated.as_ref().unwrap().item;
+                let item = self.type_space.get_type(item)?.ident();
+                quote! { dropshot::HttpResponseOk<dropshot::ResultsPage<#item>> }
+            }
+            Some((OperationResponseStatus::Code(code), OperationResponseKind::Type(type_id)))
+            | Some((
+                OperationResponseStatus::Code(code),
+                OperationResponseKind::JsonArray { body: type_id, .. },
+            )) if matches!(code, 200..=202) => {
+                let typ = self.type_space.get_type(type_id)?.ident();
+                match code {
+                    200 => quote! { dropshot::HttpResponseOk<#typ> },
+                    201 => quote! { dropshot::HttpResponseCreated<#typ> },
+                    _ => quote! { dropshot::HttpResponseAccepted<#typ> },
+                }
+            }
+            Some((OperationResponseStatus::Code(204), OperationResponseKind::None)) => {
+                match method.method {
+                    HttpMethod::Delete => quote! { dropshot::HttpResponseDeleted },
+                    _ => quote! { dropshot::HttpResponseUpdatedNoContent },
+                }
+            }
+            _ => quote! { http::Response<dropshot::Body> },
+        };
+
+        let http_method = match method.method {
+            HttpMethod::Get => quote! { GET },
+            HttpMethod::Put => quote! { PUT },
+            HttpMethod::Post => quote! { POST },
+            HttpMethod::Delete => quote! { DELETE },
+            HttpMethod::Options => quote! { OPTIONS },
+            HttpMethod::Head => quote! { HEAD },
+            HttpMethod::Patch => quote! { PATCH },
+            HttpMethod::Trace => {
+                return Err(Error::UnexpectedFormat(format!(
+                    "Dropshot does not support the TRACE method of {}",
+                    method.operation_id,
+                )))
+            }
+        };
+
+        // Keep the original operation ID if it isn't the method name.
+        let operation_id = &method.api_operation_id;
+        let operation_id = (*operation_id != method.operation_id)
+            .then(|| quote! { operation_id = #operation_id, });
+        let tags = &method.tags;
+        let tags = (!tags.is_empty()).then(|| quote! { tags = [#(#tags),*], });
+        let deprecated = method.deprecated.then(|| quote! { deprecated = true, });
+        let content_type = content_type.map(|c| quote! { content_type = #c, });
+
+        let doc = server_doc(method);
+
+        let handler = if websocket {
+            quote! {
+                #[doc = #doc]
+                #[channel {
+                    protocol = WEBSOCKETS,
+                    path = #path,
+                    #operation_id
+                    #tags
+                    #deprecated
+                }]
+                async fn #op(
+                    rqctx: dropshot::RequestContext<Self::Context>,
+                    #(#args,)*
+                    upgraded: dropshot::WebsocketConnection,
+                ) -> dropshot::WebsocketChannelResult;
+            }
+        } else {
+            quote! {
+                #[doc = #doc]
+                #[endpoint {
+                    method = #http_method,
+                    path = #path,
+                    #operation_id
+                    #tags
+                    #deprecated
+                    #content_type
+                }]
+                async fn #op(
+                    rqctx: dropshot::RequestContext<Self::Context>,
+                    #(#args,)*
+                ) -> Result<#response, dropshot::HttpError>;
+            }
+        };
+
+        Ok(ServerOp {
+            params,
+            handler,
+            headers,
+        })
+    }
+
+    /// Generate the header parameter struct of an operation, with a function
+    /// that decodes it from the headers of a request.
+    fn server_headers(&mut self, method: &OperationMethod) -> Result<TokenStream> {
+        let ident = format_ident!("{}Headers", sanitize(&method.operation_id, Case::Pascal));
+
+        let mut fields = Vec::new();
+        let mut values = Vec::new();
+        for param in &method.params {
+            let (OperationParameterKind::Header(required), OperationParameterType::Type(type_id)) =
+                (&param.kind, &param.typ)
+            else {
+                continue;
+            };
+            let name = format_ident!("{}", param.name);
+            let api_name = &param.api_name;
+            let doc = param.description.as_ref().map(|d| quote! { #[doc = #d] });
+            let typ = self.type_space.get_type(type_id)?.ident();
+
+            if *required {
+                let missing = format!("missing header {}", api_name);
+                fields.push(quote! {
+                    #doc
+                    pub #name: #typ,
+                });
+                values.push(quote! {
+                    #name: header_value(headers, #api_name)?.ok_or_else(|| {
+                        dropshot::HttpError::for_bad_request(None, #missing.to_string())
+                    })?,
+                });
+            } else {
+                fields.push(quote! {
+                    #doc
+                    pub #name: Option<#typ>,
+                });
+                values.push(quote! {
+                    #name: header_value(headers, #api_name)?,
+                });
+            }
+        }
+
+        let doc = format!(
+            "Header parameters of the `{}` operation.",
+            method.operation_id
+        );
+        Ok(quote! {
+            #[doc = #doc]
+            #[derive(Clone, Debug)]
+            pub struct #ident {
+                #(#fields)*
+            }
+
+            impl #ident {
+                /// Decode the header parameters from the headers of a
+                /// request, responding with a client error if one is missing
+                /// or invalid.
+                pub fn from_headers(
+                    headers: &http::HeaderMap,
+                ) -> Result<Self, dropshot::HttpError> {
+                    Ok(Self {
+                        #(#values)*
+                    })
+                }
+            }
+        })
+    }
+
+    /// Determine the types of the successful and error responses of an
+    /// operation. Successful responses are sent with the status code of the
+    /// first.
+    pub(crate) fn server_responses(&self, method: &OperationMethod) -> Result<ServerResponses> {
+        let (success_items, success_kind) =
+            self.extract_responses(method, OperationResponseStatus::is_success_or_default);
+        let status = match success_items.first().map(|response| &response.status_code) {
+            Some(OperationResponseStatus::Code(code)) => *code,
+            Some(OperationResponseStatus::Range(range)) => *range * 100,
+            Some(OperationResponseStatus::Default) | None => 200,
+        };
+        let status = success_status_name(status).ok_or_else(|| {
+            Error::UnexpectedFormat(format!(
+                "unsupported status code {} for the successful responses of {}",
+                status, method.operation_id,
+            ))
+        })?;
+        let status = format_ident!("{}", status);
+        let success = match &success_kind {
+            OperationResponseKind::Type(type_id)
+            | OperationResponseKind::JsonArray { body: type_id, .. } => {
+                ServerResponse::Json(self.type_space.get_type(type_id)?.ident())
+            }
+            OperationResponseKind::None => ServerResponse::Empty,
+            _ => ServerResponse::Raw,
+        };
+
+        let (_, error_kind) =
+            self.extract_responses(method, OperationResponseStatus::is_error_or_default);
+        let error = match &error_kind {
+            OperationResponseKind::Type(type_id) => {
+                ServerResponse::Json(self.type_space.get_type(type_id)?.ident())
+            }
+            _ => ServerResponse::Raw,
+        };
+
+        Ok(ServerResponses {
+            status,
+            success,
+            error,
+        })
+    }
+}
+
+/// The name of the `StatusCode` constant for a successful status code, so that
+/// generated code needn't convert a number at runtime.
+fn success_status_name(status: u16) -> Option<&'static str> {
+    Some(match status {
+        100 => "CONTINUE",
+        101 => "SWITCHING_PROTOCOLS",
+        102 => "PROCESSING",
+        200 => "OK",
+        201 => "CREATED",
+        202 => "ACCEPTED",
+        203 => "NON_AUTHORITATIVE_INFORMATION",
+        204 => "NO_CONTENT",
+        205 => "RESET_CONTENT",
+        206 => "PARTIAL_CONTENT",
+        207 => "MULTI_STATUS",
+        208 => "ALREADY_REPORTED",
+        226 => "IM_USED",
+        _ => return None,
+    })
+}
+
+/// The doc comment of a handler.
+pub(crate) fn server_doc(method: &OperationMethod) -> String {
+    match (&method.summary, &method.description) {
+        (Some(summary), Some(description)) => format!("{}\n\n{}", summary, description),
+        (Some(text), None) | (None, Some(text)) => text.clone(),
+        (None, None) => format!(
+            "Handles `{} {}`",
+            method.method.as_str().to_uppercase(),
+            method.path.to_string(),
+        ),
+    }
+}
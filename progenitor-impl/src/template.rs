@@ -84,6 +84,32 @@ impl PathTemplate {
             .collect::<String>();
         format!("^{}$", inner)
     }
+
+    /// Render the template as an axum route. Axum requires that each
+    /// parameter ends its path segment.
+    pub fn as_axum_route(&self) -> Result<String> {
+        let mut route = String::new();
+        let mut components = self.components.iter().peekable();
+        while let Some(component) = components.next() {
+            match component {
+                Component::Constant(name) => route.push_str(name),
+                Component::Parameter(name) => {
+                    match components.peek() {
+                        None => (),
+                        Some(Component::Constant(next)) if next.starts_with('/') => (),
+                        Some(_) => {
+                            return Err(Error::InvalidPath(format!(
+                                "parameter {} must end a path segment for axum",
+                                name,
+                            )))
+                        }
+                    }
+                    route.push_str(&format!("{{{}}}", name));
+                }
+            }
+        }
+        Ok(route)
+    }
 }
 
 pub fn parse(t: &str) -> Result<PathTemplate> {
@@ -325,4 +351,14 @@ mod tests {
         };
         assert_eq!(want.to_string(), out.to_string());
     }
+
+    #[test]
+    fn axum_route() {
+        let t = parse("//measure/{one}/and/{two}").unwrap();
+        assert_eq!(t.as_axum_route().unwrap(), "/measure/{one}/and/{two}");
+
+        for path in ["/abc/def:{one}:jkl", "/{foo}-{bar}", "/{foo}{bar}"] {
+            assert!(parse(path).unwrap().as_axum_route().is_err(), "{}", path);
+        }
+    }
 }
@@ -3,7 +3,9 @@
 use std::collections::BTreeMap;
 
 use indexmap::IndexMap;
-use openapiv3::{Components, Parameter, ReferenceOr, RequestBody, Response, Schema};
+use openapiv3::{
+    Components, Example, Header, Parameter, ReferenceOr, RequestBody, Response, Schema,
+};
 use unicode_ident::{is_xid_continue, is_xid_start};
 
 use crate::Result;
@@ -69,6 +71,18 @@ impl ComponentLookup for Response {
     }
 }
 
+impl ComponentLookup for Header {
+    fn get_components(components: &Components) -> &IndexMap<String, ReferenceOr<Self>> {
+        &components.headers
+    }
+}
+
+impl ComponentLookup for Example {
+    fn get_components(components: &Components) -> &IndexMap<String, ReferenceOr<Self>> {
+        &components.examples
+    }
+}
+
 impl ComponentLookup for Schema {
     fn get_components(components: &Components) -> &IndexMap<String, ReferenceOr<Self>> {
         &components.schemas
@@ -129,3 +143,137 @@ pub(crate) fn unique_ident_from(
         name.insert_str(0, "_");
     }
 }
+
+/// The most times an unbounded repetition is expanded while looking for a
+/// string that matches a pattern and satisfies its length constraints.
+const PATTERN_REPETITIONS: usize = 64;
+
+/// Find a string that matches `pattern`, with ECMAScript semantics as the
+/// generated client checks it, and whose length is within `min_length` and
+/// `max_length`: `preferred` if it qualifies, otherwise one built from the
+/// pattern by taking the first alternative of each choice and the first
+/// character of each class, expanding repetitions until the value is long
+/// enough. `None` if no such string was found.
+pub(crate) fn pattern_example(
+    pattern: &str,
+    preferred: Option<&str>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+) -> Option<String> {
+    let regex = regress::Regex::new(pattern).ok()?;
+    let satisfies = |value: &str| {
+        let len = value.chars().count();
+        min_length.is_none_or(|min| len >= min)
+            && max_length.is_none_or(|max| len <= max)
+            && regex.find(value).is_some()
+    };
+    if let Some(value) = preferred.filter(|value| satisfies(value)) {
+        return Some(value.to_string());
+    }
+
+    // Lookarounds don't contribute to the value, so they're dropped before
+    // parsing; the result is checked against the full pattern.
+    let hir = regex_syntax::Parser::new()
+        .parse(&strip_lookarounds(pattern)?)
+        .ok()?;
+    (0..=PATTERN_REPETITIONS).find_map(|extra| {
+        let mut value = String::new();
+        hir_example(&hir, extra, &mut value)?;
+        satisfies(&value).then_some(value)
+    })
+}
+
+/// Remove the lookahead and lookbehind groups from `pattern`, which
+/// `regex_syntax` doesn't support. `None` if the pattern is unbalanced.
+fn strip_lookarounds(pattern: &str) -> Option<String> {
+    let mut out = String::new();
+    let mut chars = pattern.chars().peekable();
+    // The depth of nested groups within the lookaround being removed.
+    let mut skipping = 0usize;
+    let mut in_class = false;
+    while let Some(c) = chars.next() {
+        let mut token = c.to_string();
+        match c {
+            '\\' => token.push(chars.next()?),
+            '[' if !in_class => in_class = true,
+            ']' if in_class => in_class = false,
+            '(' if !in_class => {
+                let rest = chars.clone().take(3).collect::<String>();
+                if skipping > 0 {
+                    skipping += 1;
+                } else if ["?=", "?!", "?<=", "?<!"]
+                    .iter()
+                    .any(|prefix| rest.starts_with(prefix))
+                {
+                    skipping = 1;
+                }
+            }
+            ')' if !in_class && skipping > 0 => {
+                skipping -= 1;
+                continue;
+            }
+            _ => {}
+        }
+        if skipping == 0 {
+            out.push_str(&token);
+        }
+    }
+    (skipping == 0).then_some(out)
+}
+
+/// Append to `out` a string matching `hir`, repeating each repetition
+/// `extra` times more than its minimum where its maximum allows. `None` if
+/// `hir` can't be matched by a string, such as for a byte class.
+fn hir_example(hir: &regex_syntax::hir::Hir, extra: usize, out: &mut String) -> Option<()> {
+    use regex_syntax::hir::{Class, HirKind};
+
+    match hir.kind() {
+        HirKind::Empty | HirKind::Look(_) => {}
+        HirKind::Literal(literal) => out.push_str(std::str::from_utf8(&literal.0).ok()?),
+        HirKind::Class(Class::Unicode(class)) => out.push(class_example(
+            class
+                .ranges()
+                .iter()
+                .map(|range| (range.start(), range.end())),
+        )?),
+        HirKind::Class(Class::Bytes(class)) => out.push(class_example(
+            class
+                .ranges()
+                .iter()
+                .filter(|range| range.start().is_ascii())
+                .map(|range| (char::from(range.start()), char::from(range.end().min(0x7f)))),
+        )?),
+        HirKind::Repetition(repetition) => {
+            let min = repetition.min as usize;
+            let count = match repetition.max {
+                Some(max) => (min + extra).min(max as usize),
+                None => min + extra,
+            };
+            for _ in 0..count {
+                hir_example(&repetition.sub, extra, out)?;
+            }
+        }
+        HirKind::Capture(capture) => hir_example(&capture.sub, extra, out)?,
+        HirKind::Concat(hirs) => {
+            for hir in hirs {
+                hir_example(hir, extra, out)?;
+            }
+        }
+        HirKind::Alternation(hirs) => hir_example(hirs.first()?, extra, out)?,
+    }
+    Some(())
+}
+
+/// Pick a character from the ranges of a class, preferring an ASCII digit
+/// or letter.
+fn class_example(ranges: impl Iterator<Item = (char, char)> + Clone) -> Option<char> {
+    ['0'..='9', 'a'..='z', 'A'..='Z']
+        .into_iter()
+        .find_map(|preferred| {
+            ranges.clone().find_map(|(start, end)| {
+                let start = start.max(*preferred.start());
+                (start <= end.min(*preferred.end())).then_some(start)
+            })
+        })
+        .or_else(|| ranges.clone().next().map(|(start, _)| start))
+}
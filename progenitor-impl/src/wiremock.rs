@@ -0,0 +1,415 @@
+// Copyright 2025 Oxide Computer Company
+
+//! Generation of mocking extensions for `wiremock`
+
+use openapiv3::OpenAPI;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+
+use crate::{
+    method::OperationMethod,
+    mock::{
+        MockOperation, MockParameterKind, MockResponse, MockResponseBody, MockResponseHeader,
+        MockResponseStatus, QueryShape,
+    },
+    Generator, Result,
+};
+
+struct MockOp {
+    when: TokenStream,
+    when_impl: TokenStream,
+    then: TokenStream,
+    then_impl: TokenStream,
+}
+
+impl Generator {
+    /// Generate a strongly-typed mocking extension to the `wiremock` crate.
+    ///
+    /// The `crate_path` parameter should be a valid Rust path corresponding to
+    /// the SDK. This can include `::` and instances of `-` in the crate name
+    /// should be converted to `_`.
+    pub fn wiremock(&mut self, spec: &OpenAPI, crate_path: &str) -> Result<TokenStream> {
+        let raw_methods = self.operation_methods(spec)?;
+
+        let methods = raw_methods
+            .iter()
+            .map(|method| self.wiremock_method(method))
+            .collect::<Vec<_>>();
+
+        let op = raw_methods
+            .iter()
+            .map(|method| format_ident!("{}", &method.operation_id))
+            .collect::<Vec<_>>();
+        let when = methods.iter().map(|op| &op.when).collect::<Vec<_>>();
+        let when_impl = methods.iter().map(|op| &op.when_impl).collect::<Vec<_>>();
+        let then = methods.iter().map(|op| &op.then).collect::<Vec<_>>();
+        let then_impl = methods.iter().map(|op| &op.then_impl).collect::<Vec<_>>();
+
+        let crate_path = syn::TypePath {
+            qself: None,
+            path: syn::parse_str(crate_path)
+                .unwrap_or_else(|_| panic!("{} is not a valid identifier", crate_path)),
+        };
+
+        let code = quote! {
+            pub mod operations {
+
+                //! [`MockBuilder`](::wiremock::MockBuilder) and
+                //! [`ResponseTemplate`](::wiremock::ResponseTemplate)
+                //! wrappers for each operation. Each can be converted to
+                //! its inner type with a call to `into_inner()`. This can
+                //! be used to explicitly deviate from permitted values.
+
+                use #crate_path::*;
+
+                #(
+                    pub struct #when(::wiremock::MockBuilder);
+                    #when_impl
+
+                    pub struct #then(::wiremock::ResponseTemplate);
+                    #then_impl
+                )*
+            }
+
+            /// An extension trait for [`Mock`](::wiremock::Mock) that adds a
+            /// constructor for each operation. These are the equivalent of
+            /// type-checked [`given()`](::wiremock::Mock::given) calls; finish
+            /// each with `respond_with()` and mount the resulting mock.
+            pub trait MockExt {
+                #(
+                    fn #op() -> operations::#when;
+                )*
+            }
+
+            impl MockExt for ::wiremock::Mock {
+                #(
+                    fn #op() -> operations::#when {
+                        operations::#when::new(
+                            ::wiremock::Mock::given(::wiremock::matchers::any())
+                        )
+                    }
+                )*
+            }
+        };
+        Ok(code)
+    }
+
+    fn wiremock_method(&self, method: &OperationMethod) -> MockOp {
+        let MockOperation {
+            when,
+            then,
+            method: http_method,
+            path_re,
+            params,
+            responses,
+            response_headers,
+        } = self.mock_operation(method);
+
+        // Generate methods corresponding to each parameter so that callers
+        // can specify a prescribed value for that parameter.
+        let when_methods = params.iter().map(|param| {
+            let api_name = &param.api_name;
+            match &param.kind {
+                MockParameterKind::Path(re_fmt) => param.method(
+                    quote! {
+                        Self(self.0.and(::wiremock::matchers::path_regex(
+                            format!(#re_fmt, value.to_string())
+                        )))
+                    },
+                    quote! {},
+                ),
+                MockParameterKind::Query(shape) => {
+                    let present = match shape {
+                        QueryShape::Scalar => quote! {
+                            Self(self.0.and(::wiremock::matchers::query_param(
+                                #api_name,
+                                value.to_string(),
+                            )))
+                        },
+                        QueryShape::Array(_) => quote! {
+                            Self(value.iter().fold(self.0, |when, item| {
+                                when.and(::wiremock::matchers::query_param(
+                                    #api_name,
+                                    item.to_string(),
+                                ))
+                            }))
+                        },
+                        QueryShape::Object => quote! {
+                            Self(match ::serde_json::to_value(value).unwrap() {
+                                ::serde_json::Value::Object(fields) => fields
+                                    .into_iter()
+                                    .fold(self.0, |when, (key, field)| match field {
+                                        ::serde_json::Value::Null => when,
+                                        ::serde_json::Value::String(field) => when.and(
+                                            ::wiremock::matchers::query_param(key, field)
+                                        ),
+                                        field => when.and(::wiremock::matchers::query_param(
+                                            key,
+                                            field.to_string(),
+                                        )),
+                                    }),
+                                _ => unreachable!(),
+                            })
+                        },
+                    };
+                    let method = param.method(
+                        present,
+                        quote! {
+                            Self(self.0.and(
+                                ::wiremock::matchers::query_param_is_missing(#api_name)
+                            ))
+                        },
+                    );
+
+                    // Match a single element of an array.
+                    let contains = match shape {
+                        QueryShape::Array(item_type) => {
+                            let fn_name = format_ident!("{}_contains", param.name);
+                            quote! {
+                                pub fn #fn_name(self, value: #item_type) -> Self {
+                                    Self(self.0.and(::wiremock::matchers::query_param(
+                                        #api_name,
+                                        value.to_string(),
+                                    )))
+                                }
+                            }
+                        }
+                        _ => quote! {},
+                    };
+
+                    quote! {
+                        #method
+                        #contains
+                    }
+                }
+                MockParameterKind::Header => {
+                    let method = param.method(
+                        quote! {
+                            Self(self.0.and(::wiremock::matchers::header(
+                                #api_name,
+                                value.to_string(),
+                            )))
+                        },
+                        quote! {
+                            Self(self.0.and(|req: &::wiremock::Request| {
+                                !req.headers.contains_key(#api_name)
+                            }))
+                        },
+                    );
+                    let fn_name = format_ident!("{}_exists", param.name);
+                    quote! {
+                        #method
+
+                        pub fn #fn_name(self) -> Self {
+                            Self(self.0.and(::wiremock::matchers::header_exists(#api_name)))
+                        }
+                    }
+                }
+                MockParameterKind::JsonBody(body_type) => {
+                    let method = param.method(
+                        quote! {
+                            Self(self.0.and(::wiremock::matchers::body_json(value)))
+                        },
+                        quote! {},
+                    );
+                    let partial_name = format_ident!("{}_partial", param.name);
+                    let matches_name = format_ident!("{}_matches", param.name);
+                    quote! {
+                        #method
+
+                        pub fn #partial_name(self, value: ::serde_json::Value) -> Self {
+                            Self(self.0.and(::wiremock::matchers::body_partial_json(value)))
+                        }
+
+                        pub fn #matches_name<F>(self, predicate: F) -> Self
+                        where
+                            F: Fn(&#body_type) -> bool + Send + Sync + 'static,
+                        {
+                            Self(self.0.and(move |req: &::wiremock::Request| {
+                                req.body_json::<#body_type>()
+                                    .is_ok_and(|body| predicate(&body))
+                            }))
+                        }
+                    }
+                }
+                MockParameterKind::FormBody | MockParameterKind::OctetStreamBody => param.method(
+                    quote! {
+                        Self(self.0.and(::wiremock::matchers::body_json(value)))
+                    },
+                    quote! {},
+                ),
+                MockParameterKind::JsonLinesBody => param.method(
+                    quote! {
+                        Self(self.0.and(::wiremock::matchers::body_string(
+                            value
+                                .iter()
+                                .map(|item| {
+                                    format!(
+                                        "{}\n",
+                                        ::serde_json::to_string(item).unwrap(),
+                                    )
+                                })
+                                .collect::<String>()
+                        )))
+                    },
+                    quote! {},
+                ),
+                MockParameterKind::TextBody => param.method(
+                    quote! {
+                        Self(self.0.and(::wiremock::matchers::body_string(value)))
+                    },
+                    quote! {},
+                ),
+            }
+        });
+
+        let when_impl = quote! {
+            impl #when {
+                pub fn new(inner: ::wiremock::MockBuilder) -> Self {
+                    Self(inner
+                        .and(::wiremock::matchers::method(#http_method))
+                        .and(::wiremock::matchers::path_regex(#path_re)))
+                }
+
+                pub fn into_inner(self) -> ::wiremock::MockBuilder {
+                    self.0
+                }
+
+                pub fn respond_with(self, then: #then) -> ::wiremock::Mock {
+                    self.0.respond_with(then.0)
+                }
+
+                #(#when_methods)*
+            }
+        };
+
+        // A response template has its status from the start, so each
+        // response is a constructor.
+        let then_methods = responses.iter().map(|MockResponse { name, status, body }| {
+            let (value_param, value_use) = match body {
+                MockResponseBody::Json(arg_type) => (
+                    quote! {
+                        value: #arg_type,
+                    },
+                    quote! {
+                        .set_body_json(value)
+                    },
+                ),
+                MockResponseBody::None => Default::default(),
+                MockResponseBody::Raw => (
+                    quote! {
+                        value: ::serde_json::Value,
+                    },
+                    quote! {
+                        .set_body_json(value)
+                    },
+                ),
+                MockResponseBody::EventStream(arg_type) => (
+                    quote! {
+                        value: Vec<#arg_type>,
+                    },
+                    quote! {
+                        .set_body_raw(
+                            value
+                                .iter()
+                                .map(|event| {
+                                    format!(
+                                        "data: {}\n\n",
+                                        ::serde_json::to_string(event).unwrap(),
+                                    )
+                                })
+                                .collect::<String>(),
+                            "text/event-stream",
+                        )
+                    },
+                ),
+                MockResponseBody::JsonLines(arg_type) => (
+                    quote! {
+                        value: Vec<#arg_type>,
+                    },
+                    quote! {
+                        .set_body_raw(
+                            value
+                                .iter()
+                                .map(|item| {
+                                    format!(
+                                        "{}\n",
+                                        ::serde_json::to_string(item).unwrap(),
+                                    )
+                                })
+                                .collect::<String>(),
+                            "application/x-ndjson",
+                        )
+                    },
+                ),
+            };
+
+            match status {
+                MockResponseStatus::Code(status_code) => quote! {
+                    pub fn #name(#value_param) -> Self {
+                        Self(::wiremock::ResponseTemplate::new(#status_code)
+                            #value_use
+                        )
+                    }
+                },
+                MockResponseStatus::Range(status_type) => quote! {
+                    pub fn #name(status: u16, #value_param) -> Self {
+                        assert_eq!(status / 100u16, #status_type);
+                        Self(::wiremock::ResponseTemplate::new(status)
+                            #value_use
+                        )
+                    }
+                },
+                MockResponseStatus::Default => quote! {
+                    pub fn #name(status: u16, #value_param) -> Self {
+                        Self(::wiremock::ResponseTemplate::new(status)
+                            #value_use
+                        )
+                    }
+                },
+            }
+        });
+
+        // Setters for the headers described for any of the responses.
+        let header_methods = response_headers.iter().map(
+            |MockResponseHeader {
+                 name,
+                 api_name,
+                 typ,
+             }| {
+                quote! {
+                    pub fn #name(self, value: #typ) -> Self {
+                        Self(self.0.insert_header(#api_name, value.to_string()))
+                    }
+                }
+            },
+        );
+
+        let then_impl = quote! {
+            impl #then {
+                pub fn new(inner: ::wiremock::ResponseTemplate) -> Self {
+                    Self(inner)
+                }
+
+                pub fn into_inner(self) -> ::wiremock::ResponseTemplate {
+                    self.0
+                }
+
+                pub fn delay(self, duration: ::std::time::Duration) -> Self {
+                    Self(self.0.set_delay(duration))
+                }
+
+                #(#then_methods)*
+
+                #(#header_methods)*
+            }
+        };
+
+        MockOp {
+            when: when.into_token_stream(),
+            when_impl,
+            then: then.into_token_stream(),
+            then_impl,
+        }
+    }
+}
@@ -1,7 +1,9 @@
 #[allow(unused_imports)]
 use progenitor_client::{encode_path, ClientHooks, OperationInfo, RequestBuilderExt};
 #[allow(unused_imports)]
-pub use progenitor_client::{ByteStream, ClientInfo, Error, ResponseValue};
+pub use progenitor_client::{
+    ByteStream, ClientInfo, Error, RequestOptions, ResponseValue, UploadBody,
+};
 /// Types used as operation parameters and responses.
 #[allow(clippy::all)]
 pub mod types {
@@ -2485,6 +2487,7 @@ pub mod types {
 pub struct Client {
     pub(crate) baseurl: String,
     pub(crate) client: reqwest::Client,
+    pub(crate) middleware: progenitor_client::MiddlewareStack,
 }
 
 impl Client {
@@ -2516,8 +2519,210 @@ impl Client {
         Self {
             baseurl: baseurl.to_string(),
             client,
-        }
-    }
+            middleware: Default::default(),
+        }
+    }
+
+    /// Append `middleware` to the chain that surrounds the
+    /// execution of each request. Middleware runs in the order in
+    /// which it is attached.
+    pub fn with_middleware<M: progenitor_client::Middleware>(mut self, middleware: M) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Descriptions of the API's operations.
+    pub const OPERATIONS: &'static [progenitor_client::OperationMetadata] = &[
+        progenitor_client::OperationMetadata {
+            operation_id: "control_hold",
+            method: reqwest::Method::POST,
+            path: "/v1/control/hold",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "control_resume",
+            method: reqwest::Method::POST,
+            path: "/v1/control/resume",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "task_get",
+            method: reqwest::Method::GET,
+            path: "/v1/task/{Task}",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "tasks_get",
+            method: reqwest::Method::GET,
+            path: "/v1/tasks",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "task_submit",
+            method: reqwest::Method::POST,
+            path: "/v1/tasks",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "task_events_get",
+            method: reqwest::Method::GET,
+            path: "/v1/tasks/{task}/events",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "task_outputs_get",
+            method: reqwest::Method::GET,
+            path: "/v1/tasks/{task}/outputs",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "task_output_download",
+            method: reqwest::Method::GET,
+            path: "/v1/tasks/{task}/outputs/{output}",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=299u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "user_create",
+            method: reqwest::Method::POST,
+            path: "/v1/users",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "whoami",
+            method: reqwest::Method::GET,
+            path: "/v1/whoami",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "whoami_put_name",
+            method: reqwest::Method::PUT,
+            path: "/v1/whoami/name",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "worker_bootstrap",
+            method: reqwest::Method::POST,
+            path: "/v1/worker/bootstrap",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "worker_ping",
+            method: reqwest::Method::GET,
+            path: "/v1/worker/ping",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "worker_task_append",
+            method: reqwest::Method::POST,
+            path: "/v1/worker/task/{task}/append",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "worker_task_upload_chunk",
+            method: reqwest::Method::POST,
+            path: "/v1/worker/task/{task}/chunk",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "worker_task_complete",
+            method: reqwest::Method::POST,
+            path: "/v1/worker/task/{task}/complete",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "worker_task_add_output",
+            method: reqwest::Method::POST,
+            path: "/v1/worker/task/{task}/output",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "workers_list",
+            method: reqwest::Method::GET,
+            path: "/v1/workers",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "workers_recycle",
+            method: reqwest::Method::POST,
+            path: "/v1/workers/recycle",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "get_thing_or_things",
+            method: reqwest::Method::GET,
+            path: "/v1/things",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "header_arg",
+            method: reqwest::Method::GET,
+            path: "/v1/header-arg",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[],
+        },
+    ];
 }
 
 impl ClientInfo<()> for Client {
@@ -2799,7 +3004,7 @@ pub mod builder {
     #[allow(unused_imports)]
     use super::{
         encode_path, ByteStream, ClientHooks, ClientInfo, Error, OperationInfo, RequestBuilderExt,
-        ResponseValue,
+        ResponseValue, UploadBody,
     };
     ///Builder for [`Client::control_hold`]
     ///
@@ -2807,16 +3012,88 @@ pub mod builder {
     #[derive(Debug, Clone)]
     pub struct ControlHold<'a> {
         client: &'a super::Client,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> ControlHold<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self { client: client }
+            Self {
+                client: client,
+                options: ::std::default::Default::default(),
+            }
+        }
+
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
         }
 
         ///Sends a `POST` request to `/v1/control/hold`
-        pub async fn send(self) -> Result<ResponseValue<()>, Error<()>> {
-            let Self { client } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<()>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[0])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self { client, options } = self;
             let url = format!("{}/v1/control/hold", client.baseurl,);
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
             header_map.append(
@@ -2833,17 +3110,8 @@ pub mod builder {
                 )
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "control_hold",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -2853,16 +3121,88 @@ pub mod builder {
     #[derive(Debug, Clone)]
     pub struct ControlResume<'a> {
         client: &'a super::Client,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> ControlResume<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self { client: client }
+            Self {
+                client: client,
+                options: ::std::default::Default::default(),
+            }
+        }
+
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
         }
 
         ///Sends a `POST` request to `/v1/control/resume`
-        pub async fn send(self) -> Result<ResponseValue<()>, Error<()>> {
-            let Self { client } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<()>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[1])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => Ok(ResponseValue::empty(response)),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self { client, options } = self;
             let url = format!("{}/v1/control/resume", client.baseurl,);
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
             header_map.append(
@@ -2871,17 +3211,8 @@ pub mod builder {
             );
             #[allow(unused_mut)]
             let mut request = client.client.post(url).headers(header_map).build()?;
-            let info = OperationInfo {
-                operation_id: "control_resume",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => Ok(ResponseValue::empty(response)),
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -2892,6 +3223,7 @@ pub mod builder {
     pub struct TaskGet<'a> {
         client: &'a super::Client,
         task: Result<::std::string::String, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> TaskGet<'a> {
@@ -2899,6 +3231,7 @@ pub mod builder {
             Self {
                 client: client,
                 task: Err("task was not initialized".to_string()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -2912,9 +3245,81 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `GET` request to `/v1/task/{Task}`
-        pub async fn send(self) -> Result<ResponseValue<types::Task>, Error<()>> {
-            let Self { client, task } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<types::Task>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[2])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                task,
+                options,
+            } = self;
             let task = task.map_err(Error::InvalidRequest)?;
             let url = format!(
                 "{}/v1/task/{}",
@@ -2936,17 +3341,8 @@ pub mod builder {
                 )
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "task_get",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -2956,16 +3352,90 @@ pub mod builder {
     #[derive(Debug, Clone)]
     pub struct TasksGet<'a> {
         client: &'a super::Client,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> TasksGet<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self { client: client }
+            Self {
+                client: client,
+                options: ::std::default::Default::default(),
+            }
+        }
+
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
         }
 
         ///Sends a `GET` request to `/v1/tasks`
-        pub async fn send(self) -> Result<ResponseValue<::std::vec::Vec<types::Task>>, Error<()>> {
-            let Self { client } = self;
+        pub async fn send(
+            mut self,
+        ) -> Result<ResponseValue<::std::vec::Vec<types::Task>>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[3])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self { client, options } = self;
             let url = format!("{}/v1/tasks", client.baseurl,);
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
             header_map.append(
@@ -2982,17 +3452,8 @@ pub mod builder {
                 )
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "tasks_get",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3003,6 +3464,7 @@ pub mod builder {
     pub struct TaskSubmit<'a> {
         client: &'a super::Client,
         body: Result<types::builder::TaskSubmit, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> TaskSubmit<'a> {
@@ -3010,6 +3472,7 @@ pub mod builder {
             Self {
                 client: client,
                 body: Ok(::std::default::Default::default()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -3033,9 +3496,81 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `POST` request to `/v1/tasks`
-        pub async fn send(self) -> Result<ResponseValue<types::TaskSubmitResult>, Error<()>> {
-            let Self { client, body } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<types::TaskSubmitResult>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[4])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                201u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                body,
+                options,
+            } = self;
             let body = body
                 .and_then(|v| types::TaskSubmit::try_from(v).map_err(|e| e.to_string()))
                 .map_err(Error::InvalidRequest)?;
@@ -3056,17 +3591,8 @@ pub mod builder {
                 .json(&body)
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "task_submit",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                201u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3078,6 +3604,7 @@ pub mod builder {
         client: &'a super::Client,
         task: Result<::std::string::String, String>,
         minseq: Result<Option<u32>, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> TaskEventsGet<'a> {
@@ -3086,6 +3613,7 @@ pub mod builder {
                 client: client,
                 task: Err("task was not initialized".to_string()),
                 minseq: Ok(None),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -3110,14 +3638,83 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `GET` request to `/v1/tasks/{task}/events`
         pub async fn send(
-            self,
+            mut self,
         ) -> Result<ResponseValue<::std::vec::Vec<types::TaskEvent>>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[5])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
             let Self {
                 client,
                 task,
                 minseq,
+                options,
             } = self;
             let task = task.map_err(Error::InvalidRequest)?;
             let minseq = minseq.map_err(Error::InvalidRequest)?;
@@ -3142,17 +3739,8 @@ pub mod builder {
                 .query(&progenitor_client::QueryParam::new("minseq", &minseq))
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "task_events_get",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3163,6 +3751,7 @@ pub mod builder {
     pub struct TaskOutputsGet<'a> {
         client: &'a super::Client,
         task: Result<::std::string::String, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> TaskOutputsGet<'a> {
@@ -3170,6 +3759,7 @@ pub mod builder {
             Self {
                 client: client,
                 task: Err("task was not initialized".to_string()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -3183,11 +3773,83 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `GET` request to `/v1/tasks/{task}/outputs`
         pub async fn send(
-            self,
+            mut self,
         ) -> Result<ResponseValue<::std::vec::Vec<types::TaskOutput>>, Error<()>> {
-            let Self { client, task } = self;
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[6])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                task,
+                options,
+            } = self;
             let task = task.map_err(Error::InvalidRequest)?;
             let url = format!(
                 "{}/v1/tasks/{}/outputs",
@@ -3209,17 +3871,8 @@ pub mod builder {
                 )
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "task_outputs_get",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3231,6 +3884,7 @@ pub mod builder {
         client: &'a super::Client,
         task: Result<::std::string::String, String>,
         output: Result<::std::string::String, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> TaskOutputDownload<'a> {
@@ -3239,6 +3893,7 @@ pub mod builder {
                 client: client,
                 task: Err("task was not initialized".to_string()),
                 output: Err("output was not initialized".to_string()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -3262,16 +3917,85 @@ pub mod builder {
             self
         }
 
-        ///Sends a `GET` request to `/v1/tasks/{task}/outputs/{output}`
-        pub async fn send(self) -> Result<ResponseValue<ByteStream>, Error<()>> {
-            let Self {
-                client,
-                task,
-                output,
-            } = self;
-            let task = task.map_err(Error::InvalidRequest)?;
-            let output = output.map_err(Error::InvalidRequest)?;
-            let url = format!(
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
+        ///Sends a `GET` request to `/v1/tasks/{task}/outputs/{output}`
+        pub async fn send(mut self) -> Result<ResponseValue<ByteStream>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[7])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200..=299 => Ok(ResponseValue::stream(response)),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                task,
+                output,
+                options,
+            } = self;
+            let task = task.map_err(Error::InvalidRequest)?;
+            let output = output.map_err(Error::InvalidRequest)?;
+            let url = format!(
                 "{}/v1/tasks/{}/outputs/{}",
                 client.baseurl,
                 encode_path(&task.to_string()),
@@ -3284,17 +4008,8 @@ pub mod builder {
             );
             #[allow(unused_mut)]
             let mut request = client.client.get(url).headers(header_map).build()?;
-            let info = OperationInfo {
-                operation_id: "task_output_download",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200..=299 => Ok(ResponseValue::stream(response)),
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3305,6 +4020,7 @@ pub mod builder {
     pub struct UserCreate<'a> {
         client: &'a super::Client,
         body: Result<types::builder::UserCreate, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> UserCreate<'a> {
@@ -3312,6 +4028,7 @@ pub mod builder {
             Self {
                 client: client,
                 body: Ok(::std::default::Default::default()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -3335,9 +4052,81 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `POST` request to `/v1/users`
-        pub async fn send(self) -> Result<ResponseValue<types::UserCreateResult>, Error<()>> {
-            let Self { client, body } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<types::UserCreateResult>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[8])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                201u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                body,
+                options,
+            } = self;
             let body = body
                 .and_then(|v| types::UserCreate::try_from(v).map_err(|e| e.to_string()))
                 .map_err(Error::InvalidRequest)?;
@@ -3358,17 +4147,8 @@ pub mod builder {
                 .json(&body)
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "user_create",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                201u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3378,16 +4158,88 @@ pub mod builder {
     #[derive(Debug, Clone)]
     pub struct Whoami<'a> {
         client: &'a super::Client,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> Whoami<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self { client: client }
+            Self {
+                client: client,
+                options: ::std::default::Default::default(),
+            }
+        }
+
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
         }
 
         ///Sends a `GET` request to `/v1/whoami`
-        pub async fn send(self) -> Result<ResponseValue<types::WhoamiResult>, Error<()>> {
-            let Self { client } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<types::WhoamiResult>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[9])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self { client, options } = self;
             let url = format!("{}/v1/whoami", client.baseurl,);
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
             header_map.append(
@@ -3404,17 +4256,8 @@ pub mod builder {
                 )
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "whoami",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3425,6 +4268,7 @@ pub mod builder {
     pub struct WhoamiPutName<'a> {
         client: &'a super::Client,
         body: Result<reqwest::Body, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> WhoamiPutName<'a> {
@@ -3432,6 +4276,7 @@ pub mod builder {
             Self {
                 client: client,
                 body: Err("body was not initialized".to_string()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -3446,9 +4291,89 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
+        /// Add an `If-Match` header so that the server applies the
+        /// update only if the resource still has the entity tag `etag`,
+        /// such as one from `ResponseValue::etag`.
+        pub fn with_if_match<V: AsRef<str>>(mut self, etag: V) -> Self {
+            self.options = self.options.if_match(etag);
+            self
+        }
+
         ///Sends a `PUT` request to `/v1/whoami/name`
-        pub async fn send(self) -> Result<ResponseValue<()>, Error<()>> {
-            let Self { client, body } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<()>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[10])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => Ok(ResponseValue::empty(response)),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                body,
+                options,
+            } = self;
             let body = body.map_err(Error::InvalidRequest)?;
             let url = format!("{}/v1/whoami/name", client.baseurl,);
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
@@ -3467,17 +4392,8 @@ pub mod builder {
                 .body(body)
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "whoami_put_name",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => Ok(ResponseValue::empty(response)),
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3488,6 +4404,7 @@ pub mod builder {
     pub struct WorkerBootstrap<'a> {
         client: &'a super::Client,
         body: Result<types::builder::WorkerBootstrap, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> WorkerBootstrap<'a> {
@@ -3495,6 +4412,7 @@ pub mod builder {
             Self {
                 client: client,
                 body: Ok(::std::default::Default::default()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -3518,9 +4436,83 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `POST` request to `/v1/worker/bootstrap`
-        pub async fn send(self) -> Result<ResponseValue<types::WorkerBootstrapResult>, Error<()>> {
-            let Self { client, body } = self;
+        pub async fn send(
+            mut self,
+        ) -> Result<ResponseValue<types::WorkerBootstrapResult>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[11])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                201u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                body,
+                options,
+            } = self;
             let body = body
                 .and_then(|v| types::WorkerBootstrap::try_from(v).map_err(|e| e.to_string()))
                 .map_err(Error::InvalidRequest)?;
@@ -3541,17 +4533,8 @@ pub mod builder {
                 .json(&body)
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "worker_bootstrap",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                201u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3561,16 +4544,88 @@ pub mod builder {
     #[derive(Debug, Clone)]
     pub struct WorkerPing<'a> {
         client: &'a super::Client,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> WorkerPing<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self { client: client }
+            Self {
+                client: client,
+                options: ::std::default::Default::default(),
+            }
+        }
+
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
         }
 
         ///Sends a `GET` request to `/v1/worker/ping`
-        pub async fn send(self) -> Result<ResponseValue<types::WorkerPingResult>, Error<()>> {
-            let Self { client } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<types::WorkerPingResult>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[12])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self { client, options } = self;
             let url = format!("{}/v1/worker/ping", client.baseurl,);
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
             header_map.append(
@@ -3587,17 +4642,8 @@ pub mod builder {
                 )
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "worker_ping",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3609,6 +4655,7 @@ pub mod builder {
         client: &'a super::Client,
         task: Result<::std::string::String, String>,
         body: Result<types::builder::WorkerAppendTask, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> WorkerTaskAppend<'a> {
@@ -3617,6 +4664,7 @@ pub mod builder {
                 client: client,
                 task: Err("task was not initialized".to_string()),
                 body: Ok(::std::default::Default::default()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -3652,9 +4700,82 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `POST` request to `/v1/worker/task/{task}/append`
-        pub async fn send(self) -> Result<ResponseValue<()>, Error<()>> {
-            let Self { client, task, body } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<()>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[13])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                201u16 => Ok(ResponseValue::empty(response)),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                task,
+                body,
+                options,
+            } = self;
             let task = task.map_err(Error::InvalidRequest)?;
             let body = body
                 .and_then(|v| types::WorkerAppendTask::try_from(v).map_err(|e| e.to_string()))
@@ -3676,17 +4797,8 @@ pub mod builder {
                 .json(&body)
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "worker_task_append",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                201u16 => Ok(ResponseValue::empty(response)),
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3697,7 +4809,8 @@ pub mod builder {
     pub struct WorkerTaskUploadChunk<'a> {
         client: &'a super::Client,
         task: Result<::std::string::String, String>,
-        body: Result<reqwest::Body, String>,
+        body: Result<progenitor_client::UploadBody, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> WorkerTaskUploadChunk<'a> {
@@ -3706,6 +4819,7 @@ pub mod builder {
                 client: client,
                 task: Err("task was not initialized".to_string()),
                 body: Err("body was not initialized".to_string()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -3721,7 +4835,7 @@ pub mod builder {
 
         pub fn body<B>(mut self, value: B) -> Self
         where
-            B: std::convert::TryInto<reqwest::Body>,
+            B: std::convert::TryInto<progenitor_client::UploadBody>,
         {
             self.body = value
                 .try_into()
@@ -3729,9 +4843,92 @@ pub mod builder {
             self
         }
 
+        #[cfg(not(target_arch = "wasm32"))]
+        pub fn body_file<P>(mut self, path: P) -> Self
+        where
+            P: AsRef<std::path::Path>,
+        {
+            self.body = progenitor_client::UploadBody::from_file(path)
+                .map_err(|e| format!("opening file for body failed: {}", e));
+            self
+        }
+
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `POST` request to `/v1/worker/task/{task}/chunk`
-        pub async fn send(self) -> Result<ResponseValue<types::UploadedChunk>, Error<()>> {
-            let Self { client, task, body } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<types::UploadedChunk>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[14])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                201u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                task,
+                body,
+                options,
+            } = self;
             let task = task.map_err(Error::InvalidRequest)?;
             let body = body.map_err(Error::InvalidRequest)?;
             let url = format!(
@@ -3739,11 +4936,15 @@ pub mod builder {
                 client.baseurl,
                 encode_path(&task.to_string()),
             );
-            let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+            let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
             header_map.append(
                 ::reqwest::header::HeaderName::from_static("api-version"),
                 ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
             );
+            let body: progenitor_client::UploadBody = body.into();
+            if let Some(length) = body.content_length() {
+                header_map.append(::reqwest::header::CONTENT_LENGTH, length.into());
+            }
             #[allow(unused_mut)]
             let mut request = client
                 .client
@@ -3756,20 +4957,11 @@ pub mod builder {
                     ::reqwest::header::CONTENT_TYPE,
                     ::reqwest::header::HeaderValue::from_static("application/octet-stream"),
                 )
-                .body(body)
+                .body(body.into_body())
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "worker_task_upload_chunk",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                201u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3781,6 +4973,7 @@ pub mod builder {
         client: &'a super::Client,
         task: Result<::std::string::String, String>,
         body: Result<types::builder::WorkerCompleteTask, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> WorkerTaskComplete<'a> {
@@ -3789,6 +4982,7 @@ pub mod builder {
                 client: client,
                 task: Err("task was not initialized".to_string()),
                 body: Ok(::std::default::Default::default()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -3824,9 +5018,82 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `POST` request to `/v1/worker/task/{task}/complete`
-        pub async fn send(self) -> Result<ResponseValue<()>, Error<()>> {
-            let Self { client, task, body } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<()>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[15])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => Ok(ResponseValue::empty(response)),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                task,
+                body,
+                options,
+            } = self;
             let task = task.map_err(Error::InvalidRequest)?;
             let body = body
                 .and_then(|v| types::WorkerCompleteTask::try_from(v).map_err(|e| e.to_string()))
@@ -3848,17 +5115,8 @@ pub mod builder {
                 .json(&body)
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "worker_task_complete",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => Ok(ResponseValue::empty(response)),
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3870,6 +5128,7 @@ pub mod builder {
         client: &'a super::Client,
         task: Result<::std::string::String, String>,
         body: Result<types::builder::WorkerAddOutput, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> WorkerTaskAddOutput<'a> {
@@ -3878,6 +5137,7 @@ pub mod builder {
                 client: client,
                 task: Err("task was not initialized".to_string()),
                 body: Ok(::std::default::Default::default()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -3911,9 +5171,82 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `POST` request to `/v1/worker/task/{task}/output`
-        pub async fn send(self) -> Result<ResponseValue<()>, Error<()>> {
-            let Self { client, task, body } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<()>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[16])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                201u16 => Ok(ResponseValue::empty(response)),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                task,
+                body,
+                options,
+            } = self;
             let task = task.map_err(Error::InvalidRequest)?;
             let body = body
                 .and_then(|v| types::WorkerAddOutput::try_from(v).map_err(|e| e.to_string()))
@@ -3935,17 +5268,8 @@ pub mod builder {
                 .json(&body)
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "worker_task_add_output",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                201u16 => Ok(ResponseValue::empty(response)),
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3955,16 +5279,88 @@ pub mod builder {
     #[derive(Debug, Clone)]
     pub struct WorkersList<'a> {
         client: &'a super::Client,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> WorkersList<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self { client: client }
+            Self {
+                client: client,
+                options: ::std::default::Default::default(),
+            }
+        }
+
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
         }
 
         ///Sends a `GET` request to `/v1/workers`
-        pub async fn send(self) -> Result<ResponseValue<types::WorkersResult>, Error<()>> {
-            let Self { client } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<types::WorkersResult>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[17])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self { client, options } = self;
             let url = format!("{}/v1/workers", client.baseurl,);
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
             header_map.append(
@@ -3981,17 +5377,8 @@ pub mod builder {
                 )
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "workers_list",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -4001,16 +5388,88 @@ pub mod builder {
     #[derive(Debug, Clone)]
     pub struct WorkersRecycle<'a> {
         client: &'a super::Client,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> WorkersRecycle<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self { client: client }
+            Self {
+                client: client,
+                options: ::std::default::Default::default(),
+            }
+        }
+
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
         }
 
         ///Sends a `POST` request to `/v1/workers/recycle`
-        pub async fn send(self) -> Result<ResponseValue<()>, Error<()>> {
-            let Self { client } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<()>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[18])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => Ok(ResponseValue::empty(response)),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self { client, options } = self;
             let url = format!("{}/v1/workers/recycle", client.baseurl,);
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
             header_map.append(
@@ -4019,17 +5478,8 @@ pub mod builder {
             );
             #[allow(unused_mut)]
             let mut request = client.client.post(url).headers(header_map).build()?;
-            let info = OperationInfo {
-                operation_id: "workers_recycle",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => Ok(ResponseValue::empty(response)),
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -4040,6 +5490,7 @@ pub mod builder {
     pub struct GetThingOrThings<'a> {
         client: &'a super::Client,
         id: Result<Option<types::GetThingOrThingsId>, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> GetThingOrThings<'a> {
@@ -4047,6 +5498,7 @@ pub mod builder {
             Self {
                 client: client,
                 id: Ok(None),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -4061,9 +5513,81 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `GET` request to `/v1/things`
-        pub async fn send(self) -> Result<ResponseValue<::std::string::String>, Error<()>> {
-            let Self { client, id } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<::std::string::String>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[19])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                id,
+                options,
+            } = self;
             let id = id.map_err(Error::InvalidRequest)?;
             let url = format!("{}/v1/things", client.baseurl,);
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
@@ -4082,17 +5606,8 @@ pub mod builder {
                 .query(&progenitor_client::QueryParam::new("id", &id))
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "get_thing_or_things",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -4103,6 +5618,7 @@ pub mod builder {
     pub struct HeaderArg<'a> {
         client: &'a super::Client,
         accept_language: Result<Option<types::HeaderArgAcceptLanguage>, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> HeaderArg<'a> {
@@ -4110,6 +5626,7 @@ pub mod builder {
             Self {
                 client: client,
                 accept_language: Ok(None),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -4123,11 +5640,80 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `GET` request to `/v1/header-arg`
-        pub async fn send(self) -> Result<ResponseValue<()>, Error<()>> {
+        pub async fn send(mut self) -> Result<ResponseValue<()>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[20])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200..=299 => Ok(ResponseValue::empty(response)),
+                _ => Err(Error::ErrorResponse(ResponseValue::empty(response))),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
             let Self {
                 client,
                 accept_language,
+                options,
             } = self;
             let accept_language = accept_language.map_err(Error::InvalidRequest)?;
             let url = format!("{}/v1/header-arg", client.baseurl,);
@@ -4141,17 +5727,8 @@ pub mod builder {
             }
             #[allow(unused_mut)]
             let mut request = client.client.get(url).headers(header_map).build()?;
-            let info = OperationInfo {
-                operation_id: "header_arg",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200..=299 => Ok(ResponseValue::empty(response)),
-                _ => Err(Error::ErrorResponse(ResponseValue::empty(response))),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 }
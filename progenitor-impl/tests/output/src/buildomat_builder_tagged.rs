@@ -1,7 +1,9 @@
 #[allow(unused_imports)]
 use progenitor_client::{encode_path, ClientHooks, OperationInfo, RequestBuilderExt};
 #[allow(unused_imports)]
-pub use progenitor_client::{ByteStream, ClientInfo, Error, ResponseValue};
+pub use progenitor_client::{
+    ByteStream, ClientInfo, Error, RequestOptions, ResponseValue, UploadBody,
+};
 /// Types used as operation parameters and responses.
 #[allow(clippy::all)]
 pub mod types {
@@ -2442,6 +2444,7 @@ pub mod types {
 pub struct Client {
     pub(crate) baseurl: String,
     pub(crate) client: reqwest::Client,
+    pub(crate) middleware: progenitor_client::MiddlewareStack,
 }
 
 impl Client {
@@ -2473,8 +2476,210 @@ impl Client {
         Self {
             baseurl: baseurl.to_string(),
             client,
-        }
-    }
+            middleware: Default::default(),
+        }
+    }
+
+    /// Append `middleware` to the chain that surrounds the
+    /// execution of each request. Middleware runs in the order in
+    /// which it is attached.
+    pub fn with_middleware<M: progenitor_client::Middleware>(mut self, middleware: M) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Descriptions of the API's operations.
+    pub const OPERATIONS: &'static [progenitor_client::OperationMetadata] = &[
+        progenitor_client::OperationMetadata {
+            operation_id: "control_hold",
+            method: reqwest::Method::POST,
+            path: "/v1/control/hold",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "control_resume",
+            method: reqwest::Method::POST,
+            path: "/v1/control/resume",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "task_get",
+            method: reqwest::Method::GET,
+            path: "/v1/task/{Task}",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "tasks_get",
+            method: reqwest::Method::GET,
+            path: "/v1/tasks",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "task_submit",
+            method: reqwest::Method::POST,
+            path: "/v1/tasks",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "task_events_get",
+            method: reqwest::Method::GET,
+            path: "/v1/tasks/{task}/events",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "task_outputs_get",
+            method: reqwest::Method::GET,
+            path: "/v1/tasks/{task}/outputs",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "task_output_download",
+            method: reqwest::Method::GET,
+            path: "/v1/tasks/{task}/outputs/{output}",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=299u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "user_create",
+            method: reqwest::Method::POST,
+            path: "/v1/users",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "whoami",
+            method: reqwest::Method::GET,
+            path: "/v1/whoami",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "whoami_put_name",
+            method: reqwest::Method::PUT,
+            path: "/v1/whoami/name",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "worker_bootstrap",
+            method: reqwest::Method::POST,
+            path: "/v1/worker/bootstrap",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "worker_ping",
+            method: reqwest::Method::GET,
+            path: "/v1/worker/ping",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "worker_task_append",
+            method: reqwest::Method::POST,
+            path: "/v1/worker/task/{task}/append",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "worker_task_upload_chunk",
+            method: reqwest::Method::POST,
+            path: "/v1/worker/task/{task}/chunk",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "worker_task_complete",
+            method: reqwest::Method::POST,
+            path: "/v1/worker/task/{task}/complete",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "worker_task_add_output",
+            method: reqwest::Method::POST,
+            path: "/v1/worker/task/{task}/output",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "workers_list",
+            method: reqwest::Method::GET,
+            path: "/v1/workers",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "workers_recycle",
+            method: reqwest::Method::POST,
+            path: "/v1/workers/recycle",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "get_thing_or_things",
+            method: reqwest::Method::GET,
+            path: "/v1/things",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "header_arg",
+            method: reqwest::Method::GET,
+            path: "/v1/header-arg",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[],
+        },
+    ];
 }
 
 impl ClientInfo<()> for Client {
@@ -2756,7 +2961,7 @@ pub mod builder {
     #[allow(unused_imports)]
     use super::{
         encode_path, ByteStream, ClientHooks, ClientInfo, Error, OperationInfo, RequestBuilderExt,
-        ResponseValue,
+        ResponseValue, UploadBody,
     };
     ///Builder for [`Client::control_hold`]
     ///
@@ -2764,16 +2969,88 @@ pub mod builder {
     #[derive(Debug, Clone)]
     pub struct ControlHold<'a> {
         client: &'a super::Client,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> ControlHold<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self { client: client }
+            Self {
+                client: client,
+                options: ::std::default::Default::default(),
+            }
+        }
+
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
         }
 
         ///Sends a `POST` request to `/v1/control/hold`
-        pub async fn send(self) -> Result<ResponseValue<()>, Error<()>> {
-            let Self { client } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<()>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[0])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self { client, options } = self;
             let url = format!("{}/v1/control/hold", client.baseurl,);
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
             header_map.append(
@@ -2790,17 +3067,8 @@ pub mod builder {
                 )
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "control_hold",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -2810,16 +3078,88 @@ pub mod builder {
     #[derive(Debug, Clone)]
     pub struct ControlResume<'a> {
         client: &'a super::Client,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> ControlResume<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self { client: client }
+            Self {
+                client: client,
+                options: ::std::default::Default::default(),
+            }
+        }
+
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
         }
 
         ///Sends a `POST` request to `/v1/control/resume`
-        pub async fn send(self) -> Result<ResponseValue<()>, Error<()>> {
-            let Self { client } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<()>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[1])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => Ok(ResponseValue::empty(response)),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self { client, options } = self;
             let url = format!("{}/v1/control/resume", client.baseurl,);
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
             header_map.append(
@@ -2828,17 +3168,8 @@ pub mod builder {
             );
             #[allow(unused_mut)]
             let mut request = client.client.post(url).headers(header_map).build()?;
-            let info = OperationInfo {
-                operation_id: "control_resume",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => Ok(ResponseValue::empty(response)),
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -2849,6 +3180,7 @@ pub mod builder {
     pub struct TaskGet<'a> {
         client: &'a super::Client,
         task: Result<::std::string::String, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> TaskGet<'a> {
@@ -2856,6 +3188,7 @@ pub mod builder {
             Self {
                 client: client,
                 task: Err("task was not initialized".to_string()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -2869,9 +3202,81 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `GET` request to `/v1/task/{Task}`
-        pub async fn send(self) -> Result<ResponseValue<types::Task>, Error<()>> {
-            let Self { client, task } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<types::Task>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[2])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                task,
+                options,
+            } = self;
             let task = task.map_err(Error::InvalidRequest)?;
             let url = format!(
                 "{}/v1/task/{}",
@@ -2893,17 +3298,8 @@ pub mod builder {
                 )
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "task_get",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -2913,16 +3309,90 @@ pub mod builder {
     #[derive(Debug, Clone)]
     pub struct TasksGet<'a> {
         client: &'a super::Client,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> TasksGet<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self { client: client }
+            Self {
+                client: client,
+                options: ::std::default::Default::default(),
+            }
+        }
+
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
         }
 
         ///Sends a `GET` request to `/v1/tasks`
-        pub async fn send(self) -> Result<ResponseValue<::std::vec::Vec<types::Task>>, Error<()>> {
-            let Self { client } = self;
+        pub async fn send(
+            mut self,
+        ) -> Result<ResponseValue<::std::vec::Vec<types::Task>>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[3])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self { client, options } = self;
             let url = format!("{}/v1/tasks", client.baseurl,);
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
             header_map.append(
@@ -2939,17 +3409,8 @@ pub mod builder {
                 )
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "tasks_get",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -2960,6 +3421,7 @@ pub mod builder {
     pub struct TaskSubmit<'a> {
         client: &'a super::Client,
         body: Result<types::builder::TaskSubmit, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> TaskSubmit<'a> {
@@ -2967,6 +3429,7 @@ pub mod builder {
             Self {
                 client: client,
                 body: Ok(::std::default::Default::default()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -2990,9 +3453,81 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `POST` request to `/v1/tasks`
-        pub async fn send(self) -> Result<ResponseValue<types::TaskSubmitResult>, Error<()>> {
-            let Self { client, body } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<types::TaskSubmitResult>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[4])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                201u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                body,
+                options,
+            } = self;
             let body = body
                 .and_then(|v| types::TaskSubmit::try_from(v).map_err(|e| e.to_string()))
                 .map_err(Error::InvalidRequest)?;
@@ -3013,17 +3548,8 @@ pub mod builder {
                 .json(&body)
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "task_submit",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                201u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3035,6 +3561,7 @@ pub mod builder {
         client: &'a super::Client,
         task: Result<::std::string::String, String>,
         minseq: Result<Option<u32>, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> TaskEventsGet<'a> {
@@ -3043,6 +3570,7 @@ pub mod builder {
                 client: client,
                 task: Err("task was not initialized".to_string()),
                 minseq: Ok(None),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -3067,14 +3595,83 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `GET` request to `/v1/tasks/{task}/events`
         pub async fn send(
-            self,
+            mut self,
         ) -> Result<ResponseValue<::std::vec::Vec<types::TaskEvent>>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[5])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
             let Self {
                 client,
                 task,
                 minseq,
+                options,
             } = self;
             let task = task.map_err(Error::InvalidRequest)?;
             let minseq = minseq.map_err(Error::InvalidRequest)?;
@@ -3099,17 +3696,8 @@ pub mod builder {
                 .query(&progenitor_client::QueryParam::new("minseq", &minseq))
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "task_events_get",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3120,6 +3708,7 @@ pub mod builder {
     pub struct TaskOutputsGet<'a> {
         client: &'a super::Client,
         task: Result<::std::string::String, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> TaskOutputsGet<'a> {
@@ -3127,6 +3716,7 @@ pub mod builder {
             Self {
                 client: client,
                 task: Err("task was not initialized".to_string()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -3140,11 +3730,83 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `GET` request to `/v1/tasks/{task}/outputs`
         pub async fn send(
-            self,
+            mut self,
         ) -> Result<ResponseValue<::std::vec::Vec<types::TaskOutput>>, Error<()>> {
-            let Self { client, task } = self;
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[6])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                task,
+                options,
+            } = self;
             let task = task.map_err(Error::InvalidRequest)?;
             let url = format!(
                 "{}/v1/tasks/{}/outputs",
@@ -3166,17 +3828,8 @@ pub mod builder {
                 )
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "task_outputs_get",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3188,6 +3841,7 @@ pub mod builder {
         client: &'a super::Client,
         task: Result<::std::string::String, String>,
         output: Result<::std::string::String, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> TaskOutputDownload<'a> {
@@ -3196,6 +3850,7 @@ pub mod builder {
                 client: client,
                 task: Err("task was not initialized".to_string()),
                 output: Err("output was not initialized".to_string()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -3219,16 +3874,85 @@ pub mod builder {
             self
         }
 
-        ///Sends a `GET` request to `/v1/tasks/{task}/outputs/{output}`
-        pub async fn send(self) -> Result<ResponseValue<ByteStream>, Error<()>> {
-            let Self {
-                client,
-                task,
-                output,
-            } = self;
-            let task = task.map_err(Error::InvalidRequest)?;
-            let output = output.map_err(Error::InvalidRequest)?;
-            let url = format!(
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
+        ///Sends a `GET` request to `/v1/tasks/{task}/outputs/{output}`
+        pub async fn send(mut self) -> Result<ResponseValue<ByteStream>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[7])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200..=299 => Ok(ResponseValue::stream(response)),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                task,
+                output,
+                options,
+            } = self;
+            let task = task.map_err(Error::InvalidRequest)?;
+            let output = output.map_err(Error::InvalidRequest)?;
+            let url = format!(
                 "{}/v1/tasks/{}/outputs/{}",
                 client.baseurl,
                 encode_path(&task.to_string()),
@@ -3241,17 +3965,8 @@ pub mod builder {
             );
             #[allow(unused_mut)]
             let mut request = client.client.get(url).headers(header_map).build()?;
-            let info = OperationInfo {
-                operation_id: "task_output_download",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200..=299 => Ok(ResponseValue::stream(response)),
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3262,6 +3977,7 @@ pub mod builder {
     pub struct UserCreate<'a> {
         client: &'a super::Client,
         body: Result<types::builder::UserCreate, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> UserCreate<'a> {
@@ -3269,6 +3985,7 @@ pub mod builder {
             Self {
                 client: client,
                 body: Ok(::std::default::Default::default()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -3292,9 +4009,81 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `POST` request to `/v1/users`
-        pub async fn send(self) -> Result<ResponseValue<types::UserCreateResult>, Error<()>> {
-            let Self { client, body } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<types::UserCreateResult>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[8])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                201u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                body,
+                options,
+            } = self;
             let body = body
                 .and_then(|v| types::UserCreate::try_from(v).map_err(|e| e.to_string()))
                 .map_err(Error::InvalidRequest)?;
@@ -3315,17 +4104,8 @@ pub mod builder {
                 .json(&body)
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "user_create",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                201u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3335,16 +4115,88 @@ pub mod builder {
     #[derive(Debug, Clone)]
     pub struct Whoami<'a> {
         client: &'a super::Client,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> Whoami<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self { client: client }
+            Self {
+                client: client,
+                options: ::std::default::Default::default(),
+            }
+        }
+
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
         }
 
         ///Sends a `GET` request to `/v1/whoami`
-        pub async fn send(self) -> Result<ResponseValue<types::WhoamiResult>, Error<()>> {
-            let Self { client } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<types::WhoamiResult>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[9])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self { client, options } = self;
             let url = format!("{}/v1/whoami", client.baseurl,);
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
             header_map.append(
@@ -3361,17 +4213,8 @@ pub mod builder {
                 )
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "whoami",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3382,6 +4225,7 @@ pub mod builder {
     pub struct WhoamiPutName<'a> {
         client: &'a super::Client,
         body: Result<reqwest::Body, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> WhoamiPutName<'a> {
@@ -3389,6 +4233,7 @@ pub mod builder {
             Self {
                 client: client,
                 body: Err("body was not initialized".to_string()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -3403,9 +4248,89 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
+        /// Add an `If-Match` header so that the server applies the
+        /// update only if the resource still has the entity tag `etag`,
+        /// such as one from `ResponseValue::etag`.
+        pub fn with_if_match<V: AsRef<str>>(mut self, etag: V) -> Self {
+            self.options = self.options.if_match(etag);
+            self
+        }
+
         ///Sends a `PUT` request to `/v1/whoami/name`
-        pub async fn send(self) -> Result<ResponseValue<()>, Error<()>> {
-            let Self { client, body } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<()>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[10])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => Ok(ResponseValue::empty(response)),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                body,
+                options,
+            } = self;
             let body = body.map_err(Error::InvalidRequest)?;
             let url = format!("{}/v1/whoami/name", client.baseurl,);
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
@@ -3424,17 +4349,8 @@ pub mod builder {
                 .body(body)
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "whoami_put_name",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => Ok(ResponseValue::empty(response)),
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3445,6 +4361,7 @@ pub mod builder {
     pub struct WorkerBootstrap<'a> {
         client: &'a super::Client,
         body: Result<types::builder::WorkerBootstrap, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> WorkerBootstrap<'a> {
@@ -3452,6 +4369,7 @@ pub mod builder {
             Self {
                 client: client,
                 body: Ok(::std::default::Default::default()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -3475,9 +4393,83 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `POST` request to `/v1/worker/bootstrap`
-        pub async fn send(self) -> Result<ResponseValue<types::WorkerBootstrapResult>, Error<()>> {
-            let Self { client, body } = self;
+        pub async fn send(
+            mut self,
+        ) -> Result<ResponseValue<types::WorkerBootstrapResult>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[11])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                201u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                body,
+                options,
+            } = self;
             let body = body
                 .and_then(|v| types::WorkerBootstrap::try_from(v).map_err(|e| e.to_string()))
                 .map_err(Error::InvalidRequest)?;
@@ -3498,17 +4490,8 @@ pub mod builder {
                 .json(&body)
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "worker_bootstrap",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                201u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3518,16 +4501,88 @@ pub mod builder {
     #[derive(Debug, Clone)]
     pub struct WorkerPing<'a> {
         client: &'a super::Client,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> WorkerPing<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self { client: client }
+            Self {
+                client: client,
+                options: ::std::default::Default::default(),
+            }
+        }
+
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
         }
 
         ///Sends a `GET` request to `/v1/worker/ping`
-        pub async fn send(self) -> Result<ResponseValue<types::WorkerPingResult>, Error<()>> {
-            let Self { client } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<types::WorkerPingResult>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[12])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self { client, options } = self;
             let url = format!("{}/v1/worker/ping", client.baseurl,);
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
             header_map.append(
@@ -3544,17 +4599,8 @@ pub mod builder {
                 )
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "worker_ping",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3566,6 +4612,7 @@ pub mod builder {
         client: &'a super::Client,
         task: Result<::std::string::String, String>,
         body: Result<types::builder::WorkerAppendTask, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> WorkerTaskAppend<'a> {
@@ -3574,6 +4621,7 @@ pub mod builder {
                 client: client,
                 task: Err("task was not initialized".to_string()),
                 body: Ok(::std::default::Default::default()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -3609,9 +4657,82 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `POST` request to `/v1/worker/task/{task}/append`
-        pub async fn send(self) -> Result<ResponseValue<()>, Error<()>> {
-            let Self { client, task, body } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<()>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[13])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                201u16 => Ok(ResponseValue::empty(response)),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                task,
+                body,
+                options,
+            } = self;
             let task = task.map_err(Error::InvalidRequest)?;
             let body = body
                 .and_then(|v| types::WorkerAppendTask::try_from(v).map_err(|e| e.to_string()))
@@ -3633,17 +4754,8 @@ pub mod builder {
                 .json(&body)
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "worker_task_append",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                201u16 => Ok(ResponseValue::empty(response)),
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3654,7 +4766,8 @@ pub mod builder {
     pub struct WorkerTaskUploadChunk<'a> {
         client: &'a super::Client,
         task: Result<::std::string::String, String>,
-        body: Result<reqwest::Body, String>,
+        body: Result<progenitor_client::UploadBody, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> WorkerTaskUploadChunk<'a> {
@@ -3663,6 +4776,7 @@ pub mod builder {
                 client: client,
                 task: Err("task was not initialized".to_string()),
                 body: Err("body was not initialized".to_string()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -3678,7 +4792,7 @@ pub mod builder {
 
         pub fn body<B>(mut self, value: B) -> Self
         where
-            B: std::convert::TryInto<reqwest::Body>,
+            B: std::convert::TryInto<progenitor_client::UploadBody>,
         {
             self.body = value
                 .try_into()
@@ -3686,9 +4800,92 @@ pub mod builder {
             self
         }
 
+        #[cfg(not(target_arch = "wasm32"))]
+        pub fn body_file<P>(mut self, path: P) -> Self
+        where
+            P: AsRef<std::path::Path>,
+        {
+            self.body = progenitor_client::UploadBody::from_file(path)
+                .map_err(|e| format!("opening file for body failed: {}", e));
+            self
+        }
+
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `POST` request to `/v1/worker/task/{task}/chunk`
-        pub async fn send(self) -> Result<ResponseValue<types::UploadedChunk>, Error<()>> {
-            let Self { client, task, body } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<types::UploadedChunk>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[14])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                201u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                task,
+                body,
+                options,
+            } = self;
             let task = task.map_err(Error::InvalidRequest)?;
             let body = body.map_err(Error::InvalidRequest)?;
             let url = format!(
@@ -3696,11 +4893,15 @@ pub mod builder {
                 client.baseurl,
                 encode_path(&task.to_string()),
             );
-            let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+            let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
             header_map.append(
                 ::reqwest::header::HeaderName::from_static("api-version"),
                 ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
             );
+            let body: progenitor_client::UploadBody = body.into();
+            if let Some(length) = body.content_length() {
+                header_map.append(::reqwest::header::CONTENT_LENGTH, length.into());
+            }
             #[allow(unused_mut)]
             let mut request = client
                 .client
@@ -3713,20 +4914,11 @@ pub mod builder {
                     ::reqwest::header::CONTENT_TYPE,
                     ::reqwest::header::HeaderValue::from_static("application/octet-stream"),
                 )
-                .body(body)
+                .body(body.into_body())
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "worker_task_upload_chunk",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                201u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3738,6 +4930,7 @@ pub mod builder {
         client: &'a super::Client,
         task: Result<::std::string::String, String>,
         body: Result<types::builder::WorkerCompleteTask, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> WorkerTaskComplete<'a> {
@@ -3746,6 +4939,7 @@ pub mod builder {
                 client: client,
                 task: Err("task was not initialized".to_string()),
                 body: Ok(::std::default::Default::default()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -3781,9 +4975,82 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `POST` request to `/v1/worker/task/{task}/complete`
-        pub async fn send(self) -> Result<ResponseValue<()>, Error<()>> {
-            let Self { client, task, body } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<()>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[15])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => Ok(ResponseValue::empty(response)),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                task,
+                body,
+                options,
+            } = self;
             let task = task.map_err(Error::InvalidRequest)?;
             let body = body
                 .and_then(|v| types::WorkerCompleteTask::try_from(v).map_err(|e| e.to_string()))
@@ -3805,17 +5072,8 @@ pub mod builder {
                 .json(&body)
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "worker_task_complete",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => Ok(ResponseValue::empty(response)),
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3827,6 +5085,7 @@ pub mod builder {
         client: &'a super::Client,
         task: Result<::std::string::String, String>,
         body: Result<types::builder::WorkerAddOutput, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> WorkerTaskAddOutput<'a> {
@@ -3835,6 +5094,7 @@ pub mod builder {
                 client: client,
                 task: Err("task was not initialized".to_string()),
                 body: Ok(::std::default::Default::default()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -3868,9 +5128,82 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `POST` request to `/v1/worker/task/{task}/output`
-        pub async fn send(self) -> Result<ResponseValue<()>, Error<()>> {
-            let Self { client, task, body } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<()>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[16])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                201u16 => Ok(ResponseValue::empty(response)),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                task,
+                body,
+                options,
+            } = self;
             let task = task.map_err(Error::InvalidRequest)?;
             let body = body
                 .and_then(|v| types::WorkerAddOutput::try_from(v).map_err(|e| e.to_string()))
@@ -3892,17 +5225,8 @@ pub mod builder {
                 .json(&body)
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "worker_task_add_output",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                201u16 => Ok(ResponseValue::empty(response)),
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3912,16 +5236,88 @@ pub mod builder {
     #[derive(Debug, Clone)]
     pub struct WorkersList<'a> {
         client: &'a super::Client,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> WorkersList<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self { client: client }
+            Self {
+                client: client,
+                options: ::std::default::Default::default(),
+            }
+        }
+
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
         }
 
         ///Sends a `GET` request to `/v1/workers`
-        pub async fn send(self) -> Result<ResponseValue<types::WorkersResult>, Error<()>> {
-            let Self { client } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<types::WorkersResult>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[17])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self { client, options } = self;
             let url = format!("{}/v1/workers", client.baseurl,);
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
             header_map.append(
@@ -3938,17 +5334,8 @@ pub mod builder {
                 )
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "workers_list",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3958,16 +5345,88 @@ pub mod builder {
     #[derive(Debug, Clone)]
     pub struct WorkersRecycle<'a> {
         client: &'a super::Client,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> WorkersRecycle<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self { client: client }
+            Self {
+                client: client,
+                options: ::std::default::Default::default(),
+            }
+        }
+
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
         }
 
         ///Sends a `POST` request to `/v1/workers/recycle`
-        pub async fn send(self) -> Result<ResponseValue<()>, Error<()>> {
-            let Self { client } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<()>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[18])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => Ok(ResponseValue::empty(response)),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self { client, options } = self;
             let url = format!("{}/v1/workers/recycle", client.baseurl,);
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
             header_map.append(
@@ -3976,17 +5435,8 @@ pub mod builder {
             );
             #[allow(unused_mut)]
             let mut request = client.client.post(url).headers(header_map).build()?;
-            let info = OperationInfo {
-                operation_id: "workers_recycle",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => Ok(ResponseValue::empty(response)),
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -3997,6 +5447,7 @@ pub mod builder {
     pub struct GetThingOrThings<'a> {
         client: &'a super::Client,
         id: Result<Option<types::GetThingOrThingsId>, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> GetThingOrThings<'a> {
@@ -4004,6 +5455,7 @@ pub mod builder {
             Self {
                 client: client,
                 id: Ok(None),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -4018,9 +5470,81 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `GET` request to `/v1/things`
-        pub async fn send(self) -> Result<ResponseValue<::std::string::String>, Error<()>> {
-            let Self { client, id } = self;
+        pub async fn send(mut self) -> Result<ResponseValue<::std::string::String>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[19])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                id,
+                options,
+            } = self;
             let id = id.map_err(Error::InvalidRequest)?;
             let url = format!("{}/v1/things", client.baseurl,);
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
@@ -4039,17 +5563,8 @@ pub mod builder {
                 .query(&progenitor_client::QueryParam::new("id", &id))
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "get_thing_or_things",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -4060,6 +5575,7 @@ pub mod builder {
     pub struct HeaderArg<'a> {
         client: &'a super::Client,
         accept_language: Result<Option<types::HeaderArgAcceptLanguage>, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> HeaderArg<'a> {
@@ -4067,6 +5583,7 @@ pub mod builder {
             Self {
                 client: client,
                 accept_language: Ok(None),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -4080,11 +5597,80 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `GET` request to `/v1/header-arg`
-        pub async fn send(self) -> Result<ResponseValue<()>, Error<()>> {
+        pub async fn send(mut self) -> Result<ResponseValue<()>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[20])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200..=299 => Ok(ResponseValue::empty(response)),
+                _ => Err(Error::ErrorResponse(ResponseValue::empty(response))),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
             let Self {
                 client,
                 accept_language,
+                options,
             } = self;
             let accept_language = accept_language.map_err(Error::InvalidRequest)?;
             let url = format!("{}/v1/header-arg", client.baseurl,);
@@ -4098,17 +5684,8 @@ pub mod builder {
             }
             #[allow(unused_mut)]
             let mut request = client.client.get(url).headers(header_map).build()?;
-            let info = OperationInfo {
-                operation_id: "header_arg",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200..=299 => Ok(ResponseValue::empty(response)),
-                _ => Err(Error::ErrorResponse(ResponseValue::empty(response))),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 }
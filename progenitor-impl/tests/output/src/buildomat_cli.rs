@@ -37,23 +37,72 @@ impl<T: CliConfig> Cli<T> {
 
     pub fn cli_control_hold() -> ::clap::Command {
         ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_control_resume() -> ::clap::Command {
         ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_task_get() -> ::clap::Command {
-        ::clap::Command::new("").arg(
-            ::clap::Arg::new("task")
-                .long("task")
-                .value_parser(::clap::value_parser!(::std::string::String))
-                .required(true),
-        )
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("task")
+                    .long("task")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(true),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_tasks_get() -> ::clap::Command {
         ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_task_submit() -> ::clap::Command {
@@ -90,6 +139,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_task_events_get() -> ::clap::Command {
@@ -106,15 +167,40 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(::std::string::String))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_task_outputs_get() -> ::clap::Command {
-        ::clap::Command::new("").arg(
-            ::clap::Arg::new("task")
-                .long("task")
-                .value_parser(::clap::value_parser!(::std::string::String))
-                .required(true),
-        )
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("task")
+                    .long("task")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(true),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_task_output_download() -> ::clap::Command {
@@ -131,6 +217,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(::std::string::String))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_user_create() -> ::clap::Command {
@@ -155,14 +253,50 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_whoami() -> ::clap::Command {
         ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_whoami_put_name() -> ::clap::Command {
         ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_worker_bootstrap() -> ::clap::Command {
@@ -193,10 +327,34 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_worker_ping() -> ::clap::Command {
         ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_worker_task_append() -> ::clap::Command {
@@ -241,15 +399,40 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_worker_task_upload_chunk() -> ::clap::Command {
-        ::clap::Command::new("").arg(
-            ::clap::Arg::new("task")
-                .long("task")
-                .value_parser(::clap::value_parser!(::std::string::String))
-                .required(true),
-        )
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("task")
+                    .long("task")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(true),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_worker_task_complete() -> ::clap::Command {
@@ -280,6 +463,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_worker_task_add_output() -> ::clap::Command {
@@ -316,38 +511,100 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_workers_list() -> ::clap::Command {
         ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_workers_recycle() -> ::clap::Command {
         ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_get_thing_or_things() -> ::clap::Command {
-        ::clap::Command::new("").arg(
-            ::clap::Arg::new("id")
-                .long("id")
-                .value_parser(::clap::value_parser!(types::GetThingOrThingsId))
-                .required(false),
-        )
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("id")
+                    .long("id")
+                    .value_parser(::clap::value_parser!(types::GetThingOrThingsId))
+                    .required(false),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_header_arg() -> ::clap::Command {
-        ::clap::Command::new("").arg(
-            ::clap::Arg::new("accept-language")
-                .long("accept-language")
-                .value_parser(::clap::builder::TypedValueParser::map(
-                    ::clap::builder::PossibleValuesParser::new([
-                        types::HeaderArgAcceptLanguage::De.to_string(),
-                        types::HeaderArgAcceptLanguage::En.to_string(),
-                    ]),
-                    |s| types::HeaderArgAcceptLanguage::try_from(s).unwrap(),
-                ))
-                .required(false),
-        )
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("accept-language")
+                    .long("accept-language")
+                    .value_parser(::clap::builder::TypedValueParser::map(
+                        ::clap::builder::PossibleValuesParser::new([
+                            types::HeaderArgAcceptLanguage::De.to_string(),
+                            types::HeaderArgAcceptLanguage::En.to_string(),
+                        ]),
+                        |s| types::HeaderArgAcceptLanguage::try_from(s).unwrap(),
+                    ))
+                    .required(false),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub async fn execute(
@@ -385,6 +642,19 @@ impl<T: CliConfig> Cli<T> {
     pub async fn execute_control_hold(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
         let mut request = self.client.control_hold();
         self.config.execute_control_hold(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -401,6 +671,19 @@ impl<T: CliConfig> Cli<T> {
     pub async fn execute_control_resume(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
         let mut request = self.client.control_resume();
         self.config.execute_control_resume(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -421,6 +704,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_task_get(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -437,6 +733,19 @@ impl<T: CliConfig> Cli<T> {
     pub async fn execute_tasks_get(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
         let mut request = self.client.tasks_get();
         self.config.execute_tasks_get(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -471,6 +780,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_task_submit(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -498,6 +820,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_task_events_get(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -522,6 +857,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_task_outputs_get(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -550,6 +898,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_task_output_download(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -575,6 +936,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_user_create(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -591,6 +965,19 @@ impl<T: CliConfig> Cli<T> {
     pub async fn execute_whoami(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
         let mut request = self.client.whoami();
         self.config.execute_whoami(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -610,6 +997,19 @@ impl<T: CliConfig> Cli<T> {
     ) -> anyhow::Result<()> {
         let mut request = self.client.whoami_put_name();
         self.config.execute_whoami_put_name(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -644,6 +1044,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_worker_bootstrap(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -660,6 +1073,19 @@ impl<T: CliConfig> Cli<T> {
     pub async fn execute_worker_ping(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
         let mut request = self.client.worker_ping();
         self.config.execute_worker_ping(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -702,6 +1128,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_worker_task_append(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -726,6 +1165,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_worker_task_upload_chunk(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -760,6 +1212,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_worker_task_complete(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -798,6 +1263,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_worker_task_add_output(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -814,6 +1292,19 @@ impl<T: CliConfig> Cli<T> {
     pub async fn execute_workers_list(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
         let mut request = self.client.workers_list();
         self.config.execute_workers_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -833,6 +1324,19 @@ impl<T: CliConfig> Cli<T> {
     ) -> anyhow::Result<()> {
         let mut request = self.client.workers_recycle();
         self.config.execute_workers_recycle(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -857,6 +1361,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_get_thing_or_things(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -877,6 +1394,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_header_arg(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -911,6 +1441,16 @@ pub trait CliConfig {
     fn list_end_error<T>(&self, value: &Error<T>)
     where
         T: schemars::JsonSchema + serde::Serialize + std::fmt::Debug;
+    /// Output for `--progenitor-dry-run`.
+    fn dry_run(&self, request: &reqwest::Request) {
+        print!("{}", progenitor_client::format_request(request));
+    }
+
+    /// Output for `--progenitor-print-curl`.
+    fn print_curl(&self, request: &reqwest::Request) {
+        println!("{}", progenitor_client::curl_command(request));
+    }
+
     fn execute_control_hold(
         &self,
         matches: &::clap::ArgMatches,
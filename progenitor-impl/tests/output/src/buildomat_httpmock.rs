@@ -4,6 +4,118 @@ pub mod operations {
     #![doc = r" its inner type with a call to `into_inner()`. This can"]
     #![doc = r" be used to explicitly deviate from permitted values."]
     use crate::buildomat_builder::*;
+    mod body_predicates {
+        type Predicate = Box<dyn Fn(&[u8]) -> bool + Send + Sync>;
+        static PREDICATES: ::std::sync::Mutex<Vec<((::std::any::TypeId, usize), Predicate)>> =
+            ::std::sync::Mutex::new(Vec::new());
+        fn matcher_at<const N: usize>(req: &::httpmock::prelude::HttpMockRequest) -> bool {
+            let predicates = PREDICATES
+                .lock()
+                .unwrap_or_else(::std::sync::PoisonError::into_inner);
+            req.body
+                .as_deref()
+                .is_some_and(|body| (predicates[N].1)(body))
+        }
+
+        const MATCHERS: [fn(&::httpmock::prelude::HttpMockRequest) -> bool; 64] = [
+            matcher_at::<0>,
+            matcher_at::<1>,
+            matcher_at::<2>,
+            matcher_at::<3>,
+            matcher_at::<4>,
+            matcher_at::<5>,
+            matcher_at::<6>,
+            matcher_at::<7>,
+            matcher_at::<8>,
+            matcher_at::<9>,
+            matcher_at::<10>,
+            matcher_at::<11>,
+            matcher_at::<12>,
+            matcher_at::<13>,
+            matcher_at::<14>,
+            matcher_at::<15>,
+            matcher_at::<16>,
+            matcher_at::<17>,
+            matcher_at::<18>,
+            matcher_at::<19>,
+            matcher_at::<20>,
+            matcher_at::<21>,
+            matcher_at::<22>,
+            matcher_at::<23>,
+            matcher_at::<24>,
+            matcher_at::<25>,
+            matcher_at::<26>,
+            matcher_at::<27>,
+            matcher_at::<28>,
+            matcher_at::<29>,
+            matcher_at::<30>,
+            matcher_at::<31>,
+            matcher_at::<32>,
+            matcher_at::<33>,
+            matcher_at::<34>,
+            matcher_at::<35>,
+            matcher_at::<36>,
+            matcher_at::<37>,
+            matcher_at::<38>,
+            matcher_at::<39>,
+            matcher_at::<40>,
+            matcher_at::<41>,
+            matcher_at::<42>,
+            matcher_at::<43>,
+            matcher_at::<44>,
+            matcher_at::<45>,
+            matcher_at::<46>,
+            matcher_at::<47>,
+            matcher_at::<48>,
+            matcher_at::<49>,
+            matcher_at::<50>,
+            matcher_at::<51>,
+            matcher_at::<52>,
+            matcher_at::<53>,
+            matcher_at::<54>,
+            matcher_at::<55>,
+            matcher_at::<56>,
+            matcher_at::<57>,
+            matcher_at::<58>,
+            matcher_at::<59>,
+            matcher_at::<60>,
+            matcher_at::<61>,
+            matcher_at::<62>,
+            matcher_at::<63>,
+        ];
+        #[doc = r" The matcher for bodies that decode to a value for which"]
+        #[doc = r" `predicate` returns `true`."]
+        pub(super) fn matcher<T>(
+            predicate: fn(&T) -> bool,
+        ) -> fn(&::httpmock::prelude::HttpMockRequest) -> bool
+        where
+            T: ::serde::de::DeserializeOwned + 'static,
+        {
+            let key = (::std::any::TypeId::of::<T>(), predicate as usize);
+            let mut predicates = PREDICATES
+                .lock()
+                .unwrap_or_else(::std::sync::PoisonError::into_inner);
+            let index = match predicates.iter().position(|(k, _)| *k == key) {
+                Some(index) => index,
+                None => {
+                    assert!(
+                        predicates.len() < MATCHERS.len(),
+                        "at most {} distinct body predicates may be used",
+                        MATCHERS.len(),
+                    );
+                    predicates.push((
+                        key,
+                        Box::new(move |body| {
+                            ::serde_json::from_slice(body).is_ok_and(|value| predicate(&value))
+                        }),
+                    ));
+                    predicates.len() - 1
+                }
+            };
+            MATCHERS[index]
+        }
+    }
+
     pub struct ControlHoldWhen(::httpmock::When);
     impl ControlHoldWhen {
         pub fn new(inner: ::httpmock::When) -> Self {
@@ -29,6 +141,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: ()) -> Self {
             Self(
                 self.0
@@ -64,6 +180,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self) -> Self {
             Self(self.0.status(200u16))
         }
@@ -99,6 +219,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Task) -> Self {
             Self(
                 self.0
@@ -134,6 +258,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &::std::vec::Vec<types::Task>) -> Self {
             Self(
                 self.0
@@ -161,6 +289,18 @@ pub mod operations {
         pub fn body(self, value: &types::TaskSubmit) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::TaskSubmit) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct TaskSubmitThen(::httpmock::Then);
@@ -173,6 +313,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::TaskSubmitResult) -> Self {
             Self(
                 self.0
@@ -230,6 +374,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &::std::vec::Vec<types::TaskEvent>) -> Self {
             Self(
                 self.0
@@ -271,6 +419,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &::std::vec::Vec<types::TaskOutput>) -> Self {
             Self(
                 self.0
@@ -318,6 +470,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn success(self, status: u16, value: ::serde_json::Value) -> Self {
             assert_eq!(status / 100u16, 2u16);
             Self(
@@ -346,6 +502,18 @@ pub mod operations {
         pub fn body(self, value: &types::UserCreate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::UserCreate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct UserCreateThen(::httpmock::Then);
@@ -358,6 +526,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::UserCreateResult) -> Self {
             Self(
                 self.0
@@ -393,6 +565,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::WhoamiResult) -> Self {
             Self(
                 self.0
@@ -432,6 +608,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self) -> Self {
             Self(self.0.status(200u16))
         }
@@ -454,6 +634,18 @@ pub mod operations {
         pub fn body(self, value: &types::WorkerBootstrap) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::WorkerBootstrap) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct WorkerBootstrapThen(::httpmock::Then);
@@ -466,6 +658,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::WorkerBootstrapResult) -> Self {
             Self(
                 self.0
@@ -501,6 +697,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::WorkerPingResult) -> Self {
             Self(
                 self.0
@@ -534,6 +734,18 @@ pub mod operations {
         pub fn body(self, value: &types::WorkerAppendTask) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::WorkerAppendTask) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct WorkerTaskAppendThen(::httpmock::Then);
@@ -546,6 +758,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self) -> Self {
             Self(self.0.status(201u16))
         }
@@ -586,6 +802,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::UploadedChunk) -> Self {
             Self(
                 self.0
@@ -620,6 +840,18 @@ pub mod operations {
         pub fn body(self, value: &types::WorkerCompleteTask) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::WorkerCompleteTask) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct WorkerTaskCompleteThen(::httpmock::Then);
@@ -632,6 +864,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self) -> Self {
             Self(self.0.status(200u16))
         }
@@ -660,6 +896,18 @@ pub mod operations {
         pub fn body(self, value: &types::WorkerAddOutput) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::WorkerAddOutput) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct WorkerTaskAddOutputThen(::httpmock::Then);
@@ -672,6 +920,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self) -> Self {
             Self(self.0.status(201u16))
         }
@@ -702,6 +954,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::WorkersResult) -> Self {
             Self(
                 self.0
@@ -737,6 +993,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self) -> Self {
             Self(self.0.status(200u16))
         }
@@ -783,6 +1043,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &str) -> Self {
             Self(
                 self.0
@@ -822,6 +1086,10 @@ pub mod operations {
                 }))
             }
         }
+
+        pub fn accept_language_exists(self) -> Self {
+            Self(self.0.header_exists("accept-language"))
+        }
     }
 
     pub struct HeaderArgThen(::httpmock::Then);
@@ -834,6 +1102,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn default_response(self, status: u16) -> Self {
             Self(self.0.status(status))
         }
@@ -2,7 +2,9 @@
 #[allow(unused_imports)]
 use progenitor_client::{encode_path, ClientHooks, OperationInfo, RequestBuilderExt};
 #[allow(unused_imports)]
-pub use progenitor_client::{ByteStream, ClientInfo, Error, ResponseValue};
+pub use progenitor_client::{
+    ByteStream, ClientInfo, Error, RequestOptions, ResponseValue, UploadBody,
+};
 /// Types used as operation parameters and responses.
 #[allow(clippy::all)]
 pub mod types {
@@ -967,6 +969,7 @@ pub mod types {
 pub struct Client {
     pub(crate) baseurl: String,
     pub(crate) client: reqwest::Client,
+    pub(crate) middleware: progenitor_client::MiddlewareStack,
 }
 
 impl Client {
@@ -998,8 +1001,210 @@ impl Client {
         Self {
             baseurl: baseurl.to_string(),
             client,
+            middleware: Default::default(),
         }
     }
+
+    /// Append `middleware` to the chain that surrounds the
+    /// execution of each request. Middleware runs in the order in
+    /// which it is attached.
+    pub fn with_middleware<M: progenitor_client::Middleware>(mut self, middleware: M) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Descriptions of the API's operations.
+    pub const OPERATIONS: &'static [progenitor_client::OperationMetadata] = &[
+        progenitor_client::OperationMetadata {
+            operation_id: "control_hold",
+            method: reqwest::Method::POST,
+            path: "/v1/control/hold",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "control_resume",
+            method: reqwest::Method::POST,
+            path: "/v1/control/resume",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "task_get",
+            method: reqwest::Method::GET,
+            path: "/v1/task/{Task}",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "tasks_get",
+            method: reqwest::Method::GET,
+            path: "/v1/tasks",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "task_submit",
+            method: reqwest::Method::POST,
+            path: "/v1/tasks",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "task_events_get",
+            method: reqwest::Method::GET,
+            path: "/v1/tasks/{task}/events",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "task_outputs_get",
+            method: reqwest::Method::GET,
+            path: "/v1/tasks/{task}/outputs",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "task_output_download",
+            method: reqwest::Method::GET,
+            path: "/v1/tasks/{task}/outputs/{output}",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=299u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "user_create",
+            method: reqwest::Method::POST,
+            path: "/v1/users",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "whoami",
+            method: reqwest::Method::GET,
+            path: "/v1/whoami",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "whoami_put_name",
+            method: reqwest::Method::PUT,
+            path: "/v1/whoami/name",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "worker_bootstrap",
+            method: reqwest::Method::POST,
+            path: "/v1/worker/bootstrap",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "worker_ping",
+            method: reqwest::Method::GET,
+            path: "/v1/worker/ping",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "worker_task_append",
+            method: reqwest::Method::POST,
+            path: "/v1/worker/task/{task}/append",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "worker_task_upload_chunk",
+            method: reqwest::Method::POST,
+            path: "/v1/worker/task/{task}/chunk",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "worker_task_complete",
+            method: reqwest::Method::POST,
+            path: "/v1/worker/task/{task}/complete",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "worker_task_add_output",
+            method: reqwest::Method::POST,
+            path: "/v1/worker/task/{task}/output",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "workers_list",
+            method: reqwest::Method::GET,
+            path: "/v1/workers",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "workers_recycle",
+            method: reqwest::Method::POST,
+            path: "/v1/workers/recycle",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "get_thing_or_things",
+            method: reqwest::Method::GET,
+            path: "/v1/things",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "header_arg",
+            method: reqwest::Method::GET,
+            path: "/v1/header-arg",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[],
+        },
+    ];
 }
 
 impl ClientInfo<()> for Client {
@@ -1026,6 +1231,43 @@ impl ClientHooks<()> for &Client {}
 impl Client {
     ///Sends a `POST` request to `/v1/control/hold`
     pub async fn control_hold<'a>(&'a self) -> Result<ResponseValue<()>, Error<()>> {
+        self.control_hold_with_options(progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::control_hold`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn control_hold_with_options<'a>(
+        &'a self,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<()>> {
+        let mut request = self.control_hold_request().map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[0]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::control_hold`] without sending it.
+    pub fn control_hold_request<'a>(
+        &'a self,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/control/hold", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -1042,21 +1284,48 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "control_hold",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `POST` request to `/v1/control/resume`
+    pub async fn control_resume<'a>(&'a self) -> Result<ResponseValue<()>, Error<()>> {
+        self.control_resume_with_options(progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::control_resume`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn control_resume_with_options<'a>(
+        &'a self,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<()>> {
+        let mut request = self.control_resume_request().map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[1]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            200u16 => Ok(ResponseValue::empty(response)),
             _ => Err(Error::UnexpectedResponse(response)),
         }
     }
 
-    ///Sends a `POST` request to `/v1/control/resume`
-    pub async fn control_resume<'a>(&'a self) -> Result<ResponseValue<()>, Error<()>> {
+    ///Prepares the request of [`Client::control_resume`] without sending it.
+    pub fn control_resume_request<'a>(
+        &'a self,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/control/resume", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -1065,24 +1334,53 @@ impl Client {
         );
         #[allow(unused_mut)]
         let mut request = self.client.post(url).headers(header_map).build()?;
-        let info = OperationInfo {
-            operation_id: "control_resume",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `GET` request to `/v1/task/{Task}`
+    pub async fn task_get<'a>(
+        &'a self,
+        task: &'a str,
+    ) -> Result<ResponseValue<types::Task>, Error<()>> {
+        self.task_get_with_options(task, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::task_get`] with additional headers, query
+    /// parameters, or context from `options`.
+    pub async fn task_get_with_options<'a>(
+        &'a self,
+        task: &'a str,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Task>, Error<()>> {
+        let mut request = self.task_get_request(task).map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[2]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => Ok(ResponseValue::empty(response)),
+            200u16 => ResponseValue::from_response(response).await,
             _ => Err(Error::UnexpectedResponse(response)),
         }
     }
 
-    ///Sends a `GET` request to `/v1/task/{Task}`
-    pub async fn task_get<'a>(
+    ///Prepares the request of [`Client::task_get`] without sending it.
+    pub fn task_get_request<'a>(
         &'a self,
         task: &'a str,
-    ) -> Result<ResponseValue<types::Task>, Error<()>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/task/{}",
             self.baseurl,
@@ -1103,23 +1401,50 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "task_get",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `GET` request to `/v1/tasks`
+    pub async fn tasks_get<'a>(
+        &'a self,
+    ) -> Result<ResponseValue<::std::vec::Vec<types::Task>>, Error<()>> {
+        self.tasks_get_with_options(progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::tasks_get`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn tasks_get_with_options<'a>(
+        &'a self,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<::std::vec::Vec<types::Task>>, Error<()>> {
+        let mut request = self.tasks_get_request().map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[3]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             _ => Err(Error::UnexpectedResponse(response)),
         }
     }
 
-    ///Sends a `GET` request to `/v1/tasks`
-    pub async fn tasks_get<'a>(
+    ///Prepares the request of [`Client::tasks_get`] without sending it.
+    pub fn tasks_get_request<'a>(
         &'a self,
-    ) -> Result<ResponseValue<::std::vec::Vec<types::Task>>, Error<()>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/tasks", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -1136,24 +1461,53 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "tasks_get",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `POST` request to `/v1/tasks`
+    pub async fn task_submit<'a>(
+        &'a self,
+        body: &'a types::TaskSubmit,
+    ) -> Result<ResponseValue<types::TaskSubmitResult>, Error<()>> {
+        self.task_submit_with_options(body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::task_submit`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn task_submit_with_options<'a>(
+        &'a self,
+        body: &'a types::TaskSubmit,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::TaskSubmitResult>, Error<()>> {
+        let mut request = self.task_submit_request(body).map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[4]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            201u16 => ResponseValue::from_response(response).await,
             _ => Err(Error::UnexpectedResponse(response)),
         }
     }
 
-    ///Sends a `POST` request to `/v1/tasks`
-    pub async fn task_submit<'a>(
+    ///Prepares the request of [`Client::task_submit`] without sending it.
+    pub fn task_submit_request<'a>(
         &'a self,
         body: &'a types::TaskSubmit,
-    ) -> Result<ResponseValue<types::TaskSubmitResult>, Error<()>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/tasks", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -1171,25 +1525,62 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "task_submit",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `GET` request to `/v1/tasks/{task}/events`
+    pub async fn task_events_get<'a>(
+        &'a self,
+        task: &'a str,
+        minseq: Option<u32>,
+    ) -> Result<ResponseValue<::std::vec::Vec<types::TaskEvent>>, Error<()>> {
+        self.task_events_get_with_options(
+            task,
+            minseq,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::task_events_get`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn task_events_get_with_options<'a>(
+        &'a self,
+        task: &'a str,
+        minseq: Option<u32>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<::std::vec::Vec<types::TaskEvent>>, Error<()>> {
+        let mut request = self
+            .task_events_get_request(task, minseq)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[5]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             _ => Err(Error::UnexpectedResponse(response)),
         }
     }
 
-    ///Sends a `GET` request to `/v1/tasks/{task}/events`
-    pub async fn task_events_get<'a>(
+    ///Prepares the request of [`Client::task_events_get`] without sending it.
+    pub fn task_events_get_request<'a>(
         &'a self,
         task: &'a str,
         minseq: Option<u32>,
-    ) -> Result<ResponseValue<::std::vec::Vec<types::TaskEvent>>, Error<()>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/tasks/{}/events",
             self.baseurl,
@@ -1211,24 +1602,55 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("minseq", &minseq))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "task_events_get",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `GET` request to `/v1/tasks/{task}/outputs`
+    pub async fn task_outputs_get<'a>(
+        &'a self,
+        task: &'a str,
+    ) -> Result<ResponseValue<::std::vec::Vec<types::TaskOutput>>, Error<()>> {
+        self.task_outputs_get_with_options(task, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::task_outputs_get`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn task_outputs_get_with_options<'a>(
+        &'a self,
+        task: &'a str,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<::std::vec::Vec<types::TaskOutput>>, Error<()>> {
+        let mut request = self
+            .task_outputs_get_request(task)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[6]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             _ => Err(Error::UnexpectedResponse(response)),
         }
     }
 
-    ///Sends a `GET` request to `/v1/tasks/{task}/outputs`
-    pub async fn task_outputs_get<'a>(
+    ///Prepares the request of [`Client::task_outputs_get`] without sending it.
+    pub fn task_outputs_get_request<'a>(
         &'a self,
         task: &'a str,
-    ) -> Result<ResponseValue<::std::vec::Vec<types::TaskOutput>>, Error<()>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/tasks/{}/outputs",
             self.baseurl,
@@ -1249,25 +1671,63 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "task_outputs_get",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `GET` request to `/v1/tasks/{task}/outputs/{output}`
+    pub async fn task_output_download<'a>(
+        &'a self,
+        task: &'a str,
+        output: &'a str,
+    ) -> Result<ResponseValue<ByteStream>, Error<()>> {
+        self.task_output_download_with_options(
+            task,
+            output,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::task_output_download`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn task_output_download_with_options<'a>(
+        &'a self,
+        task: &'a str,
+        output: &'a str,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<ByteStream>, Error<()>> {
+        let mut request = self
+            .task_output_download_request(task, output)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[7]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            200..=299 => Ok(ResponseValue::stream(response)),
             _ => Err(Error::UnexpectedResponse(response)),
         }
     }
 
-    ///Sends a `GET` request to `/v1/tasks/{task}/outputs/{output}`
-    pub async fn task_output_download<'a>(
+    ///Prepares the request of [`Client::task_output_download`] without sending
+    /// it.
+    pub fn task_output_download_request<'a>(
         &'a self,
         task: &'a str,
         output: &'a str,
-    ) -> Result<ResponseValue<ByteStream>, Error<()>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/tasks/{}/outputs/{}",
             self.baseurl,
@@ -1281,24 +1741,53 @@ impl Client {
         );
         #[allow(unused_mut)]
         let mut request = self.client.get(url).headers(header_map).build()?;
-        let info = OperationInfo {
-            operation_id: "task_output_download",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `POST` request to `/v1/users`
+    pub async fn user_create<'a>(
+        &'a self,
+        body: &'a types::UserCreate,
+    ) -> Result<ResponseValue<types::UserCreateResult>, Error<()>> {
+        self.user_create_with_options(body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::user_create`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn user_create_with_options<'a>(
+        &'a self,
+        body: &'a types::UserCreate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::UserCreateResult>, Error<()>> {
+        let mut request = self.user_create_request(body).map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[8]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200..=299 => Ok(ResponseValue::stream(response)),
+            201u16 => ResponseValue::from_response(response).await,
             _ => Err(Error::UnexpectedResponse(response)),
         }
     }
 
-    ///Sends a `POST` request to `/v1/users`
-    pub async fn user_create<'a>(
+    ///Prepares the request of [`Client::user_create`] without sending it.
+    pub fn user_create_request<'a>(
         &'a self,
         body: &'a types::UserCreate,
-    ) -> Result<ResponseValue<types::UserCreateResult>, Error<()>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/users", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -1316,21 +1805,48 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "user_create",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `GET` request to `/v1/whoami`
+    pub async fn whoami<'a>(&'a self) -> Result<ResponseValue<types::WhoamiResult>, Error<()>> {
+        self.whoami_with_options(progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::whoami`] with additional headers, query
+    /// parameters, or context from `options`.
+    pub async fn whoami_with_options<'a>(
+        &'a self,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::WhoamiResult>, Error<()>> {
+        let mut request = self.whoami_request().map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[9]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             _ => Err(Error::UnexpectedResponse(response)),
         }
     }
 
-    ///Sends a `GET` request to `/v1/whoami`
-    pub async fn whoami<'a>(&'a self) -> Result<ResponseValue<types::WhoamiResult>, Error<()>> {
+    ///Prepares the request of [`Client::whoami`] without sending it.
+    pub fn whoami_request<'a>(
+        &'a self,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/whoami", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -1347,24 +1863,55 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "whoami",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `PUT` request to `/v1/whoami/name`
+    pub async fn whoami_put_name<'a>(
+        &'a self,
+        body: String,
+    ) -> Result<ResponseValue<()>, Error<()>> {
+        self.whoami_put_name_with_options(body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::whoami_put_name`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn whoami_put_name_with_options<'a>(
+        &'a self,
+        body: String,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<()>> {
+        let mut request = self
+            .whoami_put_name_request(body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[10]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            200u16 => Ok(ResponseValue::empty(response)),
             _ => Err(Error::UnexpectedResponse(response)),
         }
     }
 
-    ///Sends a `PUT` request to `/v1/whoami/name`
-    pub async fn whoami_put_name<'a>(
+    ///Prepares the request of [`Client::whoami_put_name`] without sending it.
+    pub fn whoami_put_name_request<'a>(
         &'a self,
         body: String,
-    ) -> Result<ResponseValue<()>, Error<()>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/whoami/name", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -1382,24 +1929,55 @@ impl Client {
             .body(body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "whoami_put_name",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `POST` request to `/v1/worker/bootstrap`
+    pub async fn worker_bootstrap<'a>(
+        &'a self,
+        body: &'a types::WorkerBootstrap,
+    ) -> Result<ResponseValue<types::WorkerBootstrapResult>, Error<()>> {
+        self.worker_bootstrap_with_options(body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::worker_bootstrap`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn worker_bootstrap_with_options<'a>(
+        &'a self,
+        body: &'a types::WorkerBootstrap,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::WorkerBootstrapResult>, Error<()>> {
+        let mut request = self
+            .worker_bootstrap_request(body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[11]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => Ok(ResponseValue::empty(response)),
+            201u16 => ResponseValue::from_response(response).await,
             _ => Err(Error::UnexpectedResponse(response)),
         }
     }
 
-    ///Sends a `POST` request to `/v1/worker/bootstrap`
-    pub async fn worker_bootstrap<'a>(
+    ///Prepares the request of [`Client::worker_bootstrap`] without sending it.
+    pub fn worker_bootstrap_request<'a>(
         &'a self,
         body: &'a types::WorkerBootstrap,
-    ) -> Result<ResponseValue<types::WorkerBootstrapResult>, Error<()>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/worker/bootstrap", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -1417,23 +1995,50 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "worker_bootstrap",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `GET` request to `/v1/worker/ping`
+    pub async fn worker_ping<'a>(
+        &'a self,
+    ) -> Result<ResponseValue<types::WorkerPingResult>, Error<()>> {
+        self.worker_ping_with_options(progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::worker_ping`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn worker_ping_with_options<'a>(
+        &'a self,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::WorkerPingResult>, Error<()>> {
+        let mut request = self.worker_ping_request().map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[12]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             _ => Err(Error::UnexpectedResponse(response)),
         }
     }
 
-    ///Sends a `GET` request to `/v1/worker/ping`
-    pub async fn worker_ping<'a>(
+    ///Prepares the request of [`Client::worker_ping`] without sending it.
+    pub fn worker_ping_request<'a>(
         &'a self,
-    ) -> Result<ResponseValue<types::WorkerPingResult>, Error<()>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/worker/ping", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -1450,25 +2055,63 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "worker_ping",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `POST` request to `/v1/worker/task/{task}/append`
+    pub async fn worker_task_append<'a>(
+        &'a self,
+        task: &'a str,
+        body: &'a types::WorkerAppendTask,
+    ) -> Result<ResponseValue<()>, Error<()>> {
+        self.worker_task_append_with_options(
+            task,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::worker_task_append`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn worker_task_append_with_options<'a>(
+        &'a self,
+        task: &'a str,
+        body: &'a types::WorkerAppendTask,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<()>> {
+        let mut request = self
+            .worker_task_append_request(task, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[13]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            201u16 => Ok(ResponseValue::empty(response)),
             _ => Err(Error::UnexpectedResponse(response)),
         }
     }
 
-    ///Sends a `POST` request to `/v1/worker/task/{task}/append`
-    pub async fn worker_task_append<'a>(
+    ///Prepares the request of [`Client::worker_task_append`] without sending
+    /// it.
+    pub fn worker_task_append_request<'a>(
         &'a self,
         task: &'a str,
         body: &'a types::WorkerAppendTask,
-    ) -> Result<ResponseValue<()>, Error<()>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/worker/task/{}/append",
             self.baseurl,
@@ -1486,35 +2129,81 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "worker_task_append",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `POST` request to `/v1/worker/task/{task}/chunk`
+    pub async fn worker_task_upload_chunk<'a, B: Into<progenitor_client::UploadBody>>(
+        &'a self,
+        task: &'a str,
+        body: B,
+    ) -> Result<ResponseValue<types::UploadedChunk>, Error<()>> {
+        self.worker_task_upload_chunk_with_options(
+            task,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::worker_task_upload_chunk`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn worker_task_upload_chunk_with_options<
+        'a,
+        B: Into<progenitor_client::UploadBody>,
+    >(
+        &'a self,
+        task: &'a str,
+        body: B,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::UploadedChunk>, Error<()>> {
+        let mut request = self
+            .worker_task_upload_chunk_request(task, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[14]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => Ok(ResponseValue::empty(response)),
+            201u16 => ResponseValue::from_response(response).await,
             _ => Err(Error::UnexpectedResponse(response)),
         }
     }
 
-    ///Sends a `POST` request to `/v1/worker/task/{task}/chunk`
-    pub async fn worker_task_upload_chunk<'a, B: Into<reqwest::Body>>(
+    ///Prepares the request of [`Client::worker_task_upload_chunk`] without
+    /// sending it.
+    pub fn worker_task_upload_chunk_request<'a, B: Into<progenitor_client::UploadBody>>(
         &'a self,
         task: &'a str,
         body: B,
-    ) -> Result<ResponseValue<types::UploadedChunk>, Error<()>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/worker/task/{}/chunk",
             self.baseurl,
             encode_path(&task.to_string()),
         );
-        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
         header_map.append(
             ::reqwest::header::HeaderName::from_static("api-version"),
             ::reqwest::header::HeaderValue::from_static(Self::api_version()),
         );
+        let body: progenitor_client::UploadBody = body.into();
+        if let Some(length) = body.content_length() {
+            header_map.append(::reqwest::header::CONTENT_LENGTH, length.into());
+        }
+
         #[allow(unused_mut)]
         let mut request = self
             .client
@@ -1527,28 +2216,66 @@ impl Client {
                 ::reqwest::header::CONTENT_TYPE,
                 ::reqwest::header::HeaderValue::from_static("application/octet-stream"),
             )
-            .body(body)
+            .body(body.into_body())
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "worker_task_upload_chunk",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `POST` request to `/v1/worker/task/{task}/complete`
+    pub async fn worker_task_complete<'a>(
+        &'a self,
+        task: &'a str,
+        body: &'a types::WorkerCompleteTask,
+    ) -> Result<ResponseValue<()>, Error<()>> {
+        self.worker_task_complete_with_options(
+            task,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::worker_task_complete`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn worker_task_complete_with_options<'a>(
+        &'a self,
+        task: &'a str,
+        body: &'a types::WorkerCompleteTask,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<()>> {
+        let mut request = self
+            .worker_task_complete_request(task, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[15]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            200u16 => Ok(ResponseValue::empty(response)),
             _ => Err(Error::UnexpectedResponse(response)),
         }
     }
 
-    ///Sends a `POST` request to `/v1/worker/task/{task}/complete`
-    pub async fn worker_task_complete<'a>(
+    ///Prepares the request of [`Client::worker_task_complete`] without sending
+    /// it.
+    pub fn worker_task_complete_request<'a>(
         &'a self,
         task: &'a str,
         body: &'a types::WorkerCompleteTask,
-    ) -> Result<ResponseValue<()>, Error<()>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/worker/task/{}/complete",
             self.baseurl,
@@ -1566,25 +2293,63 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "worker_task_complete",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `POST` request to `/v1/worker/task/{task}/output`
+    pub async fn worker_task_add_output<'a>(
+        &'a self,
+        task: &'a str,
+        body: &'a types::WorkerAddOutput,
+    ) -> Result<ResponseValue<()>, Error<()>> {
+        self.worker_task_add_output_with_options(
+            task,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::worker_task_add_output`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn worker_task_add_output_with_options<'a>(
+        &'a self,
+        task: &'a str,
+        body: &'a types::WorkerAddOutput,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<()>> {
+        let mut request = self
+            .worker_task_add_output_request(task, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[16]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => Ok(ResponseValue::empty(response)),
+            201u16 => Ok(ResponseValue::empty(response)),
             _ => Err(Error::UnexpectedResponse(response)),
         }
     }
 
-    ///Sends a `POST` request to `/v1/worker/task/{task}/output`
-    pub async fn worker_task_add_output<'a>(
+    ///Prepares the request of [`Client::worker_task_add_output`] without
+    /// sending it.
+    pub fn worker_task_add_output_request<'a>(
         &'a self,
         task: &'a str,
         body: &'a types::WorkerAddOutput,
-    ) -> Result<ResponseValue<()>, Error<()>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/worker/task/{}/output",
             self.baseurl,
@@ -1602,23 +2367,50 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "worker_task_add_output",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `GET` request to `/v1/workers`
+    pub async fn workers_list<'a>(
+        &'a self,
+    ) -> Result<ResponseValue<types::WorkersResult>, Error<()>> {
+        self.workers_list_with_options(progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::workers_list`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn workers_list_with_options<'a>(
+        &'a self,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::WorkersResult>, Error<()>> {
+        let mut request = self.workers_list_request().map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[17]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => Ok(ResponseValue::empty(response)),
+            200u16 => ResponseValue::from_response(response).await,
             _ => Err(Error::UnexpectedResponse(response)),
         }
     }
 
-    ///Sends a `GET` request to `/v1/workers`
-    pub async fn workers_list<'a>(
+    ///Prepares the request of [`Client::workers_list`] without sending it.
+    pub fn workers_list_request<'a>(
         &'a self,
-    ) -> Result<ResponseValue<types::WorkersResult>, Error<()>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/workers", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -1635,21 +2427,48 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "workers_list",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `POST` request to `/v1/workers/recycle`
+    pub async fn workers_recycle<'a>(&'a self) -> Result<ResponseValue<()>, Error<()>> {
+        self.workers_recycle_with_options(progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::workers_recycle`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn workers_recycle_with_options<'a>(
+        &'a self,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<()>> {
+        let mut request = self.workers_recycle_request().map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[18]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            200u16 => Ok(ResponseValue::empty(response)),
             _ => Err(Error::UnexpectedResponse(response)),
         }
     }
 
-    ///Sends a `POST` request to `/v1/workers/recycle`
-    pub async fn workers_recycle<'a>(&'a self) -> Result<ResponseValue<()>, Error<()>> {
+    ///Prepares the request of [`Client::workers_recycle`] without sending it.
+    pub fn workers_recycle_request<'a>(
+        &'a self,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/workers/recycle", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -1658,24 +2477,56 @@ impl Client {
         );
         #[allow(unused_mut)]
         let mut request = self.client.post(url).headers(header_map).build()?;
-        let info = OperationInfo {
-            operation_id: "workers_recycle",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `GET` request to `/v1/things`
+    pub async fn get_thing_or_things<'a>(
+        &'a self,
+        id: Option<&'a types::GetThingOrThingsId>,
+    ) -> Result<ResponseValue<::std::string::String>, Error<()>> {
+        self.get_thing_or_things_with_options(id, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::get_thing_or_things`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn get_thing_or_things_with_options<'a>(
+        &'a self,
+        id: Option<&'a types::GetThingOrThingsId>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<::std::string::String>, Error<()>> {
+        let mut request = self
+            .get_thing_or_things_request(id)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[19]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => Ok(ResponseValue::empty(response)),
+            200u16 => ResponseValue::from_response(response).await,
             _ => Err(Error::UnexpectedResponse(response)),
         }
     }
 
-    ///Sends a `GET` request to `/v1/things`
-    pub async fn get_thing_or_things<'a>(
+    ///Prepares the request of [`Client::get_thing_or_things`] without sending
+    /// it.
+    pub fn get_thing_or_things_request<'a>(
         &'a self,
         id: Option<&'a types::GetThingOrThingsId>,
-    ) -> Result<ResponseValue<::std::string::String>, Error<()>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/things", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -1693,17 +2544,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("id", &id))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "get_thing_or_things",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Sends a `GET` request to `/v1/header-arg`
@@ -1711,6 +2552,50 @@ impl Client {
         &'a self,
         accept_language: Option<types::HeaderArgAcceptLanguage>,
     ) -> Result<ResponseValue<()>, Error<()>> {
+        self.header_arg_with_options(
+            accept_language,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::header_arg`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn header_arg_with_options<'a>(
+        &'a self,
+        accept_language: Option<types::HeaderArgAcceptLanguage>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<()>> {
+        let mut request = self
+            .header_arg_request(accept_language)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[20]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200..=299 => Ok(ResponseValue::empty(response)),
+            _ => Err(Error::ErrorResponse(ResponseValue::empty(response))),
+        }
+    }
+
+    ///Prepares the request of [`Client::header_arg`] without sending it.
+    pub fn header_arg_request<'a>(
+        &'a self,
+        accept_language: Option<types::HeaderArgAcceptLanguage>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/header-arg", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
         header_map.append(
@@ -1723,17 +2608,7 @@ impl Client {
 
         #[allow(unused_mut)]
         let mut request = self.client.get(url).headers(header_map).build()?;
-        let info = OperationInfo {
-            operation_id: "header_arg",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200..=299 => Ok(ResponseValue::empty(response)),
-            _ => Err(Error::ErrorResponse(ResponseValue::empty(response))),
-        }
+        Ok(request)
     }
 }
 
@@ -1,7 +1,7 @@
 #[allow(unused_imports)]
 use progenitor_client::{encode_path, ClientHooks, OperationInfo, RequestBuilderExt};
 #[allow(unused_imports)]
-pub use progenitor_client::{ByteStream, ClientInfo, Error, ResponseValue};
+pub use progenitor_client::{ByteStream, ClientInfo, Error, RequestOptions, ResponseValue};
 /// Types used as operation parameters and responses.
 #[allow(clippy::all)]
 pub mod types {
@@ -147,6 +147,7 @@ pub mod types {
 pub struct Client {
     pub(crate) baseurl: String,
     pub(crate) client: reqwest::Client,
+    pub(crate) middleware: progenitor_client::MiddlewareStack,
 }
 
 impl Client {
@@ -178,8 +179,29 @@ impl Client {
         Self {
             baseurl: baseurl.to_string(),
             client,
+            middleware: Default::default(),
         }
     }
+
+    /// Append `middleware` to the chain that surrounds the
+    /// execution of each request. Middleware runs in the order in
+    /// which it is attached.
+    pub fn with_middleware<M: progenitor_client::Middleware>(mut self, middleware: M) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Descriptions of the API's operations.
+    pub const OPERATIONS: &'static [progenitor_client::OperationMetadata] =
+        &[progenitor_client::OperationMetadata {
+            operation_id: "uno",
+            method: reqwest::Method::GET,
+            path: "/uno",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=299u16],
+        }];
 }
 
 impl ClientInfo<()> for Client {
@@ -233,6 +255,7 @@ pub mod builder {
         client: &'a super::Client,
         gateway: Result<::std::string::String, String>,
         body: Result<types::builder::UnoBody, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> Uno<'a> {
@@ -241,6 +264,7 @@ pub mod builder {
                 client: client,
                 gateway: Err("gateway was not initialized".to_string()),
                 body: Ok(::std::default::Default::default()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -274,12 +298,81 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `GET` request to `/uno`
-        pub async fn send(self) -> Result<ResponseValue<ByteStream>, Error<()>> {
+        pub async fn send(mut self) -> Result<ResponseValue<ByteStream>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[0])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200..=299 => Ok(ResponseValue::stream(response)),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
             let Self {
                 client,
                 gateway,
                 body,
+                options,
             } = self;
             let gateway = gateway.map_err(Error::InvalidRequest)?;
             let body = body
@@ -299,17 +392,8 @@ pub mod builder {
                 .query(&progenitor_client::QueryParam::new("gateway", &gateway))
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "uno",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                200..=299 => Ok(ResponseValue::stream(response)),
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 }
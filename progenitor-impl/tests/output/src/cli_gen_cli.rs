@@ -37,6 +37,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub async fn execute(
@@ -62,6 +74,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_uno(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -95,6 +120,16 @@ pub trait CliConfig {
     fn list_end_error<T>(&self, value: &Error<T>)
     where
         T: schemars::JsonSchema + serde::Serialize + std::fmt::Debug;
+    /// Output for `--progenitor-dry-run`.
+    fn dry_run(&self, request: &reqwest::Request) {
+        print!("{}", progenitor_client::format_request(request));
+    }
+
+    /// Output for `--progenitor-print-curl`.
+    fn print_curl(&self, request: &reqwest::Request) {
+        println!("{}", progenitor_client::curl_command(request));
+    }
+
     fn execute_uno(
         &self,
         matches: &::clap::ArgMatches,
@@ -2,7 +2,7 @@
 #[allow(unused_imports)]
 use progenitor_client::{encode_path, ClientHooks, OperationInfo, RequestBuilderExt};
 #[allow(unused_imports)]
-pub use progenitor_client::{ByteStream, ClientInfo, Error, ResponseValue};
+pub use progenitor_client::{ByteStream, ClientInfo, Error, RequestOptions, ResponseValue};
 /// Types used as operation parameters and responses.
 #[allow(clippy::all)]
 pub mod types {
@@ -77,6 +77,7 @@ pub mod types {
 pub struct Client {
     pub(crate) baseurl: String,
     pub(crate) client: reqwest::Client,
+    pub(crate) middleware: progenitor_client::MiddlewareStack,
 }
 
 impl Client {
@@ -108,8 +109,29 @@ impl Client {
         Self {
             baseurl: baseurl.to_string(),
             client,
+            middleware: Default::default(),
         }
     }
+
+    /// Append `middleware` to the chain that surrounds the
+    /// execution of each request. Middleware runs in the order in
+    /// which it is attached.
+    pub fn with_middleware<M: progenitor_client::Middleware>(mut self, middleware: M) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Descriptions of the API's operations.
+    pub const OPERATIONS: &'static [progenitor_client::OperationMetadata] =
+        &[progenitor_client::OperationMetadata {
+            operation_id: "uno",
+            method: reqwest::Method::GET,
+            path: "/uno",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=299u16],
+        }];
 }
 
 impl ClientInfo<()> for Client {
@@ -140,6 +162,47 @@ impl Client {
         gateway: &'a str,
         body: &'a types::UnoBody,
     ) -> Result<ResponseValue<ByteStream>, Error<()>> {
+        self.uno_with_options(gateway, body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::uno`] with additional headers, query
+    /// parameters, or context from `options`.
+    pub async fn uno_with_options<'a>(
+        &'a self,
+        gateway: &'a str,
+        body: &'a types::UnoBody,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<ByteStream>, Error<()>> {
+        let mut request = self.uno_request(gateway, body).map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[0]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200..=299 => Ok(ResponseValue::stream(response)),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::uno`] without sending it.
+    pub fn uno_request<'a>(
+        &'a self,
+        gateway: &'a str,
+        body: &'a types::UnoBody,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/uno", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -154,17 +217,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("gateway", &gateway))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "uno",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200..=299 => Ok(ResponseValue::stream(response)),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 }
 
@@ -0,0 +1,146 @@
+#[allow(unused_imports)]
+use crate::keeper_builder::types;
+pub mod parameters {
+    //! Path and query parameters of each operation, as extracted
+    //! by the handlers of [`Api`](super::Api).
+    #[allow(unused_imports)]
+    use crate::keeper_builder::types;
+}
+
+/// An axum service with a handler for each operation.
+///
+/// Successful responses are sent with the status code from the
+/// API description. A typed error is sent as JSON with the
+/// status code the handler returns with it; other responses are
+/// sent as the handler builds them.
+#[progenitor_client::async_trait]
+pub trait Api: Send + Sync + 'static {
+    ///Handles `POST /enrol`
+    async fn enrol(
+        &self,
+        headers: axum::http::HeaderMap,
+        body: axum::Json<types::EnrolBody>,
+    ) -> Result<(), axum::response::Response>;
+    ///Handles `GET /global/jobs`
+    async fn global_jobs(
+        &self,
+        headers: axum::http::HeaderMap,
+    ) -> Result<types::GlobalJobsResult, axum::response::Response>;
+    ///Handles `GET /ping`
+    async fn ping(
+        &self,
+        headers: axum::http::HeaderMap,
+    ) -> Result<types::PingResult, axum::response::Response>;
+    ///Handles `POST /report/finish`
+    async fn report_finish(
+        &self,
+        headers: axum::http::HeaderMap,
+        body: axum::Json<types::ReportFinishBody>,
+    ) -> Result<types::ReportResult, axum::response::Response>;
+    ///Handles `POST /report/output`
+    async fn report_output(
+        &self,
+        headers: axum::http::HeaderMap,
+        body: axum::Json<types::ReportOutputBody>,
+    ) -> Result<types::ReportResult, axum::response::Response>;
+    ///Handles `POST /report/start`
+    async fn report_start(
+        &self,
+        headers: axum::http::HeaderMap,
+        body: axum::Json<types::ReportStartBody>,
+    ) -> Result<types::ReportResult, axum::response::Response>;
+}
+
+/// Build an `axum::Router` that routes each operation to its
+/// handler in `api`.
+pub fn router<A: Api>(api: std::sync::Arc<A>) -> axum::Router {
+    use axum::response::IntoResponse;
+    axum::Router::new()
+        .route(
+            "/enrol",
+            axum::routing::post(
+                |axum::extract::State(api): axum::extract::State<std::sync::Arc<A>>,
+                 headers: axum::http::HeaderMap,
+                 body: axum::Json<types::EnrolBody>| async move {
+                    match api.enrol(headers, body).await {
+                        Ok(()) => axum::http::StatusCode::CREATED.into_response(),
+                        Err(response) => response,
+                    }
+                },
+            ),
+        )
+        .route(
+            "/global/jobs",
+            axum::routing::get(
+                |axum::extract::State(api): axum::extract::State<std::sync::Arc<A>>,
+                 headers: axum::http::HeaderMap| async move {
+                    match api.global_jobs(headers).await {
+                        Ok(value) => {
+                            (axum::http::StatusCode::CREATED, axum::Json(value)).into_response()
+                        }
+                        Err(response) => response,
+                    }
+                },
+            ),
+        )
+        .route(
+            "/ping",
+            axum::routing::get(
+                |axum::extract::State(api): axum::extract::State<std::sync::Arc<A>>,
+                 headers: axum::http::HeaderMap| async move {
+                    match api.ping(headers).await {
+                        Ok(value) => {
+                            (axum::http::StatusCode::CREATED, axum::Json(value)).into_response()
+                        }
+                        Err(response) => response,
+                    }
+                },
+            ),
+        )
+        .route(
+            "/report/finish",
+            axum::routing::post(
+                |axum::extract::State(api): axum::extract::State<std::sync::Arc<A>>,
+                 headers: axum::http::HeaderMap,
+                 body: axum::Json<types::ReportFinishBody>| async move {
+                    match api.report_finish(headers, body).await {
+                        Ok(value) => {
+                            (axum::http::StatusCode::CREATED, axum::Json(value)).into_response()
+                        }
+                        Err(response) => response,
+                    }
+                },
+            ),
+        )
+        .route(
+            "/report/output",
+            axum::routing::post(
+                |axum::extract::State(api): axum::extract::State<std::sync::Arc<A>>,
+                 headers: axum::http::HeaderMap,
+                 body: axum::Json<types::ReportOutputBody>| async move {
+                    match api.report_output(headers, body).await {
+                        Ok(value) => {
+                            (axum::http::StatusCode::CREATED, axum::Json(value)).into_response()
+                        }
+                        Err(response) => response,
+                    }
+                },
+            ),
+        )
+        .route(
+            "/report/start",
+            axum::routing::post(
+                |axum::extract::State(api): axum::extract::State<std::sync::Arc<A>>,
+                 headers: axum::http::HeaderMap,
+                 body: axum::Json<types::ReportStartBody>| async move {
+                    match api.report_start(headers, body).await {
+                        Ok(value) => {
+                            (axum::http::StatusCode::CREATED, axum::Json(value)).into_response()
+                        }
+                        Err(response) => response,
+                    }
+                },
+            ),
+        )
+        .with_state(api)
+}
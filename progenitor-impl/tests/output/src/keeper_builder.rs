@@ -1,7 +1,7 @@
 #[allow(unused_imports)]
 use progenitor_client::{encode_path, ClientHooks, OperationInfo, RequestBuilderExt};
 #[allow(unused_imports)]
-pub use progenitor_client::{ByteStream, ClientInfo, Error, ResponseValue};
+pub use progenitor_client::{ByteStream, ClientInfo, Error, RequestOptions, ResponseValue};
 /// Types used as operation parameters and responses.
 #[allow(clippy::all)]
 pub mod types {
@@ -1270,6 +1270,7 @@ pub mod types {
 pub struct Client {
     pub(crate) baseurl: String,
     pub(crate) client: reqwest::Client,
+    pub(crate) middleware: progenitor_client::MiddlewareStack,
 }
 
 impl Client {
@@ -1301,8 +1302,75 @@ impl Client {
         Self {
             baseurl: baseurl.to_string(),
             client,
+            middleware: Default::default(),
         }
     }
+
+    /// Append `middleware` to the chain that surrounds the
+    /// execution of each request. Middleware runs in the order in
+    /// which it is attached.
+    pub fn with_middleware<M: progenitor_client::Middleware>(mut self, middleware: M) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Descriptions of the API's operations.
+    pub const OPERATIONS: &'static [progenitor_client::OperationMetadata] = &[
+        progenitor_client::OperationMetadata {
+            operation_id: "enrol",
+            method: reqwest::Method::POST,
+            path: "/enrol",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "global_jobs",
+            method: reqwest::Method::GET,
+            path: "/global/jobs",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "ping",
+            method: reqwest::Method::GET,
+            path: "/ping",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "report_finish",
+            method: reqwest::Method::POST,
+            path: "/report/finish",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "report_output",
+            method: reqwest::Method::POST,
+            path: "/report/output",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "report_start",
+            method: reqwest::Method::POST,
+            path: "/report/start",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+    ];
 }
 
 impl ClientInfo<()> for Client {
@@ -1435,6 +1503,7 @@ pub mod builder {
         client: &'a super::Client,
         authorization: Result<::std::string::String, String>,
         body: Result<types::builder::EnrolBody, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> Enrol<'a> {
@@ -1443,6 +1512,7 @@ pub mod builder {
                 client: client,
                 authorization: Err("authorization was not initialized".to_string()),
                 body: Ok(::std::default::Default::default()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -1476,12 +1546,81 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `POST` request to `/enrol`
-        pub async fn send(self) -> Result<ResponseValue<()>, Error<()>> {
+        pub async fn send(mut self) -> Result<ResponseValue<()>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[0])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                201u16 => Ok(ResponseValue::empty(response)),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
             let Self {
                 client,
                 authorization,
                 body,
+                options,
             } = self;
             let authorization = authorization.map_err(Error::InvalidRequest)?;
             let body = body
@@ -1501,17 +1640,8 @@ pub mod builder {
                 .json(&body)
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "enrol",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                201u16 => Ok(ResponseValue::empty(response)),
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -1522,6 +1652,7 @@ pub mod builder {
     pub struct GlobalJobs<'a> {
         client: &'a super::Client,
         authorization: Result<::std::string::String, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> GlobalJobs<'a> {
@@ -1529,6 +1660,7 @@ pub mod builder {
             Self {
                 client: client,
                 authorization: Err("authorization was not initialized".to_string()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -1542,11 +1674,80 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `GET` request to `/global/jobs`
-        pub async fn send(self) -> Result<ResponseValue<types::GlobalJobsResult>, Error<()>> {
+        pub async fn send(mut self) -> Result<ResponseValue<types::GlobalJobsResult>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[1])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                201u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
             let Self {
                 client,
                 authorization,
+                options,
             } = self;
             let authorization = authorization.map_err(Error::InvalidRequest)?;
             let url = format!("{}/global/jobs", client.baseurl,);
@@ -1566,17 +1767,8 @@ pub mod builder {
                 )
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "global_jobs",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                201u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -1587,6 +1779,7 @@ pub mod builder {
     pub struct Ping<'a> {
         client: &'a super::Client,
         authorization: Result<::std::string::String, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> Ping<'a> {
@@ -1594,6 +1787,7 @@ pub mod builder {
             Self {
                 client: client,
                 authorization: Err("authorization was not initialized".to_string()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -1607,11 +1801,80 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `GET` request to `/ping`
-        pub async fn send(self) -> Result<ResponseValue<types::PingResult>, Error<()>> {
+        pub async fn send(mut self) -> Result<ResponseValue<types::PingResult>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[2])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                201u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
             let Self {
                 client,
                 authorization,
+                options,
             } = self;
             let authorization = authorization.map_err(Error::InvalidRequest)?;
             let url = format!("{}/ping", client.baseurl,);
@@ -1631,17 +1894,8 @@ pub mod builder {
                 )
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "ping",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                201u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -1653,6 +1907,7 @@ pub mod builder {
         client: &'a super::Client,
         authorization: Result<::std::string::String, String>,
         body: Result<types::builder::ReportFinishBody, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> ReportFinish<'a> {
@@ -1661,6 +1916,7 @@ pub mod builder {
                 client: client,
                 authorization: Err("authorization was not initialized".to_string()),
                 body: Ok(::std::default::Default::default()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -1696,12 +1952,81 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `POST` request to `/report/finish`
-        pub async fn send(self) -> Result<ResponseValue<types::ReportResult>, Error<()>> {
+        pub async fn send(mut self) -> Result<ResponseValue<types::ReportResult>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[3])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                201u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
             let Self {
                 client,
                 authorization,
                 body,
+                options,
             } = self;
             let authorization = authorization.map_err(Error::InvalidRequest)?;
             let body = body
@@ -1725,17 +2050,8 @@ pub mod builder {
                 .json(&body)
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "report_finish",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                201u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -1747,6 +2063,7 @@ pub mod builder {
         client: &'a super::Client,
         authorization: Result<::std::string::String, String>,
         body: Result<types::builder::ReportOutputBody, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> ReportOutput<'a> {
@@ -1755,6 +2072,7 @@ pub mod builder {
                 client: client,
                 authorization: Err("authorization was not initialized".to_string()),
                 body: Ok(::std::default::Default::default()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -1790,12 +2108,81 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `POST` request to `/report/output`
-        pub async fn send(self) -> Result<ResponseValue<types::ReportResult>, Error<()>> {
+        pub async fn send(mut self) -> Result<ResponseValue<types::ReportResult>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[4])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                201u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
             let Self {
                 client,
                 authorization,
                 body,
+                options,
             } = self;
             let authorization = authorization.map_err(Error::InvalidRequest)?;
             let body = body
@@ -1819,17 +2206,8 @@ pub mod builder {
                 .json(&body)
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "report_output",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                201u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 
@@ -1841,6 +2219,7 @@ pub mod builder {
         client: &'a super::Client,
         authorization: Result<::std::string::String, String>,
         body: Result<types::builder::ReportStartBody, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> ReportStart<'a> {
@@ -1849,6 +2228,7 @@ pub mod builder {
                 client: client,
                 authorization: Err("authorization was not initialized".to_string()),
                 body: Ok(::std::default::Default::default()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -1882,12 +2262,81 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `POST` request to `/report/start`
-        pub async fn send(self) -> Result<ResponseValue<types::ReportResult>, Error<()>> {
+        pub async fn send(mut self) -> Result<ResponseValue<types::ReportResult>, Error<()>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[5])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                201u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
             let Self {
                 client,
                 authorization,
                 body,
+                options,
             } = self;
             let authorization = authorization.map_err(Error::InvalidRequest)?;
             let body = body
@@ -1911,17 +2360,8 @@ pub mod builder {
                 .json(&body)
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "report_start",
-            };
-            client.pre(&mut request, &info).await?;
-            let result = client.exec(request, &info).await;
-            client.post(&result, &info).await?;
-            let response = result?;
-            match response.status().as_u16() {
-                201u16 => ResponseValue::from_response(response).await,
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
+            options.apply(&mut request)?;
+            Ok(request)
         }
     }
 }
@@ -55,26 +55,64 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_global_jobs() -> ::clap::Command {
-        ::clap::Command::new("").arg(
-            ::clap::Arg::new("authorization")
-                .long("authorization")
-                .value_parser(::clap::value_parser!(::std::string::String))
-                .required(true)
-                .help("Authorization header (bearer token)"),
-        )
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("authorization")
+                    .long("authorization")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(true)
+                    .help("Authorization header (bearer token)"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_ping() -> ::clap::Command {
-        ::clap::Command::new("").arg(
-            ::clap::Arg::new("authorization")
-                .long("authorization")
-                .value_parser(::clap::value_parser!(::std::string::String))
-                .required(true)
-                .help("Authorization header (bearer token)"),
-        )
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("authorization")
+                    .long("authorization")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(true)
+                    .help("Authorization header (bearer token)"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_report_finish() -> ::clap::Command {
@@ -120,6 +158,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_report_output() -> ::clap::Command {
@@ -145,6 +195,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_report_start() -> ::clap::Command {
@@ -184,6 +246,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub async fn execute(
@@ -222,6 +296,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_enrol(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -242,6 +329,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_global_jobs(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -262,6 +362,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_ping(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -302,6 +415,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_report_finish(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -328,6 +454,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_report_output(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -364,6 +503,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_report_start(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -398,6 +550,16 @@ pub trait CliConfig {
     fn list_end_error<T>(&self, value: &Error<T>)
     where
         T: schemars::JsonSchema + serde::Serialize + std::fmt::Debug;
+    /// Output for `--progenitor-dry-run`.
+    fn dry_run(&self, request: &reqwest::Request) {
+        print!("{}", progenitor_client::format_request(request));
+    }
+
+    /// Output for `--progenitor-print-curl`.
+    fn print_curl(&self, request: &reqwest::Request) {
+        println!("{}", progenitor_client::curl_command(request));
+    }
+
     fn execute_enrol(
         &self,
         matches: &::clap::ArgMatches,
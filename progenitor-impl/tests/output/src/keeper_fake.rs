@@ -0,0 +1,147 @@
+#[allow(unused_imports)]
+use crate::keeper_builder::types;
+pub mod parameters {
+    //! Path and query parameters of each operation, as passed to
+    //! the methods of [`Api`](super::Api).
+    #[allow(unused_imports)]
+    use crate::keeper_builder::types;
+}
+
+/// The operations of the API, as implemented by a fake backend.
+///
+/// Successful responses are sent with the status code from the
+/// API description. A typed error is sent as JSON with the
+/// status code the method returns with it; other responses are
+/// sent as the method builds them.
+#[progenitor_client::async_trait]
+pub trait Api: Send + Sync + 'static {
+    ///Handles `POST /enrol`
+    async fn enrol(
+        &self,
+        headers: reqwest::header::HeaderMap,
+        body: types::EnrolBody,
+    ) -> Result<(), reqwest::Response>;
+    ///Handles `GET /global/jobs`
+    async fn global_jobs(
+        &self,
+        headers: reqwest::header::HeaderMap,
+    ) -> Result<types::GlobalJobsResult, reqwest::Response>;
+    ///Handles `GET /ping`
+    async fn ping(
+        &self,
+        headers: reqwest::header::HeaderMap,
+    ) -> Result<types::PingResult, reqwest::Response>;
+    ///Handles `POST /report/finish`
+    async fn report_finish(
+        &self,
+        headers: reqwest::header::HeaderMap,
+        body: types::ReportFinishBody,
+    ) -> Result<types::ReportResult, reqwest::Response>;
+    ///Handles `POST /report/output`
+    async fn report_output(
+        &self,
+        headers: reqwest::header::HeaderMap,
+        body: types::ReportOutputBody,
+    ) -> Result<types::ReportResult, reqwest::Response>;
+    ///Handles `POST /report/start`
+    async fn report_start(
+        &self,
+        headers: reqwest::header::HeaderMap,
+        body: types::ReportStartBody,
+    ) -> Result<types::ReportResult, reqwest::Response>;
+}
+
+/// Middleware that answers each request by calling the method of
+/// `A` for its operation, without sending it.
+///
+/// Attach it to a client with `with_middleware`, after any other
+/// middleware.
+pub struct Fake<A>(pub std::sync::Arc<A>);
+impl<A: Api> Fake<A> {
+    /// Create middleware that calls `api`.
+    pub fn new(api: A) -> Self {
+        Self(std::sync::Arc::new(api))
+    }
+}
+
+impl<A: Api> progenitor_client::Middleware for Fake<A> {
+    fn handle<'a>(
+        &'a self,
+        request: reqwest::Request,
+        info: &'a progenitor_client::OperationInfo,
+        _next: progenitor_client::Next<'a>,
+    ) -> progenitor_client::MiddlewareFuture<'a> {
+        Box::pin(async move {
+            let request = progenitor_client::FakeRequest::new(&request, info);
+            match info.operation_id {
+                "enrol" => {
+                    let headers = request.headers();
+                    let body = request.json()?;
+                    match self.0.enrol(headers, body).await {
+                        Ok(()) => {
+                            progenitor_client::fake_empty_response(reqwest::StatusCode::CREATED)
+                        }
+                        Err(response) => Ok(response),
+                    }
+                }
+                "global_jobs" => {
+                    let headers = request.headers();
+                    match self.0.global_jobs(headers).await {
+                        Ok(value) => progenitor_client::fake_json_response(
+                            reqwest::StatusCode::CREATED,
+                            &value,
+                        ),
+                        Err(response) => Ok(response),
+                    }
+                }
+                "ping" => {
+                    let headers = request.headers();
+                    match self.0.ping(headers).await {
+                        Ok(value) => progenitor_client::fake_json_response(
+                            reqwest::StatusCode::CREATED,
+                            &value,
+                        ),
+                        Err(response) => Ok(response),
+                    }
+                }
+                "report_finish" => {
+                    let headers = request.headers();
+                    let body = request.json()?;
+                    match self.0.report_finish(headers, body).await {
+                        Ok(value) => progenitor_client::fake_json_response(
+                            reqwest::StatusCode::CREATED,
+                            &value,
+                        ),
+                        Err(response) => Ok(response),
+                    }
+                }
+                "report_output" => {
+                    let headers = request.headers();
+                    let body = request.json()?;
+                    match self.0.report_output(headers, body).await {
+                        Ok(value) => progenitor_client::fake_json_response(
+                            reqwest::StatusCode::CREATED,
+                            &value,
+                        ),
+                        Err(response) => Ok(response),
+                    }
+                }
+                "report_start" => {
+                    let headers = request.headers();
+                    let body = request.json()?;
+                    match self.0.report_start(headers, body).await {
+                        Ok(value) => progenitor_client::fake_json_response(
+                            reqwest::StatusCode::CREATED,
+                            &value,
+                        ),
+                        Err(response) => Ok(response),
+                    }
+                }
+                _ => Err(progenitor_client::Error::Custom(format!(
+                    "unknown operation {}",
+                    info.operation_id,
+                ))),
+            }
+        })
+    }
+}
@@ -4,6 +4,118 @@ pub mod operations {
     #![doc = r" its inner type with a call to `into_inner()`. This can"]
     #![doc = r" be used to explicitly deviate from permitted values."]
     use crate::keeper_builder::*;
+    mod body_predicates {
+        type Predicate = Box<dyn Fn(&[u8]) -> bool + Send + Sync>;
+        static PREDICATES: ::std::sync::Mutex<Vec<((::std::any::TypeId, usize), Predicate)>> =
+            ::std::sync::Mutex::new(Vec::new());
+        fn matcher_at<const N: usize>(req: &::httpmock::prelude::HttpMockRequest) -> bool {
+            let predicates = PREDICATES
+                .lock()
+                .unwrap_or_else(::std::sync::PoisonError::into_inner);
+            req.body
+                .as_deref()
+                .is_some_and(|body| (predicates[N].1)(body))
+        }
+
+        const MATCHERS: [fn(&::httpmock::prelude::HttpMockRequest) -> bool; 64] = [
+            matcher_at::<0>,
+            matcher_at::<1>,
+            matcher_at::<2>,
+            matcher_at::<3>,
+            matcher_at::<4>,
+            matcher_at::<5>,
+            matcher_at::<6>,
+            matcher_at::<7>,
+            matcher_at::<8>,
+            matcher_at::<9>,
+            matcher_at::<10>,
+            matcher_at::<11>,
+            matcher_at::<12>,
+            matcher_at::<13>,
+            matcher_at::<14>,
+            matcher_at::<15>,
+            matcher_at::<16>,
+            matcher_at::<17>,
+            matcher_at::<18>,
+            matcher_at::<19>,
+            matcher_at::<20>,
+            matcher_at::<21>,
+            matcher_at::<22>,
+            matcher_at::<23>,
+            matcher_at::<24>,
+            matcher_at::<25>,
+            matcher_at::<26>,
+            matcher_at::<27>,
+            matcher_at::<28>,
+            matcher_at::<29>,
+            matcher_at::<30>,
+            matcher_at::<31>,
+            matcher_at::<32>,
+            matcher_at::<33>,
+            matcher_at::<34>,
+            matcher_at::<35>,
+            matcher_at::<36>,
+            matcher_at::<37>,
+            matcher_at::<38>,
+            matcher_at::<39>,
+            matcher_at::<40>,
+            matcher_at::<41>,
+            matcher_at::<42>,
+            matcher_at::<43>,
+            matcher_at::<44>,
+            matcher_at::<45>,
+            matcher_at::<46>,
+            matcher_at::<47>,
+            matcher_at::<48>,
+            matcher_at::<49>,
+            matcher_at::<50>,
+            matcher_at::<51>,
+            matcher_at::<52>,
+            matcher_at::<53>,
+            matcher_at::<54>,
+            matcher_at::<55>,
+            matcher_at::<56>,
+            matcher_at::<57>,
+            matcher_at::<58>,
+            matcher_at::<59>,
+            matcher_at::<60>,
+            matcher_at::<61>,
+            matcher_at::<62>,
+            matcher_at::<63>,
+        ];
+        #[doc = r" The matcher for bodies that decode to a value for which"]
+        #[doc = r" `predicate` returns `true`."]
+        pub(super) fn matcher<T>(
+            predicate: fn(&T) -> bool,
+        ) -> fn(&::httpmock::prelude::HttpMockRequest) -> bool
+        where
+            T: ::serde::de::DeserializeOwned + 'static,
+        {
+            let key = (::std::any::TypeId::of::<T>(), predicate as usize);
+            let mut predicates = PREDICATES
+                .lock()
+                .unwrap_or_else(::std::sync::PoisonError::into_inner);
+            let index = match predicates.iter().position(|(k, _)| *k == key) {
+                Some(index) => index,
+                None => {
+                    assert!(
+                        predicates.len() < MATCHERS.len(),
+                        "at most {} distinct body predicates may be used",
+                        MATCHERS.len(),
+                    );
+                    predicates.push((
+                        key,
+                        Box::new(move |body| {
+                            ::serde_json::from_slice(body).is_ok_and(|value| predicate(&value))
+                        }),
+                    ));
+                    predicates.len() - 1
+                }
+            };
+            MATCHERS[index]
+        }
+    }
+
     pub struct EnrolWhen(::httpmock::When);
     impl EnrolWhen {
         pub fn new(inner: ::httpmock::When) -> Self {
@@ -22,9 +134,25 @@ pub mod operations {
             Self(self.0.header("Authorization", value.to_string()))
         }
 
+        pub fn authorization_exists(self) -> Self {
+            Self(self.0.header_exists("Authorization"))
+        }
+
         pub fn body(self, value: &types::EnrolBody) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::EnrolBody) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct EnrolThen(::httpmock::Then);
@@ -37,6 +165,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self) -> Self {
             Self(self.0.status(201u16))
         }
@@ -59,6 +191,10 @@ pub mod operations {
         pub fn authorization(self, value: &str) -> Self {
             Self(self.0.header("Authorization", value.to_string()))
         }
+
+        pub fn authorization_exists(self) -> Self {
+            Self(self.0.header_exists("Authorization"))
+        }
     }
 
     pub struct GlobalJobsThen(::httpmock::Then);
@@ -71,6 +207,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::GlobalJobsResult) -> Self {
             Self(
                 self.0
@@ -98,6 +238,10 @@ pub mod operations {
         pub fn authorization(self, value: &str) -> Self {
             Self(self.0.header("Authorization", value.to_string()))
         }
+
+        pub fn authorization_exists(self) -> Self {
+            Self(self.0.header_exists("Authorization"))
+        }
     }
 
     pub struct PingThen(::httpmock::Then);
@@ -110,6 +254,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::PingResult) -> Self {
             Self(
                 self.0
@@ -138,9 +286,25 @@ pub mod operations {
             Self(self.0.header("Authorization", value.to_string()))
         }
 
+        pub fn authorization_exists(self) -> Self {
+            Self(self.0.header_exists("Authorization"))
+        }
+
         pub fn body(self, value: &types::ReportFinishBody) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::ReportFinishBody) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct ReportFinishThen(::httpmock::Then);
@@ -153,6 +317,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::ReportResult) -> Self {
             Self(
                 self.0
@@ -181,9 +349,25 @@ pub mod operations {
             Self(self.0.header("Authorization", value.to_string()))
         }
 
+        pub fn authorization_exists(self) -> Self {
+            Self(self.0.header_exists("Authorization"))
+        }
+
         pub fn body(self, value: &types::ReportOutputBody) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::ReportOutputBody) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct ReportOutputThen(::httpmock::Then);
@@ -196,6 +380,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::ReportResult) -> Self {
             Self(
                 self.0
@@ -224,9 +412,25 @@ pub mod operations {
             Self(self.0.header("Authorization", value.to_string()))
         }
 
+        pub fn authorization_exists(self) -> Self {
+            Self(self.0.header_exists("Authorization"))
+        }
+
         pub fn body(self, value: &types::ReportStartBody) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::ReportStartBody) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct ReportStartThen(::httpmock::Then);
@@ -239,6 +443,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::ReportResult) -> Self {
             Self(
                 self.0
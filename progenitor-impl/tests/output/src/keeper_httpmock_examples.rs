@@ -0,0 +1,623 @@
+pub mod operations {
+    #![doc = r" [`When`](::httpmock::When) and [`Then`](::httpmock::Then)"]
+    #![doc = r" wrappers for each operation. Each can be converted to"]
+    #![doc = r" its inner type with a call to `into_inner()`. This can"]
+    #![doc = r" be used to explicitly deviate from permitted values."]
+    use crate::keeper_builder::*;
+    mod body_predicates {
+        type Predicate = Box<dyn Fn(&[u8]) -> bool + Send + Sync>;
+        static PREDICATES: ::std::sync::Mutex<Vec<((::std::any::TypeId, usize), Predicate)>> =
+            ::std::sync::Mutex::new(Vec::new());
+        fn matcher_at<const N: usize>(req: &::httpmock::prelude::HttpMockRequest) -> bool {
+            let predicates = PREDICATES
+                .lock()
+                .unwrap_or_else(::std::sync::PoisonError::into_inner);
+            req.body
+                .as_deref()
+                .is_some_and(|body| (predicates[N].1)(body))
+        }
+
+        const MATCHERS: [fn(&::httpmock::prelude::HttpMockRequest) -> bool; 64] = [
+            matcher_at::<0>,
+            matcher_at::<1>,
+            matcher_at::<2>,
+            matcher_at::<3>,
+            matcher_at::<4>,
+            matcher_at::<5>,
+            matcher_at::<6>,
+            matcher_at::<7>,
+            matcher_at::<8>,
+            matcher_at::<9>,
+            matcher_at::<10>,
+            matcher_at::<11>,
+            matcher_at::<12>,
+            matcher_at::<13>,
+            matcher_at::<14>,
+            matcher_at::<15>,
+            matcher_at::<16>,
+            matcher_at::<17>,
+            matcher_at::<18>,
+            matcher_at::<19>,
+            matcher_at::<20>,
+            matcher_at::<21>,
+            matcher_at::<22>,
+            matcher_at::<23>,
+            matcher_at::<24>,
+            matcher_at::<25>,
+            matcher_at::<26>,
+            matcher_at::<27>,
+            matcher_at::<28>,
+            matcher_at::<29>,
+            matcher_at::<30>,
+            matcher_at::<31>,
+            matcher_at::<32>,
+            matcher_at::<33>,
+            matcher_at::<34>,
+            matcher_at::<35>,
+            matcher_at::<36>,
+            matcher_at::<37>,
+            matcher_at::<38>,
+            matcher_at::<39>,
+            matcher_at::<40>,
+            matcher_at::<41>,
+            matcher_at::<42>,
+            matcher_at::<43>,
+            matcher_at::<44>,
+            matcher_at::<45>,
+            matcher_at::<46>,
+            matcher_at::<47>,
+            matcher_at::<48>,
+            matcher_at::<49>,
+            matcher_at::<50>,
+            matcher_at::<51>,
+            matcher_at::<52>,
+            matcher_at::<53>,
+            matcher_at::<54>,
+            matcher_at::<55>,
+            matcher_at::<56>,
+            matcher_at::<57>,
+            matcher_at::<58>,
+            matcher_at::<59>,
+            matcher_at::<60>,
+            matcher_at::<61>,
+            matcher_at::<62>,
+            matcher_at::<63>,
+        ];
+        #[doc = r" The matcher for bodies that decode to a value for which"]
+        #[doc = r" `predicate` returns `true`."]
+        pub(super) fn matcher<T>(
+            predicate: fn(&T) -> bool,
+        ) -> fn(&::httpmock::prelude::HttpMockRequest) -> bool
+        where
+            T: ::serde::de::DeserializeOwned + 'static,
+        {
+            let key = (::std::any::TypeId::of::<T>(), predicate as usize);
+            let mut predicates = PREDICATES
+                .lock()
+                .unwrap_or_else(::std::sync::PoisonError::into_inner);
+            let index = match predicates.iter().position(|(k, _)| *k == key) {
+                Some(index) => index,
+                None => {
+                    assert!(
+                        predicates.len() < MATCHERS.len(),
+                        "at most {} distinct body predicates may be used",
+                        MATCHERS.len(),
+                    );
+                    predicates.push((
+                        key,
+                        Box::new(move |body| {
+                            ::serde_json::from_slice(body).is_ok_and(|value| predicate(&value))
+                        }),
+                    ));
+                    predicates.len() - 1
+                }
+            };
+            MATCHERS[index]
+        }
+    }
+
+    pub struct EnrolWhen(::httpmock::When);
+    impl EnrolWhen {
+        pub fn new(inner: ::httpmock::When) -> Self {
+            Self(
+                inner
+                    .method(::httpmock::Method::POST)
+                    .path_matches(regex::Regex::new("^/enrol$").unwrap()),
+            )
+        }
+
+        pub fn into_inner(self) -> ::httpmock::When {
+            self.0
+        }
+
+        pub fn authorization(self, value: &str) -> Self {
+            Self(self.0.header("Authorization", value.to_string()))
+        }
+
+        pub fn authorization_exists(self) -> Self {
+            Self(self.0.header_exists("Authorization"))
+        }
+
+        pub fn body(self, value: &types::EnrolBody) -> Self {
+            Self(self.0.json_body_obj(value))
+        }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::EnrolBody) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
+    }
+
+    pub struct EnrolThen(::httpmock::Then);
+    impl EnrolThen {
+        pub fn new(inner: ::httpmock::Then) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::httpmock::Then {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
+        pub fn created(self) -> Self {
+            Self(self.0.status(201u16))
+        }
+    }
+
+    pub struct GlobalJobsWhen(::httpmock::When);
+    impl GlobalJobsWhen {
+        pub fn new(inner: ::httpmock::When) -> Self {
+            Self(
+                inner
+                    .method(::httpmock::Method::GET)
+                    .path_matches(regex::Regex::new("^/global/jobs$").unwrap()),
+            )
+        }
+
+        pub fn into_inner(self) -> ::httpmock::When {
+            self.0
+        }
+
+        pub fn authorization(self, value: &str) -> Self {
+            Self(self.0.header("Authorization", value.to_string()))
+        }
+
+        pub fn authorization_exists(self) -> Self {
+            Self(self.0.header_exists("Authorization"))
+        }
+    }
+
+    pub struct GlobalJobsThen(::httpmock::Then);
+    impl GlobalJobsThen {
+        pub fn new(inner: ::httpmock::Then) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::httpmock::Then {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
+        pub fn created(self, value: &types::GlobalJobsResult) -> Self {
+            Self(
+                self.0
+                    .status(201u16)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+    }
+
+    pub struct PingWhen(::httpmock::When);
+    impl PingWhen {
+        pub fn new(inner: ::httpmock::When) -> Self {
+            Self(
+                inner
+                    .method(::httpmock::Method::GET)
+                    .path_matches(regex::Regex::new("^/ping$").unwrap()),
+            )
+        }
+
+        pub fn into_inner(self) -> ::httpmock::When {
+            self.0
+        }
+
+        pub fn authorization(self, value: &str) -> Self {
+            Self(self.0.header("Authorization", value.to_string()))
+        }
+
+        pub fn authorization_exists(self) -> Self {
+            Self(self.0.header_exists("Authorization"))
+        }
+    }
+
+    pub struct PingThen(::httpmock::Then);
+    impl PingThen {
+        pub fn new(inner: ::httpmock::Then) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::httpmock::Then {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
+        pub fn created(self, value: &types::PingResult) -> Self {
+            Self(
+                self.0
+                    .status(201u16)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+    }
+
+    pub struct ReportFinishWhen(::httpmock::When);
+    impl ReportFinishWhen {
+        pub fn new(inner: ::httpmock::When) -> Self {
+            Self(
+                inner
+                    .method(::httpmock::Method::POST)
+                    .path_matches(regex::Regex::new("^/report/finish$").unwrap()),
+            )
+        }
+
+        pub fn into_inner(self) -> ::httpmock::When {
+            self.0
+        }
+
+        pub fn authorization(self, value: &str) -> Self {
+            Self(self.0.header("Authorization", value.to_string()))
+        }
+
+        pub fn authorization_exists(self) -> Self {
+            Self(self.0.header_exists("Authorization"))
+        }
+
+        pub fn body(self, value: &types::ReportFinishBody) -> Self {
+            Self(self.0.json_body_obj(value))
+        }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::ReportFinishBody) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
+    }
+
+    pub struct ReportFinishThen(::httpmock::Then);
+    impl ReportFinishThen {
+        pub fn new(inner: ::httpmock::Then) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::httpmock::Then {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
+        pub fn created(self, value: &types::ReportResult) -> Self {
+            Self(
+                self.0
+                    .status(201u16)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+    }
+
+    pub struct ReportOutputWhen(::httpmock::When);
+    impl ReportOutputWhen {
+        pub fn new(inner: ::httpmock::When) -> Self {
+            Self(
+                inner
+                    .method(::httpmock::Method::POST)
+                    .path_matches(regex::Regex::new("^/report/output$").unwrap()),
+            )
+        }
+
+        pub fn into_inner(self) -> ::httpmock::When {
+            self.0
+        }
+
+        pub fn authorization(self, value: &str) -> Self {
+            Self(self.0.header("Authorization", value.to_string()))
+        }
+
+        pub fn authorization_exists(self) -> Self {
+            Self(self.0.header_exists("Authorization"))
+        }
+
+        pub fn body(self, value: &types::ReportOutputBody) -> Self {
+            Self(self.0.json_body_obj(value))
+        }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::ReportOutputBody) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
+    }
+
+    pub struct ReportOutputThen(::httpmock::Then);
+    impl ReportOutputThen {
+        pub fn new(inner: ::httpmock::Then) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::httpmock::Then {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
+        pub fn created(self, value: &types::ReportResult) -> Self {
+            Self(
+                self.0
+                    .status(201u16)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+    }
+
+    pub struct ReportStartWhen(::httpmock::When);
+    impl ReportStartWhen {
+        pub fn new(inner: ::httpmock::When) -> Self {
+            Self(
+                inner
+                    .method(::httpmock::Method::POST)
+                    .path_matches(regex::Regex::new("^/report/start$").unwrap()),
+            )
+        }
+
+        pub fn into_inner(self) -> ::httpmock::When {
+            self.0
+        }
+
+        pub fn authorization(self, value: &str) -> Self {
+            Self(self.0.header("Authorization", value.to_string()))
+        }
+
+        pub fn authorization_exists(self) -> Self {
+            Self(self.0.header_exists("Authorization"))
+        }
+
+        pub fn body(self, value: &types::ReportStartBody) -> Self {
+            Self(self.0.json_body_obj(value))
+        }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::ReportStartBody) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
+    }
+
+    pub struct ReportStartThen(::httpmock::Then);
+    impl ReportStartThen {
+        pub fn new(inner: ::httpmock::Then) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::httpmock::Then {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
+        pub fn created(self, value: &types::ReportResult) -> Self {
+            Self(
+                self.0
+                    .status(201u16)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+    }
+}
+
+#[doc = r" An extension trait for [`MockServer`](::httpmock::MockServer) that"]
+#[doc = r" adds a method for each operation. These are the equivalent of"]
+#[doc = r" type-checked [`mock()`](::httpmock::MockServer::mock) calls."]
+pub trait MockServerExt {
+    fn enrol<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::EnrolWhen, operations::EnrolThen);
+    fn global_jobs<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::GlobalJobsWhen, operations::GlobalJobsThen);
+    fn ping<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::PingWhen, operations::PingThen);
+    fn report_finish<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::ReportFinishWhen, operations::ReportFinishThen);
+    fn report_output<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::ReportOutputWhen, operations::ReportOutputThen);
+    fn report_start<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::ReportStartWhen, operations::ReportStartThen);
+    #[doc = r" Mock every operation, answering with the example of its"]
+    #[doc = r" first successful response from the API description, or a"]
+    #[doc = r" value built from the response's schema if it has none. The"]
+    #[doc = r" mocks are returned by operation ID; operations for which no"]
+    #[doc = r" value satisfying the schema was found aren't mocked."]
+    #[doc = r""]
+    #[doc = r" httpmock answers with the mock created first, so mock an"]
+    #[doc = r" operation before calling this to override it, or `delete()`"]
+    #[doc = r" its mock afterwards and mock it again."]
+    fn mock_all_from_examples(&self) -> std::collections::BTreeMap<&'static str, ::httpmock::Mock>;
+}
+
+impl MockServerExt for ::httpmock::MockServer {
+    fn enrol<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::EnrolWhen, operations::EnrolThen),
+    {
+        self.mock(|when, then| {
+            config_fn(
+                operations::EnrolWhen::new(when),
+                operations::EnrolThen::new(then),
+            )
+        })
+    }
+
+    fn global_jobs<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::GlobalJobsWhen, operations::GlobalJobsThen),
+    {
+        self.mock(|when, then| {
+            config_fn(
+                operations::GlobalJobsWhen::new(when),
+                operations::GlobalJobsThen::new(then),
+            )
+        })
+    }
+
+    fn ping<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::PingWhen, operations::PingThen),
+    {
+        self.mock(|when, then| {
+            config_fn(
+                operations::PingWhen::new(when),
+                operations::PingThen::new(then),
+            )
+        })
+    }
+
+    fn report_finish<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::ReportFinishWhen, operations::ReportFinishThen),
+    {
+        self.mock(|when, then| {
+            config_fn(
+                operations::ReportFinishWhen::new(when),
+                operations::ReportFinishThen::new(then),
+            )
+        })
+    }
+
+    fn report_output<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::ReportOutputWhen, operations::ReportOutputThen),
+    {
+        self.mock(|when, then| {
+            config_fn(
+                operations::ReportOutputWhen::new(when),
+                operations::ReportOutputThen::new(then),
+            )
+        })
+    }
+
+    fn report_start<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::ReportStartWhen, operations::ReportStartThen),
+    {
+        self.mock(|when, then| {
+            config_fn(
+                operations::ReportStartWhen::new(when),
+                operations::ReportStartThen::new(then),
+            )
+        })
+    }
+
+    fn mock_all_from_examples(&self) -> std::collections::BTreeMap<&'static str, ::httpmock::Mock> {
+        let mut mocks = std::collections::BTreeMap::new();
+        mocks.insert(
+            "enrol",
+            self.enrol(|_, then| {
+                then.into_inner().status(201u16);
+            }),
+        );
+        mocks.insert(
+            "global_jobs",
+            self.global_jobs(|_, then| {
+                then.into_inner()
+                    .status(201u16)
+                    .header("content-type", "application/json")
+                    .body(r#"{"summary":[]}"#);
+            }),
+        );
+        mocks.insert(
+            "ping",
+            self.ping(|_, then| {
+                then.into_inner()
+                    .status(201u16)
+                    .header("content-type", "application/json")
+                    .body(r#"{"host":"string","ok":false}"#);
+            }),
+        );
+        mocks.insert(
+            "report_finish",
+            self.report_finish(|_, then| {
+                then.into_inner()
+                    .status(201u16)
+                    .header("content-type", "application/json")
+                    .body(r#"{"existed_already":false}"#);
+            }),
+        );
+        mocks.insert(
+            "report_output",
+            self.report_output(|_, then| {
+                then.into_inner()
+                    .status(201u16)
+                    .header("content-type", "application/json")
+                    .body(r#"{"existed_already":false}"#);
+            }),
+        );
+        mocks.insert(
+            "report_start",
+            self.report_start(|_, then| {
+                then.into_inner()
+                    .status(201u16)
+                    .header("content-type", "application/json")
+                    .body(r#"{"existed_already":false}"#);
+            }),
+        );
+        mocks
+    }
+}
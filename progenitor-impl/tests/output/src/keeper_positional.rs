@@ -2,7 +2,7 @@
 #[allow(unused_imports)]
 use progenitor_client::{encode_path, ClientHooks, OperationInfo, RequestBuilderExt};
 #[allow(unused_imports)]
-pub use progenitor_client::{ByteStream, ClientInfo, Error, ResponseValue};
+pub use progenitor_client::{ByteStream, ClientInfo, Error, RequestOptions, ResponseValue};
 /// Types used as operation parameters and responses.
 #[allow(clippy::all)]
 pub mod types {
@@ -453,6 +453,7 @@ pub mod types {
 pub struct Client {
     pub(crate) baseurl: String,
     pub(crate) client: reqwest::Client,
+    pub(crate) middleware: progenitor_client::MiddlewareStack,
 }
 
 impl Client {
@@ -484,8 +485,75 @@ impl Client {
         Self {
             baseurl: baseurl.to_string(),
             client,
+            middleware: Default::default(),
         }
     }
+
+    /// Append `middleware` to the chain that surrounds the
+    /// execution of each request. Middleware runs in the order in
+    /// which it is attached.
+    pub fn with_middleware<M: progenitor_client::Middleware>(mut self, middleware: M) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Descriptions of the API's operations.
+    pub const OPERATIONS: &'static [progenitor_client::OperationMetadata] = &[
+        progenitor_client::OperationMetadata {
+            operation_id: "enrol",
+            method: reqwest::Method::POST,
+            path: "/enrol",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "global_jobs",
+            method: reqwest::Method::GET,
+            path: "/global/jobs",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "ping",
+            method: reqwest::Method::GET,
+            path: "/ping",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "report_finish",
+            method: reqwest::Method::POST,
+            path: "/report/finish",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "report_output",
+            method: reqwest::Method::POST,
+            path: "/report/output",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "report_start",
+            method: reqwest::Method::POST,
+            path: "/report/start",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+    ];
 }
 
 impl ClientInfo<()> for Client {
@@ -520,6 +588,53 @@ impl Client {
         authorization: &'a str,
         body: &'a types::EnrolBody,
     ) -> Result<ResponseValue<()>, Error<()>> {
+        self.enrol_with_options(
+            authorization,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::enrol`] with additional headers, query
+    /// parameters, or context from `options`.
+    pub async fn enrol_with_options<'a>(
+        &'a self,
+        authorization: &'a str,
+        body: &'a types::EnrolBody,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<()>> {
+        let mut request = self
+            .enrol_request(authorization, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[0]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => Ok(ResponseValue::empty(response)),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::enrol`] without sending it.
+    pub fn enrol_request<'a>(
+        &'a self,
+        authorization: &'a str,
+        body: &'a types::EnrolBody,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/enrol", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
         header_map.append(
@@ -534,17 +649,7 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "enrol",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            201u16 => Ok(ResponseValue::empty(response)),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Sends a `GET` request to `/global/jobs`
@@ -555,6 +660,47 @@ impl Client {
         &'a self,
         authorization: &'a str,
     ) -> Result<ResponseValue<types::GlobalJobsResult>, Error<()>> {
+        self.global_jobs_with_options(authorization, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::global_jobs`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn global_jobs_with_options<'a>(
+        &'a self,
+        authorization: &'a str,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::GlobalJobsResult>, Error<()>> {
+        let mut request = self
+            .global_jobs_request(authorization)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[1]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::global_jobs`] without sending it.
+    pub fn global_jobs_request<'a>(
+        &'a self,
+        authorization: &'a str,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/global/jobs", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
         header_map.append(
@@ -572,17 +718,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "global_jobs",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Sends a `GET` request to `/ping`
@@ -593,6 +729,47 @@ impl Client {
         &'a self,
         authorization: &'a str,
     ) -> Result<ResponseValue<types::PingResult>, Error<()>> {
+        self.ping_with_options(authorization, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::ping`] with additional headers, query
+    /// parameters, or context from `options`.
+    pub async fn ping_with_options<'a>(
+        &'a self,
+        authorization: &'a str,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::PingResult>, Error<()>> {
+        let mut request = self
+            .ping_request(authorization)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[2]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::ping`] without sending it.
+    pub fn ping_request<'a>(
+        &'a self,
+        authorization: &'a str,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/ping", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
         header_map.append(
@@ -610,17 +787,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "ping",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Sends a `POST` request to `/report/finish`
@@ -633,6 +800,53 @@ impl Client {
         authorization: &'a str,
         body: &'a types::ReportFinishBody,
     ) -> Result<ResponseValue<types::ReportResult>, Error<()>> {
+        self.report_finish_with_options(
+            authorization,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::report_finish`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn report_finish_with_options<'a>(
+        &'a self,
+        authorization: &'a str,
+        body: &'a types::ReportFinishBody,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::ReportResult>, Error<()>> {
+        let mut request = self
+            .report_finish_request(authorization, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[3]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::report_finish`] without sending it.
+    pub fn report_finish_request<'a>(
+        &'a self,
+        authorization: &'a str,
+        body: &'a types::ReportFinishBody,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/report/finish", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
         header_map.append(
@@ -651,17 +865,7 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "report_finish",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Sends a `POST` request to `/report/output`
@@ -674,6 +878,53 @@ impl Client {
         authorization: &'a str,
         body: &'a types::ReportOutputBody,
     ) -> Result<ResponseValue<types::ReportResult>, Error<()>> {
+        self.report_output_with_options(
+            authorization,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::report_output`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn report_output_with_options<'a>(
+        &'a self,
+        authorization: &'a str,
+        body: &'a types::ReportOutputBody,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::ReportResult>, Error<()>> {
+        let mut request = self
+            .report_output_request(authorization, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[4]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::report_output`] without sending it.
+    pub fn report_output_request<'a>(
+        &'a self,
+        authorization: &'a str,
+        body: &'a types::ReportOutputBody,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/report/output", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
         header_map.append(
@@ -692,17 +943,7 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "report_output",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Sends a `POST` request to `/report/start`
@@ -715,6 +956,53 @@ impl Client {
         authorization: &'a str,
         body: &'a types::ReportStartBody,
     ) -> Result<ResponseValue<types::ReportResult>, Error<()>> {
+        self.report_start_with_options(
+            authorization,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::report_start`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn report_start_with_options<'a>(
+        &'a self,
+        authorization: &'a str,
+        body: &'a types::ReportStartBody,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::ReportResult>, Error<()>> {
+        let mut request = self
+            .report_start_request(authorization, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[5]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::report_start`] without sending it.
+    pub fn report_start_request<'a>(
+        &'a self,
+        authorization: &'a str,
+        body: &'a types::ReportStartBody,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/report/start", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
         header_map.append(
@@ -733,17 +1021,7 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "report_start",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 }
 
@@ -0,0 +1,221 @@
+#[allow(unused_imports)]
+use crate::keeper_builder::types;
+pub mod parameters {
+    //! Path, query, and header parameters of each operation, as
+    //! used by the handlers of [`Api`](super::Api).
+    #[allow(unused_imports)]
+    use crate::keeper_builder::types;
+    ///Header parameters of the `enrol` operation.
+    #[derive(Clone, Debug)]
+    pub struct EnrolHeaders {
+        ///Authorization header (bearer token)
+        pub authorization: ::std::string::String,
+    }
+
+    impl EnrolHeaders {
+        /// Decode the header parameters from the headers of a
+        /// request, responding with a client error if one is missing
+        /// or invalid.
+        pub fn from_headers(headers: &http::HeaderMap) -> Result<Self, dropshot::HttpError> {
+            Ok(Self {
+                authorization: header_value(headers, "Authorization")?.ok_or_else(|| {
+                    dropshot::HttpError::for_bad_request(
+                        None,
+                        "missing header Authorization".to_string(),
+                    )
+                })?,
+            })
+        }
+    }
+
+    ///Header parameters of the `global_jobs` operation.
+    #[derive(Clone, Debug)]
+    pub struct GlobalJobsHeaders {
+        ///Authorization header (bearer token)
+        pub authorization: ::std::string::String,
+    }
+
+    impl GlobalJobsHeaders {
+        /// Decode the header parameters from the headers of a
+        /// request, responding with a client error if one is missing
+        /// or invalid.
+        pub fn from_headers(headers: &http::HeaderMap) -> Result<Self, dropshot::HttpError> {
+            Ok(Self {
+                authorization: header_value(headers, "Authorization")?.ok_or_else(|| {
+                    dropshot::HttpError::for_bad_request(
+                        None,
+                        "missing header Authorization".to_string(),
+                    )
+                })?,
+            })
+        }
+    }
+
+    ///Header parameters of the `ping` operation.
+    #[derive(Clone, Debug)]
+    pub struct PingHeaders {
+        ///Authorization header (bearer token)
+        pub authorization: ::std::string::String,
+    }
+
+    impl PingHeaders {
+        /// Decode the header parameters from the headers of a
+        /// request, responding with a client error if one is missing
+        /// or invalid.
+        pub fn from_headers(headers: &http::HeaderMap) -> Result<Self, dropshot::HttpError> {
+            Ok(Self {
+                authorization: header_value(headers, "Authorization")?.ok_or_else(|| {
+                    dropshot::HttpError::for_bad_request(
+                        None,
+                        "missing header Authorization".to_string(),
+                    )
+                })?,
+            })
+        }
+    }
+
+    ///Header parameters of the `report_finish` operation.
+    #[derive(Clone, Debug)]
+    pub struct ReportFinishHeaders {
+        ///Authorization header (bearer token)
+        pub authorization: ::std::string::String,
+    }
+
+    impl ReportFinishHeaders {
+        /// Decode the header parameters from the headers of a
+        /// request, responding with a client error if one is missing
+        /// or invalid.
+        pub fn from_headers(headers: &http::HeaderMap) -> Result<Self, dropshot::HttpError> {
+            Ok(Self {
+                authorization: header_value(headers, "Authorization")?.ok_or_else(|| {
+                    dropshot::HttpError::for_bad_request(
+                        None,
+                        "missing header Authorization".to_string(),
+                    )
+                })?,
+            })
+        }
+    }
+
+    ///Header parameters of the `report_output` operation.
+    #[derive(Clone, Debug)]
+    pub struct ReportOutputHeaders {
+        ///Authorization header (bearer token)
+        pub authorization: ::std::string::String,
+    }
+
+    impl ReportOutputHeaders {
+        /// Decode the header parameters from the headers of a
+        /// request, responding with a client error if one is missing
+        /// or invalid.
+        pub fn from_headers(headers: &http::HeaderMap) -> Result<Self, dropshot::HttpError> {
+            Ok(Self {
+                authorization: header_value(headers, "Authorization")?.ok_or_else(|| {
+                    dropshot::HttpError::for_bad_request(
+                        None,
+                        "missing header Authorization".to_string(),
+                    )
+                })?,
+            })
+        }
+    }
+
+    ///Header parameters of the `report_start` operation.
+    #[derive(Clone, Debug)]
+    pub struct ReportStartHeaders {
+        ///Authorization header (bearer token)
+        pub authorization: ::std::string::String,
+    }
+
+    impl ReportStartHeaders {
+        /// Decode the header parameters from the headers of a
+        /// request, responding with a client error if one is missing
+        /// or invalid.
+        pub fn from_headers(headers: &http::HeaderMap) -> Result<Self, dropshot::HttpError> {
+            Ok(Self {
+                authorization: header_value(headers, "Authorization")?.ok_or_else(|| {
+                    dropshot::HttpError::for_bad_request(
+                        None,
+                        "missing header Authorization".to_string(),
+                    )
+                })?,
+            })
+        }
+    }
+
+    /// Decode the value of an optional header, rejecting values
+    /// that aren't valid for its type.
+    fn header_value<T>(
+        headers: &http::HeaderMap,
+        name: &str,
+    ) -> Result<Option<T>, dropshot::HttpError>
+    where
+        T: std::str::FromStr,
+        T::Err: std::fmt::Display,
+    {
+        let Some(value) = headers.get(name) else {
+            return Ok(None);
+        };
+        let value = value.to_str().map_err(|e| {
+            dropshot::HttpError::for_bad_request(
+                None,
+                format!("invalid value for header {}: {}", name, e),
+            )
+        })?;
+        value.parse().map(Some).map_err(|e| {
+            dropshot::HttpError::for_bad_request(
+                None,
+                format!("invalid value for header {}: {}", name, e),
+            )
+        })
+    }
+}
+
+/// A Dropshot API with a handler for each operation.
+///
+/// Dropshot has no extractor for headers, so handlers decode the
+/// header parameters of an operation from the request in `rqctx`
+/// with the `from_headers` function of its `parameters` struct.
+/// Paginated operations receive their scan parameters with
+/// `PaginationParams`; the page selector of their `ResultsPage`
+/// is any JSON value. An implementation may be served with the
+/// `ApiDescription` from `api_mod::api_description`.
+#[dropshot::api_description]
+pub trait Api {
+    /// The context shared by all handlers.
+    type Context;
+    ///Handles `POST /enrol`
+    # [endpoint { method = POST , path = "/enrol" , }]
+    async fn enrol(
+        rqctx: dropshot::RequestContext<Self::Context>,
+        body: dropshot::TypedBody<types::EnrolBody>,
+    ) -> Result<http::Response<dropshot::Body>, dropshot::HttpError>;
+    ///Handles `GET /global/jobs`
+    # [endpoint { method = GET , path = "/global/jobs" , }]
+    async fn global_jobs(
+        rqctx: dropshot::RequestContext<Self::Context>,
+    ) -> Result<dropshot::HttpResponseCreated<types::GlobalJobsResult>, dropshot::HttpError>;
+    ///Handles `GET /ping`
+    # [endpoint { method = GET , path = "/ping" , }]
+    async fn ping(
+        rqctx: dropshot::RequestContext<Self::Context>,
+    ) -> Result<dropshot::HttpResponseCreated<types::PingResult>, dropshot::HttpError>;
+    ///Handles `POST /report/finish`
+    # [endpoint { method = POST , path = "/report/finish" , }]
+    async fn report_finish(
+        rqctx: dropshot::RequestContext<Self::Context>,
+        body: dropshot::TypedBody<types::ReportFinishBody>,
+    ) -> Result<dropshot::HttpResponseCreated<types::ReportResult>, dropshot::HttpError>;
+    ///Handles `POST /report/output`
+    # [endpoint { method = POST , path = "/report/output" , }]
+    async fn report_output(
+        rqctx: dropshot::RequestContext<Self::Context>,
+        body: dropshot::TypedBody<types::ReportOutputBody>,
+    ) -> Result<dropshot::HttpResponseCreated<types::ReportResult>, dropshot::HttpError>;
+    ///Handles `POST /report/start`
+    # [endpoint { method = POST , path = "/report/start" , }]
+    async fn report_start(
+        rqctx: dropshot::RequestContext<Self::Context>,
+        body: dropshot::TypedBody<types::ReportStartBody>,
+    ) -> Result<dropshot::HttpResponseCreated<types::ReportResult>, dropshot::HttpError>;
+}
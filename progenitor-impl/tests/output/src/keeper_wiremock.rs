@@ -0,0 +1,430 @@
+pub mod operations {
+    #![doc = r" [`MockBuilder`](::wiremock::MockBuilder) and"]
+    #![doc = r" [`ResponseTemplate`](::wiremock::ResponseTemplate)"]
+    #![doc = r" wrappers for each operation. Each can be converted to"]
+    #![doc = r" its inner type with a call to `into_inner()`. This can"]
+    #![doc = r" be used to explicitly deviate from permitted values."]
+    use crate::keeper_builder::*;
+    pub struct EnrolWhen(::wiremock::MockBuilder);
+    impl EnrolWhen {
+        pub fn new(inner: ::wiremock::MockBuilder) -> Self {
+            Self(
+                inner
+                    .and(::wiremock::matchers::method("POST"))
+                    .and(::wiremock::matchers::path_regex("^/enrol$")),
+            )
+        }
+
+        pub fn into_inner(self) -> ::wiremock::MockBuilder {
+            self.0
+        }
+
+        pub fn respond_with(self, then: EnrolThen) -> ::wiremock::Mock {
+            self.0.respond_with(then.0)
+        }
+
+        pub fn authorization(self, value: &str) -> Self {
+            Self(self.0.and(::wiremock::matchers::header(
+                "Authorization",
+                value.to_string(),
+            )))
+        }
+
+        pub fn authorization_exists(self) -> Self {
+            Self(
+                self.0
+                    .and(::wiremock::matchers::header_exists("Authorization")),
+            )
+        }
+
+        pub fn body(self, value: &types::EnrolBody) -> Self {
+            Self(self.0.and(::wiremock::matchers::body_json(value)))
+        }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.and(::wiremock::matchers::body_partial_json(value)))
+        }
+
+        pub fn body_matches<F>(self, predicate: F) -> Self
+        where
+            F: Fn(&types::EnrolBody) -> bool + Send + Sync + 'static,
+        {
+            Self(self.0.and(move |req: &::wiremock::Request| {
+                req.body_json::<types::EnrolBody>()
+                    .is_ok_and(|body| predicate(&body))
+            }))
+        }
+    }
+
+    pub struct EnrolThen(::wiremock::ResponseTemplate);
+    impl EnrolThen {
+        pub fn new(inner: ::wiremock::ResponseTemplate) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::wiremock::ResponseTemplate {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.set_delay(duration))
+        }
+
+        pub fn created() -> Self {
+            Self(::wiremock::ResponseTemplate::new(201u16))
+        }
+    }
+
+    pub struct GlobalJobsWhen(::wiremock::MockBuilder);
+    impl GlobalJobsWhen {
+        pub fn new(inner: ::wiremock::MockBuilder) -> Self {
+            Self(
+                inner
+                    .and(::wiremock::matchers::method("GET"))
+                    .and(::wiremock::matchers::path_regex("^/global/jobs$")),
+            )
+        }
+
+        pub fn into_inner(self) -> ::wiremock::MockBuilder {
+            self.0
+        }
+
+        pub fn respond_with(self, then: GlobalJobsThen) -> ::wiremock::Mock {
+            self.0.respond_with(then.0)
+        }
+
+        pub fn authorization(self, value: &str) -> Self {
+            Self(self.0.and(::wiremock::matchers::header(
+                "Authorization",
+                value.to_string(),
+            )))
+        }
+
+        pub fn authorization_exists(self) -> Self {
+            Self(
+                self.0
+                    .and(::wiremock::matchers::header_exists("Authorization")),
+            )
+        }
+    }
+
+    pub struct GlobalJobsThen(::wiremock::ResponseTemplate);
+    impl GlobalJobsThen {
+        pub fn new(inner: ::wiremock::ResponseTemplate) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::wiremock::ResponseTemplate {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.set_delay(duration))
+        }
+
+        pub fn created(value: &types::GlobalJobsResult) -> Self {
+            Self(::wiremock::ResponseTemplate::new(201u16).set_body_json(value))
+        }
+    }
+
+    pub struct PingWhen(::wiremock::MockBuilder);
+    impl PingWhen {
+        pub fn new(inner: ::wiremock::MockBuilder) -> Self {
+            Self(
+                inner
+                    .and(::wiremock::matchers::method("GET"))
+                    .and(::wiremock::matchers::path_regex("^/ping$")),
+            )
+        }
+
+        pub fn into_inner(self) -> ::wiremock::MockBuilder {
+            self.0
+        }
+
+        pub fn respond_with(self, then: PingThen) -> ::wiremock::Mock {
+            self.0.respond_with(then.0)
+        }
+
+        pub fn authorization(self, value: &str) -> Self {
+            Self(self.0.and(::wiremock::matchers::header(
+                "Authorization",
+                value.to_string(),
+            )))
+        }
+
+        pub fn authorization_exists(self) -> Self {
+            Self(
+                self.0
+                    .and(::wiremock::matchers::header_exists("Authorization")),
+            )
+        }
+    }
+
+    pub struct PingThen(::wiremock::ResponseTemplate);
+    impl PingThen {
+        pub fn new(inner: ::wiremock::ResponseTemplate) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::wiremock::ResponseTemplate {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.set_delay(duration))
+        }
+
+        pub fn created(value: &types::PingResult) -> Self {
+            Self(::wiremock::ResponseTemplate::new(201u16).set_body_json(value))
+        }
+    }
+
+    pub struct ReportFinishWhen(::wiremock::MockBuilder);
+    impl ReportFinishWhen {
+        pub fn new(inner: ::wiremock::MockBuilder) -> Self {
+            Self(
+                inner
+                    .and(::wiremock::matchers::method("POST"))
+                    .and(::wiremock::matchers::path_regex("^/report/finish$")),
+            )
+        }
+
+        pub fn into_inner(self) -> ::wiremock::MockBuilder {
+            self.0
+        }
+
+        pub fn respond_with(self, then: ReportFinishThen) -> ::wiremock::Mock {
+            self.0.respond_with(then.0)
+        }
+
+        pub fn authorization(self, value: &str) -> Self {
+            Self(self.0.and(::wiremock::matchers::header(
+                "Authorization",
+                value.to_string(),
+            )))
+        }
+
+        pub fn authorization_exists(self) -> Self {
+            Self(
+                self.0
+                    .and(::wiremock::matchers::header_exists("Authorization")),
+            )
+        }
+
+        pub fn body(self, value: &types::ReportFinishBody) -> Self {
+            Self(self.0.and(::wiremock::matchers::body_json(value)))
+        }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.and(::wiremock::matchers::body_partial_json(value)))
+        }
+
+        pub fn body_matches<F>(self, predicate: F) -> Self
+        where
+            F: Fn(&types::ReportFinishBody) -> bool + Send + Sync + 'static,
+        {
+            Self(self.0.and(move |req: &::wiremock::Request| {
+                req.body_json::<types::ReportFinishBody>()
+                    .is_ok_and(|body| predicate(&body))
+            }))
+        }
+    }
+
+    pub struct ReportFinishThen(::wiremock::ResponseTemplate);
+    impl ReportFinishThen {
+        pub fn new(inner: ::wiremock::ResponseTemplate) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::wiremock::ResponseTemplate {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.set_delay(duration))
+        }
+
+        pub fn created(value: &types::ReportResult) -> Self {
+            Self(::wiremock::ResponseTemplate::new(201u16).set_body_json(value))
+        }
+    }
+
+    pub struct ReportOutputWhen(::wiremock::MockBuilder);
+    impl ReportOutputWhen {
+        pub fn new(inner: ::wiremock::MockBuilder) -> Self {
+            Self(
+                inner
+                    .and(::wiremock::matchers::method("POST"))
+                    .and(::wiremock::matchers::path_regex("^/report/output$")),
+            )
+        }
+
+        pub fn into_inner(self) -> ::wiremock::MockBuilder {
+            self.0
+        }
+
+        pub fn respond_with(self, then: ReportOutputThen) -> ::wiremock::Mock {
+            self.0.respond_with(then.0)
+        }
+
+        pub fn authorization(self, value: &str) -> Self {
+            Self(self.0.and(::wiremock::matchers::header(
+                "Authorization",
+                value.to_string(),
+            )))
+        }
+
+        pub fn authorization_exists(self) -> Self {
+            Self(
+                self.0
+                    .and(::wiremock::matchers::header_exists("Authorization")),
+            )
+        }
+
+        pub fn body(self, value: &types::ReportOutputBody) -> Self {
+            Self(self.0.and(::wiremock::matchers::body_json(value)))
+        }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.and(::wiremock::matchers::body_partial_json(value)))
+        }
+
+        pub fn body_matches<F>(self, predicate: F) -> Self
+        where
+            F: Fn(&types::ReportOutputBody) -> bool + Send + Sync + 'static,
+        {
+            Self(self.0.and(move |req: &::wiremock::Request| {
+                req.body_json::<types::ReportOutputBody>()
+                    .is_ok_and(|body| predicate(&body))
+            }))
+        }
+    }
+
+    pub struct ReportOutputThen(::wiremock::ResponseTemplate);
+    impl ReportOutputThen {
+        pub fn new(inner: ::wiremock::ResponseTemplate) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::wiremock::ResponseTemplate {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.set_delay(duration))
+        }
+
+        pub fn created(value: &types::ReportResult) -> Self {
+            Self(::wiremock::ResponseTemplate::new(201u16).set_body_json(value))
+        }
+    }
+
+    pub struct ReportStartWhen(::wiremock::MockBuilder);
+    impl ReportStartWhen {
+        pub fn new(inner: ::wiremock::MockBuilder) -> Self {
+            Self(
+                inner
+                    .and(::wiremock::matchers::method("POST"))
+                    .and(::wiremock::matchers::path_regex("^/report/start$")),
+            )
+        }
+
+        pub fn into_inner(self) -> ::wiremock::MockBuilder {
+            self.0
+        }
+
+        pub fn respond_with(self, then: ReportStartThen) -> ::wiremock::Mock {
+            self.0.respond_with(then.0)
+        }
+
+        pub fn authorization(self, value: &str) -> Self {
+            Self(self.0.and(::wiremock::matchers::header(
+                "Authorization",
+                value.to_string(),
+            )))
+        }
+
+        pub fn authorization_exists(self) -> Self {
+            Self(
+                self.0
+                    .and(::wiremock::matchers::header_exists("Authorization")),
+            )
+        }
+
+        pub fn body(self, value: &types::ReportStartBody) -> Self {
+            Self(self.0.and(::wiremock::matchers::body_json(value)))
+        }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.and(::wiremock::matchers::body_partial_json(value)))
+        }
+
+        pub fn body_matches<F>(self, predicate: F) -> Self
+        where
+            F: Fn(&types::ReportStartBody) -> bool + Send + Sync + 'static,
+        {
+            Self(self.0.and(move |req: &::wiremock::Request| {
+                req.body_json::<types::ReportStartBody>()
+                    .is_ok_and(|body| predicate(&body))
+            }))
+        }
+    }
+
+    pub struct ReportStartThen(::wiremock::ResponseTemplate);
+    impl ReportStartThen {
+        pub fn new(inner: ::wiremock::ResponseTemplate) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::wiremock::ResponseTemplate {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.set_delay(duration))
+        }
+
+        pub fn created(value: &types::ReportResult) -> Self {
+            Self(::wiremock::ResponseTemplate::new(201u16).set_body_json(value))
+        }
+    }
+}
+
+#[doc = r" An extension trait for [`Mock`](::wiremock::Mock) that adds a"]
+#[doc = r" constructor for each operation. These are the equivalent of"]
+#[doc = r" type-checked [`given()`](::wiremock::Mock::given) calls; finish"]
+#[doc = r" each with `respond_with()` and mount the resulting mock."]
+pub trait MockExt {
+    fn enrol() -> operations::EnrolWhen;
+    fn global_jobs() -> operations::GlobalJobsWhen;
+    fn ping() -> operations::PingWhen;
+    fn report_finish() -> operations::ReportFinishWhen;
+    fn report_output() -> operations::ReportOutputWhen;
+    fn report_start() -> operations::ReportStartWhen;
+}
+
+impl MockExt for ::wiremock::Mock {
+    fn enrol() -> operations::EnrolWhen {
+        operations::EnrolWhen::new(::wiremock::Mock::given(::wiremock::matchers::any()))
+    }
+
+    fn global_jobs() -> operations::GlobalJobsWhen {
+        operations::GlobalJobsWhen::new(::wiremock::Mock::given(::wiremock::matchers::any()))
+    }
+
+    fn ping() -> operations::PingWhen {
+        operations::PingWhen::new(::wiremock::Mock::given(::wiremock::matchers::any()))
+    }
+
+    fn report_finish() -> operations::ReportFinishWhen {
+        operations::ReportFinishWhen::new(::wiremock::Mock::given(::wiremock::matchers::any()))
+    }
+
+    fn report_output() -> operations::ReportOutputWhen {
+        operations::ReportOutputWhen::new(::wiremock::Mock::given(::wiremock::matchers::any()))
+    }
+
+    fn report_start() -> operations::ReportStartWhen {
+        operations::ReportStartWhen::new(::wiremock::Mock::given(::wiremock::matchers::any()))
+    }
+}
@@ -3,15 +3,24 @@ pub mod buildomat_builder_tagged;
 pub mod buildomat_cli;
 //pub mod buildomat_httpmock;
 pub mod buildomat_positional;
+pub mod keeper_axum;
 pub mod keeper_builder;
 pub mod keeper_builder_tagged;
 pub mod keeper_cli;
+pub mod keeper_client_api;
+pub mod keeper_fake;
 pub mod keeper_httpmock;
+pub mod keeper_httpmock_examples;
+pub mod keeper_operation_enum;
 pub mod keeper_positional;
+pub mod keeper_proptest;
+pub mod keeper_server;
+pub mod keeper_wiremock;
 pub mod nexus_builder;
 pub mod nexus_builder_tagged;
 pub mod nexus_cli;
 pub mod nexus_httpmock;
+pub mod nexus_httpmock_examples;
 pub mod nexus_positional;
 pub mod param_collision_builder;
 pub mod param_collision_builder_tagged;
@@ -23,12 +32,28 @@ pub mod param_overrides_builder_tagged;
 pub mod param_overrides_cli;
 pub mod param_overrides_httpmock;
 pub mod param_overrides_positional;
+pub mod propolis_server_axum;
 pub mod propolis_server_builder;
 pub mod propolis_server_builder_tagged;
 pub mod propolis_server_cli;
+pub mod propolis_server_client_api;
+pub mod propolis_server_fake;
 //pub mod propolis_server_httpmock;
+//pub mod propolis_server_httpmock_examples;
+pub mod propolis_server_operation_enum;
 pub mod propolis_server_positional;
+pub mod propolis_server_proptest;
+pub mod propolis_server_server;
+pub mod propolis_server_wiremock;
 pub mod test_default_params_builder;
 pub mod test_default_params_positional;
 pub mod test_freeform_response;
+pub mod test_httpmock_examples_httpmock;
+pub mod test_httpmock_examples_positional;
+pub mod test_httpmock_matching_httpmock;
+pub mod test_httpmock_matching_positional;
+pub mod test_httpmock_matching_wiremock;
+pub mod test_proptest_positional;
 pub mod test_renamed_parameters;
+pub mod test_websocket_errors_builder;
+pub mod test_websocket_errors_positional;
@@ -226,6 +226,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(::uuid::Uuid))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a disk by id")
             .long_about("Use `GET /v1/disks/{disk}` instead")
     }
@@ -238,6 +250,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(::uuid::Uuid))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch an image by id")
     }
 
@@ -249,6 +273,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(::uuid::Uuid))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch an instance by id")
     }
 
@@ -260,6 +296,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(::uuid::Uuid))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a network interface by id")
     }
 
@@ -271,6 +319,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(::uuid::Uuid))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch an organization by id")
             .long_about("Use `GET /v1/organizations/{organization}` instead")
     }
@@ -283,6 +343,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(::uuid::Uuid))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a project by id")
             .long_about("Use `GET /v1/projects/{project}` instead")
     }
@@ -295,6 +367,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(::uuid::Uuid))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a snapshot by id")
     }
 
@@ -306,6 +390,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(::uuid::Uuid))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a route by id")
     }
 
@@ -317,6 +413,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(::uuid::Uuid))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Get a router by id")
     }
 
@@ -328,6 +436,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(::uuid::Uuid))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a subnet by id")
     }
 
@@ -339,6 +459,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(::uuid::Uuid))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a VPC")
     }
 
@@ -364,6 +496,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Start an OAuth 2.0 Device Authorization Grant")
             .long_about(
                 "This endpoint is designed to be accessed from an *unauthenticated* API client. \
@@ -394,6 +538,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Confirm an OAuth 2.0 Device Authorization Grant")
             .long_about(
                 "This endpoint is designed to be accessed by the user agent (browser), not the \
@@ -436,6 +592,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Request a device access token")
             .long_about(
                 "This endpoint should be polled by the client until the user code is verified and \
@@ -463,6 +631,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List groups")
     }
 
@@ -488,6 +668,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_login_local() -> ::clap::Command {
@@ -524,6 +716,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Authenticate a user (i.e., log in) via username and password")
     }
 
@@ -541,6 +745,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Prompt user login")
             .long_about(
                 "Either display a page asking a user for their credentials, or redirect them to \
@@ -562,11 +778,35 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Authenticate a user (i.e., log in) via SAML")
     }
 
     pub fn cli_logout() -> ::clap::Command {
         ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
     }
 
     pub fn cli_organization_list() -> ::clap::Command {
@@ -591,6 +831,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List organizations")
             .long_about("Use `GET /v1/organizations` instead")
     }
@@ -623,6 +875,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Create an organization")
             .long_about("Use `POST /v1/organizations` instead")
     }
@@ -636,6 +900,18 @@ impl<T: CliConfig> Cli<T> {
                     .required(true)
                     .help("The organization's unique name."),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch an organization")
             .long_about("Use `GET /v1/organizations/{organization}` instead")
     }
@@ -675,6 +951,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Update an organization")
             .long_about("Use `PUT /v1/organizations/{organization}` instead")
     }
@@ -688,6 +976,18 @@ impl<T: CliConfig> Cli<T> {
                     .required(true)
                     .help("The organization's unique name."),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Delete an organization")
             .long_about("Use `DELETE /v1/organizations/{organization}` instead")
     }
@@ -701,6 +1001,18 @@ impl<T: CliConfig> Cli<T> {
                     .required(true)
                     .help("The organization's unique name."),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch an organization's IAM policy")
             .long_about("Use `GET /v1/organizations/{organization}/policy` instead")
     }
@@ -728,6 +1040,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Update an organization's IAM policy")
             .long_about("Use `PUT /v1/organizations/{organization}/policy` instead")
     }
@@ -761,6 +1085,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List projects")
             .long_about("Use `GET /v1/projects` instead")
     }
@@ -800,6 +1136,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Create a project")
             .long_about("Use `POST /v1/projects` instead")
     }
@@ -820,6 +1168,18 @@ impl<T: CliConfig> Cli<T> {
                     .required(true)
                     .help("The project's unique name within the organization."),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a project")
             .long_about("Use `GET /v1/projects/{project}` instead")
     }
@@ -866,6 +1226,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Update a project")
             .long_about("Use `PUT /v1/projects/{project}` instead")
     }
@@ -886,6 +1258,18 @@ impl<T: CliConfig> Cli<T> {
                     .required(true)
                     .help("The project's unique name within the organization."),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Delete a project")
             .long_about("Use `DELETE /v1/projects/{project}` instead")
     }
@@ -924,6 +1308,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List disks")
             .long_about("Use `GET /v1/disks` instead")
     }
@@ -977,6 +1373,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Use `POST /v1/disks` instead")
     }
 
@@ -1000,6 +1408,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a disk")
             .long_about("Use `GET /v1/disks/{disk}` instead")
     }
@@ -1024,6 +1444,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Use `DELETE /v1/disks/{disk}` instead")
     }
 
@@ -1088,6 +1520,18 @@ impl<T: CliConfig> Cli<T> {
                     .required(true)
                     .help("An inclusive start time of metrics."),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch disk metrics")
     }
 
@@ -1125,6 +1569,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List images")
             .long_about(
                 "List images in a project. The images are returned sorted by creation date, with \
@@ -1174,6 +1630,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Create an image")
             .long_about("Create a new image in a project.")
     }
@@ -1198,6 +1666,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch an image")
             .long_about("Fetch the details for a specific image in a project.")
     }
@@ -1222,6 +1702,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Delete an image")
             .long_about(
                 "Permanently delete an image from a project. This operation cannot be undone. Any \
@@ -1264,6 +1756,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List instances")
     }
 
@@ -1345,10 +1849,22 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
-            .about("Create an instance")
-            .long_about("Use `POST /v1/instances` instead")
-    }
-
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
+            .about("Create an instance")
+            .long_about("Use `POST /v1/instances` instead")
+    }
+
     pub fn cli_instance_view() -> ::clap::Command {
         ::clap::Command::new("")
             .arg(
@@ -1369,6 +1885,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch an instance")
             .long_about("Use `GET /v1/instances/{instance}` instead")
     }
@@ -1393,6 +1921,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Delete an instance")
     }
 
@@ -1434,6 +1974,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List an instance's disks")
             .long_about("Use `GET /v1/instances/{instance}/disks` instead")
     }
@@ -1478,6 +2030,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Attach a disk to an instance")
             .long_about("Use `POST /v1/instances/{instance}/disks/attach` instead")
     }
@@ -1522,6 +2086,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Detach a disk from an instance")
             .long_about("Use `POST /v1/disks/{disk}/detach` instead")
     }
@@ -1546,6 +2122,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List external IP addresses")
     }
 
@@ -1589,6 +2177,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Migrate an instance")
             .long_about("Use `POST /v1/instances/{instance}/migrate` instead")
     }
@@ -1631,6 +2231,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List network interfaces")
     }
 
@@ -1704,6 +2316,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Create a network interface")
     }
 
@@ -1733,6 +2357,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a network interface")
     }
 
@@ -1804,6 +2440,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Update a network interface")
     }
 
@@ -1833,6 +2481,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Delete a network interface")
             .long_about(
                 "Note that the primary interface for an instance cannot be deleted if there are \
@@ -1861,6 +2521,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Reboot an instance")
             .long_about("Use `POST /v1/instances/{instance}/reboot` instead")
     }
@@ -1919,6 +2591,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch an instance's serial console")
             .long_about("Use `GET /v1/instances/{instance}/serial-console` instead")
     }
@@ -1943,6 +2627,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Connect to an instance's serial console")
             .long_about("Use `GET /v1/instances/{instance}/serial-console/stream` instead")
     }
@@ -1967,6 +2663,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Boot an instance")
             .long_about("Use `POST /v1/instances/{instance}/start` instead")
     }
@@ -1991,6 +2699,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Halt an instance")
             .long_about("Use `POST /v1/instances/{instance}/stop` instead")
     }
@@ -2011,6 +2731,18 @@ impl<T: CliConfig> Cli<T> {
                     .required(true)
                     .help("The project's unique name within the organization."),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a project's IAM policy")
             .long_about("Use `GET /v1/projects/{project}/policy` instead")
     }
@@ -2045,6 +2777,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Update a project's IAM policy")
     }
 
@@ -2082,6 +2826,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List snapshots")
     }
 
@@ -2134,6 +2890,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Create a snapshot")
             .long_about("Creates a point-in-time snapshot from a disk.")
     }
@@ -2158,6 +2926,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a snapshot")
     }
 
@@ -2181,6 +2961,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Delete a snapshot")
     }
 
@@ -2218,6 +3010,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List VPCs")
     }
 
@@ -2281,6 +3085,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Create a VPC")
     }
 
@@ -2304,6 +3120,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a VPC")
     }
 
@@ -2359,6 +3187,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Update a VPC")
     }
 
@@ -2382,6 +3222,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Delete a VPC")
     }
 
@@ -2405,6 +3257,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List firewall rules")
     }
 
@@ -2442,6 +3306,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Replace firewall rules")
     }
 
@@ -2483,6 +3359,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List routers")
     }
 
@@ -2532,6 +3420,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Create a router")
     }
 
@@ -2561,6 +3461,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Get a router")
     }
 
@@ -2616,6 +3528,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Update a router")
     }
 
@@ -2645,6 +3569,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Delete a router")
     }
 
@@ -2692,6 +3628,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List routes")
             .long_about("List the routes associated with a router in a particular VPC.")
     }
@@ -2748,6 +3696,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Create a router")
     }
 
@@ -2783,6 +3743,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a route")
     }
 
@@ -2844,6 +3816,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Update a route")
     }
 
@@ -2879,6 +3863,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Delete a route")
     }
 
@@ -2920,6 +3916,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List subnets")
     }
 
@@ -2992,6 +4000,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Create a subnet")
     }
 
@@ -3021,6 +4041,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a subnet")
     }
 
@@ -3076,6 +4108,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Update a subnet")
     }
 
@@ -3105,6 +4149,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Delete a subnet")
     }
 
@@ -3152,11 +4208,36 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List network interfaces")
     }
 
     pub fn cli_policy_view() -> ::clap::Command {
-        ::clap::Command::new("").about("Fetch the current silo's IAM policy")
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
+            .about("Fetch the current silo's IAM policy")
     }
 
     pub fn cli_policy_update() -> ::clap::Command {
@@ -3175,6 +4256,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Update the current silo's IAM policy")
     }
 
@@ -3187,6 +4280,18 @@ impl<T: CliConfig> Cli<T> {
                     .required(false)
                     .help("Maximum number of items returned by a single call"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List built-in roles")
     }
 
@@ -3199,11 +4304,36 @@ impl<T: CliConfig> Cli<T> {
                     .required(true)
                     .help("The built-in role's unique name."),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a built-in role")
     }
 
     pub fn cli_session_me() -> ::clap::Command {
-        ::clap::Command::new("").about("Fetch the user associated with the current session")
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
+            .about("Fetch the user associated with the current session")
     }
 
     pub fn cli_session_me_groups() -> ::clap::Command {
@@ -3226,6 +4356,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch the silo\u{a0}groups the current user belongs to")
     }
 
@@ -3249,6 +4391,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List SSH public keys")
             .long_about("Lists SSH public keys for the currently authenticated user.")
     }
@@ -3288,6 +4442,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Create an SSH public key")
             .long_about("Create an SSH public key for the currently authenticated user.")
     }
@@ -3300,6 +4466,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch an SSH public key")
             .long_about("Fetch an SSH public key associated with the currently authenticated user.")
     }
@@ -3312,6 +4490,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Delete an SSH public key")
             .long_about(
                 "Delete an SSH public key associated with the currently authenticated user.",
@@ -3326,6 +4516,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(::uuid::Uuid))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a system-wide image by id")
     }
 
@@ -3337,6 +4539,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(::uuid::Uuid))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch an IP pool by id")
     }
 
@@ -3348,6 +4562,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(::uuid::Uuid))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a silo by id")
     }
 
@@ -3371,6 +4597,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List system-wide certificates")
             .long_about(
                 "Returns a list of all the system-wide certificates. System-wide certificates are \
@@ -3419,6 +4657,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Create a new system-wide x.509 certificate.")
             .long_about(
                 "This certificate is automatically used by the Oxide Control plane to serve \
@@ -3434,6 +4684,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::NameOrId))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a certificate")
             .long_about("Returns the details of a specific certificate")
     }
@@ -3446,6 +4708,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::NameOrId))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Delete a certificate")
             .long_about("Permanently delete a certificate. This operation cannot be undone.")
     }
@@ -3470,6 +4744,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List physical disks")
     }
 
@@ -3493,6 +4779,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List racks")
     }
 
@@ -3505,6 +4803,18 @@ impl<T: CliConfig> Cli<T> {
                     .required(true)
                     .help("The rack's unique ID."),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a rack")
     }
 
@@ -3528,6 +4838,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List sleds")
     }
 
@@ -3540,6 +4862,18 @@ impl<T: CliConfig> Cli<T> {
                     .required(true)
                     .help("The sled's unique ID."),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a sled")
     }
 
@@ -3570,6 +4904,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List physical disks attached to sleds")
     }
 
@@ -3593,6 +4939,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List system-wide images")
             .long_about(
                 "Returns a list of all the system-wide images. System-wide images are returned \
@@ -3628,6 +4986,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Create a system-wide image")
             .long_about(
                 "Create a new system-wide image. This image can then be used by any user in any \
@@ -3643,6 +5013,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a system-wide image")
             .long_about("Returns the details of a specific system-wide image.")
     }
@@ -3655,6 +5037,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Delete a system-wide image")
             .long_about(
                 "Permanently delete a system-wide image. This operation cannot be undone. Any \
@@ -3685,6 +5079,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List IP pools")
     }
 
@@ -3716,6 +5122,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Create an IP pool")
     }
 
@@ -3727,6 +5145,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch an IP pool")
     }
 
@@ -3764,6 +5194,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Update an IP Pool")
     }
 
@@ -3775,6 +5217,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Delete an IP Pool")
     }
 
@@ -3793,6 +5247,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::Name))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List ranges for an IP pool")
             .long_about("Ranges are ordered by their first address.")
     }
@@ -3819,6 +5285,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Add a range to an IP pool")
     }
 
@@ -3844,11 +5322,36 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Remove a range from an IP pool")
     }
 
     pub fn cli_ip_pool_service_view() -> ::clap::Command {
-        ::clap::Command::new("").about("Fetch the IP pool used for Oxide services.")
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
+            .about("Fetch the IP pool used for Oxide services.")
     }
 
     pub fn cli_ip_pool_service_range_list() -> ::clap::Command {
@@ -3860,6 +5363,18 @@ impl<T: CliConfig> Cli<T> {
                     .required(false)
                     .help("Maximum number of items returned by a single call"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List ranges for the IP pool used for Oxide services.")
             .long_about("Ranges are ordered by their first address.")
     }
@@ -3880,6 +5395,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Add a range to an IP pool used for Oxide services.")
     }
 
@@ -3899,6 +5426,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Remove a range from an IP pool used for Oxide services.")
     }
 
@@ -3956,11 +5495,36 @@ impl<T: CliConfig> Cli<T> {
                     .required(false)
                     .help("An inclusive start time of metrics."),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Access metrics data")
     }
 
     pub fn cli_system_policy_view() -> ::clap::Command {
-        ::clap::Command::new("").about("Fetch the top-level IAM policy")
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
+            .about("Fetch the top-level IAM policy")
     }
 
     pub fn cli_system_policy_update() -> ::clap::Command {
@@ -3979,6 +5543,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Update the top-level IAM policy")
     }
 
@@ -4002,6 +5578,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List sagas")
     }
 
@@ -4013,6 +5601,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(::uuid::Uuid))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a saga")
     }
 
@@ -4038,6 +5638,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List silos")
             .long_about("Lists silos that are discoverable based on the current permissions.")
     }
@@ -4102,6 +5714,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Create a silo")
     }
 
@@ -4114,6 +5738,18 @@ impl<T: CliConfig> Cli<T> {
                     .required(true)
                     .help("The silo's unique name."),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a silo")
             .long_about("Fetch a silo by name.")
     }
@@ -4127,6 +5763,18 @@ impl<T: CliConfig> Cli<T> {
                     .required(true)
                     .help("The silo's unique name."),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Delete a silo")
             .long_about("Delete a silo by name.")
     }
@@ -4158,6 +5806,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List a silo's IDPs")
     }
 
@@ -4191,6 +5851,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Create a user")
             .long_about(
                 "Users can only be created in Silos with `provision_type` == `Fixed`. Otherwise, \
@@ -4215,6 +5887,18 @@ impl<T: CliConfig> Cli<T> {
                     .required(true)
                     .help("The user's internal id"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Delete a user")
     }
 
@@ -4248,6 +5932,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Set or invalidate a user's password")
             .long_about(
                 "Passwords can only be updated for users in Silos with identity mode `LocalOnly`.",
@@ -4335,6 +6031,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Create a SAML IDP")
     }
 
@@ -4354,6 +6062,18 @@ impl<T: CliConfig> Cli<T> {
                     .required(true)
                     .help("The silo's unique name."),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a SAML IDP")
     }
 
@@ -4366,6 +6086,18 @@ impl<T: CliConfig> Cli<T> {
                     .required(true)
                     .help("The silo's unique name."),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a silo's IAM policy")
     }
 
@@ -4392,6 +6124,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Update a silo's IAM policy")
     }
 
@@ -4422,6 +6166,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List users in a silo")
     }
 
@@ -4441,6 +6197,18 @@ impl<T: CliConfig> Cli<T> {
                     .required(true)
                     .help("The user's internal id"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a user")
     }
 
@@ -4464,6 +6232,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List built-in users")
     }
 
@@ -4476,6 +6256,18 @@ impl<T: CliConfig> Cli<T> {
                     .required(true)
                     .help("The built-in user's unique name."),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a built-in user")
     }
 
@@ -4488,6 +6280,18 @@ impl<T: CliConfig> Cli<T> {
                     .required(false)
                     .help("Maximum number of items returned by a single call"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List timeseries schema")
     }
 
@@ -4511,6 +6315,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List users")
     }
 
@@ -4548,6 +6364,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List disks")
     }
 
@@ -4598,6 +6426,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Create a disk")
     }
 
@@ -4621,6 +6461,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::NameOrId))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a disk")
     }
 
@@ -4644,6 +6496,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::NameOrId))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Delete a disk")
     }
 
@@ -4681,6 +6545,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List instances")
     }
 
@@ -4760,6 +6636,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Create an instance")
     }
 
@@ -4783,6 +6671,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::NameOrId))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch an instance")
     }
 
@@ -4806,6 +6706,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::NameOrId))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Delete an instance")
     }
 
@@ -4849,6 +6761,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List an instance's disks")
     }
 
@@ -4892,6 +6816,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Attach a disk to an instance")
     }
 
@@ -4935,6 +6871,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Detach a disk from an instance")
     }
 
@@ -4978,6 +6926,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Migrate an instance")
     }
 
@@ -5001,6 +6961,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::NameOrId))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Reboot an instance")
     }
 
@@ -5058,6 +7030,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::NameOrId))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch an instance's serial console")
     }
 
@@ -5081,6 +7065,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::NameOrId))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Stream an instance's serial console")
     }
 
@@ -5104,6 +7100,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::NameOrId))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Boot an instance")
     }
 
@@ -5127,6 +7135,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::NameOrId))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Stop an instance")
     }
 
@@ -5152,6 +7172,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List organizations")
     }
 
@@ -5183,6 +7215,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Create an organization")
     }
 
@@ -5194,6 +7238,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::NameOrId))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch an organization")
     }
 
@@ -5231,6 +7287,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Update an organization")
     }
 
@@ -5242,6 +7310,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::NameOrId))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Delete an organization")
     }
 
@@ -5253,6 +7333,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::NameOrId))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch an organization's IAM policy")
     }
 
@@ -5278,6 +7370,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Update an organization's IAM policy")
     }
 
@@ -5309,6 +7413,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List projects")
     }
 
@@ -5346,6 +7462,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Create a project")
     }
 
@@ -5363,6 +7491,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::NameOrId))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a project")
     }
 
@@ -5406,6 +7546,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Update a project")
     }
 
@@ -5423,6 +7575,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::NameOrId))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Delete a project")
     }
 
@@ -5440,6 +7604,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::NameOrId))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a project's IAM policy")
     }
 
@@ -5471,6 +7647,18 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Update a project's IAM policy")
     }
 
@@ -5494,6 +7682,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("View version and update status of component tree")
     }
 
@@ -5517,6 +7717,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List all update deployments")
     }
 
@@ -5528,11 +7740,36 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(::uuid::Uuid))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Fetch a system update deployment")
     }
 
     pub fn cli_system_update_refresh() -> ::clap::Command {
-        ::clap::Command::new("").about("Refresh update data")
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
+            .about("Refresh update data")
     }
 
     pub fn cli_system_update_start() -> ::clap::Command {
@@ -5557,11 +7794,35 @@ impl<T: CliConfig> Cli<T> {
                     .action(::clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Start system update")
     }
 
     pub fn cli_system_update_stop() -> ::clap::Command {
         ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("Stop system update")
             .long_about("If there is no update in progress, do nothing.")
     }
@@ -5586,6 +7847,18 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("List all updates")
     }
 
@@ -5597,6 +7870,18 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::SemverVersion))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("View system update")
     }
 
@@ -5608,11 +7893,36 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(types::SemverVersion))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
             .about("View system update component tree")
     }
 
     pub fn cli_system_version() -> ::clap::Command {
-        ::clap::Command::new("").about("View system version and update status")
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("progenitor-dry-run")
+                    .long("progenitor-dry-run")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print the request rather than sending it"),
+            )
+            .arg(
+                ::clap::Arg::new("progenitor-print-curl")
+                    .long("progenitor-print-curl")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("Print an equivalent curl command rather than sending the request"),
+            )
+            .about("View system version and update status")
     }
 
     pub async fn execute(
@@ -5890,6 +8200,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_disk_view_by_id(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -5914,6 +8237,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_image_view_by_id(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -5938,6 +8274,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_view_by_id(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -5962,6 +8311,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_network_interface_view_by_id(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -5986,6 +8348,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_organization_view_by_id(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6010,6 +8385,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_project_view_by_id(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6034,6 +8422,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_snapshot_view_by_id(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6058,6 +8459,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_vpc_router_route_view_by_id(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6082,6 +8496,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_vpc_router_view_by_id(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6106,6 +8533,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_vpc_subnet_view_by_id(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6126,6 +8566,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_vpc_view_by_id(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6156,6 +8609,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_device_auth_request(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6184,6 +8650,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_device_auth_confirm(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6223,6 +8702,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_device_access_token(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6245,6 +8737,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_group_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::GroupResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -6282,6 +8787,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_login_spoof(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6317,6 +8835,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_login_local(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6344,6 +8875,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_login_saml_begin(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6367,6 +8911,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_login_saml(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6382,6 +8939,19 @@ impl<T: CliConfig> Cli<T> {
     pub async fn execute_logout(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
         let mut request = self.client.logout();
         self.config.execute_logout(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6410,6 +8980,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_organization_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::OrganizationResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -6456,6 +9039,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_organization_create(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6480,6 +9076,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_organization_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6518,6 +9127,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_organization_update(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6542,6 +9164,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_organization_delete(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6566,6 +9201,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_organization_policy_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6597,6 +9245,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_organization_policy_update(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6625,6 +9286,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_project_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::ProjectResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -6670,6 +9344,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_project_create(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6694,6 +9381,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_project_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6732,6 +9432,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_project_update(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6756,6 +9469,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_project_delete(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6788,6 +9514,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_disk_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::DiskResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -6841,6 +9580,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_disk_create(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6869,6 +9621,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_disk_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6897,6 +9662,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_disk_delete(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -6949,6 +9727,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_disk_metrics_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::MeasurementResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -6993,6 +9784,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_image_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::ImageResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -7042,6 +9846,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_image_create(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -7070,6 +9887,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_image_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -7098,6 +9928,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_image_delete(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -7130,6 +9973,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_instance_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::InstanceResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -7202,6 +10058,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_instance_create(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -7230,6 +10099,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_instance_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -7261,6 +10143,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_instance_delete(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -7301,6 +10196,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_disk_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::DiskResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -7354,6 +10262,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_disk_attach(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -7396,6 +10317,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_disk_detach(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -7428,6 +10362,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_external_ip_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -7470,6 +10417,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_migrate(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -7510,6 +10470,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_network_interface_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config
             .list_start::<types::NetworkInterfaceResultsPage>();
         let mut stream = futures::StreamExt::take(
@@ -7582,6 +10555,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_network_interface_create(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -7618,6 +10604,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_network_interface_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -7673,6 +10672,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_network_interface_update(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -7709,6 +10721,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_network_interface_delete(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -7740,6 +10765,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_instance_reboot(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -7784,6 +10822,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_serial_console(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -7816,6 +10867,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_serial_console_stream(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -7842,6 +10906,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_instance_start(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -7870,6 +10947,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_instance_stop(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -7898,6 +10988,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_project_policy_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -7932,6 +11035,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_project_policy_update(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -7964,6 +11080,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_snapshot_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::SnapshotResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -8020,6 +11149,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_snapshot_create(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -8048,6 +11190,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_snapshot_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -8079,6 +11234,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_snapshot_delete(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -8111,6 +11279,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_vpc_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::VpcResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -8168,6 +11349,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_vpc_create(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -8196,6 +11390,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_vpc_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -8242,6 +11449,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_vpc_update(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -8270,6 +11490,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_vpc_delete(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -8302,6 +11535,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_vpc_firewall_rules_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -8341,6 +11587,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_vpc_firewall_rules_update(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -8380,6 +11639,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_vpc_router_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::VpcRouterResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -8438,6 +11710,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_vpc_router_create(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -8473,6 +11758,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_vpc_router_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -8523,6 +11821,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_vpc_router_update(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -8559,6 +11870,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_vpc_router_delete(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -8603,6 +11927,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_vpc_router_route_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::RouterRouteResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -8666,6 +12003,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_vpc_router_route_create(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -8706,6 +12056,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_vpc_router_route_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -8761,6 +12124,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_vpc_router_route_update(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -8801,6 +12177,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_vpc_router_route_delete(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -8840,6 +12229,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_vpc_subnet_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::VpcSubnetResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -8906,6 +12308,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_vpc_subnet_create(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -8941,6 +12356,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_vpc_subnet_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -8991,6 +12419,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_vpc_subnet_update(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -9027,6 +12468,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_vpc_subnet_delete(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -9071,6 +12525,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_vpc_subnet_list_network_interfaces(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config
             .list_start::<types::NetworkInterfaceResultsPage>();
         let mut stream = futures::StreamExt::take(
@@ -9100,6 +12567,19 @@ impl<T: CliConfig> Cli<T> {
     pub async fn execute_policy_view(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
         let mut request = self.client.policy_view();
         self.config.execute_policy_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -9122,6 +12602,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_policy_update(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -9142,6 +12635,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_role_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::RoleResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -9173,6 +12679,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_role_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -9189,6 +12708,19 @@ impl<T: CliConfig> Cli<T> {
     pub async fn execute_session_me(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
         let mut request = self.client.session_me();
         self.config.execute_session_me(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -9217,6 +12749,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_session_me_groups(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::GroupResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -9256,6 +12801,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_session_sshkey_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::SshKeyResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -9305,6 +12863,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_session_sshkey_create(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -9329,6 +12900,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_session_sshkey_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -9353,6 +12937,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_session_sshkey_delete(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -9377,6 +12974,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_system_image_view_by_id(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -9401,6 +13011,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_ip_pool_view_by_id(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -9424,6 +13047,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_silo_view_by_id(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -9452,6 +13088,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_certificate_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::CertificateResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -9502,6 +13151,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_certificate_create(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -9526,6 +13188,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_certificate_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -9550,6 +13225,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_certificate_delete(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -9578,6 +13266,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_physical_disk_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::PhysicalDiskResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -9614,6 +13315,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_rack_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::RackResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -9645,6 +13359,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_rack_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -9669,6 +13396,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_sled_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::SledResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -9700,6 +13440,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_sled_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -9732,6 +13485,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_sled_physical_disk_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::PhysicalDiskResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -9772,6 +13538,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_system_image_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::GlobalImageResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -9818,6 +13597,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_system_image_create(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -9842,6 +13634,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_system_image_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -9866,6 +13671,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_system_image_delete(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -9890,6 +13708,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_ip_pool_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::IpPoolResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -9931,6 +13762,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_ip_pool_create(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -9951,6 +13795,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_ip_pool_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -9985,6 +13842,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_ip_pool_update(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10005,6 +13875,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_ip_pool_delete(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10033,6 +13916,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_ip_pool_range_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::IpPoolRangeResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -10075,6 +13971,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_ip_pool_range_add(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10105,6 +14014,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_ip_pool_range_remove(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10125,6 +14047,19 @@ impl<T: CliConfig> Cli<T> {
         let mut request = self.client.ip_pool_service_view();
         self.config
             .execute_ip_pool_service_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10149,6 +14084,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_ip_pool_service_range_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::IpPoolRangeResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -10187,6 +14135,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_ip_pool_service_range_add(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10213,6 +14174,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_ip_pool_service_range_remove(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10257,6 +14231,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_system_metric(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10277,6 +14264,19 @@ impl<T: CliConfig> Cli<T> {
         let mut request = self.client.system_policy_view();
         self.config
             .execute_system_policy_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10303,6 +14303,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_system_policy_update(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10327,6 +14340,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_saga_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::SagaResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -10358,6 +14384,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_saga_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10382,6 +14421,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_silo_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::SiloResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -10435,6 +14487,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_silo_create(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10455,6 +14520,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_silo_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10475,6 +14553,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_silo_delete(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10507,6 +14598,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_silo_identity_provider_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config
             .list_start::<types::IdentityProviderResultsPage>();
         let mut stream = futures::StreamExt::take(
@@ -10554,6 +14658,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_local_idp_user_create(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10582,6 +14699,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_local_idp_user_delete(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10616,6 +14746,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_local_idp_user_set_password(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10679,6 +14822,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_saml_identity_provider_create(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10707,6 +14863,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_saml_identity_provider_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10731,6 +14900,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_silo_policy_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10761,6 +14943,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_silo_policy_update(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10792,6 +14987,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_silo_users_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::UserResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -10827,6 +15035,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_silo_user_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10855,6 +15076,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_system_user_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::UserBuiltinResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -10891,6 +15125,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_system_user_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -10915,6 +15162,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_timeseries_schema_get(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config
             .list_start::<types::TimeseriesSchemaResultsPage>();
         let mut stream = futures::StreamExt::take(
@@ -10952,6 +15212,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_user_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::UserResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -10995,6 +15268,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_disk_list_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::DiskResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -11048,6 +15334,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_disk_create_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -11076,6 +15375,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_disk_view_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -11104,6 +15416,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_disk_delete_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -11140,6 +15465,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_list_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::InstanceResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -11213,6 +15551,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_create_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -11245,6 +15596,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_view_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -11277,6 +15641,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_delete_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -11317,6 +15694,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_disk_list_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::DiskResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -11370,6 +15760,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_disk_attach_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -11412,6 +15815,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_disk_detach_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -11454,6 +15870,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_migrate_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -11486,6 +15915,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_reboot_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -11530,6 +15972,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_serial_console_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -11562,6 +16017,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_serial_console_stream_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -11592,6 +16060,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_start_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -11624,6 +16105,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_instance_stop_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -11652,6 +16146,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_organization_list_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::OrganizationResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -11698,6 +16205,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_organization_create_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -11722,6 +16242,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_organization_view_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -11760,6 +16293,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_organization_update_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -11784,6 +16330,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_organization_delete_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -11808,6 +16367,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_organization_policy_view_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -11839,6 +16411,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_organization_policy_update_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -11870,6 +16455,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_project_list_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::ProjectResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -11919,6 +16517,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_project_create_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -11946,6 +16557,19 @@ impl<T: CliConfig> Cli<T> {
         }
 
         self.config.execute_project_view_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -11988,6 +16612,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_project_update_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -12016,6 +16653,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_project_delete_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -12044,6 +16694,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_project_policy_view_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -12078,6 +16741,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_project_policy_update_v1(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -12106,6 +16782,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_system_component_version_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config
             .list_start::<types::UpdateableComponentResultsPage>();
         let mut stream = futures::StreamExt::take(
@@ -12147,6 +16836,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_update_deployments_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config
             .list_start::<types::UpdateDeploymentResultsPage>();
         let mut stream = futures::StreamExt::take(
@@ -12184,6 +16886,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_update_deployment_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -12204,6 +16919,19 @@ impl<T: CliConfig> Cli<T> {
         let mut request = self.client.system_update_refresh();
         self.config
             .execute_system_update_refresh(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -12234,6 +16962,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_system_update_start(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -12254,6 +16995,19 @@ impl<T: CliConfig> Cli<T> {
         let mut request = self.client.system_update_stop();
         self.config
             .execute_system_update_stop(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -12282,6 +17036,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_system_update_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         self.config.list_start::<types::SystemUpdateResultsPage>();
         let mut stream = futures::StreamExt::take(
             request.stream(),
@@ -12318,6 +17085,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_system_update_view(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -12342,6 +17122,19 @@ impl<T: CliConfig> Cli<T> {
 
         self.config
             .execute_system_update_components_list(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -12358,6 +17151,19 @@ impl<T: CliConfig> Cli<T> {
     pub async fn execute_system_version(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
         let mut request = self.client.system_version();
         self.config.execute_system_version(matches, &mut request)?;
+        let dry_run = matches.get_flag("progenitor-dry-run");
+        let print_curl = matches.get_flag("progenitor-print-curl");
+        if dry_run || print_curl {
+            let request = request.into_request()?;
+            if dry_run {
+                self.config.dry_run(&request);
+            }
+            if print_curl {
+                self.config.print_curl(&request);
+            }
+            return Ok(());
+        }
+
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -12392,6 +17198,16 @@ pub trait CliConfig {
     fn list_end_error<T>(&self, value: &Error<T>)
     where
         T: schemars::JsonSchema + serde::Serialize + std::fmt::Debug;
+    /// Output for `--progenitor-dry-run`.
+    fn dry_run(&self, request: &reqwest::Request) {
+        print!("{}", progenitor_client::format_request(request));
+    }
+
+    /// Output for `--progenitor-print-curl`.
+    fn print_curl(&self, request: &reqwest::Request) {
+        println!("{}", progenitor_client::curl_command(request));
+    }
+
     fn execute_disk_view_by_id(
         &self,
         matches: &::clap::ArgMatches,
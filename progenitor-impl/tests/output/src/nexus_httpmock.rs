@@ -4,6 +4,118 @@ pub mod operations {
     #![doc = r" its inner type with a call to `into_inner()`. This can"]
     #![doc = r" be used to explicitly deviate from permitted values."]
     use crate::nexus_builder::*;
+    mod body_predicates {
+        type Predicate = Box<dyn Fn(&[u8]) -> bool + Send + Sync>;
+        static PREDICATES: ::std::sync::Mutex<Vec<((::std::any::TypeId, usize), Predicate)>> =
+            ::std::sync::Mutex::new(Vec::new());
+        fn matcher_at<const N: usize>(req: &::httpmock::prelude::HttpMockRequest) -> bool {
+            let predicates = PREDICATES
+                .lock()
+                .unwrap_or_else(::std::sync::PoisonError::into_inner);
+            req.body
+                .as_deref()
+                .is_some_and(|body| (predicates[N].1)(body))
+        }
+
+        const MATCHERS: [fn(&::httpmock::prelude::HttpMockRequest) -> bool; 64] = [
+            matcher_at::<0>,
+            matcher_at::<1>,
+            matcher_at::<2>,
+            matcher_at::<3>,
+            matcher_at::<4>,
+            matcher_at::<5>,
+            matcher_at::<6>,
+            matcher_at::<7>,
+            matcher_at::<8>,
+            matcher_at::<9>,
+            matcher_at::<10>,
+            matcher_at::<11>,
+            matcher_at::<12>,
+            matcher_at::<13>,
+            matcher_at::<14>,
+            matcher_at::<15>,
+            matcher_at::<16>,
+            matcher_at::<17>,
+            matcher_at::<18>,
+            matcher_at::<19>,
+            matcher_at::<20>,
+            matcher_at::<21>,
+            matcher_at::<22>,
+            matcher_at::<23>,
+            matcher_at::<24>,
+            matcher_at::<25>,
+            matcher_at::<26>,
+            matcher_at::<27>,
+            matcher_at::<28>,
+            matcher_at::<29>,
+            matcher_at::<30>,
+            matcher_at::<31>,
+            matcher_at::<32>,
+            matcher_at::<33>,
+            matcher_at::<34>,
+            matcher_at::<35>,
+            matcher_at::<36>,
+            matcher_at::<37>,
+            matcher_at::<38>,
+            matcher_at::<39>,
+            matcher_at::<40>,
+            matcher_at::<41>,
+            matcher_at::<42>,
+            matcher_at::<43>,
+            matcher_at::<44>,
+            matcher_at::<45>,
+            matcher_at::<46>,
+            matcher_at::<47>,
+            matcher_at::<48>,
+            matcher_at::<49>,
+            matcher_at::<50>,
+            matcher_at::<51>,
+            matcher_at::<52>,
+            matcher_at::<53>,
+            matcher_at::<54>,
+            matcher_at::<55>,
+            matcher_at::<56>,
+            matcher_at::<57>,
+            matcher_at::<58>,
+            matcher_at::<59>,
+            matcher_at::<60>,
+            matcher_at::<61>,
+            matcher_at::<62>,
+            matcher_at::<63>,
+        ];
+        #[doc = r" The matcher for bodies that decode to a value for which"]
+        #[doc = r" `predicate` returns `true`."]
+        pub(super) fn matcher<T>(
+            predicate: fn(&T) -> bool,
+        ) -> fn(&::httpmock::prelude::HttpMockRequest) -> bool
+        where
+            T: ::serde::de::DeserializeOwned + 'static,
+        {
+            let key = (::std::any::TypeId::of::<T>(), predicate as usize);
+            let mut predicates = PREDICATES
+                .lock()
+                .unwrap_or_else(::std::sync::PoisonError::into_inner);
+            let index = match predicates.iter().position(|(k, _)| *k == key) {
+                Some(index) => index,
+                None => {
+                    assert!(
+                        predicates.len() < MATCHERS.len(),
+                        "at most {} distinct body predicates may be used",
+                        MATCHERS.len(),
+                    );
+                    predicates.push((
+                        key,
+                        Box::new(move |body| {
+                            ::serde_json::from_slice(body).is_ok_and(|value| predicate(&value))
+                        }),
+                    ));
+                    predicates.len() - 1
+                }
+            };
+            MATCHERS[index]
+        }
+    }
+
     pub struct DiskViewByIdWhen(::httpmock::When);
     impl DiskViewByIdWhen {
         pub fn new(inner: ::httpmock::When) -> Self {
@@ -34,6 +146,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Disk) -> Self {
             Self(
                 self.0
@@ -94,6 +210,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Image) -> Self {
             Self(
                 self.0
@@ -155,6 +275,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Instance) -> Self {
             Self(
                 self.0
@@ -219,6 +343,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::NetworkInterface) -> Self {
             Self(
                 self.0
@@ -280,6 +408,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Organization) -> Self {
             Self(
                 self.0
@@ -341,6 +473,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Project) -> Self {
             Self(
                 self.0
@@ -402,6 +538,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Snapshot) -> Self {
             Self(
                 self.0
@@ -464,6 +604,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::RouterRoute) -> Self {
             Self(
                 self.0
@@ -525,6 +669,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::VpcRouter) -> Self {
             Self(
                 self.0
@@ -586,6 +734,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::VpcSubnet) -> Self {
             Self(
                 self.0
@@ -646,6 +798,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Vpc) -> Self {
             Self(
                 self.0
@@ -705,6 +861,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn default_response(self, status: u16, value: ::serde_json::Value) -> Self {
             Self(
                 self.0
@@ -732,6 +892,18 @@ pub mod operations {
         pub fn body(self, value: &types::DeviceAuthVerify) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::DeviceAuthVerify) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct DeviceAuthConfirmThen(::httpmock::Then);
@@ -744,6 +916,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -798,6 +974,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn default_response(self, status: u16, value: ::serde_json::Value) -> Self {
             Self(
                 self.0
@@ -881,6 +1061,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::GroupResultsPage) -> Self {
             Self(
                 self.0
@@ -928,6 +1112,18 @@ pub mod operations {
         pub fn body(self, value: &types::SpoofLoginBody) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::SpoofLoginBody) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct LoginSpoofThen(::httpmock::Then);
@@ -940,6 +1136,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -987,6 +1187,21 @@ pub mod operations {
         pub fn body(self, value: &types::UsernamePasswordCredentials) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(
+            self,
+            predicate: fn(&types::UsernamePasswordCredentials) -> bool,
+        ) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct LoginLocalThen(::httpmock::Then);
@@ -999,6 +1214,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn see_other(self) -> Self {
             Self(self.0.status(303u16))
         }
@@ -1032,6 +1251,10 @@ pub mod operations {
                     .json_body(value),
             )
         }
+
+        pub fn location_header(self, value: &str) -> Self {
+            Self(self.0.header("location", value.to_string()))
+        }
     }
 
     pub struct LoginSamlBeginWhen(::httpmock::When);
@@ -1069,6 +1292,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn found(self) -> Self {
             Self(self.0.status(302u16))
         }
@@ -1102,6 +1329,10 @@ pub mod operations {
                     .json_body(value),
             )
         }
+
+        pub fn location_header(self, value: &str) -> Self {
+            Self(self.0.header("location", value.to_string()))
+        }
     }
 
     pub struct LoginSamlWhen(::httpmock::When);
@@ -1143,6 +1374,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn see_other(self) -> Self {
             Self(self.0.status(303u16))
         }
@@ -1176,6 +1411,10 @@ pub mod operations {
                     .json_body(value),
             )
         }
+
+        pub fn location_header(self, value: &str) -> Self {
+            Self(self.0.header("location", value.to_string()))
+        }
     }
 
     pub struct LogoutWhen(::httpmock::When);
@@ -1203,6 +1442,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -1301,6 +1544,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::OrganizationResultsPage) -> Self {
             Self(
                 self.0
@@ -1348,6 +1595,18 @@ pub mod operations {
         pub fn body(self, value: &types::OrganizationCreate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::OrganizationCreate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct OrganizationCreateThen(::httpmock::Then);
@@ -1360,6 +1619,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::Organization) -> Self {
             Self(
                 self.0
@@ -1420,6 +1683,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Organization) -> Self {
             Self(
                 self.0
@@ -1472,6 +1739,18 @@ pub mod operations {
         pub fn body(self, value: &types::OrganizationUpdate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::OrganizationUpdate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct OrganizationUpdateThen(::httpmock::Then);
@@ -1484,6 +1763,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Organization) -> Self {
             Self(
                 self.0
@@ -1544,6 +1827,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -1600,6 +1887,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::OrganizationRolePolicy) -> Self {
             Self(
                 self.0
@@ -1653,6 +1944,18 @@ pub mod operations {
         pub fn body(self, value: &types::OrganizationRolePolicy) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::OrganizationRolePolicy) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct OrganizationPolicyUpdateThen(::httpmock::Then);
@@ -1665,6 +1968,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::OrganizationRolePolicy) -> Self {
             Self(
                 self.0
@@ -1774,6 +2081,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::ProjectResultsPage) -> Self {
             Self(
                 self.0
@@ -1827,6 +2138,18 @@ pub mod operations {
         pub fn body(self, value: &types::ProjectCreate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::ProjectCreate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct ProjectCreateThen(::httpmock::Then);
@@ -1839,6 +2162,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::Project) -> Self {
             Self(
                 self.0
@@ -1912,6 +2239,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Project) -> Self {
             Self(
                 self.0
@@ -1977,6 +2308,18 @@ pub mod operations {
         pub fn body(self, value: &types::ProjectUpdate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::ProjectUpdate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct ProjectUpdateThen(::httpmock::Then);
@@ -1989,6 +2332,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Project) -> Self {
             Self(
                 self.0
@@ -2062,6 +2409,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -2176,6 +2527,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::DiskResultsPage) -> Self {
             Self(
                 self.0
@@ -2239,6 +2594,18 @@ pub mod operations {
         pub fn body(self, value: &types::DiskCreate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::DiskCreate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct DiskCreateThen(::httpmock::Then);
@@ -2251,6 +2618,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::Disk) -> Self {
             Self(
                 self.0
@@ -2331,6 +2702,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Disk) -> Self {
             Self(
                 self.0
@@ -2411,6 +2786,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -2564,6 +2943,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::MeasurementResultsPage) -> Self {
             Self(
                 self.0
@@ -2683,6 +3066,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::ImageResultsPage) -> Self {
             Self(
                 self.0
@@ -2746,6 +3133,18 @@ pub mod operations {
         pub fn body(self, value: &types::ImageCreate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::ImageCreate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct ImageCreateThen(::httpmock::Then);
@@ -2758,6 +3157,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::Image) -> Self {
             Self(
                 self.0
@@ -2838,6 +3241,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Image) -> Self {
             Self(
                 self.0
@@ -2918,6 +3325,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -3032,6 +3443,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::InstanceResultsPage) -> Self {
             Self(
                 self.0
@@ -3095,6 +3510,18 @@ pub mod operations {
         pub fn body(self, value: &types::InstanceCreate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::InstanceCreate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct InstanceCreateThen(::httpmock::Then);
@@ -3107,6 +3534,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::Instance) -> Self {
             Self(
                 self.0
@@ -3187,6 +3618,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Instance) -> Self {
             Self(
                 self.0
@@ -3267,6 +3702,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -3395,6 +3834,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::DiskResultsPage) -> Self {
             Self(
                 self.0
@@ -3472,6 +3915,18 @@ pub mod operations {
         pub fn body(self, value: &types::DiskIdentifier) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::DiskIdentifier) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct InstanceDiskAttachThen(::httpmock::Then);
@@ -3484,6 +3939,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn accepted(self, value: &types::Disk) -> Self {
             Self(
                 self.0
@@ -3561,6 +4020,18 @@ pub mod operations {
         pub fn body(self, value: &types::DiskIdentifier) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::DiskIdentifier) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct InstanceDiskDetachThen(::httpmock::Then);
@@ -3573,6 +4044,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn accepted(self, value: &types::Disk) -> Self {
             Self(
                 self.0
@@ -3658,6 +4133,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::ExternalIpResultsPage) -> Self {
             Self(
                 self.0
@@ -3735,6 +4214,18 @@ pub mod operations {
         pub fn body(self, value: &types::InstanceMigrate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::InstanceMigrate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct InstanceMigrateThen(::httpmock::Then);
@@ -3747,6 +4238,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Instance) -> Self {
             Self(
                 self.0
@@ -3880,6 +4375,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::NetworkInterfaceResultsPage) -> Self {
             Self(
                 self.0
@@ -3957,6 +4456,18 @@ pub mod operations {
         pub fn body(self, value: &types::NetworkInterfaceCreate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::NetworkInterfaceCreate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct InstanceNetworkInterfaceCreateThen(::httpmock::Then);
@@ -3969,6 +4480,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::NetworkInterface) -> Self {
             Self(
                 self.0
@@ -4064,6 +4579,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::NetworkInterface) -> Self {
             Self(
                 self.0
@@ -4151,6 +4670,18 @@ pub mod operations {
         pub fn body(self, value: &types::NetworkInterfaceUpdate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::NetworkInterfaceUpdate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct InstanceNetworkInterfaceUpdateThen(::httpmock::Then);
@@ -4163,6 +4694,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::NetworkInterface) -> Self {
             Self(
                 self.0
@@ -4258,6 +4793,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -4338,6 +4877,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn accepted(self, value: &types::Instance) -> Self {
             Self(
                 self.0
@@ -4471,6 +5014,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::InstanceSerialConsoleData) -> Self {
             Self(
                 self.0
@@ -4557,6 +5104,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn default_response(self, status: u16) -> Self {
             Self(self.0.status(status))
         }
@@ -4621,6 +5172,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn accepted(self, value: &types::Instance) -> Self {
             Self(
                 self.0
@@ -4704,6 +5259,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn accepted(self, value: &types::Instance) -> Self {
             Self(
                 self.0
@@ -4775,6 +5334,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::ProjectRolePolicy) -> Self {
             Self(
                 self.0
@@ -4838,6 +5401,18 @@ pub mod operations {
         pub fn body(self, value: &types::ProjectRolePolicy) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::ProjectRolePolicy) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct ProjectPolicyUpdateThen(::httpmock::Then);
@@ -4850,6 +5425,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::ProjectRolePolicy) -> Self {
             Self(
                 self.0
@@ -4969,6 +5548,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::SnapshotResultsPage) -> Self {
             Self(
                 self.0
@@ -5032,6 +5615,18 @@ pub mod operations {
         pub fn body(self, value: &types::SnapshotCreate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::SnapshotCreate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct SnapshotCreateThen(::httpmock::Then);
@@ -5044,6 +5639,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::Snapshot) -> Self {
             Self(
                 self.0
@@ -5124,6 +5723,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Snapshot) -> Self {
             Self(
                 self.0
@@ -5204,6 +5807,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -5318,6 +5925,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::VpcResultsPage) -> Self {
             Self(
                 self.0
@@ -5381,6 +5992,18 @@ pub mod operations {
         pub fn body(self, value: &types::VpcCreate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::VpcCreate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct VpcCreateThen(::httpmock::Then);
@@ -5393,6 +6016,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::Vpc) -> Self {
             Self(
                 self.0
@@ -5473,6 +6100,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Vpc) -> Self {
             Self(
                 self.0
@@ -5545,6 +6176,18 @@ pub mod operations {
         pub fn body(self, value: &types::VpcUpdate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::VpcUpdate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct VpcUpdateThen(::httpmock::Then);
@@ -5557,6 +6200,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Vpc) -> Self {
             Self(
                 self.0
@@ -5637,6 +6284,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -5717,6 +6368,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::VpcFirewallRules) -> Self {
             Self(
                 self.0
@@ -5794,6 +6449,21 @@ pub mod operations {
         pub fn body(self, value: &types::VpcFirewallRuleUpdateParams) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(
+            self,
+            predicate: fn(&types::VpcFirewallRuleUpdateParams) -> bool,
+        ) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct VpcFirewallRulesUpdateThen(::httpmock::Then);
@@ -5806,6 +6476,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::VpcFirewallRules) -> Self {
             Self(
                 self.0
@@ -5937,6 +6611,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::VpcRouterResultsPage) -> Self {
             Self(
                 self.0
@@ -6012,6 +6690,18 @@ pub mod operations {
         pub fn body(self, value: &types::VpcRouterCreate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::VpcRouterCreate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct VpcRouterCreateThen(::httpmock::Then);
@@ -6024,6 +6714,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::VpcRouter) -> Self {
             Self(
                 self.0
@@ -6118,6 +6812,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::VpcRouter) -> Self {
             Self(
                 self.0
@@ -6204,6 +6902,18 @@ pub mod operations {
         pub fn body(self, value: &types::VpcRouterUpdate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::VpcRouterUpdate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct VpcRouterUpdateThen(::httpmock::Then);
@@ -6216,6 +6926,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::VpcRouter) -> Self {
             Self(
                 self.0
@@ -6310,6 +7024,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -6447,6 +7165,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::RouterRouteResultsPage) -> Self {
             Self(
                 self.0
@@ -6533,6 +7255,18 @@ pub mod operations {
         pub fn body(self, value: &types::RouterRouteCreateParams) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::RouterRouteCreateParams) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct VpcRouterRouteCreateThen(::httpmock::Then);
@@ -6545,6 +7279,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::RouterRoute) -> Self {
             Self(
                 self.0
@@ -6649,6 +7387,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::RouterRoute) -> Self {
             Self(
                 self.0
@@ -6745,6 +7487,18 @@ pub mod operations {
         pub fn body(self, value: &types::RouterRouteUpdateParams) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::RouterRouteUpdateParams) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct VpcRouterRouteUpdateThen(::httpmock::Then);
@@ -6757,6 +7511,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::RouterRoute) -> Self {
             Self(
                 self.0
@@ -6861,6 +7619,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -6987,6 +7749,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::VpcSubnetResultsPage) -> Self {
             Self(
                 self.0
@@ -7062,6 +7828,18 @@ pub mod operations {
         pub fn body(self, value: &types::VpcSubnetCreate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::VpcSubnetCreate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct VpcSubnetCreateThen(::httpmock::Then);
@@ -7074,6 +7852,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::VpcSubnet) -> Self {
             Self(
                 self.0
@@ -7168,6 +7950,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::VpcSubnet) -> Self {
             Self(
                 self.0
@@ -7254,6 +8040,18 @@ pub mod operations {
         pub fn body(self, value: &types::VpcSubnetUpdate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::VpcSubnetUpdate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct VpcSubnetUpdateThen(::httpmock::Then);
@@ -7266,6 +8064,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::VpcSubnet) -> Self {
             Self(
                 self.0
@@ -7360,6 +8162,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -7498,6 +8304,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::NetworkInterfaceResultsPage) -> Self {
             Self(
                 self.0
@@ -7553,6 +8363,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::SiloRolePolicy) -> Self {
             Self(
                 self.0
@@ -7600,6 +8414,18 @@ pub mod operations {
         pub fn body(self, value: &types::SiloRolePolicy) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::SiloRolePolicy) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct PolicyUpdateThen(::httpmock::Then);
@@ -7612,6 +8438,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::SiloRolePolicy) -> Self {
             Self(
                 self.0
@@ -7699,6 +8529,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::RoleResultsPage) -> Self {
             Self(
                 self.0
@@ -7759,6 +8593,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Role) -> Self {
             Self(
                 self.0
@@ -7814,6 +8652,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::User) -> Self {
             Self(
                 self.0
@@ -7917,6 +8759,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::GroupResultsPage) -> Self {
             Self(
                 self.0
@@ -8020,6 +8866,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::SshKeyResultsPage) -> Self {
             Self(
                 self.0
@@ -8067,6 +8917,18 @@ pub mod operations {
         pub fn body(self, value: &types::SshKeyCreate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::SshKeyCreate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct SessionSshkeyCreateThen(::httpmock::Then);
@@ -8079,6 +8941,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::SshKey) -> Self {
             Self(
                 self.0
@@ -8140,6 +9006,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::SshKey) -> Self {
             Self(
                 self.0
@@ -8201,6 +9071,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -8257,6 +9131,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::GlobalImage) -> Self {
             Self(
                 self.0
@@ -8318,6 +9196,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::IpPool) -> Self {
             Self(
                 self.0
@@ -8379,6 +9261,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Silo) -> Self {
             Self(
                 self.0
@@ -8482,6 +9368,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::CertificateResultsPage) -> Self {
             Self(
                 self.0
@@ -8529,6 +9419,18 @@ pub mod operations {
         pub fn body(self, value: &types::CertificateCreate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::CertificateCreate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct CertificateCreateThen(::httpmock::Then);
@@ -8541,6 +9443,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::Certificate) -> Self {
             Self(
                 self.0
@@ -8602,6 +9508,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Certificate) -> Self {
             Self(
                 self.0
@@ -8663,6 +9573,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -8761,6 +9675,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::PhysicalDiskResultsPage) -> Self {
             Self(
                 self.0
@@ -8864,6 +9782,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::RackResultsPage) -> Self {
             Self(
                 self.0
@@ -8925,6 +9847,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Rack) -> Self {
             Self(
                 self.0
@@ -9028,6 +9954,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::SledResultsPage) -> Self {
             Self(
                 self.0
@@ -9089,6 +10019,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Sled) -> Self {
             Self(
                 self.0
@@ -9201,6 +10135,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::PhysicalDiskResultsPage) -> Self {
             Self(
                 self.0
@@ -9304,6 +10242,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::GlobalImageResultsPage) -> Self {
             Self(
                 self.0
@@ -9348,8 +10290,20 @@ pub mod operations {
             self.0
         }
 
-        pub fn body(self, value: &types::GlobalImageCreate) -> Self {
-            Self(self.0.json_body_obj(value))
+        pub fn body(self, value: &types::GlobalImageCreate) -> Self {
+            Self(self.0.json_body_obj(value))
+        }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::GlobalImageCreate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
         }
     }
 
@@ -9363,6 +10317,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::GlobalImage) -> Self {
             Self(
                 self.0
@@ -9423,6 +10381,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::GlobalImage) -> Self {
             Self(
                 self.0
@@ -9483,6 +10445,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -9581,6 +10547,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::IpPoolResultsPage) -> Self {
             Self(
                 self.0
@@ -9628,6 +10598,18 @@ pub mod operations {
         pub fn body(self, value: &types::IpPoolCreate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::IpPoolCreate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct IpPoolCreateThen(::httpmock::Then);
@@ -9640,6 +10622,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::IpPool) -> Self {
             Self(
                 self.0
@@ -9701,6 +10687,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::IpPool) -> Self {
             Self(
                 self.0
@@ -9754,6 +10744,18 @@ pub mod operations {
         pub fn body(self, value: &types::IpPoolUpdate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::IpPoolUpdate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct IpPoolUpdateThen(::httpmock::Then);
@@ -9766,6 +10768,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::IpPool) -> Self {
             Self(
                 self.0
@@ -9827,6 +10833,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -9915,6 +10925,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::IpPoolRangeResultsPage) -> Self {
             Self(
                 self.0
@@ -9971,6 +10985,18 @@ pub mod operations {
         pub fn body(self, value: &types::IpRange) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::IpRange) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct IpPoolRangeAddThen(::httpmock::Then);
@@ -9983,6 +11009,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::IpPoolRange) -> Self {
             Self(
                 self.0
@@ -10039,6 +11069,18 @@ pub mod operations {
         pub fn body(self, value: &types::IpRange) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::IpRange) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct IpPoolRangeRemoveThen(::httpmock::Then);
@@ -10051,6 +11093,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -10101,6 +11147,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::IpPool) -> Self {
             Self(
                 self.0
@@ -10188,6 +11238,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::IpPoolRangeResultsPage) -> Self {
             Self(
                 self.0
@@ -10235,6 +11289,18 @@ pub mod operations {
         pub fn body(self, value: &types::IpRange) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::IpRange) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct IpPoolServiceRangeAddThen(::httpmock::Then);
@@ -10247,6 +11313,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::IpPoolRange) -> Self {
             Self(
                 self.0
@@ -10292,6 +11362,18 @@ pub mod operations {
         pub fn body(self, value: &types::IpRange) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::IpRange) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct IpPoolServiceRangeRemoveThen(::httpmock::Then);
@@ -10304,6 +11386,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -10428,6 +11514,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::MeasurementResultsPage) -> Self {
             Self(
                 self.0
@@ -10483,6 +11573,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::FleetRolePolicy) -> Self {
             Self(
                 self.0
@@ -10530,6 +11624,18 @@ pub mod operations {
         pub fn body(self, value: &types::FleetRolePolicy) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::FleetRolePolicy) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct SystemPolicyUpdateThen(::httpmock::Then);
@@ -10542,6 +11648,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::FleetRolePolicy) -> Self {
             Self(
                 self.0
@@ -10645,6 +11755,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::SagaResultsPage) -> Self {
             Self(
                 self.0
@@ -10705,6 +11819,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Saga) -> Self {
             Self(
                 self.0
@@ -10808,6 +11926,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::SiloResultsPage) -> Self {
             Self(
                 self.0
@@ -10855,6 +11977,18 @@ pub mod operations {
         pub fn body(self, value: &types::SiloCreate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::SiloCreate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct SiloCreateThen(::httpmock::Then);
@@ -10867,6 +12001,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::Silo) -> Self {
             Self(
                 self.0
@@ -10927,6 +12065,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Silo) -> Self {
             Self(
                 self.0
@@ -10987,6 +12129,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -11092,6 +12238,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::IdentityProviderResultsPage) -> Self {
             Self(
                 self.0
@@ -11146,6 +12296,18 @@ pub mod operations {
         pub fn body(self, value: &types::UserCreate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::UserCreate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct LocalIdpUserCreateThen(::httpmock::Then);
@@ -11158,6 +12320,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::User) -> Self {
             Self(
                 self.0
@@ -11232,6 +12398,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -11295,6 +12465,18 @@ pub mod operations {
         pub fn body(self, value: &types::UserPassword) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::UserPassword) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct LocalIdpUserSetPasswordThen(::httpmock::Then);
@@ -11307,6 +12489,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -11356,6 +12542,21 @@ pub mod operations {
         pub fn body(self, value: &types::SamlIdentityProviderCreate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(
+            self,
+            predicate: fn(&types::SamlIdentityProviderCreate) -> bool,
+        ) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct SamlIdentityProviderCreateThen(::httpmock::Then);
@@ -11368,6 +12569,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::SamlIdentityProvider) -> Self {
             Self(
                 self.0
@@ -11439,6 +12644,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::SamlIdentityProvider) -> Self {
             Self(
                 self.0
@@ -11500,6 +12709,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::SiloRolePolicy) -> Self {
             Self(
                 self.0
@@ -11553,6 +12766,18 @@ pub mod operations {
         pub fn body(self, value: &types::SiloRolePolicy) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::SiloRolePolicy) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct SiloPolicyUpdateThen(::httpmock::Then);
@@ -11565,6 +12790,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::SiloRolePolicy) -> Self {
             Self(
                 self.0
@@ -11674,6 +12903,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::UserResultsPage) -> Self {
             Self(
                 self.0
@@ -11747,6 +12980,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::User) -> Self {
             Self(
                 self.0
@@ -11850,6 +13087,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::UserBuiltinResultsPage) -> Self {
             Self(
                 self.0
@@ -11910,6 +13151,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::UserBuiltin) -> Self {
             Self(
                 self.0
@@ -11997,6 +13242,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::TimeseriesSchemaResultsPage) -> Self {
             Self(
                 self.0
@@ -12100,6 +13349,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::UserResultsPage) -> Self {
             Self(
                 self.0
@@ -12235,6 +13488,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::DiskResultsPage) -> Self {
             Self(
                 self.0
@@ -12302,6 +13559,18 @@ pub mod operations {
         pub fn body(self, value: &types::DiskCreate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::DiskCreate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct DiskCreateV1Then(::httpmock::Then);
@@ -12314,6 +13583,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::Disk) -> Self {
             Self(
                 self.0
@@ -12406,6 +13679,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Disk) -> Self {
             Self(
                 self.0
@@ -12498,6 +13775,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -12628,6 +13909,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::InstanceResultsPage) -> Self {
             Self(
                 self.0
@@ -12695,6 +13980,18 @@ pub mod operations {
         pub fn body(self, value: &types::InstanceCreate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::InstanceCreate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct InstanceCreateV1Then(::httpmock::Then);
@@ -12707,6 +14004,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::Instance) -> Self {
             Self(
                 self.0
@@ -12799,6 +14100,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Instance) -> Self {
             Self(
                 self.0
@@ -12891,6 +14196,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -13027,6 +14336,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::DiskResultsPage) -> Self {
             Self(
                 self.0
@@ -13115,6 +14428,18 @@ pub mod operations {
         pub fn body(self, value: &types::DiskPath) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::DiskPath) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct InstanceDiskAttachV1Then(::httpmock::Then);
@@ -13127,6 +14452,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn accepted(self, value: &types::Disk) -> Self {
             Self(
                 self.0
@@ -13215,6 +14544,18 @@ pub mod operations {
         pub fn body(self, value: &types::DiskPath) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::DiskPath) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct InstanceDiskDetachV1Then(::httpmock::Then);
@@ -13227,6 +14568,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn accepted(self, value: &types::Disk) -> Self {
             Self(
                 self.0
@@ -13312,6 +14657,18 @@ pub mod operations {
         pub fn body(self, value: &types::InstanceMigrate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::InstanceMigrate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct InstanceMigrateV1Then(::httpmock::Then);
@@ -13324,6 +14681,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Instance) -> Self {
             Self(
                 self.0
@@ -13417,6 +14778,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn accepted(self, value: &types::Instance) -> Self {
             Self(
                 self.0
@@ -13561,6 +14926,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::InstanceSerialConsoleData) -> Self {
             Self(
                 self.0
@@ -13655,6 +15024,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn default_response(self, status: u16) -> Self {
             Self(self.0.status(status))
         }
@@ -13727,6 +15100,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn accepted(self, value: &types::Instance) -> Self {
             Self(
                 self.0
@@ -13820,6 +15197,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn accepted(self, value: &types::Instance) -> Self {
             Self(
                 self.0
@@ -13923,6 +15304,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::OrganizationResultsPage) -> Self {
             Self(
                 self.0
@@ -13970,6 +15355,18 @@ pub mod operations {
         pub fn body(self, value: &types::OrganizationCreate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::OrganizationCreate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct OrganizationCreateV1Then(::httpmock::Then);
@@ -13982,6 +15379,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::Organization) -> Self {
             Self(
                 self.0
@@ -14043,6 +15444,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Organization) -> Self {
             Self(
                 self.0
@@ -14096,6 +15501,18 @@ pub mod operations {
         pub fn body(self, value: &types::OrganizationUpdate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::OrganizationUpdate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct OrganizationUpdateV1Then(::httpmock::Then);
@@ -14108,6 +15525,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Organization) -> Self {
             Self(
                 self.0
@@ -14169,6 +15590,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -14226,6 +15651,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::OrganizationRolePolicy) -> Self {
             Self(
                 self.0
@@ -14280,6 +15709,18 @@ pub mod operations {
         pub fn body(self, value: &types::OrganizationRolePolicy) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::OrganizationRolePolicy) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct OrganizationPolicyUpdateV1Then(::httpmock::Then);
@@ -14292,6 +15733,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::OrganizationRolePolicy) -> Self {
             Self(
                 self.0
@@ -14411,6 +15856,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::ProjectResultsPage) -> Self {
             Self(
                 self.0
@@ -14462,6 +15911,18 @@ pub mod operations {
         pub fn body(self, value: &types::ProjectCreate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::ProjectCreate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct ProjectCreateV1Then(::httpmock::Then);
@@ -14474,6 +15935,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn created(self, value: &types::Project) -> Self {
             Self(
                 self.0
@@ -14550,6 +16015,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Project) -> Self {
             Self(
                 self.0
@@ -14618,6 +16087,18 @@ pub mod operations {
         pub fn body(self, value: &types::ProjectUpdate) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::ProjectUpdate) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct ProjectUpdateV1Then(::httpmock::Then);
@@ -14630,6 +16111,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::Project) -> Self {
             Self(
                 self.0
@@ -14706,6 +16191,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -14778,6 +16267,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::ProjectRolePolicy) -> Self {
             Self(
                 self.0
@@ -14847,6 +16340,18 @@ pub mod operations {
         pub fn body(self, value: &types::ProjectRolePolicy) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::ProjectRolePolicy) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct ProjectPolicyUpdateV1Then(::httpmock::Then);
@@ -14859,6 +16364,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::ProjectRolePolicy) -> Self {
             Self(
                 self.0
@@ -14962,6 +16471,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::UpdateableComponentResultsPage) -> Self {
             Self(
                 self.0
@@ -15065,6 +16578,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::UpdateDeploymentResultsPage) -> Self {
             Self(
                 self.0
@@ -15129,6 +16646,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::UpdateDeployment) -> Self {
             Self(
                 self.0
@@ -15184,6 +16705,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -15226,6 +16751,18 @@ pub mod operations {
         pub fn body(self, value: &types::SystemUpdateStart) -> Self {
             Self(self.0.json_body_obj(value))
         }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::SystemUpdateStart) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
     }
 
     pub struct SystemUpdateStartThen(::httpmock::Then);
@@ -15238,6 +16775,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn accepted(self, value: &types::UpdateDeployment) -> Self {
             Self(
                 self.0
@@ -15293,6 +16834,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn no_content(self) -> Self {
             Self(self.0.status(204u16))
         }
@@ -15391,6 +16936,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::SystemUpdateResultsPage) -> Self {
             Self(
                 self.0
@@ -15455,6 +17004,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::SystemUpdate) -> Self {
             Self(
                 self.0
@@ -15517,6 +17070,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::ComponentUpdateResultsPage) -> Self {
             Self(
                 self.0
@@ -15572,6 +17129,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self, value: &types::SystemVersion) -> Self {
             Self(
                 self.0
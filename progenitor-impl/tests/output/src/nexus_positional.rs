@@ -2,7 +2,9 @@
 #[allow(unused_imports)]
 use progenitor_client::{encode_path, ClientHooks, OperationInfo, RequestBuilderExt};
 #[allow(unused_imports)]
-pub use progenitor_client::{ByteStream, ClientInfo, Error, ResponseValue};
+pub use progenitor_client::{
+    ByteStream, ClientInfo, Error, RequestOptions, ResponseValue, UploadBody,
+};
 /// Types used as operation parameters and responses.
 #[allow(clippy::all)]
 pub mod types {
@@ -14053,6 +14055,7 @@ pub mod types {
 pub struct Client {
     pub(crate) baseurl: String,
     pub(crate) client: reqwest::Client,
+    pub(crate) middleware: progenitor_client::MiddlewareStack,
 }
 
 impl Client {
@@ -14084,8 +14087,1787 @@ impl Client {
         Self {
             baseurl: baseurl.to_string(),
             client,
+            middleware: Default::default(),
         }
     }
+
+    /// Append `middleware` to the chain that surrounds the
+    /// execution of each request. Middleware runs in the order in
+    /// which it is attached.
+    pub fn with_middleware<M: progenitor_client::Middleware>(mut self, middleware: M) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Descriptions of the API's operations.
+    pub const OPERATIONS: &'static [progenitor_client::OperationMetadata] = &[
+        progenitor_client::OperationMetadata {
+            operation_id: "disk_view_by_id",
+            method: reqwest::Method::GET,
+            path: "/by-id/disks/{id}",
+            tags: &["disks"],
+            idempotent: true,
+            deprecated: true,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "image_view_by_id",
+            method: reqwest::Method::GET,
+            path: "/by-id/images/{id}",
+            tags: &["images"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_view_by_id",
+            method: reqwest::Method::GET,
+            path: "/by-id/instances/{id}",
+            tags: &["instances"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_network_interface_view_by_id",
+            method: reqwest::Method::GET,
+            path: "/by-id/network-interfaces/{id}",
+            tags: &["instances"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "organization_view_by_id",
+            method: reqwest::Method::GET,
+            path: "/by-id/organizations/{id}",
+            tags: &["organizations"],
+            idempotent: true,
+            deprecated: true,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "project_view_by_id",
+            method: reqwest::Method::GET,
+            path: "/by-id/projects/{id}",
+            tags: &["projects"],
+            idempotent: true,
+            deprecated: true,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "snapshot_view_by_id",
+            method: reqwest::Method::GET,
+            path: "/by-id/snapshots/{id}",
+            tags: &["snapshots"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_router_route_view_by_id",
+            method: reqwest::Method::GET,
+            path: "/by-id/vpc-router-routes/{id}",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_router_view_by_id",
+            method: reqwest::Method::GET,
+            path: "/by-id/vpc-routers/{id}",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_subnet_view_by_id",
+            method: reqwest::Method::GET,
+            path: "/by-id/vpc-subnets/{id}",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_view_by_id",
+            method: reqwest::Method::GET,
+            path: "/by-id/vpcs/{id}",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "device_auth_request",
+            method: reqwest::Method::POST,
+            path: "/device/auth",
+            tags: &["hidden"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "device_auth_confirm",
+            method: reqwest::Method::POST,
+            path: "/device/confirm",
+            tags: &["hidden"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "device_access_token",
+            method: reqwest::Method::POST,
+            path: "/device/token",
+            tags: &["hidden"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "group_list",
+            method: reqwest::Method::GET,
+            path: "/groups",
+            tags: &["silos"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "login_spoof",
+            method: reqwest::Method::POST,
+            path: "/login",
+            tags: &["hidden"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "login_local",
+            method: reqwest::Method::POST,
+            path: "/login/{silo_name}/local",
+            tags: &["login"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[200u16..=299u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "login_saml_begin",
+            method: reqwest::Method::GET,
+            path: "/login/{silo_name}/saml/{provider_name}",
+            tags: &["login"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=299u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "login_saml",
+            method: reqwest::Method::POST,
+            path: "/login/{silo_name}/saml/{provider_name}",
+            tags: &["login"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[200u16..=299u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "logout",
+            method: reqwest::Method::POST,
+            path: "/logout",
+            tags: &["hidden"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "organization_list",
+            method: reqwest::Method::GET,
+            path: "/organizations",
+            tags: &["organizations"],
+            idempotent: true,
+            deprecated: true,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "organization_create",
+            method: reqwest::Method::POST,
+            path: "/organizations",
+            tags: &["organizations"],
+            idempotent: false,
+            deprecated: true,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "organization_view",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}",
+            tags: &["organizations"],
+            idempotent: true,
+            deprecated: true,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "organization_update",
+            method: reqwest::Method::PUT,
+            path: "/organizations/{organization_name}",
+            tags: &["organizations"],
+            idempotent: true,
+            deprecated: true,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "organization_delete",
+            method: reqwest::Method::DELETE,
+            path: "/organizations/{organization_name}",
+            tags: &["organizations"],
+            idempotent: true,
+            deprecated: true,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "organization_policy_view",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/policy",
+            tags: &["organizations"],
+            idempotent: true,
+            deprecated: true,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "organization_policy_update",
+            method: reqwest::Method::PUT,
+            path: "/organizations/{organization_name}/policy",
+            tags: &["organizations"],
+            idempotent: true,
+            deprecated: true,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "project_list",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects",
+            tags: &["projects"],
+            idempotent: true,
+            deprecated: true,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "project_create",
+            method: reqwest::Method::POST,
+            path: "/organizations/{organization_name}/projects",
+            tags: &["projects"],
+            idempotent: false,
+            deprecated: true,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "project_view",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}",
+            tags: &["projects"],
+            idempotent: true,
+            deprecated: true,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "project_update",
+            method: reqwest::Method::PUT,
+            path: "/organizations/{organization_name}/projects/{project_name}",
+            tags: &["projects"],
+            idempotent: true,
+            deprecated: true,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "project_delete",
+            method: reqwest::Method::DELETE,
+            path: "/organizations/{organization_name}/projects/{project_name}",
+            tags: &["projects"],
+            idempotent: true,
+            deprecated: true,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "disk_list",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/disks",
+            tags: &["disks"],
+            idempotent: true,
+            deprecated: true,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "disk_create",
+            method: reqwest::Method::POST,
+            path: "/organizations/{organization_name}/projects/{project_name}/disks",
+            tags: &["disks"],
+            idempotent: false,
+            deprecated: true,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "disk_view",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/disks/{disk_name}",
+            tags: &["disks"],
+            idempotent: true,
+            deprecated: true,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "disk_delete",
+            method: reqwest::Method::DELETE,
+            path: "/organizations/{organization_name}/projects/{project_name}/disks/{disk_name}",
+            tags: &["disks"],
+            idempotent: true,
+            deprecated: true,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "disk_metrics_list",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/disks/{disk_name}/\
+                   metrics/{metric_name}",
+            tags: &["disks"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "image_list",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/images",
+            tags: &["images"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "image_create",
+            method: reqwest::Method::POST,
+            path: "/organizations/{organization_name}/projects/{project_name}/images",
+            tags: &["images"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "image_view",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/images/{image_name}",
+            tags: &["images"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "image_delete",
+            method: reqwest::Method::DELETE,
+            path: "/organizations/{organization_name}/projects/{project_name}/images/{image_name}",
+            tags: &["images"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_list",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/instances",
+            tags: &["instances"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_create",
+            method: reqwest::Method::POST,
+            path: "/organizations/{organization_name}/projects/{project_name}/instances",
+            tags: &["instances"],
+            idempotent: false,
+            deprecated: true,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_view",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+                   {instance_name}",
+            tags: &["instances"],
+            idempotent: true,
+            deprecated: true,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_delete",
+            method: reqwest::Method::DELETE,
+            path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+                   {instance_name}",
+            tags: &["instances"],
+            idempotent: true,
+            deprecated: true,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_disk_list",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+                   {instance_name}/disks",
+            tags: &["instances"],
+            idempotent: true,
+            deprecated: true,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_disk_attach",
+            method: reqwest::Method::POST,
+            path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+                   {instance_name}/disks/attach",
+            tags: &["instances"],
+            idempotent: false,
+            deprecated: true,
+            success_statuses: &[202u16..=202u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_disk_detach",
+            method: reqwest::Method::POST,
+            path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+                   {instance_name}/disks/detach",
+            tags: &["instances"],
+            idempotent: false,
+            deprecated: true,
+            success_statuses: &[202u16..=202u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_external_ip_list",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+                   {instance_name}/external-ips",
+            tags: &["instances"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_migrate",
+            method: reqwest::Method::POST,
+            path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+                   {instance_name}/migrate",
+            tags: &["instances"],
+            idempotent: false,
+            deprecated: true,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_network_interface_list",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+                   {instance_name}/network-interfaces",
+            tags: &["instances"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_network_interface_create",
+            method: reqwest::Method::POST,
+            path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+                   {instance_name}/network-interfaces",
+            tags: &["instances"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_network_interface_view",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+                   {instance_name}/network-interfaces/{interface_name}",
+            tags: &["instances"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_network_interface_update",
+            method: reqwest::Method::PUT,
+            path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+                   {instance_name}/network-interfaces/{interface_name}",
+            tags: &["instances"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_network_interface_delete",
+            method: reqwest::Method::DELETE,
+            path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+                   {instance_name}/network-interfaces/{interface_name}",
+            tags: &["instances"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_reboot",
+            method: reqwest::Method::POST,
+            path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+                   {instance_name}/reboot",
+            tags: &["instances"],
+            idempotent: false,
+            deprecated: true,
+            success_statuses: &[202u16..=202u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_serial_console",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+                   {instance_name}/serial-console",
+            tags: &["instances"],
+            idempotent: true,
+            deprecated: true,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_serial_console_stream",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+                   {instance_name}/serial-console/stream",
+            tags: &["instances"],
+            idempotent: true,
+            deprecated: true,
+            success_statuses: &[101u16..=101u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_start",
+            method: reqwest::Method::POST,
+            path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+                   {instance_name}/start",
+            tags: &["instances"],
+            idempotent: false,
+            deprecated: true,
+            success_statuses: &[202u16..=202u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_stop",
+            method: reqwest::Method::POST,
+            path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+                   {instance_name}/stop",
+            tags: &["instances"],
+            idempotent: false,
+            deprecated: true,
+            success_statuses: &[202u16..=202u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "project_policy_view",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/policy",
+            tags: &["projects"],
+            idempotent: true,
+            deprecated: true,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "project_policy_update",
+            method: reqwest::Method::PUT,
+            path: "/organizations/{organization_name}/projects/{project_name}/policy",
+            tags: &["projects"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "snapshot_list",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/snapshots",
+            tags: &["snapshots"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "snapshot_create",
+            method: reqwest::Method::POST,
+            path: "/organizations/{organization_name}/projects/{project_name}/snapshots",
+            tags: &["snapshots"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "snapshot_view",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/snapshots/\
+                   {snapshot_name}",
+            tags: &["snapshots"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "snapshot_delete",
+            method: reqwest::Method::DELETE,
+            path: "/organizations/{organization_name}/projects/{project_name}/snapshots/\
+                   {snapshot_name}",
+            tags: &["snapshots"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_list",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_create",
+            method: reqwest::Method::POST,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs",
+            tags: &["vpcs"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_view",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_update",
+            method: reqwest::Method::PUT,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_delete",
+            method: reqwest::Method::DELETE,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_firewall_rules_view",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/\
+                   firewall/rules",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_firewall_rules_update",
+            method: reqwest::Method::PUT,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/\
+                   firewall/rules",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_router_list",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/\
+                   routers",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_router_create",
+            method: reqwest::Method::POST,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/\
+                   routers",
+            tags: &["vpcs"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_router_view",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/\
+                   routers/{router_name}",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_router_update",
+            method: reqwest::Method::PUT,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/\
+                   routers/{router_name}",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_router_delete",
+            method: reqwest::Method::DELETE,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/\
+                   routers/{router_name}",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_router_route_list",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/\
+                   routers/{router_name}/routes",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_router_route_create",
+            method: reqwest::Method::POST,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/\
+                   routers/{router_name}/routes",
+            tags: &["vpcs"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_router_route_view",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/\
+                   routers/{router_name}/routes/{route_name}",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_router_route_update",
+            method: reqwest::Method::PUT,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/\
+                   routers/{router_name}/routes/{route_name}",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_router_route_delete",
+            method: reqwest::Method::DELETE,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/\
+                   routers/{router_name}/routes/{route_name}",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_subnet_list",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/\
+                   subnets",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_subnet_create",
+            method: reqwest::Method::POST,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/\
+                   subnets",
+            tags: &["vpcs"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_subnet_view",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/\
+                   subnets/{subnet_name}",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_subnet_update",
+            method: reqwest::Method::PUT,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/\
+                   subnets/{subnet_name}",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_subnet_delete",
+            method: reqwest::Method::DELETE,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/\
+                   subnets/{subnet_name}",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "vpc_subnet_list_network_interfaces",
+            method: reqwest::Method::GET,
+            path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/\
+                   subnets/{subnet_name}/network-interfaces",
+            tags: &["vpcs"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "policy_view",
+            method: reqwest::Method::GET,
+            path: "/policy",
+            tags: &["silos"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "policy_update",
+            method: reqwest::Method::PUT,
+            path: "/policy",
+            tags: &["silos"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "role_list",
+            method: reqwest::Method::GET,
+            path: "/roles",
+            tags: &["roles"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "role_view",
+            method: reqwest::Method::GET,
+            path: "/roles/{role_name}",
+            tags: &["roles"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "session_me",
+            method: reqwest::Method::GET,
+            path: "/session/me",
+            tags: &["hidden"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "session_me_groups",
+            method: reqwest::Method::GET,
+            path: "/session/me/groups",
+            tags: &["hidden"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "session_sshkey_list",
+            method: reqwest::Method::GET,
+            path: "/session/me/sshkeys",
+            tags: &["session"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "session_sshkey_create",
+            method: reqwest::Method::POST,
+            path: "/session/me/sshkeys",
+            tags: &["session"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "session_sshkey_view",
+            method: reqwest::Method::GET,
+            path: "/session/me/sshkeys/{ssh_key_name}",
+            tags: &["session"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "session_sshkey_delete",
+            method: reqwest::Method::DELETE,
+            path: "/session/me/sshkeys/{ssh_key_name}",
+            tags: &["session"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "system_image_view_by_id",
+            method: reqwest::Method::GET,
+            path: "/system/by-id/images/{id}",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "ip_pool_view_by_id",
+            method: reqwest::Method::GET,
+            path: "/system/by-id/ip-pools/{id}",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "silo_view_by_id",
+            method: reqwest::Method::GET,
+            path: "/system/by-id/silos/{id}",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "certificate_list",
+            method: reqwest::Method::GET,
+            path: "/system/certificates",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "certificate_create",
+            method: reqwest::Method::POST,
+            path: "/system/certificates",
+            tags: &["system"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "certificate_view",
+            method: reqwest::Method::GET,
+            path: "/system/certificates/{certificate}",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "certificate_delete",
+            method: reqwest::Method::DELETE,
+            path: "/system/certificates/{certificate}",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "physical_disk_list",
+            method: reqwest::Method::GET,
+            path: "/system/hardware/disks",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "rack_list",
+            method: reqwest::Method::GET,
+            path: "/system/hardware/racks",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "rack_view",
+            method: reqwest::Method::GET,
+            path: "/system/hardware/racks/{rack_id}",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "sled_list",
+            method: reqwest::Method::GET,
+            path: "/system/hardware/sleds",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "sled_view",
+            method: reqwest::Method::GET,
+            path: "/system/hardware/sleds/{sled_id}",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "sled_physical_disk_list",
+            method: reqwest::Method::GET,
+            path: "/system/hardware/sleds/{sled_id}/disks",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "system_image_list",
+            method: reqwest::Method::GET,
+            path: "/system/images",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "system_image_create",
+            method: reqwest::Method::POST,
+            path: "/system/images",
+            tags: &["system"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "system_image_view",
+            method: reqwest::Method::GET,
+            path: "/system/images/{image_name}",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "system_image_delete",
+            method: reqwest::Method::DELETE,
+            path: "/system/images/{image_name}",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "ip_pool_list",
+            method: reqwest::Method::GET,
+            path: "/system/ip-pools",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "ip_pool_create",
+            method: reqwest::Method::POST,
+            path: "/system/ip-pools",
+            tags: &["system"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "ip_pool_view",
+            method: reqwest::Method::GET,
+            path: "/system/ip-pools/{pool_name}",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "ip_pool_update",
+            method: reqwest::Method::PUT,
+            path: "/system/ip-pools/{pool_name}",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "ip_pool_delete",
+            method: reqwest::Method::DELETE,
+            path: "/system/ip-pools/{pool_name}",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "ip_pool_range_list",
+            method: reqwest::Method::GET,
+            path: "/system/ip-pools/{pool_name}/ranges",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "ip_pool_range_add",
+            method: reqwest::Method::POST,
+            path: "/system/ip-pools/{pool_name}/ranges/add",
+            tags: &["system"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "ip_pool_range_remove",
+            method: reqwest::Method::POST,
+            path: "/system/ip-pools/{pool_name}/ranges/remove",
+            tags: &["system"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "ip_pool_service_view",
+            method: reqwest::Method::GET,
+            path: "/system/ip-pools-service",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "ip_pool_service_range_list",
+            method: reqwest::Method::GET,
+            path: "/system/ip-pools-service/ranges",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "ip_pool_service_range_add",
+            method: reqwest::Method::POST,
+            path: "/system/ip-pools-service/ranges/add",
+            tags: &["system"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "ip_pool_service_range_remove",
+            method: reqwest::Method::POST,
+            path: "/system/ip-pools-service/ranges/remove",
+            tags: &["system"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "system_metric",
+            method: reqwest::Method::GET,
+            path: "/system/metrics/{metric_name}",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "system_policy_view",
+            method: reqwest::Method::GET,
+            path: "/system/policy",
+            tags: &["policy"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "system_policy_update",
+            method: reqwest::Method::PUT,
+            path: "/system/policy",
+            tags: &["policy"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "saga_list",
+            method: reqwest::Method::GET,
+            path: "/system/sagas",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "saga_view",
+            method: reqwest::Method::GET,
+            path: "/system/sagas/{saga_id}",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "silo_list",
+            method: reqwest::Method::GET,
+            path: "/system/silos",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "silo_create",
+            method: reqwest::Method::POST,
+            path: "/system/silos",
+            tags: &["system"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "silo_view",
+            method: reqwest::Method::GET,
+            path: "/system/silos/{silo_name}",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "silo_delete",
+            method: reqwest::Method::DELETE,
+            path: "/system/silos/{silo_name}",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "silo_identity_provider_list",
+            method: reqwest::Method::GET,
+            path: "/system/silos/{silo_name}/identity-providers",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "local_idp_user_create",
+            method: reqwest::Method::POST,
+            path: "/system/silos/{silo_name}/identity-providers/local/users",
+            tags: &["system"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "local_idp_user_delete",
+            method: reqwest::Method::DELETE,
+            path: "/system/silos/{silo_name}/identity-providers/local/users/{user_id}",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "local_idp_user_set_password",
+            method: reqwest::Method::POST,
+            path: "/system/silos/{silo_name}/identity-providers/local/users/{user_id}/set-password",
+            tags: &["system"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "saml_identity_provider_create",
+            method: reqwest::Method::POST,
+            path: "/system/silos/{silo_name}/identity-providers/saml",
+            tags: &["system"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "saml_identity_provider_view",
+            method: reqwest::Method::GET,
+            path: "/system/silos/{silo_name}/identity-providers/saml/{provider_name}",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "silo_policy_view",
+            method: reqwest::Method::GET,
+            path: "/system/silos/{silo_name}/policy",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "silo_policy_update",
+            method: reqwest::Method::PUT,
+            path: "/system/silos/{silo_name}/policy",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "silo_users_list",
+            method: reqwest::Method::GET,
+            path: "/system/silos/{silo_name}/users/all",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "silo_user_view",
+            method: reqwest::Method::GET,
+            path: "/system/silos/{silo_name}/users/id/{user_id}",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "system_user_list",
+            method: reqwest::Method::GET,
+            path: "/system/user",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "system_user_view",
+            method: reqwest::Method::GET,
+            path: "/system/user/{user_name}",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "timeseries_schema_get",
+            method: reqwest::Method::GET,
+            path: "/timeseries/schema",
+            tags: &["metrics"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "user_list",
+            method: reqwest::Method::GET,
+            path: "/users",
+            tags: &["silos"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "disk_list_v1",
+            method: reqwest::Method::GET,
+            path: "/v1/disks",
+            tags: &["disks"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "disk_create_v1",
+            method: reqwest::Method::POST,
+            path: "/v1/disks",
+            tags: &["disks"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "disk_view_v1",
+            method: reqwest::Method::GET,
+            path: "/v1/disks/{disk}",
+            tags: &["disks"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "disk_delete_v1",
+            method: reqwest::Method::DELETE,
+            path: "/v1/disks/{disk}",
+            tags: &["disks"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_list_v1",
+            method: reqwest::Method::GET,
+            path: "/v1/instances",
+            tags: &["instances"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_create_v1",
+            method: reqwest::Method::POST,
+            path: "/v1/instances",
+            tags: &["instances"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_view_v1",
+            method: reqwest::Method::GET,
+            path: "/v1/instances/{instance}",
+            tags: &["instances"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_delete_v1",
+            method: reqwest::Method::DELETE,
+            path: "/v1/instances/{instance}",
+            tags: &["instances"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_disk_list_v1",
+            method: reqwest::Method::GET,
+            path: "/v1/instances/{instance}/disks",
+            tags: &["instances"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_disk_attach_v1",
+            method: reqwest::Method::POST,
+            path: "/v1/instances/{instance}/disks/attach",
+            tags: &["instances"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[202u16..=202u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_disk_detach_v1",
+            method: reqwest::Method::POST,
+            path: "/v1/instances/{instance}/disks/detach",
+            tags: &["instances"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[202u16..=202u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_migrate_v1",
+            method: reqwest::Method::POST,
+            path: "/v1/instances/{instance}/migrate",
+            tags: &["instances"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_reboot_v1",
+            method: reqwest::Method::POST,
+            path: "/v1/instances/{instance}/reboot",
+            tags: &["instances"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[202u16..=202u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_serial_console_v1",
+            method: reqwest::Method::GET,
+            path: "/v1/instances/{instance}/serial-console",
+            tags: &["instances"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_serial_console_stream_v1",
+            method: reqwest::Method::GET,
+            path: "/v1/instances/{instance}/serial-console/stream",
+            tags: &["instances"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[101u16..=101u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_start_v1",
+            method: reqwest::Method::POST,
+            path: "/v1/instances/{instance}/start",
+            tags: &["instances"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[202u16..=202u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_stop_v1",
+            method: reqwest::Method::POST,
+            path: "/v1/instances/{instance}/stop",
+            tags: &["instances"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[202u16..=202u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "organization_list_v1",
+            method: reqwest::Method::GET,
+            path: "/v1/organizations",
+            tags: &["organizations"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "organization_create_v1",
+            method: reqwest::Method::POST,
+            path: "/v1/organizations",
+            tags: &["organizations"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "organization_view_v1",
+            method: reqwest::Method::GET,
+            path: "/v1/organizations/{organization}",
+            tags: &["organizations"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "organization_update_v1",
+            method: reqwest::Method::PUT,
+            path: "/v1/organizations/{organization}",
+            tags: &["organizations"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "organization_delete_v1",
+            method: reqwest::Method::DELETE,
+            path: "/v1/organizations/{organization}",
+            tags: &["organizations"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "organization_policy_view_v1",
+            method: reqwest::Method::GET,
+            path: "/v1/organizations/{organization}/policy",
+            tags: &["organizations"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "organization_policy_update_v1",
+            method: reqwest::Method::PUT,
+            path: "/v1/organizations/{organization}/policy",
+            tags: &["organizations"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "project_list_v1",
+            method: reqwest::Method::GET,
+            path: "/v1/projects",
+            tags: &["projects"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "project_create_v1",
+            method: reqwest::Method::POST,
+            path: "/v1/projects",
+            tags: &["projects"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "project_view_v1",
+            method: reqwest::Method::GET,
+            path: "/v1/projects/{project}",
+            tags: &["projects"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "project_update_v1",
+            method: reqwest::Method::PUT,
+            path: "/v1/projects/{project}",
+            tags: &["projects"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "project_delete_v1",
+            method: reqwest::Method::DELETE,
+            path: "/v1/projects/{project}",
+            tags: &["projects"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "project_policy_view_v1",
+            method: reqwest::Method::GET,
+            path: "/v1/projects/{project}/policy",
+            tags: &["projects"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "project_policy_update_v1",
+            method: reqwest::Method::PUT,
+            path: "/v1/projects/{project}/policy",
+            tags: &["projects"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "system_component_version_list",
+            method: reqwest::Method::GET,
+            path: "/v1/system/update/components",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "update_deployments_list",
+            method: reqwest::Method::GET,
+            path: "/v1/system/update/deployments",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "update_deployment_view",
+            method: reqwest::Method::GET,
+            path: "/v1/system/update/deployments/{id}",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "system_update_refresh",
+            method: reqwest::Method::POST,
+            path: "/v1/system/update/refresh",
+            tags: &["system"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "system_update_start",
+            method: reqwest::Method::POST,
+            path: "/v1/system/update/start",
+            tags: &["system"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[202u16..=202u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "system_update_stop",
+            method: reqwest::Method::POST,
+            path: "/v1/system/update/stop",
+            tags: &["system"],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "system_update_list",
+            method: reqwest::Method::GET,
+            path: "/v1/system/update/updates",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "system_update_view",
+            method: reqwest::Method::GET,
+            path: "/v1/system/update/updates/{version}",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "system_update_components_list",
+            method: reqwest::Method::GET,
+            path: "/v1/system/update/updates/{version}/components",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "system_version",
+            method: reqwest::Method::GET,
+            path: "/v1/system/update/version",
+            tags: &["system"],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+    ];
 }
 
 impl ClientInfo<()> for Client {
@@ -14119,6 +15901,53 @@ impl Client {
         &'a self,
         id: &'a ::uuid::Uuid,
     ) -> Result<ResponseValue<types::Disk>, Error<types::Error>> {
+        self.disk_view_by_id_with_options(id, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::disk_view_by_id`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn disk_view_by_id_with_options<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Disk>, Error<types::Error>> {
+        let mut request = self
+            .disk_view_by_id_request(id)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[0]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::disk_view_by_id`] without sending it.
+    pub fn disk_view_by_id_request<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/by-id/disks/{}",
             self.baseurl,
@@ -14139,13 +15968,46 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "disk_view_by_id",
-        };
+        Ok(request)
+    }
+
+    ///Fetch an image by id
+    ///
+    ///Sends a `GET` request to `/by-id/images/{id}`
+    pub async fn image_view_by_id<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+    ) -> Result<ResponseValue<types::Image>, Error<types::Error>> {
+        self.image_view_by_id_with_options(id, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::image_view_by_id`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn image_view_by_id_with_options<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Image>, Error<types::Error>> {
+        let mut request = self
+            .image_view_by_id_request(id)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[1]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -14158,13 +16020,11 @@ impl Client {
         }
     }
 
-    ///Fetch an image by id
-    ///
-    ///Sends a `GET` request to `/by-id/images/{id}`
-    pub async fn image_view_by_id<'a>(
+    ///Prepares the request of [`Client::image_view_by_id`] without sending it.
+    pub fn image_view_by_id_request<'a>(
         &'a self,
         id: &'a ::uuid::Uuid,
-    ) -> Result<ResponseValue<types::Image>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/by-id/images/{}",
             self.baseurl,
@@ -14185,13 +16045,46 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "image_view_by_id",
-        };
+        Ok(request)
+    }
+
+    ///Fetch an instance by id
+    ///
+    ///Sends a `GET` request to `/by-id/instances/{id}`
+    pub async fn instance_view_by_id<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        self.instance_view_by_id_with_options(id, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::instance_view_by_id`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_view_by_id_with_options<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        let mut request = self
+            .instance_view_by_id_request(id)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[2]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -14204,13 +16097,12 @@ impl Client {
         }
     }
 
-    ///Fetch an instance by id
-    ///
-    ///Sends a `GET` request to `/by-id/instances/{id}`
-    pub async fn instance_view_by_id<'a>(
+    ///Prepares the request of [`Client::instance_view_by_id`] without sending
+    /// it.
+    pub fn instance_view_by_id_request<'a>(
         &'a self,
         id: &'a ::uuid::Uuid,
-    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/by-id/instances/{}",
             self.baseurl,
@@ -14231,13 +16123,49 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_view_by_id",
-        };
+        Ok(request)
+    }
+
+    ///Fetch a network interface by id
+    ///
+    ///Sends a `GET` request to `/by-id/network-interfaces/{id}`
+    pub async fn instance_network_interface_view_by_id<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+    ) -> Result<ResponseValue<types::NetworkInterface>, Error<types::Error>> {
+        self.instance_network_interface_view_by_id_with_options(
+            id,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_network_interface_view_by_id`]
+    /// with additional headers, query parameters, or context from `options`.
+    pub async fn instance_network_interface_view_by_id_with_options<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::NetworkInterface>, Error<types::Error>> {
+        let mut request = self
+            .instance_network_interface_view_by_id_request(id)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[3]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -14250,13 +16178,12 @@ impl Client {
         }
     }
 
-    ///Fetch a network interface by id
-    ///
-    ///Sends a `GET` request to `/by-id/network-interfaces/{id}`
-    pub async fn instance_network_interface_view_by_id<'a>(
+    ///Prepares the request of
+    /// [`Client::instance_network_interface_view_by_id`] without sending it.
+    pub fn instance_network_interface_view_by_id_request<'a>(
         &'a self,
         id: &'a ::uuid::Uuid,
-    ) -> Result<ResponseValue<types::NetworkInterface>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/by-id/network-interfaces/{}",
             self.baseurl,
@@ -14277,13 +16204,48 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_network_interface_view_by_id",
-        };
+        Ok(request)
+    }
+
+    ///Fetch an organization by id
+    ///
+    ///Use `GET /v1/organizations/{organization}` instead
+    ///
+    ///Sends a `GET` request to `/by-id/organizations/{id}`
+    pub async fn organization_view_by_id<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+    ) -> Result<ResponseValue<types::Organization>, Error<types::Error>> {
+        self.organization_view_by_id_with_options(id, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::organization_view_by_id`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn organization_view_by_id_with_options<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Organization>, Error<types::Error>> {
+        let mut request = self
+            .organization_view_by_id_request(id)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[4]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -14296,15 +16258,12 @@ impl Client {
         }
     }
 
-    ///Fetch an organization by id
-    ///
-    ///Use `GET /v1/organizations/{organization}` instead
-    ///
-    ///Sends a `GET` request to `/by-id/organizations/{id}`
-    pub async fn organization_view_by_id<'a>(
+    ///Prepares the request of [`Client::organization_view_by_id`] without
+    /// sending it.
+    pub fn organization_view_by_id_request<'a>(
         &'a self,
         id: &'a ::uuid::Uuid,
-    ) -> Result<ResponseValue<types::Organization>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/by-id/organizations/{}",
             self.baseurl,
@@ -14325,13 +16284,48 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "organization_view_by_id",
-        };
+        Ok(request)
+    }
+
+    ///Fetch a project by id
+    ///
+    ///Use `GET /v1/projects/{project}` instead
+    ///
+    ///Sends a `GET` request to `/by-id/projects/{id}`
+    pub async fn project_view_by_id<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+    ) -> Result<ResponseValue<types::Project>, Error<types::Error>> {
+        self.project_view_by_id_with_options(id, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::project_view_by_id`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn project_view_by_id_with_options<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Project>, Error<types::Error>> {
+        let mut request = self
+            .project_view_by_id_request(id)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[5]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -14344,15 +16338,12 @@ impl Client {
         }
     }
 
-    ///Fetch a project by id
-    ///
-    ///Use `GET /v1/projects/{project}` instead
-    ///
-    ///Sends a `GET` request to `/by-id/projects/{id}`
-    pub async fn project_view_by_id<'a>(
+    ///Prepares the request of [`Client::project_view_by_id`] without sending
+    /// it.
+    pub fn project_view_by_id_request<'a>(
         &'a self,
         id: &'a ::uuid::Uuid,
-    ) -> Result<ResponseValue<types::Project>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/by-id/projects/{}",
             self.baseurl,
@@ -14373,13 +16364,46 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "project_view_by_id",
-        };
+        Ok(request)
+    }
+
+    ///Fetch a snapshot by id
+    ///
+    ///Sends a `GET` request to `/by-id/snapshots/{id}`
+    pub async fn snapshot_view_by_id<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+    ) -> Result<ResponseValue<types::Snapshot>, Error<types::Error>> {
+        self.snapshot_view_by_id_with_options(id, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::snapshot_view_by_id`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn snapshot_view_by_id_with_options<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Snapshot>, Error<types::Error>> {
+        let mut request = self
+            .snapshot_view_by_id_request(id)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[6]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -14392,13 +16416,12 @@ impl Client {
         }
     }
 
-    ///Fetch a snapshot by id
-    ///
-    ///Sends a `GET` request to `/by-id/snapshots/{id}`
-    pub async fn snapshot_view_by_id<'a>(
+    ///Prepares the request of [`Client::snapshot_view_by_id`] without sending
+    /// it.
+    pub fn snapshot_view_by_id_request<'a>(
         &'a self,
         id: &'a ::uuid::Uuid,
-    ) -> Result<ResponseValue<types::Snapshot>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/by-id/snapshots/{}",
             self.baseurl,
@@ -14419,13 +16442,49 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "snapshot_view_by_id",
-        };
+        Ok(request)
+    }
+
+    ///Fetch a route by id
+    ///
+    ///Sends a `GET` request to `/by-id/vpc-router-routes/{id}`
+    pub async fn vpc_router_route_view_by_id<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+    ) -> Result<ResponseValue<types::RouterRoute>, Error<types::Error>> {
+        self.vpc_router_route_view_by_id_with_options(
+            id,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_router_route_view_by_id`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn vpc_router_route_view_by_id_with_options<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::RouterRoute>, Error<types::Error>> {
+        let mut request = self
+            .vpc_router_route_view_by_id_request(id)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[7]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -14438,13 +16497,12 @@ impl Client {
         }
     }
 
-    ///Fetch a route by id
-    ///
-    ///Sends a `GET` request to `/by-id/vpc-router-routes/{id}`
-    pub async fn vpc_router_route_view_by_id<'a>(
+    ///Prepares the request of [`Client::vpc_router_route_view_by_id`] without
+    /// sending it.
+    pub fn vpc_router_route_view_by_id_request<'a>(
         &'a self,
         id: &'a ::uuid::Uuid,
-    ) -> Result<ResponseValue<types::RouterRoute>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/by-id/vpc-router-routes/{}",
             self.baseurl,
@@ -14465,13 +16523,46 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_router_route_view_by_id",
-        };
+        Ok(request)
+    }
+
+    ///Get a router by id
+    ///
+    ///Sends a `GET` request to `/by-id/vpc-routers/{id}`
+    pub async fn vpc_router_view_by_id<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+    ) -> Result<ResponseValue<types::VpcRouter>, Error<types::Error>> {
+        self.vpc_router_view_by_id_with_options(id, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::vpc_router_view_by_id`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn vpc_router_view_by_id_with_options<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::VpcRouter>, Error<types::Error>> {
+        let mut request = self
+            .vpc_router_view_by_id_request(id)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[8]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -14484,13 +16575,12 @@ impl Client {
         }
     }
 
-    ///Get a router by id
-    ///
-    ///Sends a `GET` request to `/by-id/vpc-routers/{id}`
-    pub async fn vpc_router_view_by_id<'a>(
+    ///Prepares the request of [`Client::vpc_router_view_by_id`] without
+    /// sending it.
+    pub fn vpc_router_view_by_id_request<'a>(
         &'a self,
         id: &'a ::uuid::Uuid,
-    ) -> Result<ResponseValue<types::VpcRouter>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/by-id/vpc-routers/{}",
             self.baseurl,
@@ -14511,13 +16601,46 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_router_view_by_id",
-        };
+        Ok(request)
+    }
+
+    ///Fetch a subnet by id
+    ///
+    ///Sends a `GET` request to `/by-id/vpc-subnets/{id}`
+    pub async fn vpc_subnet_view_by_id<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+    ) -> Result<ResponseValue<types::VpcSubnet>, Error<types::Error>> {
+        self.vpc_subnet_view_by_id_with_options(id, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::vpc_subnet_view_by_id`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn vpc_subnet_view_by_id_with_options<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::VpcSubnet>, Error<types::Error>> {
+        let mut request = self
+            .vpc_subnet_view_by_id_request(id)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[9]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -14530,13 +16653,12 @@ impl Client {
         }
     }
 
-    ///Fetch a subnet by id
-    ///
-    ///Sends a `GET` request to `/by-id/vpc-subnets/{id}`
-    pub async fn vpc_subnet_view_by_id<'a>(
+    ///Prepares the request of [`Client::vpc_subnet_view_by_id`] without
+    /// sending it.
+    pub fn vpc_subnet_view_by_id_request<'a>(
         &'a self,
         id: &'a ::uuid::Uuid,
-    ) -> Result<ResponseValue<types::VpcSubnet>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/by-id/vpc-subnets/{}",
             self.baseurl,
@@ -14557,13 +16679,44 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_subnet_view_by_id",
-        };
+        Ok(request)
+    }
+
+    ///Fetch a VPC
+    ///
+    ///Sends a `GET` request to `/by-id/vpcs/{id}`
+    pub async fn vpc_view_by_id<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+    ) -> Result<ResponseValue<types::Vpc>, Error<types::Error>> {
+        self.vpc_view_by_id_with_options(id, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::vpc_view_by_id`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn vpc_view_by_id_with_options<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Vpc>, Error<types::Error>> {
+        let mut request = self.vpc_view_by_id_request(id).map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[10]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -14576,13 +16729,11 @@ impl Client {
         }
     }
 
-    ///Fetch a VPC
-    ///
-    ///Sends a `GET` request to `/by-id/vpcs/{id}`
-    pub async fn vpc_view_by_id<'a>(
+    ///Prepares the request of [`Client::vpc_view_by_id`] without sending it.
+    pub fn vpc_view_by_id_request<'a>(
         &'a self,
         id: &'a ::uuid::Uuid,
-    ) -> Result<ResponseValue<types::Vpc>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/by-id/vpcs/{}",
             self.baseurl,
@@ -14603,23 +16754,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_view_by_id",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Start an OAuth 2.0 Device Authorization Grant
@@ -14633,6 +16768,48 @@ impl Client {
         &'a self,
         body: &'a types::DeviceAuthRequest,
     ) -> Result<ResponseValue<ByteStream>, Error<ByteStream>> {
+        self.device_auth_request_with_options(body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::device_auth_request`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn device_auth_request_with_options<'a>(
+        &'a self,
+        body: &'a types::DeviceAuthRequest,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<ByteStream>, Error<ByteStream>> {
+        let mut request = self
+            .device_auth_request_request(body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[11]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200..=299 => Ok(ResponseValue::stream(response)),
+            _ => Err(Error::ErrorResponse(ResponseValue::stream(response))),
+        }
+    }
+
+    ///Prepares the request of [`Client::device_auth_request`] without sending
+    /// it.
+    pub fn device_auth_request_request<'a>(
+        &'a self,
+        body: &'a types::DeviceAuthRequest,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/device/auth", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -14646,17 +16823,7 @@ impl Client {
             .form_urlencoded(&body)?
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "device_auth_request",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200..=299 => Ok(ResponseValue::stream(response)),
-            _ => Err(Error::ErrorResponse(ResponseValue::stream(response))),
-        }
+        Ok(request)
     }
 
     ///Confirm an OAuth 2.0 Device Authorization Grant
@@ -14671,6 +16838,54 @@ impl Client {
         &'a self,
         body: &'a types::DeviceAuthVerify,
     ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.device_auth_confirm_with_options(body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::device_auth_confirm`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn device_auth_confirm_with_options<'a>(
+        &'a self,
+        body: &'a types::DeviceAuthVerify,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .device_auth_confirm_request(body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[12]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            204u16 => Ok(ResponseValue::empty(response)),
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::device_auth_confirm`] without sending
+    /// it.
+    pub fn device_auth_confirm_request<'a>(
+        &'a self,
+        body: &'a types::DeviceAuthVerify,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/device/confirm", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -14688,23 +16903,7 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "device_auth_confirm",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Request a device access token
@@ -14716,7 +16915,49 @@ impl Client {
     pub async fn device_access_token<'a>(
         &'a self,
         body: &'a types::DeviceAccessTokenRequest,
-    ) -> Result<ResponseValue<ByteStream>, Error<ByteStream>> {
+    ) -> Result<ResponseValue<ByteStream>, Error<ByteStream>> {
+        self.device_access_token_with_options(body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::device_access_token`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn device_access_token_with_options<'a>(
+        &'a self,
+        body: &'a types::DeviceAccessTokenRequest,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<ByteStream>, Error<ByteStream>> {
+        let mut request = self
+            .device_access_token_request(body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[13]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200..=299 => Ok(ResponseValue::stream(response)),
+            _ => Err(Error::ErrorResponse(ResponseValue::stream(response))),
+        }
+    }
+
+    ///Prepares the request of [`Client::device_access_token`] without sending
+    /// it.
+    pub fn device_access_token_request<'a>(
+        &'a self,
+        body: &'a types::DeviceAccessTokenRequest,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/device/token", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -14730,17 +16971,7 @@ impl Client {
             .form_urlencoded(&body)?
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "device_access_token",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200..=299 => Ok(ResponseValue::stream(response)),
-            _ => Err(Error::ErrorResponse(ResponseValue::stream(response))),
-        }
+        Ok(request)
     }
 
     ///List groups
@@ -14758,6 +16989,62 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::IdSortMode>,
     ) -> Result<ResponseValue<types::GroupResultsPage>, Error<types::Error>> {
+        self.group_list_with_options(
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::group_list`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn group_list_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::GroupResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .group_list_request(limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[14]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::group_list`] without sending it.
+    pub fn group_list_request<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/groups", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -14780,23 +17067,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "group_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List groups as a Stream
@@ -14843,6 +17114,51 @@ impl Client {
         &'a self,
         body: &'a types::SpoofLoginBody,
     ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.login_spoof_with_options(body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::login_spoof`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn login_spoof_with_options<'a>(
+        &'a self,
+        body: &'a types::SpoofLoginBody,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self.login_spoof_request(body).map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[15]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            204u16 => Ok(ResponseValue::empty(response)),
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::login_spoof`] without sending it.
+    pub fn login_spoof_request<'a>(
+        &'a self,
+        body: &'a types::SpoofLoginBody,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/login", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -14860,15 +17176,54 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "login_spoof",
-        };
+        Ok(request)
+    }
+
+    ///Authenticate a user (i.e., log in) via username and password
+    ///
+    ///Sends a `POST` request to `/login/{silo_name}/local`
+    pub async fn login_local<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        body: &'a types::UsernamePasswordCredentials,
+    ) -> Result<ResponseValue<ByteStream>, Error<types::Error>> {
+        self.login_local_with_options(
+            silo_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::login_local`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn login_local_with_options<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        body: &'a types::UsernamePasswordCredentials,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<ByteStream>, Error<types::Error>> {
+        let mut request = self
+            .login_local_request(silo_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[16]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
+            200..=299 => Ok(ResponseValue::stream(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -14879,14 +17234,12 @@ impl Client {
         }
     }
 
-    ///Authenticate a user (i.e., log in) via username and password
-    ///
-    ///Sends a `POST` request to `/login/{silo_name}/local`
-    pub async fn login_local<'a>(
+    ///Prepares the request of [`Client::login_local`] without sending it.
+    pub fn login_local_request<'a>(
         &'a self,
         silo_name: &'a types::Name,
         body: &'a types::UsernamePasswordCredentials,
-    ) -> Result<ResponseValue<ByteStream>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/login/{}/local",
             self.baseurl,
@@ -14904,13 +17257,55 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "login_local",
-        };
+        Ok(request)
+    }
+
+    ///Prompt user login
+    ///
+    ///Either display a page asking a user for their credentials, or redirect
+    /// them to their identity provider.
+    ///
+    ///Sends a `GET` request to `/login/{silo_name}/saml/{provider_name}`
+    pub async fn login_saml_begin<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        provider_name: &'a types::Name,
+    ) -> Result<ResponseValue<ByteStream>, Error<types::Error>> {
+        self.login_saml_begin_with_options(
+            silo_name,
+            provider_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::login_saml_begin`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn login_saml_begin_with_options<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        provider_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<ByteStream>, Error<types::Error>> {
+        let mut request = self
+            .login_saml_begin_request(silo_name, provider_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[17]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200..=299 => Ok(ResponseValue::stream(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -14923,17 +17318,12 @@ impl Client {
         }
     }
 
-    ///Prompt user login
-    ///
-    ///Either display a page asking a user for their credentials, or redirect
-    /// them to their identity provider.
-    ///
-    ///Sends a `GET` request to `/login/{silo_name}/saml/{provider_name}`
-    pub async fn login_saml_begin<'a>(
+    ///Prepares the request of [`Client::login_saml_begin`] without sending it.
+    pub fn login_saml_begin_request<'a>(
         &'a self,
         silo_name: &'a types::Name,
         provider_name: &'a types::Name,
-    ) -> Result<ResponseValue<ByteStream>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/login/{}/saml/{}",
             self.baseurl,
@@ -14947,13 +17337,55 @@ impl Client {
         );
         #[allow(unused_mut)]
         let mut request = self.client.get(url).headers(header_map).build()?;
-        let info = OperationInfo {
-            operation_id: "login_saml_begin",
-        };
+        Ok(request)
+    }
+
+    ///Authenticate a user (i.e., log in) via SAML
+    ///
+    ///Sends a `POST` request to `/login/{silo_name}/saml/{provider_name}`
+    pub async fn login_saml<'a, B: Into<progenitor_client::UploadBody>>(
+        &'a self,
+        silo_name: &'a types::Name,
+        provider_name: &'a types::Name,
+        body: B,
+    ) -> Result<ResponseValue<ByteStream>, Error<types::Error>> {
+        self.login_saml_with_options(
+            silo_name,
+            provider_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::login_saml`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn login_saml_with_options<'a, B: Into<progenitor_client::UploadBody>>(
+        &'a self,
+        silo_name: &'a types::Name,
+        provider_name: &'a types::Name,
+        body: B,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<ByteStream>, Error<types::Error>> {
+        let mut request = self
+            .login_saml_request(silo_name, provider_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[18]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200..=299 => Ok(ResponseValue::stream(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -14966,26 +17398,29 @@ impl Client {
         }
     }
 
-    ///Authenticate a user (i.e., log in) via SAML
-    ///
-    ///Sends a `POST` request to `/login/{silo_name}/saml/{provider_name}`
-    pub async fn login_saml<'a, B: Into<reqwest::Body>>(
+    ///Prepares the request of [`Client::login_saml`] without sending it.
+    pub fn login_saml_request<'a, B: Into<progenitor_client::UploadBody>>(
         &'a self,
         silo_name: &'a types::Name,
         provider_name: &'a types::Name,
         body: B,
-    ) -> Result<ResponseValue<ByteStream>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/login/{}/saml/{}",
             self.baseurl,
             encode_path(&silo_name.to_string()),
             encode_path(&provider_name.to_string()),
         );
-        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
         header_map.append(
             ::reqwest::header::HeaderName::from_static("api-version"),
             ::reqwest::header::HeaderValue::from_static(Self::api_version()),
         );
+        let body: progenitor_client::UploadBody = body.into();
+        if let Some(length) = body.content_length() {
+            header_map.append(::reqwest::header::CONTENT_LENGTH, length.into());
+        }
+
         #[allow(unused_mut)]
         let mut request = self
             .client
@@ -14994,18 +17429,43 @@ impl Client {
                 ::reqwest::header::CONTENT_TYPE,
                 ::reqwest::header::HeaderValue::from_static("application/octet-stream"),
             )
-            .body(body)
+            .body(body.into_body())
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "login_saml",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `POST` request to `/logout`
+    pub async fn logout<'a>(&'a self) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.logout_with_options(progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::logout`] with additional headers, query
+    /// parameters, or context from `options`.
+    pub async fn logout_with_options<'a>(
+        &'a self,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self.logout_request().map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[19]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200..=299 => Ok(ResponseValue::stream(response)),
+            204u16 => Ok(ResponseValue::empty(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -15016,8 +17476,10 @@ impl Client {
         }
     }
 
-    ///Sends a `POST` request to `/logout`
-    pub async fn logout<'a>(&'a self) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ///Prepares the request of [`Client::logout`] without sending it.
+    pub fn logout_request<'a>(
+        &'a self,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/logout", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -15034,23 +17496,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "logout",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List organizations
@@ -15070,35 +17516,43 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::NameOrIdSortMode>,
     ) -> Result<ResponseValue<types::OrganizationResultsPage>, Error<types::Error>> {
-        let url = format!("{}/organizations", self.baseurl,);
-        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
-        header_map.append(
-            ::reqwest::header::HeaderName::from_static("api-version"),
-            ::reqwest::header::HeaderValue::from_static(Self::api_version()),
-        );
-        #[allow(unused_mut)]
+        self.organization_list_with_options(
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::organization_list`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn organization_list_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameOrIdSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::OrganizationResultsPage>, Error<types::Error>> {
         let mut request = self
-            .client
-            .get(url)
-            .header(
-                ::reqwest::header::ACCEPT,
-                ::reqwest::header::HeaderValue::from_static("application/json"),
-            )
-            .query(&progenitor_client::QueryParam::new("limit", &limit))
-            .query(&progenitor_client::QueryParam::new(
-                "page_token",
-                &page_token,
-            ))
-            .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
-            .headers(header_map)
-            .build()?;
-        let info = OperationInfo {
-            operation_id: "organization_list",
-        };
+            .organization_list_request(limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[20]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -15111,6 +17565,39 @@ impl Client {
         }
     }
 
+    ///Prepares the request of [`Client::organization_list`] without sending
+    /// it.
+    pub fn organization_list_request<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameOrIdSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+        let url = format!("{}/organizations", self.baseurl,);
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+        header_map.append(
+            ::reqwest::header::HeaderName::from_static("api-version"),
+            ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+        );
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .query(&progenitor_client::QueryParam::new("limit", &limit))
+            .query(&progenitor_client::QueryParam::new(
+                "page_token",
+                &page_token,
+            ))
+            .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
+            .headers(header_map)
+            .build()?;
+        Ok(request)
+    }
+
     ///List organizations as a Stream
     ///
     ///Use `GET /v1/organizations` instead
@@ -15162,6 +17649,54 @@ impl Client {
         &'a self,
         body: &'a types::OrganizationCreate,
     ) -> Result<ResponseValue<types::Organization>, Error<types::Error>> {
+        self.organization_create_with_options(body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::organization_create`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn organization_create_with_options<'a>(
+        &'a self,
+        body: &'a types::OrganizationCreate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Organization>, Error<types::Error>> {
+        let mut request = self
+            .organization_create_request(body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[21]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::organization_create`] without sending
+    /// it.
+    pub fn organization_create_request<'a>(
+        &'a self,
+        body: &'a types::OrganizationCreate,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/organizations", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -15179,23 +17714,7 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "organization_create",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Fetch an organization
@@ -15210,6 +17729,57 @@ impl Client {
         &'a self,
         organization_name: &'a types::Name,
     ) -> Result<ResponseValue<types::Organization>, Error<types::Error>> {
+        self.organization_view_with_options(
+            organization_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::organization_view`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn organization_view_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Organization>, Error<types::Error>> {
+        let mut request = self
+            .organization_view_request(organization_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[22]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::organization_view`] without sending
+    /// it.
+    pub fn organization_view_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}",
             self.baseurl,
@@ -15230,23 +17800,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "organization_view",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Update an organization
@@ -15263,6 +17817,60 @@ impl Client {
         organization_name: &'a types::Name,
         body: &'a types::OrganizationUpdate,
     ) -> Result<ResponseValue<types::Organization>, Error<types::Error>> {
+        self.organization_update_with_options(
+            organization_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::organization_update`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn organization_update_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        body: &'a types::OrganizationUpdate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Organization>, Error<types::Error>> {
+        let mut request = self
+            .organization_update_request(organization_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[23]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::organization_update`] without sending
+    /// it.
+    pub fn organization_update_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        body: &'a types::OrganizationUpdate,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}",
             self.baseurl,
@@ -15284,23 +17892,7 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "organization_update",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Delete an organization
@@ -15315,6 +17907,57 @@ impl Client {
         &'a self,
         organization_name: &'a types::Name,
     ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.organization_delete_with_options(
+            organization_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::organization_delete`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn organization_delete_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .organization_delete_request(organization_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[24]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            204u16 => Ok(ResponseValue::empty(response)),
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::organization_delete`] without sending
+    /// it.
+    pub fn organization_delete_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}",
             self.baseurl,
@@ -15335,23 +17978,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "organization_delete",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Fetch an organization's IAM policy
@@ -15366,6 +17993,57 @@ impl Client {
         &'a self,
         organization_name: &'a types::Name,
     ) -> Result<ResponseValue<types::OrganizationRolePolicy>, Error<types::Error>> {
+        self.organization_policy_view_with_options(
+            organization_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::organization_policy_view`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn organization_policy_view_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::OrganizationRolePolicy>, Error<types::Error>> {
+        let mut request = self
+            .organization_policy_view_request(organization_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[25]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::organization_policy_view`] without
+    /// sending it.
+    pub fn organization_policy_view_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/policy",
             self.baseurl,
@@ -15386,23 +18064,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "organization_policy_view",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Update an organization's IAM policy
@@ -15419,34 +18081,41 @@ impl Client {
         organization_name: &'a types::Name,
         body: &'a types::OrganizationRolePolicy,
     ) -> Result<ResponseValue<types::OrganizationRolePolicy>, Error<types::Error>> {
-        let url = format!(
-            "{}/organizations/{}/policy",
-            self.baseurl,
-            encode_path(&organization_name.to_string()),
-        );
-        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
-        header_map.append(
-            ::reqwest::header::HeaderName::from_static("api-version"),
-            ::reqwest::header::HeaderValue::from_static(Self::api_version()),
-        );
-        #[allow(unused_mut)]
+        self.organization_policy_update_with_options(
+            organization_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::organization_policy_update`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn organization_policy_update_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        body: &'a types::OrganizationRolePolicy,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::OrganizationRolePolicy>, Error<types::Error>> {
         let mut request = self
-            .client
-            .put(url)
-            .header(
-                ::reqwest::header::ACCEPT,
-                ::reqwest::header::HeaderValue::from_static("application/json"),
-            )
-            .json(&body)
-            .headers(header_map)
-            .build()?;
-        let info = OperationInfo {
-            operation_id: "organization_policy_update",
-        };
+            .organization_policy_update_request(organization_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[26]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -15459,6 +18128,37 @@ impl Client {
         }
     }
 
+    ///Prepares the request of [`Client::organization_policy_update`] without
+    /// sending it.
+    pub fn organization_policy_update_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        body: &'a types::OrganizationRolePolicy,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+        let url = format!(
+            "{}/organizations/{}/policy",
+            self.baseurl,
+            encode_path(&organization_name.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+        header_map.append(
+            ::reqwest::header::HeaderName::from_static("api-version"),
+            ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+        );
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .put(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .json(&body)
+            .headers(header_map)
+            .build()?;
+        Ok(request)
+    }
+
     ///List projects
     ///
     ///Use `GET /v1/projects` instead
@@ -15478,6 +18178,65 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::NameOrIdSortMode>,
     ) -> Result<ResponseValue<types::ProjectResultsPage>, Error<types::Error>> {
+        self.project_list_with_options(
+            organization_name,
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::project_list`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn project_list_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameOrIdSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::ProjectResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .project_list_request(organization_name, limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[27]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::project_list`] without sending it.
+    pub fn project_list_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameOrIdSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects",
             self.baseurl,
@@ -15504,23 +18263,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "project_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List projects as a Stream
@@ -15581,6 +18324,59 @@ impl Client {
         organization_name: &'a types::Name,
         body: &'a types::ProjectCreate,
     ) -> Result<ResponseValue<types::Project>, Error<types::Error>> {
+        self.project_create_with_options(
+            organization_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::project_create`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn project_create_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        body: &'a types::ProjectCreate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Project>, Error<types::Error>> {
+        let mut request = self
+            .project_create_request(organization_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[28]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::project_create`] without sending it.
+    pub fn project_create_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        body: &'a types::ProjectCreate,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects",
             self.baseurl,
@@ -15602,23 +18398,7 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "project_create",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Fetch a project
@@ -15636,6 +18416,59 @@ impl Client {
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
     ) -> Result<ResponseValue<types::Project>, Error<types::Error>> {
+        self.project_view_with_options(
+            organization_name,
+            project_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::project_view`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn project_view_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Project>, Error<types::Error>> {
+        let mut request = self
+            .project_view_request(organization_name, project_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[29]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::project_view`] without sending it.
+    pub fn project_view_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}",
             self.baseurl,
@@ -15657,23 +18490,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "project_view",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Update a project
@@ -15693,6 +18510,62 @@ impl Client {
         project_name: &'a types::Name,
         body: &'a types::ProjectUpdate,
     ) -> Result<ResponseValue<types::Project>, Error<types::Error>> {
+        self.project_update_with_options(
+            organization_name,
+            project_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::project_update`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn project_update_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        body: &'a types::ProjectUpdate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Project>, Error<types::Error>> {
+        let mut request = self
+            .project_update_request(organization_name, project_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[30]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::project_update`] without sending it.
+    pub fn project_update_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        body: &'a types::ProjectUpdate,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}",
             self.baseurl,
@@ -15715,23 +18588,7 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "project_update",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Delete a project
@@ -15749,6 +18606,59 @@ impl Client {
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
     ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.project_delete_with_options(
+            organization_name,
+            project_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::project_delete`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn project_delete_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .project_delete_request(organization_name, project_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[31]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            204u16 => Ok(ResponseValue::empty(response)),
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::project_delete`] without sending it.
+    pub fn project_delete_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}",
             self.baseurl,
@@ -15770,15 +18680,74 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "project_delete",
-        };
+        Ok(request)
+    }
+
+    ///List disks
+    ///
+    ///Use `GET /v1/disks` instead
+    ///
+    ///Sends a `GET` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/disks`
+    ///
+    ///Arguments:
+    /// - `organization_name`: The organization's unique name.
+    /// - `project_name`: The project's unique name within the organization.
+    /// - `limit`: Maximum number of items returned by a single call
+    /// - `page_token`: Token returned by previous call to retrieve the
+    ///   subsequent page
+    /// - `sort_by`
+    pub async fn disk_list<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+    ) -> Result<ResponseValue<types::DiskResultsPage>, Error<types::Error>> {
+        self.disk_list_with_options(
+            organization_name,
+            project_name,
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::disk_list`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn disk_list_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::DiskResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .disk_list_request(organization_name, project_name, limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[32]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -15789,28 +18758,15 @@ impl Client {
         }
     }
 
-    ///List disks
-    ///
-    ///Use `GET /v1/disks` instead
-    ///
-    ///Sends a `GET` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/disks`
-    ///
-    ///Arguments:
-    /// - `organization_name`: The organization's unique name.
-    /// - `project_name`: The project's unique name within the organization.
-    /// - `limit`: Maximum number of items returned by a single call
-    /// - `page_token`: Token returned by previous call to retrieve the
-    ///   subsequent page
-    /// - `sort_by`
-    pub async fn disk_list<'a>(
+    ///Prepares the request of [`Client::disk_list`] without sending it.
+    pub fn disk_list_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         limit: Option<::std::num::NonZeroU32>,
         page_token: Option<&'a str>,
         sort_by: Option<types::NameSortMode>,
-    ) -> Result<ResponseValue<types::DiskResultsPage>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/disks",
             self.baseurl,
@@ -15838,23 +18794,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "disk_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List disks as a Stream
@@ -15924,6 +18864,62 @@ impl Client {
         project_name: &'a types::Name,
         body: &'a types::DiskCreate,
     ) -> Result<ResponseValue<types::Disk>, Error<types::Error>> {
+        self.disk_create_with_options(
+            organization_name,
+            project_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::disk_create`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn disk_create_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        body: &'a types::DiskCreate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Disk>, Error<types::Error>> {
+        let mut request = self
+            .disk_create_request(organization_name, project_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[33]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::disk_create`] without sending it.
+    pub fn disk_create_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        body: &'a types::DiskCreate,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/disks",
             self.baseurl,
@@ -15946,15 +18942,61 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "disk_create",
-        };
+        Ok(request)
+    }
+
+    ///Fetch a disk
+    ///
+    ///Use `GET /v1/disks/{disk}` instead
+    ///
+    ///Sends a `GET` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/disks/
+    /// {disk_name}`
+    pub async fn disk_view<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        disk_name: &'a types::Name,
+    ) -> Result<ResponseValue<types::Disk>, Error<types::Error>> {
+        self.disk_view_with_options(
+            organization_name,
+            project_name,
+            disk_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::disk_view`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn disk_view_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        disk_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Disk>, Error<types::Error>> {
+        let mut request = self
+            .disk_view_request(organization_name, project_name, disk_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[34]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -15965,19 +19007,13 @@ impl Client {
         }
     }
 
-    ///Fetch a disk
-    ///
-    ///Use `GET /v1/disks/{disk}` instead
-    ///
-    ///Sends a `GET` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/disks/
-    /// {disk_name}`
-    pub async fn disk_view<'a>(
+    ///Prepares the request of [`Client::disk_view`] without sending it.
+    pub fn disk_view_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         disk_name: &'a types::Name,
-    ) -> Result<ResponseValue<types::Disk>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/disks/{}",
             self.baseurl,
@@ -16000,15 +19036,59 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "disk_view",
-        };
+        Ok(request)
+    }
+
+    ///Use `DELETE /v1/disks/{disk}` instead
+    ///
+    ///Sends a `DELETE` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/disks/
+    /// {disk_name}`
+    pub async fn disk_delete<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        disk_name: &'a types::Name,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.disk_delete_with_options(
+            organization_name,
+            project_name,
+            disk_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::disk_delete`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn disk_delete_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        disk_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .disk_delete_request(organization_name, project_name, disk_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[35]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            204u16 => Ok(ResponseValue::empty(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -16019,17 +19099,13 @@ impl Client {
         }
     }
 
-    ///Use `DELETE /v1/disks/{disk}` instead
-    ///
-    ///Sends a `DELETE` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/disks/
-    /// {disk_name}`
-    pub async fn disk_delete<'a>(
+    ///Prepares the request of [`Client::disk_delete`] without sending it.
+    pub fn disk_delete_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         disk_name: &'a types::Name,
-    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/disks/{}",
             self.baseurl,
@@ -16052,23 +19128,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "disk_delete",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Fetch disk metrics
@@ -16098,6 +19158,87 @@ impl Client {
         page_token: Option<&'a str>,
         start_time: Option<&'a ::chrono::DateTime<::chrono::offset::Utc>>,
     ) -> Result<ResponseValue<types::MeasurementResultsPage>, Error<types::Error>> {
+        self.disk_metrics_list_with_options(
+            organization_name,
+            project_name,
+            disk_name,
+            metric_name,
+            end_time,
+            limit,
+            page_token,
+            start_time,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::disk_metrics_list`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn disk_metrics_list_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        disk_name: &'a types::Name,
+        metric_name: types::DiskMetricName,
+        end_time: Option<&'a ::chrono::DateTime<::chrono::offset::Utc>>,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        start_time: Option<&'a ::chrono::DateTime<::chrono::offset::Utc>>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::MeasurementResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .disk_metrics_list_request(
+                organization_name,
+                project_name,
+                disk_name,
+                metric_name,
+                end_time,
+                limit,
+                page_token,
+                start_time,
+            )
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[36]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::disk_metrics_list`] without sending
+    /// it.
+    pub fn disk_metrics_list_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        disk_name: &'a types::Name,
+        metric_name: types::DiskMetricName,
+        end_time: Option<&'a ::chrono::DateTime<::chrono::offset::Utc>>,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        start_time: Option<&'a ::chrono::DateTime<::chrono::offset::Utc>>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/disks/{}/metrics/{}",
             self.baseurl,
@@ -16131,23 +19272,7 @@ impl Client {
             ))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "disk_metrics_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Fetch disk metrics as a Stream
@@ -16219,29 +19344,91 @@ impl Client {
         .boxed()
     }
 
-    ///List images
-    ///
-    ///List images in a project. The images are returned sorted by creation
-    /// date, with the most recent images appearing first.
-    ///
-    ///Sends a `GET` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/images`
-    ///
-    ///Arguments:
-    /// - `organization_name`: The organization's unique name.
-    /// - `project_name`: The project's unique name within the organization.
-    /// - `limit`: Maximum number of items returned by a single call
-    /// - `page_token`: Token returned by previous call to retrieve the
-    ///   subsequent page
-    /// - `sort_by`
-    pub async fn image_list<'a>(
+    ///List images
+    ///
+    ///List images in a project. The images are returned sorted by creation
+    /// date, with the most recent images appearing first.
+    ///
+    ///Sends a `GET` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/images`
+    ///
+    ///Arguments:
+    /// - `organization_name`: The organization's unique name.
+    /// - `project_name`: The project's unique name within the organization.
+    /// - `limit`: Maximum number of items returned by a single call
+    /// - `page_token`: Token returned by previous call to retrieve the
+    ///   subsequent page
+    /// - `sort_by`
+    pub async fn image_list<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+    ) -> Result<ResponseValue<types::ImageResultsPage>, Error<types::Error>> {
+        self.image_list_with_options(
+            organization_name,
+            project_name,
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::image_list`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn image_list_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::ImageResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .image_list_request(organization_name, project_name, limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[37]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::image_list`] without sending it.
+    pub fn image_list_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         limit: Option<::std::num::NonZeroU32>,
         page_token: Option<&'a str>,
         sort_by: Option<types::NameSortMode>,
-    ) -> Result<ResponseValue<types::ImageResultsPage>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/images",
             self.baseurl,
@@ -16269,23 +19456,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "image_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List images as a Stream
@@ -16358,6 +19529,62 @@ impl Client {
         project_name: &'a types::Name,
         body: &'a types::ImageCreate,
     ) -> Result<ResponseValue<types::Image>, Error<types::Error>> {
+        self.image_create_with_options(
+            organization_name,
+            project_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::image_create`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn image_create_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        body: &'a types::ImageCreate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Image>, Error<types::Error>> {
+        let mut request = self
+            .image_create_request(organization_name, project_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[38]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::image_create`] without sending it.
+    pub fn image_create_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        body: &'a types::ImageCreate,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/images",
             self.baseurl,
@@ -16380,15 +19607,61 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "image_create",
-        };
+        Ok(request)
+    }
+
+    ///Fetch an image
+    ///
+    ///Fetch the details for a specific image in a project.
+    ///
+    ///Sends a `GET` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/images/
+    /// {image_name}`
+    pub async fn image_view<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        image_name: &'a types::Name,
+    ) -> Result<ResponseValue<types::Image>, Error<types::Error>> {
+        self.image_view_with_options(
+            organization_name,
+            project_name,
+            image_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::image_view`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn image_view_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        image_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Image>, Error<types::Error>> {
+        let mut request = self
+            .image_view_request(organization_name, project_name, image_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[39]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -16399,19 +19672,13 @@ impl Client {
         }
     }
 
-    ///Fetch an image
-    ///
-    ///Fetch the details for a specific image in a project.
-    ///
-    ///Sends a `GET` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/images/
-    /// {image_name}`
-    pub async fn image_view<'a>(
+    ///Prepares the request of [`Client::image_view`] without sending it.
+    pub fn image_view_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         image_name: &'a types::Name,
-    ) -> Result<ResponseValue<types::Image>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/images/{}",
             self.baseurl,
@@ -16434,23 +19701,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "image_view",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Delete an image
@@ -16468,6 +19719,62 @@ impl Client {
         project_name: &'a types::Name,
         image_name: &'a types::Name,
     ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.image_delete_with_options(
+            organization_name,
+            project_name,
+            image_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::image_delete`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn image_delete_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        image_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .image_delete_request(organization_name, project_name, image_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[40]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            204u16 => Ok(ResponseValue::empty(response)),
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::image_delete`] without sending it.
+    pub fn image_delete_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        image_name: &'a types::Name,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/images/{}",
             self.baseurl,
@@ -16490,23 +19797,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "image_delete",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List instances
@@ -16529,6 +19820,68 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::NameSortMode>,
     ) -> Result<ResponseValue<types::InstanceResultsPage>, Error<types::Error>> {
+        self.instance_list_with_options(
+            organization_name,
+            project_name,
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_list`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn instance_list_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::InstanceResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .instance_list_request(organization_name, project_name, limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[41]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::instance_list`] without sending it.
+    pub fn instance_list_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/instances",
             self.baseurl,
@@ -16556,23 +19909,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List instances as a Stream
@@ -16626,23 +19963,79 @@ impl Client {
             .boxed()
     }
 
-    ///Create an instance
-    ///
-    ///Use `POST /v1/instances` instead
-    ///
-    ///Sends a `POST` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/instances`
-    ///
-    ///Arguments:
-    /// - `organization_name`: The organization's unique name.
-    /// - `project_name`: The project's unique name within the organization.
-    /// - `body`
-    pub async fn instance_create<'a>(
+    ///Create an instance
+    ///
+    ///Use `POST /v1/instances` instead
+    ///
+    ///Sends a `POST` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/instances`
+    ///
+    ///Arguments:
+    /// - `organization_name`: The organization's unique name.
+    /// - `project_name`: The project's unique name within the organization.
+    /// - `body`
+    pub async fn instance_create<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        body: &'a types::InstanceCreate,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        self.instance_create_with_options(
+            organization_name,
+            project_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_create`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_create_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        body: &'a types::InstanceCreate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        let mut request = self
+            .instance_create_request(organization_name, project_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[42]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::instance_create`] without sending it.
+    pub fn instance_create_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         body: &'a types::InstanceCreate,
-    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/instances",
             self.baseurl,
@@ -16665,15 +20058,61 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_create",
-        };
+        Ok(request)
+    }
+
+    ///Fetch an instance
+    ///
+    ///Use `GET /v1/instances/{instance}` instead
+    ///
+    ///Sends a `GET` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/instances/
+    /// {instance_name}`
+    pub async fn instance_view<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        self.instance_view_with_options(
+            organization_name,
+            project_name,
+            instance_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_view`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn instance_view_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        let mut request = self
+            .instance_view_request(organization_name, project_name, instance_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[43]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -16684,19 +20123,13 @@ impl Client {
         }
     }
 
-    ///Fetch an instance
-    ///
-    ///Use `GET /v1/instances/{instance}` instead
-    ///
-    ///Sends a `GET` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/instances/
-    /// {instance_name}`
-    pub async fn instance_view<'a>(
+    ///Prepares the request of [`Client::instance_view`] without sending it.
+    pub fn instance_view_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         instance_name: &'a types::Name,
-    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/instances/{}",
             self.baseurl,
@@ -16719,15 +20152,59 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_view",
-        };
+        Ok(request)
+    }
+
+    ///Delete an instance
+    ///
+    ///Sends a `DELETE` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/instances/
+    /// {instance_name}`
+    pub async fn instance_delete<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.instance_delete_with_options(
+            organization_name,
+            project_name,
+            instance_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_delete`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_delete_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .instance_delete_request(organization_name, project_name, instance_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[44]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            204u16 => Ok(ResponseValue::empty(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -16738,17 +20215,13 @@ impl Client {
         }
     }
 
-    ///Delete an instance
-    ///
-    ///Sends a `DELETE` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/instances/
-    /// {instance_name}`
-    pub async fn instance_delete<'a>(
+    ///Prepares the request of [`Client::instance_delete`] without sending it.
+    pub fn instance_delete_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         instance_name: &'a types::Name,
-    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/instances/{}",
             self.baseurl,
@@ -16771,23 +20244,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_delete",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List an instance's disks
@@ -16815,6 +20272,79 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::NameSortMode>,
     ) -> Result<ResponseValue<types::DiskResultsPage>, Error<types::Error>> {
+        self.instance_disk_list_with_options(
+            organization_name,
+            project_name,
+            instance_name,
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_disk_list`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_disk_list_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::DiskResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .instance_disk_list_request(
+                organization_name,
+                project_name,
+                instance_name,
+                limit,
+                page_token,
+                sort_by,
+            )
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[45]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::instance_disk_list`] without sending
+    /// it.
+    pub fn instance_disk_list_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/instances/{}/disks",
             self.baseurl,
@@ -16843,23 +20373,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_disk_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List an instance's disks as a Stream
@@ -16938,36 +20452,145 @@ impl Client {
         instance_name: &'a types::Name,
         body: &'a types::DiskIdentifier,
     ) -> Result<ResponseValue<types::Disk>, Error<types::Error>> {
-        let url = format!(
-            "{}/organizations/{}/projects/{}/instances/{}/disks/attach",
-            self.baseurl,
-            encode_path(&organization_name.to_string()),
-            encode_path(&project_name.to_string()),
-            encode_path(&instance_name.to_string()),
-        );
-        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
-        header_map.append(
-            ::reqwest::header::HeaderName::from_static("api-version"),
-            ::reqwest::header::HeaderValue::from_static(Self::api_version()),
-        );
-        #[allow(unused_mut)]
+        self.instance_disk_attach_with_options(
+            organization_name,
+            project_name,
+            instance_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_disk_attach`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_disk_attach_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        body: &'a types::DiskIdentifier,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Disk>, Error<types::Error>> {
+        let mut request = self
+            .instance_disk_attach_request(organization_name, project_name, instance_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[46]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            202u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::instance_disk_attach`] without sending
+    /// it.
+    pub fn instance_disk_attach_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        body: &'a types::DiskIdentifier,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+        let url = format!(
+            "{}/organizations/{}/projects/{}/instances/{}/disks/attach",
+            self.baseurl,
+            encode_path(&organization_name.to_string()),
+            encode_path(&project_name.to_string()),
+            encode_path(&instance_name.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+        header_map.append(
+            ::reqwest::header::HeaderName::from_static("api-version"),
+            ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+        );
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .post(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .json(&body)
+            .headers(header_map)
+            .build()?;
+        Ok(request)
+    }
+
+    ///Detach a disk from an instance
+    ///
+    ///Use `POST /v1/disks/{disk}/detach` instead
+    ///
+    ///Sends a `POST` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/instances/
+    /// {instance_name}/disks/detach`
+    pub async fn instance_disk_detach<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        body: &'a types::DiskIdentifier,
+    ) -> Result<ResponseValue<types::Disk>, Error<types::Error>> {
+        self.instance_disk_detach_with_options(
+            organization_name,
+            project_name,
+            instance_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_disk_detach`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_disk_detach_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        body: &'a types::DiskIdentifier,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Disk>, Error<types::Error>> {
         let mut request = self
-            .client
-            .post(url)
-            .header(
-                ::reqwest::header::ACCEPT,
-                ::reqwest::header::HeaderValue::from_static("application/json"),
-            )
-            .json(&body)
-            .headers(header_map)
-            .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_disk_attach",
-        };
+            .instance_disk_detach_request(organization_name, project_name, instance_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[47]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             202u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -16980,20 +20603,15 @@ impl Client {
         }
     }
 
-    ///Detach a disk from an instance
-    ///
-    ///Use `POST /v1/disks/{disk}/detach` instead
-    ///
-    ///Sends a `POST` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/instances/
-    /// {instance_name}/disks/detach`
-    pub async fn instance_disk_detach<'a>(
+    ///Prepares the request of [`Client::instance_disk_detach`] without sending
+    /// it.
+    pub fn instance_disk_detach_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         instance_name: &'a types::Name,
         body: &'a types::DiskIdentifier,
-    ) -> Result<ResponseValue<types::Disk>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/instances/{}/disks/detach",
             self.baseurl,
@@ -17017,15 +20635,59 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_disk_detach",
-        };
+        Ok(request)
+    }
+
+    ///List external IP addresses
+    ///
+    ///Sends a `GET` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/instances/
+    /// {instance_name}/external-ips`
+    pub async fn instance_external_ip_list<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+    ) -> Result<ResponseValue<types::ExternalIpResultsPage>, Error<types::Error>> {
+        self.instance_external_ip_list_with_options(
+            organization_name,
+            project_name,
+            instance_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_external_ip_list`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn instance_external_ip_list_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::ExternalIpResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .instance_external_ip_list_request(organization_name, project_name, instance_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[48]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            202u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -17036,17 +20698,14 @@ impl Client {
         }
     }
 
-    ///List external IP addresses
-    ///
-    ///Sends a `GET` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/instances/
-    /// {instance_name}/external-ips`
-    pub async fn instance_external_ip_list<'a>(
+    ///Prepares the request of [`Client::instance_external_ip_list`] without
+    /// sending it.
+    pub fn instance_external_ip_list_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         instance_name: &'a types::Name,
-    ) -> Result<ResponseValue<types::ExternalIpResultsPage>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/instances/{}/external-ips",
             self.baseurl,
@@ -17069,13 +20728,62 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_external_ip_list",
-        };
+        Ok(request)
+    }
+
+    ///Migrate an instance
+    ///
+    ///Use `POST /v1/instances/{instance}/migrate` instead
+    ///
+    ///Sends a `POST` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/instances/
+    /// {instance_name}/migrate`
+    pub async fn instance_migrate<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        body: &'a types::InstanceMigrate,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        self.instance_migrate_with_options(
+            organization_name,
+            project_name,
+            instance_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_migrate`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_migrate_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        body: &'a types::InstanceMigrate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        let mut request = self
+            .instance_migrate_request(organization_name, project_name, instance_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[49]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -17088,20 +20796,14 @@ impl Client {
         }
     }
 
-    ///Migrate an instance
-    ///
-    ///Use `POST /v1/instances/{instance}/migrate` instead
-    ///
-    ///Sends a `POST` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/instances/
-    /// {instance_name}/migrate`
-    pub async fn instance_migrate<'a>(
+    ///Prepares the request of [`Client::instance_migrate`] without sending it.
+    pub fn instance_migrate_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         instance_name: &'a types::Name,
         body: &'a types::InstanceMigrate,
-    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/instances/{}/migrate",
             self.baseurl,
@@ -17125,23 +20827,7 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_migrate",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List network interfaces
@@ -17167,6 +20853,79 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::NameSortMode>,
     ) -> Result<ResponseValue<types::NetworkInterfaceResultsPage>, Error<types::Error>> {
+        self.instance_network_interface_list_with_options(
+            organization_name,
+            project_name,
+            instance_name,
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_network_interface_list`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn instance_network_interface_list_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::NetworkInterfaceResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .instance_network_interface_list_request(
+                organization_name,
+                project_name,
+                instance_name,
+                limit,
+                page_token,
+                sort_by,
+            )
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[50]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::instance_network_interface_list`]
+    /// without sending it.
+    pub fn instance_network_interface_list_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/instances/{}/network-interfaces",
             self.baseurl,
@@ -17195,23 +20954,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_network_interface_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List network interfaces as a Stream
@@ -17287,6 +21030,71 @@ impl Client {
         instance_name: &'a types::Name,
         body: &'a types::NetworkInterfaceCreate,
     ) -> Result<ResponseValue<types::NetworkInterface>, Error<types::Error>> {
+        self.instance_network_interface_create_with_options(
+            organization_name,
+            project_name,
+            instance_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_network_interface_create`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn instance_network_interface_create_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        body: &'a types::NetworkInterfaceCreate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::NetworkInterface>, Error<types::Error>> {
+        let mut request = self
+            .instance_network_interface_create_request(
+                organization_name,
+                project_name,
+                instance_name,
+                body,
+            )
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[51]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::instance_network_interface_create`]
+    /// without sending it.
+    pub fn instance_network_interface_create_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        body: &'a types::NetworkInterfaceCreate,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/instances/{}/network-interfaces",
             self.baseurl,
@@ -17310,15 +21118,67 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_network_interface_create",
+        Ok(request)
+    }
+
+    ///Fetch a network interface
+    ///
+    ///Sends a `GET` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/instances/
+    /// {instance_name}/network-interfaces/{interface_name}`
+    pub async fn instance_network_interface_view<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        interface_name: &'a types::Name,
+    ) -> Result<ResponseValue<types::NetworkInterface>, Error<types::Error>> {
+        self.instance_network_interface_view_with_options(
+            organization_name,
+            project_name,
+            instance_name,
+            interface_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_network_interface_view`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn instance_network_interface_view_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        interface_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::NetworkInterface>, Error<types::Error>> {
+        let mut request = self
+            .instance_network_interface_view_request(
+                organization_name,
+                project_name,
+                instance_name,
+                interface_name,
+            )
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[52]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
         };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -17329,18 +21189,15 @@ impl Client {
         }
     }
 
-    ///Fetch a network interface
-    ///
-    ///Sends a `GET` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/instances/
-    /// {instance_name}/network-interfaces/{interface_name}`
-    pub async fn instance_network_interface_view<'a>(
+    ///Prepares the request of [`Client::instance_network_interface_view`]
+    /// without sending it.
+    pub fn instance_network_interface_view_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         instance_name: &'a types::Name,
         interface_name: &'a types::Name,
-    ) -> Result<ResponseValue<types::NetworkInterface>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/instances/{}/network-interfaces/{}",
             self.baseurl,
@@ -17364,13 +21221,69 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_network_interface_view",
-        };
+        Ok(request)
+    }
+
+    ///Update a network interface
+    ///
+    ///Sends a `PUT` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/instances/
+    /// {instance_name}/network-interfaces/{interface_name}`
+    pub async fn instance_network_interface_update<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        interface_name: &'a types::Name,
+        body: &'a types::NetworkInterfaceUpdate,
+    ) -> Result<ResponseValue<types::NetworkInterface>, Error<types::Error>> {
+        self.instance_network_interface_update_with_options(
+            organization_name,
+            project_name,
+            instance_name,
+            interface_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_network_interface_update`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn instance_network_interface_update_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        interface_name: &'a types::Name,
+        body: &'a types::NetworkInterfaceUpdate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::NetworkInterface>, Error<types::Error>> {
+        let mut request = self
+            .instance_network_interface_update_request(
+                organization_name,
+                project_name,
+                instance_name,
+                interface_name,
+                body,
+            )
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[53]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -17383,19 +21296,16 @@ impl Client {
         }
     }
 
-    ///Update a network interface
-    ///
-    ///Sends a `PUT` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/instances/
-    /// {instance_name}/network-interfaces/{interface_name}`
-    pub async fn instance_network_interface_update<'a>(
+    ///Prepares the request of [`Client::instance_network_interface_update`]
+    /// without sending it.
+    pub fn instance_network_interface_update_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         instance_name: &'a types::Name,
         interface_name: &'a types::Name,
         body: &'a types::NetworkInterfaceUpdate,
-    ) -> Result<ResponseValue<types::NetworkInterface>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/instances/{}/network-interfaces/{}",
             self.baseurl,
@@ -17420,23 +21330,7 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_network_interface_update",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Delete a network interface
@@ -17456,6 +21350,71 @@ impl Client {
         instance_name: &'a types::Name,
         interface_name: &'a types::Name,
     ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.instance_network_interface_delete_with_options(
+            organization_name,
+            project_name,
+            instance_name,
+            interface_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_network_interface_delete`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn instance_network_interface_delete_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        interface_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .instance_network_interface_delete_request(
+                organization_name,
+                project_name,
+                instance_name,
+                interface_name,
+            )
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[54]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            204u16 => Ok(ResponseValue::empty(response)),
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::instance_network_interface_delete`]
+    /// without sending it.
+    pub fn instance_network_interface_delete_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        interface_name: &'a types::Name,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/instances/{}/network-interfaces/{}",
             self.baseurl,
@@ -17479,15 +21438,61 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_network_interface_delete",
-        };
+        Ok(request)
+    }
+
+    ///Reboot an instance
+    ///
+    ///Use `POST /v1/instances/{instance}/reboot` instead
+    ///
+    ///Sends a `POST` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/instances/
+    /// {instance_name}/reboot`
+    pub async fn instance_reboot<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        self.instance_reboot_with_options(
+            organization_name,
+            project_name,
+            instance_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_reboot`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_reboot_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        let mut request = self
+            .instance_reboot_request(organization_name, project_name, instance_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[55]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
+            202u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -17498,19 +21503,13 @@ impl Client {
         }
     }
 
-    ///Reboot an instance
-    ///
-    ///Use `POST /v1/instances/{instance}/reboot` instead
-    ///
-    ///Sends a `POST` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/instances/
-    /// {instance_name}/reboot`
-    pub async fn instance_reboot<'a>(
+    ///Prepares the request of [`Client::instance_reboot`] without sending it.
+    pub fn instance_reboot_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         instance_name: &'a types::Name,
-    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/instances/{}/reboot",
             self.baseurl,
@@ -17533,23 +21532,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_reboot",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            202u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Fetch an instance's serial console
@@ -17584,6 +21567,79 @@ impl Client {
         max_bytes: Option<u64>,
         most_recent: Option<u64>,
     ) -> Result<ResponseValue<types::InstanceSerialConsoleData>, Error<types::Error>> {
+        self.instance_serial_console_with_options(
+            organization_name,
+            project_name,
+            instance_name,
+            from_start,
+            max_bytes,
+            most_recent,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_serial_console`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_serial_console_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        from_start: Option<u64>,
+        max_bytes: Option<u64>,
+        most_recent: Option<u64>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::InstanceSerialConsoleData>, Error<types::Error>> {
+        let mut request = self
+            .instance_serial_console_request(
+                organization_name,
+                project_name,
+                instance_name,
+                from_start,
+                max_bytes,
+                most_recent,
+            )
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[56]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::instance_serial_console`] without
+    /// sending it.
+    pub fn instance_serial_console_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        from_start: Option<u64>,
+        max_bytes: Option<u64>,
+        most_recent: Option<u64>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/instances/{}/serial-console",
             self.baseurl,
@@ -17615,23 +21671,7 @@ impl Client {
             ))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_serial_console",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Connect to an instance's serial console
@@ -17647,6 +21687,58 @@ impl Client {
         project_name: &'a types::Name,
         instance_name: &'a types::Name,
     ) -> Result<ResponseValue<reqwest::Upgraded>, Error<reqwest::Upgraded>> {
+        self.instance_serial_console_stream_with_options(
+            organization_name,
+            project_name,
+            instance_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_serial_console_stream`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn instance_serial_console_stream_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<reqwest::Upgraded>, Error<reqwest::Upgraded>> {
+        let mut request = self
+            .instance_serial_console_stream_request(organization_name, project_name, instance_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[57]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            101u16 => ResponseValue::upgrade(response).await,
+            200..=299 => ResponseValue::upgrade(response).await,
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::instance_serial_console_stream`]
+    /// without sending it.
+    pub fn instance_serial_console_stream_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/instances/{}/serial-console/stream",
             self.baseurl,
@@ -17675,33 +21767,78 @@ impl Client {
                 ),
             )
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_serial_console_stream",
-        };
+        Ok(request)
+    }
+
+    ///Boot an instance
+    ///
+    ///Use `POST /v1/instances/{instance}/start` instead
+    ///
+    ///Sends a `POST` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/instances/
+    /// {instance_name}/start`
+    pub async fn instance_start<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        self.instance_start_with_options(
+            organization_name,
+            project_name,
+            instance_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_start`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn instance_start_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        let mut request = self
+            .instance_start_request(organization_name, project_name, instance_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[58]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            101u16 => ResponseValue::upgrade(response).await,
-            200..=299 => ResponseValue::upgrade(response).await,
+            202u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
             _ => Err(Error::UnexpectedResponse(response)),
         }
     }
 
-    ///Boot an instance
-    ///
-    ///Use `POST /v1/instances/{instance}/start` instead
-    ///
-    ///Sends a `POST` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/instances/
-    /// {instance_name}/start`
-    pub async fn instance_start<'a>(
+    ///Prepares the request of [`Client::instance_start`] without sending it.
+    pub fn instance_start_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         instance_name: &'a types::Name,
-    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/instances/{}/start",
             self.baseurl,
@@ -17724,13 +21861,59 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_start",
-        };
+        Ok(request)
+    }
+
+    ///Halt an instance
+    ///
+    ///Use `POST /v1/instances/{instance}/stop` instead
+    ///
+    ///Sends a `POST` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/instances/
+    /// {instance_name}/stop`
+    pub async fn instance_stop<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        self.instance_stop_with_options(
+            organization_name,
+            project_name,
+            instance_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_stop`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn instance_stop_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        instance_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        let mut request = self
+            .instance_stop_request(organization_name, project_name, instance_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[59]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             202u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -17743,19 +21926,13 @@ impl Client {
         }
     }
 
-    ///Halt an instance
-    ///
-    ///Use `POST /v1/instances/{instance}/stop` instead
-    ///
-    ///Sends a `POST` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/instances/
-    /// {instance_name}/stop`
-    pub async fn instance_stop<'a>(
+    ///Prepares the request of [`Client::instance_stop`] without sending it.
+    pub fn instance_stop_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         instance_name: &'a types::Name,
-    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/instances/{}/stop",
             self.baseurl,
@@ -17778,23 +21955,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_stop",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            202u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Fetch a project's IAM policy
@@ -17812,6 +21973,60 @@ impl Client {
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
     ) -> Result<ResponseValue<types::ProjectRolePolicy>, Error<types::Error>> {
+        self.project_policy_view_with_options(
+            organization_name,
+            project_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::project_policy_view`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn project_policy_view_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::ProjectRolePolicy>, Error<types::Error>> {
+        let mut request = self
+            .project_policy_view_request(organization_name, project_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[60]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::project_policy_view`] without sending
+    /// it.
+    pub fn project_policy_view_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/policy",
             self.baseurl,
@@ -17833,23 +22048,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "project_policy_view",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Update a project's IAM policy
@@ -17867,6 +22066,63 @@ impl Client {
         project_name: &'a types::Name,
         body: &'a types::ProjectRolePolicy,
     ) -> Result<ResponseValue<types::ProjectRolePolicy>, Error<types::Error>> {
+        self.project_policy_update_with_options(
+            organization_name,
+            project_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::project_policy_update`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn project_policy_update_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        body: &'a types::ProjectRolePolicy,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::ProjectRolePolicy>, Error<types::Error>> {
+        let mut request = self
+            .project_policy_update_request(organization_name, project_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[61]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::project_policy_update`] without
+    /// sending it.
+    pub fn project_policy_update_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        body: &'a types::ProjectRolePolicy,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/policy",
             self.baseurl,
@@ -17889,23 +22145,7 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "project_policy_update",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List snapshots
@@ -17928,6 +22168,68 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::NameSortMode>,
     ) -> Result<ResponseValue<types::SnapshotResultsPage>, Error<types::Error>> {
+        self.snapshot_list_with_options(
+            organization_name,
+            project_name,
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::snapshot_list`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn snapshot_list_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::SnapshotResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .snapshot_list_request(organization_name, project_name, limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[62]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::snapshot_list`] without sending it.
+    pub fn snapshot_list_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/snapshots",
             self.baseurl,
@@ -17955,23 +22257,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "snapshot_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List snapshots as a Stream
@@ -18025,23 +22311,79 @@ impl Client {
             .boxed()
     }
 
-    ///Create a snapshot
-    ///
-    ///Creates a point-in-time snapshot from a disk.
-    ///
-    ///Sends a `POST` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/snapshots`
-    ///
-    ///Arguments:
-    /// - `organization_name`: The organization's unique name.
-    /// - `project_name`: The project's unique name within the organization.
-    /// - `body`
-    pub async fn snapshot_create<'a>(
+    ///Create a snapshot
+    ///
+    ///Creates a point-in-time snapshot from a disk.
+    ///
+    ///Sends a `POST` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/snapshots`
+    ///
+    ///Arguments:
+    /// - `organization_name`: The organization's unique name.
+    /// - `project_name`: The project's unique name within the organization.
+    /// - `body`
+    pub async fn snapshot_create<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        body: &'a types::SnapshotCreate,
+    ) -> Result<ResponseValue<types::Snapshot>, Error<types::Error>> {
+        self.snapshot_create_with_options(
+            organization_name,
+            project_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::snapshot_create`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn snapshot_create_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        body: &'a types::SnapshotCreate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Snapshot>, Error<types::Error>> {
+        let mut request = self
+            .snapshot_create_request(organization_name, project_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[63]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::snapshot_create`] without sending it.
+    pub fn snapshot_create_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         body: &'a types::SnapshotCreate,
-    ) -> Result<ResponseValue<types::Snapshot>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/snapshots",
             self.baseurl,
@@ -18064,15 +22406,59 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "snapshot_create",
-        };
+        Ok(request)
+    }
+
+    ///Fetch a snapshot
+    ///
+    ///Sends a `GET` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/snapshots/
+    /// {snapshot_name}`
+    pub async fn snapshot_view<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        snapshot_name: &'a types::Name,
+    ) -> Result<ResponseValue<types::Snapshot>, Error<types::Error>> {
+        self.snapshot_view_with_options(
+            organization_name,
+            project_name,
+            snapshot_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::snapshot_view`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn snapshot_view_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        snapshot_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Snapshot>, Error<types::Error>> {
+        let mut request = self
+            .snapshot_view_request(organization_name, project_name, snapshot_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[64]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -18083,17 +22469,13 @@ impl Client {
         }
     }
 
-    ///Fetch a snapshot
-    ///
-    ///Sends a `GET` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/snapshots/
-    /// {snapshot_name}`
-    pub async fn snapshot_view<'a>(
+    ///Prepares the request of [`Client::snapshot_view`] without sending it.
+    pub fn snapshot_view_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         snapshot_name: &'a types::Name,
-    ) -> Result<ResponseValue<types::Snapshot>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/snapshots/{}",
             self.baseurl,
@@ -18116,15 +22498,59 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "snapshot_view",
-        };
+        Ok(request)
+    }
+
+    ///Delete a snapshot
+    ///
+    ///Sends a `DELETE` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/snapshots/
+    /// {snapshot_name}`
+    pub async fn snapshot_delete<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        snapshot_name: &'a types::Name,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.snapshot_delete_with_options(
+            organization_name,
+            project_name,
+            snapshot_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::snapshot_delete`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn snapshot_delete_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        snapshot_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .snapshot_delete_request(organization_name, project_name, snapshot_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[65]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            204u16 => Ok(ResponseValue::empty(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -18135,17 +22561,13 @@ impl Client {
         }
     }
 
-    ///Delete a snapshot
-    ///
-    ///Sends a `DELETE` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/snapshots/
-    /// {snapshot_name}`
-    pub async fn snapshot_delete<'a>(
+    ///Prepares the request of [`Client::snapshot_delete`] without sending it.
+    pub fn snapshot_delete_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         snapshot_name: &'a types::Name,
-    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/snapshots/{}",
             self.baseurl,
@@ -18168,23 +22590,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "snapshot_delete",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List VPCs
@@ -18207,6 +22613,68 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::NameSortMode>,
     ) -> Result<ResponseValue<types::VpcResultsPage>, Error<types::Error>> {
+        self.vpc_list_with_options(
+            organization_name,
+            project_name,
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_list`] with additional headers, query
+    /// parameters, or context from `options`.
+    pub async fn vpc_list_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::VpcResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .vpc_list_request(organization_name, project_name, limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[66]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::vpc_list`] without sending it.
+    pub fn vpc_list_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/vpcs",
             self.baseurl,
@@ -18234,23 +22702,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List VPCs as a Stream
@@ -18318,6 +22770,62 @@ impl Client {
         project_name: &'a types::Name,
         body: &'a types::VpcCreate,
     ) -> Result<ResponseValue<types::Vpc>, Error<types::Error>> {
+        self.vpc_create_with_options(
+            organization_name,
+            project_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_create`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn vpc_create_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        body: &'a types::VpcCreate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Vpc>, Error<types::Error>> {
+        let mut request = self
+            .vpc_create_request(organization_name, project_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[67]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::vpc_create`] without sending it.
+    pub fn vpc_create_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        body: &'a types::VpcCreate,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/vpcs",
             self.baseurl,
@@ -18340,15 +22848,59 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_create",
-        };
+        Ok(request)
+    }
+
+    ///Fetch a VPC
+    ///
+    ///Sends a `GET` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
+    /// {vpc_name}`
+    pub async fn vpc_view<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+    ) -> Result<ResponseValue<types::Vpc>, Error<types::Error>> {
+        self.vpc_view_with_options(
+            organization_name,
+            project_name,
+            vpc_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_view`] with additional headers, query
+    /// parameters, or context from `options`.
+    pub async fn vpc_view_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Vpc>, Error<types::Error>> {
+        let mut request = self
+            .vpc_view_request(organization_name, project_name, vpc_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[68]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -18359,17 +22911,13 @@ impl Client {
         }
     }
 
-    ///Fetch a VPC
-    ///
-    ///Sends a `GET` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
-    /// {vpc_name}`
-    pub async fn vpc_view<'a>(
+    ///Prepares the request of [`Client::vpc_view`] without sending it.
+    pub fn vpc_view_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         vpc_name: &'a types::Name,
-    ) -> Result<ResponseValue<types::Vpc>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/vpcs/{}",
             self.baseurl,
@@ -18392,13 +22940,60 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_view",
-        };
+        Ok(request)
+    }
+
+    ///Update a VPC
+    ///
+    ///Sends a `PUT` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
+    /// {vpc_name}`
+    pub async fn vpc_update<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        body: &'a types::VpcUpdate,
+    ) -> Result<ResponseValue<types::Vpc>, Error<types::Error>> {
+        self.vpc_update_with_options(
+            organization_name,
+            project_name,
+            vpc_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_update`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn vpc_update_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        body: &'a types::VpcUpdate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Vpc>, Error<types::Error>> {
+        let mut request = self
+            .vpc_update_request(organization_name, project_name, vpc_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[69]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -18411,18 +23006,14 @@ impl Client {
         }
     }
 
-    ///Update a VPC
-    ///
-    ///Sends a `PUT` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
-    /// {vpc_name}`
-    pub async fn vpc_update<'a>(
+    ///Prepares the request of [`Client::vpc_update`] without sending it.
+    pub fn vpc_update_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         vpc_name: &'a types::Name,
         body: &'a types::VpcUpdate,
-    ) -> Result<ResponseValue<types::Vpc>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/vpcs/{}",
             self.baseurl,
@@ -18446,15 +23037,59 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_update",
-        };
+        Ok(request)
+    }
+
+    ///Delete a VPC
+    ///
+    ///Sends a `DELETE` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
+    /// {vpc_name}`
+    pub async fn vpc_delete<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.vpc_delete_with_options(
+            organization_name,
+            project_name,
+            vpc_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_delete`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn vpc_delete_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .vpc_delete_request(organization_name, project_name, vpc_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[70]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            204u16 => Ok(ResponseValue::empty(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -18465,17 +23100,13 @@ impl Client {
         }
     }
 
-    ///Delete a VPC
-    ///
-    ///Sends a `DELETE` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
-    /// {vpc_name}`
-    pub async fn vpc_delete<'a>(
+    ///Prepares the request of [`Client::vpc_delete`] without sending it.
+    pub fn vpc_delete_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         vpc_name: &'a types::Name,
-    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/vpcs/{}",
             self.baseurl,
@@ -18498,15 +23129,59 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_delete",
-        };
+        Ok(request)
+    }
+
+    ///List firewall rules
+    ///
+    ///Sends a `GET` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
+    /// {vpc_name}/firewall/rules`
+    pub async fn vpc_firewall_rules_view<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+    ) -> Result<ResponseValue<types::VpcFirewallRules>, Error<types::Error>> {
+        self.vpc_firewall_rules_view_with_options(
+            organization_name,
+            project_name,
+            vpc_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_firewall_rules_view`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn vpc_firewall_rules_view_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::VpcFirewallRules>, Error<types::Error>> {
+        let mut request = self
+            .vpc_firewall_rules_view_request(organization_name, project_name, vpc_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[71]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -18517,17 +23192,14 @@ impl Client {
         }
     }
 
-    ///List firewall rules
-    ///
-    ///Sends a `GET` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
-    /// {vpc_name}/firewall/rules`
-    pub async fn vpc_firewall_rules_view<'a>(
+    ///Prepares the request of [`Client::vpc_firewall_rules_view`] without
+    /// sending it.
+    pub fn vpc_firewall_rules_view_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         vpc_name: &'a types::Name,
-    ) -> Result<ResponseValue<types::VpcFirewallRules>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/vpcs/{}/firewall/rules",
             self.baseurl,
@@ -18550,13 +23222,60 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_firewall_rules_view",
-        };
+        Ok(request)
+    }
+
+    ///Replace firewall rules
+    ///
+    ///Sends a `PUT` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
+    /// {vpc_name}/firewall/rules`
+    pub async fn vpc_firewall_rules_update<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        body: &'a types::VpcFirewallRuleUpdateParams,
+    ) -> Result<ResponseValue<types::VpcFirewallRules>, Error<types::Error>> {
+        self.vpc_firewall_rules_update_with_options(
+            organization_name,
+            project_name,
+            vpc_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_firewall_rules_update`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn vpc_firewall_rules_update_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        body: &'a types::VpcFirewallRuleUpdateParams,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::VpcFirewallRules>, Error<types::Error>> {
+        let mut request = self
+            .vpc_firewall_rules_update_request(organization_name, project_name, vpc_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[72]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -18569,18 +23288,15 @@ impl Client {
         }
     }
 
-    ///Replace firewall rules
-    ///
-    ///Sends a `PUT` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
-    /// {vpc_name}/firewall/rules`
-    pub async fn vpc_firewall_rules_update<'a>(
+    ///Prepares the request of [`Client::vpc_firewall_rules_update`] without
+    /// sending it.
+    pub fn vpc_firewall_rules_update_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         vpc_name: &'a types::Name,
         body: &'a types::VpcFirewallRuleUpdateParams,
-    ) -> Result<ResponseValue<types::VpcFirewallRules>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/vpcs/{}/firewall/rules",
             self.baseurl,
@@ -18604,23 +23320,7 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_firewall_rules_update",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List routers
@@ -18646,6 +23346,78 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::NameSortMode>,
     ) -> Result<ResponseValue<types::VpcRouterResultsPage>, Error<types::Error>> {
+        self.vpc_router_list_with_options(
+            organization_name,
+            project_name,
+            vpc_name,
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_router_list`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn vpc_router_list_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::VpcRouterResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .vpc_router_list_request(
+                organization_name,
+                project_name,
+                vpc_name,
+                limit,
+                page_token,
+                sort_by,
+            )
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[73]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::vpc_router_list`] without sending it.
+    pub fn vpc_router_list_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/vpcs/{}/routers",
             self.baseurl,
@@ -18674,23 +23446,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_router_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List routers as a Stream
@@ -18766,6 +23522,66 @@ impl Client {
         vpc_name: &'a types::Name,
         body: &'a types::VpcRouterCreate,
     ) -> Result<ResponseValue<types::VpcRouter>, Error<types::Error>> {
+        self.vpc_router_create_with_options(
+            organization_name,
+            project_name,
+            vpc_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_router_create`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn vpc_router_create_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        body: &'a types::VpcRouterCreate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::VpcRouter>, Error<types::Error>> {
+        let mut request = self
+            .vpc_router_create_request(organization_name, project_name, vpc_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[74]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::vpc_router_create`] without sending
+    /// it.
+    pub fn vpc_router_create_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        body: &'a types::VpcRouterCreate,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/vpcs/{}/routers",
             self.baseurl,
@@ -18780,24 +23596,71 @@ impl Client {
         );
         #[allow(unused_mut)]
         let mut request = self
-            .client
-            .post(url)
-            .header(
-                ::reqwest::header::ACCEPT,
-                ::reqwest::header::HeaderValue::from_static("application/json"),
-            )
-            .json(&body)
-            .headers(header_map)
-            .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_router_create",
-        };
+            .client
+            .post(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .json(&body)
+            .headers(header_map)
+            .build()?;
+        Ok(request)
+    }
+
+    ///Get a router
+    ///
+    ///Sends a `GET` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
+    /// {vpc_name}/routers/{router_name}`
+    pub async fn vpc_router_view<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        router_name: &'a types::Name,
+    ) -> Result<ResponseValue<types::VpcRouter>, Error<types::Error>> {
+        self.vpc_router_view_with_options(
+            organization_name,
+            project_name,
+            vpc_name,
+            router_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_router_view`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn vpc_router_view_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        router_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::VpcRouter>, Error<types::Error>> {
+        let mut request = self
+            .vpc_router_view_request(organization_name, project_name, vpc_name, router_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[75]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -18808,18 +23671,14 @@ impl Client {
         }
     }
 
-    ///Get a router
-    ///
-    ///Sends a `GET` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
-    /// {vpc_name}/routers/{router_name}`
-    pub async fn vpc_router_view<'a>(
+    ///Prepares the request of [`Client::vpc_router_view`] without sending it.
+    pub fn vpc_router_view_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         vpc_name: &'a types::Name,
         router_name: &'a types::Name,
-    ) -> Result<ResponseValue<types::VpcRouter>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/vpcs/{}/routers/{}",
             self.baseurl,
@@ -18843,13 +23702,63 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_router_view",
-        };
+        Ok(request)
+    }
+
+    ///Update a router
+    ///
+    ///Sends a `PUT` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
+    /// {vpc_name}/routers/{router_name}`
+    pub async fn vpc_router_update<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        router_name: &'a types::Name,
+        body: &'a types::VpcRouterUpdate,
+    ) -> Result<ResponseValue<types::VpcRouter>, Error<types::Error>> {
+        self.vpc_router_update_with_options(
+            organization_name,
+            project_name,
+            vpc_name,
+            router_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_router_update`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn vpc_router_update_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        router_name: &'a types::Name,
+        body: &'a types::VpcRouterUpdate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::VpcRouter>, Error<types::Error>> {
+        let mut request = self
+            .vpc_router_update_request(organization_name, project_name, vpc_name, router_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[76]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -18862,19 +23771,16 @@ impl Client {
         }
     }
 
-    ///Update a router
-    ///
-    ///Sends a `PUT` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
-    /// {vpc_name}/routers/{router_name}`
-    pub async fn vpc_router_update<'a>(
+    ///Prepares the request of [`Client::vpc_router_update`] without sending
+    /// it.
+    pub fn vpc_router_update_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         vpc_name: &'a types::Name,
         router_name: &'a types::Name,
         body: &'a types::VpcRouterUpdate,
-    ) -> Result<ResponseValue<types::VpcRouter>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/vpcs/{}/routers/{}",
             self.baseurl,
@@ -18899,15 +23805,62 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_router_update",
-        };
+        Ok(request)
+    }
+
+    ///Delete a router
+    ///
+    ///Sends a `DELETE` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
+    /// {vpc_name}/routers/{router_name}`
+    pub async fn vpc_router_delete<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        router_name: &'a types::Name,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.vpc_router_delete_with_options(
+            organization_name,
+            project_name,
+            vpc_name,
+            router_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_router_delete`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn vpc_router_delete_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        router_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .vpc_router_delete_request(organization_name, project_name, vpc_name, router_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[77]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            204u16 => Ok(ResponseValue::empty(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -18918,18 +23871,15 @@ impl Client {
         }
     }
 
-    ///Delete a router
-    ///
-    ///Sends a `DELETE` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
-    /// {vpc_name}/routers/{router_name}`
-    pub async fn vpc_router_delete<'a>(
+    ///Prepares the request of [`Client::vpc_router_delete`] without sending
+    /// it.
+    pub fn vpc_router_delete_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         vpc_name: &'a types::Name,
         router_name: &'a types::Name,
-    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/vpcs/{}/routers/{}",
             self.baseurl,
@@ -18953,23 +23903,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_router_delete",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List routes
@@ -18999,6 +23933,83 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::NameSortMode>,
     ) -> Result<ResponseValue<types::RouterRouteResultsPage>, Error<types::Error>> {
+        self.vpc_router_route_list_with_options(
+            organization_name,
+            project_name,
+            vpc_name,
+            router_name,
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_router_route_list`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn vpc_router_route_list_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        router_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::RouterRouteResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .vpc_router_route_list_request(
+                organization_name,
+                project_name,
+                vpc_name,
+                router_name,
+                limit,
+                page_token,
+                sort_by,
+            )
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[78]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::vpc_router_route_list`] without
+    /// sending it.
+    pub fn vpc_router_route_list_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        router_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/vpcs/{}/routers/{}/routes",
             self.baseurl,
@@ -19028,23 +24039,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_router_route_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List routes as a Stream
@@ -19128,6 +24123,75 @@ impl Client {
         router_name: &'a types::Name,
         body: &'a types::RouterRouteCreateParams,
     ) -> Result<ResponseValue<types::RouterRoute>, Error<types::Error>> {
+        self.vpc_router_route_create_with_options(
+            organization_name,
+            project_name,
+            vpc_name,
+            router_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_router_route_create`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn vpc_router_route_create_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        router_name: &'a types::Name,
+        body: &'a types::RouterRouteCreateParams,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::RouterRoute>, Error<types::Error>> {
+        let mut request = self
+            .vpc_router_route_create_request(
+                organization_name,
+                project_name,
+                vpc_name,
+                router_name,
+                body,
+            )
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[79]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::vpc_router_route_create`] without
+    /// sending it.
+    pub fn vpc_router_route_create_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        router_name: &'a types::Name,
+        body: &'a types::RouterRouteCreateParams,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/vpcs/{}/routers/{}/routes",
             self.baseurl,
@@ -19152,15 +24216,71 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_router_route_create",
-        };
+        Ok(request)
+    }
+
+    ///Fetch a route
+    ///
+    ///Sends a `GET` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
+    /// {vpc_name}/routers/{router_name}/routes/{route_name}`
+    pub async fn vpc_router_route_view<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        router_name: &'a types::Name,
+        route_name: &'a types::Name,
+    ) -> Result<ResponseValue<types::RouterRoute>, Error<types::Error>> {
+        self.vpc_router_route_view_with_options(
+            organization_name,
+            project_name,
+            vpc_name,
+            router_name,
+            route_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_router_route_view`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn vpc_router_route_view_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        router_name: &'a types::Name,
+        route_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::RouterRoute>, Error<types::Error>> {
+        let mut request = self
+            .vpc_router_route_view_request(
+                organization_name,
+                project_name,
+                vpc_name,
+                router_name,
+                route_name,
+            )
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[80]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -19171,19 +24291,16 @@ impl Client {
         }
     }
 
-    ///Fetch a route
-    ///
-    ///Sends a `GET` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
-    /// {vpc_name}/routers/{router_name}/routes/{route_name}`
-    pub async fn vpc_router_route_view<'a>(
+    ///Prepares the request of [`Client::vpc_router_route_view`] without
+    /// sending it.
+    pub fn vpc_router_route_view_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         vpc_name: &'a types::Name,
         router_name: &'a types::Name,
         route_name: &'a types::Name,
-    ) -> Result<ResponseValue<types::RouterRoute>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/vpcs/{}/routers/{}/routes/{}",
             self.baseurl,
@@ -19208,13 +24325,73 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_router_route_view",
-        };
+        Ok(request)
+    }
+
+    ///Update a route
+    ///
+    ///Sends a `PUT` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
+    /// {vpc_name}/routers/{router_name}/routes/{route_name}`
+    pub async fn vpc_router_route_update<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        router_name: &'a types::Name,
+        route_name: &'a types::Name,
+        body: &'a types::RouterRouteUpdateParams,
+    ) -> Result<ResponseValue<types::RouterRoute>, Error<types::Error>> {
+        self.vpc_router_route_update_with_options(
+            organization_name,
+            project_name,
+            vpc_name,
+            router_name,
+            route_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_router_route_update`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn vpc_router_route_update_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        router_name: &'a types::Name,
+        route_name: &'a types::Name,
+        body: &'a types::RouterRouteUpdateParams,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::RouterRoute>, Error<types::Error>> {
+        let mut request = self
+            .vpc_router_route_update_request(
+                organization_name,
+                project_name,
+                vpc_name,
+                router_name,
+                route_name,
+                body,
+            )
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[81]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -19227,12 +24404,9 @@ impl Client {
         }
     }
 
-    ///Update a route
-    ///
-    ///Sends a `PUT` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
-    /// {vpc_name}/routers/{router_name}/routes/{route_name}`
-    pub async fn vpc_router_route_update<'a>(
+    ///Prepares the request of [`Client::vpc_router_route_update`] without
+    /// sending it.
+    pub fn vpc_router_route_update_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
@@ -19240,7 +24414,7 @@ impl Client {
         router_name: &'a types::Name,
         route_name: &'a types::Name,
         body: &'a types::RouterRouteUpdateParams,
-    ) -> Result<ResponseValue<types::RouterRoute>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/vpcs/{}/routers/{}/routes/{}",
             self.baseurl,
@@ -19266,15 +24440,71 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_router_route_update",
-        };
+        Ok(request)
+    }
+
+    ///Delete a route
+    ///
+    ///Sends a `DELETE` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
+    /// {vpc_name}/routers/{router_name}/routes/{route_name}`
+    pub async fn vpc_router_route_delete<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        router_name: &'a types::Name,
+        route_name: &'a types::Name,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.vpc_router_route_delete_with_options(
+            organization_name,
+            project_name,
+            vpc_name,
+            router_name,
+            route_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_router_route_delete`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn vpc_router_route_delete_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        router_name: &'a types::Name,
+        route_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .vpc_router_route_delete_request(
+                organization_name,
+                project_name,
+                vpc_name,
+                router_name,
+                route_name,
+            )
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[82]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            204u16 => Ok(ResponseValue::empty(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -19285,19 +24515,16 @@ impl Client {
         }
     }
 
-    ///Delete a route
-    ///
-    ///Sends a `DELETE` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
-    /// {vpc_name}/routers/{router_name}/routes/{route_name}`
-    pub async fn vpc_router_route_delete<'a>(
+    ///Prepares the request of [`Client::vpc_router_route_delete`] without
+    /// sending it.
+    pub fn vpc_router_route_delete_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         vpc_name: &'a types::Name,
         router_name: &'a types::Name,
         route_name: &'a types::Name,
-    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/vpcs/{}/routers/{}/routes/{}",
             self.baseurl,
@@ -19322,23 +24549,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_router_route_delete",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List subnets
@@ -19364,6 +24575,78 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::NameSortMode>,
     ) -> Result<ResponseValue<types::VpcSubnetResultsPage>, Error<types::Error>> {
+        self.vpc_subnet_list_with_options(
+            organization_name,
+            project_name,
+            vpc_name,
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_subnet_list`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn vpc_subnet_list_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::VpcSubnetResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .vpc_subnet_list_request(
+                organization_name,
+                project_name,
+                vpc_name,
+                limit,
+                page_token,
+                sort_by,
+            )
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[83]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::vpc_subnet_list`] without sending it.
+    pub fn vpc_subnet_list_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/vpcs/{}/subnets",
             self.baseurl,
@@ -19392,23 +24675,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_subnet_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List subnets as a Stream
@@ -19484,38 +24751,145 @@ impl Client {
         vpc_name: &'a types::Name,
         body: &'a types::VpcSubnetCreate,
     ) -> Result<ResponseValue<types::VpcSubnet>, Error<types::Error>> {
-        let url = format!(
-            "{}/organizations/{}/projects/{}/vpcs/{}/subnets",
-            self.baseurl,
-            encode_path(&organization_name.to_string()),
-            encode_path(&project_name.to_string()),
-            encode_path(&vpc_name.to_string()),
-        );
-        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
-        header_map.append(
-            ::reqwest::header::HeaderName::from_static("api-version"),
-            ::reqwest::header::HeaderValue::from_static(Self::api_version()),
-        );
-        #[allow(unused_mut)]
+        self.vpc_subnet_create_with_options(
+            organization_name,
+            project_name,
+            vpc_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_subnet_create`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn vpc_subnet_create_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        body: &'a types::VpcSubnetCreate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::VpcSubnet>, Error<types::Error>> {
+        let mut request = self
+            .vpc_subnet_create_request(organization_name, project_name, vpc_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[84]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::vpc_subnet_create`] without sending
+    /// it.
+    pub fn vpc_subnet_create_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        body: &'a types::VpcSubnetCreate,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+        let url = format!(
+            "{}/organizations/{}/projects/{}/vpcs/{}/subnets",
+            self.baseurl,
+            encode_path(&organization_name.to_string()),
+            encode_path(&project_name.to_string()),
+            encode_path(&vpc_name.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+        header_map.append(
+            ::reqwest::header::HeaderName::from_static("api-version"),
+            ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+        );
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .post(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .json(&body)
+            .headers(header_map)
+            .build()?;
+        Ok(request)
+    }
+
+    ///Fetch a subnet
+    ///
+    ///Sends a `GET` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
+    /// {vpc_name}/subnets/{subnet_name}`
+    pub async fn vpc_subnet_view<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        subnet_name: &'a types::Name,
+    ) -> Result<ResponseValue<types::VpcSubnet>, Error<types::Error>> {
+        self.vpc_subnet_view_with_options(
+            organization_name,
+            project_name,
+            vpc_name,
+            subnet_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_subnet_view`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn vpc_subnet_view_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        subnet_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::VpcSubnet>, Error<types::Error>> {
         let mut request = self
-            .client
-            .post(url)
-            .header(
-                ::reqwest::header::ACCEPT,
-                ::reqwest::header::HeaderValue::from_static("application/json"),
-            )
-            .json(&body)
-            .headers(header_map)
-            .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_subnet_create",
-        };
+            .vpc_subnet_view_request(organization_name, project_name, vpc_name, subnet_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[85]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -19526,18 +24900,14 @@ impl Client {
         }
     }
 
-    ///Fetch a subnet
-    ///
-    ///Sends a `GET` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
-    /// {vpc_name}/subnets/{subnet_name}`
-    pub async fn vpc_subnet_view<'a>(
+    ///Prepares the request of [`Client::vpc_subnet_view`] without sending it.
+    pub fn vpc_subnet_view_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         vpc_name: &'a types::Name,
         subnet_name: &'a types::Name,
-    ) -> Result<ResponseValue<types::VpcSubnet>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/vpcs/{}/subnets/{}",
             self.baseurl,
@@ -19561,13 +24931,63 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_subnet_view",
-        };
+        Ok(request)
+    }
+
+    ///Update a subnet
+    ///
+    ///Sends a `PUT` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
+    /// {vpc_name}/subnets/{subnet_name}`
+    pub async fn vpc_subnet_update<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        subnet_name: &'a types::Name,
+        body: &'a types::VpcSubnetUpdate,
+    ) -> Result<ResponseValue<types::VpcSubnet>, Error<types::Error>> {
+        self.vpc_subnet_update_with_options(
+            organization_name,
+            project_name,
+            vpc_name,
+            subnet_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_subnet_update`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn vpc_subnet_update_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        subnet_name: &'a types::Name,
+        body: &'a types::VpcSubnetUpdate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::VpcSubnet>, Error<types::Error>> {
+        let mut request = self
+            .vpc_subnet_update_request(organization_name, project_name, vpc_name, subnet_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[86]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -19580,19 +25000,16 @@ impl Client {
         }
     }
 
-    ///Update a subnet
-    ///
-    ///Sends a `PUT` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
-    /// {vpc_name}/subnets/{subnet_name}`
-    pub async fn vpc_subnet_update<'a>(
+    ///Prepares the request of [`Client::vpc_subnet_update`] without sending
+    /// it.
+    pub fn vpc_subnet_update_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         vpc_name: &'a types::Name,
         subnet_name: &'a types::Name,
         body: &'a types::VpcSubnetUpdate,
-    ) -> Result<ResponseValue<types::VpcSubnet>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/vpcs/{}/subnets/{}",
             self.baseurl,
@@ -19617,15 +25034,62 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_subnet_update",
-        };
+        Ok(request)
+    }
+
+    ///Delete a subnet
+    ///
+    ///Sends a `DELETE` request to
+    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
+    /// {vpc_name}/subnets/{subnet_name}`
+    pub async fn vpc_subnet_delete<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        subnet_name: &'a types::Name,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.vpc_subnet_delete_with_options(
+            organization_name,
+            project_name,
+            vpc_name,
+            subnet_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_subnet_delete`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn vpc_subnet_delete_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        subnet_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .vpc_subnet_delete_request(organization_name, project_name, vpc_name, subnet_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[87]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            204u16 => Ok(ResponseValue::empty(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -19636,18 +25100,15 @@ impl Client {
         }
     }
 
-    ///Delete a subnet
-    ///
-    ///Sends a `DELETE` request to
-    /// `/organizations/{organization_name}/projects/{project_name}/vpcs/
-    /// {vpc_name}/subnets/{subnet_name}`
-    pub async fn vpc_subnet_delete<'a>(
+    ///Prepares the request of [`Client::vpc_subnet_delete`] without sending
+    /// it.
+    pub fn vpc_subnet_delete_request<'a>(
         &'a self,
         organization_name: &'a types::Name,
         project_name: &'a types::Name,
         vpc_name: &'a types::Name,
         subnet_name: &'a types::Name,
-    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/vpcs/{}/subnets/{}",
             self.baseurl,
@@ -19671,23 +25132,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_subnet_delete",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List network interfaces
@@ -19715,6 +25160,83 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::NameSortMode>,
     ) -> Result<ResponseValue<types::NetworkInterfaceResultsPage>, Error<types::Error>> {
+        self.vpc_subnet_list_network_interfaces_with_options(
+            organization_name,
+            project_name,
+            vpc_name,
+            subnet_name,
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::vpc_subnet_list_network_interfaces`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn vpc_subnet_list_network_interfaces_with_options<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        subnet_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::NetworkInterfaceResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .vpc_subnet_list_network_interfaces_request(
+                organization_name,
+                project_name,
+                vpc_name,
+                subnet_name,
+                limit,
+                page_token,
+                sort_by,
+            )
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[88]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::vpc_subnet_list_network_interfaces`]
+    /// without sending it.
+    pub fn vpc_subnet_list_network_interfaces_request<'a>(
+        &'a self,
+        organization_name: &'a types::Name,
+        project_name: &'a types::Name,
+        vpc_name: &'a types::Name,
+        subnet_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/organizations/{}/projects/{}/vpcs/{}/subnets/{}/network-interfaces",
             self.baseurl,
@@ -19744,23 +25266,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "vpc_subnet_list_network_interfaces",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List network interfaces as a Stream
@@ -19835,6 +25341,49 @@ impl Client {
     pub async fn policy_view<'a>(
         &'a self,
     ) -> Result<ResponseValue<types::SiloRolePolicy>, Error<types::Error>> {
+        self.policy_view_with_options(progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::policy_view`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn policy_view_with_options<'a>(
+        &'a self,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::SiloRolePolicy>, Error<types::Error>> {
+        let mut request = self.policy_view_request().map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[89]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::policy_view`] without sending it.
+    pub fn policy_view_request<'a>(
+        &'a self,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/policy", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -19851,13 +25400,46 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "policy_view",
-        };
+        Ok(request)
+    }
+
+    ///Update the current silo's IAM policy
+    ///
+    ///Sends a `PUT` request to `/policy`
+    pub async fn policy_update<'a>(
+        &'a self,
+        body: &'a types::SiloRolePolicy,
+    ) -> Result<ResponseValue<types::SiloRolePolicy>, Error<types::Error>> {
+        self.policy_update_with_options(body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::policy_update`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn policy_update_with_options<'a>(
+        &'a self,
+        body: &'a types::SiloRolePolicy,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::SiloRolePolicy>, Error<types::Error>> {
+        let mut request = self
+            .policy_update_request(body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[90]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -19870,13 +25452,11 @@ impl Client {
         }
     }
 
-    ///Update the current silo's IAM policy
-    ///
-    ///Sends a `PUT` request to `/policy`
-    pub async fn policy_update<'a>(
+    ///Prepares the request of [`Client::policy_update`] without sending it.
+    pub fn policy_update_request<'a>(
         &'a self,
         body: &'a types::SiloRolePolicy,
-    ) -> Result<ResponseValue<types::SiloRolePolicy>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/policy", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -19894,13 +25474,57 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "policy_update",
-        };
+        Ok(request)
+    }
+
+    ///List built-in roles
+    ///
+    ///Sends a `GET` request to `/roles`
+    ///
+    ///Arguments:
+    /// - `limit`: Maximum number of items returned by a single call
+    /// - `page_token`: Token returned by previous call to retrieve the
+    ///   subsequent page
+    pub async fn role_list<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+    ) -> Result<ResponseValue<types::RoleResultsPage>, Error<types::Error>> {
+        self.role_list_with_options(
+            limit,
+            page_token,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::role_list`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn role_list_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::RoleResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .role_list_request(limit, page_token)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[91]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -19913,19 +25537,12 @@ impl Client {
         }
     }
 
-    ///List built-in roles
-    ///
-    ///Sends a `GET` request to `/roles`
-    ///
-    ///Arguments:
-    /// - `limit`: Maximum number of items returned by a single call
-    /// - `page_token`: Token returned by previous call to retrieve the
-    ///   subsequent page
-    pub async fn role_list<'a>(
+    ///Prepares the request of [`Client::role_list`] without sending it.
+    pub fn role_list_request<'a>(
         &'a self,
         limit: Option<::std::num::NonZeroU32>,
         page_token: Option<&'a str>,
-    ) -> Result<ResponseValue<types::RoleResultsPage>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/roles", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -19947,23 +25564,7 @@ impl Client {
             ))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "role_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List built-in roles as a Stream
@@ -20013,6 +25614,53 @@ impl Client {
         &'a self,
         role_name: &'a str,
     ) -> Result<ResponseValue<types::Role>, Error<types::Error>> {
+        self.role_view_with_options(role_name, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::role_view`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn role_view_with_options<'a>(
+        &'a self,
+        role_name: &'a str,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Role>, Error<types::Error>> {
+        let mut request = self
+            .role_view_request(role_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[92]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::role_view`] without sending it.
+    pub fn role_view_request<'a>(
+        &'a self,
+        role_name: &'a str,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/roles/{}",
             self.baseurl,
@@ -20033,13 +25681,42 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "role_view",
-        };
+        Ok(request)
+    }
+
+    ///Fetch the user associated with the current session
+    ///
+    ///Sends a `GET` request to `/session/me`
+    pub async fn session_me<'a>(
+        &'a self,
+    ) -> Result<ResponseValue<types::User>, Error<types::Error>> {
+        self.session_me_with_options(progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::session_me`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn session_me_with_options<'a>(
+        &'a self,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::User>, Error<types::Error>> {
+        let mut request = self.session_me_request().map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[93]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -20052,12 +25729,10 @@ impl Client {
         }
     }
 
-    ///Fetch the user associated with the current session
-    ///
-    ///Sends a `GET` request to `/session/me`
-    pub async fn session_me<'a>(
+    ///Prepares the request of [`Client::session_me`] without sending it.
+    pub fn session_me_request<'a>(
         &'a self,
-    ) -> Result<ResponseValue<types::User>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/session/me", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -20074,23 +25749,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "session_me",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Fetch the silo groups the current user belongs to
@@ -20108,6 +25767,63 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::IdSortMode>,
     ) -> Result<ResponseValue<types::GroupResultsPage>, Error<types::Error>> {
+        self.session_me_groups_with_options(
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::session_me_groups`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn session_me_groups_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::GroupResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .session_me_groups_request(limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[94]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::session_me_groups`] without sending
+    /// it.
+    pub fn session_me_groups_request<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/session/me/groups", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -20130,23 +25846,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "session_me_groups",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Fetch the silo groups the current user belongs to as a Stream
@@ -20205,6 +25905,63 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::NameSortMode>,
     ) -> Result<ResponseValue<types::SshKeyResultsPage>, Error<types::Error>> {
+        self.session_sshkey_list_with_options(
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::session_sshkey_list`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn session_sshkey_list_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::SshKeyResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .session_sshkey_list_request(limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[95]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::session_sshkey_list`] without sending
+    /// it.
+    pub fn session_sshkey_list_request<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/session/me/sshkeys", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -20227,23 +25984,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "session_sshkey_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List SSH public keys as a Stream
@@ -20296,6 +26037,54 @@ impl Client {
         &'a self,
         body: &'a types::SshKeyCreate,
     ) -> Result<ResponseValue<types::SshKey>, Error<types::Error>> {
+        self.session_sshkey_create_with_options(body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::session_sshkey_create`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn session_sshkey_create_with_options<'a>(
+        &'a self,
+        body: &'a types::SshKeyCreate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::SshKey>, Error<types::Error>> {
+        let mut request = self
+            .session_sshkey_create_request(body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[96]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::session_sshkey_create`] without
+    /// sending it.
+    pub fn session_sshkey_create_request<'a>(
+        &'a self,
+        body: &'a types::SshKeyCreate,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/session/me/sshkeys", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -20313,15 +26102,54 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "session_sshkey_create",
-        };
+        Ok(request)
+    }
+
+    ///Fetch an SSH public key
+    ///
+    ///Fetch an SSH public key associated with the currently authenticated
+    /// user.
+    ///
+    ///Sends a `GET` request to `/session/me/sshkeys/{ssh_key_name}`
+    pub async fn session_sshkey_view<'a>(
+        &'a self,
+        ssh_key_name: &'a types::Name,
+    ) -> Result<ResponseValue<types::SshKey>, Error<types::Error>> {
+        self.session_sshkey_view_with_options(
+            ssh_key_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::session_sshkey_view`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn session_sshkey_view_with_options<'a>(
+        &'a self,
+        ssh_key_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::SshKey>, Error<types::Error>> {
+        let mut request = self
+            .session_sshkey_view_request(ssh_key_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[97]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -20332,16 +26160,12 @@ impl Client {
         }
     }
 
-    ///Fetch an SSH public key
-    ///
-    ///Fetch an SSH public key associated with the currently authenticated
-    /// user.
-    ///
-    ///Sends a `GET` request to `/session/me/sshkeys/{ssh_key_name}`
-    pub async fn session_sshkey_view<'a>(
+    ///Prepares the request of [`Client::session_sshkey_view`] without sending
+    /// it.
+    pub fn session_sshkey_view_request<'a>(
         &'a self,
         ssh_key_name: &'a types::Name,
-    ) -> Result<ResponseValue<types::SshKey>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/session/me/sshkeys/{}",
             self.baseurl,
@@ -20362,15 +26186,54 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "session_sshkey_view",
-        };
+        Ok(request)
+    }
+
+    ///Delete an SSH public key
+    ///
+    ///Delete an SSH public key associated with the currently authenticated
+    /// user.
+    ///
+    ///Sends a `DELETE` request to `/session/me/sshkeys/{ssh_key_name}`
+    pub async fn session_sshkey_delete<'a>(
+        &'a self,
+        ssh_key_name: &'a types::Name,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.session_sshkey_delete_with_options(
+            ssh_key_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::session_sshkey_delete`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn session_sshkey_delete_with_options<'a>(
+        &'a self,
+        ssh_key_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .session_sshkey_delete_request(ssh_key_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[98]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            204u16 => Ok(ResponseValue::empty(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -20381,16 +26244,12 @@ impl Client {
         }
     }
 
-    ///Delete an SSH public key
-    ///
-    ///Delete an SSH public key associated with the currently authenticated
-    /// user.
-    ///
-    ///Sends a `DELETE` request to `/session/me/sshkeys/{ssh_key_name}`
-    pub async fn session_sshkey_delete<'a>(
+    ///Prepares the request of [`Client::session_sshkey_delete`] without
+    /// sending it.
+    pub fn session_sshkey_delete_request<'a>(
         &'a self,
         ssh_key_name: &'a types::Name,
-    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/session/me/sshkeys/{}",
             self.baseurl,
@@ -20411,15 +26270,48 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "session_sshkey_delete",
-        };
+        Ok(request)
+    }
+
+    ///Fetch a system-wide image by id
+    ///
+    ///Sends a `GET` request to `/system/by-id/images/{id}`
+    pub async fn system_image_view_by_id<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+    ) -> Result<ResponseValue<types::GlobalImage>, Error<types::Error>> {
+        self.system_image_view_by_id_with_options(id, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::system_image_view_by_id`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn system_image_view_by_id_with_options<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::GlobalImage>, Error<types::Error>> {
+        let mut request = self
+            .system_image_view_by_id_request(id)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[99]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -20430,13 +26322,12 @@ impl Client {
         }
     }
 
-    ///Fetch a system-wide image by id
-    ///
-    ///Sends a `GET` request to `/system/by-id/images/{id}`
-    pub async fn system_image_view_by_id<'a>(
+    ///Prepares the request of [`Client::system_image_view_by_id`] without
+    /// sending it.
+    pub fn system_image_view_by_id_request<'a>(
         &'a self,
         id: &'a ::uuid::Uuid,
-    ) -> Result<ResponseValue<types::GlobalImage>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/by-id/images/{}",
             self.baseurl,
@@ -20457,13 +26348,46 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "system_image_view_by_id",
-        };
+        Ok(request)
+    }
+
+    ///Fetch an IP pool by id
+    ///
+    ///Sends a `GET` request to `/system/by-id/ip-pools/{id}`
+    pub async fn ip_pool_view_by_id<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+    ) -> Result<ResponseValue<types::IpPool>, Error<types::Error>> {
+        self.ip_pool_view_by_id_with_options(id, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::ip_pool_view_by_id`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn ip_pool_view_by_id_with_options<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::IpPool>, Error<types::Error>> {
+        let mut request = self
+            .ip_pool_view_by_id_request(id)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[100]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -20476,13 +26400,12 @@ impl Client {
         }
     }
 
-    ///Fetch an IP pool by id
-    ///
-    ///Sends a `GET` request to `/system/by-id/ip-pools/{id}`
-    pub async fn ip_pool_view_by_id<'a>(
+    ///Prepares the request of [`Client::ip_pool_view_by_id`] without sending
+    /// it.
+    pub fn ip_pool_view_by_id_request<'a>(
         &'a self,
         id: &'a ::uuid::Uuid,
-    ) -> Result<ResponseValue<types::IpPool>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/by-id/ip-pools/{}",
             self.baseurl,
@@ -20503,13 +26426,46 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "ip_pool_view_by_id",
-        };
+        Ok(request)
+    }
+
+    ///Fetch a silo by id
+    ///
+    ///Sends a `GET` request to `/system/by-id/silos/{id}`
+    pub async fn silo_view_by_id<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+    ) -> Result<ResponseValue<types::Silo>, Error<types::Error>> {
+        self.silo_view_by_id_with_options(id, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::silo_view_by_id`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn silo_view_by_id_with_options<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Silo>, Error<types::Error>> {
+        let mut request = self
+            .silo_view_by_id_request(id)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[101]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -20522,13 +26478,11 @@ impl Client {
         }
     }
 
-    ///Fetch a silo by id
-    ///
-    ///Sends a `GET` request to `/system/by-id/silos/{id}`
-    pub async fn silo_view_by_id<'a>(
+    ///Prepares the request of [`Client::silo_view_by_id`] without sending it.
+    pub fn silo_view_by_id_request<'a>(
         &'a self,
         id: &'a ::uuid::Uuid,
-    ) -> Result<ResponseValue<types::Silo>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/by-id/silos/{}",
             self.baseurl,
@@ -20549,23 +26503,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "silo_view_by_id",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List system-wide certificates
@@ -20587,6 +26525,62 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::NameSortMode>,
     ) -> Result<ResponseValue<types::CertificateResultsPage>, Error<types::Error>> {
+        self.certificate_list_with_options(
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::certificate_list`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn certificate_list_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::CertificateResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .certificate_list_request(limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[102]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::certificate_list`] without sending it.
+    pub fn certificate_list_request<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/system/certificates", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -20609,23 +26603,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "certificate_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List system-wide certificates as a Stream
@@ -20682,6 +26660,54 @@ impl Client {
         &'a self,
         body: &'a types::CertificateCreate,
     ) -> Result<ResponseValue<types::Certificate>, Error<types::Error>> {
+        self.certificate_create_with_options(body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::certificate_create`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn certificate_create_with_options<'a>(
+        &'a self,
+        body: &'a types::CertificateCreate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Certificate>, Error<types::Error>> {
+        let mut request = self
+            .certificate_create_request(body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[103]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::certificate_create`] without sending
+    /// it.
+    pub fn certificate_create_request<'a>(
+        &'a self,
+        body: &'a types::CertificateCreate,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/system/certificates", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -20699,15 +26725,53 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "certificate_create",
-        };
+        Ok(request)
+    }
+
+    ///Fetch a certificate
+    ///
+    ///Returns the details of a specific certificate
+    ///
+    ///Sends a `GET` request to `/system/certificates/{certificate}`
+    pub async fn certificate_view<'a>(
+        &'a self,
+        certificate: &'a types::NameOrId,
+    ) -> Result<ResponseValue<types::Certificate>, Error<types::Error>> {
+        self.certificate_view_with_options(
+            certificate,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::certificate_view`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn certificate_view_with_options<'a>(
+        &'a self,
+        certificate: &'a types::NameOrId,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Certificate>, Error<types::Error>> {
+        let mut request = self
+            .certificate_view_request(certificate)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[104]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -20718,15 +26782,11 @@ impl Client {
         }
     }
 
-    ///Fetch a certificate
-    ///
-    ///Returns the details of a specific certificate
-    ///
-    ///Sends a `GET` request to `/system/certificates/{certificate}`
-    pub async fn certificate_view<'a>(
+    ///Prepares the request of [`Client::certificate_view`] without sending it.
+    pub fn certificate_view_request<'a>(
         &'a self,
         certificate: &'a types::NameOrId,
-    ) -> Result<ResponseValue<types::Certificate>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/certificates/{}",
             self.baseurl,
@@ -20747,15 +26807,53 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "certificate_view",
-        };
+        Ok(request)
+    }
+
+    ///Delete a certificate
+    ///
+    ///Permanently delete a certificate. This operation cannot be undone.
+    ///
+    ///Sends a `DELETE` request to `/system/certificates/{certificate}`
+    pub async fn certificate_delete<'a>(
+        &'a self,
+        certificate: &'a types::NameOrId,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.certificate_delete_with_options(
+            certificate,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::certificate_delete`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn certificate_delete_with_options<'a>(
+        &'a self,
+        certificate: &'a types::NameOrId,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .certificate_delete_request(certificate)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[105]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            204u16 => Ok(ResponseValue::empty(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -20766,15 +26864,12 @@ impl Client {
         }
     }
 
-    ///Delete a certificate
-    ///
-    ///Permanently delete a certificate. This operation cannot be undone.
-    ///
-    ///Sends a `DELETE` request to `/system/certificates/{certificate}`
-    pub async fn certificate_delete<'a>(
+    ///Prepares the request of [`Client::certificate_delete`] without sending
+    /// it.
+    pub fn certificate_delete_request<'a>(
         &'a self,
         certificate: &'a types::NameOrId,
-    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/certificates/{}",
             self.baseurl,
@@ -20789,29 +26884,13 @@ impl Client {
         let mut request = self
             .client
             .delete(url)
-            .header(
-                ::reqwest::header::ACCEPT,
-                ::reqwest::header::HeaderValue::from_static("application/json"),
-            )
-            .headers(header_map)
-            .build()?;
-        let info = OperationInfo {
-            operation_id: "certificate_delete",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        Ok(request)
     }
 
     ///List physical disks
@@ -20829,6 +26908,63 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::IdSortMode>,
     ) -> Result<ResponseValue<types::PhysicalDiskResultsPage>, Error<types::Error>> {
+        self.physical_disk_list_with_options(
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::physical_disk_list`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn physical_disk_list_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::PhysicalDiskResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .physical_disk_list_request(limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[106]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::physical_disk_list`] without sending
+    /// it.
+    pub fn physical_disk_list_request<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/system/hardware/disks", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -20851,23 +26987,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "physical_disk_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List physical disks as a Stream
@@ -20925,6 +27045,62 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::IdSortMode>,
     ) -> Result<ResponseValue<types::RackResultsPage>, Error<types::Error>> {
+        self.rack_list_with_options(
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::rack_list`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn rack_list_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::RackResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .rack_list_request(limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[107]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::rack_list`] without sending it.
+    pub fn rack_list_request<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/system/hardware/racks", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -20947,23 +27123,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "rack_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List racks as a Stream
@@ -21015,6 +27175,51 @@ impl Client {
         &'a self,
         rack_id: &'a ::uuid::Uuid,
     ) -> Result<ResponseValue<types::Rack>, Error<types::Error>> {
+        self.rack_view_with_options(rack_id, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::rack_view`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn rack_view_with_options<'a>(
+        &'a self,
+        rack_id: &'a ::uuid::Uuid,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Rack>, Error<types::Error>> {
+        let mut request = self.rack_view_request(rack_id).map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[108]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::rack_view`] without sending it.
+    pub fn rack_view_request<'a>(
+        &'a self,
+        rack_id: &'a ::uuid::Uuid,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/hardware/racks/{}",
             self.baseurl,
@@ -21035,23 +27240,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "rack_view",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List sleds
@@ -21069,6 +27258,62 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::IdSortMode>,
     ) -> Result<ResponseValue<types::SledResultsPage>, Error<types::Error>> {
+        self.sled_list_with_options(
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::sled_list`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn sled_list_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::SledResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .sled_list_request(limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[109]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::sled_list`] without sending it.
+    pub fn sled_list_request<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/system/hardware/sleds", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -21091,23 +27336,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "sled_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List sleds as a Stream
@@ -21159,6 +27388,51 @@ impl Client {
         &'a self,
         sled_id: &'a ::uuid::Uuid,
     ) -> Result<ResponseValue<types::Sled>, Error<types::Error>> {
+        self.sled_view_with_options(sled_id, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::sled_view`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn sled_view_with_options<'a>(
+        &'a self,
+        sled_id: &'a ::uuid::Uuid,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Sled>, Error<types::Error>> {
+        let mut request = self.sled_view_request(sled_id).map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[110]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::sled_view`] without sending it.
+    pub fn sled_view_request<'a>(
+        &'a self,
+        sled_id: &'a ::uuid::Uuid,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/hardware/sleds/{}",
             self.baseurl,
@@ -21179,23 +27453,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "sled_view",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List physical disks attached to sleds
@@ -21215,6 +27473,66 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::IdSortMode>,
     ) -> Result<ResponseValue<types::PhysicalDiskResultsPage>, Error<types::Error>> {
+        self.sled_physical_disk_list_with_options(
+            sled_id,
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::sled_physical_disk_list`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn sled_physical_disk_list_with_options<'a>(
+        &'a self,
+        sled_id: &'a ::uuid::Uuid,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::PhysicalDiskResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .sled_physical_disk_list_request(sled_id, limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[111]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::sled_physical_disk_list`] without
+    /// sending it.
+    pub fn sled_physical_disk_list_request<'a>(
+        &'a self,
+        sled_id: &'a ::uuid::Uuid,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/hardware/sleds/{}/disks",
             self.baseurl,
@@ -21241,23 +27559,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "sled_physical_disk_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List physical disks attached to sleds as a Stream
@@ -21322,6 +27624,63 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::NameSortMode>,
     ) -> Result<ResponseValue<types::GlobalImageResultsPage>, Error<types::Error>> {
+        self.system_image_list_with_options(
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::system_image_list`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn system_image_list_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::GlobalImageResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .system_image_list_request(limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[112]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::system_image_list`] without sending
+    /// it.
+    pub fn system_image_list_request<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/system/images", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -21344,23 +27703,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "system_image_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List system-wide images as a Stream
@@ -21417,6 +27760,54 @@ impl Client {
         &'a self,
         body: &'a types::GlobalImageCreate,
     ) -> Result<ResponseValue<types::GlobalImage>, Error<types::Error>> {
+        self.system_image_create_with_options(body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::system_image_create`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn system_image_create_with_options<'a>(
+        &'a self,
+        body: &'a types::GlobalImageCreate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::GlobalImage>, Error<types::Error>> {
+        let mut request = self
+            .system_image_create_request(body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[113]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::system_image_create`] without sending
+    /// it.
+    pub fn system_image_create_request<'a>(
+        &'a self,
+        body: &'a types::GlobalImageCreate,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/system/images", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -21434,15 +27825,53 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "system_image_create",
-        };
+        Ok(request)
+    }
+
+    ///Fetch a system-wide image
+    ///
+    ///Returns the details of a specific system-wide image.
+    ///
+    ///Sends a `GET` request to `/system/images/{image_name}`
+    pub async fn system_image_view<'a>(
+        &'a self,
+        image_name: &'a types::Name,
+    ) -> Result<ResponseValue<types::GlobalImage>, Error<types::Error>> {
+        self.system_image_view_with_options(
+            image_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::system_image_view`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn system_image_view_with_options<'a>(
+        &'a self,
+        image_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::GlobalImage>, Error<types::Error>> {
+        let mut request = self
+            .system_image_view_request(image_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[114]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -21453,15 +27882,12 @@ impl Client {
         }
     }
 
-    ///Fetch a system-wide image
-    ///
-    ///Returns the details of a specific system-wide image.
-    ///
-    ///Sends a `GET` request to `/system/images/{image_name}`
-    pub async fn system_image_view<'a>(
+    ///Prepares the request of [`Client::system_image_view`] without sending
+    /// it.
+    pub fn system_image_view_request<'a>(
         &'a self,
         image_name: &'a types::Name,
-    ) -> Result<ResponseValue<types::GlobalImage>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/images/{}",
             self.baseurl,
@@ -21482,15 +27908,55 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "system_image_view",
-        };
+        Ok(request)
+    }
+
+    ///Delete a system-wide image
+    ///
+    ///Permanently delete a system-wide image. This operation cannot be undone.
+    /// Any instances using the system-wide image will continue to run, however
+    /// new instances can not be created with this image.
+    ///
+    ///Sends a `DELETE` request to `/system/images/{image_name}`
+    pub async fn system_image_delete<'a>(
+        &'a self,
+        image_name: &'a types::Name,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.system_image_delete_with_options(
+            image_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::system_image_delete`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn system_image_delete_with_options<'a>(
+        &'a self,
+        image_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .system_image_delete_request(image_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[115]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            204u16 => Ok(ResponseValue::empty(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -21501,17 +27967,12 @@ impl Client {
         }
     }
 
-    ///Delete a system-wide image
-    ///
-    ///Permanently delete a system-wide image. This operation cannot be undone.
-    /// Any instances using the system-wide image will continue to run, however
-    /// new instances can not be created with this image.
-    ///
-    ///Sends a `DELETE` request to `/system/images/{image_name}`
-    pub async fn system_image_delete<'a>(
+    ///Prepares the request of [`Client::system_image_delete`] without sending
+    /// it.
+    pub fn system_image_delete_request<'a>(
         &'a self,
         image_name: &'a types::Name,
-    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/images/{}",
             self.baseurl,
@@ -21532,23 +27993,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "system_image_delete",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List IP pools
@@ -21566,6 +28011,62 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::NameOrIdSortMode>,
     ) -> Result<ResponseValue<types::IpPoolResultsPage>, Error<types::Error>> {
+        self.ip_pool_list_with_options(
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::ip_pool_list`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn ip_pool_list_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameOrIdSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::IpPoolResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .ip_pool_list_request(limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[116]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::ip_pool_list`] without sending it.
+    pub fn ip_pool_list_request<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameOrIdSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/system/ip-pools", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -21588,23 +28089,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "ip_pool_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List IP pools as a Stream
@@ -21653,6 +28138,53 @@ impl Client {
         &'a self,
         body: &'a types::IpPoolCreate,
     ) -> Result<ResponseValue<types::IpPool>, Error<types::Error>> {
+        self.ip_pool_create_with_options(body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::ip_pool_create`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn ip_pool_create_with_options<'a>(
+        &'a self,
+        body: &'a types::IpPoolCreate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::IpPool>, Error<types::Error>> {
+        let mut request = self
+            .ip_pool_create_request(body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[117]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::ip_pool_create`] without sending it.
+    pub fn ip_pool_create_request<'a>(
+        &'a self,
+        body: &'a types::IpPoolCreate,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/system/ip-pools", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -21670,15 +28202,48 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "ip_pool_create",
-        };
+        Ok(request)
+    }
+
+    ///Fetch an IP pool
+    ///
+    ///Sends a `GET` request to `/system/ip-pools/{pool_name}`
+    pub async fn ip_pool_view<'a>(
+        &'a self,
+        pool_name: &'a types::Name,
+    ) -> Result<ResponseValue<types::IpPool>, Error<types::Error>> {
+        self.ip_pool_view_with_options(pool_name, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::ip_pool_view`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn ip_pool_view_with_options<'a>(
+        &'a self,
+        pool_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::IpPool>, Error<types::Error>> {
+        let mut request = self
+            .ip_pool_view_request(pool_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[118]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -21689,13 +28254,11 @@ impl Client {
         }
     }
 
-    ///Fetch an IP pool
-    ///
-    ///Sends a `GET` request to `/system/ip-pools/{pool_name}`
-    pub async fn ip_pool_view<'a>(
+    ///Prepares the request of [`Client::ip_pool_view`] without sending it.
+    pub fn ip_pool_view_request<'a>(
         &'a self,
         pool_name: &'a types::Name,
-    ) -> Result<ResponseValue<types::IpPool>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/ip-pools/{}",
             self.baseurl,
@@ -21716,13 +28279,52 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "ip_pool_view",
-        };
+        Ok(request)
+    }
+
+    ///Update an IP Pool
+    ///
+    ///Sends a `PUT` request to `/system/ip-pools/{pool_name}`
+    pub async fn ip_pool_update<'a>(
+        &'a self,
+        pool_name: &'a types::Name,
+        body: &'a types::IpPoolUpdate,
+    ) -> Result<ResponseValue<types::IpPool>, Error<types::Error>> {
+        self.ip_pool_update_with_options(
+            pool_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::ip_pool_update`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn ip_pool_update_with_options<'a>(
+        &'a self,
+        pool_name: &'a types::Name,
+        body: &'a types::IpPoolUpdate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::IpPool>, Error<types::Error>> {
+        let mut request = self
+            .ip_pool_update_request(pool_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[119]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -21735,14 +28337,12 @@ impl Client {
         }
     }
 
-    ///Update an IP Pool
-    ///
-    ///Sends a `PUT` request to `/system/ip-pools/{pool_name}`
-    pub async fn ip_pool_update<'a>(
+    ///Prepares the request of [`Client::ip_pool_update`] without sending it.
+    pub fn ip_pool_update_request<'a>(
         &'a self,
         pool_name: &'a types::Name,
         body: &'a types::IpPoolUpdate,
-    ) -> Result<ResponseValue<types::IpPool>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/ip-pools/{}",
             self.baseurl,
@@ -21764,15 +28364,48 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "ip_pool_update",
-        };
+        Ok(request)
+    }
+
+    ///Delete an IP Pool
+    ///
+    ///Sends a `DELETE` request to `/system/ip-pools/{pool_name}`
+    pub async fn ip_pool_delete<'a>(
+        &'a self,
+        pool_name: &'a types::Name,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.ip_pool_delete_with_options(pool_name, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::ip_pool_delete`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn ip_pool_delete_with_options<'a>(
+        &'a self,
+        pool_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .ip_pool_delete_request(pool_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[120]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            204u16 => Ok(ResponseValue::empty(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -21783,13 +28416,11 @@ impl Client {
         }
     }
 
-    ///Delete an IP Pool
-    ///
-    ///Sends a `DELETE` request to `/system/ip-pools/{pool_name}`
-    pub async fn ip_pool_delete<'a>(
+    ///Prepares the request of [`Client::ip_pool_delete`] without sending it.
+    pub fn ip_pool_delete_request<'a>(
         &'a self,
         pool_name: &'a types::Name,
-    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/ip-pools/{}",
             self.baseurl,
@@ -21810,23 +28441,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "ip_pool_delete",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List ranges for an IP pool
@@ -21846,6 +28461,63 @@ impl Client {
         limit: Option<::std::num::NonZeroU32>,
         page_token: Option<&'a str>,
     ) -> Result<ResponseValue<types::IpPoolRangeResultsPage>, Error<types::Error>> {
+        self.ip_pool_range_list_with_options(
+            pool_name,
+            limit,
+            page_token,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::ip_pool_range_list`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn ip_pool_range_list_with_options<'a>(
+        &'a self,
+        pool_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::IpPoolRangeResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .ip_pool_range_list_request(pool_name, limit, page_token)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[121]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::ip_pool_range_list`] without sending
+    /// it.
+    pub fn ip_pool_range_list_request<'a>(
+        &'a self,
+        pool_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/ip-pools/{}/ranges",
             self.baseurl,
@@ -21871,23 +28543,7 @@ impl Client {
             ))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "ip_pool_range_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List ranges for an IP pool as a Stream
@@ -21940,6 +28596,60 @@ impl Client {
         pool_name: &'a types::Name,
         body: &'a types::IpRange,
     ) -> Result<ResponseValue<types::IpPoolRange>, Error<types::Error>> {
+        self.ip_pool_range_add_with_options(
+            pool_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::ip_pool_range_add`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn ip_pool_range_add_with_options<'a>(
+        &'a self,
+        pool_name: &'a types::Name,
+        body: &'a types::IpRange,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::IpPoolRange>, Error<types::Error>> {
+        let mut request = self
+            .ip_pool_range_add_request(pool_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[122]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::ip_pool_range_add`] without sending
+    /// it.
+    pub fn ip_pool_range_add_request<'a>(
+        &'a self,
+        pool_name: &'a types::Name,
+        body: &'a types::IpRange,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/ip-pools/{}/ranges/add",
             self.baseurl,
@@ -21961,15 +28671,54 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "ip_pool_range_add",
-        };
+        Ok(request)
+    }
+
+    ///Remove a range from an IP pool
+    ///
+    ///Sends a `POST` request to `/system/ip-pools/{pool_name}/ranges/remove`
+    pub async fn ip_pool_range_remove<'a>(
+        &'a self,
+        pool_name: &'a types::Name,
+        body: &'a types::IpRange,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.ip_pool_range_remove_with_options(
+            pool_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::ip_pool_range_remove`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn ip_pool_range_remove_with_options<'a>(
+        &'a self,
+        pool_name: &'a types::Name,
+        body: &'a types::IpRange,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .ip_pool_range_remove_request(pool_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[123]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            204u16 => Ok(ResponseValue::empty(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -21980,14 +28729,13 @@ impl Client {
         }
     }
 
-    ///Remove a range from an IP pool
-    ///
-    ///Sends a `POST` request to `/system/ip-pools/{pool_name}/ranges/remove`
-    pub async fn ip_pool_range_remove<'a>(
+    ///Prepares the request of [`Client::ip_pool_range_remove`] without sending
+    /// it.
+    pub fn ip_pool_range_remove_request<'a>(
         &'a self,
         pool_name: &'a types::Name,
         body: &'a types::IpRange,
-    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/ip-pools/{}/ranges/remove",
             self.baseurl,
@@ -22009,15 +28757,46 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "ip_pool_range_remove",
-        };
+        Ok(request)
+    }
+
+    ///Fetch the IP pool used for Oxide services
+    ///
+    ///Sends a `GET` request to `/system/ip-pools-service`
+    pub async fn ip_pool_service_view<'a>(
+        &'a self,
+    ) -> Result<ResponseValue<types::IpPool>, Error<types::Error>> {
+        self.ip_pool_service_view_with_options(progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::ip_pool_service_view`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn ip_pool_service_view_with_options<'a>(
+        &'a self,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::IpPool>, Error<types::Error>> {
+        let mut request = self
+            .ip_pool_service_view_request()
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[124]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -22028,12 +28807,11 @@ impl Client {
         }
     }
 
-    ///Fetch the IP pool used for Oxide services
-    ///
-    ///Sends a `GET` request to `/system/ip-pools-service`
-    pub async fn ip_pool_service_view<'a>(
+    ///Prepares the request of [`Client::ip_pool_service_view`] without sending
+    /// it.
+    pub fn ip_pool_service_view_request<'a>(
         &'a self,
-    ) -> Result<ResponseValue<types::IpPool>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/system/ip-pools-service", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -22050,23 +28828,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "ip_pool_service_view",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List ranges for the IP pool used for Oxide services
@@ -22084,6 +28846,60 @@ impl Client {
         limit: Option<::std::num::NonZeroU32>,
         page_token: Option<&'a str>,
     ) -> Result<ResponseValue<types::IpPoolRangeResultsPage>, Error<types::Error>> {
+        self.ip_pool_service_range_list_with_options(
+            limit,
+            page_token,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::ip_pool_service_range_list`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn ip_pool_service_range_list_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::IpPoolRangeResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .ip_pool_service_range_list_request(limit, page_token)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[125]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::ip_pool_service_range_list`] without
+    /// sending it.
+    pub fn ip_pool_service_range_list_request<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/system/ip-pools-service/ranges", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -22105,23 +28921,7 @@ impl Client {
             ))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "ip_pool_service_range_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List ranges for the IP pool used for Oxide services as a Stream
@@ -22164,13 +28964,64 @@ impl Client {
             .boxed()
     }
 
-    ///Add a range to an IP pool used for Oxide services
-    ///
-    ///Sends a `POST` request to `/system/ip-pools-service/ranges/add`
-    pub async fn ip_pool_service_range_add<'a>(
+    ///Add a range to an IP pool used for Oxide services
+    ///
+    ///Sends a `POST` request to `/system/ip-pools-service/ranges/add`
+    pub async fn ip_pool_service_range_add<'a>(
+        &'a self,
+        body: &'a types::IpRange,
+    ) -> Result<ResponseValue<types::IpPoolRange>, Error<types::Error>> {
+        self.ip_pool_service_range_add_with_options(
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::ip_pool_service_range_add`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn ip_pool_service_range_add_with_options<'a>(
+        &'a self,
+        body: &'a types::IpRange,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::IpPoolRange>, Error<types::Error>> {
+        let mut request = self
+            .ip_pool_service_range_add_request(body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[126]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::ip_pool_service_range_add`] without
+    /// sending it.
+    pub fn ip_pool_service_range_add_request<'a>(
         &'a self,
         body: &'a types::IpRange,
-    ) -> Result<ResponseValue<types::IpPoolRange>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/system/ip-pools-service/ranges/add", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -22188,15 +29039,51 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "ip_pool_service_range_add",
-        };
+        Ok(request)
+    }
+
+    ///Remove a range from an IP pool used for Oxide services
+    ///
+    ///Sends a `POST` request to `/system/ip-pools-service/ranges/remove`
+    pub async fn ip_pool_service_range_remove<'a>(
+        &'a self,
+        body: &'a types::IpRange,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.ip_pool_service_range_remove_with_options(
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::ip_pool_service_range_remove`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn ip_pool_service_range_remove_with_options<'a>(
+        &'a self,
+        body: &'a types::IpRange,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .ip_pool_service_range_remove_request(body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[127]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            204u16 => Ok(ResponseValue::empty(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -22207,13 +29094,12 @@ impl Client {
         }
     }
 
-    ///Remove a range from an IP pool used for Oxide services
-    ///
-    ///Sends a `POST` request to `/system/ip-pools-service/ranges/remove`
-    pub async fn ip_pool_service_range_remove<'a>(
+    ///Prepares the request of [`Client::ip_pool_service_range_remove`] without
+    /// sending it.
+    pub fn ip_pool_service_range_remove_request<'a>(
         &'a self,
         body: &'a types::IpRange,
-    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/system/ip-pools-service/ranges/remove", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -22231,23 +29117,7 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "ip_pool_service_range_remove",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Access metrics data
@@ -22271,6 +29141,71 @@ impl Client {
         page_token: Option<&'a str>,
         start_time: Option<&'a ::chrono::DateTime<::chrono::offset::Utc>>,
     ) -> Result<ResponseValue<types::MeasurementResultsPage>, Error<types::Error>> {
+        self.system_metric_with_options(
+            metric_name,
+            end_time,
+            id,
+            limit,
+            page_token,
+            start_time,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::system_metric`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn system_metric_with_options<'a>(
+        &'a self,
+        metric_name: types::SystemMetricName,
+        end_time: Option<&'a ::chrono::DateTime<::chrono::offset::Utc>>,
+        id: &'a ::uuid::Uuid,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        start_time: Option<&'a ::chrono::DateTime<::chrono::offset::Utc>>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::MeasurementResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .system_metric_request(metric_name, end_time, id, limit, page_token, start_time)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[128]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::system_metric`] without sending it.
+    pub fn system_metric_request<'a>(
+        &'a self,
+        metric_name: types::SystemMetricName,
+        end_time: Option<&'a ::chrono::DateTime<::chrono::offset::Utc>>,
+        id: &'a ::uuid::Uuid,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        start_time: Option<&'a ::chrono::DateTime<::chrono::offset::Utc>>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/metrics/{}",
             self.baseurl,
@@ -22302,13 +29237,44 @@ impl Client {
             ))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "system_metric",
-        };
+        Ok(request)
+    }
+
+    ///Fetch the top-level IAM policy
+    ///
+    ///Sends a `GET` request to `/system/policy`
+    pub async fn system_policy_view<'a>(
+        &'a self,
+    ) -> Result<ResponseValue<types::FleetRolePolicy>, Error<types::Error>> {
+        self.system_policy_view_with_options(progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::system_policy_view`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn system_policy_view_with_options<'a>(
+        &'a self,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::FleetRolePolicy>, Error<types::Error>> {
+        let mut request = self
+            .system_policy_view_request()
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[129]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -22321,12 +29287,11 @@ impl Client {
         }
     }
 
-    ///Fetch the top-level IAM policy
-    ///
-    ///Sends a `GET` request to `/system/policy`
-    pub async fn system_policy_view<'a>(
+    ///Prepares the request of [`Client::system_policy_view`] without sending
+    /// it.
+    pub fn system_policy_view_request<'a>(
         &'a self,
-    ) -> Result<ResponseValue<types::FleetRolePolicy>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/system/policy", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -22343,13 +29308,46 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "system_policy_view",
-        };
+        Ok(request)
+    }
+
+    ///Update the top-level IAM policy
+    ///
+    ///Sends a `PUT` request to `/system/policy`
+    pub async fn system_policy_update<'a>(
+        &'a self,
+        body: &'a types::FleetRolePolicy,
+    ) -> Result<ResponseValue<types::FleetRolePolicy>, Error<types::Error>> {
+        self.system_policy_update_with_options(body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::system_policy_update`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn system_policy_update_with_options<'a>(
+        &'a self,
+        body: &'a types::FleetRolePolicy,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::FleetRolePolicy>, Error<types::Error>> {
+        let mut request = self
+            .system_policy_update_request(body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[130]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -22362,13 +29360,12 @@ impl Client {
         }
     }
 
-    ///Update the top-level IAM policy
-    ///
-    ///Sends a `PUT` request to `/system/policy`
-    pub async fn system_policy_update<'a>(
+    ///Prepares the request of [`Client::system_policy_update`] without sending
+    /// it.
+    pub fn system_policy_update_request<'a>(
         &'a self,
         body: &'a types::FleetRolePolicy,
-    ) -> Result<ResponseValue<types::FleetRolePolicy>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/system/policy", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -22386,23 +29383,7 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "system_policy_update",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List sagas
@@ -22420,6 +29401,62 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::IdSortMode>,
     ) -> Result<ResponseValue<types::SagaResultsPage>, Error<types::Error>> {
+        self.saga_list_with_options(
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::saga_list`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn saga_list_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::SagaResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .saga_list_request(limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[131]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::saga_list`] without sending it.
+    pub fn saga_list_request<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/system/sagas", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -22442,23 +29479,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "saga_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List sagas as a Stream
@@ -22507,6 +29528,51 @@ impl Client {
         &'a self,
         saga_id: &'a ::uuid::Uuid,
     ) -> Result<ResponseValue<types::Saga>, Error<types::Error>> {
+        self.saga_view_with_options(saga_id, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::saga_view`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn saga_view_with_options<'a>(
+        &'a self,
+        saga_id: &'a ::uuid::Uuid,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Saga>, Error<types::Error>> {
+        let mut request = self.saga_view_request(saga_id).map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[132]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::saga_view`] without sending it.
+    pub fn saga_view_request<'a>(
+        &'a self,
+        saga_id: &'a ::uuid::Uuid,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/sagas/{}",
             self.baseurl,
@@ -22519,21 +29585,71 @@ impl Client {
         );
         #[allow(unused_mut)]
         let mut request = self
-            .client
-            .get(url)
-            .header(
-                ::reqwest::header::ACCEPT,
-                ::reqwest::header::HeaderValue::from_static("application/json"),
-            )
-            .headers(header_map)
-            .build()?;
-        let info = OperationInfo {
-            operation_id: "saga_view",
-        };
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        Ok(request)
+    }
+
+    ///List silos
+    ///
+    ///Lists silos that are discoverable based on the current permissions.
+    ///
+    ///Sends a `GET` request to `/system/silos`
+    ///
+    ///Arguments:
+    /// - `limit`: Maximum number of items returned by a single call
+    /// - `page_token`: Token returned by previous call to retrieve the
+    ///   subsequent page
+    /// - `sort_by`
+    pub async fn silo_list<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameOrIdSortMode>,
+    ) -> Result<ResponseValue<types::SiloResultsPage>, Error<types::Error>> {
+        self.silo_list_with_options(
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::silo_list`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn silo_list_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameOrIdSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::SiloResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .silo_list_request(limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[133]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -22546,23 +29662,13 @@ impl Client {
         }
     }
 
-    ///List silos
-    ///
-    ///Lists silos that are discoverable based on the current permissions.
-    ///
-    ///Sends a `GET` request to `/system/silos`
-    ///
-    ///Arguments:
-    /// - `limit`: Maximum number of items returned by a single call
-    /// - `page_token`: Token returned by previous call to retrieve the
-    ///   subsequent page
-    /// - `sort_by`
-    pub async fn silo_list<'a>(
+    ///Prepares the request of [`Client::silo_list`] without sending it.
+    pub fn silo_list_request<'a>(
         &'a self,
         limit: Option<::std::num::NonZeroU32>,
         page_token: Option<&'a str>,
         sort_by: Option<types::NameOrIdSortMode>,
-    ) -> Result<ResponseValue<types::SiloResultsPage>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/system/silos", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -22585,23 +29691,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "silo_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List silos as a Stream
@@ -22652,6 +29742,51 @@ impl Client {
         &'a self,
         body: &'a types::SiloCreate,
     ) -> Result<ResponseValue<types::Silo>, Error<types::Error>> {
+        self.silo_create_with_options(body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::silo_create`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn silo_create_with_options<'a>(
+        &'a self,
+        body: &'a types::SiloCreate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Silo>, Error<types::Error>> {
+        let mut request = self.silo_create_request(body).map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[134]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::silo_create`] without sending it.
+    pub fn silo_create_request<'a>(
+        &'a self,
+        body: &'a types::SiloCreate,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/system/silos", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -22669,23 +29804,7 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "silo_create",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Fetch a silo
@@ -22700,6 +29819,53 @@ impl Client {
         &'a self,
         silo_name: &'a types::Name,
     ) -> Result<ResponseValue<types::Silo>, Error<types::Error>> {
+        self.silo_view_with_options(silo_name, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::silo_view`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn silo_view_with_options<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Silo>, Error<types::Error>> {
+        let mut request = self
+            .silo_view_request(silo_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[135]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::silo_view`] without sending it.
+    pub fn silo_view_request<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/silos/{}",
             self.baseurl,
@@ -22720,23 +29886,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "silo_view",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Delete a silo
@@ -22751,6 +29901,53 @@ impl Client {
         &'a self,
         silo_name: &'a types::Name,
     ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.silo_delete_with_options(silo_name, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::silo_delete`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn silo_delete_with_options<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .silo_delete_request(silo_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[136]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            204u16 => Ok(ResponseValue::empty(response)),
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::silo_delete`] without sending it.
+    pub fn silo_delete_request<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/silos/{}",
             self.baseurl,
@@ -22771,23 +29968,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "silo_delete",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List a silo's IDPs
@@ -22807,6 +29988,66 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::NameSortMode>,
     ) -> Result<ResponseValue<types::IdentityProviderResultsPage>, Error<types::Error>> {
+        self.silo_identity_provider_list_with_options(
+            silo_name,
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::silo_identity_provider_list`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn silo_identity_provider_list_with_options<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::IdentityProviderResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .silo_identity_provider_list_request(silo_name, limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[137]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::silo_identity_provider_list`] without
+    /// sending it.
+    pub fn silo_identity_provider_list_request<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/silos/{}/identity-providers",
             self.baseurl,
@@ -22833,23 +30074,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "silo_identity_provider_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List a silo's IDPs as a Stream
@@ -22912,6 +30137,60 @@ impl Client {
         silo_name: &'a types::Name,
         body: &'a types::UserCreate,
     ) -> Result<ResponseValue<types::User>, Error<types::Error>> {
+        self.local_idp_user_create_with_options(
+            silo_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::local_idp_user_create`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn local_idp_user_create_with_options<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        body: &'a types::UserCreate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::User>, Error<types::Error>> {
+        let mut request = self
+            .local_idp_user_create_request(silo_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[138]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::local_idp_user_create`] without
+    /// sending it.
+    pub fn local_idp_user_create_request<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        body: &'a types::UserCreate,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/silos/{}/identity-providers/local/users",
             self.baseurl,
@@ -22924,24 +30203,68 @@ impl Client {
         );
         #[allow(unused_mut)]
         let mut request = self
-            .client
-            .post(url)
-            .header(
-                ::reqwest::header::ACCEPT,
-                ::reqwest::header::HeaderValue::from_static("application/json"),
-            )
-            .json(&body)
-            .headers(header_map)
-            .build()?;
-        let info = OperationInfo {
-            operation_id: "local_idp_user_create",
-        };
+            .client
+            .post(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .json(&body)
+            .headers(header_map)
+            .build()?;
+        Ok(request)
+    }
+
+    ///Delete a user
+    ///
+    ///Sends a `DELETE` request to
+    /// `/system/silos/{silo_name}/identity-providers/local/users/{user_id}`
+    ///
+    ///Arguments:
+    /// - `silo_name`: The silo's unique name.
+    /// - `user_id`: The user's internal id
+    pub async fn local_idp_user_delete<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        user_id: &'a ::uuid::Uuid,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.local_idp_user_delete_with_options(
+            silo_name,
+            user_id,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::local_idp_user_delete`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn local_idp_user_delete_with_options<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        user_id: &'a ::uuid::Uuid,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .local_idp_user_delete_request(silo_name, user_id)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[139]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            204u16 => Ok(ResponseValue::empty(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -22952,19 +30275,13 @@ impl Client {
         }
     }
 
-    ///Delete a user
-    ///
-    ///Sends a `DELETE` request to
-    /// `/system/silos/{silo_name}/identity-providers/local/users/{user_id}`
-    ///
-    ///Arguments:
-    /// - `silo_name`: The silo's unique name.
-    /// - `user_id`: The user's internal id
-    pub async fn local_idp_user_delete<'a>(
+    ///Prepares the request of [`Client::local_idp_user_delete`] without
+    /// sending it.
+    pub fn local_idp_user_delete_request<'a>(
         &'a self,
         silo_name: &'a types::Name,
         user_id: &'a ::uuid::Uuid,
-    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/silos/{}/identity-providers/local/users/{}",
             self.baseurl,
@@ -22986,23 +30303,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "local_idp_user_delete",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Set or invalidate a user's password
@@ -23024,6 +30325,63 @@ impl Client {
         user_id: &'a ::uuid::Uuid,
         body: &'a types::UserPassword,
     ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.local_idp_user_set_password_with_options(
+            silo_name,
+            user_id,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::local_idp_user_set_password`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn local_idp_user_set_password_with_options<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        user_id: &'a ::uuid::Uuid,
+        body: &'a types::UserPassword,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .local_idp_user_set_password_request(silo_name, user_id, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[140]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            204u16 => Ok(ResponseValue::empty(response)),
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::local_idp_user_set_password`] without
+    /// sending it.
+    pub fn local_idp_user_set_password_request<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        user_id: &'a ::uuid::Uuid,
+        body: &'a types::UserPassword,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/silos/{}/identity-providers/local/users/{}/set-password",
             self.baseurl,
@@ -23046,23 +30404,7 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "local_idp_user_set_password",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Create a SAML IDP
@@ -23078,6 +30420,60 @@ impl Client {
         silo_name: &'a types::Name,
         body: &'a types::SamlIdentityProviderCreate,
     ) -> Result<ResponseValue<types::SamlIdentityProvider>, Error<types::Error>> {
+        self.saml_identity_provider_create_with_options(
+            silo_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::saml_identity_provider_create`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn saml_identity_provider_create_with_options<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        body: &'a types::SamlIdentityProviderCreate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::SamlIdentityProvider>, Error<types::Error>> {
+        let mut request = self
+            .saml_identity_provider_create_request(silo_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[141]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::saml_identity_provider_create`]
+    /// without sending it.
+    pub fn saml_identity_provider_create_request<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        body: &'a types::SamlIdentityProviderCreate,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/silos/{}/identity-providers/saml",
             self.baseurl,
@@ -23099,23 +30495,7 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "saml_identity_provider_create",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Fetch a SAML IDP
@@ -23131,6 +30511,60 @@ impl Client {
         silo_name: &'a types::Name,
         provider_name: &'a types::Name,
     ) -> Result<ResponseValue<types::SamlIdentityProvider>, Error<types::Error>> {
+        self.saml_identity_provider_view_with_options(
+            silo_name,
+            provider_name,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::saml_identity_provider_view`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn saml_identity_provider_view_with_options<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        provider_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::SamlIdentityProvider>, Error<types::Error>> {
+        let mut request = self
+            .saml_identity_provider_view_request(silo_name, provider_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[142]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::saml_identity_provider_view`] without
+    /// sending it.
+    pub fn saml_identity_provider_view_request<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        provider_name: &'a types::Name,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/silos/{}/identity-providers/saml/{}",
             self.baseurl,
@@ -23152,13 +30586,49 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "saml_identity_provider_view",
-        };
+        Ok(request)
+    }
+
+    ///Fetch a silo's IAM policy
+    ///
+    ///Sends a `GET` request to `/system/silos/{silo_name}/policy`
+    ///
+    ///Arguments:
+    /// - `silo_name`: The silo's unique name.
+    pub async fn silo_policy_view<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+    ) -> Result<ResponseValue<types::SiloRolePolicy>, Error<types::Error>> {
+        self.silo_policy_view_with_options(silo_name, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::silo_policy_view`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn silo_policy_view_with_options<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::SiloRolePolicy>, Error<types::Error>> {
+        let mut request = self
+            .silo_policy_view_request(silo_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[143]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -23171,16 +30641,11 @@ impl Client {
         }
     }
 
-    ///Fetch a silo's IAM policy
-    ///
-    ///Sends a `GET` request to `/system/silos/{silo_name}/policy`
-    ///
-    ///Arguments:
-    /// - `silo_name`: The silo's unique name.
-    pub async fn silo_policy_view<'a>(
+    ///Prepares the request of [`Client::silo_policy_view`] without sending it.
+    pub fn silo_policy_view_request<'a>(
         &'a self,
         silo_name: &'a types::Name,
-    ) -> Result<ResponseValue<types::SiloRolePolicy>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/silos/{}/policy",
             self.baseurl,
@@ -23201,13 +30666,56 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "silo_policy_view",
-        };
+        Ok(request)
+    }
+
+    ///Update a silo's IAM policy
+    ///
+    ///Sends a `PUT` request to `/system/silos/{silo_name}/policy`
+    ///
+    ///Arguments:
+    /// - `silo_name`: The silo's unique name.
+    /// - `body`
+    pub async fn silo_policy_update<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        body: &'a types::SiloRolePolicy,
+    ) -> Result<ResponseValue<types::SiloRolePolicy>, Error<types::Error>> {
+        self.silo_policy_update_with_options(
+            silo_name,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::silo_policy_update`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn silo_policy_update_with_options<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        body: &'a types::SiloRolePolicy,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::SiloRolePolicy>, Error<types::Error>> {
+        let mut request = self
+            .silo_policy_update_request(silo_name, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[144]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -23220,46 +30728,93 @@ impl Client {
         }
     }
 
-    ///Update a silo's IAM policy
+    ///Prepares the request of [`Client::silo_policy_update`] without sending
+    /// it.
+    pub fn silo_policy_update_request<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        body: &'a types::SiloRolePolicy,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+        let url = format!(
+            "{}/system/silos/{}/policy",
+            self.baseurl,
+            encode_path(&silo_name.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+        header_map.append(
+            ::reqwest::header::HeaderName::from_static("api-version"),
+            ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+        );
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .put(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .json(&body)
+            .headers(header_map)
+            .build()?;
+        Ok(request)
+    }
+
+    ///List users in a silo
     ///
-    ///Sends a `PUT` request to `/system/silos/{silo_name}/policy`
+    ///Sends a `GET` request to `/system/silos/{silo_name}/users/all`
     ///
     ///Arguments:
     /// - `silo_name`: The silo's unique name.
-    /// - `body`
-    pub async fn silo_policy_update<'a>(
+    /// - `limit`: Maximum number of items returned by a single call
+    /// - `page_token`: Token returned by previous call to retrieve the
+    ///   subsequent page
+    /// - `sort_by`
+    pub async fn silo_users_list<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+    ) -> Result<ResponseValue<types::UserResultsPage>, Error<types::Error>> {
+        self.silo_users_list_with_options(
+            silo_name,
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::silo_users_list`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn silo_users_list_with_options<'a>(
         &'a self,
         silo_name: &'a types::Name,
-        body: &'a types::SiloRolePolicy,
-    ) -> Result<ResponseValue<types::SiloRolePolicy>, Error<types::Error>> {
-        let url = format!(
-            "{}/system/silos/{}/policy",
-            self.baseurl,
-            encode_path(&silo_name.to_string()),
-        );
-        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
-        header_map.append(
-            ::reqwest::header::HeaderName::from_static("api-version"),
-            ::reqwest::header::HeaderValue::from_static(Self::api_version()),
-        );
-        #[allow(unused_mut)]
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::UserResultsPage>, Error<types::Error>> {
         let mut request = self
-            .client
-            .put(url)
-            .header(
-                ::reqwest::header::ACCEPT,
-                ::reqwest::header::HeaderValue::from_static("application/json"),
-            )
-            .json(&body)
-            .headers(header_map)
-            .build()?;
-        let info = OperationInfo {
-            operation_id: "silo_policy_update",
-        };
+            .silo_users_list_request(silo_name, limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[145]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -23272,23 +30827,14 @@ impl Client {
         }
     }
 
-    ///List users in a silo
-    ///
-    ///Sends a `GET` request to `/system/silos/{silo_name}/users/all`
-    ///
-    ///Arguments:
-    /// - `silo_name`: The silo's unique name.
-    /// - `limit`: Maximum number of items returned by a single call
-    /// - `page_token`: Token returned by previous call to retrieve the
-    ///   subsequent page
-    /// - `sort_by`
-    pub async fn silo_users_list<'a>(
+    ///Prepares the request of [`Client::silo_users_list`] without sending it.
+    pub fn silo_users_list_request<'a>(
         &'a self,
         silo_name: &'a types::Name,
         limit: Option<::std::num::NonZeroU32>,
         page_token: Option<&'a str>,
         sort_by: Option<types::IdSortMode>,
-    ) -> Result<ResponseValue<types::UserResultsPage>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/silos/{}/users/all",
             self.baseurl,
@@ -23315,23 +30861,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "silo_users_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List users in a silo as a Stream
@@ -23387,6 +30917,59 @@ impl Client {
         silo_name: &'a types::Name,
         user_id: &'a ::uuid::Uuid,
     ) -> Result<ResponseValue<types::User>, Error<types::Error>> {
+        self.silo_user_view_with_options(
+            silo_name,
+            user_id,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::silo_user_view`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn silo_user_view_with_options<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        user_id: &'a ::uuid::Uuid,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::User>, Error<types::Error>> {
+        let mut request = self
+            .silo_user_view_request(silo_name, user_id)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[146]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::silo_user_view`] without sending it.
+    pub fn silo_user_view_request<'a>(
+        &'a self,
+        silo_name: &'a types::Name,
+        user_id: &'a ::uuid::Uuid,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/silos/{}/users/id/{}",
             self.baseurl,
@@ -23408,23 +30991,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "silo_user_view",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List built-in users
@@ -23442,6 +31009,62 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::NameSortMode>,
     ) -> Result<ResponseValue<types::UserBuiltinResultsPage>, Error<types::Error>> {
+        self.system_user_list_with_options(
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::system_user_list`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn system_user_list_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::UserBuiltinResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .system_user_list_request(limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[147]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::system_user_list`] without sending it.
+    pub fn system_user_list_request<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/system/user", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -23464,23 +31087,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "system_user_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List built-in users as a Stream
@@ -23533,6 +31140,53 @@ impl Client {
         &'a self,
         user_name: &'a types::Name,
     ) -> Result<ResponseValue<types::UserBuiltin>, Error<types::Error>> {
+        self.system_user_view_with_options(user_name, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::system_user_view`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn system_user_view_with_options<'a>(
+        &'a self,
+        user_name: &'a types::Name,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::UserBuiltin>, Error<types::Error>> {
+        let mut request = self
+            .system_user_view_request(user_name)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[148]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::system_user_view`] without sending it.
+    pub fn system_user_view_request<'a>(
+        &'a self,
+        user_name: &'a types::Name,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/system/user/{}",
             self.baseurl,
@@ -23553,13 +31207,57 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "system_user_view",
-        };
+        Ok(request)
+    }
+
+    ///List timeseries schema
+    ///
+    ///Sends a `GET` request to `/timeseries/schema`
+    ///
+    ///Arguments:
+    /// - `limit`: Maximum number of items returned by a single call
+    /// - `page_token`: Token returned by previous call to retrieve the
+    ///   subsequent page
+    pub async fn timeseries_schema_get<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+    ) -> Result<ResponseValue<types::TimeseriesSchemaResultsPage>, Error<types::Error>> {
+        self.timeseries_schema_get_with_options(
+            limit,
+            page_token,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::timeseries_schema_get`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn timeseries_schema_get_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::TimeseriesSchemaResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .timeseries_schema_get_request(limit, page_token)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[149]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -23572,19 +31270,13 @@ impl Client {
         }
     }
 
-    ///List timeseries schema
-    ///
-    ///Sends a `GET` request to `/timeseries/schema`
-    ///
-    ///Arguments:
-    /// - `limit`: Maximum number of items returned by a single call
-    /// - `page_token`: Token returned by previous call to retrieve the
-    ///   subsequent page
-    pub async fn timeseries_schema_get<'a>(
+    ///Prepares the request of [`Client::timeseries_schema_get`] without
+    /// sending it.
+    pub fn timeseries_schema_get_request<'a>(
         &'a self,
         limit: Option<::std::num::NonZeroU32>,
         page_token: Option<&'a str>,
-    ) -> Result<ResponseValue<types::TimeseriesSchemaResultsPage>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/timeseries/schema", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -23606,23 +31298,7 @@ impl Client {
             ))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "timeseries_schema_get",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List timeseries schema as a Stream
@@ -23663,21 +31339,77 @@ impl Client {
             .boxed()
     }
 
-    ///List users
-    ///
-    ///Sends a `GET` request to `/users`
-    ///
-    ///Arguments:
-    /// - `limit`: Maximum number of items returned by a single call
-    /// - `page_token`: Token returned by previous call to retrieve the
-    ///   subsequent page
-    /// - `sort_by`
-    pub async fn user_list<'a>(
+    ///List users
+    ///
+    ///Sends a `GET` request to `/users`
+    ///
+    ///Arguments:
+    /// - `limit`: Maximum number of items returned by a single call
+    /// - `page_token`: Token returned by previous call to retrieve the
+    ///   subsequent page
+    /// - `sort_by`
+    pub async fn user_list<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+    ) -> Result<ResponseValue<types::UserResultsPage>, Error<types::Error>> {
+        self.user_list_with_options(
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::user_list`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn user_list_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::UserResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .user_list_request(limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[150]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::user_list`] without sending it.
+    pub fn user_list_request<'a>(
         &'a self,
         limit: Option<::std::num::NonZeroU32>,
         page_token: Option<&'a str>,
         sort_by: Option<types::IdSortMode>,
-    ) -> Result<ResponseValue<types::UserResultsPage>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/users", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -23700,23 +31432,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "user_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List users as a Stream
@@ -23777,6 +31493,68 @@ impl Client {
         project: Option<&'a types::NameOrId>,
         sort_by: Option<types::NameOrIdSortMode>,
     ) -> Result<ResponseValue<types::DiskResultsPage>, Error<types::Error>> {
+        self.disk_list_v1_with_options(
+            limit,
+            organization,
+            page_token,
+            project,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::disk_list_v1`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn disk_list_v1_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        organization: Option<&'a types::NameOrId>,
+        page_token: Option<&'a str>,
+        project: Option<&'a types::NameOrId>,
+        sort_by: Option<types::NameOrIdSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::DiskResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .disk_list_v1_request(limit, organization, page_token, project, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[151]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::disk_list_v1`] without sending it.
+    pub fn disk_list_v1_request<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        organization: Option<&'a types::NameOrId>,
+        page_token: Option<&'a str>,
+        project: Option<&'a types::NameOrId>,
+        sort_by: Option<types::NameOrIdSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/disks", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -23804,23 +31582,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "disk_list_v1",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List disks as a Stream
@@ -23875,6 +31637,62 @@ impl Client {
         project: &'a types::NameOrId,
         body: &'a types::DiskCreate,
     ) -> Result<ResponseValue<types::Disk>, Error<types::Error>> {
+        self.disk_create_v1_with_options(
+            organization,
+            project,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::disk_create_v1`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn disk_create_v1_with_options<'a>(
+        &'a self,
+        organization: Option<&'a types::NameOrId>,
+        project: &'a types::NameOrId,
+        body: &'a types::DiskCreate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Disk>, Error<types::Error>> {
+        let mut request = self
+            .disk_create_v1_request(organization, project, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[152]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::disk_create_v1`] without sending it.
+    pub fn disk_create_v1_request<'a>(
+        &'a self,
+        organization: Option<&'a types::NameOrId>,
+        project: &'a types::NameOrId,
+        body: &'a types::DiskCreate,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/disks", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -23897,15 +31715,57 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("project", &project))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "disk_create_v1",
-        };
+        Ok(request)
+    }
+
+    ///Fetch a disk
+    ///
+    ///Sends a `GET` request to `/v1/disks/{disk}`
+    pub async fn disk_view_v1<'a>(
+        &'a self,
+        disk: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+    ) -> Result<ResponseValue<types::Disk>, Error<types::Error>> {
+        self.disk_view_v1_with_options(
+            disk,
+            organization,
+            project,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::disk_view_v1`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn disk_view_v1_with_options<'a>(
+        &'a self,
+        disk: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Disk>, Error<types::Error>> {
+        let mut request = self
+            .disk_view_v1_request(disk, organization, project)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[153]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -23916,15 +31776,13 @@ impl Client {
         }
     }
 
-    ///Fetch a disk
-    ///
-    ///Sends a `GET` request to `/v1/disks/{disk}`
-    pub async fn disk_view_v1<'a>(
+    ///Prepares the request of [`Client::disk_view_v1`] without sending it.
+    pub fn disk_view_v1_request<'a>(
         &'a self,
         disk: &'a types::NameOrId,
         organization: Option<&'a types::NameOrId>,
         project: Option<&'a types::NameOrId>,
-    ) -> Result<ResponseValue<types::Disk>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/disks/{}",
             self.baseurl,
@@ -23950,15 +31808,57 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("project", &project))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "disk_view_v1",
-        };
+        Ok(request)
+    }
+
+    ///Delete a disk
+    ///
+    ///Sends a `DELETE` request to `/v1/disks/{disk}`
+    pub async fn disk_delete_v1<'a>(
+        &'a self,
+        disk: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.disk_delete_v1_with_options(
+            disk,
+            organization,
+            project,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::disk_delete_v1`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn disk_delete_v1_with_options<'a>(
+        &'a self,
+        disk: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .disk_delete_v1_request(disk, organization, project)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[154]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            204u16 => Ok(ResponseValue::empty(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -23969,15 +31869,13 @@ impl Client {
         }
     }
 
-    ///Delete a disk
-    ///
-    ///Sends a `DELETE` request to `/v1/disks/{disk}`
-    pub async fn disk_delete_v1<'a>(
+    ///Prepares the request of [`Client::disk_delete_v1`] without sending it.
+    pub fn disk_delete_v1_request<'a>(
         &'a self,
         disk: &'a types::NameOrId,
         organization: Option<&'a types::NameOrId>,
         project: Option<&'a types::NameOrId>,
-    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/disks/{}",
             self.baseurl,
@@ -24003,23 +31901,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("project", &project))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "disk_delete_v1",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List instances
@@ -24041,6 +31923,68 @@ impl Client {
         project: Option<&'a types::NameOrId>,
         sort_by: Option<types::NameOrIdSortMode>,
     ) -> Result<ResponseValue<types::InstanceResultsPage>, Error<types::Error>> {
+        self.instance_list_v1_with_options(
+            limit,
+            organization,
+            page_token,
+            project,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_list_v1`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_list_v1_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        organization: Option<&'a types::NameOrId>,
+        page_token: Option<&'a str>,
+        project: Option<&'a types::NameOrId>,
+        sort_by: Option<types::NameOrIdSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::InstanceResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .instance_list_v1_request(limit, organization, page_token, project, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[155]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::instance_list_v1`] without sending it.
+    pub fn instance_list_v1_request<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        organization: Option<&'a types::NameOrId>,
+        page_token: Option<&'a str>,
+        project: Option<&'a types::NameOrId>,
+        sort_by: Option<types::NameOrIdSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/instances", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -24068,23 +32012,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_list_v1",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List instances as a Stream
@@ -24124,22 +32052,79 @@ impl Client {
                             .await
                     }
                 })
-                .try_flatten();
-                first.chain(rest)
-            })
-            .try_flatten_stream()
-            .boxed()
+                .try_flatten();
+                first.chain(rest)
+            })
+            .try_flatten_stream()
+            .boxed()
+    }
+
+    ///Create an instance
+    ///
+    ///Sends a `POST` request to `/v1/instances`
+    pub async fn instance_create_v1<'a>(
+        &'a self,
+        organization: Option<&'a types::NameOrId>,
+        project: &'a types::NameOrId,
+        body: &'a types::InstanceCreate,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        self.instance_create_v1_with_options(
+            organization,
+            project,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_create_v1`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_create_v1_with_options<'a>(
+        &'a self,
+        organization: Option<&'a types::NameOrId>,
+        project: &'a types::NameOrId,
+        body: &'a types::InstanceCreate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        let mut request = self
+            .instance_create_v1_request(organization, project, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[156]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
     }
 
-    ///Create an instance
-    ///
-    ///Sends a `POST` request to `/v1/instances`
-    pub async fn instance_create_v1<'a>(
+    ///Prepares the request of [`Client::instance_create_v1`] without sending
+    /// it.
+    pub fn instance_create_v1_request<'a>(
         &'a self,
         organization: Option<&'a types::NameOrId>,
         project: &'a types::NameOrId,
         body: &'a types::InstanceCreate,
-    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/instances", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -24162,15 +32147,57 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("project", &project))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_create_v1",
-        };
+        Ok(request)
+    }
+
+    ///Fetch an instance
+    ///
+    ///Sends a `GET` request to `/v1/instances/{instance}`
+    pub async fn instance_view_v1<'a>(
+        &'a self,
+        instance: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        self.instance_view_v1_with_options(
+            instance,
+            organization,
+            project,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_view_v1`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_view_v1_with_options<'a>(
+        &'a self,
+        instance: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        let mut request = self
+            .instance_view_v1_request(instance, organization, project)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[157]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -24181,15 +32208,13 @@ impl Client {
         }
     }
 
-    ///Fetch an instance
-    ///
-    ///Sends a `GET` request to `/v1/instances/{instance}`
-    pub async fn instance_view_v1<'a>(
+    ///Prepares the request of [`Client::instance_view_v1`] without sending it.
+    pub fn instance_view_v1_request<'a>(
         &'a self,
         instance: &'a types::NameOrId,
         organization: Option<&'a types::NameOrId>,
         project: Option<&'a types::NameOrId>,
-    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/instances/{}",
             self.baseurl,
@@ -24215,15 +32240,57 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("project", &project))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_view_v1",
-        };
+        Ok(request)
+    }
+
+    ///Delete an instance
+    ///
+    ///Sends a `DELETE` request to `/v1/instances/{instance}`
+    pub async fn instance_delete_v1<'a>(
+        &'a self,
+        instance: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.instance_delete_v1_with_options(
+            instance,
+            organization,
+            project,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_delete_v1`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_delete_v1_with_options<'a>(
+        &'a self,
+        instance: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .instance_delete_v1_request(instance, organization, project)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[158]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            204u16 => Ok(ResponseValue::empty(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -24234,15 +32301,14 @@ impl Client {
         }
     }
 
-    ///Delete an instance
-    ///
-    ///Sends a `DELETE` request to `/v1/instances/{instance}`
-    pub async fn instance_delete_v1<'a>(
+    ///Prepares the request of [`Client::instance_delete_v1`] without sending
+    /// it.
+    pub fn instance_delete_v1_request<'a>(
         &'a self,
         instance: &'a types::NameOrId,
         organization: Option<&'a types::NameOrId>,
         project: Option<&'a types::NameOrId>,
-    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/instances/{}",
             self.baseurl,
@@ -24268,23 +32334,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("project", &project))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_delete_v1",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List an instance's disks
@@ -24308,6 +32358,79 @@ impl Client {
         project: Option<&'a types::NameOrId>,
         sort_by: Option<types::NameOrIdSortMode>,
     ) -> Result<ResponseValue<types::DiskResultsPage>, Error<types::Error>> {
+        self.instance_disk_list_v1_with_options(
+            instance,
+            limit,
+            organization,
+            page_token,
+            project,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_disk_list_v1`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_disk_list_v1_with_options<'a>(
+        &'a self,
+        instance: &'a types::NameOrId,
+        limit: Option<::std::num::NonZeroU32>,
+        organization: Option<&'a types::NameOrId>,
+        page_token: Option<&'a str>,
+        project: Option<&'a types::NameOrId>,
+        sort_by: Option<types::NameOrIdSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::DiskResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .instance_disk_list_v1_request(
+                instance,
+                limit,
+                organization,
+                page_token,
+                project,
+                sort_by,
+            )
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[159]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::instance_disk_list_v1`] without
+    /// sending it.
+    pub fn instance_disk_list_v1_request<'a>(
+        &'a self,
+        instance: &'a types::NameOrId,
+        limit: Option<::std::num::NonZeroU32>,
+        organization: Option<&'a types::NameOrId>,
+        page_token: Option<&'a str>,
+        project: Option<&'a types::NameOrId>,
+        sort_by: Option<types::NameOrIdSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/instances/{}/disks",
             self.baseurl,
@@ -24339,23 +32462,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_disk_list_v1",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List an instance's disks as a Stream
@@ -24420,6 +32527,66 @@ impl Client {
         project: Option<&'a types::NameOrId>,
         body: &'a types::DiskPath,
     ) -> Result<ResponseValue<types::Disk>, Error<types::Error>> {
+        self.instance_disk_attach_v1_with_options(
+            instance,
+            organization,
+            project,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_disk_attach_v1`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_disk_attach_v1_with_options<'a>(
+        &'a self,
+        instance: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+        body: &'a types::DiskPath,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Disk>, Error<types::Error>> {
+        let mut request = self
+            .instance_disk_attach_v1_request(instance, organization, project, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[160]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            202u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::instance_disk_attach_v1`] without
+    /// sending it.
+    pub fn instance_disk_attach_v1_request<'a>(
+        &'a self,
+        instance: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+        body: &'a types::DiskPath,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/instances/{}/disks/attach",
             self.baseurl,
@@ -24446,13 +32613,58 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("project", &project))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_disk_attach_v1",
-        };
+        Ok(request)
+    }
+
+    ///Detach a disk from an instance
+    ///
+    ///Sends a `POST` request to `/v1/instances/{instance}/disks/detach`
+    pub async fn instance_disk_detach_v1<'a>(
+        &'a self,
+        instance: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+        body: &'a types::DiskPath,
+    ) -> Result<ResponseValue<types::Disk>, Error<types::Error>> {
+        self.instance_disk_detach_v1_with_options(
+            instance,
+            organization,
+            project,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_disk_detach_v1`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_disk_detach_v1_with_options<'a>(
+        &'a self,
+        instance: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+        body: &'a types::DiskPath,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Disk>, Error<types::Error>> {
+        let mut request = self
+            .instance_disk_detach_v1_request(instance, organization, project, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[161]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             202u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -24465,16 +32677,15 @@ impl Client {
         }
     }
 
-    ///Detach a disk from an instance
-    ///
-    ///Sends a `POST` request to `/v1/instances/{instance}/disks/detach`
-    pub async fn instance_disk_detach_v1<'a>(
+    ///Prepares the request of [`Client::instance_disk_detach_v1`] without
+    /// sending it.
+    pub fn instance_disk_detach_v1_request<'a>(
         &'a self,
         instance: &'a types::NameOrId,
         organization: Option<&'a types::NameOrId>,
         project: Option<&'a types::NameOrId>,
         body: &'a types::DiskPath,
-    ) -> Result<ResponseValue<types::Disk>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/instances/{}/disks/detach",
             self.baseurl,
@@ -24501,15 +32712,60 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("project", &project))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_disk_detach_v1",
-        };
+        Ok(request)
+    }
+
+    ///Migrate an instance
+    ///
+    ///Sends a `POST` request to `/v1/instances/{instance}/migrate`
+    pub async fn instance_migrate_v1<'a>(
+        &'a self,
+        instance: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+        body: &'a types::InstanceMigrate,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        self.instance_migrate_v1_with_options(
+            instance,
+            organization,
+            project,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_migrate_v1`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_migrate_v1_with_options<'a>(
+        &'a self,
+        instance: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+        body: &'a types::InstanceMigrate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        let mut request = self
+            .instance_migrate_v1_request(instance, organization, project, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[162]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            202u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -24520,16 +32776,15 @@ impl Client {
         }
     }
 
-    ///Migrate an instance
-    ///
-    ///Sends a `POST` request to `/v1/instances/{instance}/migrate`
-    pub async fn instance_migrate_v1<'a>(
+    ///Prepares the request of [`Client::instance_migrate_v1`] without sending
+    /// it.
+    pub fn instance_migrate_v1_request<'a>(
         &'a self,
         instance: &'a types::NameOrId,
         organization: Option<&'a types::NameOrId>,
         project: Option<&'a types::NameOrId>,
         body: &'a types::InstanceMigrate,
-    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/instances/{}/migrate",
             self.baseurl,
@@ -24556,15 +32811,57 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("project", &project))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_migrate_v1",
-        };
+        Ok(request)
+    }
+
+    ///Reboot an instance
+    ///
+    ///Sends a `POST` request to `/v1/instances/{instance}/reboot`
+    pub async fn instance_reboot_v1<'a>(
+        &'a self,
+        instance: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        self.instance_reboot_v1_with_options(
+            instance,
+            organization,
+            project,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_reboot_v1`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_reboot_v1_with_options<'a>(
+        &'a self,
+        instance: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        let mut request = self
+            .instance_reboot_v1_request(instance, organization, project)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[163]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            202u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -24575,15 +32872,14 @@ impl Client {
         }
     }
 
-    ///Reboot an instance
-    ///
-    ///Sends a `POST` request to `/v1/instances/{instance}/reboot`
-    pub async fn instance_reboot_v1<'a>(
+    ///Prepares the request of [`Client::instance_reboot_v1`] without sending
+    /// it.
+    pub fn instance_reboot_v1_request<'a>(
         &'a self,
         instance: &'a types::NameOrId,
         organization: Option<&'a types::NameOrId>,
         project: Option<&'a types::NameOrId>,
-    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/instances/{}/reboot",
             self.baseurl,
@@ -24609,23 +32905,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("project", &project))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_reboot_v1",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            202u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Fetch an instance's serial console
@@ -24656,6 +32936,79 @@ impl Client {
         organization: Option<&'a types::NameOrId>,
         project: Option<&'a types::NameOrId>,
     ) -> Result<ResponseValue<types::InstanceSerialConsoleData>, Error<types::Error>> {
+        self.instance_serial_console_v1_with_options(
+            instance,
+            from_start,
+            max_bytes,
+            most_recent,
+            organization,
+            project,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_serial_console_v1`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn instance_serial_console_v1_with_options<'a>(
+        &'a self,
+        instance: &'a types::NameOrId,
+        from_start: Option<u64>,
+        max_bytes: Option<u64>,
+        most_recent: Option<u64>,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::InstanceSerialConsoleData>, Error<types::Error>> {
+        let mut request = self
+            .instance_serial_console_v1_request(
+                instance,
+                from_start,
+                max_bytes,
+                most_recent,
+                organization,
+                project,
+            )
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[164]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::instance_serial_console_v1`] without
+    /// sending it.
+    pub fn instance_serial_console_v1_request<'a>(
+        &'a self,
+        instance: &'a types::NameOrId,
+        from_start: Option<u64>,
+        max_bytes: Option<u64>,
+        most_recent: Option<u64>,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/instances/{}/serial-console",
             self.baseurl,
@@ -24690,23 +33043,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("project", &project))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_serial_console_v1",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Stream an instance's serial console
@@ -24719,6 +33056,58 @@ impl Client {
         organization: Option<&'a types::NameOrId>,
         project: Option<&'a types::NameOrId>,
     ) -> Result<ResponseValue<reqwest::Upgraded>, Error<reqwest::Upgraded>> {
+        self.instance_serial_console_stream_v1_with_options(
+            instance,
+            organization,
+            project,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_serial_console_stream_v1`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn instance_serial_console_stream_v1_with_options<'a>(
+        &'a self,
+        instance: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<reqwest::Upgraded>, Error<reqwest::Upgraded>> {
+        let mut request = self
+            .instance_serial_console_stream_v1_request(instance, organization, project)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[165]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            101u16 => ResponseValue::upgrade(response).await,
+            200..=299 => ResponseValue::upgrade(response).await,
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::instance_serial_console_stream_v1`]
+    /// without sending it.
+    pub fn instance_serial_console_stream_v1_request<'a>(
+        &'a self,
+        instance: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/instances/{}/serial-console/stream",
             self.baseurl,
@@ -24750,18 +33139,7 @@ impl Client {
                 ),
             )
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_serial_console_stream_v1",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            101u16 => ResponseValue::upgrade(response).await,
-            200..=299 => ResponseValue::upgrade(response).await,
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///Boot an instance
@@ -24773,6 +33151,63 @@ impl Client {
         organization: Option<&'a types::NameOrId>,
         project: Option<&'a types::NameOrId>,
     ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        self.instance_start_v1_with_options(
+            instance,
+            organization,
+            project,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_start_v1`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_start_v1_with_options<'a>(
+        &'a self,
+        instance: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        let mut request = self
+            .instance_start_v1_request(instance, organization, project)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[166]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            202u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::instance_start_v1`] without sending
+    /// it.
+    pub fn instance_start_v1_request<'a>(
+        &'a self,
+        instance: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/instances/{}/start",
             self.baseurl,
@@ -24798,13 +33233,55 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("project", &project))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_start_v1",
-        };
+        Ok(request)
+    }
+
+    ///Stop an instance
+    ///
+    ///Sends a `POST` request to `/v1/instances/{instance}/stop`
+    pub async fn instance_stop_v1<'a>(
+        &'a self,
+        instance: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        self.instance_stop_v1_with_options(
+            instance,
+            organization,
+            project,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_stop_v1`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_stop_v1_with_options<'a>(
+        &'a self,
+        instance: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        project: Option<&'a types::NameOrId>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+        let mut request = self
+            .instance_stop_v1_request(instance, organization, project)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[167]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             202u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -24817,15 +33294,13 @@ impl Client {
         }
     }
 
-    ///Stop an instance
-    ///
-    ///Sends a `POST` request to `/v1/instances/{instance}/stop`
-    pub async fn instance_stop_v1<'a>(
+    ///Prepares the request of [`Client::instance_stop_v1`] without sending it.
+    pub fn instance_stop_v1_request<'a>(
         &'a self,
         instance: &'a types::NameOrId,
         organization: Option<&'a types::NameOrId>,
         project: Option<&'a types::NameOrId>,
-    ) -> Result<ResponseValue<types::Instance>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/instances/{}/stop",
             self.baseurl,
@@ -24851,23 +33326,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("project", &project))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_stop_v1",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            202u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List organizations
@@ -24885,6 +33344,63 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::NameOrIdSortMode>,
     ) -> Result<ResponseValue<types::OrganizationResultsPage>, Error<types::Error>> {
+        self.organization_list_v1_with_options(
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::organization_list_v1`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn organization_list_v1_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameOrIdSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::OrganizationResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .organization_list_v1_request(limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[168]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::organization_list_v1`] without sending
+    /// it.
+    pub fn organization_list_v1_request<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameOrIdSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/organizations", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -24907,23 +33423,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "organization_list_v1",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List organizations as a Stream
@@ -24972,7 +33472,55 @@ impl Client {
     pub async fn organization_create_v1<'a>(
         &'a self,
         body: &'a types::OrganizationCreate,
-    ) -> Result<ResponseValue<types::Organization>, Error<types::Error>> {
+    ) -> Result<ResponseValue<types::Organization>, Error<types::Error>> {
+        self.organization_create_v1_with_options(body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::organization_create_v1`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn organization_create_v1_with_options<'a>(
+        &'a self,
+        body: &'a types::OrganizationCreate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Organization>, Error<types::Error>> {
+        let mut request = self
+            .organization_create_v1_request(body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[169]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::organization_create_v1`] without
+    /// sending it.
+    pub fn organization_create_v1_request<'a>(
+        &'a self,
+        body: &'a types::OrganizationCreate,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/organizations", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -24990,15 +33538,51 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "organization_create_v1",
-        };
+        Ok(request)
+    }
+
+    ///Fetch an organization
+    ///
+    ///Sends a `GET` request to `/v1/organizations/{organization}`
+    pub async fn organization_view_v1<'a>(
+        &'a self,
+        organization: &'a types::NameOrId,
+    ) -> Result<ResponseValue<types::Organization>, Error<types::Error>> {
+        self.organization_view_v1_with_options(
+            organization,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::organization_view_v1`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn organization_view_v1_with_options<'a>(
+        &'a self,
+        organization: &'a types::NameOrId,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Organization>, Error<types::Error>> {
+        let mut request = self
+            .organization_view_v1_request(organization)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[170]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -25009,13 +33593,12 @@ impl Client {
         }
     }
 
-    ///Fetch an organization
-    ///
-    ///Sends a `GET` request to `/v1/organizations/{organization}`
-    pub async fn organization_view_v1<'a>(
+    ///Prepares the request of [`Client::organization_view_v1`] without sending
+    /// it.
+    pub fn organization_view_v1_request<'a>(
         &'a self,
         organization: &'a types::NameOrId,
-    ) -> Result<ResponseValue<types::Organization>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/organizations/{}",
             self.baseurl,
@@ -25036,13 +33619,52 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "organization_view_v1",
-        };
+        Ok(request)
+    }
+
+    ///Update an organization
+    ///
+    ///Sends a `PUT` request to `/v1/organizations/{organization}`
+    pub async fn organization_update_v1<'a>(
+        &'a self,
+        organization: &'a types::NameOrId,
+        body: &'a types::OrganizationUpdate,
+    ) -> Result<ResponseValue<types::Organization>, Error<types::Error>> {
+        self.organization_update_v1_with_options(
+            organization,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::organization_update_v1`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn organization_update_v1_with_options<'a>(
+        &'a self,
+        organization: &'a types::NameOrId,
+        body: &'a types::OrganizationUpdate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Organization>, Error<types::Error>> {
+        let mut request = self
+            .organization_update_v1_request(organization, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[171]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -25055,14 +33677,13 @@ impl Client {
         }
     }
 
-    ///Update an organization
-    ///
-    ///Sends a `PUT` request to `/v1/organizations/{organization}`
-    pub async fn organization_update_v1<'a>(
+    ///Prepares the request of [`Client::organization_update_v1`] without
+    /// sending it.
+    pub fn organization_update_v1_request<'a>(
         &'a self,
         organization: &'a types::NameOrId,
         body: &'a types::OrganizationUpdate,
-    ) -> Result<ResponseValue<types::Organization>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/organizations/{}",
             self.baseurl,
@@ -25084,15 +33705,51 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "organization_update_v1",
-        };
+        Ok(request)
+    }
+
+    ///Delete an organization
+    ///
+    ///Sends a `DELETE` request to `/v1/organizations/{organization}`
+    pub async fn organization_delete_v1<'a>(
+        &'a self,
+        organization: &'a types::NameOrId,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.organization_delete_v1_with_options(
+            organization,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::organization_delete_v1`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn organization_delete_v1_with_options<'a>(
+        &'a self,
+        organization: &'a types::NameOrId,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .organization_delete_v1_request(organization)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[172]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            204u16 => Ok(ResponseValue::empty(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -25103,13 +33760,12 @@ impl Client {
         }
     }
 
-    ///Delete an organization
-    ///
-    ///Sends a `DELETE` request to `/v1/organizations/{organization}`
-    pub async fn organization_delete_v1<'a>(
+    ///Prepares the request of [`Client::organization_delete_v1`] without
+    /// sending it.
+    pub fn organization_delete_v1_request<'a>(
         &'a self,
         organization: &'a types::NameOrId,
-    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/organizations/{}",
             self.baseurl,
@@ -25130,15 +33786,51 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "organization_delete_v1",
-        };
+        Ok(request)
+    }
+
+    ///Fetch an organization's IAM policy
+    ///
+    ///Sends a `GET` request to `/v1/organizations/{organization}/policy`
+    pub async fn organization_policy_view_v1<'a>(
+        &'a self,
+        organization: &'a types::NameOrId,
+    ) -> Result<ResponseValue<types::OrganizationRolePolicy>, Error<types::Error>> {
+        self.organization_policy_view_v1_with_options(
+            organization,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::organization_policy_view_v1`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn organization_policy_view_v1_with_options<'a>(
+        &'a self,
+        organization: &'a types::NameOrId,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::OrganizationRolePolicy>, Error<types::Error>> {
+        let mut request = self
+            .organization_policy_view_v1_request(organization)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[173]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -25149,13 +33841,12 @@ impl Client {
         }
     }
 
-    ///Fetch an organization's IAM policy
-    ///
-    ///Sends a `GET` request to `/v1/organizations/{organization}/policy`
-    pub async fn organization_policy_view_v1<'a>(
+    ///Prepares the request of [`Client::organization_policy_view_v1`] without
+    /// sending it.
+    pub fn organization_policy_view_v1_request<'a>(
         &'a self,
         organization: &'a types::NameOrId,
-    ) -> Result<ResponseValue<types::OrganizationRolePolicy>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/organizations/{}/policy",
             self.baseurl,
@@ -25176,13 +33867,52 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "organization_policy_view_v1",
-        };
+        Ok(request)
+    }
+
+    ///Update an organization's IAM policy
+    ///
+    ///Sends a `PUT` request to `/v1/organizations/{organization}/policy`
+    pub async fn organization_policy_update_v1<'a>(
+        &'a self,
+        organization: &'a types::NameOrId,
+        body: &'a types::OrganizationRolePolicy,
+    ) -> Result<ResponseValue<types::OrganizationRolePolicy>, Error<types::Error>> {
+        self.organization_policy_update_v1_with_options(
+            organization,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::organization_policy_update_v1`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn organization_policy_update_v1_with_options<'a>(
+        &'a self,
+        organization: &'a types::NameOrId,
+        body: &'a types::OrganizationRolePolicy,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::OrganizationRolePolicy>, Error<types::Error>> {
+        let mut request = self
+            .organization_policy_update_v1_request(organization, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[174]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -25195,14 +33925,13 @@ impl Client {
         }
     }
 
-    ///Update an organization's IAM policy
-    ///
-    ///Sends a `PUT` request to `/v1/organizations/{organization}/policy`
-    pub async fn organization_policy_update_v1<'a>(
+    ///Prepares the request of [`Client::organization_policy_update_v1`]
+    /// without sending it.
+    pub fn organization_policy_update_v1_request<'a>(
         &'a self,
         organization: &'a types::NameOrId,
         body: &'a types::OrganizationRolePolicy,
-    ) -> Result<ResponseValue<types::OrganizationRolePolicy>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/organizations/{}/policy",
             self.baseurl,
@@ -25224,23 +33953,7 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "organization_policy_update_v1",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List projects
@@ -25260,6 +33973,65 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::NameOrIdSortMode>,
     ) -> Result<ResponseValue<types::ProjectResultsPage>, Error<types::Error>> {
+        self.project_list_v1_with_options(
+            limit,
+            organization,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::project_list_v1`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn project_list_v1_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        organization: Option<&'a types::NameOrId>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameOrIdSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::ProjectResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .project_list_v1_request(limit, organization, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[175]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::project_list_v1`] without sending it.
+    pub fn project_list_v1_request<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        organization: Option<&'a types::NameOrId>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::NameOrIdSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/projects", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -25286,23 +34058,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "project_list_v1",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List projects as a Stream
@@ -25354,6 +34110,60 @@ impl Client {
         organization: &'a types::NameOrId,
         body: &'a types::ProjectCreate,
     ) -> Result<ResponseValue<types::Project>, Error<types::Error>> {
+        self.project_create_v1_with_options(
+            organization,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::project_create_v1`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn project_create_v1_with_options<'a>(
+        &'a self,
+        organization: &'a types::NameOrId,
+        body: &'a types::ProjectCreate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Project>, Error<types::Error>> {
+        let mut request = self
+            .project_create_v1_request(organization, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[176]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::project_create_v1`] without sending
+    /// it.
+    pub fn project_create_v1_request<'a>(
+        &'a self,
+        organization: &'a types::NameOrId,
+        body: &'a types::ProjectCreate,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/projects", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -25362,28 +34172,67 @@ impl Client {
         );
         #[allow(unused_mut)]
         let mut request = self
-            .client
-            .post(url)
-            .header(
-                ::reqwest::header::ACCEPT,
-                ::reqwest::header::HeaderValue::from_static("application/json"),
-            )
-            .json(&body)
-            .query(&progenitor_client::QueryParam::new(
-                "organization",
-                &organization,
-            ))
-            .headers(header_map)
-            .build()?;
-        let info = OperationInfo {
-            operation_id: "project_create_v1",
-        };
+            .client
+            .post(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .json(&body)
+            .query(&progenitor_client::QueryParam::new(
+                "organization",
+                &organization,
+            ))
+            .headers(header_map)
+            .build()?;
+        Ok(request)
+    }
+
+    ///Fetch a project
+    ///
+    ///Sends a `GET` request to `/v1/projects/{project}`
+    pub async fn project_view_v1<'a>(
+        &'a self,
+        project: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+    ) -> Result<ResponseValue<types::Project>, Error<types::Error>> {
+        self.project_view_v1_with_options(
+            project,
+            organization,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::project_view_v1`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn project_view_v1_with_options<'a>(
+        &'a self,
+        project: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Project>, Error<types::Error>> {
+        let mut request = self
+            .project_view_v1_request(project, organization)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[177]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -25394,14 +34243,12 @@ impl Client {
         }
     }
 
-    ///Fetch a project
-    ///
-    ///Sends a `GET` request to `/v1/projects/{project}`
-    pub async fn project_view_v1<'a>(
+    ///Prepares the request of [`Client::project_view_v1`] without sending it.
+    pub fn project_view_v1_request<'a>(
         &'a self,
         project: &'a types::NameOrId,
         organization: Option<&'a types::NameOrId>,
-    ) -> Result<ResponseValue<types::Project>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/projects/{}",
             self.baseurl,
@@ -25426,13 +34273,55 @@ impl Client {
             ))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "project_view_v1",
-        };
+        Ok(request)
+    }
+
+    ///Update a project
+    ///
+    ///Sends a `PUT` request to `/v1/projects/{project}`
+    pub async fn project_update_v1<'a>(
+        &'a self,
+        project: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        body: &'a types::ProjectUpdate,
+    ) -> Result<ResponseValue<types::Project>, Error<types::Error>> {
+        self.project_update_v1_with_options(
+            project,
+            organization,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::project_update_v1`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn project_update_v1_with_options<'a>(
+        &'a self,
+        project: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        body: &'a types::ProjectUpdate,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Project>, Error<types::Error>> {
+        let mut request = self
+            .project_update_v1_request(project, organization, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[178]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -25445,15 +34334,14 @@ impl Client {
         }
     }
 
-    ///Update a project
-    ///
-    ///Sends a `PUT` request to `/v1/projects/{project}`
-    pub async fn project_update_v1<'a>(
+    ///Prepares the request of [`Client::project_update_v1`] without sending
+    /// it.
+    pub fn project_update_v1_request<'a>(
         &'a self,
         project: &'a types::NameOrId,
         organization: Option<&'a types::NameOrId>,
         body: &'a types::ProjectUpdate,
-    ) -> Result<ResponseValue<types::Project>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/projects/{}",
             self.baseurl,
@@ -25479,15 +34367,54 @@ impl Client {
             ))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "project_update_v1",
-        };
+        Ok(request)
+    }
+
+    ///Delete a project
+    ///
+    ///Sends a `DELETE` request to `/v1/projects/{project}`
+    pub async fn project_delete_v1<'a>(
+        &'a self,
+        project: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.project_delete_v1_with_options(
+            project,
+            organization,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::project_delete_v1`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn project_delete_v1_with_options<'a>(
+        &'a self,
+        project: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .project_delete_v1_request(project, organization)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[179]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            204u16 => Ok(ResponseValue::empty(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -25498,14 +34425,13 @@ impl Client {
         }
     }
 
-    ///Delete a project
-    ///
-    ///Sends a `DELETE` request to `/v1/projects/{project}`
-    pub async fn project_delete_v1<'a>(
+    ///Prepares the request of [`Client::project_delete_v1`] without sending
+    /// it.
+    pub fn project_delete_v1_request<'a>(
         &'a self,
         project: &'a types::NameOrId,
         organization: Option<&'a types::NameOrId>,
-    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/projects/{}",
             self.baseurl,
@@ -25530,15 +34456,54 @@ impl Client {
             ))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "project_delete_v1",
-        };
+        Ok(request)
+    }
+
+    ///Fetch a project's IAM policy
+    ///
+    ///Sends a `GET` request to `/v1/projects/{project}/policy`
+    pub async fn project_policy_view_v1<'a>(
+        &'a self,
+        project: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+    ) -> Result<ResponseValue<types::ProjectRolePolicy>, Error<types::Error>> {
+        self.project_policy_view_v1_with_options(
+            project,
+            organization,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::project_policy_view_v1`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn project_policy_view_v1_with_options<'a>(
+        &'a self,
+        project: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::ProjectRolePolicy>, Error<types::Error>> {
+        let mut request = self
+            .project_policy_view_v1_request(project, organization)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[180]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -25549,14 +34514,13 @@ impl Client {
         }
     }
 
-    ///Fetch a project's IAM policy
-    ///
-    ///Sends a `GET` request to `/v1/projects/{project}/policy`
-    pub async fn project_policy_view_v1<'a>(
+    ///Prepares the request of [`Client::project_policy_view_v1`] without
+    /// sending it.
+    pub fn project_policy_view_v1_request<'a>(
         &'a self,
         project: &'a types::NameOrId,
         organization: Option<&'a types::NameOrId>,
-    ) -> Result<ResponseValue<types::ProjectRolePolicy>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/projects/{}/policy",
             self.baseurl,
@@ -25581,13 +34545,55 @@ impl Client {
             ))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "project_policy_view_v1",
-        };
+        Ok(request)
+    }
+
+    ///Update a project's IAM policy
+    ///
+    ///Sends a `PUT` request to `/v1/projects/{project}/policy`
+    pub async fn project_policy_update_v1<'a>(
+        &'a self,
+        project: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        body: &'a types::ProjectRolePolicy,
+    ) -> Result<ResponseValue<types::ProjectRolePolicy>, Error<types::Error>> {
+        self.project_policy_update_v1_with_options(
+            project,
+            organization,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::project_policy_update_v1`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn project_policy_update_v1_with_options<'a>(
+        &'a self,
+        project: &'a types::NameOrId,
+        organization: Option<&'a types::NameOrId>,
+        body: &'a types::ProjectRolePolicy,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::ProjectRolePolicy>, Error<types::Error>> {
+        let mut request = self
+            .project_policy_update_v1_request(project, organization, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[181]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -25600,15 +34606,14 @@ impl Client {
         }
     }
 
-    ///Update a project's IAM policy
-    ///
-    ///Sends a `PUT` request to `/v1/projects/{project}/policy`
-    pub async fn project_policy_update_v1<'a>(
+    ///Prepares the request of [`Client::project_policy_update_v1`] without
+    /// sending it.
+    pub fn project_policy_update_v1_request<'a>(
         &'a self,
         project: &'a types::NameOrId,
         organization: Option<&'a types::NameOrId>,
         body: &'a types::ProjectRolePolicy,
-    ) -> Result<ResponseValue<types::ProjectRolePolicy>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/projects/{}/policy",
             self.baseurl,
@@ -25634,23 +34639,7 @@ impl Client {
             ))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "project_policy_update_v1",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///View version and update status of component tree
@@ -25668,6 +34657,63 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::IdSortMode>,
     ) -> Result<ResponseValue<types::UpdateableComponentResultsPage>, Error<types::Error>> {
+        self.system_component_version_list_with_options(
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::system_component_version_list`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn system_component_version_list_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::UpdateableComponentResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .system_component_version_list_request(limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[182]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::system_component_version_list`]
+    /// without sending it.
+    pub fn system_component_version_list_request<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/system/update/components", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -25690,23 +34736,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "system_component_version_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///View version and update status of component tree as a Stream
@@ -25764,6 +34794,63 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::IdSortMode>,
     ) -> Result<ResponseValue<types::UpdateDeploymentResultsPage>, Error<types::Error>> {
+        self.update_deployments_list_with_options(
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::update_deployments_list`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn update_deployments_list_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::UpdateDeploymentResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .update_deployments_list_request(limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[183]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::update_deployments_list`] without
+    /// sending it.
+    pub fn update_deployments_list_request<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/system/update/deployments", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -25779,30 +34866,14 @@ impl Client {
                 ::reqwest::header::HeaderValue::from_static("application/json"),
             )
             .query(&progenitor_client::QueryParam::new("limit", &limit))
-            .query(&progenitor_client::QueryParam::new(
-                "page_token",
-                &page_token,
-            ))
-            .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
-            .headers(header_map)
-            .build()?;
-        let info = OperationInfo {
-            operation_id: "update_deployments_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+            .query(&progenitor_client::QueryParam::new(
+                "page_token",
+                &page_token,
+            ))
+            .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
+            .headers(header_map)
+            .build()?;
+        Ok(request)
     }
 
     ///List all update deployments as a Stream
@@ -25852,6 +34923,54 @@ impl Client {
         &'a self,
         id: &'a ::uuid::Uuid,
     ) -> Result<ResponseValue<types::UpdateDeployment>, Error<types::Error>> {
+        self.update_deployment_view_with_options(id, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::update_deployment_view`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn update_deployment_view_with_options<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::UpdateDeployment>, Error<types::Error>> {
+        let mut request = self
+            .update_deployment_view_request(id)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[184]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::update_deployment_view`] without
+    /// sending it.
+    pub fn update_deployment_view_request<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/system/update/deployments/{}",
             self.baseurl,
@@ -25872,15 +34991,46 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "update_deployment_view",
-        };
+        Ok(request)
+    }
+
+    ///Refresh update data
+    ///
+    ///Sends a `POST` request to `/v1/system/update/refresh`
+    pub async fn system_update_refresh<'a>(
+        &'a self,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.system_update_refresh_with_options(progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::system_update_refresh`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn system_update_refresh_with_options<'a>(
+        &'a self,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .system_update_refresh_request()
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[185]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            204u16 => Ok(ResponseValue::empty(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -25891,12 +35041,11 @@ impl Client {
         }
     }
 
-    ///Refresh update data
-    ///
-    ///Sends a `POST` request to `/v1/system/update/refresh`
-    pub async fn system_update_refresh<'a>(
+    ///Prepares the request of [`Client::system_update_refresh`] without
+    /// sending it.
+    pub fn system_update_refresh_request<'a>(
         &'a self,
-    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/system/update/refresh", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -25913,15 +35062,48 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "system_update_refresh",
-        };
+        Ok(request)
+    }
+
+    ///Start system update
+    ///
+    ///Sends a `POST` request to `/v1/system/update/start`
+    pub async fn system_update_start<'a>(
+        &'a self,
+        body: &'a types::SystemUpdateStart,
+    ) -> Result<ResponseValue<types::UpdateDeployment>, Error<types::Error>> {
+        self.system_update_start_with_options(body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::system_update_start`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn system_update_start_with_options<'a>(
+        &'a self,
+        body: &'a types::SystemUpdateStart,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::UpdateDeployment>, Error<types::Error>> {
+        let mut request = self
+            .system_update_start_request(body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[186]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
+            202u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -25932,13 +35114,12 @@ impl Client {
         }
     }
 
-    ///Start system update
-    ///
-    ///Sends a `POST` request to `/v1/system/update/start`
-    pub async fn system_update_start<'a>(
+    ///Prepares the request of [`Client::system_update_start`] without sending
+    /// it.
+    pub fn system_update_start_request<'a>(
         &'a self,
         body: &'a types::SystemUpdateStart,
-    ) -> Result<ResponseValue<types::UpdateDeployment>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/system/update/start", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -25956,15 +35137,48 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "system_update_start",
-        };
+        Ok(request)
+    }
+
+    ///Stop system update
+    ///
+    ///If there is no update in progress, do nothing.
+    ///
+    ///Sends a `POST` request to `/v1/system/update/stop`
+    pub async fn system_update_stop<'a>(
+        &'a self,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.system_update_stop_with_options(progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::system_update_stop`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn system_update_stop_with_options<'a>(
+        &'a self,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .system_update_stop_request()
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[187]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            202u16 => ResponseValue::from_response(response).await,
+            204u16 => Ok(ResponseValue::empty(response)),
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -25975,14 +35189,11 @@ impl Client {
         }
     }
 
-    ///Stop system update
-    ///
-    ///If there is no update in progress, do nothing.
-    ///
-    ///Sends a `POST` request to `/v1/system/update/stop`
-    pub async fn system_update_stop<'a>(
+    ///Prepares the request of [`Client::system_update_stop`] without sending
+    /// it.
+    pub fn system_update_stop_request<'a>(
         &'a self,
-    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/system/update/stop", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -25999,23 +35210,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "system_update_stop",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List all updates
@@ -26033,6 +35228,63 @@ impl Client {
         page_token: Option<&'a str>,
         sort_by: Option<types::IdSortMode>,
     ) -> Result<ResponseValue<types::SystemUpdateResultsPage>, Error<types::Error>> {
+        self.system_update_list_with_options(
+            limit,
+            page_token,
+            sort_by,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::system_update_list`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn system_update_list_with_options<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::SystemUpdateResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .system_update_list_request(limit, page_token, sort_by)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[188]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::system_update_list`] without sending
+    /// it.
+    pub fn system_update_list_request<'a>(
+        &'a self,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        sort_by: Option<types::IdSortMode>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/system/update/updates", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -26055,23 +35307,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("sort_by", &sort_by))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "system_update_list",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 
     ///List all updates as a Stream
@@ -26121,6 +35357,54 @@ impl Client {
         &'a self,
         version: &'a types::SemverVersion,
     ) -> Result<ResponseValue<types::SystemUpdate>, Error<types::Error>> {
+        self.system_update_view_with_options(version, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::system_update_view`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn system_update_view_with_options<'a>(
+        &'a self,
+        version: &'a types::SemverVersion,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::SystemUpdate>, Error<types::Error>> {
+        let mut request = self
+            .system_update_view_request(version)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[189]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::system_update_view`] without sending
+    /// it.
+    pub fn system_update_view_request<'a>(
+        &'a self,
+        version: &'a types::SemverVersion,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/system/update/updates/{}",
             self.baseurl,
@@ -26141,13 +35425,50 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "system_update_view",
-        };
+        Ok(request)
+    }
+
+    ///View system update component tree
+    ///
+    ///Sends a `GET` request to
+    /// `/v1/system/update/updates/{version}/components`
+    pub async fn system_update_components_list<'a>(
+        &'a self,
+        version: &'a types::SemverVersion,
+    ) -> Result<ResponseValue<types::ComponentUpdateResultsPage>, Error<types::Error>> {
+        self.system_update_components_list_with_options(
+            version,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::system_update_components_list`] with
+    /// additional headers, query parameters, or context from `options`.
+    pub async fn system_update_components_list_with_options<'a>(
+        &'a self,
+        version: &'a types::SemverVersion,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::ComponentUpdateResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .system_update_components_list_request(version)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[190]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -26160,14 +35481,12 @@ impl Client {
         }
     }
 
-    ///View system update component tree
-    ///
-    ///Sends a `GET` request to
-    /// `/v1/system/update/updates/{version}/components`
-    pub async fn system_update_components_list<'a>(
+    ///Prepares the request of [`Client::system_update_components_list`]
+    /// without sending it.
+    pub fn system_update_components_list_request<'a>(
         &'a self,
         version: &'a types::SemverVersion,
-    ) -> Result<ResponseValue<types::ComponentUpdateResultsPage>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/v1/system/update/updates/{}/components",
             self.baseurl,
@@ -26188,13 +35507,42 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "system_update_components_list",
-        };
+        Ok(request)
+    }
+
+    ///View system version and update status
+    ///
+    ///Sends a `GET` request to `/v1/system/update/version`
+    pub async fn system_version<'a>(
+        &'a self,
+    ) -> Result<ResponseValue<types::SystemVersion>, Error<types::Error>> {
+        self.system_version_with_options(progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::system_version`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn system_version_with_options<'a>(
+        &'a self,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::SystemVersion>, Error<types::Error>> {
+        let mut request = self.system_version_request().map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[191]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -26207,12 +35555,10 @@ impl Client {
         }
     }
 
-    ///View system version and update status
-    ///
-    ///Sends a `GET` request to `/v1/system/update/version`
-    pub async fn system_version<'a>(
+    ///Prepares the request of [`Client::system_version`] without sending it.
+    pub fn system_version_request<'a>(
         &'a self,
-    ) -> Result<ResponseValue<types::SystemVersion>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/v1/system/update/version", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -26229,23 +35575,7 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "system_version",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 }
 
@@ -1,7 +1,7 @@
 #[allow(unused_imports)]
 use progenitor_client::{encode_path, ClientHooks, OperationInfo, RequestBuilderExt};
 #[allow(unused_imports)]
-pub use progenitor_client::{ByteStream, ClientInfo, Error, ResponseValue};
+pub use progenitor_client::{ByteStream, ClientInfo, Error, RequestOptions, ResponseValue};
 /// Types used as operation parameters and responses.
 #[allow(clippy::all)]
 pub mod types {
@@ -45,6 +45,7 @@ pub mod types {
 pub struct Client {
     pub(crate) baseurl: String,
     pub(crate) client: reqwest::Client,
+    pub(crate) middleware: progenitor_client::MiddlewareStack,
 }
 
 impl Client {
@@ -76,8 +77,29 @@ impl Client {
         Self {
             baseurl: baseurl.to_string(),
             client,
+            middleware: Default::default(),
         }
     }
+
+    /// Append `middleware` to the chain that surrounds the
+    /// execution of each request. Middleware runs in the order in
+    /// which it is attached.
+    pub fn with_middleware<M: progenitor_client::Middleware>(mut self, middleware: M) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Descriptions of the API's operations.
+    pub const OPERATIONS: &'static [progenitor_client::OperationMetadata] =
+        &[progenitor_client::OperationMetadata {
+            operation_id: "key_get",
+            method: reqwest::Method::GET,
+            path: "/key/{query}",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        }];
 }
 
 impl ClientInfo<()> for Client {
@@ -148,6 +170,7 @@ pub mod builder {
         response: Result<bool, String>,
         result: Result<bool, String>,
         url: Result<bool, String>,
+        options: progenitor_client::RequestOptions,
     }
 
     impl<'a> KeyGet<'a> {
@@ -160,6 +183,7 @@ pub mod builder {
                 response: Err("response was not initialized".to_string()),
                 result: Err("result was not initialized".to_string()),
                 url: Err("url was not initialized".to_string()),
+                options: ::std::default::Default::default(),
             }
         }
 
@@ -223,8 +247,76 @@ pub mod builder {
             self
         }
 
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
         ///Sends a `GET` request to `/key/{query}`
-        pub async fn send(self) -> Result<ResponseValue<()>, Error<()>> {
+        pub async fn send(mut self) -> Result<ResponseValue<()>, Error<()>> {
+            let _client = self._client;
+            let options = std::mem::take(&mut self.options);
+            let mut _request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[0])
+                .with_context(options.apply(&mut _request)?);
+            _client.pre(&mut _request, &info).await?;
+            let _response = {
+                let info = &info;
+                _client
+                    .middleware
+                    .run(_request, info, &|_request| {
+                        Box::pin(async move {
+                            let _result = _client.exec(_request, info).await;
+                            _client.post(&_result, info).await?;
+                            Ok(_result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match _response.status().as_u16() {
+                200u16 => Ok(ResponseValue::empty(_response)),
+                _ => Err(Error::UnexpectedResponse(_response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
             let Self {
                 _client,
                 query,
@@ -233,6 +325,7 @@ pub mod builder {
                 response,
                 result,
                 url,
+                options,
             } = self;
             let query = query.map_err(Error::InvalidRequest)?;
             let client = client.map_err(Error::InvalidRequest)?;
@@ -261,17 +354,8 @@ pub mod builder {
                 .query(&progenitor_client::QueryParam::new("url", &url))
                 .headers(header_map)
                 .build()?;
-            let info = OperationInfo {
-                operation_id: "key_get",
-            };
-            _client.pre(&mut _request, &info).await?;
-            let _result = _client.exec(_request, &info).await;
-            _client.post(&_result, &info).await?;
-            let _response = _result?;
-            match _response.status().as_u16() {
-                200u16 => Ok(ResponseValue::empty(_response)),
-                _ => Err(Error::UnexpectedResponse(_response)),
-            }
+            options.apply(&mut _request)?;
+            Ok(_request)
         }
     }
 }
@@ -54,6 +54,10 @@ pub mod operations {
             self.0
         }
 
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
         pub fn ok(self) -> Self {
             Self(self.0.status(200u16))
         }
@@ -2,7 +2,7 @@
 #[allow(unused_imports)]
 use progenitor_client::{encode_path, ClientHooks, OperationInfo, RequestBuilderExt};
 #[allow(unused_imports)]
-pub use progenitor_client::{ByteStream, ClientInfo, Error, ResponseValue};
+pub use progenitor_client::{ByteStream, ClientInfo, Error, RequestOptions, ResponseValue};
 /// Types used as operation parameters and responses.
 #[allow(clippy::all)]
 pub mod types {
@@ -46,6 +46,7 @@ pub mod types {
 pub struct Client {
     pub(crate) baseurl: String,
     pub(crate) client: reqwest::Client,
+    pub(crate) middleware: progenitor_client::MiddlewareStack,
 }
 
 impl Client {
@@ -77,8 +78,29 @@ impl Client {
         Self {
             baseurl: baseurl.to_string(),
             client,
+            middleware: Default::default(),
         }
     }
+
+    /// Append `middleware` to the chain that surrounds the
+    /// execution of each request. Middleware runs in the order in
+    /// which it is attached.
+    pub fn with_middleware<M: progenitor_client::Middleware>(mut self, middleware: M) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Descriptions of the API's operations.
+    pub const OPERATIONS: &'static [progenitor_client::OperationMetadata] =
+        &[progenitor_client::OperationMetadata {
+            operation_id: "key_get",
+            method: reqwest::Method::GET,
+            path: "/key",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        }];
 }
 
 impl ClientInfo<()> for Client {
@@ -117,6 +139,53 @@ impl Client {
         key: Option<bool>,
         unique_key: Option<&'a str>,
     ) -> Result<ResponseValue<()>, Error<()>> {
+        self.key_get_with_options(
+            key,
+            unique_key,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::key_get`] with additional headers, query
+    /// parameters, or context from `options`.
+    pub async fn key_get_with_options<'a>(
+        &'a self,
+        key: Option<bool>,
+        unique_key: Option<&'a str>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<()>> {
+        let mut request = self
+            .key_get_request(key, unique_key)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[0]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => Ok(ResponseValue::empty(response)),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::key_get`] without sending it.
+    pub fn key_get_request<'a>(
+        &'a self,
+        key: Option<bool>,
+        unique_key: Option<&'a str>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/key", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -134,17 +203,7 @@ impl Client {
             ))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "key_get",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => Ok(ResponseValue::empty(response)),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 }
 
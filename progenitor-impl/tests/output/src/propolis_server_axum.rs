@@ -0,0 +1,158 @@
+#[allow(unused_imports)]
+use crate::propolis_server_builder::types;
+pub mod parameters {
+    //! Path and query parameters of each operation, as extracted
+    //! by the handlers of [`Api`](super::Api).
+    #[allow(unused_imports)]
+    use crate::propolis_server_builder::types;
+    ///Path parameters of the `instance_issue_crucible_snapshot_request`
+    /// operation.
+    #[derive(Clone, Debug, serde :: Deserialize)]
+    pub struct InstanceIssueCrucibleSnapshotRequestPath {
+        pub id: ::uuid::Uuid,
+        pub snapshot_id: ::uuid::Uuid,
+    }
+}
+
+/// An axum service with a handler for each operation.
+///
+/// Successful responses are sent with the status code from the
+/// API description. A typed error is sent as JSON with the
+/// status code the handler returns with it; other responses are
+/// sent as the handler builds them.
+#[progenitor_client::async_trait]
+pub trait Api: Send + Sync + 'static {
+    ///Handles `GET /instance`
+    async fn instance_get(
+        &self,
+    ) -> Result<types::InstanceGetResponse, (axum::http::StatusCode, types::Error)>;
+    ///Handles `PUT /instance`
+    async fn instance_ensure(
+        &self,
+        body: axum::Json<types::InstanceEnsureRequest>,
+    ) -> Result<types::InstanceEnsureResponse, (axum::http::StatusCode, types::Error)>;
+    ///Issue a snapshot request to a crucible backend
+    async fn instance_issue_crucible_snapshot_request(
+        &self,
+        path: axum::extract::Path<parameters::InstanceIssueCrucibleSnapshotRequestPath>,
+    ) -> Result<(), (axum::http::StatusCode, types::Error)>;
+    ///Handles `GET /instance/migrate/status`
+    async fn instance_migrate_status(
+        &self,
+        body: axum::Json<types::InstanceMigrateStatusRequest>,
+    ) -> Result<types::InstanceMigrateStatusResponse, (axum::http::StatusCode, types::Error)>;
+    ///Handles `GET /instance/serial`
+    async fn instance_serial(&self) -> Result<axum::response::Response, axum::response::Response>;
+    ///Handles `PUT /instance/state`
+    async fn instance_state_put(
+        &self,
+        body: axum::Json<types::InstanceStateRequested>,
+    ) -> Result<(), (axum::http::StatusCode, types::Error)>;
+    ///Handles `GET /instance/state-monitor`
+    async fn instance_state_monitor(
+        &self,
+        body: axum::Json<types::InstanceStateMonitorRequest>,
+    ) -> Result<types::InstanceStateMonitorResponse, (axum::http::StatusCode, types::Error)>;
+}
+
+/// Build an `axum::Router` that routes each operation to its
+/// handler in `api`.
+pub fn router<A: Api>(api: std::sync::Arc<A>) -> axum::Router {
+    use axum::response::IntoResponse;
+    axum::Router::new()
+        .route(
+            "/instance",
+            axum::routing::get(
+                |axum::extract::State(api): axum::extract::State<std::sync::Arc<A>>| async move {
+                    match api.instance_get().await {
+                        Ok(value) => {
+                            (axum::http::StatusCode::OK, axum::Json(value)).into_response()
+                        }
+                        Err((status, error)) => (status, axum::Json(error)).into_response(),
+                    }
+                },
+            ),
+        )
+        .route(
+            "/instance",
+            axum::routing::put(
+                |axum::extract::State(api): axum::extract::State<std::sync::Arc<A>>,
+                 body: axum::Json<types::InstanceEnsureRequest>| async move {
+                    match api.instance_ensure(body).await {
+                        Ok(value) => {
+                            (axum::http::StatusCode::CREATED, axum::Json(value)).into_response()
+                        }
+                        Err((status, error)) => (status, axum::Json(error)).into_response(),
+                    }
+                },
+            ),
+        )
+        .route(
+            "/instance/disk/{id}/snapshot/{snapshot_id}",
+            axum::routing::post(
+                |axum::extract::State(api): axum::extract::State<std::sync::Arc<A>>,
+                 path: axum::extract::Path<
+                    parameters::InstanceIssueCrucibleSnapshotRequestPath,
+                >| async move {
+                    match api.instance_issue_crucible_snapshot_request(path).await {
+                        Ok(value) => {
+                            (axum::http::StatusCode::OK, axum::Json(value)).into_response()
+                        }
+                        Err((status, error)) => (status, axum::Json(error)).into_response(),
+                    }
+                },
+            ),
+        )
+        .route(
+            "/instance/migrate/status",
+            axum::routing::get(
+                |axum::extract::State(api): axum::extract::State<std::sync::Arc<A>>,
+                 body: axum::Json<types::InstanceMigrateStatusRequest>| async move {
+                    match api.instance_migrate_status(body).await {
+                        Ok(value) => {
+                            (axum::http::StatusCode::OK, axum::Json(value)).into_response()
+                        }
+                        Err((status, error)) => (status, axum::Json(error)).into_response(),
+                    }
+                },
+            ),
+        )
+        .route(
+            "/instance/serial",
+            axum::routing::get(
+                |axum::extract::State(api): axum::extract::State<std::sync::Arc<A>>| async move {
+                    match api.instance_serial().await {
+                        Ok(response) => response,
+                        Err(response) => response,
+                    }
+                },
+            ),
+        )
+        .route(
+            "/instance/state",
+            axum::routing::put(
+                |axum::extract::State(api): axum::extract::State<std::sync::Arc<A>>,
+                 body: axum::Json<types::InstanceStateRequested>| async move {
+                    match api.instance_state_put(body).await {
+                        Ok(()) => axum::http::StatusCode::NO_CONTENT.into_response(),
+                        Err((status, error)) => (status, axum::Json(error)).into_response(),
+                    }
+                },
+            ),
+        )
+        .route(
+            "/instance/state-monitor",
+            axum::routing::get(
+                |axum::extract::State(api): axum::extract::State<std::sync::Arc<A>>,
+                 body: axum::Json<types::InstanceStateMonitorRequest>| async move {
+                    match api.instance_state_monitor(body).await {
+                        Ok(value) => {
+                            (axum::http::StatusCode::OK, axum::Json(value)).into_response()
+                        }
+                        Err((status, error)) => (status, axum::Json(error)).into_response(),
+                    }
+                },
+            ),
+        )
+        .with_state(api)
+}
@@ -0,0 +1,154 @@
+#[allow(unused_imports)]
+use crate::propolis_server_builder::types;
+pub mod parameters {
+    //! Path and query parameters of each operation, as passed to
+    //! the methods of [`Api`](super::Api).
+    #[allow(unused_imports)]
+    use crate::propolis_server_builder::types;
+    ///Path parameters of the `instance_issue_crucible_snapshot_request`
+    /// operation.
+    #[derive(Clone, Debug, serde :: Deserialize)]
+    pub struct InstanceIssueCrucibleSnapshotRequestPath {
+        pub id: ::uuid::Uuid,
+        pub snapshot_id: ::uuid::Uuid,
+    }
+}
+
+/// The operations of the API, as implemented by a fake backend.
+///
+/// Successful responses are sent with the status code from the
+/// API description. A typed error is sent as JSON with the
+/// status code the method returns with it; other responses are
+/// sent as the method builds them.
+#[progenitor_client::async_trait]
+pub trait Api: Send + Sync + 'static {
+    ///Handles `GET /instance`
+    async fn instance_get(
+        &self,
+    ) -> Result<types::InstanceGetResponse, (reqwest::StatusCode, types::Error)>;
+    ///Handles `PUT /instance`
+    async fn instance_ensure(
+        &self,
+        body: types::InstanceEnsureRequest,
+    ) -> Result<types::InstanceEnsureResponse, (reqwest::StatusCode, types::Error)>;
+    ///Issue a snapshot request to a crucible backend
+    async fn instance_issue_crucible_snapshot_request(
+        &self,
+        path: parameters::InstanceIssueCrucibleSnapshotRequestPath,
+    ) -> Result<(), (reqwest::StatusCode, types::Error)>;
+    ///Handles `GET /instance/migrate/status`
+    async fn instance_migrate_status(
+        &self,
+        body: types::InstanceMigrateStatusRequest,
+    ) -> Result<types::InstanceMigrateStatusResponse, (reqwest::StatusCode, types::Error)>;
+    ///Handles `GET /instance/serial`
+    async fn instance_serial(&self) -> Result<reqwest::Response, reqwest::Response>;
+    ///Handles `PUT /instance/state`
+    async fn instance_state_put(
+        &self,
+        body: types::InstanceStateRequested,
+    ) -> Result<(), (reqwest::StatusCode, types::Error)>;
+    ///Handles `GET /instance/state-monitor`
+    async fn instance_state_monitor(
+        &self,
+        body: types::InstanceStateMonitorRequest,
+    ) -> Result<types::InstanceStateMonitorResponse, (reqwest::StatusCode, types::Error)>;
+}
+
+/// Middleware that answers each request by calling the method of
+/// `A` for its operation, without sending it.
+///
+/// Attach it to a client with `with_middleware`, after any other
+/// middleware.
+pub struct Fake<A>(pub std::sync::Arc<A>);
+impl<A: Api> Fake<A> {
+    /// Create middleware that calls `api`.
+    pub fn new(api: A) -> Self {
+        Self(std::sync::Arc::new(api))
+    }
+}
+
+impl<A: Api> progenitor_client::Middleware for Fake<A> {
+    fn handle<'a>(
+        &'a self,
+        request: reqwest::Request,
+        info: &'a progenitor_client::OperationInfo,
+        _next: progenitor_client::Next<'a>,
+    ) -> progenitor_client::MiddlewareFuture<'a> {
+        Box::pin(async move {
+            let request = progenitor_client::FakeRequest::new(&request, info);
+            match info.operation_id {
+                "instance_get" => match self.0.instance_get().await {
+                    Ok(value) => {
+                        progenitor_client::fake_json_response(reqwest::StatusCode::OK, &value)
+                    }
+                    Err((status, error)) => progenitor_client::fake_json_response(status, &error),
+                },
+                "instance_ensure" => {
+                    let body = request.json()?;
+                    match self.0.instance_ensure(body).await {
+                        Ok(value) => progenitor_client::fake_json_response(
+                            reqwest::StatusCode::CREATED,
+                            &value,
+                        ),
+                        Err((status, error)) => {
+                            progenitor_client::fake_json_response(status, &error)
+                        }
+                    }
+                }
+                "instance_issue_crucible_snapshot_request" => {
+                    let path = request.path()?;
+                    match self.0.instance_issue_crucible_snapshot_request(path).await {
+                        Ok(value) => {
+                            progenitor_client::fake_json_response(reqwest::StatusCode::OK, &value)
+                        }
+                        Err((status, error)) => {
+                            progenitor_client::fake_json_response(status, &error)
+                        }
+                    }
+                }
+                "instance_migrate_status" => {
+                    let body = request.json()?;
+                    match self.0.instance_migrate_status(body).await {
+                        Ok(value) => {
+                            progenitor_client::fake_json_response(reqwest::StatusCode::OK, &value)
+                        }
+                        Err((status, error)) => {
+                            progenitor_client::fake_json_response(status, &error)
+                        }
+                    }
+                }
+                "instance_serial" => match self.0.instance_serial().await {
+                    Ok(response) => Ok(response),
+                    Err(response) => Ok(response),
+                },
+                "instance_state_put" => {
+                    let body = request.json()?;
+                    match self.0.instance_state_put(body).await {
+                        Ok(()) => {
+                            progenitor_client::fake_empty_response(reqwest::StatusCode::NO_CONTENT)
+                        }
+                        Err((status, error)) => {
+                            progenitor_client::fake_json_response(status, &error)
+                        }
+                    }
+                }
+                "instance_state_monitor" => {
+                    let body = request.json()?;
+                    match self.0.instance_state_monitor(body).await {
+                        Ok(value) => {
+                            progenitor_client::fake_json_response(reqwest::StatusCode::OK, &value)
+                        }
+                        Err((status, error)) => {
+                            progenitor_client::fake_json_response(status, &error)
+                        }
+                    }
+                }
+                _ => Err(progenitor_client::Error::Custom(format!(
+                    "unknown operation {}",
+                    info.operation_id,
+                ))),
+            }
+        })
+    }
+}
@@ -0,0 +1,770 @@
+pub mod operations {
+    #![doc = r" [`When`](::httpmock::When) and [`Then`](::httpmock::Then)"]
+    #![doc = r" wrappers for each operation. Each can be converted to"]
+    #![doc = r" its inner type with a call to `into_inner()`. This can"]
+    #![doc = r" be used to explicitly deviate from permitted values."]
+    use crate::propolis_server_builder::*;
+    mod body_predicates {
+        type Predicate = Box<dyn Fn(&[u8]) -> bool + Send + Sync>;
+        static PREDICATES: ::std::sync::Mutex<Vec<((::std::any::TypeId, usize), Predicate)>> =
+            ::std::sync::Mutex::new(Vec::new());
+        fn matcher_at<const N: usize>(req: &::httpmock::prelude::HttpMockRequest) -> bool {
+            let predicates = PREDICATES
+                .lock()
+                .unwrap_or_else(::std::sync::PoisonError::into_inner);
+            req.body
+                .as_deref()
+                .is_some_and(|body| (predicates[N].1)(body))
+        }
+
+        const MATCHERS: [fn(&::httpmock::prelude::HttpMockRequest) -> bool; 64] = [
+            matcher_at::<0>,
+            matcher_at::<1>,
+            matcher_at::<2>,
+            matcher_at::<3>,
+            matcher_at::<4>,
+            matcher_at::<5>,
+            matcher_at::<6>,
+            matcher_at::<7>,
+            matcher_at::<8>,
+            matcher_at::<9>,
+            matcher_at::<10>,
+            matcher_at::<11>,
+            matcher_at::<12>,
+            matcher_at::<13>,
+            matcher_at::<14>,
+            matcher_at::<15>,
+            matcher_at::<16>,
+            matcher_at::<17>,
+            matcher_at::<18>,
+            matcher_at::<19>,
+            matcher_at::<20>,
+            matcher_at::<21>,
+            matcher_at::<22>,
+            matcher_at::<23>,
+            matcher_at::<24>,
+            matcher_at::<25>,
+            matcher_at::<26>,
+            matcher_at::<27>,
+            matcher_at::<28>,
+            matcher_at::<29>,
+            matcher_at::<30>,
+            matcher_at::<31>,
+            matcher_at::<32>,
+            matcher_at::<33>,
+            matcher_at::<34>,
+            matcher_at::<35>,
+            matcher_at::<36>,
+            matcher_at::<37>,
+            matcher_at::<38>,
+            matcher_at::<39>,
+            matcher_at::<40>,
+            matcher_at::<41>,
+            matcher_at::<42>,
+            matcher_at::<43>,
+            matcher_at::<44>,
+            matcher_at::<45>,
+            matcher_at::<46>,
+            matcher_at::<47>,
+            matcher_at::<48>,
+            matcher_at::<49>,
+            matcher_at::<50>,
+            matcher_at::<51>,
+            matcher_at::<52>,
+            matcher_at::<53>,
+            matcher_at::<54>,
+            matcher_at::<55>,
+            matcher_at::<56>,
+            matcher_at::<57>,
+            matcher_at::<58>,
+            matcher_at::<59>,
+            matcher_at::<60>,
+            matcher_at::<61>,
+            matcher_at::<62>,
+            matcher_at::<63>,
+        ];
+        #[doc = r" The matcher for bodies that decode to a value for which"]
+        #[doc = r" `predicate` returns `true`."]
+        pub(super) fn matcher<T>(
+            predicate: fn(&T) -> bool,
+        ) -> fn(&::httpmock::prelude::HttpMockRequest) -> bool
+        where
+            T: ::serde::de::DeserializeOwned + 'static,
+        {
+            let key = (::std::any::TypeId::of::<T>(), predicate as usize);
+            let mut predicates = PREDICATES
+                .lock()
+                .unwrap_or_else(::std::sync::PoisonError::into_inner);
+            let index = match predicates.iter().position(|(k, _)| *k == key) {
+                Some(index) => index,
+                None => {
+                    assert!(
+                        predicates.len() < MATCHERS.len(),
+                        "at most {} distinct body predicates may be used",
+                        MATCHERS.len(),
+                    );
+                    predicates.push((
+                        key,
+                        Box::new(move |body| {
+                            ::serde_json::from_slice(body).is_ok_and(|value| predicate(&value))
+                        }),
+                    ));
+                    predicates.len() - 1
+                }
+            };
+            MATCHERS[index]
+        }
+    }
+
+    pub struct InstanceGetWhen(::httpmock::When);
+    impl InstanceGetWhen {
+        pub fn new(inner: ::httpmock::When) -> Self {
+            Self(
+                inner
+                    .method(::httpmock::Method::GET)
+                    .path_matches(regex::Regex::new("^/instance$").unwrap()),
+            )
+        }
+
+        pub fn into_inner(self) -> ::httpmock::When {
+            self.0
+        }
+    }
+
+    pub struct InstanceGetThen(::httpmock::Then);
+    impl InstanceGetThen {
+        pub fn new(inner: ::httpmock::Then) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::httpmock::Then {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
+        pub fn ok(self, value: &types::InstanceGetResponse) -> Self {
+            Self(
+                self.0
+                    .status(200u16)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+
+        pub fn client_error(self, status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 4u16);
+            Self(
+                self.0
+                    .status(status)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+
+        pub fn server_error(self, status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 5u16);
+            Self(
+                self.0
+                    .status(status)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+    }
+
+    pub struct InstanceEnsureWhen(::httpmock::When);
+    impl InstanceEnsureWhen {
+        pub fn new(inner: ::httpmock::When) -> Self {
+            Self(
+                inner
+                    .method(::httpmock::Method::PUT)
+                    .path_matches(regex::Regex::new("^/instance$").unwrap()),
+            )
+        }
+
+        pub fn into_inner(self) -> ::httpmock::When {
+            self.0
+        }
+
+        pub fn body(self, value: &types::InstanceEnsureRequest) -> Self {
+            Self(self.0.json_body_obj(value))
+        }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::InstanceEnsureRequest) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
+    }
+
+    pub struct InstanceEnsureThen(::httpmock::Then);
+    impl InstanceEnsureThen {
+        pub fn new(inner: ::httpmock::Then) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::httpmock::Then {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
+        pub fn created(self, value: &types::InstanceEnsureResponse) -> Self {
+            Self(
+                self.0
+                    .status(201u16)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+
+        pub fn client_error(self, status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 4u16);
+            Self(
+                self.0
+                    .status(status)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+
+        pub fn server_error(self, status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 5u16);
+            Self(
+                self.0
+                    .status(status)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+    }
+
+    pub struct InstanceIssueCrucibleSnapshotRequestWhen(::httpmock::When);
+    impl InstanceIssueCrucibleSnapshotRequestWhen {
+        pub fn new(inner: ::httpmock::When) -> Self {
+            Self(
+                inner.method(::httpmock::Method::POST).path_matches(
+                    regex::Regex::new("^/instance/disk/[^/]*/snapshot/[^/]*$").unwrap(),
+                ),
+            )
+        }
+
+        pub fn into_inner(self) -> ::httpmock::When {
+            self.0
+        }
+
+        pub fn id(self, value: &::uuid::Uuid) -> Self {
+            let re = regex::Regex::new(&format!(
+                "^/instance/disk/{}/snapshot/.*$",
+                value.to_string()
+            ))
+            .unwrap();
+            Self(self.0.path_matches(re))
+        }
+
+        pub fn snapshot_id(self, value: &::uuid::Uuid) -> Self {
+            let re = regex::Regex::new(&format!(
+                "^/instance/disk/.*/snapshot/{}$",
+                value.to_string()
+            ))
+            .unwrap();
+            Self(self.0.path_matches(re))
+        }
+    }
+
+    pub struct InstanceIssueCrucibleSnapshotRequestThen(::httpmock::Then);
+    impl InstanceIssueCrucibleSnapshotRequestThen {
+        pub fn new(inner: ::httpmock::Then) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::httpmock::Then {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
+        pub fn ok(self, value: ()) -> Self {
+            Self(
+                self.0
+                    .status(200u16)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+
+        pub fn client_error(self, status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 4u16);
+            Self(
+                self.0
+                    .status(status)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+
+        pub fn server_error(self, status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 5u16);
+            Self(
+                self.0
+                    .status(status)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+    }
+
+    pub struct InstanceMigrateStatusWhen(::httpmock::When);
+    impl InstanceMigrateStatusWhen {
+        pub fn new(inner: ::httpmock::When) -> Self {
+            Self(
+                inner
+                    .method(::httpmock::Method::GET)
+                    .path_matches(regex::Regex::new("^/instance/migrate/status$").unwrap()),
+            )
+        }
+
+        pub fn into_inner(self) -> ::httpmock::When {
+            self.0
+        }
+
+        pub fn body(self, value: &types::InstanceMigrateStatusRequest) -> Self {
+            Self(self.0.json_body_obj(value))
+        }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(
+            self,
+            predicate: fn(&types::InstanceMigrateStatusRequest) -> bool,
+        ) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
+    }
+
+    pub struct InstanceMigrateStatusThen(::httpmock::Then);
+    impl InstanceMigrateStatusThen {
+        pub fn new(inner: ::httpmock::Then) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::httpmock::Then {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
+        pub fn ok(self, value: &types::InstanceMigrateStatusResponse) -> Self {
+            Self(
+                self.0
+                    .status(200u16)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+
+        pub fn client_error(self, status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 4u16);
+            Self(
+                self.0
+                    .status(status)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+
+        pub fn server_error(self, status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 5u16);
+            Self(
+                self.0
+                    .status(status)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+    }
+
+    pub struct InstanceSerialWhen(::httpmock::When);
+    impl InstanceSerialWhen {
+        pub fn new(inner: ::httpmock::When) -> Self {
+            Self(
+                inner
+                    .method(::httpmock::Method::GET)
+                    .path_matches(regex::Regex::new("^/instance/serial$").unwrap()),
+            )
+        }
+
+        pub fn into_inner(self) -> ::httpmock::When {
+            self.0
+        }
+    }
+
+    pub struct InstanceSerialThen(::httpmock::Then);
+    impl InstanceSerialThen {
+        pub fn new(inner: ::httpmock::Then) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::httpmock::Then {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
+        pub fn default_response(self, status: u16) -> Self {
+            Self(self.0.status(status))
+        }
+
+        pub fn switching_protocols(self) -> Self {
+            Self(self.0.status(101u16))
+        }
+    }
+
+    pub struct InstanceStatePutWhen(::httpmock::When);
+    impl InstanceStatePutWhen {
+        pub fn new(inner: ::httpmock::When) -> Self {
+            Self(
+                inner
+                    .method(::httpmock::Method::PUT)
+                    .path_matches(regex::Regex::new("^/instance/state$").unwrap()),
+            )
+        }
+
+        pub fn into_inner(self) -> ::httpmock::When {
+            self.0
+        }
+
+        pub fn body(self, value: types::InstanceStateRequested) -> Self {
+            Self(self.0.json_body_obj(value))
+        }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(self, predicate: fn(&types::InstanceStateRequested) -> bool) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
+    }
+
+    pub struct InstanceStatePutThen(::httpmock::Then);
+    impl InstanceStatePutThen {
+        pub fn new(inner: ::httpmock::Then) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::httpmock::Then {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
+        pub fn no_content(self) -> Self {
+            Self(self.0.status(204u16))
+        }
+
+        pub fn client_error(self, status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 4u16);
+            Self(
+                self.0
+                    .status(status)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+
+        pub fn server_error(self, status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 5u16);
+            Self(
+                self.0
+                    .status(status)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+    }
+
+    pub struct InstanceStateMonitorWhen(::httpmock::When);
+    impl InstanceStateMonitorWhen {
+        pub fn new(inner: ::httpmock::When) -> Self {
+            Self(
+                inner
+                    .method(::httpmock::Method::GET)
+                    .path_matches(regex::Regex::new("^/instance/state-monitor$").unwrap()),
+            )
+        }
+
+        pub fn into_inner(self) -> ::httpmock::When {
+            self.0
+        }
+
+        pub fn body(self, value: &types::InstanceStateMonitorRequest) -> Self {
+            Self(self.0.json_body_obj(value))
+        }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.json_body_partial(value.to_string()))
+        }
+
+        #[doc = r" Match requests whose body decodes to a value for"]
+        #[doc = r" which `predicate` returns `true`. As httpmock"]
+        #[doc = r" matchers are plain functions, the predicate can't"]
+        #[doc = r" capture any variables."]
+        pub fn body_matches(
+            self,
+            predicate: fn(&types::InstanceStateMonitorRequest) -> bool,
+        ) -> Self {
+            Self(self.0.matches(body_predicates::matcher(predicate)))
+        }
+    }
+
+    pub struct InstanceStateMonitorThen(::httpmock::Then);
+    impl InstanceStateMonitorThen {
+        pub fn new(inner: ::httpmock::Then) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::httpmock::Then {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
+        pub fn ok(self, value: &types::InstanceStateMonitorResponse) -> Self {
+            Self(
+                self.0
+                    .status(200u16)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+
+        pub fn client_error(self, status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 4u16);
+            Self(
+                self.0
+                    .status(status)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+
+        pub fn server_error(self, status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 5u16);
+            Self(
+                self.0
+                    .status(status)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+    }
+}
+
+#[doc = r" An extension trait for [`MockServer`](::httpmock::MockServer) that"]
+#[doc = r" adds a method for each operation. These are the equivalent of"]
+#[doc = r" type-checked [`mock()`](::httpmock::MockServer::mock) calls."]
+pub trait MockServerExt {
+    fn instance_get<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::InstanceGetWhen, operations::InstanceGetThen);
+    fn instance_ensure<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::InstanceEnsureWhen, operations::InstanceEnsureThen);
+    fn instance_issue_crucible_snapshot_request<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(
+            operations::InstanceIssueCrucibleSnapshotRequestWhen,
+            operations::InstanceIssueCrucibleSnapshotRequestThen,
+        );
+    fn instance_migrate_status<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::InstanceMigrateStatusWhen, operations::InstanceMigrateStatusThen);
+    fn instance_serial<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::InstanceSerialWhen, operations::InstanceSerialThen);
+    fn instance_state_put<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::InstanceStatePutWhen, operations::InstanceStatePutThen);
+    fn instance_state_monitor<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::InstanceStateMonitorWhen, operations::InstanceStateMonitorThen);
+    #[doc = r" Mock every operation, answering with the example of its"]
+    #[doc = r" first successful response from the API description, or a"]
+    #[doc = r" value built from the response's schema if it has none. The"]
+    #[doc = r" mocks are returned by operation ID; operations for which no"]
+    #[doc = r" value satisfying the schema was found aren't mocked."]
+    #[doc = r""]
+    #[doc = r" httpmock answers with the mock created first, so mock an"]
+    #[doc = r" operation before calling this to override it, or `delete()`"]
+    #[doc = r" its mock afterwards and mock it again."]
+    fn mock_all_from_examples(&self) -> std::collections::BTreeMap<&'static str, ::httpmock::Mock>;
+}
+
+impl MockServerExt for ::httpmock::MockServer {
+    fn instance_get<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::InstanceGetWhen, operations::InstanceGetThen),
+    {
+        self.mock(|when, then| {
+            config_fn(
+                operations::InstanceGetWhen::new(when),
+                operations::InstanceGetThen::new(then),
+            )
+        })
+    }
+
+    fn instance_ensure<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::InstanceEnsureWhen, operations::InstanceEnsureThen),
+    {
+        self.mock(|when, then| {
+            config_fn(
+                operations::InstanceEnsureWhen::new(when),
+                operations::InstanceEnsureThen::new(then),
+            )
+        })
+    }
+
+    fn instance_issue_crucible_snapshot_request<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(
+            operations::InstanceIssueCrucibleSnapshotRequestWhen,
+            operations::InstanceIssueCrucibleSnapshotRequestThen,
+        ),
+    {
+        self.mock(|when, then| {
+            config_fn(
+                operations::InstanceIssueCrucibleSnapshotRequestWhen::new(when),
+                operations::InstanceIssueCrucibleSnapshotRequestThen::new(then),
+            )
+        })
+    }
+
+    fn instance_migrate_status<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::InstanceMigrateStatusWhen, operations::InstanceMigrateStatusThen),
+    {
+        self.mock(|when, then| {
+            config_fn(
+                operations::InstanceMigrateStatusWhen::new(when),
+                operations::InstanceMigrateStatusThen::new(then),
+            )
+        })
+    }
+
+    fn instance_serial<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::InstanceSerialWhen, operations::InstanceSerialThen),
+    {
+        self.mock(|when, then| {
+            config_fn(
+                operations::InstanceSerialWhen::new(when),
+                operations::InstanceSerialThen::new(then),
+            )
+        })
+    }
+
+    fn instance_state_put<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::InstanceStatePutWhen, operations::InstanceStatePutThen),
+    {
+        self.mock(|when, then| {
+            config_fn(
+                operations::InstanceStatePutWhen::new(when),
+                operations::InstanceStatePutThen::new(then),
+            )
+        })
+    }
+
+    fn instance_state_monitor<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::InstanceStateMonitorWhen, operations::InstanceStateMonitorThen),
+    {
+        self.mock(|when, then| {
+            config_fn(
+                operations::InstanceStateMonitorWhen::new(when),
+                operations::InstanceStateMonitorThen::new(then),
+            )
+        })
+    }
+
+    fn mock_all_from_examples(&self) -> std::collections::BTreeMap<&'static str, ::httpmock::Mock> {
+        let mut mocks = std::collections::BTreeMap::new();
+        mocks . insert ("instance_get" , self . instance_get (| _ , then | { then . into_inner () . status (200u16) . header ("content-type" , "application/json") . body (r#"{"instance":{"disks":[],"nics":[],"properties":{"bootrom_id":"00000000-0000-0000-0000-000000000000","description":"string","id":"00000000-0000-0000-0000-000000000000","image_id":"00000000-0000-0000-0000-000000000000","memory":0,"name":"string","vcpus":0},"state":"Creating"}}"#) ; }) ,) ;
+        mocks.insert(
+            "instance_ensure",
+            self.instance_ensure(|_, then| {
+                then.into_inner()
+                    .status(201u16)
+                    .header("content-type", "application/json")
+                    .body(r#"{}"#);
+            }),
+        );
+        mocks.insert(
+            "instance_migrate_status",
+            self.instance_migrate_status(|_, then| {
+                then.into_inner()
+                    .status(200u16)
+                    .header("content-type", "application/json")
+                    .body(r#"{"state":"Sync"}"#);
+            }),
+        );
+        mocks.insert(
+            "instance_state_put",
+            self.instance_state_put(|_, then| {
+                then.into_inner().status(204u16);
+            }),
+        );
+        mocks.insert(
+            "instance_state_monitor",
+            self.instance_state_monitor(|_, then| {
+                then.into_inner()
+                    .status(200u16)
+                    .header("content-type", "application/json")
+                    .body(r#"{"gen":0,"state":"Creating"}"#);
+            }),
+        );
+        mocks.insert(
+            "instance_issue_crucible_snapshot_request",
+            self.instance_issue_crucible_snapshot_request(|_, then| {
+                then.into_inner()
+                    .status(200u16)
+                    .header("content-type", "application/json")
+                    .body(r#""string""#);
+            }),
+        );
+        mocks
+    }
+}
@@ -2,7 +2,7 @@
 #[allow(unused_imports)]
 use progenitor_client::{encode_path, ClientHooks, OperationInfo, RequestBuilderExt};
 #[allow(unused_imports)]
-pub use progenitor_client::{ByteStream, ClientInfo, Error, ResponseValue};
+pub use progenitor_client::{ByteStream, ClientInfo, Error, RequestOptions, ResponseValue};
 /// Types used as operation parameters and responses.
 #[allow(clippy::all)]
 pub mod types {
@@ -1506,6 +1506,7 @@ pub mod types {
 pub struct Client {
     pub(crate) baseurl: String,
     pub(crate) client: reqwest::Client,
+    pub(crate) middleware: progenitor_client::MiddlewareStack,
 }
 
 impl Client {
@@ -1537,8 +1538,84 @@ impl Client {
         Self {
             baseurl: baseurl.to_string(),
             client,
+            middleware: Default::default(),
         }
     }
+
+    /// Append `middleware` to the chain that surrounds the
+    /// execution of each request. Middleware runs in the order in
+    /// which it is attached.
+    pub fn with_middleware<M: progenitor_client::Middleware>(mut self, middleware: M) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Descriptions of the API's operations.
+    pub const OPERATIONS: &'static [progenitor_client::OperationMetadata] = &[
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_get",
+            method: reqwest::Method::GET,
+            path: "/instance",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_ensure",
+            method: reqwest::Method::PUT,
+            path: "/instance",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[201u16..=201u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_issue_crucible_snapshot_request",
+            method: reqwest::Method::POST,
+            path: "/instance/disk/{id}/snapshot/{snapshot_id}",
+            tags: &[],
+            idempotent: false,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_migrate_status",
+            method: reqwest::Method::GET,
+            path: "/instance/migrate/status",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_serial",
+            method: reqwest::Method::GET,
+            path: "/instance/serial",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[101u16..=101u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_state_put",
+            method: reqwest::Method::PUT,
+            path: "/instance/state",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "instance_state_monitor",
+            method: reqwest::Method::GET,
+            path: "/instance/state-monitor",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+    ];
 }
 
 impl ClientInfo<()> for Client {
@@ -1567,6 +1644,49 @@ impl Client {
     pub async fn instance_get<'a>(
         &'a self,
     ) -> Result<ResponseValue<types::InstanceGetResponse>, Error<types::Error>> {
+        self.instance_get_with_options(progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::instance_get`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn instance_get_with_options<'a>(
+        &'a self,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::InstanceGetResponse>, Error<types::Error>> {
+        let mut request = self.instance_get_request().map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[0]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::instance_get`] without sending it.
+    pub fn instance_get_request<'a>(
+        &'a self,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/instance", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -1583,15 +1703,46 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_get",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `PUT` request to `/instance`
+    pub async fn instance_ensure<'a>(
+        &'a self,
+        body: &'a types::InstanceEnsureRequest,
+    ) -> Result<ResponseValue<types::InstanceEnsureResponse>, Error<types::Error>> {
+        self.instance_ensure_with_options(body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::instance_ensure`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_ensure_with_options<'a>(
+        &'a self,
+        body: &'a types::InstanceEnsureRequest,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::InstanceEnsureResponse>, Error<types::Error>> {
+        let mut request = self
+            .instance_ensure_request(body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[1]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
+            201u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -1602,11 +1753,11 @@ impl Client {
         }
     }
 
-    ///Sends a `PUT` request to `/instance`
-    pub async fn instance_ensure<'a>(
+    ///Prepares the request of [`Client::instance_ensure`] without sending it.
+    pub fn instance_ensure_request<'a>(
         &'a self,
         body: &'a types::InstanceEnsureRequest,
-    ) -> Result<ResponseValue<types::InstanceEnsureResponse>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/instance", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -1624,15 +1775,55 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_ensure",
-        };
+        Ok(request)
+    }
+
+    ///Issue a snapshot request to a crucible backend
+    ///
+    ///Sends a `POST` request to `/instance/disk/{id}/snapshot/{snapshot_id}`
+    pub async fn instance_issue_crucible_snapshot_request<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+        snapshot_id: &'a ::uuid::Uuid,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.instance_issue_crucible_snapshot_request_with_options(
+            id,
+            snapshot_id,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of
+    /// [`Client::instance_issue_crucible_snapshot_request`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_issue_crucible_snapshot_request_with_options<'a>(
+        &'a self,
+        id: &'a ::uuid::Uuid,
+        snapshot_id: &'a ::uuid::Uuid,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .instance_issue_crucible_snapshot_request_request(id, snapshot_id)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[2]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            201u16 => ResponseValue::from_response(response).await,
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -1643,14 +1834,13 @@ impl Client {
         }
     }
 
-    ///Issue a snapshot request to a crucible backend
-    ///
-    ///Sends a `POST` request to `/instance/disk/{id}/snapshot/{snapshot_id}`
-    pub async fn instance_issue_crucible_snapshot_request<'a>(
+    ///Prepares the request of
+    /// [`Client::instance_issue_crucible_snapshot_request`] without sending it.
+    pub fn instance_issue_crucible_snapshot_request_request<'a>(
         &'a self,
         id: &'a ::uuid::Uuid,
         snapshot_id: &'a ::uuid::Uuid,
-    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/instance/disk/{}/snapshot/{}",
             self.baseurl,
@@ -1672,13 +1862,47 @@ impl Client {
             )
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_issue_crucible_snapshot_request",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `GET` request to `/instance/migrate/status`
+    pub async fn instance_migrate_status<'a>(
+        &'a self,
+        body: &'a types::InstanceMigrateStatusRequest,
+    ) -> Result<ResponseValue<types::InstanceMigrateStatusResponse>, Error<types::Error>> {
+        self.instance_migrate_status_with_options(
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::instance_migrate_status`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_migrate_status_with_options<'a>(
+        &'a self,
+        body: &'a types::InstanceMigrateStatusRequest,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::InstanceMigrateStatusResponse>, Error<types::Error>> {
+        let mut request = self
+            .instance_migrate_status_request(body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[3]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
             200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
@@ -1691,11 +1915,12 @@ impl Client {
         }
     }
 
-    ///Sends a `GET` request to `/instance/migrate/status`
-    pub async fn instance_migrate_status<'a>(
+    ///Prepares the request of [`Client::instance_migrate_status`] without
+    /// sending it.
+    pub fn instance_migrate_status_request<'a>(
         &'a self,
         body: &'a types::InstanceMigrateStatusRequest,
-    ) -> Result<ResponseValue<types::InstanceMigrateStatusResponse>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/instance/migrate/status", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -1713,29 +1938,51 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_migrate_status",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `GET` request to `/instance/serial`
+    pub async fn instance_serial<'a>(
+        &'a self,
+    ) -> Result<ResponseValue<reqwest::Upgraded>, Error<reqwest::Upgraded>> {
+        self.instance_serial_with_options(progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::instance_serial`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_serial_with_options<'a>(
+        &'a self,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<reqwest::Upgraded>, Error<reqwest::Upgraded>> {
+        let mut request = self.instance_serial_request().map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[4]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
+            101u16 => ResponseValue::upgrade(response).await,
+            200..=299 => ResponseValue::upgrade(response).await,
             _ => Err(Error::UnexpectedResponse(response)),
         }
     }
 
-    ///Sends a `GET` request to `/instance/serial`
-    pub async fn instance_serial<'a>(
+    ///Prepares the request of [`Client::instance_serial`] without sending it.
+    pub fn instance_serial_request<'a>(
         &'a self,
-    ) -> Result<ResponseValue<reqwest::Upgraded>, Error<reqwest::Upgraded>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/instance/serial", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -1758,25 +2005,62 @@ impl Client {
                 ),
             )
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_serial",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `PUT` request to `/instance/state`
+    pub async fn instance_state_put<'a>(
+        &'a self,
+        body: types::InstanceStateRequested,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.instance_state_put_with_options(body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::instance_state_put`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_state_put_with_options<'a>(
+        &'a self,
+        body: types::InstanceStateRequested,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .instance_state_put_request(body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[5]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            101u16 => ResponseValue::upgrade(response).await,
-            200..=299 => ResponseValue::upgrade(response).await,
+            204u16 => Ok(ResponseValue::empty(response)),
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
             _ => Err(Error::UnexpectedResponse(response)),
         }
     }
 
-    ///Sends a `PUT` request to `/instance/state`
-    pub async fn instance_state_put<'a>(
+    ///Prepares the request of [`Client::instance_state_put`] without sending
+    /// it.
+    pub fn instance_state_put_request<'a>(
         &'a self,
         body: types::InstanceStateRequested,
-    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/instance/state", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -1794,15 +2078,46 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_state_put",
-        };
+        Ok(request)
+    }
+
+    ///Sends a `GET` request to `/instance/state-monitor`
+    pub async fn instance_state_monitor<'a>(
+        &'a self,
+        body: &'a types::InstanceStateMonitorRequest,
+    ) -> Result<ResponseValue<types::InstanceStateMonitorResponse>, Error<types::Error>> {
+        self.instance_state_monitor_with_options(body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::instance_state_monitor`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn instance_state_monitor_with_options<'a>(
+        &'a self,
+        body: &'a types::InstanceStateMonitorRequest,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::InstanceStateMonitorResponse>, Error<types::Error>> {
+        let mut request = self
+            .instance_state_monitor_request(body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[6]).with_context(options.apply(&mut request)?);
         self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
         match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
+            200u16 => ResponseValue::from_response(response).await,
             400u16..=499u16 => Err(Error::ErrorResponse(
                 ResponseValue::from_response(response).await?,
             )),
@@ -1813,11 +2128,12 @@ impl Client {
         }
     }
 
-    ///Sends a `GET` request to `/instance/state-monitor`
-    pub async fn instance_state_monitor<'a>(
+    ///Prepares the request of [`Client::instance_state_monitor`] without
+    /// sending it.
+    pub fn instance_state_monitor_request<'a>(
         &'a self,
         body: &'a types::InstanceStateMonitorRequest,
-    ) -> Result<ResponseValue<types::InstanceStateMonitorResponse>, Error<types::Error>> {
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!("{}/instance/state-monitor", self.baseurl,);
         let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
         header_map.append(
@@ -1835,23 +2151,7 @@ impl Client {
             .json(&body)
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "instance_state_monitor",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            200u16 => ResponseValue::from_response(response).await,
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 }
 
@@ -0,0 +1,71 @@
+#[allow(unused_imports)]
+use crate::propolis_server_builder::types;
+pub mod parameters {
+    //! Path, query, and header parameters of each operation, as
+    //! used by the handlers of [`Api`](super::Api).
+    #[allow(unused_imports)]
+    use crate::propolis_server_builder::types;
+    ///Path parameters of the `instance_issue_crucible_snapshot_request`
+    /// operation.
+    #[derive(Clone, Debug, serde :: Deserialize, schemars :: JsonSchema)]
+    pub struct InstanceIssueCrucibleSnapshotRequestPath {
+        pub id: ::uuid::Uuid,
+        pub snapshot_id: ::uuid::Uuid,
+    }
+}
+
+/// A Dropshot API with a handler for each operation.
+///
+/// Dropshot has no extractor for headers, so handlers decode the
+/// header parameters of an operation from the request in `rqctx`
+/// with the `from_headers` function of its `parameters` struct.
+/// Paginated operations receive their scan parameters with
+/// `PaginationParams`; the page selector of their `ResultsPage`
+/// is any JSON value. An implementation may be served with the
+/// `ApiDescription` from `api_mod::api_description`.
+#[dropshot::api_description]
+pub trait Api {
+    /// The context shared by all handlers.
+    type Context;
+    ///Handles `GET /instance`
+    # [endpoint { method = GET , path = "/instance" , }]
+    async fn instance_get(
+        rqctx: dropshot::RequestContext<Self::Context>,
+    ) -> Result<dropshot::HttpResponseOk<types::InstanceGetResponse>, dropshot::HttpError>;
+    ///Handles `PUT /instance`
+    # [endpoint { method = PUT , path = "/instance" , }]
+    async fn instance_ensure(
+        rqctx: dropshot::RequestContext<Self::Context>,
+        body: dropshot::TypedBody<types::InstanceEnsureRequest>,
+    ) -> Result<dropshot::HttpResponseCreated<types::InstanceEnsureResponse>, dropshot::HttpError>;
+    ///Issue a snapshot request to a crucible backend
+    # [endpoint { method = POST , path = "/instance/disk/{id}/snapshot/{snapshot_id}" , }]
+    async fn instance_issue_crucible_snapshot_request(
+        rqctx: dropshot::RequestContext<Self::Context>,
+        path: dropshot::Path<parameters::InstanceIssueCrucibleSnapshotRequestPath>,
+    ) -> Result<dropshot::HttpResponseOk<()>, dropshot::HttpError>;
+    ///Handles `GET /instance/migrate/status`
+    # [endpoint { method = GET , path = "/instance/migrate/status" , }]
+    async fn instance_migrate_status(
+        rqctx: dropshot::RequestContext<Self::Context>,
+        body: dropshot::TypedBody<types::InstanceMigrateStatusRequest>,
+    ) -> Result<dropshot::HttpResponseOk<types::InstanceMigrateStatusResponse>, dropshot::HttpError>;
+    ///Handles `GET /instance/serial`
+    # [channel { protocol = WEBSOCKETS , path = "/instance/serial" , }]
+    async fn instance_serial(
+        rqctx: dropshot::RequestContext<Self::Context>,
+        upgraded: dropshot::WebsocketConnection,
+    ) -> dropshot::WebsocketChannelResult;
+    ///Handles `PUT /instance/state`
+    # [endpoint { method = PUT , path = "/instance/state" , }]
+    async fn instance_state_put(
+        rqctx: dropshot::RequestContext<Self::Context>,
+        body: dropshot::TypedBody<types::InstanceStateRequested>,
+    ) -> Result<dropshot::HttpResponseUpdatedNoContent, dropshot::HttpError>;
+    ///Handles `GET /instance/state-monitor`
+    # [endpoint { method = GET , path = "/instance/state-monitor" , }]
+    async fn instance_state_monitor(
+        rqctx: dropshot::RequestContext<Self::Context>,
+        body: dropshot::TypedBody<types::InstanceStateMonitorRequest>,
+    ) -> Result<dropshot::HttpResponseOk<types::InstanceStateMonitorResponse>, dropshot::HttpError>;
+}
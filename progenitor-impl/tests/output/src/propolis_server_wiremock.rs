@@ -0,0 +1,472 @@
+pub mod operations {
+    #![doc = r" [`MockBuilder`](::wiremock::MockBuilder) and"]
+    #![doc = r" [`ResponseTemplate`](::wiremock::ResponseTemplate)"]
+    #![doc = r" wrappers for each operation. Each can be converted to"]
+    #![doc = r" its inner type with a call to `into_inner()`. This can"]
+    #![doc = r" be used to explicitly deviate from permitted values."]
+    use crate::propolis_server_builder::*;
+    pub struct InstanceGetWhen(::wiremock::MockBuilder);
+    impl InstanceGetWhen {
+        pub fn new(inner: ::wiremock::MockBuilder) -> Self {
+            Self(
+                inner
+                    .and(::wiremock::matchers::method("GET"))
+                    .and(::wiremock::matchers::path_regex("^/instance$")),
+            )
+        }
+
+        pub fn into_inner(self) -> ::wiremock::MockBuilder {
+            self.0
+        }
+
+        pub fn respond_with(self, then: InstanceGetThen) -> ::wiremock::Mock {
+            self.0.respond_with(then.0)
+        }
+    }
+
+    pub struct InstanceGetThen(::wiremock::ResponseTemplate);
+    impl InstanceGetThen {
+        pub fn new(inner: ::wiremock::ResponseTemplate) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::wiremock::ResponseTemplate {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.set_delay(duration))
+        }
+
+        pub fn ok(value: &types::InstanceGetResponse) -> Self {
+            Self(::wiremock::ResponseTemplate::new(200u16).set_body_json(value))
+        }
+
+        pub fn client_error(status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 4u16);
+            Self(::wiremock::ResponseTemplate::new(status).set_body_json(value))
+        }
+
+        pub fn server_error(status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 5u16);
+            Self(::wiremock::ResponseTemplate::new(status).set_body_json(value))
+        }
+    }
+
+    pub struct InstanceEnsureWhen(::wiremock::MockBuilder);
+    impl InstanceEnsureWhen {
+        pub fn new(inner: ::wiremock::MockBuilder) -> Self {
+            Self(
+                inner
+                    .and(::wiremock::matchers::method("PUT"))
+                    .and(::wiremock::matchers::path_regex("^/instance$")),
+            )
+        }
+
+        pub fn into_inner(self) -> ::wiremock::MockBuilder {
+            self.0
+        }
+
+        pub fn respond_with(self, then: InstanceEnsureThen) -> ::wiremock::Mock {
+            self.0.respond_with(then.0)
+        }
+
+        pub fn body(self, value: &types::InstanceEnsureRequest) -> Self {
+            Self(self.0.and(::wiremock::matchers::body_json(value)))
+        }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.and(::wiremock::matchers::body_partial_json(value)))
+        }
+
+        pub fn body_matches<F>(self, predicate: F) -> Self
+        where
+            F: Fn(&types::InstanceEnsureRequest) -> bool + Send + Sync + 'static,
+        {
+            Self(self.0.and(move |req: &::wiremock::Request| {
+                req.body_json::<types::InstanceEnsureRequest>()
+                    .is_ok_and(|body| predicate(&body))
+            }))
+        }
+    }
+
+    pub struct InstanceEnsureThen(::wiremock::ResponseTemplate);
+    impl InstanceEnsureThen {
+        pub fn new(inner: ::wiremock::ResponseTemplate) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::wiremock::ResponseTemplate {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.set_delay(duration))
+        }
+
+        pub fn created(value: &types::InstanceEnsureResponse) -> Self {
+            Self(::wiremock::ResponseTemplate::new(201u16).set_body_json(value))
+        }
+
+        pub fn client_error(status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 4u16);
+            Self(::wiremock::ResponseTemplate::new(status).set_body_json(value))
+        }
+
+        pub fn server_error(status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 5u16);
+            Self(::wiremock::ResponseTemplate::new(status).set_body_json(value))
+        }
+    }
+
+    pub struct InstanceIssueCrucibleSnapshotRequestWhen(::wiremock::MockBuilder);
+    impl InstanceIssueCrucibleSnapshotRequestWhen {
+        pub fn new(inner: ::wiremock::MockBuilder) -> Self {
+            Self(inner.and(::wiremock::matchers::method("POST")).and(
+                ::wiremock::matchers::path_regex("^/instance/disk/[^/]*/snapshot/[^/]*$"),
+            ))
+        }
+
+        pub fn into_inner(self) -> ::wiremock::MockBuilder {
+            self.0
+        }
+
+        pub fn respond_with(
+            self,
+            then: InstanceIssueCrucibleSnapshotRequestThen,
+        ) -> ::wiremock::Mock {
+            self.0.respond_with(then.0)
+        }
+
+        pub fn id(self, value: &::uuid::Uuid) -> Self {
+            Self(self.0.and(::wiremock::matchers::path_regex(format!(
+                "^/instance/disk/{}/snapshot/.*$",
+                value.to_string()
+            ))))
+        }
+
+        pub fn snapshot_id(self, value: &::uuid::Uuid) -> Self {
+            Self(self.0.and(::wiremock::matchers::path_regex(format!(
+                "^/instance/disk/.*/snapshot/{}$",
+                value.to_string()
+            ))))
+        }
+    }
+
+    pub struct InstanceIssueCrucibleSnapshotRequestThen(::wiremock::ResponseTemplate);
+    impl InstanceIssueCrucibleSnapshotRequestThen {
+        pub fn new(inner: ::wiremock::ResponseTemplate) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::wiremock::ResponseTemplate {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.set_delay(duration))
+        }
+
+        pub fn ok(value: ()) -> Self {
+            Self(::wiremock::ResponseTemplate::new(200u16).set_body_json(value))
+        }
+
+        pub fn client_error(status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 4u16);
+            Self(::wiremock::ResponseTemplate::new(status).set_body_json(value))
+        }
+
+        pub fn server_error(status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 5u16);
+            Self(::wiremock::ResponseTemplate::new(status).set_body_json(value))
+        }
+    }
+
+    pub struct InstanceMigrateStatusWhen(::wiremock::MockBuilder);
+    impl InstanceMigrateStatusWhen {
+        pub fn new(inner: ::wiremock::MockBuilder) -> Self {
+            Self(inner.and(::wiremock::matchers::method("GET")).and(
+                ::wiremock::matchers::path_regex("^/instance/migrate/status$"),
+            ))
+        }
+
+        pub fn into_inner(self) -> ::wiremock::MockBuilder {
+            self.0
+        }
+
+        pub fn respond_with(self, then: InstanceMigrateStatusThen) -> ::wiremock::Mock {
+            self.0.respond_with(then.0)
+        }
+
+        pub fn body(self, value: &types::InstanceMigrateStatusRequest) -> Self {
+            Self(self.0.and(::wiremock::matchers::body_json(value)))
+        }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.and(::wiremock::matchers::body_partial_json(value)))
+        }
+
+        pub fn body_matches<F>(self, predicate: F) -> Self
+        where
+            F: Fn(&types::InstanceMigrateStatusRequest) -> bool + Send + Sync + 'static,
+        {
+            Self(self.0.and(move |req: &::wiremock::Request| {
+                req.body_json::<types::InstanceMigrateStatusRequest>()
+                    .is_ok_and(|body| predicate(&body))
+            }))
+        }
+    }
+
+    pub struct InstanceMigrateStatusThen(::wiremock::ResponseTemplate);
+    impl InstanceMigrateStatusThen {
+        pub fn new(inner: ::wiremock::ResponseTemplate) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::wiremock::ResponseTemplate {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.set_delay(duration))
+        }
+
+        pub fn ok(value: &types::InstanceMigrateStatusResponse) -> Self {
+            Self(::wiremock::ResponseTemplate::new(200u16).set_body_json(value))
+        }
+
+        pub fn client_error(status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 4u16);
+            Self(::wiremock::ResponseTemplate::new(status).set_body_json(value))
+        }
+
+        pub fn server_error(status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 5u16);
+            Self(::wiremock::ResponseTemplate::new(status).set_body_json(value))
+        }
+    }
+
+    pub struct InstanceSerialWhen(::wiremock::MockBuilder);
+    impl InstanceSerialWhen {
+        pub fn new(inner: ::wiremock::MockBuilder) -> Self {
+            Self(
+                inner
+                    .and(::wiremock::matchers::method("GET"))
+                    .and(::wiremock::matchers::path_regex("^/instance/serial$")),
+            )
+        }
+
+        pub fn into_inner(self) -> ::wiremock::MockBuilder {
+            self.0
+        }
+
+        pub fn respond_with(self, then: InstanceSerialThen) -> ::wiremock::Mock {
+            self.0.respond_with(then.0)
+        }
+    }
+
+    pub struct InstanceSerialThen(::wiremock::ResponseTemplate);
+    impl InstanceSerialThen {
+        pub fn new(inner: ::wiremock::ResponseTemplate) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::wiremock::ResponseTemplate {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.set_delay(duration))
+        }
+
+        pub fn default_response(status: u16) -> Self {
+            Self(::wiremock::ResponseTemplate::new(status))
+        }
+
+        pub fn switching_protocols() -> Self {
+            Self(::wiremock::ResponseTemplate::new(101u16))
+        }
+    }
+
+    pub struct InstanceStatePutWhen(::wiremock::MockBuilder);
+    impl InstanceStatePutWhen {
+        pub fn new(inner: ::wiremock::MockBuilder) -> Self {
+            Self(
+                inner
+                    .and(::wiremock::matchers::method("PUT"))
+                    .and(::wiremock::matchers::path_regex("^/instance/state$")),
+            )
+        }
+
+        pub fn into_inner(self) -> ::wiremock::MockBuilder {
+            self.0
+        }
+
+        pub fn respond_with(self, then: InstanceStatePutThen) -> ::wiremock::Mock {
+            self.0.respond_with(then.0)
+        }
+
+        pub fn body(self, value: types::InstanceStateRequested) -> Self {
+            Self(self.0.and(::wiremock::matchers::body_json(value)))
+        }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.and(::wiremock::matchers::body_partial_json(value)))
+        }
+
+        pub fn body_matches<F>(self, predicate: F) -> Self
+        where
+            F: Fn(&types::InstanceStateRequested) -> bool + Send + Sync + 'static,
+        {
+            Self(self.0.and(move |req: &::wiremock::Request| {
+                req.body_json::<types::InstanceStateRequested>()
+                    .is_ok_and(|body| predicate(&body))
+            }))
+        }
+    }
+
+    pub struct InstanceStatePutThen(::wiremock::ResponseTemplate);
+    impl InstanceStatePutThen {
+        pub fn new(inner: ::wiremock::ResponseTemplate) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::wiremock::ResponseTemplate {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.set_delay(duration))
+        }
+
+        pub fn no_content() -> Self {
+            Self(::wiremock::ResponseTemplate::new(204u16))
+        }
+
+        pub fn client_error(status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 4u16);
+            Self(::wiremock::ResponseTemplate::new(status).set_body_json(value))
+        }
+
+        pub fn server_error(status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 5u16);
+            Self(::wiremock::ResponseTemplate::new(status).set_body_json(value))
+        }
+    }
+
+    pub struct InstanceStateMonitorWhen(::wiremock::MockBuilder);
+    impl InstanceStateMonitorWhen {
+        pub fn new(inner: ::wiremock::MockBuilder) -> Self {
+            Self(inner.and(::wiremock::matchers::method("GET")).and(
+                ::wiremock::matchers::path_regex("^/instance/state-monitor$"),
+            ))
+        }
+
+        pub fn into_inner(self) -> ::wiremock::MockBuilder {
+            self.0
+        }
+
+        pub fn respond_with(self, then: InstanceStateMonitorThen) -> ::wiremock::Mock {
+            self.0.respond_with(then.0)
+        }
+
+        pub fn body(self, value: &types::InstanceStateMonitorRequest) -> Self {
+            Self(self.0.and(::wiremock::matchers::body_json(value)))
+        }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.and(::wiremock::matchers::body_partial_json(value)))
+        }
+
+        pub fn body_matches<F>(self, predicate: F) -> Self
+        where
+            F: Fn(&types::InstanceStateMonitorRequest) -> bool + Send + Sync + 'static,
+        {
+            Self(self.0.and(move |req: &::wiremock::Request| {
+                req.body_json::<types::InstanceStateMonitorRequest>()
+                    .is_ok_and(|body| predicate(&body))
+            }))
+        }
+    }
+
+    pub struct InstanceStateMonitorThen(::wiremock::ResponseTemplate);
+    impl InstanceStateMonitorThen {
+        pub fn new(inner: ::wiremock::ResponseTemplate) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::wiremock::ResponseTemplate {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.set_delay(duration))
+        }
+
+        pub fn ok(value: &types::InstanceStateMonitorResponse) -> Self {
+            Self(::wiremock::ResponseTemplate::new(200u16).set_body_json(value))
+        }
+
+        pub fn client_error(status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 4u16);
+            Self(::wiremock::ResponseTemplate::new(status).set_body_json(value))
+        }
+
+        pub fn server_error(status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 5u16);
+            Self(::wiremock::ResponseTemplate::new(status).set_body_json(value))
+        }
+    }
+}
+
+#[doc = r" An extension trait for [`Mock`](::wiremock::Mock) that adds a"]
+#[doc = r" constructor for each operation. These are the equivalent of"]
+#[doc = r" type-checked [`given()`](::wiremock::Mock::given) calls; finish"]
+#[doc = r" each with `respond_with()` and mount the resulting mock."]
+pub trait MockExt {
+    fn instance_get() -> operations::InstanceGetWhen;
+    fn instance_ensure() -> operations::InstanceEnsureWhen;
+    fn instance_issue_crucible_snapshot_request(
+    ) -> operations::InstanceIssueCrucibleSnapshotRequestWhen;
+    fn instance_migrate_status() -> operations::InstanceMigrateStatusWhen;
+    fn instance_serial() -> operations::InstanceSerialWhen;
+    fn instance_state_put() -> operations::InstanceStatePutWhen;
+    fn instance_state_monitor() -> operations::InstanceStateMonitorWhen;
+}
+
+impl MockExt for ::wiremock::Mock {
+    fn instance_get() -> operations::InstanceGetWhen {
+        operations::InstanceGetWhen::new(::wiremock::Mock::given(::wiremock::matchers::any()))
+    }
+
+    fn instance_ensure() -> operations::InstanceEnsureWhen {
+        operations::InstanceEnsureWhen::new(::wiremock::Mock::given(::wiremock::matchers::any()))
+    }
+
+    fn instance_issue_crucible_snapshot_request(
+    ) -> operations::InstanceIssueCrucibleSnapshotRequestWhen {
+        operations::InstanceIssueCrucibleSnapshotRequestWhen::new(::wiremock::Mock::given(
+            ::wiremock::matchers::any(),
+        ))
+    }
+
+    fn instance_migrate_status() -> operations::InstanceMigrateStatusWhen {
+        operations::InstanceMigrateStatusWhen::new(::wiremock::Mock::given(
+            ::wiremock::matchers::any(),
+        ))
+    }
+
+    fn instance_serial() -> operations::InstanceSerialWhen {
+        operations::InstanceSerialWhen::new(::wiremock::Mock::given(::wiremock::matchers::any()))
+    }
+
+    fn instance_state_put() -> operations::InstanceStatePutWhen {
+        operations::InstanceStatePutWhen::new(::wiremock::Mock::given(::wiremock::matchers::any()))
+    }
+
+    fn instance_state_monitor() -> operations::InstanceStateMonitorWhen {
+        operations::InstanceStateMonitorWhen::new(::wiremock::Mock::given(
+            ::wiremock::matchers::any(),
+        ))
+    }
+}
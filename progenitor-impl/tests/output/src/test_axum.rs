@@ -0,0 +1,97 @@
+#[allow(unused_imports)]
+use super::types;
+pub mod parameters {
+    //! Path and query parameters of each operation, as extracted
+    //! by the handlers of [`Api`](super::Api).
+    #[allow(unused_imports)]
+    use super::types;
+    ///Path parameters of the `list_folder_notes` operation.
+    #[derive(Clone, Debug, serde :: Deserialize)]
+    pub struct ListFolderNotesPath {
+        pub folder: ::std::string::String,
+    }
+
+    ///Query parameters of the `list_folder_notes` operation.
+    #[derive(Clone, Debug, serde :: Deserialize)]
+    pub struct ListFolderNotesQuery {
+        ///Maximum number of items returned by a single call
+        pub limit: Option<::std::num::NonZeroU32>,
+        ///Token returned by previous call to retrieve the subsequent page
+        pub page_token: Option<::std::string::String>,
+    }
+
+    ///Path parameters of the `put_note` operation.
+    #[derive(Clone, Debug, serde :: Deserialize)]
+    pub struct PutNotePath {
+        pub id: u32,
+    }
+
+    ///Query parameters of the `put_note` operation.
+    #[derive(Clone, Debug, serde :: Deserialize)]
+    pub struct PutNoteQuery {
+        pub pinned: Option<bool>,
+        pub visibility: ::std::string::String,
+    }
+}
+
+/// An axum service with a handler for each operation.
+///
+/// Successful responses are sent with the status code from the
+/// API description. A typed error is sent as JSON with the
+/// status code the handler returns with it; other responses are
+/// sent as the handler builds them.
+#[progenitor_client::async_trait]
+pub trait Api: Send + Sync + 'static {
+    ///Handles `GET /folders/{folder}/notes`
+    async fn list_folder_notes(
+        &self,
+        path: axum::extract::Path<parameters::ListFolderNotesPath>,
+        query: axum::extract::Query<parameters::ListFolderNotesQuery>,
+    ) -> Result<types::Uint32ResultsPage, (axum::http::StatusCode, types::Error)>;
+    ///Handles `PUT /notes/{id}`
+    async fn put_note(
+        &self,
+        path: axum::extract::Path<parameters::PutNotePath>,
+        query: axum::extract::Query<parameters::PutNoteQuery>,
+        body: axum::Json<types::Note>,
+    ) -> Result<types::NoteRecord, (axum::http::StatusCode, types::Error)>;
+}
+
+/// Build an `axum::Router` that routes each operation to its
+/// handler in `api`.
+pub fn router<A: Api>(api: std::sync::Arc<A>) -> axum::Router {
+    use axum::response::IntoResponse;
+    axum::Router::new()
+        .route(
+            "/folders/{folder}/notes",
+            axum::routing::get(
+                |axum::extract::State(api): axum::extract::State<std::sync::Arc<A>>,
+                 path: axum::extract::Path<parameters::ListFolderNotesPath>,
+                 query: axum::extract::Query<parameters::ListFolderNotesQuery>| async move {
+                    match api.list_folder_notes(path, query).await {
+                        Ok(value) => {
+                            (axum::http::StatusCode::OK, axum::Json(value)).into_response()
+                        }
+                        Err((status, error)) => (status, axum::Json(error)).into_response(),
+                    }
+                },
+            ),
+        )
+        .route(
+            "/notes/{id}",
+            axum::routing::put(
+                |axum::extract::State(api): axum::extract::State<std::sync::Arc<A>>,
+                 path: axum::extract::Path<parameters::PutNotePath>,
+                 query: axum::extract::Query<parameters::PutNoteQuery>,
+                 body: axum::Json<types::Note>| async move {
+                    match api.put_note(path, query, body).await {
+                        Ok(value) => {
+                            (axum::http::StatusCode::OK, axum::Json(value)).into_response()
+                        }
+                        Err((status, error)) => (status, axum::Json(error)).into_response(),
+                    }
+                },
+            ),
+        )
+        .with_state(api)
+}
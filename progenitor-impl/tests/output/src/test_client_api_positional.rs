@@ -0,0 +1,582 @@
+#[allow(unused_imports)]
+use progenitor_client::{encode_path, ClientHooks, OperationInfo, RequestBuilderExt};
+#[allow(unused_imports)]
+pub use progenitor_client::{ByteStream, ClientInfo, Error, RequestOptions, ResponseValue};
+/// Types used as operation parameters and responses.
+#[allow(clippy::all)]
+pub mod types {
+    /// Error types.
+    pub mod error {
+        /// Error from a `TryFrom` or `FromStr` implementation.
+        pub struct ConversionError(::std::borrow::Cow<'static, str>);
+        impl ::std::error::Error for ConversionError {}
+        impl ::std::fmt::Display for ConversionError {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+                ::std::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl ::std::fmt::Debug for ConversionError {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+                ::std::fmt::Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl From<&'static str> for ConversionError {
+            fn from(value: &'static str) -> Self {
+                Self(value.into())
+            }
+        }
+
+        impl From<String> for ConversionError {
+            fn from(value: String) -> Self {
+                Self(value.into())
+            }
+        }
+    }
+
+    ///Error information from a response.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "Error information from a response.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "message",
+    ///    "request_id"
+    ///  ],
+    ///  "properties": {
+    ///    "error_code": {
+    ///      "type": "string"
+    ///    },
+    ///    "message": {
+    ///      "type": "string"
+    ///    },
+    ///    "request_id": {
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    pub struct Error {
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub error_code: ::std::option::Option<::std::string::String>,
+        pub message: ::std::string::String,
+        pub request_id: ::std::string::String,
+    }
+
+    impl ::std::convert::From<&Error> for Error {
+        fn from(value: &Error) -> Self {
+            value.clone()
+        }
+    }
+
+    ///`Note`
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "type": "object",
+    ///  "required": [
+    ///    "text"
+    ///  ],
+    ///  "properties": {
+    ///    "text": {
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    pub struct Note {
+        pub text: ::std::string::String,
+    }
+
+    impl ::std::convert::From<&Note> for Note {
+        fn from(value: &Note) -> Self {
+            value.clone()
+        }
+    }
+
+    ///`NoteRecord`
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "type": "object",
+    ///  "required": [
+    ///    "id",
+    ///    "pinned",
+    ///    "text",
+    ///    "visibility"
+    ///  ],
+    ///  "properties": {
+    ///    "id": {
+    ///      "type": "integer",
+    ///      "format": "uint32",
+    ///      "minimum": 0.0
+    ///    },
+    ///    "pinned": {
+    ///      "type": "boolean"
+    ///    },
+    ///    "text": {
+    ///      "type": "string"
+    ///    },
+    ///    "visibility": {
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    pub struct NoteRecord {
+        pub id: u32,
+        pub pinned: bool,
+        pub text: ::std::string::String,
+        pub visibility: ::std::string::String,
+    }
+
+    impl ::std::convert::From<&NoteRecord> for NoteRecord {
+        fn from(value: &NoteRecord) -> Self {
+            value.clone()
+        }
+    }
+
+    ///A single page of results
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "A single page of results",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "items"
+    ///  ],
+    ///  "properties": {
+    ///    "items": {
+    ///      "description": "list of items on this page of results",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "type": "integer",
+    ///        "format": "uint32",
+    ///        "minimum": 0.0
+    ///      }
+    ///    },
+    ///    "next_page": {
+    ///      "description": "token used to fetch the next page of results (if
+    /// any)",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    pub struct Uint32ResultsPage {
+        ///list of items on this page of results
+        pub items: ::std::vec::Vec<u32>,
+        ///token used to fetch the next page of results (if any)
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub next_page: ::std::option::Option<::std::string::String>,
+    }
+
+    impl ::std::convert::From<&Uint32ResultsPage> for Uint32ResultsPage {
+        fn from(value: &Uint32ResultsPage) -> Self {
+            value.clone()
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+///Client for test_client_api
+///
+///Version: 1.0.0
+pub struct Client {
+    pub(crate) baseurl: String,
+    pub(crate) client: reqwest::Client,
+    pub(crate) middleware: progenitor_client::MiddlewareStack,
+}
+
+impl Client {
+    /// Create a new client.
+    ///
+    /// `baseurl` is the base URL provided to the internal
+    /// `reqwest::Client`, and should include a scheme and hostname,
+    /// as well as port and a path stem if applicable.
+    pub fn new(baseurl: &str) -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        let client = {
+            let dur = std::time::Duration::from_secs(15);
+            reqwest::ClientBuilder::new()
+                .connect_timeout(dur)
+                .timeout(dur)
+        };
+        #[cfg(target_arch = "wasm32")]
+        let client = reqwest::ClientBuilder::new();
+        Self::new_with_client(baseurl, client.build().unwrap())
+    }
+
+    /// Construct a new client with an existing `reqwest::Client`,
+    /// allowing more control over its configuration.
+    ///
+    /// `baseurl` is the base URL provided to the internal
+    /// `reqwest::Client`, and should include a scheme and hostname,
+    /// as well as port and a path stem if applicable.
+    pub fn new_with_client(baseurl: &str, client: reqwest::Client) -> Self {
+        Self {
+            baseurl: baseurl.to_string(),
+            client,
+            middleware: Default::default(),
+        }
+    }
+
+    /// Append `middleware` to the chain that surrounds the
+    /// execution of each request. Middleware runs in the order in
+    /// which it is attached.
+    pub fn with_middleware<M: progenitor_client::Middleware>(mut self, middleware: M) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Descriptions of the API's operations.
+    pub const OPERATIONS: &'static [progenitor_client::OperationMetadata] = &[
+        progenitor_client::OperationMetadata {
+            operation_id: "list_folder_notes",
+            method: reqwest::Method::GET,
+            path: "/folders/{folder}/notes",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "put_note",
+            method: reqwest::Method::PUT,
+            path: "/notes/{id}",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+    ];
+}
+
+impl ClientInfo<()> for Client {
+    fn api_version() -> &'static str {
+        "1.0.0"
+    }
+
+    fn baseurl(&self) -> &str {
+        self.baseurl.as_str()
+    }
+
+    fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    fn inner(&self) -> &() {
+        &()
+    }
+}
+
+impl ClientHooks<()> for &Client {}
+#[allow(clippy::all)]
+#[allow(elided_named_lifetimes)]
+impl Client {
+    ///Sends a `GET` request to `/folders/{folder}/notes`
+    ///
+    ///Arguments:
+    /// - `folder`
+    /// - `limit`: Maximum number of items returned by a single call
+    /// - `page_token`: Token returned by previous call to retrieve the
+    ///   subsequent page
+    pub async fn list_folder_notes<'a>(
+        &'a self,
+        folder: &'a str,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+    ) -> Result<ResponseValue<types::Uint32ResultsPage>, Error<types::Error>> {
+        self.list_folder_notes_with_options(
+            folder,
+            limit,
+            page_token,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::list_folder_notes`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn list_folder_notes_with_options<'a>(
+        &'a self,
+        folder: &'a str,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Uint32ResultsPage>, Error<types::Error>> {
+        let mut request = self
+            .list_folder_notes_request(folder, limit, page_token)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[0]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::list_folder_notes`] without sending
+    /// it.
+    pub fn list_folder_notes_request<'a>(
+        &'a self,
+        folder: &'a str,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+        let url = format!(
+            "{}/folders/{}/notes",
+            self.baseurl,
+            encode_path(&folder.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+        header_map.append(
+            ::reqwest::header::HeaderName::from_static("api-version"),
+            ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+        );
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .query(&progenitor_client::QueryParam::new("limit", &limit))
+            .query(&progenitor_client::QueryParam::new(
+                "page_token",
+                &page_token,
+            ))
+            .headers(header_map)
+            .build()?;
+        Ok(request)
+    }
+
+    ///Sends repeated `GET` requests to `/folders/{folder}/notes` until there
+    /// are no more results.
+    ///
+    ///Arguments:
+    /// - `folder`
+    /// - `limit`: Maximum number of items returned by a single call
+    pub fn list_folder_notes_stream<'a>(
+        &'a self,
+        folder: &'a str,
+        limit: Option<::std::num::NonZeroU32>,
+    ) -> impl futures::Stream<Item = Result<u32, Error<types::Error>>> + Unpin + '_ {
+        use futures::StreamExt;
+        use futures::TryFutureExt;
+        use futures::TryStreamExt;
+        self.list_folder_notes(folder, limit, None)
+            .map_ok(move |page| {
+                let page = page.into_inner();
+                let first = futures::stream::iter(page.items).map(Ok);
+                let rest = futures::stream::try_unfold(page.next_page, move |state| async move {
+                    if state.is_none() {
+                        Ok(None)
+                    } else {
+                        self.list_folder_notes(folder, limit, state.as_deref())
+                            .map_ok(|page| {
+                                let page = page.into_inner();
+                                Some((futures::stream::iter(page.items).map(Ok), page.next_page))
+                            })
+                            .await
+                    }
+                })
+                .try_flatten();
+                first.chain(rest)
+            })
+            .try_flatten_stream()
+            .boxed()
+    }
+
+    ///Sends a `PUT` request to `/notes/{id}`
+    pub async fn put_note<'a>(
+        &'a self,
+        id: u32,
+        pinned: Option<bool>,
+        visibility: &'a str,
+        body: &'a types::Note,
+    ) -> Result<ResponseValue<types::NoteRecord>, Error<types::Error>> {
+        self.put_note_with_options(
+            id,
+            pinned,
+            visibility,
+            body,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::put_note`] with additional headers, query
+    /// parameters, or context from `options`.
+    pub async fn put_note_with_options<'a>(
+        &'a self,
+        id: u32,
+        pinned: Option<bool>,
+        visibility: &'a str,
+        body: &'a types::Note,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::NoteRecord>, Error<types::Error>> {
+        let mut request = self
+            .put_note_request(id, pinned, visibility, body)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[1]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::put_note`] without sending it.
+    pub fn put_note_request<'a>(
+        &'a self,
+        id: u32,
+        pinned: Option<bool>,
+        visibility: &'a str,
+        body: &'a types::Note,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+        let url = format!("{}/notes/{}", self.baseurl, encode_path(&id.to_string()),);
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+        header_map.append(
+            ::reqwest::header::HeaderName::from_static("api-version"),
+            ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+        );
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .put(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .json(&body)
+            .query(&progenitor_client::QueryParam::new("pinned", &pinned))
+            .query(&progenitor_client::QueryParam::new(
+                "visibility",
+                &visibility,
+            ))
+            .headers(header_map)
+            .build()?;
+        Ok(request)
+    }
+}
+
+/// Items consumers will typically use such as the Client.
+pub mod prelude {
+    #[allow(unused_imports)]
+    pub use super::Client;
+}
+
+/// The operations of the API, implemented by [`Client`].
+///
+/// Code that depends on this trait rather than on `Client` may be
+/// tested with a fake or mock implementation.
+#[progenitor_client::async_trait]
+pub trait ClientApi {
+    ///Sends a `GET` request to `/folders/{folder}/notes`
+    ///
+    ///Arguments:
+    /// - `folder`
+    /// - `limit`: Maximum number of items returned by a single call
+    /// - `page_token`: Token returned by previous call to retrieve the
+    ///   subsequent page
+    async fn list_folder_notes<'a>(
+        &self,
+        folder: &'a str,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+    ) -> Result<ResponseValue<types::Uint32ResultsPage>, Error<types::Error>>;
+    ///Sends a `PUT` request to `/notes/{id}`
+    async fn put_note<'a>(
+        &self,
+        id: u32,
+        pinned: Option<bool>,
+        visibility: &'a str,
+        body: &'a types::Note,
+    ) -> Result<ResponseValue<types::NoteRecord>, Error<types::Error>>;
+}
+
+#[allow(clippy::all)]
+#[progenitor_client::async_trait]
+impl ClientApi for Client {
+    async fn list_folder_notes<'a>(
+        &self,
+        folder: &'a str,
+        limit: Option<::std::num::NonZeroU32>,
+        page_token: Option<&'a str>,
+    ) -> Result<ResponseValue<types::Uint32ResultsPage>, Error<types::Error>> {
+        Client::list_folder_notes(self, folder, limit, page_token).await
+    }
+
+    async fn put_note<'a>(
+        &self,
+        id: u32,
+        pinned: Option<bool>,
+        visibility: &'a str,
+        body: &'a types::Note,
+    ) -> Result<ResponseValue<types::NoteRecord>, Error<types::Error>> {
+        Client::put_note(self, id, pinned, visibility, body).await
+    }
+}
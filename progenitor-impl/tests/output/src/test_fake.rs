@@ -0,0 +1,115 @@
+#[allow(unused_imports)]
+use super::types;
+pub mod parameters {
+    //! Path and query parameters of each operation, as passed to
+    //! the methods of [`Api`](super::Api).
+    #[allow(unused_imports)]
+    use super::types;
+    ///Path parameters of the `list_folder_notes` operation.
+    #[derive(Clone, Debug, serde :: Deserialize)]
+    pub struct ListFolderNotesPath {
+        pub folder: ::std::string::String,
+    }
+
+    ///Query parameters of the `list_folder_notes` operation.
+    #[derive(Clone, Debug, serde :: Deserialize)]
+    pub struct ListFolderNotesQuery {
+        ///Maximum number of items returned by a single call
+        pub limit: Option<::std::num::NonZeroU32>,
+        ///Token returned by previous call to retrieve the subsequent page
+        pub page_token: Option<::std::string::String>,
+    }
+
+    ///Path parameters of the `put_note` operation.
+    #[derive(Clone, Debug, serde :: Deserialize)]
+    pub struct PutNotePath {
+        pub id: u32,
+    }
+
+    ///Query parameters of the `put_note` operation.
+    #[derive(Clone, Debug, serde :: Deserialize)]
+    pub struct PutNoteQuery {
+        pub pinned: Option<bool>,
+        pub visibility: ::std::string::String,
+    }
+}
+
+/// The operations of the API, as implemented by a fake backend.
+///
+/// Successful responses are sent with the status code from the
+/// API description. A typed error is sent as JSON with the
+/// status code the method returns with it; other responses are
+/// sent as the method builds them.
+#[progenitor_client::async_trait]
+pub trait Api: Send + Sync + 'static {
+    ///Handles `GET /folders/{folder}/notes`
+    async fn list_folder_notes(
+        &self,
+        path: parameters::ListFolderNotesPath,
+        query: parameters::ListFolderNotesQuery,
+    ) -> Result<types::Uint32ResultsPage, (reqwest::StatusCode, types::Error)>;
+    ///Handles `PUT /notes/{id}`
+    async fn put_note(
+        &self,
+        path: parameters::PutNotePath,
+        query: parameters::PutNoteQuery,
+        body: types::Note,
+    ) -> Result<types::NoteRecord, (reqwest::StatusCode, types::Error)>;
+}
+
+/// Middleware that answers each request by calling the method of
+/// `A` for its operation, without sending it.
+///
+/// Attach it to a client with `with_middleware`, after any other
+/// middleware.
+pub struct Fake<A>(pub std::sync::Arc<A>);
+impl<A: Api> Fake<A> {
+    /// Create middleware that calls `api`.
+    pub fn new(api: A) -> Self {
+        Self(std::sync::Arc::new(api))
+    }
+}
+
+impl<A: Api> progenitor_client::Middleware for Fake<A> {
+    fn handle<'a>(
+        &'a self,
+        request: reqwest::Request,
+        info: &'a progenitor_client::OperationInfo,
+        _next: progenitor_client::Next<'a>,
+    ) -> progenitor_client::MiddlewareFuture<'a> {
+        Box::pin(async move {
+            let request = progenitor_client::FakeRequest::new(&request, info);
+            match info.operation_id {
+                "list_folder_notes" => {
+                    let path = request.path()?;
+                    let query = request.query()?;
+                    match self.0.list_folder_notes(path, query).await {
+                        Ok(value) => {
+                            progenitor_client::fake_json_response(reqwest::StatusCode::OK, &value)
+                        }
+                        Err((status, error)) => {
+                            progenitor_client::fake_json_response(status, &error)
+                        }
+                    }
+                }
+                "put_note" => {
+                    let path = request.path()?;
+                    let query = request.query()?;
+                    let body = request.json()?;
+                    match self.0.put_note(path, query, body).await {
+                        Ok(value) => {
+                            progenitor_client::fake_json_response(reqwest::StatusCode::OK, &value)
+                        }
+                        Err((status, error)) => {
+                            progenitor_client::fake_json_response(status, &error)
+                        }
+                    }
+                }
+                _ => Err(progenitor_client::Error::Custom(format!(
+                    "unknown operation {}",
+                    info.operation_id,
+                ))),
+            }
+        })
+    }
+}
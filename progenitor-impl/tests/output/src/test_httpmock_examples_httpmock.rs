@@ -0,0 +1,398 @@
+pub mod operations {
+    //! [`When`](::httpmock::When) and [`Then`](::httpmock::Then)
+    //! wrappers for each operation. Each can be converted to
+    //! its inner type with a call to `into_inner()`. This can
+    //! be used to explicitly deviate from permitted values.
+    use crate::test_httpmock_examples_positional::*;
+    pub struct GetChoiceWhen(::httpmock::When);
+    impl GetChoiceWhen {
+        pub fn new(inner: ::httpmock::When) -> Self {
+            Self(
+                inner
+                    .method(::httpmock::Method::GET)
+                    .path_matches(regex::Regex::new("^/choice$").unwrap()),
+            )
+        }
+
+        pub fn into_inner(self) -> ::httpmock::When {
+            self.0
+        }
+    }
+
+    pub struct GetChoiceThen(::httpmock::Then);
+    impl GetChoiceThen {
+        pub fn new(inner: ::httpmock::Then) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::httpmock::Then {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
+        pub fn ok(self, value: &types::Choice) -> Self {
+            Self(
+                self.0
+                    .status(200u16)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+    }
+
+    pub struct GetImpossibleWhen(::httpmock::When);
+    impl GetImpossibleWhen {
+        pub fn new(inner: ::httpmock::When) -> Self {
+            Self(
+                inner
+                    .method(::httpmock::Method::GET)
+                    .path_matches(regex::Regex::new("^/impossible$").unwrap()),
+            )
+        }
+
+        pub fn into_inner(self) -> ::httpmock::When {
+            self.0
+        }
+    }
+
+    pub struct GetImpossibleThen(::httpmock::Then);
+    impl GetImpossibleThen {
+        pub fn new(inner: ::httpmock::Then) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::httpmock::Then {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
+        pub fn ok(self, value: &types::Impossible) -> Self {
+            Self(
+                self.0
+                    .status(200u16)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+    }
+
+    pub struct GetNamesWhen(::httpmock::When);
+    impl GetNamesWhen {
+        pub fn new(inner: ::httpmock::When) -> Self {
+            Self(
+                inner
+                    .method(::httpmock::Method::GET)
+                    .path_matches(regex::Regex::new("^/names$").unwrap()),
+            )
+        }
+
+        pub fn into_inner(self) -> ::httpmock::When {
+            self.0
+        }
+    }
+
+    pub struct GetNamesThen(::httpmock::Then);
+    impl GetNamesThen {
+        pub fn new(inner: ::httpmock::Then) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::httpmock::Then {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
+        pub fn ok(self, value: &types::Names) -> Self {
+            Self(
+                self.0
+                    .status(200u16)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+    }
+
+    pub struct GetRangeWhen(::httpmock::When);
+    impl GetRangeWhen {
+        pub fn new(inner: ::httpmock::When) -> Self {
+            Self(
+                inner
+                    .method(::httpmock::Method::GET)
+                    .path_matches(regex::Regex::new("^/range$").unwrap()),
+            )
+        }
+
+        pub fn into_inner(self) -> ::httpmock::When {
+            self.0
+        }
+    }
+
+    pub struct GetRangeThen(::httpmock::Then);
+    impl GetRangeThen {
+        pub fn new(inner: ::httpmock::Then) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::httpmock::Then {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
+        pub fn ok(self, value: &types::Range) -> Self {
+            Self(
+                self.0
+                    .status(200u16)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+    }
+
+    pub struct GetSubnetWhen(::httpmock::When);
+    impl GetSubnetWhen {
+        pub fn new(inner: ::httpmock::When) -> Self {
+            Self(
+                inner
+                    .method(::httpmock::Method::GET)
+                    .path_matches(regex::Regex::new("^/subnet$").unwrap()),
+            )
+        }
+
+        pub fn into_inner(self) -> ::httpmock::When {
+            self.0
+        }
+    }
+
+    pub struct GetSubnetThen(::httpmock::Then);
+    impl GetSubnetThen {
+        pub fn new(inner: ::httpmock::Then) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::httpmock::Then {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
+        pub fn ok(self, value: &types::Subnet) -> Self {
+            Self(
+                self.0
+                    .status(200u16)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+    }
+
+    pub struct GetVersionWhen(::httpmock::When);
+    impl GetVersionWhen {
+        pub fn new(inner: ::httpmock::When) -> Self {
+            Self(
+                inner
+                    .method(::httpmock::Method::GET)
+                    .path_matches(regex::Regex::new("^/version$").unwrap()),
+            )
+        }
+
+        pub fn into_inner(self) -> ::httpmock::When {
+            self.0
+        }
+    }
+
+    pub struct GetVersionThen(::httpmock::Then);
+    impl GetVersionThen {
+        pub fn new(inner: ::httpmock::Then) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::httpmock::Then {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.delay(duration))
+        }
+
+        pub fn ok(self, value: &types::Version) -> Self {
+            Self(
+                self.0
+                    .status(200u16)
+                    .header("content-type", "application/json")
+                    .json_body_obj(value),
+            )
+        }
+    }
+}
+
+/// An extension trait for [`MockServer`](::httpmock::MockServer) that
+/// adds a method for each operation. These are the equivalent of
+/// type-checked [`mock()`](::httpmock::MockServer::mock) calls.
+pub trait MockServerExt {
+    fn get_choice<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::GetChoiceWhen, operations::GetChoiceThen);
+    fn get_impossible<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::GetImpossibleWhen, operations::GetImpossibleThen);
+    fn get_names<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::GetNamesWhen, operations::GetNamesThen);
+    fn get_range<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::GetRangeWhen, operations::GetRangeThen);
+    fn get_subnet<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::GetSubnetWhen, operations::GetSubnetThen);
+    fn get_version<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::GetVersionWhen, operations::GetVersionThen);
+    /// Mock every operation, answering with the example of its
+    /// first successful response from the API description, or a
+    /// value built from the response's schema if it has none. The
+    /// mocks are returned by operation ID; operations for which no
+    /// value satisfying the schema was found aren't mocked.
+    ///
+    /// httpmock answers with the mock created first, so mock an
+    /// operation before calling this to override it, or `delete()`
+    /// its mock afterwards and mock it again.
+    fn mock_all_from_examples(&self) -> std::collections::BTreeMap<&'static str, ::httpmock::Mock>;
+}
+
+impl MockServerExt for ::httpmock::MockServer {
+    fn get_choice<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::GetChoiceWhen, operations::GetChoiceThen),
+    {
+        self.mock(|when, then| {
+            config_fn(
+                operations::GetChoiceWhen::new(when),
+                operations::GetChoiceThen::new(then),
+            )
+        })
+    }
+
+    fn get_impossible<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::GetImpossibleWhen, operations::GetImpossibleThen),
+    {
+        self.mock(|when, then| {
+            config_fn(
+                operations::GetImpossibleWhen::new(when),
+                operations::GetImpossibleThen::new(then),
+            )
+        })
+    }
+
+    fn get_names<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::GetNamesWhen, operations::GetNamesThen),
+    {
+        self.mock(|when, then| {
+            config_fn(
+                operations::GetNamesWhen::new(when),
+                operations::GetNamesThen::new(then),
+            )
+        })
+    }
+
+    fn get_range<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::GetRangeWhen, operations::GetRangeThen),
+    {
+        self.mock(|when, then| {
+            config_fn(
+                operations::GetRangeWhen::new(when),
+                operations::GetRangeThen::new(then),
+            )
+        })
+    }
+
+    fn get_subnet<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::GetSubnetWhen, operations::GetSubnetThen),
+    {
+        self.mock(|when, then| {
+            config_fn(
+                operations::GetSubnetWhen::new(when),
+                operations::GetSubnetThen::new(then),
+            )
+        })
+    }
+
+    fn get_version<F>(&self, config_fn: F) -> ::httpmock::Mock
+    where
+        F: FnOnce(operations::GetVersionWhen, operations::GetVersionThen),
+    {
+        self.mock(|when, then| {
+            config_fn(
+                operations::GetVersionWhen::new(when),
+                operations::GetVersionThen::new(then),
+            )
+        })
+    }
+
+    fn mock_all_from_examples(&self) -> std::collections::BTreeMap<&'static str, ::httpmock::Mock> {
+        let mut mocks = std::collections::BTreeMap::new();
+        mocks.insert(
+            "get_choice",
+            self.get_choice(|_, then| {
+                then.into_inner()
+                    .status(200u16)
+                    .header("content-type", "application/json")
+                    .body(r#""a0000000""#);
+            }),
+        );
+        mocks.insert(
+            "get_names",
+            self.get_names(|_, then| {
+                then.into_inner()
+                    .status(200u16)
+                    .header("content-type", "application/json")
+                    .body(r#"["a0000000","a0000000"]"#);
+            }),
+        );
+        mocks.insert(
+            "get_range",
+            self.get_range(|_, then| {
+                then.into_inner()
+                    .status(200u16)
+                    .header("content-type", "application/json")
+                    .body(r#"{"high":"0.0.0","low":"0.0.0"}"#);
+            }),
+        );
+        mocks.insert(
+            "get_subnet",
+            self.get_subnet(|_, then| {
+                then.into_inner()
+                    .status(200u16)
+                    .header("content-type", "application/json")
+                    .body(r#"{"gateway":"127.0.0.1","net":"0.0.0.0/8"}"#);
+            }),
+        );
+        mocks.insert(
+            "get_version",
+            self.get_version(|_, then| {
+                then.into_inner()
+                    .status(200u16)
+                    .header("content-type", "application/json")
+                    .body(r#""0.0.0""#);
+            }),
+        );
+        mocks
+    }
+}
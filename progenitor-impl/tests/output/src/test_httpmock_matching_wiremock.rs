@@ -0,0 +1,189 @@
+pub mod operations {
+    //! [`MockBuilder`](::wiremock::MockBuilder) and
+    //! [`ResponseTemplate`](::wiremock::ResponseTemplate)
+    //! wrappers for each operation. Each can be converted to
+    //! its inner type with a call to `into_inner()`. This can
+    //! be used to explicitly deviate from permitted values.
+    use crate::test_httpmock_matching_positional::*;
+    pub struct PutNoteWhen(::wiremock::MockBuilder);
+    impl PutNoteWhen {
+        pub fn new(inner: ::wiremock::MockBuilder) -> Self {
+            Self(
+                inner
+                    .and(::wiremock::matchers::method("PUT"))
+                    .and(::wiremock::matchers::path_regex("^/notes/[^/]*$")),
+            )
+        }
+
+        pub fn into_inner(self) -> ::wiremock::MockBuilder {
+            self.0
+        }
+
+        pub fn respond_with(self, then: PutNoteThen) -> ::wiremock::Mock {
+            self.0.respond_with(then.0)
+        }
+
+        pub fn id(self, value: u32) -> Self {
+            Self(self.0.and(::wiremock::matchers::path_regex(format!(
+                "^/notes/{}$",
+                value.to_string()
+            ))))
+        }
+
+        pub fn pinned<T>(self, value: T) -> Self
+        where
+            T: Into<Option<bool>>,
+        {
+            if let Some(value) = value.into() {
+                Self(self.0.and(::wiremock::matchers::query_param(
+                    "pinned",
+                    value.to_string(),
+                )))
+            } else {
+                Self(
+                    self.0
+                        .and(::wiremock::matchers::query_param_is_missing("pinned")),
+                )
+            }
+        }
+
+        pub fn range(self, value: &types::NoteRange) -> Self {
+            Self(match ::serde_json::to_value(value).unwrap() {
+                ::serde_json::Value::Object(fields) => {
+                    fields
+                        .into_iter()
+                        .fold(self.0, |when, (key, field)| match field {
+                            ::serde_json::Value::Null => when,
+                            ::serde_json::Value::String(field) => {
+                                when.and(::wiremock::matchers::query_param(key, field))
+                            }
+                            field => {
+                                when.and(::wiremock::matchers::query_param(key, field.to_string()))
+                            }
+                        })
+                }
+                _ => unreachable!(),
+            })
+        }
+
+        pub fn tags<'a, T>(self, value: T) -> Self
+        where
+            T: Into<Option<&'a ::std::vec::Vec<::std::string::String>>>,
+        {
+            if let Some(value) = value.into() {
+                Self(value.iter().fold(self.0, |when, item| {
+                    when.and(::wiremock::matchers::query_param("tags", item.to_string()))
+                }))
+            } else {
+                Self(
+                    self.0
+                        .and(::wiremock::matchers::query_param_is_missing("tags")),
+                )
+            }
+        }
+
+        pub fn tags_contains(self, value: &str) -> Self {
+            Self(
+                self.0
+                    .and(::wiremock::matchers::query_param("tags", value.to_string())),
+            )
+        }
+
+        pub fn visibility(self, value: types::PutNoteVisibility) -> Self {
+            Self(self.0.and(::wiremock::matchers::query_param(
+                "visibility",
+                value.to_string(),
+            )))
+        }
+
+        pub fn x_request_source<'a, T>(self, value: T) -> Self
+        where
+            T: Into<Option<&'a str>>,
+        {
+            if let Some(value) = value.into() {
+                Self(self.0.and(::wiremock::matchers::header(
+                    "x-request-source",
+                    value.to_string(),
+                )))
+            } else {
+                Self(
+                    self.0.and(|req: &::wiremock::Request| {
+                        !req.headers.contains_key("x-request-source")
+                    }),
+                )
+            }
+        }
+
+        pub fn x_request_source_exists(self) -> Self {
+            Self(
+                self.0
+                    .and(::wiremock::matchers::header_exists("x-request-source")),
+            )
+        }
+
+        pub fn body(self, value: &types::Note) -> Self {
+            Self(self.0.and(::wiremock::matchers::body_json(value)))
+        }
+
+        pub fn body_partial(self, value: ::serde_json::Value) -> Self {
+            Self(self.0.and(::wiremock::matchers::body_partial_json(value)))
+        }
+
+        pub fn body_matches<F>(self, predicate: F) -> Self
+        where
+            F: Fn(&types::Note) -> bool + Send + Sync + 'static,
+        {
+            Self(self.0.and(move |req: &::wiremock::Request| {
+                req.body_json::<types::Note>()
+                    .is_ok_and(|body| predicate(&body))
+            }))
+        }
+    }
+
+    pub struct PutNoteThen(::wiremock::ResponseTemplate);
+    impl PutNoteThen {
+        pub fn new(inner: ::wiremock::ResponseTemplate) -> Self {
+            Self(inner)
+        }
+
+        pub fn into_inner(self) -> ::wiremock::ResponseTemplate {
+            self.0
+        }
+
+        pub fn delay(self, duration: ::std::time::Duration) -> Self {
+            Self(self.0.set_delay(duration))
+        }
+
+        pub fn ok(value: &types::NoteRecord) -> Self {
+            Self(::wiremock::ResponseTemplate::new(200u16).set_body_json(value))
+        }
+
+        pub fn client_error(status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 4u16);
+            Self(::wiremock::ResponseTemplate::new(status).set_body_json(value))
+        }
+
+        pub fn server_error(status: u16, value: &types::Error) -> Self {
+            assert_eq!(status / 100u16, 5u16);
+            Self(::wiremock::ResponseTemplate::new(status).set_body_json(value))
+        }
+
+        pub fn etag_header(self, value: &str) -> Self {
+            Self(self.0.insert_header("etag", value.to_string()))
+        }
+    }
+}
+
+/// An extension trait for [`Mock`](::wiremock::Mock) that adds a
+/// constructor for each operation. These are the equivalent of
+/// type-checked [`given()`](::wiremock::Mock::given) calls; finish
+/// each with `respond_with()` and mount the resulting mock.
+pub trait MockExt {
+    fn put_note() -> operations::PutNoteWhen;
+}
+
+impl MockExt for ::wiremock::Mock {
+    fn put_note() -> operations::PutNoteWhen {
+        operations::PutNoteWhen::new(::wiremock::Mock::given(::wiremock::matchers::any()))
+    }
+}
@@ -0,0 +1,1272 @@
+#[allow(unused_imports)]
+use progenitor_client::{encode_path, ClientHooks, OperationInfo, RequestBuilderExt};
+#[allow(unused_imports)]
+pub use progenitor_client::{ByteStream, ClientInfo, Error, RequestOptions, ResponseValue};
+/// Types used as operation parameters and responses.
+#[allow(clippy::all)]
+pub mod types {
+    /// Error types.
+    pub mod error {
+        /// Error from a `TryFrom` or `FromStr` implementation.
+        pub struct ConversionError(::std::borrow::Cow<'static, str>);
+        impl ::std::error::Error for ConversionError {}
+        impl ::std::fmt::Display for ConversionError {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+                ::std::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl ::std::fmt::Debug for ConversionError {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+                ::std::fmt::Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl From<&'static str> for ConversionError {
+            fn from(value: &'static str) -> Self {
+                Self(value.into())
+            }
+        }
+
+        impl From<String> for ConversionError {
+            fn from(value: String) -> Self {
+                Self(value.into())
+            }
+        }
+    }
+
+    ///Error information from a response.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "Error information from a response.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "message",
+    ///    "request_id"
+    ///  ],
+    ///  "properties": {
+    ///    "error_code": {
+    ///      "type": "string"
+    ///    },
+    ///    "message": {
+    ///      "type": "string"
+    ///    },
+    ///    "request_id": {
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    pub struct Error {
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub error_code: ::std::option::Option<::std::string::String>,
+        pub message: ::std::string::String,
+        pub request_id: ::std::string::String,
+    }
+
+    impl ::std::convert::From<&Error> for Error {
+        fn from(value: &Error) -> Self {
+            value.clone()
+        }
+    }
+
+    impl Error {
+        pub fn builder() -> builder::Error {
+            Default::default()
+        }
+    }
+
+    ///`Note`
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "type": "object",
+    ///  "required": [
+    ///    "text"
+    ///  ],
+    ///  "properties": {
+    ///    "text": {
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    pub struct Note {
+        pub text: ::std::string::String,
+    }
+
+    impl ::std::convert::From<&Note> for Note {
+        fn from(value: &Note) -> Self {
+            value.clone()
+        }
+    }
+
+    impl Note {
+        pub fn builder() -> builder::Note {
+            Default::default()
+        }
+    }
+
+    ///`NoteRecord`
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "type": "object",
+    ///  "required": [
+    ///    "id",
+    ///    "pinned",
+    ///    "text",
+    ///    "visibility"
+    ///  ],
+    ///  "properties": {
+    ///    "id": {
+    ///      "type": "integer",
+    ///      "format": "uint32",
+    ///      "minimum": 0.0
+    ///    },
+    ///    "pinned": {
+    ///      "type": "boolean"
+    ///    },
+    ///    "text": {
+    ///      "type": "string"
+    ///    },
+    ///    "visibility": {
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    pub struct NoteRecord {
+        pub id: u32,
+        pub pinned: bool,
+        pub text: ::std::string::String,
+        pub visibility: ::std::string::String,
+    }
+
+    impl ::std::convert::From<&NoteRecord> for NoteRecord {
+        fn from(value: &NoteRecord) -> Self {
+            value.clone()
+        }
+    }
+
+    impl NoteRecord {
+        pub fn builder() -> builder::NoteRecord {
+            Default::default()
+        }
+    }
+
+    ///A single page of results
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "A single page of results",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "items"
+    ///  ],
+    ///  "properties": {
+    ///    "items": {
+    ///      "description": "list of items on this page of results",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "type": "integer",
+    ///        "format": "uint32",
+    ///        "minimum": 0.0
+    ///      }
+    ///    },
+    ///    "next_page": {
+    ///      "description": "token used to fetch the next page of results (if
+    /// any)",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    pub struct Uint32ResultsPage {
+        ///list of items on this page of results
+        pub items: ::std::vec::Vec<u32>,
+        ///token used to fetch the next page of results (if any)
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub next_page: ::std::option::Option<::std::string::String>,
+    }
+
+    impl ::std::convert::From<&Uint32ResultsPage> for Uint32ResultsPage {
+        fn from(value: &Uint32ResultsPage) -> Self {
+            value.clone()
+        }
+    }
+
+    impl Uint32ResultsPage {
+        pub fn builder() -> builder::Uint32ResultsPage {
+            Default::default()
+        }
+    }
+
+    /// Types for composing complex structures.
+    pub mod builder {
+        #[derive(Clone, Debug)]
+        pub struct Error {
+            error_code: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+            message: ::std::result::Result<::std::string::String, ::std::string::String>,
+            request_id: ::std::result::Result<::std::string::String, ::std::string::String>,
+        }
+
+        impl ::std::default::Default for Error {
+            fn default() -> Self {
+                Self {
+                    error_code: Ok(Default::default()),
+                    message: Err("no value supplied for message".to_string()),
+                    request_id: Err("no value supplied for request_id".to_string()),
+                }
+            }
+        }
+
+        impl Error {
+            pub fn error_code<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.error_code = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for error_code: {}", e));
+                self
+            }
+            pub fn message<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.message = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for message: {}", e));
+                self
+            }
+            pub fn request_id<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.request_id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for request_id: {}", e));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<Error> for super::Error {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: Error,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    error_code: value.error_code?,
+                    message: value.message?,
+                    request_id: value.request_id?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::Error> for Error {
+            fn from(value: super::Error) -> Self {
+                Self {
+                    error_code: Ok(value.error_code),
+                    message: Ok(value.message),
+                    request_id: Ok(value.request_id),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct Note {
+            text: ::std::result::Result<::std::string::String, ::std::string::String>,
+        }
+
+        impl ::std::default::Default for Note {
+            fn default() -> Self {
+                Self {
+                    text: Err("no value supplied for text".to_string()),
+                }
+            }
+        }
+
+        impl Note {
+            pub fn text<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.text = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for text: {}", e));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<Note> for super::Note {
+            type Error = super::error::ConversionError;
+            fn try_from(value: Note) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self { text: value.text? })
+            }
+        }
+
+        impl ::std::convert::From<super::Note> for Note {
+            fn from(value: super::Note) -> Self {
+                Self {
+                    text: Ok(value.text),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct NoteRecord {
+            id: ::std::result::Result<u32, ::std::string::String>,
+            pinned: ::std::result::Result<bool, ::std::string::String>,
+            text: ::std::result::Result<::std::string::String, ::std::string::String>,
+            visibility: ::std::result::Result<::std::string::String, ::std::string::String>,
+        }
+
+        impl ::std::default::Default for NoteRecord {
+            fn default() -> Self {
+                Self {
+                    id: Err("no value supplied for id".to_string()),
+                    pinned: Err("no value supplied for pinned".to_string()),
+                    text: Err("no value supplied for text".to_string()),
+                    visibility: Err("no value supplied for visibility".to_string()),
+                }
+            }
+        }
+
+        impl NoteRecord {
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<u32>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn pinned<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<bool>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.pinned = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for pinned: {}", e));
+                self
+            }
+            pub fn text<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.text = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for text: {}", e));
+                self
+            }
+            pub fn visibility<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.visibility = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for visibility: {}", e));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<NoteRecord> for super::NoteRecord {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: NoteRecord,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    id: value.id?,
+                    pinned: value.pinned?,
+                    text: value.text?,
+                    visibility: value.visibility?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::NoteRecord> for NoteRecord {
+            fn from(value: super::NoteRecord) -> Self {
+                Self {
+                    id: Ok(value.id),
+                    pinned: Ok(value.pinned),
+                    text: Ok(value.text),
+                    visibility: Ok(value.visibility),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct Uint32ResultsPage {
+            items: ::std::result::Result<::std::vec::Vec<u32>, ::std::string::String>,
+            next_page: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+        }
+
+        impl ::std::default::Default for Uint32ResultsPage {
+            fn default() -> Self {
+                Self {
+                    items: Err("no value supplied for items".to_string()),
+                    next_page: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl Uint32ResultsPage {
+            pub fn items<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::vec::Vec<u32>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.items = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for items: {}", e));
+                self
+            }
+            pub fn next_page<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.next_page = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for next_page: {}", e));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<Uint32ResultsPage> for super::Uint32ResultsPage {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: Uint32ResultsPage,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    items: value.items?,
+                    next_page: value.next_page?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::Uint32ResultsPage> for Uint32ResultsPage {
+            fn from(value: super::Uint32ResultsPage) -> Self {
+                Self {
+                    items: Ok(value.items),
+                    next_page: Ok(value.next_page),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+///Client for test_operation_enum
+///
+///Version: 1.0.0
+pub struct Client {
+    pub(crate) baseurl: String,
+    pub(crate) client: reqwest::Client,
+    pub(crate) middleware: progenitor_client::MiddlewareStack,
+}
+
+impl Client {
+    /// Create a new client.
+    ///
+    /// `baseurl` is the base URL provided to the internal
+    /// `reqwest::Client`, and should include a scheme and hostname,
+    /// as well as port and a path stem if applicable.
+    pub fn new(baseurl: &str) -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        let client = {
+            let dur = std::time::Duration::from_secs(15);
+            reqwest::ClientBuilder::new()
+                .connect_timeout(dur)
+                .timeout(dur)
+        };
+        #[cfg(target_arch = "wasm32")]
+        let client = reqwest::ClientBuilder::new();
+        Self::new_with_client(baseurl, client.build().unwrap())
+    }
+
+    /// Construct a new client with an existing `reqwest::Client`,
+    /// allowing more control over its configuration.
+    ///
+    /// `baseurl` is the base URL provided to the internal
+    /// `reqwest::Client`, and should include a scheme and hostname,
+    /// as well as port and a path stem if applicable.
+    pub fn new_with_client(baseurl: &str, client: reqwest::Client) -> Self {
+        Self {
+            baseurl: baseurl.to_string(),
+            client,
+            middleware: Default::default(),
+        }
+    }
+
+    /// Append `middleware` to the chain that surrounds the
+    /// execution of each request. Middleware runs in the order in
+    /// which it is attached.
+    pub fn with_middleware<M: progenitor_client::Middleware>(mut self, middleware: M) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Descriptions of the API's operations.
+    pub const OPERATIONS: &'static [progenitor_client::OperationMetadata] = &[
+        progenitor_client::OperationMetadata {
+            operation_id: "list_folder_notes",
+            method: reqwest::Method::GET,
+            path: "/folders/{folder}/notes",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+        progenitor_client::OperationMetadata {
+            operation_id: "put_note",
+            method: reqwest::Method::PUT,
+            path: "/notes/{id}",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        },
+    ];
+}
+
+impl ClientInfo<()> for Client {
+    fn api_version() -> &'static str {
+        "1.0.0"
+    }
+
+    fn baseurl(&self) -> &str {
+        self.baseurl.as_str()
+    }
+
+    fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    fn inner(&self) -> &() {
+        &()
+    }
+}
+
+impl ClientHooks<()> for &Client {}
+impl Client {
+    ///Sends a `GET` request to `/folders/{folder}/notes`
+    ///
+    ///Arguments:
+    /// - `folder`
+    /// - `limit`: Maximum number of items returned by a single call
+    /// - `page_token`: Token returned by previous call to retrieve the
+    ///   subsequent page
+    ///```ignore
+    /// let response = client.list_folder_notes()
+    ///    .folder(folder)
+    ///    .limit(limit)
+    ///    .page_token(page_token)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn list_folder_notes(&self) -> builder::ListFolderNotes {
+        builder::ListFolderNotes::new(self)
+    }
+
+    ///Sends a `PUT` request to `/notes/{id}`
+    ///
+    ///```ignore
+    /// let response = client.put_note()
+    ///    .id(id)
+    ///    .pinned(pinned)
+    ///    .visibility(visibility)
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn put_note(&self) -> builder::PutNote {
+        builder::PutNote::new(self)
+    }
+}
+
+/// Types for composing operation parameters.
+#[allow(clippy::all)]
+pub mod builder {
+    use super::types;
+    #[allow(unused_imports)]
+    use super::{
+        encode_path, ByteStream, ClientHooks, ClientInfo, Error, OperationInfo, RequestBuilderExt,
+        ResponseValue,
+    };
+    ///Builder for [`Client::list_folder_notes`]
+    ///
+    ///[`Client::list_folder_notes`]: super::Client::list_folder_notes
+    #[derive(Debug, Clone)]
+    pub struct ListFolderNotes<'a, FolderState = progenitor_client::Missing> {
+        client: &'a super::Client,
+        folder: Result<::std::string::String, String>,
+        limit: Result<Option<::std::num::NonZeroU32>, String>,
+        page_token: Result<Option<::std::string::String>, String>,
+        options: progenitor_client::RequestOptions,
+        state: ::std::marker::PhantomData<(FolderState,)>,
+    }
+
+    impl<'a> ListFolderNotes<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                folder: Err("folder was not initialized".to_string()),
+                limit: Ok(None),
+                page_token: Ok(None),
+                options: ::std::default::Default::default(),
+                state: ::std::marker::PhantomData,
+            }
+        }
+    }
+
+    impl<'a, FolderState> ListFolderNotes<'a, FolderState> {
+        pub fn folder<V>(mut self, value: V) -> ListFolderNotes<'a, progenitor_client::Provided>
+        where
+            V: std::convert::TryInto<::std::string::String>,
+        {
+            self.folder = value.try_into().map_err(|_| {
+                "conversion to `:: std :: string :: String` for folder failed".to_string()
+            });
+            ListFolderNotes {
+                client: self.client,
+                folder: self.folder,
+                limit: self.limit,
+                page_token: self.page_token,
+                options: self.options,
+                state: ::std::marker::PhantomData,
+            }
+        }
+
+        pub fn limit<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::std::num::NonZeroU32>,
+        {
+            self.limit = value.try_into().map(Some).map_err(|_| {
+                "conversion to `:: std :: num :: NonZeroU32` for limit failed".to_string()
+            });
+            self
+        }
+
+        pub fn page_token<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::std::string::String>,
+        {
+            self.page_token = value.try_into().map(Some).map_err(|_| {
+                "conversion to `:: std :: string :: String` for page_token failed".to_string()
+            });
+            self
+        }
+
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+    }
+
+    impl<'a> ListFolderNotes<'a, progenitor_client::Provided> {
+        ///Sends a `GET` request to `/folders/{folder}/notes`
+        pub async fn send(
+            mut self,
+        ) -> Result<ResponseValue<types::Uint32ResultsPage>, Error<types::Error>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[0])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                folder,
+                limit,
+                page_token,
+                options,
+                state: _,
+            } = self;
+            let folder = folder.map_err(Error::InvalidRequest)?;
+            let limit = limit.map_err(Error::InvalidRequest)?;
+            let page_token = page_token.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/folders/{}/notes",
+                client.baseurl,
+                encode_path(&folder.to_string()),
+            );
+            let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+            header_map.append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    ::reqwest::header::ACCEPT,
+                    ::reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .query(&progenitor_client::QueryParam::new("limit", &limit))
+                .query(&progenitor_client::QueryParam::new(
+                    "page_token",
+                    &page_token,
+                ))
+                .headers(header_map)
+                .build()?;
+            options.apply(&mut request)?;
+            Ok(request)
+        }
+
+        ///Streams `GET` requests to `/folders/{folder}/notes`
+        pub fn stream(
+            self,
+        ) -> impl futures::Stream<Item = Result<u32, Error<types::Error>>> + Unpin + 'a {
+            use ::futures::StreamExt;
+            use ::futures::TryFutureExt;
+            use ::futures::TryStreamExt;
+            let next = Self {
+                page_token: Ok(None),
+                ..self.clone()
+            };
+            self.send()
+                .map_ok(move |page| {
+                    let page = page.into_inner();
+                    let first = futures::stream::iter(page.items).map(Ok);
+                    let rest = futures::stream::try_unfold(
+                        (page.next_page, next),
+                        |(next_page, next)| async {
+                            if next_page.is_none() {
+                                Ok(None)
+                            } else {
+                                Self {
+                                    page_token: Ok(next_page),
+                                    ..next.clone()
+                                }
+                                .send()
+                                .map_ok(|page| {
+                                    let page = page.into_inner();
+                                    Some((
+                                        futures::stream::iter(page.items).map(Ok),
+                                        (page.next_page, next),
+                                    ))
+                                })
+                                .await
+                            }
+                        },
+                    )
+                    .try_flatten();
+                    first.chain(rest)
+                })
+                .try_flatten_stream()
+                .boxed()
+        }
+    }
+
+    ///Builder for [`Client::put_note`]
+    ///
+    ///[`Client::put_note`]: super::Client::put_note
+    #[derive(Debug, Clone)]
+    pub struct PutNote<
+        'a,
+        IdState = progenitor_client::Missing,
+        VisibilityState = progenitor_client::Missing,
+        BodyState = progenitor_client::Missing,
+    > {
+        client: &'a super::Client,
+        id: Result<u32, String>,
+        pinned: Result<Option<bool>, String>,
+        visibility: Result<::std::string::String, String>,
+        body: Result<types::builder::Note, String>,
+        options: progenitor_client::RequestOptions,
+        state: ::std::marker::PhantomData<(IdState, VisibilityState, BodyState)>,
+    }
+
+    impl<'a> PutNote<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                id: Err("id was not initialized".to_string()),
+                pinned: Ok(None),
+                visibility: Err("visibility was not initialized".to_string()),
+                body: Ok(::std::default::Default::default()),
+                options: ::std::default::Default::default(),
+                state: ::std::marker::PhantomData,
+            }
+        }
+    }
+
+    impl<'a, IdState, VisibilityState, BodyState> PutNote<'a, IdState, VisibilityState, BodyState> {
+        pub fn id<V>(
+            mut self,
+            value: V,
+        ) -> PutNote<'a, progenitor_client::Provided, VisibilityState, BodyState>
+        where
+            V: std::convert::TryInto<u32>,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|_| "conversion to `u32` for id failed".to_string());
+            PutNote {
+                client: self.client,
+                id: self.id,
+                pinned: self.pinned,
+                visibility: self.visibility,
+                body: self.body,
+                options: self.options,
+                state: ::std::marker::PhantomData,
+            }
+        }
+
+        pub fn pinned<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<bool>,
+        {
+            self.pinned = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `bool` for pinned failed".to_string());
+            self
+        }
+
+        pub fn visibility<V>(
+            mut self,
+            value: V,
+        ) -> PutNote<'a, IdState, progenitor_client::Provided, BodyState>
+        where
+            V: std::convert::TryInto<::std::string::String>,
+        {
+            self.visibility = value.try_into().map_err(|_| {
+                "conversion to `:: std :: string :: String` for visibility failed".to_string()
+            });
+            PutNote {
+                client: self.client,
+                id: self.id,
+                pinned: self.pinned,
+                visibility: self.visibility,
+                body: self.body,
+                options: self.options,
+                state: ::std::marker::PhantomData,
+            }
+        }
+
+        pub fn body<V>(
+            mut self,
+            value: V,
+        ) -> PutNote<'a, IdState, VisibilityState, progenitor_client::Provided>
+        where
+            V: std::convert::TryInto<types::Note>,
+            <V as std::convert::TryInto<types::Note>>::Error: std::fmt::Display,
+        {
+            self.body = value
+                .try_into()
+                .map(From::from)
+                .map_err(|s| format!("conversion to `Note` for body failed: {}", s));
+            PutNote {
+                client: self.client,
+                id: self.id,
+                pinned: self.pinned,
+                visibility: self.visibility,
+                body: self.body,
+                options: self.options,
+                state: ::std::marker::PhantomData,
+            }
+        }
+
+        pub fn body_map<F>(
+            mut self,
+            f: F,
+        ) -> PutNote<'a, IdState, VisibilityState, progenitor_client::Provided>
+        where
+            F: std::ops::FnOnce(types::builder::Note) -> types::builder::Note,
+        {
+            self.body = self.body.map(f);
+            PutNote {
+                client: self.client,
+                id: self.id,
+                pinned: self.pinned,
+                visibility: self.visibility,
+                body: self.body,
+                options: self.options,
+                state: ::std::marker::PhantomData,
+            }
+        }
+
+        /// Add a header to the request, replacing any header of the same
+        /// name.
+        pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+        where
+            reqwest::header::HeaderName: std::convert::TryFrom<K>,
+            <reqwest::header::HeaderName as std::convert::TryFrom<K>>::Error: std::fmt::Display,
+            reqwest::header::HeaderValue: std::convert::TryFrom<V>,
+            <reqwest::header::HeaderValue as std::convert::TryFrom<V>>::Error: std::fmt::Display,
+        {
+            self.options = self.options.header(name, value);
+            self
+        }
+
+        /// Add a query parameter to the request.
+        pub fn with_query<K, V>(mut self, name: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: ToString,
+        {
+            self.options = self.options.query(name, value);
+            self
+        }
+
+        /// Set a value available to hooks through `OperationInfo::context`.
+        pub fn with_context<T>(mut self, value: T) -> Self
+        where
+            T: std::any::Any + Send + Sync,
+        {
+            self.options = self.options.context(value);
+            self
+        }
+
+        /// Replace the additional headers, query parameters, and context
+        /// for the request.
+        pub fn with_options(mut self, options: progenitor_client::RequestOptions) -> Self {
+            self.options = options;
+            self
+        }
+
+        /// Add an `If-Match` header so that the server applies the
+        /// update only if the resource still has the entity tag `etag`,
+        /// such as one from `ResponseValue::etag`.
+        pub fn with_if_match<V: AsRef<str>>(mut self, etag: V) -> Self {
+            self.options = self.options.if_match(etag);
+            self
+        }
+    }
+
+    impl<'a>
+        PutNote<
+            'a,
+            progenitor_client::Provided,
+            progenitor_client::Provided,
+            progenitor_client::Provided,
+        >
+    {
+        ///Sends a `PUT` request to `/notes/{id}`
+        pub async fn send(
+            mut self,
+        ) -> Result<ResponseValue<types::NoteRecord>, Error<types::Error>> {
+            let client = self.client;
+            let options = std::mem::take(&mut self.options);
+            let mut request = self.into_request().map_err(Error::into_typed)?;
+            let info = OperationInfo::new(&super::Client::OPERATIONS[1])
+                .with_context(options.apply(&mut request)?);
+            client.pre(&mut request, &info).await?;
+            let response = {
+                let info = &info;
+                client
+                    .middleware
+                    .run(request, info, &|request| {
+                        Box::pin(async move {
+                            let result = client.exec(request, info).await;
+                            client.post(&result, info).await?;
+                            Ok(result?)
+                        })
+                    })
+                    .await
+                    .map_err(Error::into_typed)?
+            };
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+
+        /// Prepares the request as [`Self::send`] would, but returns it
+        /// rather than sending it. Hooks and middleware don't run.
+        pub fn into_request(self) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+            let Self {
+                client,
+                id,
+                pinned,
+                visibility,
+                body,
+                options,
+                state: _,
+            } = self;
+            let id = id.map_err(Error::InvalidRequest)?;
+            let pinned = pinned.map_err(Error::InvalidRequest)?;
+            let visibility = visibility.map_err(Error::InvalidRequest)?;
+            let body = body
+                .and_then(|v| types::Note::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
+            let url = format!("{}/notes/{}", client.baseurl, encode_path(&id.to_string()),);
+            let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+            header_map.append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .put(url)
+                .header(
+                    ::reqwest::header::ACCEPT,
+                    ::reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .json(&body)
+                .query(&progenitor_client::QueryParam::new("pinned", &pinned))
+                .query(&progenitor_client::QueryParam::new(
+                    "visibility",
+                    &visibility,
+                ))
+                .headers(header_map)
+                .build()?;
+            options.apply(&mut request)?;
+            Ok(request)
+        }
+    }
+}
+
+/// Items consumers will typically use such as the Client.
+pub mod prelude {
+    pub use self::super::Client;
+}
+
+/// Parameters of each operation, for use in [`Operation`].
+pub mod operations {
+    #[allow(unused_imports)]
+    use super::types;
+    ///Parameters of the `list_folder_notes` operation.
+    #[derive(Clone, Debug, serde :: Serialize, serde :: Deserialize)]
+    pub struct ListFolderNotes {
+        pub folder: ::std::string::String,
+        pub limit: Option<::std::num::NonZeroU32>,
+        pub page_token: Option<::std::string::String>,
+    }
+
+    impl From<ListFolderNotes> for super::Operation {
+        fn from(value: ListFolderNotes) -> Self {
+            Self::ListFolderNotes(value)
+        }
+    }
+
+    ///Parameters of the `put_note` operation.
+    #[derive(Clone, Debug, serde :: Serialize, serde :: Deserialize)]
+    pub struct PutNote {
+        pub id: u32,
+        pub pinned: Option<bool>,
+        pub visibility: ::std::string::String,
+        pub body: types::Note,
+    }
+
+    impl From<PutNote> for super::Operation {
+        fn from(value: PutNote) -> Self {
+            Self::PutNote(value)
+        }
+    }
+}
+
+/// An operation with its parameters, which may be serialized (for
+/// example, to queue it) and later executed with
+/// [`Client::execute_operation`].
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, serde :: Serialize, serde :: Deserialize)]
+#[serde(tag = "operation_id", content = "parameters")]
+pub enum Operation {
+    #[serde(rename = "list_folder_notes")]
+    ListFolderNotes(operations::ListFolderNotes),
+    #[serde(rename = "put_note")]
+    PutNote(operations::PutNote),
+}
+
+impl Operation {
+    /// The `operationId` of the operation.
+    pub fn operation_id(&self) -> &'static str {
+        match *self {
+            Self::ListFolderNotes(_) => "list_folder_notes",
+            Self::PutNote(_) => "put_note",
+        }
+    }
+}
+
+/// The successful response of an [`Operation`].
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+pub enum OperationResponse {
+    ListFolderNotes(types::Uint32ResultsPage),
+    PutNote(types::NoteRecord),
+}
+
+/// The error response body of an [`Operation`].
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+pub enum OperationError {
+    ListFolderNotes(types::Error),
+    PutNote(types::Error),
+}
+
+#[allow(clippy::all)]
+impl Client {
+    /// Execute `operation` as the corresponding method would,
+    /// with the response or error body in the variant of that
+    /// operation.
+    pub async fn execute_operation(
+        &self,
+        operation: Operation,
+    ) -> Result<ResponseValue<OperationResponse>, Error<OperationError>> {
+        match operation {
+            Operation::ListFolderNotes(operations::ListFolderNotes {
+                folder,
+                limit,
+                page_token,
+            }) => {
+                #[allow(unused_mut)]
+                let mut request = builder::ListFolderNotes::new(self).folder(folder);
+                if let Some(limit) = limit {
+                    request = request.limit(limit);
+                }
+                if let Some(page_token) = page_token {
+                    request = request.page_token(page_token);
+                }
+                request.send()
+            }
+            .await
+            .map(|response| {
+                let status = response.status();
+                let headers = response.headers().clone();
+                ResponseValue::new(
+                    OperationResponse::ListFolderNotes(response.into_inner()),
+                    status,
+                    headers,
+                )
+            })
+            .map_err(|e| e.map(OperationError::ListFolderNotes)),
+            Operation::PutNote(operations::PutNote {
+                id,
+                pinned,
+                visibility,
+                body,
+            }) => {
+                #[allow(unused_mut)]
+                let mut request = builder::PutNote::new(self)
+                    .id(id)
+                    .visibility(visibility)
+                    .body(body);
+                if let Some(pinned) = pinned {
+                    request = request.pinned(pinned);
+                }
+                request.send()
+            }
+            .await
+            .map(|response| {
+                let status = response.status();
+                let headers = response.headers().clone();
+                ResponseValue::new(
+                    OperationResponse::PutNote(response.into_inner()),
+                    status,
+                    headers,
+                )
+            })
+            .map_err(|e| e.map(OperationError::PutNote)),
+        }
+    }
+}
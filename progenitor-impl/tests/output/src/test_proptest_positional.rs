@@ -0,0 +1,1059 @@
+#[allow(unused_imports)]
+use progenitor_client::{encode_path, ClientHooks, OperationInfo, RequestBuilderExt};
+#[allow(unused_imports)]
+pub use progenitor_client::{ByteStream, ClientInfo, Error, RequestOptions, ResponseValue};
+/// Types used as operation parameters and responses.
+#[allow(clippy::all)]
+pub mod types {
+    /// Error types.
+    pub mod error {
+        /// Error from a `TryFrom` or `FromStr` implementation.
+        pub struct ConversionError(::std::borrow::Cow<'static, str>);
+        impl ::std::error::Error for ConversionError {}
+        impl ::std::fmt::Display for ConversionError {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+                ::std::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl ::std::fmt::Debug for ConversionError {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+                ::std::fmt::Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl From<&'static str> for ConversionError {
+            fn from(value: &'static str) -> Self {
+                Self(value.into())
+            }
+        }
+
+        impl From<String> for ConversionError {
+            fn from(value: String) -> Self {
+                Self(value.into())
+            }
+        }
+    }
+
+    ///Error information from a response.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "Error information from a response.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "message",
+    ///    "request_id"
+    ///  ],
+    ///  "properties": {
+    ///    "error_code": {
+    ///      "type": "string"
+    ///    },
+    ///    "message": {
+    ///      "type": "string"
+    ///    },
+    ///    "request_id": {
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    pub struct Error {
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub error_code: ::std::option::Option<::std::string::String>,
+        pub message: ::std::string::String,
+        pub request_id: ::std::string::String,
+    }
+
+    impl ::std::convert::From<&Error> for Error {
+        fn from(value: &Error) -> Self {
+            value.clone()
+        }
+    }
+
+    ///A machine, with constraints on the values of its properties.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "A machine, with constraints on the values of its
+    /// properties.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "count",
+    ///    "kind",
+    ///    "labels",
+    ///    "name",
+    ///    "parts",
+    ///    "profile",
+    ///    "ratio",
+    ///    "size"
+    ///  ],
+    ///  "properties": {
+    ///    "count": {
+    ///      "type": "integer",
+    ///      "format": "uint32",
+    ///      "maximum": 10.0,
+    ///      "minimum": 1.0
+    ///    },
+    ///    "kind": {
+    ///      "$ref": "#/components/schemas/MachineKind"
+    ///    },
+    ///    "labels": {
+    ///      "type": "object",
+    ///      "additionalProperties": {
+    ///        "$ref": "#/components/schemas/MachineLabel"
+    ///      }
+    ///    },
+    ///    "name": {
+    ///      "type": "string",
+    ///      "maxLength": 16,
+    ///      "minLength": 1,
+    ///      "pattern": "^[a-z][a-z0-9-]*$"
+    ///    },
+    ///    "parts": {
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/MachinePart"
+    ///      }
+    ///    },
+    ///    "profile": {
+    ///      "description": "The lookahead isn't supported by proptest's
+    /// generation.",
+    ///      "type": "string",
+    ///      "pattern": "^(?!none$)[a-z]+$"
+    ///    },
+    ///    "ratio": {
+    ///      "type": "number",
+    ///      "format": "double",
+    ///      "maximum": 1.0,
+    ///      "minimum": 0.0
+    ///    },
+    ///    "size": {
+    ///      "$ref": "#/components/schemas/MachineSize"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    pub struct Machine {
+        pub count: ::std::num::NonZeroU32,
+        pub kind: MachineKind,
+        pub labels: ::std::collections::HashMap<::std::string::String, MachineLabel>,
+        pub name: MachineName,
+        pub parts: ::std::vec::Vec<MachinePart>,
+        ///The lookahead isn't supported by proptest's generation.
+        pub profile: MachineProfile,
+        pub ratio: f64,
+        pub size: MachineSize,
+    }
+
+    impl ::std::convert::From<&Machine> for Machine {
+        fn from(value: &Machine) -> Self {
+            value.clone()
+        }
+    }
+
+    ///`MachineKind`
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "type": "string",
+    ///  "enum": [
+    ///    "gear",
+    ///    "spring"
+    ///  ]
+    ///}
+    /// ```
+    /// </details>
+    #[derive(
+        :: serde :: Deserialize,
+        :: serde :: Serialize,
+        Clone,
+        Copy,
+        Debug,
+        Eq,
+        Hash,
+        Ord,
+        PartialEq,
+        PartialOrd,
+    )]
+    pub enum MachineKind {
+        #[serde(rename = "gear")]
+        Gear,
+        #[serde(rename = "spring")]
+        Spring,
+    }
+
+    impl ::std::convert::From<&Self> for MachineKind {
+        fn from(value: &MachineKind) -> Self {
+            value.clone()
+        }
+    }
+
+    impl ::std::fmt::Display for MachineKind {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            match *self {
+                Self::Gear => write!(f, "gear"),
+                Self::Spring => write!(f, "spring"),
+            }
+        }
+    }
+
+    impl ::std::str::FromStr for MachineKind {
+        type Err = self::error::ConversionError;
+        fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+            match value {
+                "gear" => Ok(Self::Gear),
+                "spring" => Ok(Self::Spring),
+                _ => Err("invalid value".into()),
+            }
+        }
+    }
+
+    impl ::std::convert::TryFrom<&str> for MachineKind {
+        type Error = self::error::ConversionError;
+        fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::convert::TryFrom<&::std::string::String> for MachineKind {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: &::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::convert::TryFrom<::std::string::String> for MachineKind {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: ::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    ///`MachineLabel`
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "type": "string",
+    ///  "maxLength": 8
+    ///}
+    /// ```
+    /// </details>
+    #[derive(:: serde :: Serialize, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    #[serde(transparent)]
+    pub struct MachineLabel(::std::string::String);
+    impl ::std::ops::Deref for MachineLabel {
+        type Target = ::std::string::String;
+        fn deref(&self) -> &::std::string::String {
+            &self.0
+        }
+    }
+
+    impl ::std::convert::From<MachineLabel> for ::std::string::String {
+        fn from(value: MachineLabel) -> Self {
+            value.0
+        }
+    }
+
+    impl ::std::convert::From<&MachineLabel> for MachineLabel {
+        fn from(value: &MachineLabel) -> Self {
+            value.clone()
+        }
+    }
+
+    impl ::std::str::FromStr for MachineLabel {
+        type Err = self::error::ConversionError;
+        fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+            if value.chars().count() > 8usize {
+                return Err("longer than 8 characters".into());
+            }
+            Ok(Self(value.to_string()))
+        }
+    }
+
+    impl ::std::convert::TryFrom<&str> for MachineLabel {
+        type Error = self::error::ConversionError;
+        fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::convert::TryFrom<&::std::string::String> for MachineLabel {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: &::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::convert::TryFrom<::std::string::String> for MachineLabel {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: ::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl<'de> ::serde::Deserialize<'de> for MachineLabel {
+        fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+        where
+            D: ::serde::Deserializer<'de>,
+        {
+            ::std::string::String::deserialize(deserializer)?
+                .parse()
+                .map_err(|e: self::error::ConversionError| {
+                    <D::Error as ::serde::de::Error>::custom(e.to_string())
+                })
+        }
+    }
+
+    ///`MachineName`
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "type": "string",
+    ///  "maxLength": 16,
+    ///  "minLength": 1,
+    ///  "pattern": "^[a-z][a-z0-9-]*$"
+    ///}
+    /// ```
+    /// </details>
+    #[derive(:: serde :: Serialize, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    #[serde(transparent)]
+    pub struct MachineName(::std::string::String);
+    impl ::std::ops::Deref for MachineName {
+        type Target = ::std::string::String;
+        fn deref(&self) -> &::std::string::String {
+            &self.0
+        }
+    }
+
+    impl ::std::convert::From<MachineName> for ::std::string::String {
+        fn from(value: MachineName) -> Self {
+            value.0
+        }
+    }
+
+    impl ::std::convert::From<&MachineName> for MachineName {
+        fn from(value: &MachineName) -> Self {
+            value.clone()
+        }
+    }
+
+    impl ::std::str::FromStr for MachineName {
+        type Err = self::error::ConversionError;
+        fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+            if value.chars().count() > 16usize {
+                return Err("longer than 16 characters".into());
+            }
+            if value.chars().count() < 1usize {
+                return Err("shorter than 1 characters".into());
+            }
+            static PATTERN: ::std::sync::LazyLock<::regress::Regex> =
+                ::std::sync::LazyLock::new(|| ::regress::Regex::new("^[a-z][a-z0-9-]*$").unwrap());
+            if (&*PATTERN).find(value).is_none() {
+                return Err("doesn't match pattern \"^[a-z][a-z0-9-]*$\"".into());
+            }
+            Ok(Self(value.to_string()))
+        }
+    }
+
+    impl ::std::convert::TryFrom<&str> for MachineName {
+        type Error = self::error::ConversionError;
+        fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::convert::TryFrom<&::std::string::String> for MachineName {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: &::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::convert::TryFrom<::std::string::String> for MachineName {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: ::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl<'de> ::serde::Deserialize<'de> for MachineName {
+        fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+        where
+            D: ::serde::Deserializer<'de>,
+        {
+            ::std::string::String::deserialize(deserializer)?
+                .parse()
+                .map_err(|e: self::error::ConversionError| {
+                    <D::Error as ::serde::de::Error>::custom(e.to_string())
+                })
+        }
+    }
+
+    ///A part of a machine, which may be made of other parts.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "A part of a machine, which may be made of other
+    /// parts.",
+    ///  "oneOf": [
+    ///    {
+    ///      "type": "object",
+    ///      "required": [
+    ///        "type",
+    ///        "weight"
+    ///      ],
+    ///      "properties": {
+    ///        "type": {
+    ///          "type": "string",
+    ///          "enum": [
+    ///            "leaf"
+    ///          ]
+    ///        },
+    ///        "weight": {
+    ///          "type": "integer",
+    ///          "format": "uint8",
+    ///          "minimum": 1.0
+    ///        }
+    ///      }
+    ///    },
+    ///    {
+    ///      "type": "object",
+    ///      "required": [
+    ///        "parts",
+    ///        "type"
+    ///      ],
+    ///      "properties": {
+    ///        "parts": {
+    ///          "type": "array",
+    ///          "items": {
+    ///            "$ref": "#/components/schemas/MachinePart"
+    ///          }
+    ///        },
+    ///        "type": {
+    ///          "type": "string",
+    ///          "enum": [
+    ///            "assembly"
+    ///          ]
+    ///        }
+    ///      }
+    ///    }
+    ///  ]
+    ///}
+    /// ```
+    /// </details>
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[serde(tag = "type")]
+    pub enum MachinePart {
+        #[serde(rename = "leaf")]
+        Leaf { weight: ::std::num::NonZeroU8 },
+        #[serde(rename = "assembly")]
+        Assembly { parts: ::std::vec::Vec<MachinePart> },
+    }
+
+    impl ::std::convert::From<&Self> for MachinePart {
+        fn from(value: &MachinePart) -> Self {
+            value.clone()
+        }
+    }
+
+    ///The lookahead isn't supported by proptest's generation.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "The lookahead isn't supported by proptest's
+    /// generation.",
+    ///  "type": "string",
+    ///  "pattern": "^(?!none$)[a-z]+$"
+    ///}
+    /// ```
+    /// </details>
+    #[derive(:: serde :: Serialize, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    #[serde(transparent)]
+    pub struct MachineProfile(::std::string::String);
+    impl ::std::ops::Deref for MachineProfile {
+        type Target = ::std::string::String;
+        fn deref(&self) -> &::std::string::String {
+            &self.0
+        }
+    }
+
+    impl ::std::convert::From<MachineProfile> for ::std::string::String {
+        fn from(value: MachineProfile) -> Self {
+            value.0
+        }
+    }
+
+    impl ::std::convert::From<&MachineProfile> for MachineProfile {
+        fn from(value: &MachineProfile) -> Self {
+            value.clone()
+        }
+    }
+
+    impl ::std::str::FromStr for MachineProfile {
+        type Err = self::error::ConversionError;
+        fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+            static PATTERN: ::std::sync::LazyLock<::regress::Regex> =
+                ::std::sync::LazyLock::new(|| ::regress::Regex::new("^(?!none$)[a-z]+$").unwrap());
+            if (&*PATTERN).find(value).is_none() {
+                return Err("doesn't match pattern \"^(?!none$)[a-z]+$\"".into());
+            }
+            Ok(Self(value.to_string()))
+        }
+    }
+
+    impl ::std::convert::TryFrom<&str> for MachineProfile {
+        type Error = self::error::ConversionError;
+        fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::convert::TryFrom<&::std::string::String> for MachineProfile {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: &::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::convert::TryFrom<::std::string::String> for MachineProfile {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: ::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl<'de> ::serde::Deserialize<'de> for MachineProfile {
+        fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+        where
+            D: ::serde::Deserializer<'de>,
+        {
+            ::std::string::String::deserialize(deserializer)?
+                .parse()
+                .map_err(|e: self::error::ConversionError| {
+                    <D::Error as ::serde::de::Error>::custom(e.to_string())
+                })
+        }
+    }
+
+    ///`MachineSize`
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "anyOf": [
+    ///    {
+    ///      "type": "integer",
+    ///      "format": "uint64",
+    ///      "minimum": 0.0
+    ///    },
+    ///    {
+    ///      "type": "string"
+    ///    }
+    ///  ]
+    ///}
+    /// ```
+    /// </details>
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[serde(untagged)]
+    pub enum MachineSize {
+        Variant0(u64),
+        Variant1(::std::string::String),
+    }
+
+    impl ::std::convert::From<&Self> for MachineSize {
+        fn from(value: &MachineSize) -> Self {
+            value.clone()
+        }
+    }
+
+    impl ::std::str::FromStr for MachineSize {
+        type Err = self::error::ConversionError;
+        fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+            if let Ok(v) = value.parse() {
+                Ok(Self::Variant0(v))
+            } else if let Ok(v) = value.parse() {
+                Ok(Self::Variant1(v))
+            } else {
+                Err("string conversion failed for all variants".into())
+            }
+        }
+    }
+
+    impl ::std::convert::TryFrom<&str> for MachineSize {
+        type Error = self::error::ConversionError;
+        fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::convert::TryFrom<&::std::string::String> for MachineSize {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: &::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::convert::TryFrom<::std::string::String> for MachineSize {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: ::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::fmt::Display for MachineSize {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            match self {
+                Self::Variant0(x) => x.fmt(f),
+                Self::Variant1(x) => x.fmt(f),
+            }
+        }
+    }
+
+    impl ::std::convert::From<u64> for MachineSize {
+        fn from(value: u64) -> Self {
+            Self::Variant0(value)
+        }
+    }
+}
+
+impl ::proptest::arbitrary::Arbitrary for types::Error {
+    type Parameters = ();
+    type Strategy = ::proptest::strategy::BoxedStrategy<Self>;
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use ::proptest::strategy::Strategy as _;
+        (
+            ::proptest::option::of(::proptest::arbitrary::any::<::std::string::String>()),
+            ::proptest::arbitrary::any::<::std::string::String>(),
+            ::proptest::arbitrary::any::<::std::string::String>(),
+        )
+            .prop_map(|(error_code, message, request_id)| Self {
+                error_code,
+                message,
+                request_id,
+            })
+            .boxed()
+    }
+}
+
+impl ::proptest::arbitrary::Arbitrary for types::Machine {
+    type Parameters = ();
+    type Strategy = ::proptest::strategy::BoxedStrategy<Self>;
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use ::proptest::strategy::Strategy as _;
+        (
+            (1u32..=10u32).prop_filter_map("value must not be zero", ::std::num::NonZeroU32::new),
+            ::proptest::arbitrary::any::<types::MachineKind>(),
+            ::proptest::collection::vec(
+                (
+                    ::proptest::arbitrary::any::<::std::string::String>(),
+                    ::proptest::arbitrary::any::<types::MachineLabel>(),
+                ),
+                0usize..=4usize,
+            )
+            .prop_map(|entries| entries.into_iter().collect()),
+            ::proptest::arbitrary::any::<types::MachineName>(),
+            ::proptest::collection::vec(
+                ::proptest::arbitrary::any::<types::MachinePart>(),
+                0usize..=4usize,
+            ),
+            ::proptest::arbitrary::any::<types::MachineProfile>(),
+            0f64..=1f64,
+            ::proptest::arbitrary::any::<types::MachineSize>(),
+        )
+            .prop_map(
+                |(count, kind, labels, name, parts, profile, ratio, size)| Self {
+                    count,
+                    kind,
+                    labels,
+                    name,
+                    parts,
+                    profile,
+                    ratio,
+                    size,
+                },
+            )
+            .boxed()
+    }
+}
+
+impl ::proptest::arbitrary::Arbitrary for types::MachineKind {
+    type Parameters = ();
+    type Strategy = ::proptest::strategy::BoxedStrategy<Self>;
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use ::proptest::strategy::Strategy as _;
+        ::proptest::strategy::Union::new(vec![
+            ::proptest::strategy::Just(Self::Gear).boxed(),
+            ::proptest::strategy::Just(Self::Spring).boxed(),
+        ])
+        .boxed()
+    }
+}
+
+impl ::proptest::arbitrary::Arbitrary for types::MachineLabel {
+    type Parameters = ();
+    type Strategy = ::proptest::strategy::BoxedStrategy<Self>;
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use ::proptest::strategy::Strategy as _;
+        progenitor_client::arbitrary::string(None, None, Some(8usize))
+            .prop_filter_map("value must satisfy the schema", |value| {
+                Self::try_from(value).ok()
+            })
+            .boxed()
+    }
+}
+
+impl ::proptest::arbitrary::Arbitrary for types::MachineName {
+    type Parameters = ();
+    type Strategy = ::proptest::strategy::BoxedStrategy<Self>;
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use ::proptest::strategy::Strategy as _;
+        progenitor_client::arbitrary::string(Some("^[a-z][a-z0-9-]*$"), Some(1usize), Some(16usize))
+            .prop_filter_map("value must satisfy the schema", |value| {
+                Self::try_from(value).ok()
+            })
+            .boxed()
+    }
+}
+
+impl ::proptest::arbitrary::Arbitrary for types::MachinePart {
+    type Parameters = u32;
+    type Strategy = ::proptest::strategy::BoxedStrategy<Self>;
+    fn arbitrary_with(depth: Self::Parameters) -> Self::Strategy {
+        use ::proptest::strategy::Strategy as _;
+        ::proptest::strategy::Union::new(vec![
+            ::proptest::arbitrary::any::<::std::num::NonZeroU8>()
+                .prop_map(|weight| Self::Leaf { weight })
+                .boxed(),
+            if depth < progenitor_client::arbitrary::MAX_DEPTH {
+                ::proptest::collection::vec(
+                    ::proptest::arbitrary::any_with::<types::MachinePart>(depth + 1),
+                    0usize..=4usize,
+                )
+                .boxed()
+            } else {
+                ::proptest::strategy::Just(::std::default::Default::default()).boxed()
+            }
+            .prop_map(|parts| Self::Assembly { parts })
+            .boxed(),
+        ])
+        .boxed()
+    }
+}
+
+impl ::proptest::arbitrary::Arbitrary for types::MachineProfile {
+    type Parameters = ();
+    type Strategy = ::proptest::strategy::BoxedStrategy<Self>;
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use ::proptest::strategy::Strategy as _;
+        ::proptest::strategy::Just("a".to_string())
+            .prop_filter_map("value must satisfy the schema", |value| {
+                Self::try_from(value).ok()
+            })
+            .boxed()
+    }
+}
+
+impl ::proptest::arbitrary::Arbitrary for types::MachineSize {
+    type Parameters = ();
+    type Strategy = ::proptest::strategy::BoxedStrategy<Self>;
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use ::proptest::strategy::Strategy as _;
+        ::proptest::strategy::Union::new(vec![
+            ::proptest::arbitrary::any::<u64>()
+                .prop_map(Self::Variant0)
+                .boxed(),
+            ::proptest::arbitrary::any::<::std::string::String>()
+                .prop_map(Self::Variant1)
+                .boxed(),
+        ])
+        .boxed()
+    }
+}
+
+/// Tests that values of each type are unchanged by a serde round
+/// trip.
+#[cfg(test)]
+mod types_round_trip {
+    #[allow(unused_imports)]
+    use super::*;
+    #[test]
+    fn error() {
+        ::proptest::test_runner::TestRunner::default()
+            .run(&::proptest::arbitrary::any::<types::Error>(), |value| {
+                progenitor_client::arbitrary::round_trip(&value)
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn machine() {
+        ::proptest::test_runner::TestRunner::default()
+            .run(&::proptest::arbitrary::any::<types::Machine>(), |value| {
+                progenitor_client::arbitrary::round_trip(&value)
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn machine_kind() {
+        ::proptest::test_runner::TestRunner::default()
+            .run(
+                &::proptest::arbitrary::any::<types::MachineKind>(),
+                |value| progenitor_client::arbitrary::round_trip(&value),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn machine_label() {
+        ::proptest::test_runner::TestRunner::default()
+            .run(
+                &::proptest::arbitrary::any::<types::MachineLabel>(),
+                |value| progenitor_client::arbitrary::round_trip(&value),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn machine_name() {
+        ::proptest::test_runner::TestRunner::default()
+            .run(
+                &::proptest::arbitrary::any::<types::MachineName>(),
+                |value| progenitor_client::arbitrary::round_trip(&value),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn machine_part() {
+        ::proptest::test_runner::TestRunner::default()
+            .run(
+                &::proptest::arbitrary::any::<types::MachinePart>(),
+                |value| progenitor_client::arbitrary::round_trip(&value),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn machine_profile() {
+        ::proptest::test_runner::TestRunner::default()
+            .run(
+                &::proptest::arbitrary::any::<types::MachineProfile>(),
+                |value| progenitor_client::arbitrary::round_trip(&value),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn machine_size() {
+        ::proptest::test_runner::TestRunner::default()
+            .run(
+                &::proptest::arbitrary::any::<types::MachineSize>(),
+                |value| progenitor_client::arbitrary::round_trip(&value),
+            )
+            .unwrap();
+    }
+}
+
+#[derive(Clone, Debug)]
+///Client for test_proptest
+///
+///Version: 1.0.0
+pub struct Client {
+    pub(crate) baseurl: String,
+    pub(crate) client: reqwest::Client,
+    pub(crate) middleware: progenitor_client::MiddlewareStack,
+}
+
+impl Client {
+    /// Create a new client.
+    ///
+    /// `baseurl` is the base URL provided to the internal
+    /// `reqwest::Client`, and should include a scheme and hostname,
+    /// as well as port and a path stem if applicable.
+    pub fn new(baseurl: &str) -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        let client = {
+            let dur = std::time::Duration::from_secs(15);
+            reqwest::ClientBuilder::new()
+                .connect_timeout(dur)
+                .timeout(dur)
+        };
+        #[cfg(target_arch = "wasm32")]
+        let client = reqwest::ClientBuilder::new();
+        Self::new_with_client(baseurl, client.build().unwrap())
+    }
+
+    /// Construct a new client with an existing `reqwest::Client`,
+    /// allowing more control over its configuration.
+    ///
+    /// `baseurl` is the base URL provided to the internal
+    /// `reqwest::Client`, and should include a scheme and hostname,
+    /// as well as port and a path stem if applicable.
+    pub fn new_with_client(baseurl: &str, client: reqwest::Client) -> Self {
+        Self {
+            baseurl: baseurl.to_string(),
+            client,
+            middleware: Default::default(),
+        }
+    }
+
+    /// Append `middleware` to the chain that surrounds the
+    /// execution of each request. Middleware runs in the order in
+    /// which it is attached.
+    pub fn with_middleware<M: progenitor_client::Middleware>(mut self, middleware: M) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Descriptions of the API's operations.
+    pub const OPERATIONS: &'static [progenitor_client::OperationMetadata] =
+        &[progenitor_client::OperationMetadata {
+            operation_id: "put_machine",
+            method: reqwest::Method::PUT,
+            path: "/machines",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        }];
+}
+
+impl ClientInfo<()> for Client {
+    fn api_version() -> &'static str {
+        "1.0.0"
+    }
+
+    fn baseurl(&self) -> &str {
+        self.baseurl.as_str()
+    }
+
+    fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    fn inner(&self) -> &() {
+        &()
+    }
+}
+
+impl ClientHooks<()> for &Client {}
+#[allow(clippy::all)]
+#[allow(elided_named_lifetimes)]
+impl Client {
+    ///Sends a `PUT` request to `/machines`
+    pub async fn put_machine<'a>(
+        &'a self,
+        body: &'a types::Machine,
+    ) -> Result<ResponseValue<types::Machine>, Error<types::Error>> {
+        self.put_machine_with_options(body, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::put_machine`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn put_machine_with_options<'a>(
+        &'a self,
+        body: &'a types::Machine,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Machine>, Error<types::Error>> {
+        let mut request = self.put_machine_request(body).map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[0]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::put_machine`] without sending it.
+    pub fn put_machine_request<'a>(
+        &'a self,
+        body: &'a types::Machine,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+        let url = format!("{}/machines", self.baseurl,);
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+        header_map.append(
+            ::reqwest::header::HeaderName::from_static("api-version"),
+            ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+        );
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .put(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .json(&body)
+            .headers(header_map)
+            .build()?;
+        Ok(request)
+    }
+}
+
+/// Items consumers will typically use such as the Client.
+pub mod prelude {
+    #[allow(unused_imports)]
+    pub use super::Client;
+}
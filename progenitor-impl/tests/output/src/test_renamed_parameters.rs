@@ -1,7 +1,7 @@
 #[allow(unused_imports)]
 use progenitor_client::{encode_path, ClientHooks, OperationInfo, RequestBuilderExt};
 #[allow(unused_imports)]
-pub use progenitor_client::{ByteStream, ClientInfo, Error, ResponseValue};
+pub use progenitor_client::{ByteStream, ClientInfo, Error, RequestOptions, ResponseValue};
 /// Types used as operation parameters and responses.
 #[allow(clippy::all)]
 pub mod types {
@@ -83,6 +83,7 @@ pub mod types {
 pub struct Client {
     pub(crate) baseurl: String,
     pub(crate) client: reqwest::Client,
+    pub(crate) middleware: progenitor_client::MiddlewareStack,
 }
 
 impl Client {
@@ -114,8 +115,29 @@ impl Client {
         Self {
             baseurl: baseurl.to_string(),
             client,
+            middleware: Default::default(),
         }
     }
+
+    /// Append `middleware` to the chain that surrounds the
+    /// execution of each request. Middleware runs in the order in
+    /// which it is attached.
+    pub fn with_middleware<M: progenitor_client::Middleware>(mut self, middleware: M) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Descriptions of the API's operations.
+    pub const OPERATIONS: &'static [progenitor_client::OperationMetadata] =
+        &[progenitor_client::OperationMetadata {
+            operation_id: "renamed_parameters",
+            method: reqwest::Method::GET,
+            path: "/{ref}/{type}/{trait}",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[204u16..=204u16],
+        }];
 }
 
 impl ClientInfo<()> for Client {
@@ -150,6 +172,72 @@ impl Client {
         in_: &'a str,
         use_: &'a str,
     ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        self.renamed_parameters_with_options(
+            ref_,
+            type_,
+            trait_,
+            if_,
+            in_,
+            use_,
+            progenitor_client::RequestOptions::default(),
+        )
+        .await
+    }
+
+    ///Sends the request of [`Client::renamed_parameters`] with additional
+    /// headers, query parameters, or context from `options`.
+    pub async fn renamed_parameters_with_options<'a>(
+        &'a self,
+        ref_: &'a str,
+        type_: &'a str,
+        trait_: &'a str,
+        if_: &'a str,
+        in_: &'a str,
+        use_: &'a str,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<()>, Error<types::Error>> {
+        let mut request = self
+            .renamed_parameters_request(ref_, type_, trait_, if_, in_, use_)
+            .map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[0]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            204u16 => Ok(ResponseValue::empty(response)),
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response(response).await?,
+            )),
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    ///Prepares the request of [`Client::renamed_parameters`] without sending
+    /// it.
+    pub fn renamed_parameters_request<'a>(
+        &'a self,
+        ref_: &'a str,
+        type_: &'a str,
+        trait_: &'a str,
+        if_: &'a str,
+        in_: &'a str,
+        use_: &'a str,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
         let url = format!(
             "{}/{}/{}/{}",
             self.baseurl,
@@ -175,23 +263,7 @@ impl Client {
             .query(&progenitor_client::QueryParam::new("use", &use_))
             .headers(header_map)
             .build()?;
-        let info = OperationInfo {
-            operation_id: "renamed_parameters",
-        };
-        self.pre(&mut request, &info).await?;
-        let result = self.exec(request, &info).await;
-        self.post(&result, &info).await?;
-        let response = result?;
-        match response.status().as_u16() {
-            204u16 => Ok(ResponseValue::empty(response)),
-            400u16..=499u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            500u16..=599u16 => Err(Error::ErrorResponse(
-                ResponseValue::from_response(response).await?,
-            )),
-            _ => Err(Error::UnexpectedResponse(response)),
-        }
+        Ok(request)
     }
 }
 
@@ -0,0 +1,417 @@
+#[allow(unused_imports)]
+use progenitor_client::{encode_path, ClientHooks, OperationInfo, RequestBuilderExt};
+#[allow(unused_imports)]
+pub use progenitor_client::{ByteStream, ClientInfo, Error, RequestOptions, ResponseValue};
+/// Types used as operation parameters and responses.
+#[allow(clippy::all)]
+pub mod types {
+    /// Error types.
+    pub mod error {
+        /// Error from a `TryFrom` or `FromStr` implementation.
+        pub struct ConversionError(::std::borrow::Cow<'static, str>);
+        impl ::std::error::Error for ConversionError {}
+        impl ::std::fmt::Display for ConversionError {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+                ::std::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl ::std::fmt::Debug for ConversionError {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+                ::std::fmt::Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl From<&'static str> for ConversionError {
+            fn from(value: &'static str) -> Self {
+                Self(value.into())
+            }
+        }
+
+        impl From<String> for ConversionError {
+            fn from(value: String) -> Self {
+                Self(value.into())
+            }
+        }
+    }
+
+    ///Error information from a response.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "Error information from a response.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "message",
+    ///    "request_id"
+    ///  ],
+    ///  "properties": {
+    ///    "error_code": {
+    ///      "type": "string"
+    ///    },
+    ///    "message": {
+    ///      "type": "string"
+    ///    },
+    ///    "request_id": {
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    pub struct Error {
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub error_code: ::std::option::Option<::std::string::String>,
+        pub message: ::std::string::String,
+        pub request_id: ::std::string::String,
+    }
+
+    impl ::std::convert::From<&Error> for Error {
+        fn from(value: &Error) -> Self {
+            value.clone()
+        }
+    }
+
+    ///`Gadget`
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "type": "object",
+    ///  "required": [
+    ///    "kind",
+    ///    "name"
+    ///  ],
+    ///  "properties": {
+    ///    "kind": {
+    ///      "$ref": "#/components/schemas/GadgetKind"
+    ///    },
+    ///    "name": {
+    ///      "type": "string"
+    ///    }
+    ///  },
+    ///  "additionalProperties": false
+    ///}
+    /// ```
+    /// </details>
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[serde(deny_unknown_fields)]
+    pub struct Gadget {
+        pub kind: GadgetKind,
+        pub name: ::std::string::String,
+    }
+
+    impl ::std::convert::From<&Gadget> for Gadget {
+        fn from(value: &Gadget) -> Self {
+            value.clone()
+        }
+    }
+
+    ///`GadgetKind`
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "type": "string",
+    ///  "enum": [
+    ///    "sprocket",
+    ///    "widget"
+    ///  ]
+    ///}
+    /// ```
+    /// </details>
+    #[derive(
+        :: serde :: Deserialize,
+        :: serde :: Serialize,
+        Clone,
+        Copy,
+        Debug,
+        Eq,
+        Hash,
+        Ord,
+        PartialEq,
+        PartialOrd,
+    )]
+    pub enum GadgetKind {
+        #[serde(rename = "sprocket")]
+        Sprocket,
+        #[serde(rename = "widget")]
+        Widget,
+    }
+
+    impl ::std::convert::From<&Self> for GadgetKind {
+        fn from(value: &GadgetKind) -> Self {
+            value.clone()
+        }
+    }
+
+    impl ::std::fmt::Display for GadgetKind {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            match *self {
+                Self::Sprocket => write!(f, "sprocket"),
+                Self::Widget => write!(f, "widget"),
+            }
+        }
+    }
+
+    impl ::std::str::FromStr for GadgetKind {
+        type Err = self::error::ConversionError;
+        fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+            match value {
+                "sprocket" => Ok(Self::Sprocket),
+                "widget" => Ok(Self::Widget),
+                _ => Err("invalid value".into()),
+            }
+        }
+    }
+
+    impl ::std::convert::TryFrom<&str> for GadgetKind {
+        type Error = self::error::ConversionError;
+        fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::convert::TryFrom<&::std::string::String> for GadgetKind {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: &::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::convert::TryFrom<::std::string::String> for GadgetKind {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: ::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+///Client for test_response_checking
+///
+///Version: 1.0.0
+pub struct Client {
+    pub(crate) baseurl: String,
+    pub(crate) client: reqwest::Client,
+    pub(crate) middleware: progenitor_client::MiddlewareStack,
+}
+
+impl Client {
+    /// Create a new client.
+    ///
+    /// `baseurl` is the base URL provided to the internal
+    /// `reqwest::Client`, and should include a scheme and hostname,
+    /// as well as port and a path stem if applicable.
+    pub fn new(baseurl: &str) -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        let client = {
+            let dur = std::time::Duration::from_secs(15);
+            reqwest::ClientBuilder::new()
+                .connect_timeout(dur)
+                .timeout(dur)
+        };
+        #[cfg(target_arch = "wasm32")]
+        let client = reqwest::ClientBuilder::new();
+        Self::new_with_client(baseurl, client.build().unwrap())
+    }
+
+    /// Construct a new client with an existing `reqwest::Client`,
+    /// allowing more control over its configuration.
+    ///
+    /// `baseurl` is the base URL provided to the internal
+    /// `reqwest::Client`, and should include a scheme and hostname,
+    /// as well as port and a path stem if applicable.
+    pub fn new_with_client(baseurl: &str, client: reqwest::Client) -> Self {
+        Self {
+            baseurl: baseurl.to_string(),
+            client,
+            middleware: Default::default(),
+        }
+    }
+
+    /// Append `middleware` to the chain that surrounds the
+    /// execution of each request. Middleware runs in the order in
+    /// which it is attached.
+    pub fn with_middleware<M: progenitor_client::Middleware>(mut self, middleware: M) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Descriptions of the API's operations.
+    pub const OPERATIONS: &'static [progenitor_client::OperationMetadata] =
+        &[progenitor_client::OperationMetadata {
+            operation_id: "get_gadget",
+            method: reqwest::Method::GET,
+            path: "/gadgets/{name}",
+            tags: &[],
+            idempotent: true,
+            deprecated: false,
+            success_statuses: &[200u16..=200u16],
+        }];
+}
+
+impl ClientInfo<()> for Client {
+    fn api_version() -> &'static str {
+        "1.0.0"
+    }
+
+    fn baseurl(&self) -> &str {
+        self.baseurl.as_str()
+    }
+
+    fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    fn inner(&self) -> &() {
+        &()
+    }
+}
+
+impl ClientHooks<()> for &Client {}
+#[allow(clippy::all)]
+#[allow(elided_named_lifetimes)]
+impl Client {
+    ///Sends a `GET` request to `/gadgets/{name}`
+    pub async fn get_gadget<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> Result<ResponseValue<types::Gadget>, Error<types::Error>> {
+        self.get_gadget_with_options(name, progenitor_client::RequestOptions::default())
+            .await
+    }
+
+    ///Sends the request of [`Client::get_gadget`] with additional headers,
+    /// query parameters, or context from `options`.
+    pub async fn get_gadget_with_options<'a>(
+        &'a self,
+        name: &'a str,
+        options: progenitor_client::RequestOptions,
+    ) -> Result<ResponseValue<types::Gadget>, Error<types::Error>> {
+        let mut request = self.get_gadget_request(name).map_err(Error::into_typed)?;
+        let info =
+            OperationInfo::new(&Self::OPERATIONS[0]).with_context(options.apply(&mut request)?);
+        self.pre(&mut request, &info).await?;
+        let response = {
+            let info = &info;
+            self.middleware
+                .run(request, info, &|request| {
+                    Box::pin(async move {
+                        let result = self.exec(request, info).await;
+                        self.post(&result, info).await?;
+                        Ok(result?)
+                    })
+                })
+                .await
+                .map_err(Error::into_typed)?
+        };
+        match response.status().as_u16() {
+            200u16 => {
+                ResponseValue::from_response_checked(
+                    response,
+                    &progenitor_client::ContractSchema {
+                        properties: &[
+                            (
+                                "kind",
+                                progenitor_client::ContractSchema {
+                                    enum_values: &["\"sprocket\"", "\"widget\""],
+                                    ..progenitor_client::ContractSchema::ANY
+                                },
+                            ),
+                            ("name", progenitor_client::ContractSchema::ANY),
+                        ],
+                        required: &["kind", "name"],
+                        additional_properties: false,
+                        ..progenitor_client::ContractSchema::ANY
+                    },
+                    |status, violation| self.contract_violation(status, violation, &info),
+                )
+                .await
+            }
+            400u16..=499u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response_checked(
+                    response,
+                    &progenitor_client::ContractSchema {
+                        properties: &[
+                            ("error_code", progenitor_client::ContractSchema::ANY),
+                            ("message", progenitor_client::ContractSchema::ANY),
+                            ("request_id", progenitor_client::ContractSchema::ANY),
+                        ],
+                        required: &["message", "request_id"],
+                        ..progenitor_client::ContractSchema::ANY
+                    },
+                    |status, violation| self.contract_violation(status, violation, &info),
+                )
+                .await?,
+            )),
+            500u16..=599u16 => Err(Error::ErrorResponse(
+                ResponseValue::from_response_checked(
+                    response,
+                    &progenitor_client::ContractSchema {
+                        properties: &[
+                            ("error_code", progenitor_client::ContractSchema::ANY),
+                            ("message", progenitor_client::ContractSchema::ANY),
+                            ("request_id", progenitor_client::ContractSchema::ANY),
+                        ],
+                        required: &["message", "request_id"],
+                        ..progenitor_client::ContractSchema::ANY
+                    },
+                    |status, violation| self.contract_violation(status, violation, &info),
+                )
+                .await?,
+            )),
+            _ => {
+                self.contract_violation(
+                    response.status(),
+                    &progenitor_client::ContractViolation::UndeclaredStatus,
+                    &info,
+                );
+                Err(Error::UnexpectedResponse(response))
+            }
+        }
+    }
+
+    ///Prepares the request of [`Client::get_gadget`] without sending it.
+    pub fn get_gadget_request<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> Result<reqwest::Request, Error<std::convert::Infallible>> {
+        let url = format!(
+            "{}/gadgets/{}",
+            self.baseurl,
+            encode_path(&name.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+        header_map.append(
+            ::reqwest::header::HeaderName::from_static("api-version"),
+            ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+        );
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        Ok(request)
+    }
+}
+
+/// Items consumers will typically use such as the Client.
+pub mod prelude {
+    #[allow(unused_imports)]
+    pub use super::Client;
+}
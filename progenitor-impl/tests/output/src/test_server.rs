@@ -0,0 +1,107 @@
+#[allow(unused_imports)]
+use super::types;
+pub mod parameters {
+    //! Path, query, and header parameters of each operation, as
+    //! used by the handlers of [`Api`](super::Api).
+    #[allow(unused_imports)]
+    use super::types;
+    ///Path parameters of the `list_folder_notes` operation.
+    #[derive(Clone, Debug, serde :: Deserialize, schemars :: JsonSchema)]
+    pub struct ListFolderNotesPath {
+        pub folder: ::std::string::String,
+    }
+
+    ///Path parameters of the `put_note` operation.
+    #[derive(Clone, Debug, serde :: Deserialize, schemars :: JsonSchema)]
+    pub struct PutNotePath {
+        pub id: u32,
+    }
+
+    ///Query parameters of the `put_note` operation.
+    #[derive(Clone, Debug, serde :: Deserialize, schemars :: JsonSchema)]
+    pub struct PutNoteQuery {
+        pub pinned: Option<bool>,
+        pub visibility: ::std::string::String,
+    }
+
+    ///Header parameters of the `put_note` operation.
+    #[derive(Clone, Debug)]
+    pub struct PutNoteHeaders {
+        pub x_note_author: ::std::string::String,
+    }
+
+    impl PutNoteHeaders {
+        /// Decode the header parameters from the headers of a
+        /// request, responding with a client error if one is missing
+        /// or invalid.
+        pub fn from_headers(headers: &http::HeaderMap) -> Result<Self, dropshot::HttpError> {
+            Ok(Self {
+                x_note_author: header_value(headers, "x-note-author")?.ok_or_else(|| {
+                    dropshot::HttpError::for_bad_request(
+                        None,
+                        "missing header x-note-author".to_string(),
+                    )
+                })?,
+            })
+        }
+    }
+
+    /// Decode the value of an optional header, rejecting values
+    /// that aren't valid for its type.
+    fn header_value<T>(
+        headers: &http::HeaderMap,
+        name: &str,
+    ) -> Result<Option<T>, dropshot::HttpError>
+    where
+        T: std::str::FromStr,
+        T::Err: std::fmt::Display,
+    {
+        let Some(value) = headers.get(name) else {
+            return Ok(None);
+        };
+        let value = value.to_str().map_err(|e| {
+            dropshot::HttpError::for_bad_request(
+                None,
+                format!("invalid value for header {}: {}", name, e),
+            )
+        })?;
+        value.parse().map(Some).map_err(|e| {
+            dropshot::HttpError::for_bad_request(
+                None,
+                format!("invalid value for header {}: {}", name, e),
+            )
+        })
+    }
+}
+
+/// A Dropshot API with a handler for each operation.
+///
+/// Dropshot has no extractor for headers, so handlers decode the
+/// header parameters of an operation from the request in `rqctx`
+/// with the `from_headers` function of its `parameters` struct.
+/// Paginated operations receive their scan parameters with
+/// `PaginationParams`; the page selector of their `ResultsPage`
+/// is any JSON value. An implementation may be served with the
+/// `ApiDescription` from `api_mod::api_description`.
+#[dropshot::api_description]
+pub trait Api {
+    /// The context shared by all handlers.
+    type Context;
+    ///Handles `GET /folders/{folder}/notes`
+    # [endpoint { method = GET , path = "/folders/{folder}/notes" , }]
+    async fn list_folder_notes(
+        rqctx: dropshot::RequestContext<Self::Context>,
+        path: dropshot::Path<parameters::ListFolderNotesPath>,
+        query: dropshot::Query<
+            dropshot::PaginationParams<dropshot::EmptyScanParams, serde_json::Value>,
+        >,
+    ) -> Result<dropshot::HttpResponseOk<dropshot::ResultsPage<u32>>, dropshot::HttpError>;
+    ///Handles `PUT /notes/{id}`
+    # [endpoint { method = PUT , path = "/notes/{id}" , }]
+    async fn put_note(
+        rqctx: dropshot::RequestContext<Self::Context>,
+        path: dropshot::Path<parameters::PutNotePath>,
+        query: dropshot::Query<parameters::PutNoteQuery>,
+        body: dropshot::TypedBody<types::Note>,
+    ) -> Result<dropshot::HttpResponseOk<types::NoteRecord>, dropshot::HttpError>;
+}
@@ -62,22 +62,7 @@ fn verify_apis(openapi_file: &str) {
     );
 
     // Builder generation with derives and patches.
-    let mut generator = Generator::new(
-        GenerationSettings::default()
-            .with_interface(InterfaceStyle::Builder)
-            .with_tag(TagStyle::Merged)
-            .with_derive("schemars::JsonSchema")
-            .with_patch("Name", TypePatch::default().with_derive("Hash"))
-            .with_conversion(
-                schemars::schema::SchemaObject {
-                    instance_type: Some(schemars::schema::InstanceType::Integer.into()),
-                    format: Some("int32".to_string()),
-                    ..Default::default()
-                },
-                "usize",
-                [TypeImpl::Display].into_iter(),
-            ),
-    );
+    let mut generator = Generator::new(&builder_settings());
     let output = generate_formatted(&mut generator, &spec);
     expectorate::assert_contents(
         format!("tests/output/src/{}_builder.rs", openapi_stem),
@@ -126,9 +111,158 @@ fn verify_apis(openapi_file: &str) {
     );
 }
 
+/// The settings of builder generation, whose types the server and mock
+/// generators use.
+fn builder_settings() -> GenerationSettings {
+    let mut settings = GenerationSettings::default();
+    settings
+        .with_interface(InterfaceStyle::Builder)
+        .with_tag(TagStyle::Merged)
+        .with_derive("schemars::JsonSchema")
+        .with_patch("Name", TypePatch::default().with_derive("Hash"))
+        .with_conversion(
+            schemars::schema::SchemaObject {
+                instance_type: Some(schemars::schema::InstanceType::Integer.into()),
+                format: Some("int32".to_string()),
+                ..Default::default()
+            },
+            "usize",
+            [TypeImpl::Display].into_iter(),
+        );
+    settings
+}
+
+/// Verifies the output of server and mock generation, which is checked for a
+/// few representative APIs rather than all of them.
+#[track_caller]
+fn verify_servers(openapi_file: &str) {
+    let mut in_path = PathBuf::from("../sample_openapi");
+    in_path.push(openapi_file);
+    let openapi_stem = openapi_file.split('.').next().unwrap().replace('-', "_");
+
+    let spec = load_api(in_path);
+
+    // Generate the builder client first so that the types match its own.
+    let mut generator = Generator::new(&builder_settings());
+    generator.generate_tokens(&spec).unwrap();
+
+    // Dropshot server generation, which requires the `JsonSchema` derive.
+    let tokens = generator
+        .server(&spec, &format!("crate::{openapi_stem}_builder"))
+        .unwrap();
+    let output = reformat_code(tokens);
+    expectorate::assert_contents(
+        format!("tests/output/src/{}_server.rs", openapi_stem),
+        &output,
+    );
+
+    // axum router generation.
+    let tokens = generator
+        .axum(&spec, &format!("crate::{openapi_stem}_builder"))
+        .unwrap();
+    let output = reformat_code(tokens);
+    expectorate::assert_contents(
+        format!("tests/output/src/{}_axum.rs", openapi_stem),
+        &output,
+    );
+
+    // In-process fake generation.
+    let tokens = generator
+        .fake(&spec, &format!("crate::{openapi_stem}_builder"))
+        .unwrap();
+    let output = reformat_code(tokens);
+    expectorate::assert_contents(
+        format!("tests/output/src/{}_fake.rs", openapi_stem),
+        &output,
+    );
+
+    // wiremock generation.
+    let code = generator
+        .wiremock(&spec, &format!("crate::{openapi_stem}_builder"))
+        .unwrap();
+
+    let output = rustfmt_wrapper::rustfmt_config(
+        rustfmt_wrapper::config::Config {
+            format_strings: Some(true),
+            ..Default::default()
+        },
+        code,
+    )
+    .unwrap();
+
+    let output = progenitor_impl::space_out_items(output).unwrap();
+    expectorate::assert_contents(
+        format!("tests/output/src/{}_wiremock.rs", openapi_stem),
+        &output,
+    );
+}
+
+/// Enables an optional generation feature.
+type EnableFeature = fn(&mut GenerationSettings) -> &mut GenerationSettings;
+
+/// Verifies the output of optional generation features, each with its own
+/// settings so that one feature's output doesn't hide changes to another's.
+#[track_caller]
+fn verify_features(openapi_file: &str) {
+    let mut in_path = PathBuf::from("../sample_openapi");
+    in_path.push(openapi_file);
+    let openapi_stem = openapi_file.split('.').next().unwrap().replace('-', "_");
+
+    let spec = load_api(in_path);
+
+    let features: [(&str, EnableFeature); 3] = [
+        ("operation_enum", |settings| {
+            settings.with_operation_enum(true)
+        }),
+        ("client_api", |settings| settings.with_client_api(true)),
+        ("proptest", |settings| settings.with_proptest(true)),
+    ];
+    for (feature, enable) in features {
+        let mut generator = Generator::new(enable(
+            GenerationSettings::default().with_interface(InterfaceStyle::Builder),
+        ));
+        let output = generate_formatted(&mut generator, &spec);
+        expectorate::assert_contents(
+            format!("tests/output/src/{}_{}.rs", openapi_stem, feature),
+            &output,
+        );
+    }
+
+    verify_httpmock_examples(&spec, &openapi_stem);
+}
+
+/// Verifies the output of httpmock generation with mocks answering from
+/// examples.
+#[track_caller]
+fn verify_httpmock_examples(spec: &OpenAPI, openapi_stem: &str) {
+    let mut generator = Generator::new(
+        GenerationSettings::default()
+            .with_interface(InterfaceStyle::Builder)
+            .with_httpmock_examples(true),
+    );
+    let code = generator
+        .httpmock(spec, &format!("crate::{openapi_stem}_builder"))
+        .unwrap();
+    let output = rustfmt_wrapper::rustfmt_config(
+        rustfmt_wrapper::config::Config {
+            format_strings: Some(true),
+            ..Default::default()
+        },
+        code,
+    )
+    .unwrap();
+    let output = progenitor_impl::space_out_items(output).unwrap();
+    expectorate::assert_contents(
+        format!("tests/output/src/{}_httpmock_examples.rs", openapi_stem),
+        &output,
+    );
+}
+
 #[test]
 fn test_keeper() {
     verify_apis("keeper.json");
+    verify_features("keeper.json");
+    verify_servers("keeper.json");
 }
 
 #[test]
@@ -139,11 +273,17 @@ fn test_buildomat() {
 #[test]
 fn test_nexus() {
     verify_apis("nexus.json");
+
+    // Many of Nexus's string types have patterns.
+    let spec = load_api("../sample_openapi/nexus.json");
+    verify_httpmock_examples(&spec, "nexus");
 }
 
 #[test]
 fn test_propolis_server() {
     verify_apis("propolis-server.json");
+    verify_features("propolis-server.json");
+    verify_servers("propolis-server.json");
 }
 
 #[test]
@@ -0,0 +1,16 @@
+// Copyright 2025 Oxide Computer Company
+
+//! Runs the round-trip tests generated for the sample APIs' types. The output
+//! crate in which their generated code is checked isn't a member of the
+//! workspace, so its tests aren't otherwise run. As there, lints that the
+//! generated code doesn't attempt to satisfy are allowed.
+
+#[allow(dead_code, unused_imports)]
+mod keeper_proptest {
+    include!("output/src/keeper_proptest.rs");
+}
+
+#[allow(dead_code, unused_imports)]
+mod propolis_server_proptest {
+    include!("output/src/propolis_server_proptest.rs");
+}
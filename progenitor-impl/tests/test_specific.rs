@@ -1,16 +1,17 @@
 // Copyright 2024 Oxide Computer Company
 
 use dropshot::{
-    endpoint, ApiDescription, Body, ConfigDropshot, ConfigLogging, ConfigLoggingLevel,
-    EmptyScanParams, HttpError, HttpResponseOk, HttpResponseUpdatedNoContent, HttpServerStarter,
-    PaginationParams, Path, Query, RequestContext, ResultsPage, TypedBody,
+    channel, endpoint, ApiDescription, Body, ConfigDropshot, ConfigLogging, ConfigLoggingLevel,
+    EmptyScanParams, HttpError, HttpResponseCreated, HttpResponseOk, HttpResponseUpdatedNoContent,
+    HttpServer, HttpServerStarter, PaginationParams, Path, Query, RequestContext, ResultsPage,
+    ServerContext, TypedBody, UntypedBody, WebsocketChannelResult, WebsocketConnection,
 };
 use futures::StreamExt;
 use http::Response;
 use openapiv3::OpenAPI;
 use progenitor_impl::{space_out_items, GenerationSettings, Generator, InterfaceStyle};
 use schemars::JsonSchema;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::{
     net::{Ipv4Addr, SocketAddr},
     str::from_utf8,
@@ -19,6 +20,10 @@ use std::{
 
 fn generate_formatted(generator: &mut Generator, spec: &OpenAPI) -> String {
     let content = generator.generate_tokens(spec).unwrap();
+    format_tokens(content)
+}
+
+fn format_tokens(content: proc_macro2::TokenStream) -> String {
     let rustfmt_config = rustfmt_wrapper::config::Config {
         normalize_doc_attributes: Some(true),
         wrap_comments: Some(true),
@@ -27,6 +32,62 @@ fn generate_formatted(generator: &mut Generator, spec: &OpenAPI) -> String {
     space_out_items(rustfmt_wrapper::rustfmt_config(rustfmt_config, content).unwrap()).unwrap()
 }
 
+/// Describe `api` as a JSON OpenAPI document, for tests to amend.
+fn api_json<C: ServerContext>(api: &ApiDescription<C>, name: &str) -> serde_json::Value {
+    let mut out = Vec::new();
+    api.openapi(name, semver::Version::new(1, 0, 0))
+        .write(&mut out)
+        .unwrap();
+    serde_json::from_slice(&out).unwrap()
+}
+
+/// Describe `api` as an OpenAPI document.
+fn api_spec<C: ServerContext>(api: &ApiDescription<C>, name: &str) -> OpenAPI {
+    serde_json::from_value(api_json(api, name)).unwrap()
+}
+
+/// Generate a client for `spec` and check it against
+/// `tests/output/src/{name}.rs`, returning the generator for further output.
+fn assert_generated(settings: &GenerationSettings, spec: &OpenAPI, name: &str) -> Generator {
+    let mut generator = Generator::new(settings);
+    let output = generate_formatted(&mut generator, spec);
+    expectorate::assert_contents(format!("tests/output/src/{name}.rs"), &output);
+    generator
+}
+
+/// Start a Dropshot server for `api` on a local port, returning it along with
+/// its base URL.
+fn start_server<C: ServerContext>(
+    name: &str,
+    api: ApiDescription<C>,
+    context: C,
+) -> (HttpServer<C>, String) {
+    let config_dropshot = ConfigDropshot {
+        bind_address: SocketAddr::from((Ipv4Addr::LOCALHOST, 0)),
+        ..Default::default()
+    };
+    start_server_with_config(name, &config_dropshot, api, context)
+}
+
+fn start_server_with_config<C: ServerContext>(
+    name: &str,
+    config_dropshot: &ConfigDropshot,
+    api: ApiDescription<C>,
+    context: C,
+) -> (HttpServer<C>, String) {
+    let config_logging = ConfigLogging::StderrTerminal {
+        level: ConfigLoggingLevel::Debug,
+    };
+    let log = config_logging
+        .to_logger(name)
+        .expect("failed to create logger");
+    let server = HttpServerStarter::new(config_dropshot, api, context, &log)
+        .expect("failed to create server")
+        .start();
+    let server_addr = format!("http://{}", server.local_addr());
+    (server, server_addr)
+}
+
 #[allow(dead_code)]
 #[derive(Deserialize, JsonSchema)]
 struct CursedPath {
@@ -179,179 +240,3888 @@ fn test_default_params() {
     );
 }
 
-#[derive(Debug)]
-struct PaginatedU32sContext {
-    all_values: std::ops::Range<u32>,
-    // Record of `(offset, limit)` pairs we received
-    page_pairs: Mutex<Vec<(usize, usize)>>,
-}
-
-#[endpoint {
-    method = GET,
-    path = "/",
+#[channel {
+    protocol = WEBSOCKETS,
+    path = "/ws",
 }]
-async fn paginated_u32s(
-    rqctx: RequestContext<Arc<PaginatedU32sContext>>,
-    query_params: Query<PaginationParams<EmptyScanParams, u32>>,
-) -> Result<HttpResponseOk<ResultsPage<u32>>, HttpError> {
-    let ctx = rqctx.context();
-    let page_params = query_params.into_inner();
-    let limit = usize::try_from(
-        rqctx
-            .page_limit(&page_params)
-            .expect("invalid page limit")
-            .get(),
-    )
-    .expect("non-usize limit");
-
-    let offset = match page_params.page {
-        dropshot::WhichPage::First(EmptyScanParams {}) => 0,
-        dropshot::WhichPage::Next(offset) => usize::try_from(offset + 1).expect("non-usize offset"),
-    };
-
-    ctx.page_pairs.lock().unwrap().push((offset, limit));
-    let values = ctx.all_values.clone().skip(offset).take(limit).collect();
-    let result = ResultsPage::new(values, &(), |&x, &()| x).expect("bad results page");
-
-    Ok(HttpResponseOk(result))
+async fn websocket_errors(
+    _rqctx: RequestContext<()>,
+    _upgraded: WebsocketConnection,
+) -> WebsocketChannelResult {
+    unreachable!();
 }
 
-#[tokio::test]
-async fn test_stream_pagination() {
-    const TEST_NAME: &str = "test_stream_pagination";
-
+/// Test websocket channels that document specific error responses.
+#[test]
+fn test_websocket_errors() {
     let mut api = ApiDescription::new();
-    api.register(paginated_u32s).unwrap();
+    api.register(websocket_errors).unwrap();
 
     let mut out = Vec::new();
 
-    api.openapi(TEST_NAME, semver::Version::new(1, 0, 0))
+    api.openapi("websocket-errors", semver::Version::new(9000, 0, 0))
         .write(&mut out)
         .unwrap();
 
     let out = from_utf8(&out).unwrap();
-    let spec = serde_json::from_str::<OpenAPI>(out).unwrap();
+    let mut spec = serde_json::from_str::<serde_json::Value>(out).unwrap();
 
-    // Test both interface styles.
-    let mut generator =
-        Generator::new(GenerationSettings::new().with_interface(InterfaceStyle::Positional));
+    // Dropshot only describes the default response for channels; add typed
+    // error responses as an API might document for authentication or lookup
+    // failures.
+    let error_response = serde_json::json!({
+        "description": "error",
+        "content": {
+            "application/json": {
+                "schema": {
+                    "title": "Error",
+                    "type": "object",
+                    "properties": {
+                        "message": { "type": "string" }
+                    },
+                    "required": ["message"]
+                }
+            }
+        }
+    });
+    let responses = &mut spec["paths"]["/ws"]["get"]["responses"];
+    responses["401"] = error_response.clone();
+    responses["404"] = error_response;
+
+    let spec = serde_json::from_value::<OpenAPI>(spec).unwrap();
+
+    let mut generator = Generator::default();
     let output = generate_formatted(&mut generator, &spec);
     expectorate::assert_contents(
-        format!("tests/output/src/{TEST_NAME}_positional.rs"),
+        format!("tests/output/src/{}.rs", "test_websocket_errors_positional"),
         &output,
     );
+
     let mut generator =
-        Generator::new(GenerationSettings::new().with_interface(InterfaceStyle::Builder));
+        Generator::new(GenerationSettings::default().with_interface(InterfaceStyle::Builder));
     let output = generate_formatted(&mut generator, &spec);
-    expectorate::assert_contents(format!("tests/output/src/{TEST_NAME}_builder.rs"), &output);
+    expectorate::assert_contents(
+        format!("tests/output/src/{}.rs", "test_websocket_errors_builder"),
+        &output,
+    );
+}
 
-    // Run the Dropshot server.
-    let config_dropshot = ConfigDropshot {
-        bind_address: SocketAddr::from((Ipv4Addr::LOCALHOST, 0)),
-        ..Default::default()
-    };
-    let config_logging = ConfigLogging::StderrTerminal {
-        level: ConfigLoggingLevel::Debug,
+#[derive(Debug, Default)]
+struct EventsContext {
+    // The Last-Event-ID header sent with each request.
+    last_event_ids: Mutex<Vec<Option<String>>>,
+}
+
+#[endpoint {
+    method = GET,
+    path = "/logs",
+}]
+async fn stream_logs(
+    rqctx: RequestContext<Arc<EventsContext>>,
+) -> Result<Response<Body>, HttpError> {
+    let last_event_id = rqctx
+        .request
+        .headers()
+        .get("last-event-id")
+        .map(|value| value.to_str().unwrap().to_string());
+    rqctx
+        .context()
+        .last_event_ids
+        .lock()
+        .unwrap()
+        .push(last_event_id.clone());
+
+    // Send two events, then resume from the last one before reporting that
+    // there are no more.
+    let body = match last_event_id.as_deref() {
+        None => concat!(
+            "retry: 0\n",
+            ": comments are ignored\n",
+            "id: 1\n",
+            "data: {\"line\": \"one\"}\n\n",
+            "event: log\n",
+            "id: 2\n",
+            "data: {\"line\": \"two\"}\n\n",
+        ),
+        Some("2") => "id: 3\ndata: {\"line\": \"three\"}\n\n",
+        Some(_) => {
+            return Ok(Response::builder()
+                .status(http::StatusCode::NO_CONTENT)
+                .body(Body::empty())?)
+        }
     };
-    let log = config_logging
-        .to_logger(TEST_NAME)
-        .expect("failed to create logger");
-    let server_ctx = Arc::new(PaginatedU32sContext {
-        all_values: 0..35,
-        page_pairs: Mutex::default(),
+
+    Ok(Response::builder()
+        .status(http::StatusCode::OK)
+        .header(http::header::CONTENT_TYPE, "text/event-stream")
+        .body(body.into())?)
+}
+
+/// Test typed Server-Sent Events responses and reconnection.
+#[tokio::test]
+async fn test_event_stream() {
+    const TEST_NAME: &str = "test_event_stream";
+
+    let mut api = ApiDescription::new();
+    api.register(stream_logs).unwrap();
+
+    let mut spec = api_json(&api, TEST_NAME);
+
+    // Dropshot doesn't know the format of a freeform response; describe the
+    // event stream and the type of each event's data.
+    spec["paths"]["/logs"]["get"]["responses"] = serde_json::json!({
+        "200": {
+            "description": "log lines",
+            "content": {
+                "text/event-stream": {
+                    "schema": {
+                        "title": "LogLine",
+                        "type": "object",
+                        "properties": {
+                            "line": { "type": "string" }
+                        },
+                        "required": ["line"]
+                    }
+                }
+            }
+        }
     });
-    let server = HttpServerStarter::new(&config_dropshot, api, Arc::clone(&server_ctx), &log)
-        .expect("failed to create server")
-        .start();
 
-    let server_addr = format!("http://{}", server.local_addr());
+    let spec = serde_json::from_value::<OpenAPI>(spec).unwrap();
+
+    assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Positional),
+        &spec,
+        &format!("{TEST_NAME}_positional"),
+    );
+    assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Builder),
+        &spec,
+        &format!("{TEST_NAME}_builder"),
+    );
+
+    // Run the Dropshot server.
+    let server_ctx = Arc::new(EventsContext::default());
+    let (server, server_addr) = start_server(TEST_NAME, api, Arc::clone(&server_ctx));
 
-    // Test the positional client.
     #[allow(dead_code)]
     mod gen_client_positional {
-        // This is weird: we're now `include!`ing the file we just used to
-        // confirm the generated code is what we expect. If changes are made to
-        // progenitor that affect this generated code, keep in mind that when
-        // this test executes, the above check is against what we _currently_
-        // produce, while this `include!` is what was on disk before the test
-        // ran. This can be surprising if you're running the test with
-        // `EXPECTORATE=overwrite`, because the above check will overwrite the
-        // file on disk, but then the test proceeds and gets to this point,
-        // where it uses what was on disk _before_ expectorate overwrote it.
-        include!("output/src/test_stream_pagination_positional.rs");
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_event_stream_positional.rs");
     }
 
     let client = gen_client_positional::Client::new(&server_addr);
 
-    let page_limit = 10.try_into().unwrap();
-    let mut stream = client.paginated_u32s_stream(Some(page_limit));
+    // Without reconnection, the stream ends along with the response.
+    let response = client.stream_logs().await.unwrap();
+    assert_eq!(
+        response.headers().get(http::header::CONTENT_TYPE).unwrap(),
+        "text/event-stream"
+    );
+    let events = response
+        .into_inner()
+        .map(|event| event.unwrap())
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(
+        events
+            .iter()
+            .map(|event| (
+                event.event.as_deref(),
+                event.id.as_deref(),
+                event.data.line.as_str()
+            ))
+            .collect::<Vec<_>>(),
+        vec![(None, Some("1"), "one"), (Some("log"), Some("2"), "two")],
+    );
 
-    let mut all_values = Vec::new();
-    while let Some(result) = stream.next().await {
-        match result {
-            Ok(value) => {
-                all_values.push(value);
-            }
-            Err(err) => {
-                panic!("unexpected error: {err}");
+    // With reconnection, the client resumes from the last event it saw until
+    // the server responds with 204.
+    server_ctx.last_event_ids.lock().unwrap().clear();
+    let lines = client
+        .stream_logs()
+        .await
+        .unwrap()
+        .into_inner()
+        .with_reconnect(|_| std::future::ready(()))
+        .map(|event| event.unwrap().data.line)
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(lines, vec!["one", "two", "three"]);
+    assert_eq!(
+        *server_ctx.last_event_ids.lock().unwrap(),
+        vec![None, Some("2".to_string()), Some("3".to_string())],
+    );
+
+    server.close().await.expect("failed to close server");
+}
+
+#[endpoint {
+    method = POST,
+    path = "/records",
+}]
+async fn echo_records(
+    _rqctx: RequestContext<()>,
+    body: UntypedBody,
+) -> Result<Response<Body>, HttpError> {
+    Ok(Response::builder()
+        .status(http::StatusCode::OK)
+        .header(http::header::CONTENT_TYPE, "application/x-ndjson")
+        .body(body.as_bytes().to_vec().into())?)
+}
+
+/// Test JSON Lines request bodies and responses.
+#[tokio::test]
+async fn test_json_lines() {
+    const TEST_NAME: &str = "test_json_lines";
+
+    let mut api = ApiDescription::new();
+    api.register(echo_records).unwrap();
+
+    let mut spec = api_json(&api, TEST_NAME);
+
+    // Describe the body and response as streams of records.
+    let record = serde_json::json!({
+        "title": "Record",
+        "type": "object",
+        "properties": {
+            "id": { "type": "integer", "format": "uint32", "minimum": 0 }
+        },
+        "required": ["id"]
+    });
+    spec["paths"]["/records"]["post"]["requestBody"] = serde_json::json!({
+        "content": {
+            "application/x-ndjson": { "schema": record }
+        },
+        "required": true
+    });
+    spec["paths"]["/records"]["post"]["responses"] = serde_json::json!({
+        "200": {
+            "description": "the same records",
+            "content": {
+                "application/jsonl": { "schema": record }
             }
         }
-    }
+    });
 
-    // Ensure we got all the results we expected.
-    let expected_values = (0..35).collect::<Vec<_>>();
-    assert_eq!(expected_values, all_values);
+    let spec = serde_json::from_value::<OpenAPI>(spec).unwrap();
 
-    // Ensure the server saw the page requests we expect: we should always see a
-    // limit of 10, and we should see offsets increasing by 10 until we get to
-    // (30, 10); that will return 5 items, so we should see one final (35, 10)
-    // for the client to confirm there are no more results.
-    let expected_pages = vec![(0, 10), (10, 10), (20, 10), (30, 10), (35, 10)];
-    assert_eq!(expected_pages, *server_ctx.page_pairs.lock().unwrap());
+    assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Positional),
+        &spec,
+        &format!("{TEST_NAME}_positional"),
+    );
+    assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Builder),
+        &spec,
+        &format!("{TEST_NAME}_builder"),
+    );
 
-    // Repeat the test with the builder client.
-    server_ctx.page_pairs.lock().unwrap().clear();
+    // Run the Dropshot server.
+    let (server, server_addr) = start_server(TEST_NAME, api, ());
+
+    #[allow(dead_code)]
+    mod gen_client_positional {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_json_lines_positional.rs");
+    }
 
     #[allow(dead_code, unused_imports)]
     mod gen_client_builder {
-        // This is weird: we're now `include!`ing the file we just used to
-        // confirm the generated code is what we expect. If changes are made to
-        // progenitor that affect this generated code, keep in mind that when
-        // this test executes, the above check is against what we _currently_
-        // produce, while this `include!` is what was on disk before the test
-        // ran. This can be surprising if you're running the test with
-        // `EXPECTORATE=overwrite`, because the above check will overwrite the
-        // file on disk, but then the test proceeds and gets to this point,
-        // where it uses what was on disk _before_ expectorate overwrote it.
-        include!("output/src/test_stream_pagination_builder.rs");
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_json_lines_builder.rs");
     }
 
+    let client = gen_client_positional::Client::new(&server_addr);
+    let records = (0..100).map(|id| gen_client_positional::types::Record { id });
+    let response = client
+        .echo_records(futures::stream::iter(records))
+        .await
+        .unwrap();
+    let ids = response
+        .into_inner()
+        .map(|record| record.unwrap().id)
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(ids, (0..100).collect::<Vec<_>>());
+
     let client = gen_client_builder::Client::new(&server_addr);
+    let records = (0..3).map(|id| gen_client_builder::types::Record { id });
+    let ids = client
+        .echo_records()
+        .body(futures::stream::iter(records))
+        .send()
+        .await
+        .unwrap()
+        .into_inner()
+        .map(|record| record.unwrap().id)
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(ids, vec![0, 1, 2]);
 
-    let mut stream = client.paginated_u32s().limit(page_limit).stream();
+    // The body is required.
+    assert!(matches!(
+        client.echo_records().send().await,
+        Err(progenitor_client::Error::InvalidRequest(_))
+    ));
 
-    let mut all_values = Vec::new();
-    while let Some(result) = stream.next().await {
-        match result {
-            Ok(value) => {
-                all_values.push(value);
-            }
-            Err(err) => {
-                panic!("unexpected error: {err}");
+    server.close().await.expect("failed to close server");
+}
+
+#[derive(Debug, Default)]
+struct UploadContext {
+    content_lengths: std::sync::Mutex<Vec<Option<String>>>,
+}
+
+#[endpoint {
+    method = PUT,
+    path = "/blobs",
+}]
+async fn echo_blob(
+    rqctx: RequestContext<UploadContext>,
+    body: UntypedBody,
+) -> Result<Response<Body>, HttpError> {
+    let content_length = rqctx
+        .request
+        .headers()
+        .get(http::header::CONTENT_LENGTH)
+        .map(|value| value.to_str().unwrap().to_string());
+    rqctx
+        .context()
+        .content_lengths
+        .lock()
+        .unwrap()
+        .push(content_length);
+    Ok(Response::builder()
+        .status(http::StatusCode::OK)
+        .header(http::header::CONTENT_TYPE, "application/octet-stream")
+        .body(body.as_bytes().to_vec().into())?)
+}
+
+/// Test streaming uploads and progress reporting.
+#[tokio::test]
+async fn test_upload_body() {
+    const TEST_NAME: &str = "test_upload_body";
+
+    let mut api = ApiDescription::new();
+    api.register(echo_blob).unwrap();
+
+    let mut spec = api_json(&api, TEST_NAME);
+
+    // Describe the response as the uploaded bytes.
+    spec["paths"]["/blobs"]["put"]["responses"] = serde_json::json!({
+        "200": {
+            "description": "the same bytes",
+            "content": {
+                "application/octet-stream": {
+                    "schema": { "type": "string", "format": "binary" }
+                }
             }
         }
+    });
+
+    let spec = serde_json::from_value::<OpenAPI>(spec).unwrap();
+
+    assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Positional),
+        &spec,
+        &format!("{TEST_NAME}_positional"),
+    );
+    assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Builder),
+        &spec,
+        &format!("{TEST_NAME}_builder"),
+    );
+
+    // Run the Dropshot server.
+    let config_dropshot = ConfigDropshot {
+        bind_address: SocketAddr::from((Ipv4Addr::LOCALHOST, 0)),
+        request_body_max_bytes: 1 << 20,
+        ..Default::default()
+    };
+    let (server, server_addr) =
+        start_server_with_config(TEST_NAME, &config_dropshot, api, UploadContext::default());
+    let server_ctx = server.app_private();
+
+    #[allow(dead_code)]
+    mod gen_client_positional {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_upload_body_positional.rs");
     }
 
-    // Ensure we got all the results we expected.
-    let expected_values = (0..35).collect::<Vec<_>>();
-    assert_eq!(expected_values, all_values);
+    #[allow(dead_code, unused_imports)]
+    mod gen_client_builder {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_upload_body_builder.rs");
+    }
 
-    // Ensure the server saw the page requests we expect: we should always see a
-    // limit of 10, and we should see offsets increasing by 10 until we get to
-    // (30, 10); that will return 5 items, so we should see one final (35, 10)
-    // for the client to confirm there are no more results.
-    let expected_pages = vec![(0, 10), (10, 10), (20, 10), (30, 10), (35, 10)];
-    assert_eq!(expected_pages, *server_ctx.page_pairs.lock().unwrap());
+    let data = (0..200_000u32).map(|i| i as u8).collect::<Vec<_>>();
+    let path = std::env::temp_dir().join(format!("{TEST_NAME}-{}", std::process::id()));
+    std::fs::write(&path, &data).unwrap();
 
-    server.close().await.expect("failed to close server");
+    // Upload from a reader, reporting progress in both directions.
+    let client = gen_client_positional::Client::new(&server_addr);
+    let sent = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let progress = sent.clone();
+    let body = gen_client_positional::UploadBody::from_reader(
+        std::io::Cursor::new(data.clone()),
+        data.len() as u64,
+    )
+    .with_progress(move |sent, total| progress.lock().unwrap().push((sent, total)));
+    let received = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let progress = received.clone();
+    let response = client
+        .echo_blob(body)
+        .await
+        .unwrap()
+        .into_inner()
+        .with_progress(move |received, total| progress.lock().unwrap().push((received, total)));
+    let echoed = response
+        .into_inner()
+        .map(|chunk| chunk.unwrap())
+        .collect::<Vec<_>>()
+        .await
+        .concat();
+    assert_eq!(echoed, data);
+
+    let total = Some(data.len() as u64);
+    for reports in [&sent, &received] {
+        let reports = reports.lock().unwrap();
+        assert!(reports.len() > 1);
+        assert!(reports.windows(2).all(|w| w[0].0 <= w[1].0));
+        assert!(reports.iter().all(|(_, t)| *t == total));
+        assert_eq!(reports.last().unwrap().0, data.len() as u64);
+    }
+
+    // Upload a file by path with the builder.
+    let client = gen_client_builder::Client::new(&server_addr);
+    let response = client.echo_blob().body_file(&path).send().await.unwrap();
+    let echoed = response
+        .into_inner()
+        .into_inner()
+        .map(|chunk| chunk.unwrap())
+        .collect::<Vec<_>>()
+        .await
+        .concat();
+    assert_eq!(echoed, data);
+
+    // Bytes still work as before.
+    client.echo_blob().body("hello").send().await.unwrap();
+
+    // Progress is reported for bytes too.
+    let sent = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let progress = sent.clone();
+    let body = gen_client_builder::UploadBody::from("hello")
+        .with_progress(move |sent, total| progress.lock().unwrap().push((sent, total)));
+    client.echo_blob().body(body).send().await.unwrap();
+    assert_eq!(*sent.lock().unwrap(), vec![(5, Some(5))]);
+
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(
+        client.echo_blob().body_file(&path).send().await,
+        Err(progenitor_client::Error::InvalidRequest(_))
+    ));
+
+    // The length of every body was sent.
+    let length = data.len().to_string();
+    assert_eq!(
+        *server_ctx.content_lengths.lock().unwrap(),
+        vec![
+            Some(length.clone()),
+            Some(length),
+            Some("5".to_string()),
+            Some("5".to_string()),
+        ],
+    );
+
+    server.close().await.expect("failed to close server");
+}
+
+#[derive(Debug, Default)]
+struct DownloadContext {
+    etag: Mutex<&'static str>,
+    /// Change the ETag after each response.
+    rotate_etag: Mutex<bool>,
+    requests: Mutex<Vec<(Option<String>, Option<String>)>>,
+}
+
+const DOWNLOAD_SIZE: usize = 100_000;
+const DOWNLOAD_CHUNK: usize = 30_000;
+
+/// A response body that fails after sending some data, as if the connection
+/// had dropped.
+enum TruncatedBody {
+    Sending(hyper::body::Bytes),
+    // Fail a little later so that the data is sent first.
+    Dropping(std::pin::Pin<Box<tokio::time::Sleep>>),
+}
+
+impl hyper::body::Body for TruncatedBody {
+    type Data = hyper::body::Bytes;
+    type Error = std::io::Error;
+
+    fn poll_frame(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Result<hyper::body::Frame<Self::Data>, Self::Error>>> {
+        match &mut *self {
+            TruncatedBody::Sending(data) => {
+                let frame = hyper::body::Frame::data(std::mem::take(data));
+                *self = TruncatedBody::Dropping(Box::pin(tokio::time::sleep(
+                    std::time::Duration::from_millis(50),
+                )));
+                std::task::Poll::Ready(Some(Ok(frame)))
+            }
+            TruncatedBody::Dropping(sleep) => std::future::Future::poll(sleep.as_mut(), cx)
+                .map(|()| Some(Err(std::io::Error::other("connection dropped")))),
+        }
+    }
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct DownloadPath {
+    #[allow(dead_code)]
+    name: String,
+}
+
+#[endpoint {
+    method = GET,
+    path = "/files/{name}",
+}]
+async fn download_file(
+    rqctx: RequestContext<DownloadContext>,
+    _path: Path<DownloadPath>,
+) -> Result<Response<Body>, HttpError> {
+    let header = |name| {
+        rqctx
+            .request
+            .headers()
+            .get(name)
+            .map(|value: &http::HeaderValue| value.to_str().unwrap().to_string())
+    };
+    let range = header(http::header::RANGE);
+    let if_range = header(http::header::IF_RANGE);
+    rqctx
+        .context()
+        .requests
+        .lock()
+        .unwrap()
+        .push((range.clone(), if_range.clone()));
+
+    let etag = {
+        let mut etag = rqctx.context().etag.lock().unwrap();
+        let current = *etag;
+        if *rqctx.context().rotate_etag.lock().unwrap() {
+            *etag = if current == "\"v1\"" {
+                "\"v2\""
+            } else {
+                "\"v1\""
+            };
+        }
+        current
+    };
+    let start = match (range, if_range) {
+        (Some(range), Some(if_range)) if if_range == etag => range
+            .strip_prefix("bytes=")
+            .and_then(|range| range.strip_suffix('-'))
+            .unwrap()
+            .parse::<usize>()
+            .unwrap(),
+        _ => 0,
+    };
+
+    // Send at most one chunk before dropping the connection.
+    let data = (start..DOWNLOAD_SIZE).map(|i| i as u8).collect::<Vec<_>>();
+    let response = Response::builder()
+        .header(http::header::ETAG, etag)
+        .header(http::header::ACCEPT_RANGES, "bytes")
+        .header(
+            http::header::CONTENT_LENGTH,
+            (DOWNLOAD_SIZE - start).to_string(),
+        );
+    let response = if start > 0 {
+        response.status(http::StatusCode::PARTIAL_CONTENT).header(
+            http::header::CONTENT_RANGE,
+            format!("bytes {}-{}/{}", start, DOWNLOAD_SIZE - 1, DOWNLOAD_SIZE),
+        )
+    } else {
+        response.status(http::StatusCode::OK)
+    };
+    let body = if data.len() > DOWNLOAD_CHUNK {
+        Body::wrap(TruncatedBody::Sending(
+            data[..DOWNLOAD_CHUNK].to_vec().into(),
+        ))
+    } else {
+        data.into()
+    };
+    Ok(response.body(body)?)
+}
+
+/// Test resuming interrupted downloads with range requests.
+#[tokio::test]
+async fn test_ranged_download() {
+    const TEST_NAME: &str = "test_ranged_download";
+
+    let mut api = ApiDescription::new();
+    api.register(download_file).unwrap();
+
+    let mut spec = api_json(&api, TEST_NAME);
+
+    // Describe the full and partial responses.
+    let content = serde_json::json!({
+        "application/octet-stream": {
+            "schema": { "type": "string", "format": "binary" }
+        }
+    });
+    spec["paths"]["/files/{name}"]["get"]["responses"] = serde_json::json!({
+        "200": {
+            "description": "the file",
+            "headers": {
+                "Accept-Ranges": { "schema": { "type": "string" } }
+            },
+            "content": content
+        },
+        "206": {
+            "description": "part of the file",
+            "content": content
+        }
+    });
+
+    let spec = serde_json::from_value::<OpenAPI>(spec).unwrap();
+
+    assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Positional),
+        &spec,
+        &format!("{TEST_NAME}_positional"),
+    );
+    assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Builder),
+        &spec,
+        &format!("{TEST_NAME}_builder"),
+    );
+
+    // Run the Dropshot server.
+    let server_ctx = DownloadContext {
+        etag: Mutex::new("\"v1\""),
+        ..Default::default()
+    };
+    let (server, server_addr) = start_server(TEST_NAME, api, server_ctx);
+    let server_ctx = server.app_private();
+
+    #[allow(dead_code)]
+    mod gen_client_positional {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_ranged_download_positional.rs");
+    }
+
+    #[allow(dead_code, unused_imports)]
+    mod gen_client_builder {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_ranged_download_builder.rs");
+    }
+
+    let expected = (0..DOWNLOAD_SIZE).map(|i| i as u8).collect::<Vec<_>>();
+    let resumed = |start: usize| (Some(format!("bytes={start}-")), Some("\"v1\"".to_string()));
+
+    // A single request fails part way through.
+    let client = gen_client_positional::Client::new(&server_addr);
+    let chunks = client
+        .download_file("data")
+        .await
+        .unwrap()
+        .into_inner()
+        .into_inner()
+        .collect::<Vec<_>>()
+        .await;
+    assert!(matches!(chunks.last(), Some(Err(_))));
+    server_ctx.requests.lock().unwrap().clear();
+
+    // A download resumes each time.
+    let mut data = Vec::new();
+    let response = client
+        .download_file_download("data", &mut data)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), http::StatusCode::OK);
+    assert_eq!(response.into_inner(), DOWNLOAD_SIZE as u64);
+    assert_eq!(data, expected);
+    assert_eq!(
+        *server_ctx.requests.lock().unwrap(),
+        vec![
+            (None, None),
+            resumed(30_000),
+            resumed(60_000),
+            resumed(90_000)
+        ],
+    );
+
+    // The builder also resumes downloads.
+    let client = gen_client_builder::Client::new(&server_addr);
+    let mut data = Vec::new();
+    let response = client
+        .download_file()
+        .name("data")
+        .download(&mut data)
+        .await
+        .unwrap();
+    assert_eq!(response.into_inner(), DOWNLOAD_SIZE as u64);
+    assert_eq!(data, expected);
+    server_ctx.requests.lock().unwrap().clear();
+
+    // If the file changes part way through, the download fails rather than
+    // mixing contents.
+    *server_ctx.rotate_etag.lock().unwrap() = true;
+    let mut data = Vec::new();
+    let result = client
+        .download_file()
+        .name("data")
+        .download(&mut data)
+        .await;
+    assert!(matches!(result, Err(progenitor_client::Error::Custom(_))));
+    assert_eq!(data, expected[..DOWNLOAD_CHUNK]);
+    assert_eq!(server_ctx.requests.lock().unwrap().len(), 2);
+
+    server.close().await.expect("failed to close server");
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[allow(dead_code)]
+struct WidgetPath {
+    name: String,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[allow(dead_code)]
+struct WidgetQuery {
+    #[schemars(range(max = 100))]
+    limit: Option<u32>,
+    #[schemars(regex(pattern = r"^[a-z]+$"))]
+    label: Option<String>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[allow(dead_code)]
+struct Widget {
+    #[schemars(length(max = 2))]
+    tags: Vec<u32>,
+    #[schemars(range(min = 0.5, max = 2.5))]
+    weight: f64,
+}
+
+#[endpoint {
+    method = PUT,
+    path = "/widgets/{name}",
+}]
+async fn put_widget(
+    rqctx: RequestContext<Mutex<usize>>,
+    _path: Path<WidgetPath>,
+    _query: Query<WidgetQuery>,
+    _body: TypedBody<Widget>,
+) -> Result<HttpResponseUpdatedNoContent, HttpError> {
+    *rqctx.context().lock().unwrap() += 1;
+    Ok(HttpResponseUpdatedNoContent())
+}
+
+/// Test checking requests against schema constraints before sending them.
+#[tokio::test]
+async fn test_request_validation() {
+    const TEST_NAME: &str = "test_request_validation";
+
+    let mut api = ApiDescription::new();
+    api.register(put_widget).unwrap();
+
+    let out = serde_json::to_string_pretty(&api_json(&api, TEST_NAME)).unwrap();
+    let spec = serde_json::from_str::<OpenAPI>(&out).unwrap();
+
+    assert_generated(
+        GenerationSettings::new()
+            .with_interface(InterfaceStyle::Positional)
+            .with_request_validation(true),
+        &spec,
+        &format!("{TEST_NAME}_positional"),
+    );
+    assert_generated(
+        GenerationSettings::new()
+            .with_interface(InterfaceStyle::Builder)
+            .with_request_validation(true),
+        &spec,
+        &format!("{TEST_NAME}_builder"),
+    );
+
+    // Invalid patterns are reported when the client is generated.
+    let invalid = serde_json::from_str::<OpenAPI>(&out.replace("^[a-z]+$", "^[a-z+$")).unwrap();
+    let mut generator = Generator::new(GenerationSettings::new().with_request_validation(true));
+    let error = generator.generate_tokens(&invalid).unwrap_err();
+    assert!(error.to_string().contains("invalid pattern"), "{}", error);
+
+    // Constraints are found through an `allOf` that wraps a reference.
+    let wrapped = serde_json::from_str::<OpenAPI>(
+        r##"{
+            "openapi": "3.0.3",
+            "info": { "title": "wrapped", "version": "1.0.0" },
+            "paths": {
+                "/weight": {
+                    "put": {
+                        "operationId": "put_weight",
+                        "requestBody": {
+                            "required": true,
+                            "content": {
+                                "application/json": {
+                                    "schema": {
+                                        "type": "object",
+                                        "properties": {
+                                            "weight": {
+                                                "description": "The weight.",
+                                                "allOf": [{ "$ref": "#/components/schemas/Weight" }]
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                        "responses": { "204": { "description": "updated" } }
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Weight": { "type": "number", "maximum": 2.5 }
+                }
+            }
+        }"##,
+    )
+    .unwrap();
+    let mut generator = Generator::new(GenerationSettings::new().with_request_validation(true));
+    let output = generator.generate_tokens(&wrapped).unwrap().to_string();
+    assert!(
+        output.contains(
+            "(\"weight\" , progenitor_client :: Constraints { maximum : Some ((2.5f64 , false))"
+        ),
+        "{}",
+        output
+    );
+
+    // Run the Dropshot server.
+    let (server, server_addr) = start_server(TEST_NAME, api, Mutex::new(0));
+    let requests = server.app_private();
+
+    #[allow(dead_code)]
+    mod gen_client_positional {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_request_validation_positional.rs");
+    }
+
+    #[allow(dead_code, unused_imports)]
+    mod gen_client_builder {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_request_validation_builder.rs");
+    }
+
+    fn invalid_request<T, E>(result: Result<T, progenitor_client::Error<E>>) -> String {
+        match result {
+            Err(progenitor_client::Error::InvalidRequest(message)) => message,
+            _ => panic!("expected an invalid request"),
+        }
+    }
+
+    let client = gen_client_positional::Client::new(&server_addr);
+    let widget = gen_client_positional::types::Widget {
+        tags: vec![1, 2],
+        weight: 1.0,
+    };
+    let label = "abc".parse().unwrap();
+    client
+        .put_widget("w", Some(&label), Some(100), &widget)
+        .await
+        .unwrap();
+    assert_eq!(
+        invalid_request(client.put_widget("w", None, Some(101), &widget).await),
+        "`limit` violates `maximum = 100`"
+    );
+    let widget = gen_client_positional::types::Widget {
+        tags: vec![1, 2],
+        weight: 3.0,
+    };
+    assert_eq!(
+        invalid_request(client.put_widget("w", None, None, &widget).await),
+        "`body.weight` violates `maximum = 2.5`"
+    );
+
+    let client = gen_client_builder::Client::new(&server_addr);
+    client
+        .put_widget()
+        .name("w")
+        .body_map(|body| body.tags(vec![]).weight(0.5))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(
+        invalid_request(
+            client
+                .put_widget()
+                .name("w")
+                .body_map(|body| body.tags(vec![1, 2, 3]).weight(0.5))
+                .send()
+                .await
+        ),
+        "`body.tags` violates `maxItems = 2`"
+    );
+
+    // Invalid requests were never sent.
+    assert_eq!(*requests.lock().unwrap(), 2);
+
+    server.close().await.expect("failed to close server");
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[allow(dead_code)]
+struct GadgetPath {
+    name: String,
+}
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+enum GadgetKind {
+    Sprocket,
+    Widget,
+}
+
+#[derive(Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct Gadget {
+    name: String,
+    kind: GadgetKind,
+}
+
+#[endpoint {
+    method = GET,
+    path = "/gadgets/{name}",
+}]
+async fn get_gadget(
+    _rqctx: RequestContext<()>,
+    _path: Path<GadgetPath>,
+) -> Result<HttpResponseOk<Gadget>, HttpError> {
+    unreachable!();
+}
+
+/// Serves `/gadgets/{name}` with responses that drift from the API
+/// description generated from `get_gadget`.
+#[endpoint {
+    method = GET,
+    path = "/gadgets/{name}",
+}]
+async fn serve_gadget(
+    _rqctx: RequestContext<()>,
+    path: Path<GadgetPath>,
+) -> Result<Response<Body>, HttpError> {
+    let (status, body) = match path.into_inner().name.as_str() {
+        "good" => (200, r#"{"name":"good","kind":"sprocket"}"#),
+        "drifted" => (200, r#"{"kind":"gizmo","color":"red"}"#),
+        "missing" => (404, r#"{"message":"no such gadget"}"#),
+        _ => (202, ""),
+    };
+    Ok(Response::builder()
+        .status(status)
+        .header(http::header::CONTENT_TYPE, "application/json")
+        .body(body.to_string().into())?)
+}
+
+/// Test reporting differences between responses and the API description.
+#[tokio::test]
+async fn test_response_checking() {
+    const TEST_NAME: &str = "test_response_checking";
+
+    let mut api = ApiDescription::new();
+    api.register(get_gadget).unwrap();
+
+    let spec = api_spec(&api, TEST_NAME);
+
+    assert_generated(
+        GenerationSettings::new()
+            .with_interface(InterfaceStyle::Positional)
+            .with_response_checking(true),
+        &spec,
+        &format!("{TEST_NAME}_positional"),
+    );
+    assert_generated(
+        GenerationSettings::new()
+            .with_interface(InterfaceStyle::Builder)
+            .with_response_checking(true),
+        &spec,
+        &format!("{TEST_NAME}_builder"),
+    );
+
+    // Run the Dropshot server.
+    let mut api = ApiDescription::new();
+    api.register(serve_gadget).unwrap();
+    let (server, server_addr) = start_server(TEST_NAME, api, ());
+
+    #[allow(dead_code)]
+    mod gen_client_positional {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_response_checking_positional.rs");
+    }
+
+    #[allow(dead_code, unused_imports)]
+    mod gen_client_builder {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_response_checking_builder.rs");
+    }
+
+    use progenitor_client::{ClientHooks, ContractViolation, OperationInfo};
+
+    static VIOLATIONS: Mutex<Vec<(u16, ContractViolation)>> = Mutex::new(Vec::new());
+
+    impl ClientHooks for gen_client_positional::Client {
+        fn contract_violation(
+            &self,
+            status: reqwest::StatusCode,
+            violation: &ContractViolation,
+            info: &OperationInfo,
+        ) {
+            assert_eq!(info.operation_id, "get_gadget");
+            VIOLATIONS
+                .lock()
+                .unwrap()
+                .push((status.as_u16(), violation.clone()));
+        }
+    }
+
+    impl ClientHooks for gen_client_builder::Client {
+        fn contract_violation(
+            &self,
+            status: reqwest::StatusCode,
+            violation: &ContractViolation,
+            _info: &OperationInfo,
+        ) {
+            VIOLATIONS
+                .lock()
+                .unwrap()
+                .push((status.as_u16(), violation.clone()));
+        }
+    }
+
+    let take = || std::mem::take(&mut *VIOLATIONS.lock().unwrap());
+
+    let client = gen_client_positional::Client::new(&server_addr);
+    client.get_gadget("good").await.unwrap();
+    assert_eq!(take(), vec![]);
+
+    // Both the missing property and the unknown variant also prevent the body
+    // from being decoded.
+    assert!(matches!(
+        client.get_gadget("drifted").await,
+        Err(progenitor_client::Error::InvalidResponsePayload(..))
+    ));
+    assert_eq!(
+        take(),
+        vec![
+            (
+                200,
+                ContractViolation::MissingProperty {
+                    path: "body".to_string(),
+                    property: "name".to_string(),
+                }
+            ),
+            (
+                200,
+                ContractViolation::ExtraProperty {
+                    path: "body".to_string(),
+                    property: "color".to_string(),
+                }
+            ),
+            (
+                200,
+                ContractViolation::UnknownEnumValue {
+                    path: "body.kind".to_string(),
+                    value: "gizmo".into(),
+                }
+            ),
+        ]
+    );
+
+    let client = gen_client_builder::Client::new(&server_addr);
+    assert!(client.get_gadget().name("missing").send().await.is_err());
+    assert_eq!(
+        take(),
+        vec![(
+            404,
+            ContractViolation::MissingProperty {
+                path: "body".to_string(),
+                property: "request_id".to_string(),
+            }
+        )]
+    );
+    assert!(matches!(
+        client.get_gadget().name("other").send().await,
+        Err(progenitor_client::Error::UnexpectedResponse(_))
+    ));
+    assert_eq!(take(), vec![(202, ContractViolation::UndeclaredStatus)]);
+
+    server.close().await.expect("failed to close server");
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[allow(dead_code)]
+struct NotePath {
+    id: u32,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct NoteQuery {
+    visibility: String,
+    pinned: Option<bool>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct Note {
+    text: String,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct NoteRecord {
+    id: u32,
+    visibility: String,
+    pinned: bool,
+    text: String,
+}
+
+#[endpoint {
+    method = PUT,
+    path = "/notes/{id}",
+}]
+async fn put_note(
+    _rqctx: RequestContext<()>,
+    path: Path<NotePath>,
+    query: Query<NoteQuery>,
+    body: TypedBody<Note>,
+) -> Result<HttpResponseOk<NoteRecord>, HttpError> {
+    let query = query.into_inner();
+    Ok(HttpResponseOk(NoteRecord {
+        id: path.into_inner().id,
+        visibility: query.visibility,
+        pinned: query.pinned.unwrap_or_default(),
+        text: body.into_inner().text,
+    }))
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[allow(dead_code)]
+struct FolderPath {
+    folder: String,
+}
+
+#[endpoint {
+    method = GET,
+    path = "/folders/{folder}/notes",
+}]
+async fn list_folder_notes(
+    _rqctx: RequestContext<()>,
+    _path: Path<FolderPath>,
+    query: Query<PaginationParams<EmptyScanParams, u32>>,
+) -> Result<HttpResponseOk<ResultsPage<u32>>, HttpError> {
+    let values = match query.into_inner().page {
+        dropshot::WhichPage::First(EmptyScanParams {}) => vec![1, 2, 3],
+        dropshot::WhichPage::Next(_) => vec![],
+    };
+    Ok(HttpResponseOk(
+        ResultsPage::new(values, &(), |&x, &()| x).expect("bad results page"),
+    ))
+}
+
+/// Test builders that require all required parameters to be set before
+/// `send()` is available.
+#[tokio::test]
+async fn test_typestate_builders() {
+    const TEST_NAME: &str = "test_typestate_builders";
+
+    let mut api = ApiDescription::new();
+    api.register(put_note).unwrap();
+    api.register(list_folder_notes).unwrap();
+
+    let spec = api_spec(&api, TEST_NAME);
+
+    assert_generated(
+        GenerationSettings::new()
+            .with_interface(InterfaceStyle::Builder)
+            .with_typestate_builders(true),
+        &spec,
+        TEST_NAME,
+    );
+
+    // Run the Dropshot server.
+    let (server, server_addr) = start_server(TEST_NAME, api, ());
+
+    #[allow(dead_code, unused_imports)]
+    mod gen_client {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_typestate_builders.rs");
+    }
+
+    use progenitor_client::{Missing, Provided};
+
+    let client = gen_client::Client::new(&server_addr);
+
+    // Each required parameter moves its state to `Provided`, in any order;
+    // optional parameters leave it unchanged.
+    let builder: gen_client::builder::PutNote<'_, Missing, Missing, Missing> = client.put_note();
+    let builder: gen_client::builder::PutNote<'_, Missing, Provided, Missing> =
+        builder.pinned(true).visibility("private");
+    let builder = builder.body_map(|body| body.text("hello")).id(7u32);
+    let note = builder.send().await.unwrap().into_inner();
+    assert_eq!(note.id, 7);
+    assert_eq!(note.visibility, "private");
+    assert!(note.pinned);
+    assert_eq!(note.text, "hello");
+
+    // Conversion errors are still reported at runtime.
+    let result = client
+        .put_note()
+        .id(-1i64)
+        .visibility("public")
+        .body(gen_client::types::Note {
+            text: "x".to_string(),
+        })
+        .send()
+        .await;
+    assert!(matches!(
+        result,
+        Err(progenitor_client::Error::InvalidRequest(_))
+    ));
+
+    let notes = client
+        .list_folder_notes()
+        .folder("inbox")
+        .stream()
+        .map(Result::unwrap)
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(notes, vec![1, 2, 3]);
+
+    server.close().await.expect("failed to close server");
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[allow(dead_code)]
+struct EchoQuery {
+    verbose: Option<bool>,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct EchoedRequest {
+    query: Option<String>,
+    request_id: Option<String>,
+    tag: Option<String>,
+}
+
+#[endpoint {
+    method = GET,
+    path = "/echo",
+}]
+async fn echo_request(
+    rqctx: RequestContext<()>,
+    _query: Query<EchoQuery>,
+) -> Result<HttpResponseOk<EchoedRequest>, HttpError> {
+    let header = |name: &str| {
+        rqctx
+            .request
+            .headers()
+            .get(name)
+            .map(|value| value.to_str().unwrap().to_string())
+    };
+    Ok(HttpResponseOk(EchoedRequest {
+        query: rqctx.request.uri().query().map(str::to_string),
+        request_id: header("x-request-id"),
+        tag: header("x-tag"),
+    }))
+}
+
+/// Test additional headers, query parameters, and context for individual
+/// requests.
+#[tokio::test]
+async fn test_request_options() {
+    const TEST_NAME: &str = "test_request_options";
+
+    let mut api = ApiDescription::new();
+    api.register(echo_request).unwrap();
+
+    let spec = api_spec(&api, TEST_NAME);
+
+    assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Positional),
+        &spec,
+        &format!("{TEST_NAME}_positional"),
+    );
+    assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Builder),
+        &spec,
+        &format!("{TEST_NAME}_builder"),
+    );
+
+    // Run the Dropshot server.
+    let (server, server_addr) = start_server(TEST_NAME, api, ());
+
+    #[allow(dead_code)]
+    mod gen_client_positional {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_request_options_positional.rs");
+    }
+
+    #[allow(dead_code, unused_imports)]
+    mod gen_client_builder {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_request_options_builder.rs");
+    }
+
+    use progenitor_client::{ClientHooks, OperationInfo, RequestOptions};
+
+    struct Tag(&'static str);
+
+    // Hooks see the context of each request.
+    impl ClientHooks for gen_client_builder::Client {
+        async fn pre<E>(
+            &self,
+            request: &mut reqwest::Request,
+            info: &OperationInfo,
+        ) -> Result<(), progenitor_client::Error<E>> {
+            if let Some(Tag(tag)) = info.context::<Tag>() {
+                request.headers_mut().insert("x-tag", tag.parse().unwrap());
+            }
+            Ok(())
+        }
+    }
+
+    let client = gen_client_positional::Client::new(&server_addr);
+    let echoed = client.echo_request(Some(true)).await.unwrap().into_inner();
+    assert_eq!(echoed.query.as_deref(), Some("verbose=true"));
+    assert_eq!(echoed.request_id, None);
+
+    let echoed = client
+        .echo_request_with_options(
+            Some(true),
+            RequestOptions::new()
+                .header("x-request-id", "abc")
+                .query("trace", 1),
+        )
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(echoed.query.as_deref(), Some("verbose=true&trace=1"));
+    assert_eq!(echoed.request_id.as_deref(), Some("abc"));
+
+    let result = client
+        .echo_request_with_options(None, RequestOptions::new().header("x-request-id", "\n"))
+        .await;
+    assert!(matches!(
+        result,
+        Err(progenitor_client::Error::InvalidRequest(_))
+    ));
+
+    let client = gen_client_builder::Client::new(&server_addr);
+    let echoed = client
+        .echo_request()
+        .with_header("x-request-id", "def")
+        .with_query("trace", "2")
+        .with_context(Tag("blue"))
+        .send()
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(echoed.query.as_deref(), Some("trace=2"));
+    assert_eq!(echoed.request_id.as_deref(), Some("def"));
+    assert_eq!(echoed.tag.as_deref(), Some("blue"));
+
+    let echoed = client
+        .echo_request()
+        .with_options(RequestOptions::new().header("x-request-id", "ghi"))
+        .send()
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(echoed.request_id.as_deref(), Some("ghi"));
+    assert_eq!(echoed.tag, None);
+
+    server.close().await.expect("failed to close server");
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[allow(dead_code)]
+struct ItemPath {
+    id: u32,
+}
+
+#[endpoint {
+    method = GET,
+    path = "/items/{id}",
+    tags = ["items"],
+    deprecated = true,
+}]
+async fn get_item(
+    _rqctx: RequestContext<()>,
+    path: Path<ItemPath>,
+) -> Result<HttpResponseOk<u32>, HttpError> {
+    Ok(HttpResponseOk(path.into_inner().id))
+}
+
+#[endpoint {
+    method = POST,
+    path = "/items",
+    tags = ["items", "admin"],
+}]
+async fn create_item(
+    _rqctx: RequestContext<()>,
+    body: TypedBody<u32>,
+) -> Result<HttpResponseCreated<u32>, HttpError> {
+    Ok(HttpResponseCreated(body.into_inner()))
+}
+
+/// Test the operation metadata available to hooks and in
+/// `Client::OPERATIONS`.
+#[tokio::test]
+async fn test_operation_metadata() {
+    const TEST_NAME: &str = "test_operation_metadata";
+
+    let mut api = ApiDescription::new();
+    api.register(get_item).unwrap();
+    api.register(create_item).unwrap();
+
+    let spec = api_spec(&api, TEST_NAME);
+
+    assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Positional),
+        &spec,
+        TEST_NAME,
+    );
+
+    // Run the Dropshot server.
+    let (server, server_addr) = start_server(TEST_NAME, api, ());
+
+    #[allow(dead_code)]
+    mod gen_client {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_operation_metadata.rs");
+    }
+
+    use progenitor_client::{ClientHooks, OperationInfo};
+
+    static SEEN: Mutex<Vec<(&str, String, bool)>> = Mutex::new(Vec::new());
+
+    impl ClientHooks for gen_client::Client {
+        async fn post<E>(
+            &self,
+            result: &reqwest::Result<reqwest::Response>,
+            info: &OperationInfo,
+        ) -> Result<(), progenitor_client::Error<E>> {
+            let status = result.as_ref().unwrap().status();
+            SEEN.lock().unwrap().push((
+                info.metadata.path,
+                info.metadata.method.to_string(),
+                info.metadata.is_success(status),
+            ));
+            Ok(())
+        }
+    }
+
+    let operations = gen_client::Client::OPERATIONS;
+    assert_eq!(
+        operations
+            .iter()
+            .map(|operation| operation.operation_id)
+            .collect::<Vec<_>>(),
+        vec!["create_item", "get_item"],
+    );
+    let create = &operations[0];
+    assert_eq!(create.method, reqwest::Method::POST);
+    assert_eq!(create.path, "/items");
+    assert_eq!(create.tags, ["items", "admin"]);
+    assert!(!create.idempotent);
+    assert!(!create.deprecated);
+    assert_eq!(create.success_statuses, [201..=201]);
+    let get = &operations[1];
+    assert_eq!(get.path, "/items/{id}");
+    assert!(get.idempotent);
+    assert!(get.deprecated);
+
+    // A default response may as well describe an error, so it isn't counted
+    // as a success.
+    let default_only = serde_json::from_value::<OpenAPI>(serde_json::json!({
+        "openapi": "3.0.0",
+        "info": { "title": "default", "version": "1.0.0" },
+        "paths": {
+            "/ping": {
+                "get": {
+                    "operationId": "ping",
+                    "responses": { "default": { "description": "any" } }
+                }
+            }
+        }
+    }))
+    .unwrap();
+    let output = generate_formatted(&mut Generator::default(), &default_only);
+    assert!(output.contains("success_statuses: &[],"));
+
+    let client = gen_client::Client::new(&server_addr);
+    client.get_item(3).await.unwrap();
+    client.create_item(4).await.unwrap();
+    assert_eq!(
+        *SEEN.lock().unwrap(),
+        vec![
+            ("/items/{id}", "GET".to_string(), true),
+            ("/items", "POST".to_string(), true),
+        ]
+    );
+
+    server.close().await.expect("failed to close server");
+}
+
+#[derive(Serialize, JsonSchema)]
+struct Measurement {
+    value: u32,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct MeasurementList {
+    #[serde(rename = "data")]
+    measurements: Vec<Measurement>,
+    source: String,
+}
+
+#[endpoint {
+    method = GET,
+    path = "/measurements",
+}]
+async fn list_measurements(
+    _rqctx: RequestContext<()>,
+) -> Result<HttpResponseOk<Vec<Measurement>>, HttpError> {
+    Ok(HttpResponseOk(
+        (0..1000).map(|value| Measurement { value }).collect(),
+    ))
+}
+
+#[endpoint {
+    method = GET,
+    path = "/measurements/labeled",
+}]
+async fn list_labeled_measurements(
+    _rqctx: RequestContext<()>,
+) -> Result<HttpResponseOk<MeasurementList>, HttpError> {
+    Ok(HttpResponseOk(MeasurementList {
+        measurements: (0..3).map(|value| Measurement { value }).collect(),
+        source: "sensor".to_string(),
+    }))
+}
+
+/// Test incremental decoding of array responses.
+#[tokio::test]
+async fn test_array_streaming() {
+    const TEST_NAME: &str = "test_array_streaming";
+
+    let mut api = ApiDescription::new();
+    api.register(list_measurements).unwrap();
+    api.register(list_labeled_measurements).unwrap();
+
+    let spec = api_spec(&api, TEST_NAME);
+
+    assert_generated(
+        GenerationSettings::new()
+            .with_interface(InterfaceStyle::Positional)
+            .with_array_streaming(true),
+        &spec,
+        &format!("{TEST_NAME}_positional"),
+    );
+
+    // Run the Dropshot server.
+    let (server, server_addr) = start_server(TEST_NAME, api, ());
+
+    #[allow(dead_code)]
+    mod gen_client_positional {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_array_streaming_positional.rs");
+    }
+
+    let client = gen_client_positional::Client::new(&server_addr);
+
+    let values = client
+        .list_measurements()
+        .await
+        .unwrap()
+        .into_inner()
+        .map(|measurement| measurement.unwrap().value)
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(values, (0..1000).collect::<Vec<_>>());
+
+    let mut measurements = client
+        .list_labeled_measurements()
+        .await
+        .unwrap()
+        .into_inner();
+    let values = (&mut measurements)
+        .map(|measurement| measurement.unwrap().value)
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(values, vec![0, 1, 2]);
+    assert_eq!(
+        measurements.other_fields().get("source"),
+        Some(&serde_json::json!("sensor"))
+    );
+
+    // The body size limit ends the stream with an error.
+    let results = client
+        .list_measurements()
+        .await
+        .unwrap()
+        .into_inner()
+        .with_max_body_size(1000)
+        .collect::<Vec<_>>()
+        .await;
+    assert!(matches!(
+        results.last(),
+        Some(Err(progenitor_client::Error::ResponseBodyTooLarge(1000)))
+    ));
+
+    server.close().await.expect("failed to close server");
+}
+
+#[derive(Debug)]
+struct PaginatedU32sContext {
+    all_values: std::ops::Range<u32>,
+    // Record of `(offset, limit)` pairs we received
+    page_pairs: Mutex<Vec<(usize, usize)>>,
+}
+
+#[endpoint {
+    method = GET,
+    path = "/",
+}]
+async fn paginated_u32s(
+    rqctx: RequestContext<Arc<PaginatedU32sContext>>,
+    query_params: Query<PaginationParams<EmptyScanParams, u32>>,
+) -> Result<HttpResponseOk<ResultsPage<u32>>, HttpError> {
+    let ctx = rqctx.context();
+    let page_params = query_params.into_inner();
+    let limit = usize::try_from(
+        rqctx
+            .page_limit(&page_params)
+            .expect("invalid page limit")
+            .get(),
+    )
+    .expect("non-usize limit");
+
+    let offset = match page_params.page {
+        dropshot::WhichPage::First(EmptyScanParams {}) => 0,
+        dropshot::WhichPage::Next(offset) => usize::try_from(offset + 1).expect("non-usize offset"),
+    };
+
+    ctx.page_pairs.lock().unwrap().push((offset, limit));
+    let values = ctx.all_values.clone().skip(offset).take(limit).collect();
+    let result = ResultsPage::new(values, &(), |&x, &()| x).expect("bad results page");
+
+    Ok(HttpResponseOk(result))
+}
+
+#[tokio::test]
+async fn test_stream_pagination() {
+    const TEST_NAME: &str = "test_stream_pagination";
+
+    let mut api = ApiDescription::new();
+    api.register(paginated_u32s).unwrap();
+
+    let spec = api_spec(&api, TEST_NAME);
+
+    // Test both interface styles.
+    assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Positional),
+        &spec,
+        &format!("{TEST_NAME}_positional"),
+    );
+    assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Builder),
+        &spec,
+        &format!("{TEST_NAME}_builder"),
+    );
+
+    // Run the Dropshot server.
+    let server_ctx = Arc::new(PaginatedU32sContext {
+        all_values: 0..35,
+        page_pairs: Mutex::default(),
+    });
+    let (server, server_addr) = start_server(TEST_NAME, api, Arc::clone(&server_ctx));
+
+    // Test the positional client.
+    #[allow(dead_code)]
+    mod gen_client_positional {
+        // This is weird: we're now `include!`ing the file we just used to
+        // confirm the generated code is what we expect. If changes are made to
+        // progenitor that affect this generated code, keep in mind that when
+        // this test executes, the above check is against what we _currently_
+        // produce, while this `include!` is what was on disk before the test
+        // ran. This can be surprising if you're running the test with
+        // `EXPECTORATE=overwrite`, because the above check will overwrite the
+        // file on disk, but then the test proceeds and gets to this point,
+        // where it uses what was on disk _before_ expectorate overwrote it.
+        include!("output/src/test_stream_pagination_positional.rs");
+    }
+
+    let client = gen_client_positional::Client::new(&server_addr);
+
+    let page_limit = 10.try_into().unwrap();
+    let mut stream = client.paginated_u32s_stream(Some(page_limit));
+
+    let mut all_values = Vec::new();
+    while let Some(result) = stream.next().await {
+        match result {
+            Ok(value) => {
+                all_values.push(value);
+            }
+            Err(err) => {
+                panic!("unexpected error: {err}");
+            }
+        }
+    }
+
+    // Ensure we got all the results we expected.
+    let expected_values = (0..35).collect::<Vec<_>>();
+    assert_eq!(expected_values, all_values);
+
+    // Ensure the server saw the page requests we expect: we should always see a
+    // limit of 10, and we should see offsets increasing by 10 until we get to
+    // (30, 10); that will return 5 items, so we should see one final (35, 10)
+    // for the client to confirm there are no more results.
+    let expected_pages = vec![(0, 10), (10, 10), (20, 10), (30, 10), (35, 10)];
+    assert_eq!(expected_pages, *server_ctx.page_pairs.lock().unwrap());
+
+    // Repeat the test with the builder client.
+    server_ctx.page_pairs.lock().unwrap().clear();
+
+    #[allow(dead_code, unused_imports)]
+    mod gen_client_builder {
+        // This is weird: we're now `include!`ing the file we just used to
+        // confirm the generated code is what we expect. If changes are made to
+        // progenitor that affect this generated code, keep in mind that when
+        // this test executes, the above check is against what we _currently_
+        // produce, while this `include!` is what was on disk before the test
+        // ran. This can be surprising if you're running the test with
+        // `EXPECTORATE=overwrite`, because the above check will overwrite the
+        // file on disk, but then the test proceeds and gets to this point,
+        // where it uses what was on disk _before_ expectorate overwrote it.
+        include!("output/src/test_stream_pagination_builder.rs");
+    }
+
+    let client = gen_client_builder::Client::new(&server_addr);
+
+    let mut stream = client.paginated_u32s().limit(page_limit).stream();
+
+    let mut all_values = Vec::new();
+    while let Some(result) = stream.next().await {
+        match result {
+            Ok(value) => {
+                all_values.push(value);
+            }
+            Err(err) => {
+                panic!("unexpected error: {err}");
+            }
+        }
+    }
+
+    // Ensure we got all the results we expected.
+    let expected_values = (0..35).collect::<Vec<_>>();
+    assert_eq!(expected_values, all_values);
+
+    // Ensure the server saw the page requests we expect: we should always see a
+    // limit of 10, and we should see offsets increasing by 10 until we get to
+    // (30, 10); that will return 5 items, so we should see one final (35, 10)
+    // for the client to confirm there are no more results.
+    let expected_pages = vec![(0, 10), (10, 10), (20, 10), (30, 10), (35, 10)];
+    assert_eq!(expected_pages, *server_ctx.page_pairs.lock().unwrap());
+
+    server.close().await.expect("failed to close server");
+}
+
+/// Test that middleware attached to a client runs in order around each
+/// request, and that its errors surface as the client's `Error`.
+#[tokio::test]
+async fn test_middleware() {
+    const TEST_NAME: &str = "test_middleware";
+
+    let mut api = ApiDescription::new();
+    api.register(echo_request).unwrap();
+
+    let spec = api_spec(&api, TEST_NAME);
+
+    assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Builder),
+        &spec,
+        &format!("{TEST_NAME}_builder"),
+    );
+
+    // Run the Dropshot server.
+    let (server, server_addr) = start_server(TEST_NAME, api, ());
+
+    #[allow(dead_code, unused_imports)]
+    mod gen_client_builder {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_middleware_builder.rs");
+    }
+
+    use progenitor_client::{Error, Middleware, MiddlewareFuture, Next, OperationInfo};
+
+    type Log = Arc<Mutex<Vec<String>>>;
+
+    // Records its name before and after the rest of the chain.
+    struct Record(&'static str, Log);
+
+    impl Middleware for Record {
+        fn handle<'a>(
+            &'a self,
+            request: reqwest::Request,
+            info: &'a OperationInfo,
+            next: Next<'a>,
+        ) -> MiddlewareFuture<'a> {
+            Box::pin(async move {
+                let Record(name, log) = self;
+                log.lock()
+                    .unwrap()
+                    .push(format!("{name} {}", info.operation_id));
+                let result = next.run(request).await;
+                log.lock().unwrap().push(format!("{name} done"));
+                result
+            })
+        }
+    }
+
+    // Stamps each request, or rejects it without sending it.
+    struct Stamp(Option<&'static str>);
+
+    impl Middleware for Stamp {
+        fn handle<'a>(
+            &'a self,
+            mut request: reqwest::Request,
+            _info: &'a OperationInfo,
+            next: Next<'a>,
+        ) -> MiddlewareFuture<'a> {
+            Box::pin(async move {
+                let Some(id) = self.0 else {
+                    return Err(Error::Custom("rejected".to_string()));
+                };
+                request
+                    .headers_mut()
+                    .insert("x-request-id", id.parse().unwrap());
+                next.run(request).await
+            })
+        }
+    }
+
+    let log = Log::default();
+    let client = gen_client_builder::Client::new(&server_addr)
+        .with_middleware(Record("outer", log.clone()))
+        .with_middleware(Stamp(Some("stamped")))
+        .with_middleware(Record("inner", log.clone()));
+    let echoed = client.echo_request().send().await.unwrap().into_inner();
+    assert_eq!(echoed.request_id.as_deref(), Some("stamped"));
+    assert_eq!(
+        *log.lock().unwrap(),
+        [
+            "outer echo_request",
+            "inner echo_request",
+            "inner done",
+            "outer done",
+        ]
+    );
+
+    let log = Log::default();
+    let client = gen_client_builder::Client::new(&server_addr)
+        .with_middleware(Stamp(None))
+        .with_middleware(Record("inner", log.clone()));
+    let result = client.echo_request().send().await;
+    assert!(matches!(result, Err(Error::Custom(message)) if message == "rejected"));
+    assert!(log.lock().unwrap().is_empty());
+
+    server.close().await.expect("failed to close server");
+}
+
+#[endpoint {
+    method = GET,
+    path = "/traced",
+}]
+async fn traced_request(
+    rqctx: RequestContext<()>,
+) -> Result<HttpResponseOk<Option<String>>, HttpError> {
+    Ok(HttpResponseOk(
+        rqctx
+            .request
+            .headers()
+            .get("traceparent")
+            .map(|value| value.to_str().unwrap().to_string()),
+    ))
+}
+
+/// Test the spans, `traceparent` headers, and metrics produced with the
+/// `tracing` and `metrics` features of progenitor-client.
+#[tokio::test]
+async fn test_telemetry() {
+    const TEST_NAME: &str = "test_telemetry";
+
+    let mut api = ApiDescription::new();
+    api.register(traced_request).unwrap();
+
+    let spec = api_spec(&api, TEST_NAME);
+
+    assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Positional),
+        &spec,
+        TEST_NAME,
+    );
+
+    // Run the Dropshot server.
+    let (server, server_addr) = start_server(TEST_NAME, api, ());
+
+    #[allow(dead_code)]
+    mod gen_client {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_telemetry.rs");
+    }
+
+    use progenitor_client::{Middleware, MiddlewareFuture, Next, OperationInfo, RequestOptions};
+    use tracing_subscriber::layer::SubscriberExt;
+
+    type Fields = Vec<(String, String)>;
+
+    // Records the id and fields of each operation span.
+    #[derive(Clone, Default)]
+    struct Spans(Arc<Mutex<Vec<(u64, Fields)>>>);
+
+    struct Visitor<'a>(&'a mut Fields);
+
+    impl tracing::field::Visit for Visitor<'_> {
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .push((field.name().to_string(), format!("{value:?}")));
+        }
+    }
+
+    impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for Spans {
+        fn on_new_span(
+            &self,
+            attrs: &tracing::span::Attributes<'_>,
+            id: &tracing::span::Id,
+            _ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            if attrs.metadata().name() == "progenitor_client.operation" {
+                let mut fields = Vec::new();
+                attrs.record(&mut Visitor(&mut fields));
+                self.0.lock().unwrap().push((id.into_u64(), fields));
+            }
+        }
+
+        fn on_record(
+            &self,
+            id: &tracing::span::Id,
+            values: &tracing::span::Record<'_>,
+            _ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let mut spans = self.0.lock().unwrap();
+            if let Some((_, fields)) = spans.iter_mut().find(|(span, _)| *span == id.into_u64()) {
+                values.record(&mut Visitor(fields));
+            }
+        }
+    }
+
+    impl Spans {
+        fn field(&self, index: usize, name: &str) -> Option<String> {
+            let spans = self.0.lock().unwrap();
+            spans[index]
+                .1
+                .iter()
+                .find(|(field, _)| field == name)
+                .map(|(_, value)| value.clone())
+        }
+    }
+
+    // Sends each request twice.
+    struct Retry;
+
+    impl Middleware for Retry {
+        fn handle<'a>(
+            &'a self,
+            request: reqwest::Request,
+            _info: &'a OperationInfo,
+            next: Next<'a>,
+        ) -> MiddlewareFuture<'a> {
+            Box::pin(async move {
+                next.run(request.try_clone().unwrap()).await?;
+                next.run(request).await
+            })
+        }
+    }
+
+    let recorder = metrics_util::debugging::DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    metrics::set_global_recorder(recorder).unwrap();
+
+    let spans = Spans::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(spans.clone()));
+
+    let client = gen_client::Client::new(&server_addr);
+    let traceparent = client.traced_request().await.unwrap().into_inner().unwrap();
+    let parts = traceparent.split('-').collect::<Vec<_>>();
+    assert_eq!(parts.len(), 4);
+    assert_eq!(parts[0], "00");
+    assert_eq!(parts[1].len(), 32);
+    assert_eq!(parts[3], "01");
+
+    assert_ne!(parts[1], "0".repeat(32));
+    assert_eq!(spans.field(0, "trace_id"), Some(format!("{:?}", parts[1])));
+    assert_eq!(spans.field(0, "span_id"), Some(format!("{:?}", parts[2])));
+    assert_eq!(
+        spans.field(0, "operation_id").as_deref(),
+        Some("\"traced_request\"")
+    );
+    assert_eq!(
+        spans.field(0, "http.request.method").as_deref(),
+        Some("GET")
+    );
+    assert_eq!(spans.field(0, "http.route").as_deref(), Some("\"/traced\""));
+    assert_eq!(
+        spans.field(0, "http.response.status_code").as_deref(),
+        Some("200")
+    );
+    assert_eq!(spans.field(0, "retry_count").as_deref(), Some("0"));
+
+    // An existing trace continues with the client's span as the parent.
+    let traceparent = client
+        .traced_request_with_options(RequestOptions::new().header(
+            "traceparent",
+            "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01",
+        ))
+        .await
+        .unwrap()
+        .into_inner()
+        .unwrap();
+    let span_id = spans.field(1, "span_id").unwrap();
+    assert_eq!(
+        traceparent,
+        format!(
+            "00-0af7651916cd43dd8448eb211c80319c-{}-01",
+            span_id.trim_matches('"')
+        )
+    );
+
+    let client = gen_client::Client::new(&server_addr).with_middleware(Retry);
+    client.traced_request().await.unwrap();
+    assert_eq!(spans.field(2, "retry_count").as_deref(), Some("1"));
+
+    let metrics = snapshotter
+        .snapshot()
+        .into_vec()
+        .into_iter()
+        .filter(|(key, _, _, _)| {
+            key.key()
+                .labels()
+                .any(|label| label.key() == "operation_id" && label.value() == "traced_request")
+        })
+        .map(|(key, _, _, value)| (key.key().name().to_string(), value))
+        .collect::<Vec<_>>();
+    let value = |name: &str| {
+        metrics
+            .iter()
+            .find(|(metric, _)| metric == name)
+            .map(|(_, value)| value)
+    };
+    use metrics_util::debugging::DebugValue;
+    assert_eq!(
+        value("progenitor_client_requests_total"),
+        Some(&DebugValue::Counter(3))
+    );
+    assert_eq!(
+        value("progenitor_client_retries_total"),
+        Some(&DebugValue::Counter(1))
+    );
+    assert!(matches!(
+        value("progenitor_client_request_duration_seconds"),
+        Some(DebugValue::Histogram(samples)) if samples.len() == 3
+    ));
+    assert_eq!(value("progenitor_client_errors_total"), None);
+
+    server.close().await.expect("failed to close server");
+}
+
+/// Test recording interactions with a server to a cassette and replaying
+/// them without it.
+#[tokio::test]
+async fn test_cassette() {
+    const TEST_NAME: &str = "test_cassette";
+
+    let mut api = ApiDescription::new();
+    api.register(put_note).unwrap();
+
+    let spec = api_spec(&api, TEST_NAME);
+
+    assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Positional),
+        &spec,
+        TEST_NAME,
+    );
+
+    // Run the Dropshot server.
+    let (server, server_addr) = start_server(TEST_NAME, api, ());
+
+    #[allow(dead_code)]
+    mod gen_client {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_cassette.rs");
+    }
+
+    use gen_client::types::Note;
+    use progenitor_client::{Cassette, CassetteMatch, Error, RequestOptions};
+
+    let path = std::env::temp_dir().join(format!(
+        "progenitor-{TEST_NAME}-{}.json",
+        std::process::id()
+    ));
+    let note = |text: &str| Note {
+        text: text.to_string(),
+    };
+    let secret = RequestOptions::new().header("authorization", "Bearer secret");
+
+    let cassette = Cassette::record(&path);
+    let client = gen_client::Client::new(&server_addr).with_middleware(cassette.clone());
+    let first = client
+        .put_note_with_options(1, None, "public", &note("first"), secret.clone())
+        .await
+        .unwrap()
+        .into_inner();
+    let second = client
+        .put_note(2, Some(true), "private", &note("second"))
+        .await
+        .unwrap()
+        .into_inner();
+
+    // Nothing is written until the cassette is flushed.
+    assert!(!path.exists());
+    cassette.flush().await.unwrap();
+    let recorded = std::fs::read_to_string(&path).unwrap();
+    assert!(!recorded.contains("secret"));
+    assert!(recorded.contains("[REDACTED]"));
+
+    // Otherwise it's written when dropped.
+    std::fs::remove_file(&path).unwrap();
+    client
+        .put_note(3, None, "public", &note("third"))
+        .await
+        .unwrap();
+    drop((client, cassette));
+    let recorded = std::fs::read_to_string(&path).unwrap();
+    assert!(recorded.contains("third"));
+
+    // Replay in a different order, without the server.
+    let client = gen_client::Client::new("http://localhost:1")
+        .with_middleware(Cassette::replay(&path).unwrap());
+    let replayed = client
+        .put_note(2, Some(true), "private", &note("second"))
+        .await
+        .unwrap();
+    assert_eq!(replayed.status(), 200);
+    assert_eq!(replayed.into_inner().text, second.text);
+    let replayed = client
+        .put_note_with_options(1, None, "public", &note("first"), secret)
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(replayed.text, first.text);
+    assert_eq!(replayed.visibility, "public");
+
+    // Each interaction is replayed once.
+    let result = client
+        .put_note(2, Some(true), "private", &note("second"))
+        .await;
+    assert!(matches!(result, Err(Error::Custom(_))));
+
+    // Bodies are compared unless configured otherwise.
+    let client = gen_client::Client::new("http://localhost:1")
+        .with_middleware(Cassette::replay(&path).unwrap());
+    let result = client.put_note(1, None, "public", &note("other")).await;
+    assert!(matches!(result, Err(Error::Custom(_))));
+    let client = gen_client::Client::new("http://localhost:1").with_middleware(
+        Cassette::replay(&path).unwrap().match_on(CassetteMatch {
+            body: false,
+            ..Default::default()
+        }),
+    );
+    let replayed = client
+        .put_note(1, None, "public", &note("other"))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(replayed.text, "first");
+
+    std::fs::remove_file(&path).unwrap();
+
+    server.close().await.expect("failed to close server");
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[allow(dead_code)]
+struct DocumentPath {
+    id: String,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct Document {
+    id: String,
+    text: String,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[allow(dead_code)]
+struct DocumentUpdate {
+    text: String,
+}
+
+#[endpoint {
+    method = GET,
+    path = "/documents/{id}",
+}]
+async fn get_document(
+    _rqctx: RequestContext<()>,
+    _path: Path<DocumentPath>,
+) -> Result<HttpResponseOk<Document>, HttpError> {
+    unimplemented!()
+}
+
+#[endpoint {
+    method = PUT,
+    path = "/documents/{id}",
+}]
+async fn put_document(
+    _rqctx: RequestContext<()>,
+    _path: Path<DocumentPath>,
+    _body: TypedBody<DocumentUpdate>,
+) -> Result<HttpResponseOk<Document>, HttpError> {
+    unimplemented!()
+}
+
+/// The state behind `serve_get_document` and `serve_put_document`: a version
+/// number (which is also the entity tag) and text shared by all documents,
+/// and counts of requests.
+#[derive(Default)]
+struct Documents {
+    current: Mutex<(u64, String)>,
+    gets: std::sync::atomic::AtomicUsize,
+    not_modified: std::sync::atomic::AtomicUsize,
+}
+
+impl Documents {
+    fn response(
+        &self,
+        id: String,
+        status: u16,
+        max_age: &str,
+    ) -> Result<Response<Body>, HttpError> {
+        let (version, text) = self.current.lock().unwrap().clone();
+        let body = serde_json::to_string(&Document { id, text }).unwrap();
+        Ok(Response::builder()
+            .status(status)
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .header(http::header::ETAG, format!("\"{version}\""))
+            .header(http::header::CACHE_CONTROL, max_age)
+            .body(body.into())?)
+    }
+}
+
+/// Serves `/documents/{id}` with entity tags, answering conditional requests
+/// with `304 Not Modified`. The document "fresh" may be cached for a minute.
+#[endpoint {
+    method = GET,
+    path = "/documents/{id}",
+}]
+async fn serve_get_document(
+    rqctx: RequestContext<Arc<Documents>>,
+    path: Path<DocumentPath>,
+) -> Result<Response<Body>, HttpError> {
+    use std::sync::atomic::Ordering;
+
+    let documents = rqctx.context();
+    documents.gets.fetch_add(1, Ordering::SeqCst);
+    let id = path.into_inner().id;
+    let etag = format!("\"{}\"", documents.current.lock().unwrap().0);
+    if rqctx
+        .request
+        .headers()
+        .get(http::header::IF_NONE_MATCH)
+        .is_some_and(|value| value == etag.as_str())
+    {
+        documents.not_modified.fetch_add(1, Ordering::SeqCst);
+        return Ok(Response::builder()
+            .status(304)
+            .header(http::header::ETAG, etag)
+            .body(Body::empty())?);
+    }
+    let cache_control = if id == "fresh" {
+        "max-age=60"
+    } else {
+        "no-cache"
+    };
+    documents.response(id, 200, cache_control)
+}
+
+/// Updates the text of all documents, if an `If-Match` header matches.
+#[endpoint {
+    method = PUT,
+    path = "/documents/{id}",
+}]
+async fn serve_put_document(
+    rqctx: RequestContext<Arc<Documents>>,
+    path: Path<DocumentPath>,
+    body: TypedBody<DocumentUpdate>,
+) -> Result<Response<Body>, HttpError> {
+    let documents = rqctx.context();
+    {
+        let mut current = documents.current.lock().unwrap();
+        let etag = format!("\"{}\"", current.0);
+        if let Some(value) = rqctx.request.headers().get(http::header::IF_MATCH) {
+            if value != etag.as_str() {
+                return Err(HttpError::for_client_error(
+                    None,
+                    http::StatusCode::PRECONDITION_FAILED,
+                    "entity tag mismatch".to_string(),
+                ));
+            }
+        }
+        *current = (current.0 + 1, body.into_inner().text);
+    }
+    documents.response(path.into_inner().id, 200, "no-cache")
+}
+
+/// Test caching responses with `HttpCache` and conditional updates with
+/// `If-Match`.
+#[tokio::test]
+async fn test_http_cache() {
+    const TEST_NAME: &str = "test_http_cache";
+
+    let mut api = ApiDescription::new();
+    api.register(get_document).unwrap();
+    api.register(put_document).unwrap();
+
+    let spec = api_spec(&api, TEST_NAME);
+
+    assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Builder),
+        &spec,
+        TEST_NAME,
+    );
+
+    // Run the Dropshot server.
+    let mut api = ApiDescription::new();
+    api.register(serve_get_document).unwrap();
+    api.register(serve_put_document).unwrap();
+
+    let documents = Arc::new(Documents {
+        current: Mutex::new((1, "hello".to_string())),
+        ..Default::default()
+    });
+    let (server, server_addr) = start_server(TEST_NAME, api, documents.clone());
+
+    #[allow(dead_code, unused_imports)]
+    mod gen_client {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_http_cache.rs");
+    }
+
+    use progenitor_client::{DiskCache, Error, HttpCache, MemoryCache};
+    use std::sync::atomic::Ordering;
+
+    let gets = || documents.gets.load(Ordering::SeqCst);
+    let not_modified = || documents.not_modified.load(Ordering::SeqCst);
+
+    let client =
+        gen_client::Client::new(&server_addr).with_middleware(HttpCache::new(MemoryCache::new()));
+
+    let document = client.get_document().id("doc").send().await.unwrap();
+    assert_eq!(document.etag(), Some("\"1\""));
+    assert_eq!(document.text, "hello");
+    assert_eq!((gets(), not_modified()), (1, 0));
+
+    // A response that must be revalidated is replaced after a 304.
+    let revalidated = client.get_document().id("doc").send().await.unwrap();
+    assert_eq!(revalidated.status(), 200);
+    assert_eq!(revalidated.text, "hello");
+    assert_eq!((gets(), not_modified()), (2, 1));
+
+    // A fresh response is returned without a request.
+    client.get_document().id("fresh").send().await.unwrap();
+    let cached = client.get_document().id("fresh").send().await.unwrap();
+    assert_eq!(cached.text, "hello");
+    assert_eq!(gets(), 3);
+
+    // Updates are conditional on the entity tag.
+    let result = client
+        .put_document()
+        .id("doc")
+        .body_map(|body| body.text("stale"))
+        .with_if_match("\"0\"")
+        .send()
+        .await;
+    match result {
+        Err(Error::ErrorResponse(response)) => assert_eq!(response.status(), 412),
+        _ => panic!("unexpected result"),
+    }
+    let updated = client
+        .put_document()
+        .id("doc")
+        .body_map(|body| body.text("updated"))
+        .with_if_match(document.etag().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(updated.etag(), Some("\"2\""));
+
+    // The update removed the cached response.
+    let document = client.get_document().id("doc").send().await.unwrap();
+    assert_eq!(document.text, "updated");
+    assert_eq!((gets(), not_modified()), (4, 1));
+
+    // Responses on disk are shared by clients.
+    let dir = std::env::temp_dir().join(format!("progenitor-{TEST_NAME}-{}", std::process::id()));
+    for _ in 0..2 {
+        let client = gen_client::Client::new(&server_addr)
+            .with_middleware(HttpCache::new(DiskCache::new(&dir).unwrap()));
+        let document = client.get_document().id("doc").send().await.unwrap();
+        assert_eq!(document.text, "updated");
+    }
+    assert_eq!((gets(), not_modified()), (6, 2));
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    // Authenticated and range requests bypass the cache.
+    for (name, value) in [("authorization", "Bearer token"), ("range", "bytes=0-1")] {
+        client
+            .get_document()
+            .id("fresh")
+            .with_header(name, value)
+            .send()
+            .await
+            .unwrap();
+    }
+    assert_eq!(gets(), 8);
+
+    // Responses larger than the maximum entry size are not cached.
+    let client = gen_client::Client::new(&server_addr)
+        .with_middleware(HttpCache::new(MemoryCache::new()).with_max_entry_size(1));
+    for _ in 0..2 {
+        client.get_document().id("fresh").send().await.unwrap();
+    }
+    assert_eq!(gets(), 10);
+
+    server.close().await.expect("failed to close server");
+}
+
+/// Test preparing requests without sending them.
+#[test]
+fn test_into_request() {
+    const TEST_NAME: &str = "test_into_request";
+
+    let mut api = ApiDescription::new();
+    api.register(get_document).unwrap();
+    api.register(put_document).unwrap();
+
+    let out = serde_json::to_string_pretty(&api_json(&api, TEST_NAME)).unwrap();
+    let spec = serde_json::from_str::<OpenAPI>(&out).unwrap();
+
+    assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Builder),
+        &spec,
+        &format!("{TEST_NAME}_builder"),
+    );
+
+    assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Positional),
+        &spec,
+        &format!("{TEST_NAME}_positional"),
+    );
+
+    #[allow(dead_code, unused_imports)]
+    mod gen_client_builder {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_into_request_builder.rs");
+    }
+
+    #[allow(dead_code, unused_imports)]
+    mod gen_client_positional {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_into_request_positional.rs");
+    }
+
+    use progenitor_client::{curl_command, format_request, Error};
+
+    let client = gen_client_builder::Client::new("http://localhost:8080");
+    let request = client
+        .put_document()
+        .id("it's")
+        .body_map(|body| body.text("it's new"))
+        .with_if_match("\"1\"")
+        .into_request()
+        .unwrap();
+    assert_eq!(request.method(), reqwest::Method::PUT);
+    assert_eq!(
+        request.url().as_str(),
+        "http://localhost:8080/documents/it's"
+    );
+    assert_eq!(request.headers()[reqwest::header::IF_MATCH], "\"1\"");
+    assert_eq!(
+        format_request(&request),
+        "PUT http://localhost:8080/documents/it's\n\
+        accept: application/json\n\
+        content-type: application/json\n\
+        api-version: 1.0.0\n\
+        if-match: \"1\"\n\
+        \n\
+        {\"text\":\"it's new\"}\n",
+    );
+    assert_eq!(
+        curl_command(&request),
+        r#"curl -X PUT 'http://localhost:8080/documents/it'\''s' -H 'accept: application/json' -H 'content-type: application/json' -H 'api-version: 1.0.0' -H 'if-match: "1"' --data-binary '{"text":"it'\''s new"}'"#,
+    );
+
+    // Missing parameters are reported without sending anything.
+    match client.get_document().into_request() {
+        Err(Error::InvalidRequest(_)) => {}
+        _ => panic!("unexpected result"),
+    }
+
+    let client = gen_client_positional::Client::new("http://localhost:8080");
+    let request = client.get_document_request("doc").unwrap();
+    assert_eq!(request.method(), reqwest::Method::GET);
+    assert!(request.body().is_none());
+    assert_eq!(
+        curl_command(&request),
+        "curl 'http://localhost:8080/documents/doc' -H 'accept: application/json' -H 'api-version: 1.0.0'",
+    );
+
+    // Methods that would collide with another operation's are named with a
+    // suffix.
+    let colliding = serde_json::from_str::<OpenAPI>(&out.replace(
+        "\"operationId\": \"put_document\"",
+        "\"operationId\": \"get_document_request\"",
+    ))
+    .unwrap();
+    let mut generator =
+        Generator::new(GenerationSettings::new().with_interface(InterfaceStyle::Positional));
+    let output = generator.generate_tokens(&colliding).unwrap().to_string();
+    assert!(output.contains("fn get_document_request_2 <"), "{}", output);
+    assert!(
+        output.contains("fn get_document_request_request <"),
+        "{}",
+        output
+    );
+
+    // The CLI's flags don't collide with parameters of the same name.
+    let colliding = serde_json::from_str::<OpenAPI>(
+        &out.replace("{id}", "{dry_run}")
+            .replace("\"name\": \"id\"", "\"name\": \"dry_run\""),
+    )
+    .unwrap();
+    let mut generator = Generator::default();
+    generator.cli(&colliding, "sdk").unwrap();
+}
+
+/// Test serializing operations and executing them with
+/// `Client::execute_operation`.
+#[tokio::test]
+async fn test_operation_enum() {
+    const TEST_NAME: &str = "test_operation_enum";
+
+    let mut api = ApiDescription::new();
+    api.register(put_note).unwrap();
+    api.register(list_folder_notes).unwrap();
+
+    let spec = api_spec(&api, TEST_NAME);
+
+    assert_generated(
+        GenerationSettings::new()
+            .with_interface(InterfaceStyle::Positional)
+            .with_operation_enum(true),
+        &spec,
+        &format!("{TEST_NAME}_positional"),
+    );
+
+    assert_generated(
+        GenerationSettings::new()
+            .with_interface(InterfaceStyle::Builder)
+            .with_typestate_builders(true)
+            .with_operation_enum(true),
+        &spec,
+        &format!("{TEST_NAME}_builder"),
+    );
+
+    // Run the Dropshot server.
+    let (server, server_addr) = start_server(TEST_NAME, api, ());
+
+    #[allow(dead_code)]
+    mod gen_client_positional {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_operation_enum_positional.rs");
+    }
+
+    #[allow(dead_code, unused_imports)]
+    mod gen_client_builder {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_operation_enum_builder.rs");
+    }
+
+    use gen_client_positional::{operations, types, Operation, OperationResponse};
+
+    let operation = Operation::from(operations::PutNote {
+        id: 7,
+        pinned: Some(true),
+        visibility: "public".to_string(),
+        body: types::Note {
+            text: "queued".to_string(),
+        },
+    });
+    assert_eq!(operation.operation_id(), "put_note");
+    let json = serde_json::to_value(&operation).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "operation_id": "put_note",
+            "parameters": {
+                "id": 7,
+                "pinned": true,
+                "visibility": "public",
+                "body": { "text": "queued" },
+            },
+        })
+    );
+
+    // Operations survive a round trip through their serialized form.
+    let client = gen_client_positional::Client::new(&server_addr);
+    let operation = serde_json::from_value::<Operation>(json.clone()).unwrap();
+    let response = client.execute_operation(operation).await.unwrap();
+    assert_eq!(response.status(), 200);
+    match response.into_inner() {
+        OperationResponse::PutNote(record) => {
+            assert_eq!(record.id, 7);
+            assert!(record.pinned);
+            assert_eq!(record.visibility, "public");
+            assert_eq!(record.text, "queued");
+        }
+        _ => panic!("unexpected response"),
+    }
+
+    let operation = serde_json::from_value::<Operation>(serde_json::json!({
+        "operation_id": "list_folder_notes",
+        "parameters": { "folder": "inbox", "limit": null, "page_token": null },
+    }))
+    .unwrap();
+    match client
+        .execute_operation(operation)
+        .await
+        .unwrap()
+        .into_inner()
+    {
+        OperationResponse::ListFolderNotes(page) => assert_eq!(page.items, vec![1, 2, 3]),
+        _ => panic!("unexpected response"),
+    }
+
+    // The same serialized operation executes with a builder client.
+    let client = gen_client_builder::Client::new(&server_addr);
+    let operation = serde_json::from_value::<gen_client_builder::Operation>(json).unwrap();
+    match client
+        .execute_operation(operation)
+        .await
+        .unwrap()
+        .into_inner()
+    {
+        gen_client_builder::OperationResponse::PutNote(record) => {
+            assert_eq!(record.id, 7);
+            assert!(record.pinned);
+        }
+        _ => panic!("unexpected response"),
+    }
+
+    server.close().await.expect("failed to close server");
+}
+
+/// Test the `ClientApi` trait, both as implemented by `Client` and by a fake.
+#[tokio::test]
+async fn test_client_api() {
+    const TEST_NAME: &str = "test_client_api";
+
+    let mut api = ApiDescription::new();
+    api.register(put_note).unwrap();
+    api.register(list_folder_notes).unwrap();
+
+    let spec = api_spec(&api, TEST_NAME);
+
+    assert_generated(
+        GenerationSettings::new()
+            .with_interface(InterfaceStyle::Positional)
+            .with_client_api(true),
+        &spec,
+        &format!("{TEST_NAME}_positional"),
+    );
+
+    assert_generated(
+        GenerationSettings::new()
+            .with_interface(InterfaceStyle::Builder)
+            .with_client_api(true)
+            .with_client_api_attr("cfg_attr(test, mockall::automock)"),
+        &spec,
+        &format!("{TEST_NAME}_builder"),
+    );
+
+    // Run the Dropshot server.
+    let (server, server_addr) = start_server(TEST_NAME, api, ());
+
+    #[allow(dead_code)]
+    mod gen_client_positional {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_client_api_positional.rs");
+    }
+
+    #[allow(dead_code, unused_imports)]
+    mod gen_client_builder {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_client_api_builder.rs");
+    }
+
+    use gen_client_positional::{types, ClientApi, Error, ResponseValue};
+
+    async fn pin_note(api: &dyn ClientApi, id: u32) -> String {
+        let note = types::Note {
+            text: format!("note {id}"),
+        };
+        let record = api
+            .put_note(id, Some(true), "private", &note)
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(record.pinned);
+        record.text
+    }
+
+    // A fake records the calls made through the trait.
+    #[derive(Default)]
+    struct FakeApi {
+        calls: std::sync::Mutex<Vec<String>>,
+    }
+
+    #[progenitor_client::async_trait]
+    impl ClientApi for FakeApi {
+        async fn put_note<'a>(
+            &self,
+            id: u32,
+            pinned: Option<bool>,
+            visibility: &'a str,
+            body: &'a types::Note,
+        ) -> Result<ResponseValue<types::NoteRecord>, Error<types::Error>> {
+            self.calls.lock().unwrap().push(format!("put_note {id}"));
+            Ok(ResponseValue::new(
+                types::NoteRecord {
+                    id,
+                    pinned: pinned.unwrap_or_default(),
+                    text: format!("fake {}", body.text),
+                    visibility: visibility.to_string(),
+                },
+                reqwest::StatusCode::OK,
+                Default::default(),
+            ))
+        }
+
+        async fn list_folder_notes<'a>(
+            &self,
+            _folder: &'a str,
+            _limit: Option<std::num::NonZeroU32>,
+            _page_token: Option<&'a str>,
+        ) -> Result<ResponseValue<types::Uint32ResultsPage>, Error<types::Error>> {
+            unimplemented!()
+        }
+    }
+
+    let fake = FakeApi::default();
+    assert_eq!(pin_note(&fake, 3).await, "fake note 3");
+    assert_eq!(*fake.calls.lock().unwrap(), vec!["put_note 3"]);
+
+    // Attributes such as `automock` apply to the trait.
+    let mut mock = gen_client_builder::MockClientApi::new();
+    mock.expect_list_folder_notes()
+        .withf(|folder, limit, page_token| {
+            folder == "inbox" && limit.is_none() && page_token.is_none()
+        })
+        .times(1)
+        .returning(|_, _, _| {
+            Ok(gen_client_builder::ResponseValue::new(
+                gen_client_builder::types::Uint32ResultsPage {
+                    items: vec![7],
+                    next_page: None,
+                },
+                reqwest::StatusCode::OK,
+                Default::default(),
+            ))
+        });
+    let api: &dyn gen_client_builder::ClientApi = &mock;
+    let page = api
+        .list_folder_notes("inbox", None, None)
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(page.items, vec![7]);
+
+    let client = gen_client_positional::Client::new(&server_addr);
+    assert_eq!(pin_note(&client, 4).await, "note 4");
+
+    // Builder clients implement the trait too, and may be shared as objects.
+    let client: std::sync::Arc<dyn gen_client_builder::ClientApi> =
+        std::sync::Arc::new(gen_client_builder::Client::new(&server_addr));
+    let page = client
+        .list_folder_notes("inbox", None, None)
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(page.items, vec![1, 2, 3]);
+
+    server.close().await.expect("failed to close server");
+}
+
+/// Test a Dropshot server implemented from the generated API trait, and
+/// called with the generated client.
+#[tokio::test]
+async fn test_server() {
+    const TEST_NAME: &str = "test_server";
+
+    let mut api = ApiDescription::new();
+    api.register(put_note).unwrap();
+    api.register(list_folder_notes).unwrap();
+
+    let mut spec = api_json(&api, TEST_NAME);
+
+    // Dropshot doesn't describe header parameters; add one.
+    spec["paths"]["/notes/{id}"]["put"]["parameters"]
+        .as_array_mut()
+        .unwrap()
+        .push(serde_json::json!({
+            "in": "header",
+            "name": "x-note-author",
+            "required": true,
+            "schema": { "type": "string" }
+        }));
+    let spec = serde_json::from_value::<OpenAPI>(spec).unwrap();
+
+    let mut generator = assert_generated(
+        GenerationSettings::new()
+            .with_interface(InterfaceStyle::Positional)
+            .with_derive("schemars::JsonSchema"),
+        &spec,
+        &format!("{TEST_NAME}_client"),
+    );
+
+    let output = format_tokens(generator.server(&spec, "super").unwrap());
+    expectorate::assert_contents(format!("tests/output/src/{TEST_NAME}.rs"), &output);
+
+    #[allow(dead_code)]
+    mod gen_client {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_server_client.rs");
+
+        pub mod server {
+            include!("output/src/test_server.rs");
+        }
+    }
+
+    use gen_client::{
+        server::{api_mod, parameters, Api},
+        types,
+    };
+
+    // The server keeps the IDs of the notes it has been given.
+    enum NotesApi {}
+
+    impl Api for NotesApi {
+        type Context = Mutex<Vec<u32>>;
+
+        async fn list_folder_notes(
+            rqctx: RequestContext<Self::Context>,
+            path: Path<parameters::ListFolderNotesPath>,
+            query: Query<PaginationParams<EmptyScanParams, serde_json::Value>>,
+        ) -> Result<HttpResponseOk<ResultsPage<u32>>, HttpError> {
+            assert_eq!(path.into_inner().folder, "inbox");
+            assert!(matches!(
+                query.into_inner().page,
+                dropshot::WhichPage::First(EmptyScanParams {})
+            ));
+            let items = rqctx.context().lock().unwrap().clone();
+            Ok(HttpResponseOk(ResultsPage {
+                next_page: None,
+                items,
+            }))
+        }
+
+        async fn put_note(
+            rqctx: RequestContext<Self::Context>,
+            path: Path<parameters::PutNotePath>,
+            query: Query<parameters::PutNoteQuery>,
+            body: TypedBody<types::Note>,
+        ) -> Result<HttpResponseOk<types::NoteRecord>, HttpError> {
+            let headers = parameters::PutNoteHeaders::from_headers(rqctx.request.headers())?;
+            assert_eq!(headers.x_note_author, "ann");
+            let id = path.into_inner().id;
+            let query = query.into_inner();
+            rqctx.context().lock().unwrap().push(id);
+            Ok(HttpResponseOk(types::NoteRecord {
+                id,
+                pinned: query.pinned.unwrap_or_default(),
+                text: body.into_inner().text,
+                visibility: query.visibility,
+            }))
+        }
+    }
+
+    // The trait describes the same operations as the original document.
+    let mut out = Vec::new();
+    api_mod::stub_api_description()
+        .unwrap()
+        .openapi(TEST_NAME, semver::Version::new(1, 0, 0))
+        .write(&mut out)
+        .unwrap();
+    let stub_spec = serde_json::from_slice::<OpenAPI>(&out).unwrap();
+    assert_eq!(
+        stub_spec.paths.paths.keys().collect::<Vec<_>>(),
+        spec.paths.paths.keys().collect::<Vec<_>>(),
+    );
+
+    // A missing or invalid header is a client error.
+    let err = parameters::PutNoteHeaders::from_headers(&http::HeaderMap::new()).unwrap_err();
+    assert_eq!(err.status_code, http::StatusCode::BAD_REQUEST);
+    assert_eq!(err.external_message, "missing header x-note-author");
+
+    // Run the generated server.
+    let (server, server_addr) = start_server(
+        TEST_NAME,
+        api_mod::api_description::<NotesApi>().unwrap(),
+        Mutex::new(Vec::new()),
+    );
+
+    let client = gen_client::Client::new(&server_addr);
+    let record = client
+        .put_note(
+            5,
+            Some(true),
+            "public",
+            "ann",
+            &types::Note {
+                text: "served".to_string(),
+            },
+        )
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(record.id, 5);
+    assert!(record.pinned);
+    assert_eq!(record.text, "served");
+
+    let page = client
+        .list_folder_notes("inbox", None, None)
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(page.items, vec![5]);
+
+    server.close().await.expect("failed to close server");
+}
+
+/// Test an axum router implemented from the generated handler trait, and
+/// called with the generated client.
+#[tokio::test]
+async fn test_axum() {
+    const TEST_NAME: &str = "test_axum";
+
+    let mut api = ApiDescription::new();
+    api.register(put_note).unwrap();
+    api.register(list_folder_notes).unwrap();
+
+    let out = serde_json::to_string_pretty(&api_json(&api, TEST_NAME)).unwrap();
+    let spec = serde_json::from_str::<OpenAPI>(&out).unwrap();
+
+    let mut generator = assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Positional),
+        &spec,
+        &format!("{TEST_NAME}_client"),
+    );
+
+    let output = format_tokens(generator.axum(&spec, "super").unwrap());
+    expectorate::assert_contents(format!("tests/output/src/{TEST_NAME}.rs"), &output);
+
+    // Successful responses need a status code that `StatusCode` names.
+    let unsupported =
+        serde_json::from_str::<OpenAPI>(&out.replace("\"200\":", "\"299\":")).unwrap();
+    let error = Generator::default()
+        .axum(&unsupported, "super")
+        .unwrap_err();
+    assert!(
+        error.to_string().contains("unsupported status code 299"),
+        "{}",
+        error
+    );
+
+    #[allow(dead_code)]
+    mod gen_client {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_axum_client.rs");
+
+        pub mod service {
+            include!("output/src/test_axum.rs");
+        }
+    }
+
+    use gen_client::{
+        service::{parameters, router, Api},
+        types, Error,
+    };
+
+    // The service keeps the IDs of the notes it has been given, and refuses
+    // notes without text.
+    #[derive(Default)]
+    struct NotesApi {
+        ids: Mutex<Vec<u32>>,
+    }
+
+    #[progenitor_client::async_trait]
+    impl Api for NotesApi {
+        async fn list_folder_notes(
+            &self,
+            path: axum::extract::Path<parameters::ListFolderNotesPath>,
+            query: axum::extract::Query<parameters::ListFolderNotesQuery>,
+        ) -> Result<types::Uint32ResultsPage, (axum::http::StatusCode, types::Error)> {
+            assert_eq!(path.folder, "inbox");
+            assert_eq!(query.limit.map(|limit| limit.get()), Some(10));
+            Ok(types::Uint32ResultsPage {
+                items: self.ids.lock().unwrap().clone(),
+                next_page: None,
+            })
+        }
+
+        async fn put_note(
+            &self,
+            path: axum::extract::Path<parameters::PutNotePath>,
+            query: axum::extract::Query<parameters::PutNoteQuery>,
+            body: axum::Json<types::Note>,
+        ) -> Result<types::NoteRecord, (axum::http::StatusCode, types::Error)> {
+            if body.text.is_empty() {
+                return Err((
+                    axum::http::StatusCode::BAD_REQUEST,
+                    types::Error {
+                        error_code: None,
+                        message: "empty note".to_string(),
+                        request_id: "1".to_string(),
+                    },
+                ));
+            }
+            self.ids.lock().unwrap().push(path.id);
+            Ok(types::NoteRecord {
+                id: path.id,
+                pinned: query.pinned.unwrap_or_default(),
+                text: body.0.text,
+                visibility: query.0.visibility,
+            })
+        }
+    }
+
+    let listener = tokio::net::TcpListener::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, 0)))
+        .await
+        .unwrap();
+    let addr = listener.local_addr().unwrap();
+    let app = router(Arc::new(NotesApi::default()));
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    let client = gen_client::Client::new(&format!("http://{addr}"));
+    let record = client
+        .put_note(
+            5,
+            Some(true),
+            "public",
+            &types::Note {
+                text: "routed".to_string(),
+            },
+        )
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(record.id, 5);
+    assert!(record.pinned);
+    assert_eq!(record.text, "routed");
+
+    // Typed errors are sent with the status code from the handler.
+    match client
+        .put_note(
+            6,
+            None,
+            "public",
+            &types::Note {
+                text: String::new(),
+            },
+        )
+        .await
+    {
+        Err(Error::ErrorResponse(response)) => {
+            assert_eq!(response.status(), 400);
+            assert_eq!(response.message, "empty note");
+        }
+        other => panic!("unexpected result {:?}", other.map(|r| r.into_inner())),
+    }
+
+    let page = client
+        .list_folder_notes("inbox", std::num::NonZeroU32::new(10), None)
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(page.items, vec![5]);
+}
+
+/// Test a fake backend implemented from the generated trait, which answers
+/// the requests of the generated client without a server.
+#[tokio::test]
+async fn test_fake() {
+    const TEST_NAME: &str = "test_fake";
+
+    let mut api = ApiDescription::new();
+    api.register(put_note).unwrap();
+    api.register(list_folder_notes).unwrap();
+
+    let out = serde_json::to_string_pretty(&api_json(&api, TEST_NAME)).unwrap();
+    let spec = serde_json::from_str::<OpenAPI>(&out).unwrap();
+
+    let mut generator = assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Positional),
+        &spec,
+        &format!("{TEST_NAME}_client"),
+    );
+
+    let output = format_tokens(generator.fake(&spec, "super").unwrap());
+    expectorate::assert_contents(format!("tests/output/src/{TEST_NAME}.rs"), &output);
+
+    // Successful responses need a status code that `StatusCode` names.
+    let unsupported =
+        serde_json::from_str::<OpenAPI>(&out.replace("\"200\":", "\"299\":")).unwrap();
+    let error = Generator::default()
+        .fake(&unsupported, "super")
+        .unwrap_err();
+    assert!(
+        error.to_string().contains("unsupported status code 299"),
+        "{}",
+        error
+    );
+
+    #[allow(dead_code)]
+    mod gen_client {
+        // See the comment in `test_stream_pagination` regarding the use of
+        // `include!` for generated code.
+        include!("output/src/test_fake_client.rs");
+
+        pub mod fake {
+            include!("output/src/test_fake.rs");
+        }
+    }
+
+    use gen_client::{
+        fake::{parameters, Api, Fake},
+        types, Error,
+    };
+
+    // The fake keeps the notes it has been given, and refuses to replace a
+    // pinned note.
+    #[derive(Default)]
+    struct NotesApi {
+        notes: Mutex<Vec<types::NoteRecord>>,
+    }
+
+    #[progenitor_client::async_trait]
+    impl Api for NotesApi {
+        async fn list_folder_notes(
+            &self,
+            path: parameters::ListFolderNotesPath,
+            query: parameters::ListFolderNotesQuery,
+        ) -> Result<types::Uint32ResultsPage, (reqwest::StatusCode, types::Error)> {
+            assert_eq!(path.folder, "in box");
+            assert!(query.limit.is_none());
+            Ok(types::Uint32ResultsPage {
+                items: self.notes.lock().unwrap().iter().map(|n| n.id).collect(),
+                next_page: None,
+            })
+        }
+
+        async fn put_note(
+            &self,
+            path: parameters::PutNotePath,
+            query: parameters::PutNoteQuery,
+            body: types::Note,
+        ) -> Result<types::NoteRecord, (reqwest::StatusCode, types::Error)> {
+            let mut notes = self.notes.lock().unwrap();
+            if notes.iter().any(|note| note.id == path.id && note.pinned) {
+                return Err((
+                    reqwest::StatusCode::CONFLICT,
+                    types::Error {
+                        error_code: None,
+                        message: "note is pinned".to_string(),
+                        request_id: "1".to_string(),
+                    },
+                ));
+            }
+            let record = types::NoteRecord {
+                id: path.id,
+                pinned: query.pinned.unwrap_or_default(),
+                text: body.text,
+                visibility: query.visibility,
+            };
+            notes.push(record.clone());
+            Ok(record)
+        }
+    }
+
+    // Nothing listens at this address; the fake answers every request.
+    let client = gen_client::Client::new("http://fake.invalid/api")
+        .with_middleware(Fake::new(NotesApi::default()));
+
+    let record = client
+        .put_note(
+            7,
+            Some(true),
+            "public",
+            &types::Note {
+                text: "faked".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(record.status(), 200);
+    assert_eq!(record.id, 7);
+    assert!(record.pinned);
+    assert_eq!(record.text, "faked");
+
+    // The fake's state determines later responses.
+    match client
+        .put_note(
+            7,
+            None,
+            "public",
+            &types::Note {
+                text: "again".to_string(),
+            },
+        )
+        .await
+    {
+        Err(Error::ErrorResponse(response)) => {
+            assert_eq!(response.status(), 409);
+            assert_eq!(response.message, "note is pinned");
+        }
+        other => panic!("unexpected result {:?}", other.map(|r| r.into_inner())),
+    }
+
+    let page = client
+        .list_folder_notes("in box", None, None)
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(page.items, vec![7]);
+}
+
+// The mock helpers refer to the client by its path from the crate root, so
+// the generated code is included at the top level.
+#[allow(dead_code, unused_imports)]
+mod test_httpmock_matching_positional {
+    include!("output/src/test_httpmock_matching_positional.rs");
+}
+
+#[allow(dead_code, unused_imports)]
+mod test_httpmock_matching_httpmock {
+    include!("output/src/test_httpmock_matching_httpmock.rs");
+}
+
+#[allow(dead_code, unused_imports)]
+mod test_httpmock_matching_wiremock {
+    include!("output/src/test_httpmock_matching_wiremock.rs");
+}
+
+/// Test the `httpmock` and `wiremock` helpers for array and object query
+/// parameters, headers, partial and typed bodies, and response headers.
+#[tokio::test]
+async fn test_httpmock_matching() {
+    const TEST_NAME: &str = "test_httpmock_matching";
+
+    let mut api = ApiDescription::new();
+    api.register(put_note).unwrap();
+
+    let mut spec = api_json(&api, TEST_NAME);
+
+    // Dropshot doesn't describe query parameters that aren't single values,
+    // or response headers; add an array and an object query parameter, a
+    // header parameter, and an ETag response header. The visibility is
+    // described as an enum.
+    let operation = &mut spec["paths"]["/notes/{id}"]["put"];
+    let parameters = operation["parameters"].as_array_mut().unwrap();
+    for parameter in parameters.iter_mut() {
+        if parameter["name"] == "visibility" {
+            parameter["schema"] = serde_json::json!({
+                "type": "string",
+                "enum": ["public", "private"]
+            });
+        }
+    }
+    parameters.push(serde_json::json!({
+        "in": "query",
+        "name": "tags",
+        "schema": {
+            "type": "array",
+            "items": { "type": "string" }
+        }
+    }));
+    parameters.push(serde_json::json!({
+        "in": "query",
+        "name": "range",
+        "required": true,
+        "schema": {
+            "title": "NoteRange",
+            "type": "object",
+            "properties": {
+                "min": { "type": "integer", "format": "uint32", "minimum": 0 },
+                "max": { "type": "integer", "format": "uint32", "minimum": 0 }
+            },
+            "required": ["min", "max"]
+        }
+    }));
+    parameters.push(serde_json::json!({
+        "in": "header",
+        "name": "x-request-source",
+        "schema": { "type": "string" }
+    }));
+    operation["responses"]["200"]["headers"] = serde_json::json!({
+        "etag": {
+            "required": true,
+            "schema": { "type": "string" }
+        }
+    });
+
+    let spec = serde_json::from_value::<OpenAPI>(spec).unwrap();
+
+    let mut generator = assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Positional),
+        &spec,
+        &format!("{TEST_NAME}_positional"),
+    );
+
+    let output = format_tokens(
+        generator
+            .httpmock(&spec, &format!("crate::{TEST_NAME}_positional"))
+            .unwrap(),
+    );
+    expectorate::assert_contents(format!("tests/output/src/{TEST_NAME}_httpmock.rs"), &output);
+
+    let output = format_tokens(
+        generator
+            .wiremock(&spec, &format!("crate::{TEST_NAME}_positional"))
+            .unwrap(),
+    );
+    expectorate::assert_contents(format!("tests/output/src/{TEST_NAME}_wiremock.rs"), &output);
+
+    use test_httpmock_matching_positional::{types, Client, Error};
+
+    let range = types::NoteRange { min: 1, max: 5 };
+    let tags = vec!["a".to_string(), "b".to_string()];
+    let note = types::Note {
+        text: "hello".to_string(),
+    };
+    let record = types::NoteRecord {
+        id: 1,
+        pinned: true,
+        text: "hello".to_string(),
+        visibility: "private".to_string(),
+    };
+
+    // The wiremock matchers accept the requests the client sends.
+    {
+        use test_httpmock_matching_wiremock::{operations::PutNoteThen, MockExt};
+        use wiremock::{Mock, MockServer};
+
+        let server = MockServer::start().await;
+        Mock::put_note()
+            .id(1)
+            .pinned(true)
+            .range(&range)
+            .tags_contains("b")
+            .visibility(types::PutNoteVisibility::Private)
+            .x_request_source_exists()
+            .body_partial(serde_json::json!({ "text": "hello" }))
+            .body_matches(|note| note.text.starts_with('h'))
+            .respond_with(PutNoteThen::ok(&record).etag_header("\"1\""))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::put_note()
+            .id(2)
+            .pinned(None)
+            .tags(&tags)
+            .x_request_source(None)
+            .body(&note)
+            .respond_with(PutNoteThen::client_error(
+                409,
+                &types::Error {
+                    error_code: None,
+                    message: "conflict".to_string(),
+                    request_id: "r".to_string(),
+                },
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::new(&server.uri());
+        let response = client
+            .put_note(
+                1,
+                Some(true),
+                &range,
+                Some(&tags),
+                types::PutNoteVisibility::Private,
+                Some("test"),
+                &note,
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.headers()["etag"], "\"1\"");
+        assert_eq!(response.into_inner().text, "hello");
+
+        match client
+            .put_note(
+                2,
+                None,
+                &range,
+                Some(&tags),
+                types::PutNoteVisibility::Private,
+                None,
+                &note,
+            )
+            .await
+        {
+            Err(Error::ErrorResponse(response)) => {
+                assert_eq!(response.status(), 409);
+                assert_eq!(response.message, "conflict");
+            }
+            _ => panic!("unexpected result"),
+        }
+
+        // Requests that don't match aren't answered by the mocks, but by
+        // wiremock with an empty 404 response.
+        let unmatched = client
+            .put_note(
+                1,
+                Some(false),
+                &range,
+                None,
+                types::PutNoteVisibility::Private,
+                None,
+                &note,
+            )
+            .await;
+        assert!(
+            matches!(unmatched, Err(Error::InvalidResponsePayload(body, _)) if body.is_empty())
+        );
+    }
+
+    // So do the httpmock matchers.
+    {
+        use test_httpmock_matching_httpmock::MockServerExt;
+
+        let server = httpmock::MockServer::start_async().await;
+        let matched = server.put_note(|when, then| {
+            when.id(1)
+                .pinned(true)
+                .range(&range)
+                .tags_contains("b")
+                .visibility(types::PutNoteVisibility::Private)
+                .x_request_source_exists()
+                .body_partial(serde_json::json!({ "text": "hello" }))
+                .body_matches(|note| note.text.starts_with('h'));
+            then.ok(&record).etag_header("\"1\"");
+        });
+        let conflict = server.put_note(|when, then| {
+            when.id(2)
+                .pinned(None)
+                .tags(&tags)
+                .x_request_source(None)
+                .body(&note);
+            then.client_error(
+                409,
+                &types::Error {
+                    error_code: None,
+                    message: "conflict".to_string(),
+                    request_id: "r".to_string(),
+                },
+            );
+        });
+
+        let client = Client::new(&server.base_url());
+        let response = client
+            .put_note(
+                1,
+                Some(true),
+                &range,
+                Some(&tags),
+                types::PutNoteVisibility::Private,
+                Some("test"),
+                &note,
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.headers()["etag"], "\"1\"");
+        assert_eq!(response.into_inner().text, "hello");
+
+        match client
+            .put_note(
+                2,
+                None,
+                &range,
+                Some(&tags),
+                types::PutNoteVisibility::Private,
+                None,
+                &note,
+            )
+            .await
+        {
+            Err(Error::ErrorResponse(response)) => {
+                assert_eq!(response.status(), 409);
+                assert_eq!(response.message, "conflict");
+            }
+            _ => panic!("unexpected result"),
+        }
+
+        // A request without the header that must be present isn't matched,
+        // but answered by httpmock with a 404 response the client can't
+        // decode.
+        let unmatched = client
+            .put_note(
+                1,
+                Some(true),
+                &range,
+                Some(&tags),
+                types::PutNoteVisibility::Private,
+                None,
+                &note,
+            )
+            .await;
+        assert!(matches!(unmatched, Err(Error::InvalidResponsePayload(..))));
+
+        matched.assert_hits_async(1).await;
+        conflict.assert_hits_async(1).await;
+    }
+}
+
+#[allow(dead_code, unused_imports)]
+mod test_httpmock_examples_positional {
+    include!("output/src/test_httpmock_examples_positional.rs");
+}
+
+#[allow(dead_code, unused_imports)]
+mod test_httpmock_examples_httpmock {
+    include!("output/src/test_httpmock_examples_httpmock.rs");
+}
+
+/// Test that the values `mock_all_from_examples` synthesizes for strings
+/// satisfy their patterns and lengths, so that the client accepts every
+/// response, and that operations for which no value is found aren't mocked.
+#[tokio::test]
+async fn test_httpmock_examples() {
+    const TEST_NAME: &str = "test_httpmock_examples";
+
+    let get = |schema: serde_json::Value| {
+        serde_json::json!({
+            "get": {
+                "operationId": schema["$ref"]
+                    .as_str()
+                    .and_then(|reference| reference.strip_prefix("#/components/schemas/"))
+                    .map(|name| format!("get_{}", name.to_lowercase()))
+                    .unwrap(),
+                "responses": {
+                    "200": {
+                        "description": "successful operation",
+                        "content": {
+                            "application/json": { "schema": schema }
+                        }
+                    }
+                }
+            }
+        })
+    };
+    let reference =
+        |name: &str| serde_json::json!({ "$ref": format!("#/components/schemas/{name}") });
+
+    // The patterns of the string types are those of the Nexus API.
+    let spec = serde_json::json!({
+        "openapi": "3.0.3",
+        "info": { "title": TEST_NAME, "version": "1.0.0" },
+        "paths": {
+            "/version": get(reference("Version")),
+            "/range": get(reference("Range")),
+            "/names": get(reference("Names")),
+            "/subnet": get(reference("Subnet")),
+            "/choice": get(reference("Choice")),
+            "/impossible": get(reference("Impossible")),
+        },
+        "components": {
+            "schemas": {
+                "Version": {
+                    "type": "string",
+                    "pattern": "^\\d+\\.\\d+\\.\\d+([\\-\\+].+)?$"
+                },
+                "Range": {
+                    "type": "object",
+                    "properties": {
+                        "high": { "$ref": "#/components/schemas/Version" },
+                        "low": { "$ref": "#/components/schemas/Version" }
+                    },
+                    "required": ["high", "low"]
+                },
+                "Name": {
+                    "type": "string",
+                    "pattern": "^(?![0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$)^[a-z][a-z0-9-]*[a-zA-Z0-9]$",
+                    "minLength": 8,
+                    "maxLength": 63
+                },
+                "Names": {
+                    "type": "array",
+                    "items": { "$ref": "#/components/schemas/Name" },
+                    "minItems": 2
+                },
+                "Subnet": {
+                    "type": "object",
+                    "properties": {
+                        "net": {
+                            "type": "string",
+                            "pattern": "^(([0-9]|[1-9][0-9]|1[0-9][0-9]|2[0-4][0-9]|25[0-5])\\.){3}([0-9]|[1-9][0-9]|1[0-9][0-9]|2[0-4][0-9]|25[0-5])/([8-9]|1[0-9]|2[0-9]|3[0-2])$"
+                        },
+                        "gateway": { "type": "string", "format": "ipv4", "pattern": "^127\\." }
+                    },
+                    "required": ["net", "gateway"]
+                },
+                "Impossible": {
+                    "type": "string",
+                    "pattern": "^a$",
+                    "minLength": 2
+                },
+                "Choice": {
+                    "oneOf": [
+                        { "$ref": "#/components/schemas/Impossible" },
+                        { "$ref": "#/components/schemas/Name" }
+                    ]
+                }
+            }
+        }
+    });
+    let spec = serde_json::from_value::<OpenAPI>(spec).unwrap();
+
+    assert_generated(
+        GenerationSettings::new().with_interface(InterfaceStyle::Positional),
+        &spec,
+        &format!("{TEST_NAME}_positional"),
+    );
+
+    let mut generator = Generator::new(
+        GenerationSettings::new()
+            .with_interface(InterfaceStyle::Positional)
+            .with_httpmock_examples(true),
+    );
+    let output = format_tokens(
+        generator
+            .httpmock(&spec, &format!("crate::{TEST_NAME}_positional"))
+            .unwrap(),
+    );
+    expectorate::assert_contents(format!("tests/output/src/{TEST_NAME}_httpmock.rs"), &output);
+
+    use test_httpmock_examples_httpmock::MockServerExt;
+    use test_httpmock_examples_positional::Client;
+
+    let server = httpmock::MockServer::start_async().await;
+    let mocks = server.mock_all_from_examples();
+    assert_eq!(
+        mocks.keys().copied().collect::<Vec<_>>(),
+        [
+            "get_choice",
+            "get_names",
+            "get_range",
+            "get_subnet",
+            "get_version"
+        ]
+    );
+
+    let client = Client::new(&server.base_url());
+    let version = client.get_version().await.unwrap().into_inner();
+    let range = client.get_range().await.unwrap().into_inner();
+    assert_eq!(range.low, version);
+    let names = client.get_names().await.unwrap().into_inner();
+    assert_eq!(names.len(), 2);
+    assert!(names[0].len() >= 8);
+    client.get_subnet().await.unwrap();
+    client.get_choice().await.unwrap();
+    for mock in mocks.values() {
+        mock.assert_hits_async(1).await;
+    }
+}
+
+/// A machine, with constraints on the values of its properties.
+#[derive(Deserialize, Serialize, JsonSchema)]
+#[allow(dead_code)]
+struct Machine {
+    #[schemars(length(min = 1, max = 16), regex(pattern = r"^[a-z][a-z0-9-]*$"))]
+    name: String,
+    /// The lookahead isn't supported by proptest's generation.
+    #[schemars(regex(pattern = r"^(?!none$)[a-z]+$"))]
+    profile: String,
+    #[schemars(range(min = 1, max = 10))]
+    count: u32,
+    #[schemars(range(min = 0.0, max = 1.0))]
+    ratio: f64,
+    kind: MachineKind,
+    size: MachineSize,
+    labels: std::collections::BTreeMap<String, MachineLabel>,
+    parts: Vec<MachinePart>,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[allow(dead_code)]
+enum MachineKind {
+    Gear,
+    Spring,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema)]
+#[serde(untagged)]
+#[allow(dead_code)]
+enum MachineSize {
+    Fixed(u64),
+    Named(String),
+}
+
+#[derive(Deserialize, Serialize, JsonSchema)]
+#[allow(dead_code)]
+struct MachineLabel(#[schemars(length(max = 8))] String);
+
+/// A part of a machine, which may be made of other parts.
+#[derive(Deserialize, Serialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[allow(dead_code)]
+enum MachinePart {
+    Leaf {
+        #[schemars(range(min = 1))]
+        weight: u8,
+    },
+    Assembly {
+        parts: Vec<MachinePart>,
+    },
+}
+
+#[endpoint {
+    method = PUT,
+    path = "/machines",
+}]
+async fn put_machine(
+    _rqctx: RequestContext<()>,
+    _body: TypedBody<Machine>,
+) -> Result<HttpResponseOk<Machine>, HttpError> {
+    unreachable!();
+}
+
+#[allow(dead_code, unused_imports)]
+mod gen_client_proptest {
+    // See the comment in `test_stream_pagination` regarding the use of
+    // `include!` for generated code. This is at the top level so that the
+    // generated round-trip tests are run.
+    include!("output/src/test_proptest_positional.rs");
+}
+
+/// Test generating values of types that satisfy the constraints of their
+/// schemas, and round-trip tests for them.
+#[test]
+fn test_proptest() {
+    const TEST_NAME: &str = "test_proptest";
+
+    let mut api = ApiDescription::new();
+    api.register(put_machine).unwrap();
+
+    let spec = api_spec(&api, TEST_NAME);
+
+    assert_generated(
+        GenerationSettings::new()
+            .with_interface(InterfaceStyle::Positional)
+            .with_proptest(true),
+        &spec,
+        &format!("{TEST_NAME}_positional"),
+    );
+
+    use gen_client_proptest::types;
+    use proptest::{
+        arbitrary::any_with,
+        strategy::{Strategy, ValueTree},
+        test_runner::TestRunner,
+    };
+
+    fn depth(part: &types::MachinePart) -> u32 {
+        match part {
+            types::MachinePart::Leaf { .. } => 0,
+            types::MachinePart::Assembly { parts } => {
+                1 + parts.iter().map(depth).max().unwrap_or(0)
+            }
+        }
+    }
+
+    let name_re = regress::Regex::new("^[a-z][a-z0-9-]*$").unwrap();
+    let profile_re = regress::Regex::new("^(?!none$)[a-z]+$").unwrap();
+    let mut runner = TestRunner::deterministic();
+    for _ in 0..256 {
+        let machine = any_with::<types::Machine>(())
+            .new_tree(&mut runner)
+            .unwrap()
+            .current();
+        let name = machine.name.as_str();
+        assert!((1..=16).contains(&name.chars().count()), "{name}");
+        assert!(name_re.find(name).is_some(), "{name}");
+        assert!(profile_re.find(&machine.profile).is_some());
+        assert!((1..=10).contains(&machine.count.get()));
+        assert!((0.0..=1.0).contains(&machine.ratio));
+        assert!(machine
+            .labels
+            .values()
+            .all(|label| label.chars().count() <= 8));
+        // Beyond the maximum depth, assemblies are empty.
+        for part in &machine.parts {
+            assert!(depth(part) <= progenitor_client::arbitrary::MAX_DEPTH + 1);
+        }
+    }
 }
@@ -43,6 +43,14 @@ mod token_utils;
 ///     [ post_hook = closure::or::path::to::function, ]
 ///     [ pre_hook_async = closure::or::path::to::function, ]
 ///     [ post_hook_async = closure::or::path::to::function, ]
+///     [ array_streaming = ( true | false ), ]
+///     [ request_validation = ( true | false ), ]
+///     [ response_checking = ( true | false ), ]
+///     [ typestate_builders = ( true | false ), ]
+///     [ operation_enum = ( true | false ), ]
+///     [ client_api = ( true | false ), ]
+///     [ client_api_attrs = [ attribute ], ]
+///     [ proptest = ( true | false ), ]
 ///
 ///     [ derives = [ path::to::DeriveMacro ], ]
 ///
@@ -84,6 +92,42 @@ mod token_utils;
 /// allows clients to examine responses, for example to log them. The optional
 /// `post_hook_async` is the `async` variant of the same.
 ///
+/// The optional `array_streaming` causes operations whose successful response
+/// is a JSON array (or an object with a single array-valued property) to
+/// return a `JsonArrayStream` that decodes the array's items incrementally
+/// rather than the full response type. The default is `false`.
+///
+/// The optional `request_validation` causes generated methods to check
+/// parameters and request bodies against the constraints in their schemas
+/// (such as `maximum`, `maxLength`, or `pattern`) before sending a request;
+/// patterns are checked with the `regress` crate, which must then be a
+/// dependency. The default is `false`.
+///
+/// The optional `response_checking` causes generated methods to check
+/// responses against the API description, reporting undeclared status codes,
+/// missing required properties, unknown enum values, and undeclared
+/// properties to `ClientHooks::contract_violation`. The default is `false`.
+///
+/// The optional `typestate_builders` applies to the `Builder` interface and
+/// makes `send()` available only once all required parameters have been set,
+/// so that omitting one is a compile-time error. The default is `false`.
+///
+/// The optional `operation_enum` generates a serializable `Operation` enum
+/// with the parameters of each operation, and `Client::execute_operation` to
+/// execute one. The default is `false`.
+///
+/// The optional `client_api` generates a `ClientApi` trait with an async
+/// method for each operation, implemented by `Client`, so that code using the
+/// client may be tested against a mock. It requires the `async-trait` feature
+/// of `progenitor`. The default is `false`. The optional `client_api_attrs`
+/// array holds attributes to apply to the trait, such as
+/// `cfg_attr(test, mockall::automock)`.
+///
+/// The optional `proptest` implements `proptest::arbitrary::Arbitrary` for
+/// each generated type, respecting the constraints of its schema, and
+/// generates a test for each type that its values survive a serde round trip.
+/// It requires the `proptest` feature of `progenitor`. The default is `false`.
+///
 /// Additional options control type generation:
 /// - `derives`: optional array of derive macro paths; the derive macros to be
 ///   applied to all generated types
@@ -138,6 +182,22 @@ struct MacroSettings {
     pre_hook_async: Option<ParseWrapper<ClosureOrPath>>,
     post_hook: Option<ParseWrapper<ClosureOrPath>>,
     post_hook_async: Option<ParseWrapper<ClosureOrPath>>,
+    #[serde(default)]
+    array_streaming: bool,
+    #[serde(default)]
+    request_validation: bool,
+    #[serde(default)]
+    response_checking: bool,
+    #[serde(default)]
+    typestate_builders: bool,
+    #[serde(default)]
+    operation_enum: bool,
+    #[serde(default)]
+    client_api: bool,
+    #[serde(default)]
+    client_api_attrs: Vec<ParseWrapper<syn::Meta>>,
+    #[serde(default)]
+    proptest: bool,
 
     map_type: Option<ParseWrapper<syn::Type>>,
 
@@ -307,6 +367,14 @@ fn do_generate_api(item: TokenStream) -> Result<TokenStream, syn::Error> {
             pre_hook_async,
             post_hook,
             post_hook_async,
+            array_streaming,
+            request_validation,
+            response_checking,
+            typestate_builders,
+            operation_enum,
+            client_api,
+            client_api_attrs,
+            proptest,
             map_type,
             unknown_crates,
             crates,
@@ -325,6 +393,16 @@ fn do_generate_api(item: TokenStream) -> Result<TokenStream, syn::Error> {
         post_hook.map(|post_hook| settings.with_post_hook(post_hook.into_inner().0));
         post_hook_async
             .map(|post_hook_async| settings.with_post_hook_async(post_hook_async.into_inner().0));
+        settings.with_array_streaming(array_streaming);
+        settings.with_request_validation(request_validation);
+        settings.with_response_checking(response_checking);
+        settings.with_typestate_builders(typestate_builders);
+        settings.with_operation_enum(operation_enum);
+        settings.with_client_api(client_api);
+        client_api_attrs.into_iter().for_each(|attr| {
+            settings.with_client_api_attr(attr.to_token_stream());
+        });
+        settings.with_proptest(proptest);
         map_type.map(|map_type| settings.with_map_type(map_type.to_token_stream()));
 
         settings.with_unknown_crates(unknown_crates);